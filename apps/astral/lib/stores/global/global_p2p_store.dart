@@ -1,5 +1,4 @@
 import 'dart:async';
-import 'dart:convert';
 import 'dart:io';
 
 import 'package:flutter/foundation.dart';
@@ -88,41 +87,38 @@ class GlobalP2PStore {
     _startEventListener();
   }
 
-  void _startEventListener() {
+  /// 绑定随机的 loopback 端口并告诉 Rust，事件包都要经 [P2PService.verifyLocalEvent]
+  /// 校验，其他进程伪造或重放的包直接丢弃。
+  Future<void> _startEventListener() async {
     try {
-      RawDatagramSocket.bind(InternetAddress.loopbackIPv4, 9999).then((socket) {
-        _eventSocket = socket;
-        _eventSubscription = socket.listen(_handleEvent);
-        debugPrint('[P2P事件] 开始监听 UDP 端口 9999');
-      });
+      final socket = await RawDatagramSocket.bind(InternetAddress.loopbackIPv4, 0);
+      await _p2pService.setLocalEventPort(socket.port);
+      _eventSocket = socket;
+      _eventSubscription = socket.listen(_handleEvent);
+      debugPrint('[P2P事件] 开始监听 UDP 端口 ${socket.port}');
     } catch (e) {
-      debugPrint('[P2P事件] 无法绑定 UDP 端口 9999: $e');
+      debugPrint('[P2P事件] 无法启动本机事件监听: $e');
     }
   }
 
-  void _handleEvent(RawSocketEvent event) {
-    if (event == RawSocketEvent.read) {
-      final datagram = _eventSocket?.receive();
-      if (datagram != null) {
-        final message = utf8.decode(datagram.data);
-        try {
-          final decoded = jsonDecode(message);
-          if (decoded is Map<String, dynamic>) {
-            final instanceId = decoded['instance_id'] as String?;
-            final logMessage = decoded['message'] as String?;
-            if (instanceId != null && logMessage != null) {
-              final instancePath = pathByInstanceId.value[instanceId];
-              debugPrint('[P2P事件] [$instanceId] $logMessage');
-              _logService.info('P2P', logMessage, instancePath: instancePath);
-              return;
-            }
-          }
-        } catch (_) {
-          // Fallback: treat as plain text if JSON parse fails
-        }
-        debugPrint('[P2P事件] $message');
-      }
+  Future<void> _handleEvent(RawSocketEvent event) async {
+    if (event != RawSocketEvent.read) return;
+    final datagram = _eventSocket?.receive();
+    if (datagram == null) return;
+    final verified = await _p2pService.verifyLocalEvent(datagram.data);
+    if (verified == null) {
+      debugPrint('[P2P事件] 丢弃未通过校验的数据包（来自 ${datagram.address.address}:${datagram.port}）');
+      return;
+    }
+    final instanceId = verified.instanceId;
+    final logMessage = verified.message;
+    if (instanceId.isEmpty) {
+      debugPrint('[P2P事件] $logMessage');
+      return;
     }
+    final instancePath = pathByInstanceId.value[instanceId];
+    debugPrint('[P2P事件] [$instanceId] $logMessage');
+    _logService.info('P2P', logMessage, instancePath: instancePath);
   }
 
   void setStarting(String path, bool starting) {
@@ -3,6 +3,7 @@ import 'dart:io';
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+import 'src/rust/api/local_channel.dart' as local_channel;
 import 'src/rust/api/p2p.dart' as p2p;
 import 'src/rust/frb_generated.dart' show RustLib;
 
@@ -138,4 +139,12 @@ class P2PService {
   /// 获取网络状态汇总信息。
   Future<p2p.KVNetworkStatus> getNetworkStatus(String instanceId) =>
       _withInit(() => p2p.getNetworkStatus(instanceId: instanceId));
+
+  /// 告诉 Rust 本机事件发往哪个端口（先绑定 `127.0.0.1:0` 拿到随机端口）。
+  Future<void> setLocalEventPort(int port) =>
+      _withInit(() async => local_channel.setLocalEventPort(port: port));
+
+  /// 校验一条本机事件 UDP 包；其他进程发来、签名不对或重放的包返回 null。
+  Future<local_channel.LocalEventC?> verifyLocalEvent(List<int> datagram) =>
+      _withInit(() async => local_channel.verifyLocalEvent(datagram: datagram));
 }
@@ -0,0 +1,83 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// Send the same notify to every peer in `scope`. The local node is never a
+/// target. Results are sorted by peer id.
+Future<List<BroadcastDeliveryC>> appBroadcast({
+  required String instanceId,
+  required String channel,
+  required List<int> payload,
+  required BroadcastScopeC scope,
+  required int timeoutMs,
+}) => RustLib.instance.api.crateApiAppBroadcastAppBroadcast(
+  instanceId: instanceId,
+  channel: channel,
+  payload: payload,
+  scope: scope,
+  timeoutMs: timeoutMs,
+);
+
+class BroadcastDeliveryC {
+  final int peerId;
+
+  /// The peer acknowledged the notify within `timeout_ms`.
+  final bool delivered;
+  final String error;
+
+  const BroadcastDeliveryC({
+    required this.peerId,
+    required this.delivered,
+    required this.error,
+  });
+
+  @override
+  int get hashCode => peerId.hashCode ^ delivered.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BroadcastDeliveryC &&
+          runtimeType == other.runtimeType &&
+          peerId == other.peerId &&
+          delivered == other.delivered &&
+          error == other.error;
+}
+
+/// Flat struct rather than an enum with data, like `AppInboundEventC`.
+class BroadcastScopeC {
+  final BroadcastScopeKindC kind;
+
+  /// Only used with [`BroadcastScopeKindC::Peers`].
+  final Uint32List peerIds;
+
+  const BroadcastScopeC({required this.kind, required this.peerIds});
+
+  @override
+  int get hashCode => kind.hashCode ^ peerIds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BroadcastScopeC &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          peerIds == other.peerIds;
+}
+
+enum BroadcastScopeKindC {
+  /// Every peer in the route table, including ones reached through relays.
+  all,
+
+  /// Only peers with a direct connection to this node.
+  directOnly,
+
+  /// Exactly the ids in [`BroadcastScopeC::peer_ids`].
+  peers,
+}
@@ -0,0 +1,115 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'p2p.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `emit`, `forget_call`, `forget_instance`, `is_handled`, `now_ms`, `track`, `watch_instance`, `with_call`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `EVENTS`, `InboundCall`, `OUTGOING`, `TRACKERS`, `Tracker`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `default`, `deref`, `deref`, `deref`, `eq`, `fmt`, `fmt`, `initialize`, `initialize`, `initialize`
+
+/// `app_call` that sends its `timeout_ms` to the callee as a deadline and
+/// can be cancelled with [`cancel_app_call`] using the same `dst_peer_id` and
+/// `request_id`. A cancelled call resolves right away with
+/// `app_rpc_status::CALL_CANCELLED`.
+Future<AppCallResultC> appCallWithDeadline({
+  required String instanceId,
+  required int dstPeerId,
+  required String channel,
+  required BigInt requestId,
+  required List<int> payload,
+  required int flags,
+  required int timeoutMs,
+}) => RustLib.instance.api.crateApiAppCallControlAppCallWithDeadline(
+  instanceId: instanceId,
+  dstPeerId: dstPeerId,
+  channel: channel,
+  requestId: requestId,
+  payload: payload,
+  flags: flags,
+  timeoutMs: timeoutMs,
+);
+
+/// Cancel a call made with [`app_call_with_deadline`] and tell the callee.
+/// Returns `false` if no such call is in flight.
+Future<bool> cancelAppCall({
+  required String instanceId,
+  required int dstPeerId,
+  required BigInt requestId,
+}) => RustLib.instance.api.crateApiAppCallControlCancelAppCall(
+  instanceId: instanceId,
+  dstPeerId: dstPeerId,
+  requestId: requestId,
+);
+
+/// Deadlines and cancellations of inbound calls. Calls are tracked from the
+/// start of the instance, so the state of calls that arrived before the
+/// subscription can still be polled; events are only streamed from now on.
+Stream<AppCallControlC> subscribeAppCallControl({
+  required String instanceId,
+}) => RustLib.instance.api.crateApiAppCallControlSubscribeAppCallControl(
+  instanceId: instanceId,
+);
+
+/// Whether the caller cancelled the inbound call `token`.
+bool isAppCallCancelled({
+  required String instanceId,
+  required BigInt token,
+}) => RustLib.instance.api.crateApiAppCallControlIsAppCallCancelled(
+  instanceId: instanceId,
+  token: token,
+);
+
+/// Deadline of the inbound call `token` in Unix ms; 0 when unknown.
+BigInt appCallDeadline({
+  required String instanceId,
+  required BigInt token,
+}) => RustLib.instance.api.crateApiAppCallControlAppCallDeadline(
+  instanceId: instanceId,
+  token: token,
+);
+
+/// Flat struct rather than an enum with data, like `AppInboundEventC`.
+class AppCallControlC {
+  final AppCallControlKindC kind;
+  final int fromPeerId;
+  final BigInt requestId;
+
+  /// The inbound call's token, as in `AppInboundEventC`.
+  final BigInt token;
+
+  /// Unix ms on this node's clock; 0 when the caller sent no deadline.
+  final BigInt deadlineMs;
+
+  const AppCallControlC({
+    required this.kind,
+    required this.fromPeerId,
+    required this.requestId,
+    required this.token,
+    required this.deadlineMs,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      fromPeerId.hashCode ^
+      requestId.hashCode ^
+      token.hashCode ^
+      deadlineMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AppCallControlC &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          fromPeerId == other.fromPeerId &&
+          requestId == other.requestId &&
+          token == other.token &&
+          deadlineMs == other.deadlineMs;
+}
+
+enum AppCallControlKindC { deadline, cancelled }
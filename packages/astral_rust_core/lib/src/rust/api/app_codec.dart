@@ -0,0 +1,162 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `accepted_by`, `decode`, `decompress`, `encode`, `forget_instance`, `from_value`, `is_legacy_parse_error`, `to_value`, `transcode`, `untagged`, `validate`, `watch_instance`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `LEGACY_PEERS`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `deref`, `eq`, `fmt`, `fmt`, `fmt`, `initialize`
+
+/// `app_call` with a tagged payload. `payload` must be valid `codec`; the
+/// reply is converted to `reply_codec`. Peers still on untagged JSON are
+/// detected and retried once with plain JSON.
+Future<AppCallDecodedC> appCallEncoded({
+  required String instanceId,
+  required int dstPeerId,
+  required String channel,
+  required BigInt requestId,
+  required List<int> payload,
+  required PayloadCodecC codec,
+  required PayloadCodecC replyCodec,
+  required int flags,
+  required int timeoutMs,
+}) => RustLib.instance.api.crateApiAppCodecAppCallEncoded(
+  instanceId: instanceId,
+  dstPeerId: dstPeerId,
+  channel: channel,
+  requestId: requestId,
+  payload: payload,
+  codec: codec,
+  replyCodec: replyCodec,
+  flags: flags,
+  timeoutMs: timeoutMs,
+);
+
+/// `app_notify` with a tagged payload. Peers previously found to be on
+/// untagged JSON get plain JSON.
+Future<void> appNotifyEncoded({
+  required String instanceId,
+  required int dstPeerId,
+  required String channel,
+  required List<int> payload,
+  required PayloadCodecC codec,
+  required int timeoutMs,
+}) => RustLib.instance.api.crateApiAppCodecAppNotifyEncoded(
+  instanceId: instanceId,
+  dstPeerId: dstPeerId,
+  channel: channel,
+  payload: payload,
+  codec: codec,
+  timeoutMs: timeoutMs,
+);
+
+/// `app_call_reply` with a tagged payload. `peer_accepts` comes from
+/// [`decode_app_payload`] on the inbound call: the reply is transcoded to the
+/// first accepted codec if `codec` is not among them, and sent as untagged
+/// JSON when the caller did not tag its request.
+Future<bool> appCallReplyEncoded({
+  required String instanceId,
+  required BigInt token,
+  required int status,
+  required String errorMsg,
+  required List<int> payload,
+  required PayloadCodecC codec,
+  required List<PayloadCodecC> peerAccepts,
+}) => RustLib.instance.api.crateApiAppCodecAppCallReplyEncoded(
+  instanceId: instanceId,
+  token: token,
+  status: status,
+  errorMsg: errorMsg,
+  payload: payload,
+  codec: codec,
+  peerAccepts: peerAccepts,
+);
+
+/// Decode an inbound payload (from `subscribe_app_inbound` or a call reply)
+/// into `want`. Untagged payloads are treated as JSON.
+DecodedPayloadC decodeAppPayload({
+  required List<int> payload,
+  required PayloadCodecC want,
+}) => RustLib.instance.api.crateApiAppCodecDecodeAppPayload(
+  payload: payload,
+  want: want,
+);
+
+/// Result of [`app_call_encoded`]; `payload` is already in `codec`.
+class AppCallDecodedC {
+  final int status;
+  final String errorMsg;
+  final PayloadCodecC codec;
+  final Uint8List payload;
+
+  const AppCallDecodedC({
+    required this.status,
+    required this.errorMsg,
+    required this.codec,
+    required this.payload,
+  });
+
+  @override
+  int get hashCode =>
+      status.hashCode ^ errorMsg.hashCode ^ codec.hashCode ^ payload.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AppCallDecodedC &&
+          runtimeType == other.runtimeType &&
+          status == other.status &&
+          errorMsg == other.errorMsg &&
+          codec == other.codec &&
+          payload == other.payload;
+}
+
+/// A payload with its header stripped, decompressed and converted to the
+/// codec the caller asked for.
+class DecodedPayloadC {
+  final PayloadCodecC codec;
+  final Uint8List payload;
+
+  /// The sender tagged the payload; `false` means an older client that only
+  /// speaks untagged JSON.
+  final bool tagged;
+
+  /// Codecs the sender can read. Pass to [`app_call_reply_encoded`] so the
+  /// reply is in a codec the caller understands.
+  final List<PayloadCodecC> peerAccepts;
+
+  const DecodedPayloadC({
+    required this.codec,
+    required this.payload,
+    required this.tagged,
+    required this.peerAccepts,
+  });
+
+  @override
+  int get hashCode =>
+      codec.hashCode ^
+      payload.hashCode ^
+      tagged.hashCode ^
+      peerAccepts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DecodedPayloadC &&
+          runtimeType == other.runtimeType &&
+          codec == other.codec &&
+          payload == other.payload &&
+          tagged == other.tagged &&
+          peerAccepts == other.peerAccepts;
+}
+
+enum PayloadCodecC {
+  /// Opaque bytes: never validated or transcoded.
+  raw,
+  json,
+  messagePack,
+  cbor,
+}
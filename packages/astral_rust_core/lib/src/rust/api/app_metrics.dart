@@ -0,0 +1,227 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `escape_label`, `forget_instance`, `record_call`, `record_inbound`, `record_lagged`, `record_notify`, `record_reply`, `update`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Histogram`, `InstanceMetrics`, `METRICS`, `Metrics`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `default`, `default`, `default`, `default`, `deref`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`
+
+/// Metrics of an instance since it started or was last reset.
+AppRpcMetricsC getAppRpcMetrics({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiAppMetricsGetAppRpcMetrics(instanceId: instanceId);
+
+void resetAppRpcMetrics({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiAppMetricsResetAppRpcMetrics(instanceId: instanceId);
+
+/// The instance's metrics in the OpenMetrics text format, labelled by
+/// `instance`, and `channel` or `peer`.
+String appRpcMetricsOpenmetrics({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiAppMetricsAppRpcMetricsOpenmetrics(instanceId: instanceId);
+
+class AppRpcCountersC {
+  final BigInt callsSent;
+  final BigInt callsReceived;
+  final BigInt notifiesSent;
+  final BigInt notifiesReceived;
+
+  /// Replies to inbound calls sent from this node.
+  final BigInt repliesSent;
+
+  /// Replies received for outgoing calls, whatever their status.
+  final BigInt repliesReceived;
+
+  /// Outgoing calls answered with `REPLY_TIMEOUT`.
+  final BigInt timeouts;
+  final BigInt noSubscriber;
+  final BigInt serviceDropped;
+
+  /// Outgoing calls cancelled by the caller (see `app_call_control`).
+  final BigInt cancelled;
+
+  /// Calls and notifies that failed before any reply (routing, ack
+  /// timeout).
+  final BigInt sendErrors;
+
+  const AppRpcCountersC({
+    required this.callsSent,
+    required this.callsReceived,
+    required this.notifiesSent,
+    required this.notifiesReceived,
+    required this.repliesSent,
+    required this.repliesReceived,
+    required this.timeouts,
+    required this.noSubscriber,
+    required this.serviceDropped,
+    required this.cancelled,
+    required this.sendErrors,
+  });
+
+  @override
+  int get hashCode =>
+      callsSent.hashCode ^
+      callsReceived.hashCode ^
+      notifiesSent.hashCode ^
+      notifiesReceived.hashCode ^
+      repliesSent.hashCode ^
+      repliesReceived.hashCode ^
+      timeouts.hashCode ^
+      noSubscriber.hashCode ^
+      serviceDropped.hashCode ^
+      cancelled.hashCode ^
+      sendErrors.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AppRpcCountersC &&
+          runtimeType == other.runtimeType &&
+          callsSent == other.callsSent &&
+          callsReceived == other.callsReceived &&
+          notifiesSent == other.notifiesSent &&
+          notifiesReceived == other.notifiesReceived &&
+          repliesSent == other.repliesSent &&
+          repliesReceived == other.repliesReceived &&
+          timeouts == other.timeouts &&
+          noSubscriber == other.noSubscriber &&
+          serviceDropped == other.serviceDropped &&
+          cancelled == other.cancelled &&
+          sendErrors == other.sendErrors;
+}
+
+class AppRpcMetricsC {
+  final BigInt laggedEvents;
+
+  /// Sorted by channel; unregistered channels the policy doesn't name are
+  /// reported as `"other"`.
+  final List<ChannelMetricsC> channels;
+
+  /// Sorted by peer id.
+  final List<PeerMetricsC> peers;
+
+  const AppRpcMetricsC({
+    required this.laggedEvents,
+    required this.channels,
+    required this.peers,
+  });
+
+  @override
+  int get hashCode =>
+      laggedEvents.hashCode ^ channels.hashCode ^ peers.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AppRpcMetricsC &&
+          runtimeType == other.runtimeType &&
+          laggedEvents == other.laggedEvents &&
+          channels == other.channels &&
+          peers == other.peers;
+}
+
+class ChannelMetricsC {
+  final String channel;
+  final AppRpcCountersC counters;
+
+  /// Round trip of outgoing calls.
+  final LatencyHistogramC callLatency;
+
+  /// Inbound call reaching Dart until its reply.
+  final LatencyHistogramC handlerLatency;
+
+  const ChannelMetricsC({
+    required this.channel,
+    required this.counters,
+    required this.callLatency,
+    required this.handlerLatency,
+  });
+
+  @override
+  int get hashCode =>
+      channel.hashCode ^
+      counters.hashCode ^
+      callLatency.hashCode ^
+      handlerLatency.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChannelMetricsC &&
+          runtimeType == other.runtimeType &&
+          channel == other.channel &&
+          counters == other.counters &&
+          callLatency == other.callLatency &&
+          handlerLatency == other.handlerLatency;
+}
+
+class LatencyHistogramC {
+  /// Upper bounds in milliseconds; `counts` has one more entry for values
+  /// above the last bound.
+  final Float64List boundsMs;
+
+  /// Per-bucket (not cumulative) counts.
+  final Uint64List counts;
+  final BigInt count;
+  final double sumMs;
+
+  const LatencyHistogramC({
+    required this.boundsMs,
+    required this.counts,
+    required this.count,
+    required this.sumMs,
+  });
+
+  @override
+  int get hashCode =>
+      boundsMs.hashCode ^ counts.hashCode ^ count.hashCode ^ sumMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LatencyHistogramC &&
+          runtimeType == other.runtimeType &&
+          boundsMs == other.boundsMs &&
+          counts == other.counts &&
+          count == other.count &&
+          sumMs == other.sumMs;
+}
+
+class PeerMetricsC {
+  final int peerId;
+  final AppRpcCountersC counters;
+  final LatencyHistogramC callLatency;
+  final LatencyHistogramC handlerLatency;
+
+  const PeerMetricsC({
+    required this.peerId,
+    required this.counters,
+    required this.callLatency,
+    required this.handlerLatency,
+  });
+
+  @override
+  int get hashCode =>
+      peerId.hashCode ^
+      counters.hashCode ^
+      callLatency.hashCode ^
+      handlerLatency.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PeerMetricsC &&
+          runtimeType == other.runtimeType &&
+          peerId == other.peerId &&
+          counters == other.counters &&
+          callLatency == other.callLatency &&
+          handlerLatency == other.handlerLatency;
+}
@@ -0,0 +1,226 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `admit`, `forget_instance`, `names_channel`, `track`, `tracked_channel`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Bucket`, `POLICIES`, `PolicyState`, `Rejections`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `default`, `deref`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`
+
+/// Set (or replace) the inbound policy of an instance. Rate limit state
+/// starts over; rejection counters are kept.
+void setAppRpcPolicy({
+  required String instanceId,
+  required AppRpcPolicyC policy,
+}) => RustLib.instance.api.crateApiAppPolicySetAppRpcPolicy(
+  instanceId: instanceId,
+  policy: policy,
+);
+
+/// Remove the policy; every event is forwarded again and counters are
+/// dropped.
+void clearAppRpcPolicy({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiAppPolicyClearAppRpcPolicy(instanceId: instanceId);
+
+AppRpcPolicyC? getAppRpcPolicy({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiAppPolicyGetAppRpcPolicy(instanceId: instanceId);
+
+/// Rejections since the policy was first set or the counters were reset.
+AppRpcRejectionsC getAppRpcRejections({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiAppPolicyGetAppRpcRejections(instanceId: instanceId);
+
+void resetAppRpcRejections({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiAppPolicyResetAppRpcRejections(instanceId: instanceId);
+
+class AppRpcPolicyC {
+  /// Largest accepted payload in bytes, on every channel; 0 disables the
+  /// check.
+  final int maxPayloadBytes;
+
+  /// Applied to each sending peer separately, counting every channel.
+  final RateLimitC peerRate;
+
+  /// Applied to each app channel separately, across all peers.
+  final RateLimitC channelRate;
+  final List<PeerChannelRuleC> channelRules;
+
+  const AppRpcPolicyC({
+    required this.maxPayloadBytes,
+    required this.peerRate,
+    required this.channelRate,
+    required this.channelRules,
+  });
+
+  @override
+  int get hashCode =>
+      maxPayloadBytes.hashCode ^
+      peerRate.hashCode ^
+      channelRate.hashCode ^
+      channelRules.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AppRpcPolicyC &&
+          runtimeType == other.runtimeType &&
+          maxPayloadBytes == other.maxPayloadBytes &&
+          peerRate == other.peerRate &&
+          channelRate == other.channelRate &&
+          channelRules == other.channelRules;
+}
+
+class AppRpcRejectionsC {
+  final BigInt total;
+  final List<ReasonRejectionsC> byReason;
+
+  /// Sorted by peer id.
+  final List<PeerRejectionsC> byPeer;
+
+  /// Sorted by channel. Channels that are neither registered nor named by
+  /// the policy are counted as `"other"`.
+  final List<ChannelRejectionsC> byChannel;
+
+  const AppRpcRejectionsC({
+    required this.total,
+    required this.byReason,
+    required this.byPeer,
+    required this.byChannel,
+  });
+
+  @override
+  int get hashCode =>
+      total.hashCode ^ byReason.hashCode ^ byPeer.hashCode ^ byChannel.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AppRpcRejectionsC &&
+          runtimeType == other.runtimeType &&
+          total == other.total &&
+          byReason == other.byReason &&
+          byPeer == other.byPeer &&
+          byChannel == other.byChannel;
+}
+
+class ChannelRejectionsC {
+  final String channel;
+  final BigInt count;
+
+  const ChannelRejectionsC({required this.channel, required this.count});
+
+  @override
+  int get hashCode => channel.hashCode ^ count.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChannelRejectionsC &&
+          runtimeType == other.runtimeType &&
+          channel == other.channel &&
+          count == other.count;
+}
+
+/// Channel allow/deny lists for one peer, or for every peer without a rule
+/// of its own when `peer_id` is 0.
+class PeerChannelRuleC {
+  final int peerId;
+
+  /// When non-empty, only these channels are accepted from the peer.
+  final List<String> allow;
+
+  /// Never accepted from the peer; wins over `allow`.
+  final List<String> deny;
+
+  const PeerChannelRuleC({
+    required this.peerId,
+    required this.allow,
+    required this.deny,
+  });
+
+  @override
+  int get hashCode => peerId.hashCode ^ allow.hashCode ^ deny.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PeerChannelRuleC &&
+          runtimeType == other.runtimeType &&
+          peerId == other.peerId &&
+          allow == other.allow &&
+          deny == other.deny;
+}
+
+class PeerRejectionsC {
+  final int peerId;
+  final BigInt count;
+
+  const PeerRejectionsC({required this.peerId, required this.count});
+
+  @override
+  int get hashCode => peerId.hashCode ^ count.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PeerRejectionsC &&
+          runtimeType == other.runtimeType &&
+          peerId == other.peerId &&
+          count == other.count;
+}
+
+class RateLimitC {
+  /// Sustained events per second; 0 disables the limit.
+  final int perSecond;
+
+  /// Events allowed in a burst above the sustained rate; at least 1 is used.
+  final int burst;
+
+  const RateLimitC({required this.perSecond, required this.burst});
+
+  @override
+  int get hashCode => perSecond.hashCode ^ burst.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RateLimitC &&
+          runtimeType == other.runtimeType &&
+          perSecond == other.perSecond &&
+          burst == other.burst;
+}
+
+class ReasonRejectionsC {
+  final RejectReasonC reason;
+  final BigInt count;
+
+  const ReasonRejectionsC({required this.reason, required this.count});
+
+  @override
+  int get hashCode => reason.hashCode ^ count.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReasonRejectionsC &&
+          runtimeType == other.runtimeType &&
+          reason == other.reason &&
+          count == other.count;
+}
+
+enum RejectReasonC {
+  payloadTooLarge,
+  peerRateLimited,
+  channelRateLimited,
+  channelDenied,
+}
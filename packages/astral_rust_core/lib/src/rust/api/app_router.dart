@@ -0,0 +1,50 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'p2p.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `ensure_router`, `forget_instance`, `is_registered`, `is_reserved`, `route`, `subscribe_inbound`, `watch_instance`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ROUTERS`, `Router`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `default`, `deref`, `initialize`
+
+/// Declare channels served by this node. Calls to any other app channel are
+/// answered with `app_rpc_status::CHANNEL_NOT_FOUND` from then on.
+/// Registering an already known channel is a no-op.
+Future<void> registerAppChannels({
+  required String instanceId,
+  required List<String> channels,
+}) => RustLib.instance.api.crateApiAppRouterRegisterAppChannels(
+  instanceId: instanceId,
+  channels: channels,
+);
+
+/// Stop serving the given channels. Once the last channel is gone the
+/// instance falls back to streaming every app event.
+Future<void> unregisterAppChannels({
+  required String instanceId,
+  required List<String> channels,
+}) => RustLib.instance.api.crateApiAppRouterUnregisterAppChannels(
+  instanceId: instanceId,
+  channels: channels,
+);
+
+/// Channels currently registered for the instance, sorted.
+List<String> listAppChannels({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiAppRouterListAppChannels(instanceId: instanceId);
+
+/// Like `subscribe_app_inbound`, but only delivers events for `channels`,
+/// which must already be registered. Each Dart service can hold its own
+/// subscription for the channels it handles.
+Stream<AppInboundEventC> subscribeAppChannels({
+  required String instanceId,
+  required List<String> channels,
+}) => RustLib.instance.api.crateApiAppRouterSubscribeAppChannels(
+  instanceId: instanceId,
+  channels: channels,
+);
@@ -0,0 +1,172 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `close_inbound`, `close_outbound`, `deliver`, `emit`, `forget_instance`, `frame_header`, `handle_frame`, `handle_frames`, `is_handled`, `progress`, `read_u64`, `reply`, `watch_instance`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `INBOUND`, `Inbound`, `InboundFrame`, `OFFERS`, `OUTBOUND`, `Outbound`, `PROGRESS`, `RECEIVERS`, `Receiver`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `default`, `deref`, `deref`, `deref`, `deref`, `deref`, `eq`, `fmt`, `fmt`, `fmt`, `initialize`, `initialize`, `initialize`, `initialize`, `initialize`
+
+/// Open a stream to `dst_peer_id` on `channel`. Fails if the peer has no
+/// [`subscribe_app_streams`] listener for the channel. `total_len` is only
+/// used for progress (0 when unknown); `timeout_ms` applies to every frame.
+Future<BigInt> appStreamOpen({
+  required String instanceId,
+  required int dstPeerId,
+  required String channel,
+  required BigInt totalLen,
+  required List<int> metadata,
+  required int timeoutMs,
+}) => RustLib.instance.api.crateApiAppStreamAppStreamOpen(
+  instanceId: instanceId,
+  dstPeerId: dstPeerId,
+  channel: channel,
+  totalLen: totalLen,
+  metadata: metadata,
+  timeoutMs: timeoutMs,
+);
+
+/// Send `data` on an open stream, split into chunks with at most `WINDOW` in
+/// flight. Resolves once the receiver has buffered every chunk. Writes on the
+/// same stream must not overlap.
+Future<void> appStreamWrite({
+  required String instanceId,
+  required BigInt streamId,
+  required List<int> data,
+}) => RustLib.instance.api.crateApiAppStreamAppStreamWrite(
+  instanceId: instanceId,
+  streamId: streamId,
+  data: data,
+);
+
+/// Mark the end of the stream. Resolves once the receiver has everything.
+Future<void> appStreamFinish({
+  required String instanceId,
+  required BigInt streamId,
+}) => RustLib.instance.api.crateApiAppStreamAppStreamFinish(
+  instanceId: instanceId,
+  streamId: streamId,
+);
+
+/// Cancel a stream from either side: `peer_id` is the destination for
+/// streams we opened, or the sender for streams we are reading.
+Future<void> appStreamCancel({
+  required String instanceId,
+  required int peerId,
+  required BigInt streamId,
+}) => RustLib.instance.api.crateApiAppStreamAppStreamCancel(
+  instanceId: instanceId,
+  peerId: peerId,
+  streamId: streamId,
+);
+
+/// Receive stream offers for `channel`. While at least one subscription is
+/// open, peers can open streams on the channel; otherwise they are rejected
+/// with `app_rpc_status::CHANNEL_NOT_FOUND`.
+Stream<InboundAppStreamC> subscribeAppStreams({
+  required String instanceId,
+  required String channel,
+}) => RustLib.instance.api.crateApiAppStreamSubscribeAppStreams(
+  instanceId: instanceId,
+  channel: channel,
+);
+
+/// Read an offered stream's chunks in order. The Dart stream ends when the
+/// sender finishes; a cancelled stream ends early (see
+/// [`subscribe_app_stream_progress`] for why). Can only be called once per
+/// stream.
+Stream<Uint8List> appStreamRead({
+  required String instanceId,
+  required int fromPeerId,
+  required BigInt streamId,
+}) => RustLib.instance.api.crateApiAppStreamAppStreamRead(
+  instanceId: instanceId,
+  fromPeerId: fromPeerId,
+  streamId: streamId,
+);
+
+/// Progress of one stream, on either side. `peer_id` as in
+/// [`app_stream_cancel`]. Ends after the final (non-`Open`) event.
+Stream<AppStreamProgressC> subscribeAppStreamProgress({
+  required String instanceId,
+  required int peerId,
+  required BigInt streamId,
+}) => RustLib.instance.api.crateApiAppStreamSubscribeAppStreamProgress(
+  instanceId: instanceId,
+  peerId: peerId,
+  streamId: streamId,
+);
+
+class AppStreamProgressC {
+  final AppStreamStateC state;
+
+  /// Bytes acknowledged by the receiver (sender side) or delivered to the
+  /// reader (receiver side).
+  final BigInt bytes;
+
+  /// Announced size; 0 when unknown.
+  final BigInt totalLen;
+  final String error;
+
+  const AppStreamProgressC({
+    required this.state,
+    required this.bytes,
+    required this.totalLen,
+    required this.error,
+  });
+
+  @override
+  int get hashCode =>
+      state.hashCode ^ bytes.hashCode ^ totalLen.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AppStreamProgressC &&
+          runtimeType == other.runtimeType &&
+          state == other.state &&
+          bytes == other.bytes &&
+          totalLen == other.totalLen &&
+          error == other.error;
+}
+
+enum AppStreamStateC { open, finished, cancelled, failed }
+
+/// A stream offered by a peer; read it with [`app_stream_read`].
+class InboundAppStreamC {
+  final int fromPeerId;
+  final BigInt streamId;
+  final String channel;
+  final BigInt totalLen;
+  final Uint8List metadata;
+
+  const InboundAppStreamC({
+    required this.fromPeerId,
+    required this.streamId,
+    required this.channel,
+    required this.totalLen,
+    required this.metadata,
+  });
+
+  @override
+  int get hashCode =>
+      fromPeerId.hashCode ^
+      streamId.hashCode ^
+      channel.hashCode ^
+      totalLen.hashCode ^
+      metadata.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InboundAppStreamC &&
+          runtimeType == other.runtimeType &&
+          fromPeerId == other.fromPeerId &&
+          streamId == other.streamId &&
+          channel == other.channel &&
+          totalLen == other.totalLen &&
+          metadata == other.metadata;
+}
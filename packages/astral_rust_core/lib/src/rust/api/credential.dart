@@ -0,0 +1,154 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `apply_node_key`, `decode_public_key`, `fingerprint`, `generate_secret`, `node_key_info`, `notify_local_key_changed`, `peer_config`, `read_key_file`, `write_key_file`, `write_private_file`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CHANGES`, `KeyStore`, `STORE`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `default`, `deref`, `deref`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`, `initialize`
+
+/// 从 `store_dir` 加载节点密钥；不存在时生成一对新密钥并写盘。
+/// 加载后，开启了安全模式的实例会用这对密钥握手。
+Future<NodeKeyInfoC> loadOrGenerateNodeKeypair({
+  required String storeDir,
+}) => RustLib.instance.api.crateApiCredentialLoadOrGenerateNodeKeypair(
+  storeDir: storeDir,
+);
+
+/// 丢弃旧密钥并生成新的一对。已固定本机旧公钥的对端需要重新固定。
+Future<NodeKeyInfoC> regenerateNodeKeypair({
+  required String storeDir,
+}) => RustLib.instance.api.crateApiCredentialRegenerateNodeKeypair(
+  storeDir: storeDir,
+);
+
+/// 本机公钥与指纹；还没加载密钥库时返回 `None`。
+NodeKeyInfoC? localPublicKey() =>
+    RustLib.instance.api.crateApiCredentialLocalPublicKey();
+
+/// 计算任意 base64 公钥的指纹，方便 UI 让用户和服务器管理员核对。
+String publicKeyFingerprint({required String publicKey}) => RustLib
+    .instance
+    .api
+    .crateApiCredentialPublicKeyFingerprint(publicKey: publicKey);
+
+/// 固定某个服务器 URL 的公钥。之后新建的实例连接该 URL 时会校验对端公钥。
+Future<void> pinServerPublicKey({
+  required String serverUrl,
+  required String publicKey,
+}) => RustLib.instance.api.crateApiCredentialPinServerPublicKey(
+  serverUrl: serverUrl,
+  publicKey: publicKey,
+);
+
+/// 取消固定。返回 `false` 表示该 URL 本来就没有固定公钥。
+Future<bool> unpinServerPublicKey({required String serverUrl}) => RustLib
+    .instance
+    .api
+    .crateApiCredentialUnpinServerPublicKey(serverUrl: serverUrl);
+
+List<PinnedServerKeyC> listPinnedServerKeys() =>
+    RustLib.instance.api.crateApiCredentialListPinnedServerKeys();
+
+/// 推送凭据变更：本机密钥 / 固定列表的改动，以及该实例上 EasyTier 报告的
+/// `CredentialChanged`。实例关闭后 future 结束。
+Stream<CredentialEventC> subscribeCredentialEvents({
+  required String instanceId,
+}) => RustLib.instance.api.crateApiCredentialSubscribeCredentialEvents(
+  instanceId: instanceId,
+);
+
+/// [`subscribe_credential_events`] 推送的事件。和 `AppInboundEventC` 一样用扁平结构，
+/// 不相关的字段留空串。
+class CredentialEventC {
+  final CredentialEventKindC kind;
+
+  /// `PinnedKeyChanged` 对应的服务器 URL。
+  final String serverUrl;
+
+  /// 变更后的公钥；本机密钥事件为本机公钥，固定被移除时为空串。
+  final String publicKey;
+  final String fingerprint;
+
+  const CredentialEventC({
+    required this.kind,
+    required this.serverUrl,
+    required this.publicKey,
+    required this.fingerprint,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      serverUrl.hashCode ^
+      publicKey.hashCode ^
+      fingerprint.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CredentialEventC &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          serverUrl == other.serverUrl &&
+          publicKey == other.publicKey &&
+          fingerprint == other.fingerprint;
+}
+
+enum CredentialEventKindC {
+  /// 本机密钥被生成 / 重新生成。
+  localKeyChanged,
+
+  /// 某个服务器的固定公钥被新增、替换或移除。
+  pinnedKeyChanged,
+
+  /// EasyTier 实例报告凭据变更（`GlobalCtxEvent::CredentialChanged`）。
+  instanceCredentialChanged,
+}
+
+class NodeKeyInfoC {
+  /// base64 编码的 x25519 公钥。
+  final String publicKey;
+  final String fingerprint;
+
+  const NodeKeyInfoC({required this.publicKey, required this.fingerprint});
+
+  @override
+  int get hashCode => publicKey.hashCode ^ fingerprint.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NodeKeyInfoC &&
+          runtimeType == other.runtimeType &&
+          publicKey == other.publicKey &&
+          fingerprint == other.fingerprint;
+}
+
+class PinnedServerKeyC {
+  final String serverUrl;
+  final String publicKey;
+  final String fingerprint;
+
+  const PinnedServerKeyC({
+    required this.serverUrl,
+    required this.publicKey,
+    required this.fingerprint,
+  });
+
+  @override
+  int get hashCode =>
+      serverUrl.hashCode ^ publicKey.hashCode ^ fingerprint.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PinnedServerKeyC &&
+          runtimeType == other.runtimeType &&
+          serverUrl == other.serverUrl &&
+          publicKey == other.publicKey &&
+          fingerprint == other.fingerprint;
+}
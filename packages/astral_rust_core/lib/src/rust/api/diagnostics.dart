@@ -0,0 +1,128 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `check_mtu`, `check_nat`, `check_server`, `forget_instance`, `now_ms`, `pass`, `same_listener`, `watch_instance`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `EventRecord`, `RECORDS`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `cmp`, `default`, `deref`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`, `partial_cmp`
+
+/// 对实例做一次完整的连接诊断。
+Future<DiagnosticReportC> runDiagnostics({
+  required String instanceId,
+}) => RustLib.instance.api.crateApiDiagnosticsRunDiagnostics(
+  instanceId: instanceId,
+);
+
+class ConnectErrorC {
+  /// 目标地址（已脱敏）。
+  final String dst;
+  final String ipVersion;
+  final String error;
+  final BigInt timestampMs;
+
+  const ConnectErrorC({
+    required this.dst,
+    required this.ipVersion,
+    required this.error,
+    required this.timestampMs,
+  });
+
+  @override
+  int get hashCode =>
+      dst.hashCode ^ ipVersion.hashCode ^ error.hashCode ^ timestampMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConnectErrorC &&
+          runtimeType == other.runtimeType &&
+          dst == other.dst &&
+          ipVersion == other.ipVersion &&
+          error == other.error &&
+          timestampMs == other.timestampMs;
+}
+
+class DiagnosticCheckC {
+  final DiagnosticCheckKindC kind;
+  final DiagnosticStatusC status;
+
+  /// 检查对象，例如服务器 / 监听 URL（已脱敏）；整体性检查为空串。
+  final String target;
+  final String detail;
+
+  /// 修复建议；`Pass` 时为空串。
+  final String suggestion;
+
+  const DiagnosticCheckC({
+    required this.kind,
+    required this.status,
+    required this.target,
+    required this.detail,
+    required this.suggestion,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      status.hashCode ^
+      target.hashCode ^
+      detail.hashCode ^
+      suggestion.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DiagnosticCheckC &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          status == other.status &&
+          target == other.target &&
+          detail == other.detail &&
+          suggestion == other.suggestion;
+}
+
+enum DiagnosticCheckKindC {
+  serverResolve,
+  serverReachability,
+  listener,
+  tunDevice,
+  natType,
+  holePunching,
+  firewall,
+  mtu,
+  connectErrors,
+}
+
+class DiagnosticReportC {
+  /// 所有检查里最差的状态。
+  final DiagnosticStatusC overall;
+  final List<DiagnosticCheckC> checks;
+
+  /// 最近的连接错误，按时间先后排列。
+  final List<ConnectErrorC> recentConnectErrors;
+
+  const DiagnosticReportC({
+    required this.overall,
+    required this.checks,
+    required this.recentConnectErrors,
+  });
+
+  @override
+  int get hashCode =>
+      overall.hashCode ^ checks.hashCode ^ recentConnectErrors.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DiagnosticReportC &&
+          runtimeType == other.runtimeType &&
+          overall == other.overall &&
+          checks == other.checks &&
+          recentConnectErrors == other.recentConnectErrors;
+}
+
+enum DiagnosticStatusC { pass, warn, fail }
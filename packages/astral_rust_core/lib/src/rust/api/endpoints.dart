@@ -0,0 +1,82 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `result`, `same_listener`, `wait_listener`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+/// 运行中添加一个服务器 / 节点地址，例如好友的直连 IP `tcp://1.2.3.4:11010`。
+/// 地址固定了公钥时会重启实例，见模块说明。
+Future<EndpointResultC> addPeerUri({
+  required String instanceId,
+  required String uri,
+}) => RustLib.instance.api.crateApiEndpointsAddPeerUri(
+  instanceId: instanceId,
+  uri: uri,
+);
+
+/// 运行中移除一个服务器 / 节点地址，已建立的连接随之关闭。
+Future<EndpointResultC> removePeerUri({
+  required String instanceId,
+  required String uri,
+}) => RustLib.instance.api.crateApiEndpointsRemovePeerUri(
+  instanceId: instanceId,
+  uri: uri,
+);
+
+/// 添加监听地址并返回绑定结果。会重启实例，断开所有连接，见模块说明。
+Future<EndpointResultC> addListener({
+  required String instanceId,
+  required String url,
+}) => RustLib.instance.api.crateApiEndpointsAddListener(
+  instanceId: instanceId,
+  url: url,
+);
+
+/// 移除监听地址。会重启实例，断开所有连接，见模块说明。
+Future<EndpointResultC> removeListener({
+  required String instanceId,
+  required String url,
+}) => RustLib.instance.api.crateApiEndpointsRemoveListener(
+  instanceId: instanceId,
+  url: url,
+);
+
+enum EndpointOutcomeC {
+  added,
+  alreadyPresent,
+  removed,
+  notFound,
+
+  /// 仅监听：绑定失败，`error` 为原因。失败的监听不会保留在配置里。
+  failed,
+}
+
+class EndpointResultC {
+  /// 操作的 URL（已脱敏）。
+  final String url;
+  final EndpointOutcomeC outcome;
+  final String error;
+
+  const EndpointResultC({
+    required this.url,
+    required this.outcome,
+    required this.error,
+  });
+
+  @override
+  int get hashCode => url.hashCode ^ outcome.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EndpointResultC &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          outcome == other.outcome &&
+          error == other.error;
+}
@@ -0,0 +1,179 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `control_call`, `download`, `emit`, `ensure_handler`, `forget_instance`, `handle_control`, `handle_message`, `hash_into`, `hash_prefix`, `is_handled`, `is_sha256_hex`, `part_path`, `read_chunk`, `reply`, `sanitize_name`, `unique_path`, `watch_instance`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `HANDLERS`, `INCOMING`, `OFFERS`, `OUTGOING`, `Outgoing`, `PROGRESS`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `deref`, `deref`, `deref`, `deref`, `deref`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`, `initialize`, `initialize`, `initialize`, `initialize`
+
+/// Offer a local file to `dst_peer_id`. Hashes the file first, so this takes
+/// a while for large files. Returns the transfer id once the peer has seen
+/// the offer; whether it accepts shows up in the progress stream.
+Future<String> offerFile({
+  required String instanceId,
+  required int dstPeerId,
+  required String path,
+}) => RustLib.instance.api.crateApiFileTransferOfferFile(
+  instanceId: instanceId,
+  dstPeerId: dstPeerId,
+  path: path,
+);
+
+/// Receive file offers. Offers only arrive while at least one subscription is
+/// open (or a transfer was offered from this instance); earlier offers that are
+/// still pending can be listed with [`list_file_offers`].
+Stream<FileOfferC> subscribeFileOffers({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiFileTransferSubscribeFileOffers(instanceId: instanceId);
+
+/// Offers received and not yet completed, rejected or cancelled.
+List<FileOfferC> listFileOffers({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiFileTransferListFileOffers(instanceId: instanceId);
+
+/// Accept an offer and download it into `dir`. Resolves with the final path
+/// once the file is complete and verified. If the transfer is interrupted the
+/// partial data is kept; calling this again resumes it.
+Future<String> acceptFile({
+  required String instanceId,
+  required String transferId,
+  required String dir,
+}) => RustLib.instance.api.crateApiFileTransferAcceptFile(
+  instanceId: instanceId,
+  transferId: transferId,
+  dir: dir,
+);
+
+/// Decline an offer.
+Future<void> rejectFile({
+  required String instanceId,
+  required String transferId,
+  required String reason,
+}) => RustLib.instance.api.crateApiFileTransferRejectFile(
+  instanceId: instanceId,
+  transferId: transferId,
+  reason: reason,
+);
+
+/// Cancel a transfer from either side. A cancelled download keeps its partial
+/// file, so a new offer of the same file resumes it.
+Future<void> cancelFileTransfer({
+  required String instanceId,
+  required String transferId,
+}) => RustLib.instance.api.crateApiFileTransferCancelFileTransfer(
+  instanceId: instanceId,
+  transferId: transferId,
+);
+
+/// Progress of every transfer on the instance, in both directions.
+Stream<FileTransferProgressC> subscribeFileTransferProgress({
+  required String instanceId,
+}) => RustLib.instance.api.crateApiFileTransferSubscribeFileTransferProgress(
+  instanceId: instanceId,
+);
+
+class FileOfferC {
+  final int fromPeerId;
+  final String transferId;
+  final String name;
+  final BigInt size;
+
+  /// Hex SHA-256 of the whole file.
+  final String sha256;
+
+  const FileOfferC({
+    required this.fromPeerId,
+    required this.transferId,
+    required this.name,
+    required this.size,
+    required this.sha256,
+  });
+
+  @override
+  int get hashCode =>
+      fromPeerId.hashCode ^
+      transferId.hashCode ^
+      name.hashCode ^
+      size.hashCode ^
+      sha256.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FileOfferC &&
+          runtimeType == other.runtimeType &&
+          fromPeerId == other.fromPeerId &&
+          transferId == other.transferId &&
+          name == other.name &&
+          size == other.size &&
+          sha256 == other.sha256;
+}
+
+enum FileTransferDirectionC { sending, receiving }
+
+class FileTransferProgressC {
+  final String transferId;
+
+  /// The other side of the transfer.
+  final int peerId;
+  final FileTransferDirectionC direction;
+  final FileTransferStateC state;
+  final String name;
+  final BigInt bytes;
+  final BigInt size;
+  final String error;
+
+  const FileTransferProgressC({
+    required this.transferId,
+    required this.peerId,
+    required this.direction,
+    required this.state,
+    required this.name,
+    required this.bytes,
+    required this.size,
+    required this.error,
+  });
+
+  @override
+  int get hashCode =>
+      transferId.hashCode ^
+      peerId.hashCode ^
+      direction.hashCode ^
+      state.hashCode ^
+      name.hashCode ^
+      bytes.hashCode ^
+      size.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FileTransferProgressC &&
+          runtimeType == other.runtimeType &&
+          transferId == other.transferId &&
+          peerId == other.peerId &&
+          direction == other.direction &&
+          state == other.state &&
+          name == other.name &&
+          bytes == other.bytes &&
+          size == other.size &&
+          error == other.error;
+}
+
+enum FileTransferStateC {
+  offered,
+  transferring,
+  completed,
+  rejected,
+  cancelled,
+
+  /// Interrupted or failed verification; a receiver can accept again to
+  /// resume.
+  failed,
+}
@@ -6,11 +6,19 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+// These functions are ignored because they are not marked as `pub`: `add_rule`, `blocking`, `diagnose`, `finding`, `instance_targets`, `remove_rule`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `RuleTarget`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`
+
+/// 旧接口，`profile_index` 含义见 [`FirewallProfile::from_index`]。
+/// 新代码用 [`get_firewall_profile_status`] 或 [`get_firewall_snapshot`]。
 Future<bool> getFirewallStatus({required int profileIndex}) => RustLib
     .instance
     .api
     .crateApiFirewallGetFirewallStatus(profileIndex: profileIndex);
 
+/// 整体开关某个 profile 的防火墙。影响整机所有程序，只应作为最后手段：
+/// 优先用 [`allow_instance_through_firewall`] 只放行虚拟网和监听端口。
 Future<void> setFirewallStatus({
   required int profileIndex,
   required bool enable,
@@ -18,3 +26,367 @@ Future<void> setFirewallStatus({
   profileIndex: profileIndex,
   enable: enable,
 );
+
+Future<bool> getFirewallProfileStatus({
+  required FirewallProfile profile,
+}) => RustLib.instance.api.crateApiFirewallGetFirewallProfileStatus(
+  profile: profile,
+);
+
+/// 见 [`set_firewall_status`]，同样只应作为最后手段。
+Future<void> setFirewallProfileStatus({
+  required FirewallProfile profile,
+  required bool enable,
+}) => RustLib.instance.api.crateApiFirewallSetFirewallProfileStatus(
+  profile: profile,
+  enable: enable,
+);
+
+/// 一次读出所有 profile 的状态。
+Future<FirewallSnapshotC> getFirewallSnapshot() =>
+    RustLib.instance.api.crateApiFirewallGetFirewallSnapshot();
+
+Future<TunFirewallStatusC> getTunFirewallStatus({
+  required String devName,
+  required List<FirewallPortC> ports,
+}) => RustLib.instance.api.crateApiFirewallGetTunFirewallStatus(
+  devName: devName,
+  ports: ports,
+);
+
+Future<void> allowTunThroughFirewall({required String devName}) => RustLib
+    .instance
+    .api
+    .crateApiFirewallAllowTunThroughFirewall(devName: devName);
+
+Future<void> removeTunFirewallRule({required String devName}) => RustLib
+    .instance
+    .api
+    .crateApiFirewallRemoveTunFirewallRule(devName: devName);
+
+/// 为实例添加精确放行规则：虚拟网流量 + 每个监听端口。需要管理员 / root 权限。
+Future<List<FirewallRuleC>> allowInstanceThroughFirewall({
+  required String instanceId,
+}) => RustLib.instance.api.crateApiFirewallAllowInstanceThroughFirewall(
+  instanceId: instanceId,
+);
+
+/// 删除 [`allow_instance_through_firewall`] 添加的规则。
+Future<void> removeInstanceFirewallRules({
+  required String instanceId,
+}) => RustLib.instance.api.crateApiFirewallRemoveInstanceFirewallRules(
+  instanceId: instanceId,
+);
+
+/// 检查虚拟网和各监听端口的入站流量是否会被防火墙拦截，以及拦截它的规则。
+Future<FirewallDiagnosisC> diagnoseFirewall({
+  required String instanceId,
+}) => RustLib.instance.api.crateApiFirewallDiagnoseFirewall(
+  instanceId: instanceId,
+);
+
+/// 当前生效的防火墙实现。
+enum FirewallBackendC {
+  none,
+  firewalld,
+  ufw,
+  nftables,
+  iptables,
+  windowsFirewall,
+
+  /// 检测命令因权限不足失败，无法判断有没有防火墙在拦截（Linux 非 root 运行时）。
+  unknown,
+}
+
+class FirewallDiagnosisC {
+  final FirewallBackendC backend;
+  final bool active;
+  final List<FirewallFindingC> findings;
+
+  const FirewallDiagnosisC({
+    required this.backend,
+    required this.active,
+    required this.findings,
+  });
+
+  @override
+  int get hashCode => backend.hashCode ^ active.hashCode ^ findings.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FirewallDiagnosisC &&
+          runtimeType == other.runtimeType &&
+          backend == other.backend &&
+          active == other.active &&
+          findings == other.findings;
+}
+
+/// 防火墙接口统一的错误类型，各平台的底层错误都转成这个。
+///
+/// 用 `kind` + `message` 的扁平结构而不是带数据的 enum，Dart 侧生成的是普通 class，
+/// 不需要引入 freezed。
+class FirewallError implements FrbException {
+  final FirewallErrorKind kind;
+  final String message;
+
+  const FirewallError({required this.kind, required this.message});
+
+  @override
+  int get hashCode => kind.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FirewallError &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          message == other.message;
+}
+
+/// 防火墙错误的类别。
+enum FirewallErrorKind {
+  /// 旧接口传入了 1..=3 以外的 profile 编号。
+  invalidProfile,
+
+  /// 当前平台不支持该操作。
+  unsupported,
+
+  /// 实例不存在或配置读取失败。
+  instance,
+
+  /// 系统防火墙调用失败（COM 错误、命令执行失败、权限不足等）。
+  backend,
+
+  /// 没有权限读取防火墙规则，状态未知。
+  needsPrivileges,
+}
+
+class FirewallFindingC {
+  final FirewallRuleC rule;
+
+  /// 该流量当前会被拦截。
+  final bool blocked;
+
+  /// 拦截它的规则或策略，例如 `iptables -P INPUT DROP`；Windows 上命中多条 block
+  /// 规则时是逗号分隔的规则名。未拦截时为空串。
+  final String blockingRule;
+
+  /// 给用户看的修复建议；未拦截时为空串。
+  final String suggestion;
+
+  const FirewallFindingC({
+    required this.rule,
+    required this.blocked,
+    required this.blockingRule,
+    required this.suggestion,
+  });
+
+  @override
+  int get hashCode =>
+      rule.hashCode ^
+      blocked.hashCode ^
+      blockingRule.hashCode ^
+      suggestion.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FirewallFindingC &&
+          runtimeType == other.runtimeType &&
+          rule == other.rule &&
+          blocked == other.blocked &&
+          blockingRule == other.blockingRule &&
+          suggestion == other.suggestion;
+}
+
+class FirewallPortC {
+  /// `tcp` / `udp`
+  final String proto;
+  final int port;
+
+  const FirewallPortC({required this.proto, required this.port});
+
+  @override
+  int get hashCode => proto.hashCode ^ port.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FirewallPortC &&
+          runtimeType == other.runtimeType &&
+          proto == other.proto &&
+          port == other.port;
+}
+
+class FirewallPortStatusC {
+  final String proto;
+  final int port;
+  final bool allowed;
+
+  const FirewallPortStatusC({
+    required this.proto,
+    required this.port,
+    required this.allowed,
+  });
+
+  @override
+  int get hashCode => proto.hashCode ^ port.hashCode ^ allowed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FirewallPortStatusC &&
+          runtimeType == other.runtimeType &&
+          proto == other.proto &&
+          port == other.port &&
+          allowed == other.allowed;
+}
+
+/// Windows 防火墙的网络配置文件。Linux 没有 profile 的概念，三个 profile 都返回同一个状态。
+enum FirewallProfile { domain, private, public }
+
+class FirewallProfileStatusC {
+  final FirewallProfile profile;
+  final bool enabled;
+
+  /// 该 profile 是当前网络正在使用的。
+  final bool current;
+
+  const FirewallProfileStatusC({
+    required this.profile,
+    required this.enabled,
+    required this.current,
+  });
+
+  @override
+  int get hashCode => profile.hashCode ^ enabled.hashCode ^ current.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FirewallProfileStatusC &&
+          runtimeType == other.runtimeType &&
+          profile == other.profile &&
+          enabled == other.enabled &&
+          current == other.current;
+}
+
+/// 一条 Astral 管理的放行规则。
+class FirewallRuleC {
+  final FirewallRuleKindC kind;
+
+  /// 规则名；Windows 防火墙界面里显示的就是这个名字。
+  final String name;
+
+  /// `ListenerPort` 时为 `tcp` / `udp`，否则为空串。
+  final String proto;
+
+  /// `ListenerPort` 时为端口号，否则为 0。
+  final int port;
+
+  /// `Overlay` 时为 TUN 设备名 / 虚拟网网段。
+  final String detail;
+
+  const FirewallRuleC({
+    required this.kind,
+    required this.name,
+    required this.proto,
+    required this.port,
+    required this.detail,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      name.hashCode ^
+      proto.hashCode ^
+      port.hashCode ^
+      detail.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FirewallRuleC &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          name == other.name &&
+          proto == other.proto &&
+          port == other.port &&
+          detail == other.detail;
+}
+
+enum FirewallRuleKindC {
+  /// 虚拟网流量：Linux 上按 TUN 设备放行，Windows 上按虚拟网网段放行。
+  overlay,
+
+  /// 某个监听端口。
+  listenerPort,
+}
+
+/// 所有 profile 的开关状态，以及当前网络使用的 profile。
+class FirewallSnapshotC {
+  final FirewallBackendC backend;
+  final List<FirewallProfileStatusC> profiles;
+
+  /// Windows 上多块网卡可能同时处于不同 profile；Linux 上为空。
+  final List<FirewallProfile> currentProfiles;
+
+  const FirewallSnapshotC({
+    required this.backend,
+    required this.profiles,
+    required this.currentProfiles,
+  });
+
+  @override
+  int get hashCode =>
+      backend.hashCode ^ profiles.hashCode ^ currentProfiles.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FirewallSnapshotC &&
+          runtimeType == other.runtimeType &&
+          backend == other.backend &&
+          profiles == other.profiles &&
+          currentProfiles == other.currentProfiles;
+}
+
+class TunFirewallStatusC {
+  final FirewallBackendC backend;
+
+  /// 防火墙是否在拦截入站流量；为 `false` 时下面的放行状态都视为 `true`。
+  final bool active;
+  final bool tunAllowed;
+  final List<FirewallPortStatusC> ports;
+
+  /// 补充信息，例如 firewalld 的 zone，或读取规则失败的原因。
+  final String detail;
+
+  const TunFirewallStatusC({
+    required this.backend,
+    required this.active,
+    required this.tunAllowed,
+    required this.ports,
+    required this.detail,
+  });
+
+  @override
+  int get hashCode =>
+      backend.hashCode ^
+      active.hashCode ^
+      tunAllowed.hashCode ^
+      ports.hashCode ^
+      detail.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TunFirewallStatusC &&
+          runtimeType == other.runtimeType &&
+          backend == other.backend &&
+          active == other.active &&
+          tunAllowed == other.tunAllowed &&
+          ports == other.ports &&
+          detail == other.detail;
+}
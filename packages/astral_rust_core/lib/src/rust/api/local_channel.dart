@@ -0,0 +1,53 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `mac_for`, `send`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `KEY`, `REPLAY`, `ReplayWindow`, `SESSION`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `default`, `deref`, `deref`, `deref`, `fmt`, `initialize`, `initialize`, `initialize`
+
+/// 设置事件接收端口。Dart 应先绑定 `127.0.0.1:0` 拿到随机端口再调用，
+/// 不调用时沿用默认的 9999。
+void setLocalEventPort({required int port}) =>
+    RustLib.instance.api.crateApiLocalChannelSetLocalEventPort(port: port);
+
+/// 本次启动的 session id，接收方可以据此快速丢弃其他进程的包。
+String localEventSession() =>
+    RustLib.instance.api.crateApiLocalChannelLocalEventSession();
+
+/// 校验收到的 UDP 包：session 必须是本进程的、签名正确、且不是重放。
+/// 任一条件不满足返回 `None`。
+LocalEventC? verifyLocalEvent({required List<int> datagram}) => RustLib
+    .instance
+    .api
+    .crateApiLocalChannelVerifyLocalEvent(datagram: datagram);
+
+/// 校验通过的本机事件。
+class LocalEventC {
+  /// 来源实例；不属于某个实例的消息为空串。
+  final String instanceId;
+  final String message;
+  final BigInt seq;
+
+  const LocalEventC({
+    required this.instanceId,
+    required this.message,
+    required this.seq,
+  });
+
+  @override
+  int get hashCode => instanceId.hashCode ^ message.hashCode ^ seq.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LocalEventC &&
+          runtimeType == other.runtimeType &&
+          instanceId == other.instanceId &&
+          message == other.message &&
+          seq == other.seq;
+}
@@ -0,0 +1,187 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `call`, `emit`, `enqueue`, `find`, `flush`, `forget_instance`, `handle_inbound`, `handle_message`, `is_current`, `is_handled`, `kind_str`, `load_history`, `mark_delivered`, `message_json`, `message_op`, `new_message`, `now_ms`, `parse_message`, `persist`, `record`, `spawn_tasks`, `update`, `watch_instance`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `EVENTS`, `MESSENGERS`, `Messenger`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `deref`, `deref`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`, `initialize`
+
+/// Start the messaging service for an instance, loading history from
+/// `history_dir`. Calling it again for the same instance is a no-op.
+Future<void> startMessaging({
+  required String instanceId,
+  required String historyDir,
+}) => RustLib.instance.api.crateApiMessagingStartMessaging(
+  instanceId: instanceId,
+  historyDir: historyDir,
+);
+
+/// Send a message to one peer. Resolves once it is delivered or queued.
+Future<ChatMessageC> sendDirectMessage({
+  required String instanceId,
+  required int peerId,
+  required String text,
+}) => RustLib.instance.api.crateApiMessagingSendDirectMessage(
+  instanceId: instanceId,
+  peerId: peerId,
+  text: text,
+);
+
+/// Send a message to every peer currently in the route table. Peers that
+/// can't be reached get it queued; peers that join later don't get it.
+Future<ChatMessageC> sendRoomMessage({
+  required String instanceId,
+  required String text,
+}) => RustLib.instance.api.crateApiMessagingSendRoomMessage(
+  instanceId: instanceId,
+  text: text,
+);
+
+/// Mark received messages as read and send read receipts to their senders.
+/// Unknown ids and messages already read are ignored.
+Future<void> markMessagesRead({
+  required String instanceId,
+  required List<String> messageIds,
+}) => RustLib.instance.api.crateApiMessagingMarkMessagesRead(
+  instanceId: instanceId,
+  messageIds: messageIds,
+);
+
+/// Stored messages, oldest first. `limit` 0 returns everything.
+List<ChatMessageC> listMessages({
+  required String instanceId,
+  required int limit,
+}) => RustLib.instance.api.crateApiMessagingListMessages(
+  instanceId: instanceId,
+  limit: limit,
+);
+
+/// Incoming messages and status changes of outgoing ones.
+Stream<MessageEventC> subscribeMessages({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiMessagingSubscribeMessages(instanceId: instanceId);
+
+class ChatMessageC {
+  final String id;
+  final ChatMessageKindC kind;
+  final int fromPeerId;
+
+  /// 0 for room messages.
+  final int toPeerId;
+  final String text;
+  final BigInt sentAtMs;
+
+  /// Sent by this node.
+  final bool outgoing;
+
+  /// Peers that acknowledged the message.
+  final Uint32List deliveredTo;
+
+  /// Peers that read it; for received messages, this node once marked read.
+  final Uint32List readBy;
+
+  /// Peers the message is queued for until they reappear.
+  final Uint32List queuedFor;
+
+  const ChatMessageC({
+    required this.id,
+    required this.kind,
+    required this.fromPeerId,
+    required this.toPeerId,
+    required this.text,
+    required this.sentAtMs,
+    required this.outgoing,
+    required this.deliveredTo,
+    required this.readBy,
+    required this.queuedFor,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      kind.hashCode ^
+      fromPeerId.hashCode ^
+      toPeerId.hashCode ^
+      text.hashCode ^
+      sentAtMs.hashCode ^
+      outgoing.hashCode ^
+      deliveredTo.hashCode ^
+      readBy.hashCode ^
+      queuedFor.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChatMessageC &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          kind == other.kind &&
+          fromPeerId == other.fromPeerId &&
+          toPeerId == other.toPeerId &&
+          text == other.text &&
+          sentAtMs == other.sentAtMs &&
+          outgoing == other.outgoing &&
+          deliveredTo == other.deliveredTo &&
+          readBy == other.readBy &&
+          queuedFor == other.queuedFor;
+}
+
+enum ChatMessageKindC {
+  direct,
+
+  /// Sent to every peer of the network instance.
+  room,
+}
+
+/// Flat struct rather than an enum with data, like `AppInboundEventC`.
+/// `message` is always the full current state.
+class MessageEventC {
+  final MessageEventKindC kind;
+
+  /// The peer the event is about: sender for `Received`, recipient otherwise.
+  final int peerId;
+  final ChatMessageC message;
+
+  /// Why the history write failed; empty for every other kind.
+  final String error;
+
+  const MessageEventC({
+    required this.kind,
+    required this.peerId,
+    required this.message,
+    required this.error,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^ peerId.hashCode ^ message.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MessageEventC &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          peerId == other.peerId &&
+          message == other.message &&
+          error == other.error;
+}
+
+enum MessageEventKindC {
+  received,
+
+  /// The message was handed to the network (or queued, see `queued_for`).
+  sent,
+  delivered,
+  read,
+  queued,
+
+  /// Saving `message` to the history file failed; see `error`. The message
+  /// itself was still sent or received.
+  historyWriteFailed,
+}
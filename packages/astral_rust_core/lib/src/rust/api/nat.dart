@@ -0,0 +1,137 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `compatibility`, `port_mapping`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// 独立跑一次 STUN 检测。`stun_servers` 为空时使用 EasyTier 内置的服务器列表。
+Future<NatDetectionC> detectNat({required List<String> stunServers}) =>
+    RustLib.instance.api.crateApiNatDetectNat(stunServers: stunServers);
+
+/// 两种 NAT 类型之间直连的可能性，给 UI 在加入房间前提示用。
+NatCompatibilityC natCompatibility({
+  required NatTypeC local,
+  required NatTypeC peer,
+}) => RustLib.instance.api.crateApiNatNatCompatibility(
+  local: local,
+  peer: peer,
+);
+
+class NatCompatibilityC {
+  final NatTypeC peerNatType;
+  final P2pLikelihoodC likelihood;
+
+  /// 给用户看的解释。
+  final String explanation;
+
+  const NatCompatibilityC({
+    required this.peerNatType,
+    required this.likelihood,
+    required this.explanation,
+  });
+
+  @override
+  int get hashCode =>
+      peerNatType.hashCode ^ likelihood.hashCode ^ explanation.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NatCompatibilityC &&
+          runtimeType == other.runtimeType &&
+          peerNatType == other.peerNatType &&
+          likelihood == other.likelihood &&
+          explanation == other.explanation;
+}
+
+class NatDetectionC {
+  final NatTypeC udpNatType;
+  final NatTypeC tcpNatType;
+  final PortMappingC udpPortMapping;
+
+  /// STUN 看到的公网 IP。
+  final List<String> publicIps;
+
+  /// 一次实际的 UDP 端口映射结果 `ip:port`；拿不到时为空串。
+  final String mappedUdpAddr;
+
+  /// 对称型 NAT 观察到的公网端口范围；cone 型时两者相同或为 0。
+  final int minPort;
+  final int maxPort;
+
+  /// 和每种 NAT 类型的对端直连的可能性。
+  final List<NatCompatibilityC> compatibility;
+
+  const NatDetectionC({
+    required this.udpNatType,
+    required this.tcpNatType,
+    required this.udpPortMapping,
+    required this.publicIps,
+    required this.mappedUdpAddr,
+    required this.minPort,
+    required this.maxPort,
+    required this.compatibility,
+  });
+
+  @override
+  int get hashCode =>
+      udpNatType.hashCode ^
+      tcpNatType.hashCode ^
+      udpPortMapping.hashCode ^
+      publicIps.hashCode ^
+      mappedUdpAddr.hashCode ^
+      minPort.hashCode ^
+      maxPort.hashCode ^
+      compatibility.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NatDetectionC &&
+          runtimeType == other.runtimeType &&
+          udpNatType == other.udpNatType &&
+          tcpNatType == other.tcpNatType &&
+          udpPortMapping == other.udpPortMapping &&
+          publicIps == other.publicIps &&
+          mappedUdpAddr == other.mappedUdpAddr &&
+          minPort == other.minPort &&
+          maxPort == other.maxPort &&
+          compatibility == other.compatibility;
+}
+
+enum NatTypeC {
+  unknown,
+  openInternet,
+  noPat,
+  fullCone,
+  restricted,
+  portRestricted,
+  symmetric,
+  symUdpFirewall,
+  symmetricEasyInc,
+  symmetricEasyDec,
+}
+
+enum P2pLikelihoodC { likely, possible, unlikely }
+
+/// 端口映射行为（RFC 4787 的 mapping behavior）。
+enum PortMappingC {
+  unknown,
+
+  /// 没有 NAT，公网地址就是本机地址。
+  noTranslation,
+
+  /// 同一个本地端口对所有目标映射到同一个公网端口（cone）。
+  endpointIndependent,
+
+  /// 对不同目标映射到不同公网端口，但按固定步长递增 / 递减，可以预测。
+  endpointDependentPredictable,
+
+  /// 对不同目标映射到不同且无规律的公网端口（symmetric）。
+  endpointDependent,
+}
@@ -6,10 +6,11 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `get_instance_info`, `handle_event_with_instance_id`, `lookup_app_rpc`, `parse_instance_id`, `peer_conn_info_to_string`, `send_udp_to_localhost_with_instance_id`, `tracing_log_lagged`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `MANAGER`, `RT`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `deref`, `deref`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`, `initialize`
+// These functions are ignored because they are not marked as `pub`: `config_snapshot`, `connected_remote_addrs`, `connected_remote_urls`, `connector_patch`, `describe_event`, `get_instance_info`, `handle_event_with_instance_id`, `inbound_event`, `instance_config`, `lookup_app_rpc`, `parse_instance_id`, `patch_instance_config`, `peer_conn_info_to_string`, `restart_instance`, `restart_with_pinned_peers`, `runtime`, `send_udp_to_localhost_with_instance_id`, `start_restarted_instance`, `subscribe_instance_events`, `tracing_log_lagged`, `wait_for_app_rpc_service`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `INSTANCE_CONFIGS`, `MANAGER`, `RT`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `deref`, `deref`, `deref`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`, `initialize`, `initialize`
 
+/// 发送一条不属于任何实例的本机事件；和实例事件一样经过签名，见 [`local_channel`]。
 Future<void> sendUdpToLocalhost({required String message}) =>
     RustLib.instance.api.crateApiP2PSendUdpToLocalhost(message: message);
 
@@ -79,6 +80,15 @@ Future<KVNetworkStatus> getNetworkStatus({required String instanceId}) =>
 Future<void> initApp() => RustLib.instance.api.crateApiP2PInitApp();
 
 /// Send a request-response RPC to `dst_peer_id` and await the typed reply.
+/// `app_call_control::app_call_with_deadline` is the cancellable variant.
+///
+/// 不要用 `RT.spawn(...).await` 把这次调用搬到 `RT` —— `RT` 既不是 FRB 的
+/// runtime，也不是 EasyTier 每个 instance 自己的 runtime（见 `EasyTierLauncher::start`
+/// 里的 `std::thread::spawn` + 独立 `tokio::runtime::Runtime`）。跨三个 runtime
+/// 调度时，`tokio::time::timeout` 注册在 RT 的 timer driver、mpsc 唤醒发生在
+/// EasyTier runtime、JoinHandle 唤醒落在 FRB executor，时序上很容易错过 wake，
+/// 表现为 Dart 侧 `appCall` 永久 Pending、5s 超时也不触发。直接在 FRB executor
+/// 上 `.await svc.call()` 反而是稳的（参考 commit a0fb25e）。
 Future<AppCallResultC> appCall({
   required String instanceId,
   required int dstPeerId,
@@ -128,6 +138,11 @@ Future<PlatformInt64> peerPing({
 /// Dart. The future resolves once the EasyTier instance shuts down (the
 /// underlying broadcast channel is closed); Dart can re-subscribe after a
 /// subsequent `create_server` call.
+///
+/// Events come from the instance's channel router (see `app_router`): once
+/// channels are registered only events for registered channels are
+/// delivered, and events rejected by the instance's `app_policy` never
+/// arrive. Use `app_router::subscribe_app_channels` to receive a subset.
 Stream<AppInboundEventC> subscribeAppInbound({required String instanceId}) =>
     RustLib.instance.api.crateApiP2PSubscribeAppInbound(instanceId: instanceId);
 
@@ -153,7 +168,8 @@ Future<bool> appCallReply({
 );
 
 /// Number of `Call` events currently awaiting application replies for the
-/// given instance. Useful for diagnostics / liveness checks from Dart.
+/// given instance. Useful for diagnostics / liveness checks from Dart; see
+/// `app_metrics` for per-channel counters and latencies.
 Future<BigInt> pendingAppCallCount({required String instanceId}) =>
     RustLib.instance.api.crateApiP2PPendingAppCallCount(instanceId: instanceId);
 
@@ -290,6 +306,9 @@ class FlagsC {
   final String tcpWhitelist;
   final String udpWhitelist;
 
+  /// 用本机节点密钥启用 EasyTier 安全模式，需要先加载密钥库（见 `credential`）。
+  final bool secureMode;
+
   const FlagsC({
     required this.defaultProtocol,
     required this.devName,
@@ -319,6 +338,7 @@ class FlagsC {
     required this.disableSymHolePunching,
     required this.tcpWhitelist,
     required this.udpWhitelist,
+    required this.secureMode,
   });
 
   @override
@@ -350,7 +370,8 @@ class FlagsC {
       disableQuicInput.hashCode ^
       disableSymHolePunching.hashCode ^
       tcpWhitelist.hashCode ^
-      udpWhitelist.hashCode;
+      udpWhitelist.hashCode ^
+      secureMode.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -384,7 +405,8 @@ class FlagsC {
           disableQuicInput == other.disableQuicInput &&
           disableSymHolePunching == other.disableSymHolePunching &&
           tcpWhitelist == other.tcpWhitelist &&
-          udpWhitelist == other.udpWhitelist;
+          udpWhitelist == other.udpWhitelist &&
+          secureMode == other.secureMode;
 }
 
 class Forward {
@@ -468,6 +490,8 @@ class KVNodeInfo {
   final int peerId;
   final String hostname;
   final String ipv4;
+
+  /// 虚拟网 IPv6（含前缀长度），与 `Route.ipv6_addr` 一致；无分配时为空串。
   final String ipv6;
   final double latencyMs;
   final String nat;
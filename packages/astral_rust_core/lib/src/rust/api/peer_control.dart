@@ -0,0 +1,83 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `matches_connector`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// 列出到某个节点的所有连接，以及哪些可以从本机控制。
+Future<List<PeerConnectionC>> listPeerConnections({
+  required String instanceId,
+  required int peerId,
+}) => RustLib.instance.api.crateApiPeerControlListPeerConnections(
+  instanceId: instanceId,
+  peerId: peerId,
+);
+
+/// 关闭到某个节点的一条连接。连接对应的 connector 会被移除，不会自动重连；
+/// 需要时用 `add_peer_uri` 重新添加。
+Future<void> closePeerConnection({
+  required String instanceId,
+  required int peerId,
+  required String connId,
+}) => RustLib.instance.api.crateApiPeerControlClosePeerConnection(
+  instanceId: instanceId,
+  peerId: peerId,
+  connId: connId,
+);
+
+/// 让本机发起的到某个节点的连接全部断开重连。返回重连的连接数。
+///
+/// 对应的 connector 固定了公钥时会重启实例，所有连接都会断开重连，见模块说明。
+Future<int> reconnectPeer({
+  required String instanceId,
+  required int peerId,
+}) => RustLib.instance.api.crateApiPeerControlReconnectPeer(
+  instanceId: instanceId,
+  peerId: peerId,
+);
+
+class PeerConnectionC {
+  final String connId;
+
+  /// `tcp` / `udp` / `quic` ...
+  final String tunnelType;
+  final String remoteAddr;
+
+  /// 本机主动发起的连接。
+  final bool isClient;
+
+  /// 对应的 connector URL（配置里写的原始地址）；对端发起的连接为空串。
+  final String connectorUrl;
+
+  const PeerConnectionC({
+    required this.connId,
+    required this.tunnelType,
+    required this.remoteAddr,
+    required this.isClient,
+    required this.connectorUrl,
+  });
+
+  @override
+  int get hashCode =>
+      connId.hashCode ^
+      tunnelType.hashCode ^
+      remoteAddr.hashCode ^
+      isClient.hashCode ^
+      connectorUrl.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PeerConnectionC &&
+          runtimeType == other.runtimeType &&
+          connId == other.connId &&
+          tunnelType == other.tunnelType &&
+          remoteAddr == other.remoteAddr &&
+          isClient == other.isClient &&
+          connectorUrl == other.connectorUrl;
+}
@@ -0,0 +1,106 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'p2p.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `bind_std`, `pipe`, `prepare`, `run_tcp_forward`, `run_udp_forward`, `run_udp_session`, `spawn_forward`, `start_prepared`, `stop_all`, `take_config_forwards`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ActiveGuard`, `BoundSocket`, `FORWARDS`, `ForwardStats`, `ManagedForward`, `PreparedForward`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `default`, `deref`, `drop`, `fmt`, `initialize`
+
+/// 列出实例上的全部转发：交给 EasyTier 的在前，其余按添加顺序。
+Future<List<PortForwardC>> listPortForwards({
+  required String instanceId,
+}) => RustLib.instance.api.crateApiPortForwardListPortForwards(
+  instanceId: instanceId,
+);
+
+/// 在运行中的实例上新增一条转发。`bind_addr` 被占用时返回明确的错误。
+Future<PortForwardC> addPortForward({
+  required String instanceId,
+  required Forward forward,
+}) => RustLib.instance.api.crateApiPortForwardAddPortForward(
+  instanceId: instanceId,
+  forward: forward,
+);
+
+/// 删除一条转发，已建立的连接一并断开。返回 `false` 表示没有找到
+/// （交给 EasyTier 的转发也找不到）。
+Future<bool> removePortForward({
+  required String instanceId,
+  required String proto,
+  required String bindAddr,
+}) => RustLib.instance.api.crateApiPortForwardRemovePortForward(
+  instanceId: instanceId,
+  proto: proto,
+  bindAddr: bindAddr,
+);
+
+class PortForwardC {
+  final String proto;
+  final String bindAddr;
+  final String dstAddr;
+
+  /// `false` 表示交给 EasyTier 处理的转发（`no_tun` 实例创建时配置的），
+  /// 没有统计且不能删除。
+  final bool managed;
+  final int activeConnections;
+  final BigInt totalConnections;
+
+  /// 本机客户端 -> `dst_addr` 方向的字节数。
+  final BigInt txBytes;
+
+  /// `dst_addr` -> 本机客户端方向的字节数。
+  final BigInt rxBytes;
+
+  /// accept / 连接目标 / 收包失败的次数。
+  final BigInt errors;
+
+  /// 最近一次失败的原因，没有失败时为空串。
+  final String lastError;
+
+  const PortForwardC({
+    required this.proto,
+    required this.bindAddr,
+    required this.dstAddr,
+    required this.managed,
+    required this.activeConnections,
+    required this.totalConnections,
+    required this.txBytes,
+    required this.rxBytes,
+    required this.errors,
+    required this.lastError,
+  });
+
+  @override
+  int get hashCode =>
+      proto.hashCode ^
+      bindAddr.hashCode ^
+      dstAddr.hashCode ^
+      managed.hashCode ^
+      activeConnections.hashCode ^
+      totalConnections.hashCode ^
+      txBytes.hashCode ^
+      rxBytes.hashCode ^
+      errors.hashCode ^
+      lastError.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PortForwardC &&
+          runtimeType == other.runtimeType &&
+          proto == other.proto &&
+          bindAddr == other.bindAddr &&
+          dstAddr == other.dstAddr &&
+          managed == other.managed &&
+          activeConnections == other.activeConnections &&
+          totalConnections == other.totalConnections &&
+          txBytes == other.txBytes &&
+          rxBytes == other.rxBytes &&
+          errors == other.errors &&
+          lastError == other.lastError;
+}
@@ -0,0 +1,153 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `advertise`, `forget_instance`, `is_live`, `listener_protocol`, `local_ip_towards`, `map_natpmp`, `map_port`, `map_upnp`, `mapped_url`, `release`, `renew`, `still_current`, `take_managed`, `unmap_port`, `watch_instance`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Backend`, `EVENTS`, `INSTANCES`, `InstanceMappings`, `Mapped`, `Mapping`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `default`, `deref`, `deref`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`, `initialize`
+
+/// 为实例的监听建立端口映射。`requests` 为空时映射所有监听、外部端口与本地相同。
+/// 重复调用会先撤掉之前的映射。返回每个监听的映射结果。
+Future<List<ListenerMappingC>> enablePortMapping({
+  required String instanceId,
+  required List<PortMappingRequestC> requests,
+}) => RustLib.instance.api.crateApiPortMappingEnablePortMapping(
+  instanceId: instanceId,
+  requests: requests,
+);
+
+/// 撤掉本模块为实例建立的所有映射，并停止对外公布。EasyTier 自己的映射不受影响。
+Future<void> disablePortMapping({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiPortMappingDisablePortMapping(instanceId: instanceId);
+
+/// 当前所有映射：本模块管理的和 EasyTier 上报的。
+List<ListenerMappingC> listPortMappings({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiPortMappingListPortMappings(instanceId: instanceId);
+
+/// 映射建立、续期失败、到期、撤销时推送。
+Stream<ListenerMappingC> subscribePortMappingEvents({
+  required String instanceId,
+}) => RustLib.instance.api.crateApiPortMappingSubscribePortMappingEvents(
+  instanceId: instanceId,
+);
+
+/// 本机的监听地址和对外公布的公网入口，用于生成邀请信息、展示本机节点信息。
+Future<LocalEndpointsC> getLocalEndpoints({
+  required String instanceId,
+}) => RustLib.instance.api.crateApiPortMappingGetLocalEndpoints(
+  instanceId: instanceId,
+);
+
+class ListenerMappingC {
+  /// 本机监听 URL（已脱敏）。
+  final String localListener;
+
+  /// 公网地址，例如 `tcp://203.0.113.5:11010`；建立失败时为空串。
+  final String mappedListener;
+
+  /// `upnp` / `natpmp`，EasyTier 上报的映射为其原始 backend 名。
+  final String backend;
+  final PortMappingStateC state;
+
+  /// 由 EasyTier 建立（只读），而不是本模块管理的。
+  final bool managedByEasytier;
+
+  /// 剩余租约秒数；未知或已失效时为 0。
+  final int leaseRemainingSecs;
+  final String error;
+
+  const ListenerMappingC({
+    required this.localListener,
+    required this.mappedListener,
+    required this.backend,
+    required this.state,
+    required this.managedByEasytier,
+    required this.leaseRemainingSecs,
+    required this.error,
+  });
+
+  @override
+  int get hashCode =>
+      localListener.hashCode ^
+      mappedListener.hashCode ^
+      backend.hashCode ^
+      state.hashCode ^
+      managedByEasytier.hashCode ^
+      leaseRemainingSecs.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ListenerMappingC &&
+          runtimeType == other.runtimeType &&
+          localListener == other.localListener &&
+          mappedListener == other.mappedListener &&
+          backend == other.backend &&
+          state == other.state &&
+          managedByEasytier == other.managedByEasytier &&
+          leaseRemainingSecs == other.leaseRemainingSecs &&
+          error == other.error;
+}
+
+class LocalEndpointsC {
+  final List<String> listeners;
+
+  /// 对外公布的公网入口（端口映射建立的、配置里手动写的）。
+  final List<String> mappedListeners;
+
+  const LocalEndpointsC({
+    required this.listeners,
+    required this.mappedListeners,
+  });
+
+  @override
+  int get hashCode => listeners.hashCode ^ mappedListeners.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LocalEndpointsC &&
+          runtimeType == other.runtimeType &&
+          listeners == other.listeners &&
+          mappedListeners == other.mappedListeners;
+}
+
+/// 请求映射的监听；`external_port` 为 0 表示和本地端口相同。
+class PortMappingRequestC {
+  final String listener;
+  final int externalPort;
+
+  const PortMappingRequestC({
+    required this.listener,
+    required this.externalPort,
+  });
+
+  @override
+  int get hashCode => listener.hashCode ^ externalPort.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PortMappingRequestC &&
+          runtimeType == other.runtimeType &&
+          listener == other.listener &&
+          externalPort == other.externalPort;
+}
+
+enum PortMappingStateC {
+  active,
+
+  /// 续期失败，映射在租约到期前仍然有效。
+  failed,
+  expired,
+  removed,
+}
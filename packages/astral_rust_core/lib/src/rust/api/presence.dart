@@ -0,0 +1,158 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'p2p.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `check_profile`, `count_bad_announce`, `emit`, `emit_left`, `forget_instance`, `greet`, `handle_message`, `is_current`, `is_handled`, `local_message`, `now_ms`, `parse_profile`, `spawn_tasks`, `status_str`, `sync_with_routes`, `watch_instance`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `EVENTS`, `PRESENCE`, `Presence`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `deref`, `deref`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`, `initialize`
+
+/// Publish or update this node's profile and announce it to every peer. The
+/// first call starts the presence service for the instance; peers' profiles
+/// are only collected while it runs.
+Future<void> setPresenceProfile({
+  required String instanceId,
+  required PresenceProfileC profile,
+}) => RustLib.instance.api.crateApiPresenceSetPresenceProfile(
+  instanceId: instanceId,
+  profile: profile,
+);
+
+/// Withdraw this node's profile: peers drop it and the service stops until
+/// the next [`set_presence_profile`].
+Future<void> clearPresenceProfile({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiPresenceClearPresenceProfile(instanceId: instanceId);
+
+/// This node's published profile, if any.
+PresenceProfileC? getPresenceProfile({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiPresenceGetPresenceProfile(instanceId: instanceId);
+
+/// Presence messages dropped since the service started for the instance:
+/// malformed, sent for another peer id, or with an invalid profile.
+BigInt getPresenceBadAnnounces({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiPresenceGetPresenceBadAnnounces(instanceId: instanceId);
+
+/// `get_network_status` nodes joined with cached profiles by peer id. The
+/// local node carries this node's own profile.
+Future<List<PeerPresenceC>> listPeerPresence({
+  required String instanceId,
+}) => RustLib.instance.api.crateApiPresenceListPeerPresence(
+  instanceId: instanceId,
+);
+
+/// Profile changes and departures of peers.
+Stream<PresenceEventC> subscribePresence({
+  required String instanceId,
+}) => RustLib.instance.api.crateApiPresenceSubscribePresence(
+  instanceId: instanceId,
+);
+
+/// A node from `get_network_status` joined with its profile, if it published
+/// one.
+class PeerPresenceC {
+  final KVNodeInfo node;
+  final PresenceProfileC? profile;
+
+  const PeerPresenceC({required this.node, required this.profile});
+
+  @override
+  int get hashCode => node.hashCode ^ profile.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PeerPresenceC &&
+          runtimeType == other.runtimeType &&
+          node == other.node &&
+          profile == other.profile;
+}
+
+/// Flat struct rather than an enum with data, like `AppInboundEventC`.
+class PresenceEventC {
+  final PresenceEventKindC kind;
+  final int peerId;
+
+  /// Set for [`PresenceEventKindC::Updated`].
+  final PresenceProfileC? profile;
+
+  const PresenceEventC({
+    required this.kind,
+    required this.peerId,
+    required this.profile,
+  });
+
+  @override
+  int get hashCode => kind.hashCode ^ peerId.hashCode ^ profile.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PresenceEventC &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          peerId == other.peerId &&
+          profile == other.profile;
+}
+
+enum PresenceEventKindC {
+  updated,
+
+  /// The peer withdrew its profile or left the network.
+  left,
+}
+
+class PresenceProfileC {
+  final String displayName;
+
+  /// Hex hash of the avatar image; the image itself is fetched separately
+  /// (e.g. with `offer_file`) when the hash isn't cached.
+  final String avatarHash;
+  final PresenceStatusC status;
+  final String appName;
+  final String appVersion;
+
+  /// Empty when not in a game.
+  final String currentGame;
+
+  const PresenceProfileC({
+    required this.displayName,
+    required this.avatarHash,
+    required this.status,
+    required this.appName,
+    required this.appVersion,
+    required this.currentGame,
+  });
+
+  @override
+  int get hashCode =>
+      displayName.hashCode ^
+      avatarHash.hashCode ^
+      status.hashCode ^
+      appName.hashCode ^
+      appVersion.hashCode ^
+      currentGame.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PresenceProfileC &&
+          runtimeType == other.runtimeType &&
+          displayName == other.displayName &&
+          avatarHash == other.avatarHash &&
+          status == other.status &&
+          appName == other.appName &&
+          appVersion == other.appVersion &&
+          currentGame == other.currentGame;
+}
+
+enum PresenceStatusC { online, away, busy }
@@ -0,0 +1,151 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `local_interface_networks`, `overlaps`, `parse_advertised`, `parse_cidr`, `parse_proxy_cidr_spec`, `virtual_network`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// 本机当前导出的子网。
+Future<List<ProxyCidrC>> listProxyCidrs({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiProxyCidrListProxyCidrs(instanceId: instanceId);
+
+/// 运行中新增导出子网。`mapped_cidr` 为空串表示不做地址映射。
+///
+/// 冲突不会阻止添加（例如和其他节点做主备导出是合法用法），检测结果原样返回给调用方展示。
+Future<List<ProxyCidrConflictC>> addProxyCidr({
+  required String instanceId,
+  required String cidr,
+  required String mappedCidr,
+}) => RustLib.instance.api.crateApiProxyCidrAddProxyCidr(
+  instanceId: instanceId,
+  cidr: cidr,
+  mappedCidr: mappedCidr,
+);
+
+/// 运行中移除导出子网。返回 `false` 表示本机没有导出该网段。
+Future<bool> removeProxyCidr({
+  required String instanceId,
+  required String cidr,
+}) => RustLib.instance.api.crateApiProxyCidrRemoveProxyCidr(
+  instanceId: instanceId,
+  cidr: cidr,
+);
+
+/// 每个节点导出了哪些子网，不含本机（本机用 [`list_proxy_cidrs`]）。
+Future<List<PeerProxyCidrsC>> listPeerProxyCidrs({
+  required String instanceId,
+}) => RustLib.instance.api.crateApiProxyCidrListPeerProxyCidrs(
+  instanceId: instanceId,
+);
+
+/// 对本机导出、对端导出、虚拟网网段和本机网卡做一次全量冲突检测。
+Future<List<ProxyCidrConflictC>> checkProxyCidrConflicts({
+  required String instanceId,
+}) => RustLib.instance.api.crateApiProxyCidrCheckProxyCidrConflicts(
+  instanceId: instanceId,
+);
+
+/// 某个节点导出的子网，来自路由表的 `Route.proxy_cidrs`。
+class PeerProxyCidrsC {
+  final int peerId;
+  final String hostname;
+  final List<String> cidrs;
+
+  const PeerProxyCidrsC({
+    required this.peerId,
+    required this.hostname,
+    required this.cidrs,
+  });
+
+  @override
+  int get hashCode => peerId.hashCode ^ hostname.hashCode ^ cidrs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PeerProxyCidrsC &&
+          runtimeType == other.runtimeType &&
+          peerId == other.peerId &&
+          hostname == other.hostname &&
+          cidrs == other.cidrs;
+}
+
+class ProxyCidrC {
+  final String cidr;
+
+  /// 对外公布的映射网段；没有映射时为空串。
+  final String mappedCidr;
+
+  const ProxyCidrC({required this.cidr, required this.mappedCidr});
+
+  @override
+  int get hashCode => cidr.hashCode ^ mappedCidr.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProxyCidrC &&
+          runtimeType == other.runtimeType &&
+          cidr == other.cidr &&
+          mappedCidr == other.mappedCidr;
+}
+
+class ProxyCidrConflictC {
+  final ProxyCidrConflictKindC kind;
+  final String cidr;
+  final String otherCidr;
+
+  /// `PeerExport` / `LocalInterface` 时为导出该网段的节点，否则为 0。
+  final int peerId;
+  final String hostname;
+
+  /// `LocalInterface` 时为本机网卡名，否则为空串。
+  final String interface;
+
+  const ProxyCidrConflictC({
+    required this.kind,
+    required this.cidr,
+    required this.otherCidr,
+    required this.peerId,
+    required this.hostname,
+    required this.interface,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      cidr.hashCode ^
+      otherCidr.hashCode ^
+      peerId.hashCode ^
+      hostname.hashCode ^
+      interface.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProxyCidrConflictC &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          cidr == other.cidr &&
+          otherCidr == other.otherCidr &&
+          peerId == other.peerId &&
+          hostname == other.hostname &&
+          interface == other.interface;
+}
+
+enum ProxyCidrConflictKindC {
+  /// 和虚拟网网段重叠。
+  virtualNetwork,
+
+  /// 和其他节点导出的网段重叠。
+  peerExport,
+
+  /// 对端导出的网段和本机网卡网段重叠。
+  localInterface,
+}
@@ -0,0 +1,18 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `is_sensitive_query_key`, `public_key`, `secret`, `url`
+
+/// 调试开关：打开后日志 / 事件里输出完整的密码、公钥和凭据，默认关闭。
+void setUnredactedDebugLogging({required bool enabled}) => RustLib
+    .instance
+    .api
+    .crateApiRedactSetUnredactedDebugLogging(enabled: enabled);
+
+bool isUnredactedDebugLogging() =>
+    RustLib.instance.api.crateApiRedactIsUnredactedDebugLogging();
@@ -0,0 +1,73 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `handshake`, `probe_server`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// 并发探测一组服务器，结果顺序与 `urls` 一致。`timeout_ms` 是单个服务器的总超时。
+Future<List<ServerProbeResultC>> probeServers({
+  required List<String> urls,
+  required int timeoutMs,
+}) => RustLib.instance.api.crateApiServerProbeProbeServers(
+  urls: urls,
+  timeoutMs: timeoutMs,
+);
+
+class ServerProbeResultC {
+  /// 传入的 URL（已脱敏）。
+  final String url;
+  final String scheme;
+
+  /// 该协议能否在不加入网络的情况下探测。
+  final bool protocolSupported;
+
+  /// 实际握手的地址；解析失败时为空串。
+  final String resolvedAddr;
+  final int dnsMs;
+  final bool reachable;
+
+  /// 隧道握手耗时，不含 DNS；不可达时为 0。
+  final int latencyMs;
+  final String error;
+
+  const ServerProbeResultC({
+    required this.url,
+    required this.scheme,
+    required this.protocolSupported,
+    required this.resolvedAddr,
+    required this.dnsMs,
+    required this.reachable,
+    required this.latencyMs,
+    required this.error,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      scheme.hashCode ^
+      protocolSupported.hashCode ^
+      resolvedAddr.hashCode ^
+      dnsMs.hashCode ^
+      reachable.hashCode ^
+      latencyMs.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ServerProbeResultC &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          scheme == other.scheme &&
+          protocolSupported == other.protocolSupported &&
+          resolvedAddr == other.resolvedAddr &&
+          dnsMs == other.dnsMs &&
+          reachable == other.reachable &&
+          latencyMs == other.latencyMs &&
+          error == other.error;
+}
@@ -0,0 +1,102 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `forget_instance`, `is_connected`, `probe_all`, `resolves_to_connected`, `selection_status`, `supervise`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SELECTIONS`, `Selection`, `ServerEntry`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `deref`, `eq`, `fmt`, `fmt`, `fmt`, `initialize`
+
+/// 对实例启用服务器选择：只连延迟最低的 `active_count` 个服务器，其余备用。
+/// 重复调用会按新的数量重新测速选择。
+Future<ServerSelectionStatusC> enableServerSelection({
+  required String instanceId,
+  required int activeCount,
+}) => RustLib.instance.api.crateApiServerSelectionEnableServerSelection(
+  instanceId: instanceId,
+  activeCount: activeCount,
+);
+
+/// 停用服务器选择，恢复连接所有服务器。有固定公钥的 standby 服务器时会重启实例，
+/// 见模块说明。
+Future<void> disableServerSelection({required String instanceId}) => RustLib
+    .instance
+    .api
+    .crateApiServerSelectionDisableServerSelection(instanceId: instanceId);
+
+/// 每个服务器当前是 active 还是 standby，以及原因。未启用选择时所有服务器都是 active。
+Future<ServerSelectionStatusC> getServerSelectionStatus({
+  required String instanceId,
+}) => RustLib.instance.api.crateApiServerSelectionGetServerSelectionStatus(
+  instanceId: instanceId,
+);
+
+class SelectedServerC {
+  /// 服务器 URL（已脱敏）。
+  final String url;
+  final ServerRoleC role;
+
+  /// 最近一次测速结果；不可达时为 0。
+  final int latencyMs;
+  final bool connected;
+
+  /// 为什么是当前角色，例如 "lowest latency (23 ms)"、"failed over from ..."。
+  final String reason;
+
+  const SelectedServerC({
+    required this.url,
+    required this.role,
+    required this.latencyMs,
+    required this.connected,
+    required this.reason,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      role.hashCode ^
+      latencyMs.hashCode ^
+      connected.hashCode ^
+      reason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SelectedServerC &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          role == other.role &&
+          latencyMs == other.latencyMs &&
+          connected == other.connected &&
+          reason == other.reason;
+}
+
+enum ServerRoleC { active, standby }
+
+class ServerSelectionStatusC {
+  final bool enabled;
+  final int activeCount;
+  final List<SelectedServerC> servers;
+
+  const ServerSelectionStatusC({
+    required this.enabled,
+    required this.activeCount,
+    required this.servers,
+  });
+
+  @override
+  int get hashCode =>
+      enabled.hashCode ^ activeCount.hashCode ^ servers.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ServerSelectionStatusC &&
+          runtimeType == other.runtimeType &&
+          enabled == other.enabled &&
+          activeCount == other.activeCount &&
+          servers == other.servers;
+}
@@ -3,8 +3,30 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/app_broadcast.dart';
+import 'api/app_call_control.dart';
+import 'api/app_codec.dart';
+import 'api/app_metrics.dart';
+import 'api/app_policy.dart';
+import 'api/app_router.dart';
+import 'api/app_stream.dart';
+import 'api/credential.dart';
+import 'api/diagnostics.dart';
+import 'api/endpoints.dart';
+import 'api/file_transfer.dart';
 import 'api/firewall.dart';
+import 'api/local_channel.dart';
+import 'api/messaging.dart';
+import 'api/nat.dart';
 import 'api/p2p.dart';
+import 'api/peer_control.dart';
+import 'api/port_forward.dart';
+import 'api/port_mapping.dart';
+import 'api/presence.dart';
+import 'api/proxy_cidr.dart';
+import 'api/redact.dart';
+import 'api/server_probe.dart';
+import 'api/server_selection.dart';
 import 'api/simple.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -68,7 +90,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.12.0';
 
   @override
-  int get rustContentHash => -2008553100;
+  int get rustContentHash => -1022946600;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -80,6 +102,49 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<String> crateApiFileTransferAcceptFile({
+    required String instanceId,
+    required String transferId,
+    required String dir,
+  });
+
+  Future<EndpointResultC> crateApiEndpointsAddListener({
+    required String instanceId,
+    required String url,
+  });
+
+  Future<EndpointResultC> crateApiEndpointsAddPeerUri({
+    required String instanceId,
+    required String uri,
+  });
+
+  Future<PortForwardC> crateApiPortForwardAddPortForward({
+    required String instanceId,
+    required Forward forward,
+  });
+
+  Future<List<ProxyCidrConflictC>> crateApiProxyCidrAddProxyCidr({
+    required String instanceId,
+    required String cidr,
+    required String mappedCidr,
+  });
+
+  Future<List<FirewallRuleC>> crateApiFirewallAllowInstanceThroughFirewall({
+    required String instanceId,
+  });
+
+  Future<void> crateApiFirewallAllowTunThroughFirewall({
+    required String devName,
+  });
+
+  Future<List<BroadcastDeliveryC>> crateApiAppBroadcastAppBroadcast({
+    required String instanceId,
+    required String channel,
+    required List<int> payload,
+    required BroadcastScopeC scope,
+    required int timeoutMs,
+  });
+
   Future<AppCallResultC> crateApiP2PAppCall({
     required String instanceId,
     required int dstPeerId,
@@ -90,6 +155,23 @@ abstract class RustLibApi extends BaseApi {
     required int timeoutMs,
   });
 
+  BigInt crateApiAppCallControlAppCallDeadline({
+    required String instanceId,
+    required BigInt token,
+  });
+
+  Future<AppCallDecodedC> crateApiAppCodecAppCallEncoded({
+    required String instanceId,
+    required int dstPeerId,
+    required String channel,
+    required BigInt requestId,
+    required List<int> payload,
+    required PayloadCodecC codec,
+    required PayloadCodecC replyCodec,
+    required int flags,
+    required int timeoutMs,
+  });
+
   Future<bool> crateApiP2PAppCallReply({
     required String instanceId,
     required BigInt token,
@@ -98,6 +180,26 @@ abstract class RustLibApi extends BaseApi {
     required List<int> payload,
   });
 
+  Future<bool> crateApiAppCodecAppCallReplyEncoded({
+    required String instanceId,
+    required BigInt token,
+    required int status,
+    required String errorMsg,
+    required List<int> payload,
+    required PayloadCodecC codec,
+    required List<PayloadCodecC> peerAccepts,
+  });
+
+  Future<AppCallResultC> crateApiAppCallControlAppCallWithDeadline({
+    required String instanceId,
+    required int dstPeerId,
+    required String channel,
+    required BigInt requestId,
+    required List<int> payload,
+    required int flags,
+    required int timeoutMs,
+  });
+
   Future<void> crateApiP2PAppNotify({
     required String instanceId,
     required int dstPeerId,
@@ -106,6 +208,78 @@ abstract class RustLibApi extends BaseApi {
     required int timeoutMs,
   });
 
+  Future<void> crateApiAppCodecAppNotifyEncoded({
+    required String instanceId,
+    required int dstPeerId,
+    required String channel,
+    required List<int> payload,
+    required PayloadCodecC codec,
+    required int timeoutMs,
+  });
+
+  String crateApiAppMetricsAppRpcMetricsOpenmetrics({
+    required String instanceId,
+  });
+
+  Future<void> crateApiAppStreamAppStreamCancel({
+    required String instanceId,
+    required int peerId,
+    required BigInt streamId,
+  });
+
+  Future<void> crateApiAppStreamAppStreamFinish({
+    required String instanceId,
+    required BigInt streamId,
+  });
+
+  Future<BigInt> crateApiAppStreamAppStreamOpen({
+    required String instanceId,
+    required int dstPeerId,
+    required String channel,
+    required BigInt totalLen,
+    required List<int> metadata,
+    required int timeoutMs,
+  });
+
+  Stream<Uint8List> crateApiAppStreamAppStreamRead({
+    required String instanceId,
+    required int fromPeerId,
+    required BigInt streamId,
+  });
+
+  Future<void> crateApiAppStreamAppStreamWrite({
+    required String instanceId,
+    required BigInt streamId,
+    required List<int> data,
+  });
+
+  Future<bool> crateApiAppCallControlCancelAppCall({
+    required String instanceId,
+    required int dstPeerId,
+    required BigInt requestId,
+  });
+
+  Future<void> crateApiFileTransferCancelFileTransfer({
+    required String instanceId,
+    required String transferId,
+  });
+
+  Future<List<ProxyCidrConflictC>> crateApiProxyCidrCheckProxyCidrConflicts({
+    required String instanceId,
+  });
+
+  void crateApiAppPolicyClearAppRpcPolicy({required String instanceId});
+
+  Future<void> crateApiPresenceClearPresenceProfile({
+    required String instanceId,
+  });
+
+  Future<void> crateApiPeerControlClosePeerConnection({
+    required String instanceId,
+    required int peerId,
+    required String connId,
+  });
+
   Future<void> crateApiP2PCloseServer({required String instanceId});
 
   Future<JoinHandleResultStringString> crateApiP2PCreateServer({
@@ -126,12 +300,63 @@ abstract class RustLibApi extends BaseApi {
     required FlagsC flag,
   });
 
+  DecodedPayloadC crateApiAppCodecDecodeAppPayload({
+    required List<int> payload,
+    required PayloadCodecC want,
+  });
+
+  Future<NatDetectionC> crateApiNatDetectNat({
+    required List<String> stunServers,
+  });
+
+  Future<FirewallDiagnosisC> crateApiFirewallDiagnoseFirewall({
+    required String instanceId,
+  });
+
+  Future<void> crateApiPortMappingDisablePortMapping({
+    required String instanceId,
+  });
+
+  Future<void> crateApiServerSelectionDisableServerSelection({
+    required String instanceId,
+  });
+
   Future<String> crateApiP2PEasytierVersion();
 
+  Future<List<ListenerMappingC>> crateApiPortMappingEnablePortMapping({
+    required String instanceId,
+    required List<PortMappingRequestC> requests,
+  });
+
+  Future<ServerSelectionStatusC> crateApiServerSelectionEnableServerSelection({
+    required String instanceId,
+    required int activeCount,
+  });
+
+  AppRpcMetricsC crateApiAppMetricsGetAppRpcMetrics({
+    required String instanceId,
+  });
+
+  AppRpcPolicyC? crateApiAppPolicyGetAppRpcPolicy({required String instanceId});
+
+  AppRpcRejectionsC crateApiAppPolicyGetAppRpcRejections({
+    required String instanceId,
+  });
+
+  Future<bool> crateApiFirewallGetFirewallProfileStatus({
+    required FirewallProfile profile,
+  });
+
+  Future<FirewallSnapshotC> crateApiFirewallGetFirewallSnapshot();
+
   Future<bool> crateApiFirewallGetFirewallStatus({required int profileIndex});
 
   Future<List<String>> crateApiP2PGetIps({required String instanceId});
 
+  Future<LocalEndpointsC> crateApiPortMappingGetLocalEndpoints({
+    required String instanceId,
+  });
+
   Future<KVNetworkStatus> crateApiP2PGetNetworkStatus({
     required String instanceId,
   });
@@ -140,8 +365,22 @@ abstract class RustLibApi extends BaseApi {
     required String instanceId,
   });
 
+  BigInt crateApiPresenceGetPresenceBadAnnounces({required String instanceId});
+
+  PresenceProfileC? crateApiPresenceGetPresenceProfile({
+    required String instanceId,
+  });
+
   Future<String> crateApiP2PGetRunningInfo({required String instanceId});
 
+  Future<ServerSelectionStatusC>
+  crateApiServerSelectionGetServerSelectionStatus({required String instanceId});
+
+  Future<TunFirewallStatusC> crateApiFirewallGetTunFirewallStatus({
+    required String devName,
+    required List<FirewallPortC> ports,
+  });
+
   String crateApiSimpleGreet({required String name});
 
   Future<JoinHandle> crateApiP2PHandleEvent({
@@ -152,14 +391,83 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiSimpleInitApp();
 
+  bool crateApiAppCallControlIsAppCallCancelled({
+    required String instanceId,
+    required BigInt token,
+  });
+
   Future<bool> crateApiP2PIsEasytierRunning({required String instanceId});
 
+  bool crateApiRedactIsUnredactedDebugLogging();
+
   Future<String> crateApiP2PJoinHandleResult({
     required JoinHandleResultStringString handle,
   });
 
+  List<String> crateApiAppRouterListAppChannels({required String instanceId});
+
+  List<FileOfferC> crateApiFileTransferListFileOffers({
+    required String instanceId,
+  });
+
+  List<ChatMessageC> crateApiMessagingListMessages({
+    required String instanceId,
+    required int limit,
+  });
+
+  Future<List<PeerConnectionC>> crateApiPeerControlListPeerConnections({
+    required String instanceId,
+    required int peerId,
+  });
+
+  Future<List<PeerPresenceC>> crateApiPresenceListPeerPresence({
+    required String instanceId,
+  });
+
+  Future<List<PeerProxyCidrsC>> crateApiProxyCidrListPeerProxyCidrs({
+    required String instanceId,
+  });
+
+  List<PinnedServerKeyC> crateApiCredentialListPinnedServerKeys();
+
+  Future<List<PortForwardC>> crateApiPortForwardListPortForwards({
+    required String instanceId,
+  });
+
+  List<ListenerMappingC> crateApiPortMappingListPortMappings({
+    required String instanceId,
+  });
+
+  Future<List<ProxyCidrC>> crateApiProxyCidrListProxyCidrs({
+    required String instanceId,
+  });
+
+  Future<NodeKeyInfoC> crateApiCredentialLoadOrGenerateNodeKeypair({
+    required String storeDir,
+  });
+
+  String crateApiLocalChannelLocalEventSession();
+
+  NodeKeyInfoC? crateApiCredentialLocalPublicKey();
+
+  Future<void> crateApiMessagingMarkMessagesRead({
+    required String instanceId,
+    required List<String> messageIds,
+  });
+
   Future<int> crateApiP2PMyPeerId({required String instanceId});
 
+  NatCompatibilityC crateApiNatNatCompatibility({
+    required NatTypeC local,
+    required NatTypeC peer,
+  });
+
+  Future<String> crateApiFileTransferOfferFile({
+    required String instanceId,
+    required int dstPeerId,
+    required String path,
+  });
+
   Future<PlatformInt64> crateApiP2PPeerPing({
     required String instanceId,
     required int dstPeerId,
@@ -168,87 +476,239 @@ abstract class RustLibApi extends BaseApi {
 
   Future<BigInt> crateApiP2PPendingAppCallCount({required String instanceId});
 
-  Future<void> crateApiP2PSendUdpToLocalhost({required String message});
+  Future<void> crateApiCredentialPinServerPublicKey({
+    required String serverUrl,
+    required String publicKey,
+  });
 
-  Future<void> crateApiFirewallSetFirewallStatus({
-    required int profileIndex,
-    required bool enable,
+  Future<List<ServerProbeResultC>> crateApiServerProbeProbeServers({
+    required List<String> urls,
+    required int timeoutMs,
   });
 
-  Future<void> crateApiP2PSetTunFd({
+  String crateApiCredentialPublicKeyFingerprint({required String publicKey});
+
+  Future<int> crateApiPeerControlReconnectPeer({
     required String instanceId,
-    required int fd,
+    required int peerId,
   });
 
-  Stream<AppInboundEventC> crateApiP2PSubscribeAppInbound({
-    required String instanceId,
+  Future<NodeKeyInfoC> crateApiCredentialRegenerateNodeKeypair({
+    required String storeDir,
   });
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_EventBusSubscriber;
+  Future<void> crateApiAppRouterRegisterAppChannels({
+    required String instanceId,
+    required List<String> channels,
+  });
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_EventBusSubscriber;
+  Future<void> crateApiFileTransferRejectFile({
+    required String instanceId,
+    required String transferId,
+    required String reason,
+  });
 
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_EventBusSubscriberPtr;
+  Future<void> crateApiFirewallRemoveInstanceFirewallRules({
+    required String instanceId,
+  });
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_JoinHandle;
+  Future<EndpointResultC> crateApiEndpointsRemoveListener({
+    required String instanceId,
+    required String url,
+  });
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_JoinHandle;
+  Future<EndpointResultC> crateApiEndpointsRemovePeerUri({
+    required String instanceId,
+    required String uri,
+  });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_JoinHandlePtr;
+  Future<bool> crateApiPortForwardRemovePortForward({
+    required String instanceId,
+    required String proto,
+    required String bindAddr,
+  });
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_JoinHandleResultStringString;
+  Future<bool> crateApiProxyCidrRemoveProxyCidr({
+    required String instanceId,
+    required String cidr,
+  });
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_JoinHandleResultStringString;
+  Future<void> crateApiFirewallRemoveTunFirewallRule({required String devName});
 
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_JoinHandleResultStringStringPtr;
+  void crateApiAppMetricsResetAppRpcMetrics({required String instanceId});
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_PeerRoutePair;
+  void crateApiAppPolicyResetAppRpcRejections({required String instanceId});
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_PeerRoutePair;
+  Future<DiagnosticReportC> crateApiDiagnosticsRunDiagnostics({
+    required String instanceId,
+  });
 
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_PeerRoutePairPtr;
-}
+  Future<ChatMessageC> crateApiMessagingSendDirectMessage({
+    required String instanceId,
+    required int peerId,
+    required String text,
+  });
 
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
+  Future<ChatMessageC> crateApiMessagingSendRoomMessage({
+    required String instanceId,
+    required String text,
   });
 
-  @override
-  Future<AppCallResultC> crateApiP2PAppCall({
+  Future<void> crateApiP2PSendUdpToLocalhost({required String message});
+
+  void crateApiAppPolicySetAppRpcPolicy({
     required String instanceId,
-    required int dstPeerId,
-    required String channel,
-    required BigInt requestId,
-    required List<int> payload,
-    required int flags,
-    required int timeoutMs,
-  }) {
+    required AppRpcPolicyC policy,
+  });
+
+  Future<void> crateApiFirewallSetFirewallProfileStatus({
+    required FirewallProfile profile,
+    required bool enable,
+  });
+
+  Future<void> crateApiFirewallSetFirewallStatus({
+    required int profileIndex,
+    required bool enable,
+  });
+
+  void crateApiLocalChannelSetLocalEventPort({required int port});
+
+  Future<void> crateApiPresenceSetPresenceProfile({
+    required String instanceId,
+    required PresenceProfileC profile,
+  });
+
+  Future<void> crateApiP2PSetTunFd({
+    required String instanceId,
+    required int fd,
+  });
+
+  void crateApiRedactSetUnredactedDebugLogging({required bool enabled});
+
+  Future<void> crateApiMessagingStartMessaging({
+    required String instanceId,
+    required String historyDir,
+  });
+
+  Stream<AppCallControlC> crateApiAppCallControlSubscribeAppCallControl({
+    required String instanceId,
+  });
+
+  Stream<AppInboundEventC> crateApiAppRouterSubscribeAppChannels({
+    required String instanceId,
+    required List<String> channels,
+  });
+
+  Stream<AppInboundEventC> crateApiP2PSubscribeAppInbound({
+    required String instanceId,
+  });
+
+  Stream<AppStreamProgressC> crateApiAppStreamSubscribeAppStreamProgress({
+    required String instanceId,
+    required int peerId,
+    required BigInt streamId,
+  });
+
+  Stream<InboundAppStreamC> crateApiAppStreamSubscribeAppStreams({
+    required String instanceId,
+    required String channel,
+  });
+
+  Stream<CredentialEventC> crateApiCredentialSubscribeCredentialEvents({
+    required String instanceId,
+  });
+
+  Stream<FileOfferC> crateApiFileTransferSubscribeFileOffers({
+    required String instanceId,
+  });
+
+  Stream<FileTransferProgressC>
+  crateApiFileTransferSubscribeFileTransferProgress({
+    required String instanceId,
+  });
+
+  Stream<MessageEventC> crateApiMessagingSubscribeMessages({
+    required String instanceId,
+  });
+
+  Stream<ListenerMappingC> crateApiPortMappingSubscribePortMappingEvents({
+    required String instanceId,
+  });
+
+  Stream<PresenceEventC> crateApiPresenceSubscribePresence({
+    required String instanceId,
+  });
+
+  Future<bool> crateApiCredentialUnpinServerPublicKey({
+    required String serverUrl,
+  });
+
+  Future<void> crateApiAppRouterUnregisterAppChannels({
+    required String instanceId,
+    required List<String> channels,
+  });
+
+  LocalEventC? crateApiLocalChannelVerifyLocalEvent({
+    required List<int> datagram,
+  });
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_EventBusSubscriber;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_EventBusSubscriber;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_EventBusSubscriberPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_JoinHandle;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_JoinHandle;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_JoinHandlePtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_JoinHandleResultStringString;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_JoinHandleResultStringString;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_JoinHandleResultStringStringPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_PeerRoutePair;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_PeerRoutePair;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_PeerRoutePairPtr;
+}
+
+class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+  RustLibApiImpl({
+    required super.handler,
+    required super.wire,
+    required super.generalizedFrbRustBinding,
+    required super.portManager,
+  });
+
+  @override
+  Future<String> crateApiFileTransferAcceptFile({
+    required String instanceId,
+    required String transferId,
+    required String dir,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(instanceId, serializer);
-          sse_encode_u_32(dstPeerId, serializer);
-          sse_encode_String(channel, serializer);
-          sse_encode_u_64(requestId, serializer);
-          sse_encode_list_prim_u_8_loose(payload, serializer);
-          sse_encode_u_32(flags, serializer);
-          sse_encode_i_32(timeoutMs, serializer);
+          sse_encode_String(transferId, serializer);
+          sse_encode_String(dir, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -257,54 +717,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_app_call_result_c,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PAppCallConstMeta,
-        argValues: [
-          instanceId,
-          dstPeerId,
-          channel,
-          requestId,
-          payload,
-          flags,
-          timeoutMs,
-        ],
+        constMeta: kCrateApiFileTransferAcceptFileConstMeta,
+        argValues: [instanceId, transferId, dir],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PAppCallConstMeta => const TaskConstMeta(
-    debugName: "app_call",
-    argNames: [
-      "instanceId",
-      "dstPeerId",
-      "channel",
-      "requestId",
-      "payload",
-      "flags",
-      "timeoutMs",
-    ],
-  );
+  TaskConstMeta get kCrateApiFileTransferAcceptFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "accept_file",
+        argNames: ["instanceId", "transferId", "dir"],
+      );
 
   @override
-  Future<bool> crateApiP2PAppCallReply({
+  Future<EndpointResultC> crateApiEndpointsAddListener({
     required String instanceId,
-    required BigInt token,
-    required int status,
-    required String errorMsg,
-    required List<int> payload,
+    required String url,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(instanceId, serializer);
-          sse_encode_u_64(token, serializer);
-          sse_encode_i_32(status, serializer);
-          sse_encode_String(errorMsg, serializer);
-          sse_encode_list_prim_u_8_loose(payload, serializer);
+          sse_encode_String(url, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -313,38 +752,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_endpoint_result_c,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PAppCallReplyConstMeta,
-        argValues: [instanceId, token, status, errorMsg, payload],
+        constMeta: kCrateApiEndpointsAddListenerConstMeta,
+        argValues: [instanceId, url],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PAppCallReplyConstMeta => const TaskConstMeta(
-    debugName: "app_call_reply",
-    argNames: ["instanceId", "token", "status", "errorMsg", "payload"],
-  );
+  TaskConstMeta get kCrateApiEndpointsAddListenerConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_listener",
+        argNames: ["instanceId", "url"],
+      );
 
   @override
-  Future<void> crateApiP2PAppNotify({
+  Future<EndpointResultC> crateApiEndpointsAddPeerUri({
     required String instanceId,
-    required int dstPeerId,
-    required String channel,
-    required List<int> payload,
-    required int timeoutMs,
+    required String uri,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(instanceId, serializer);
-          sse_encode_u_32(dstPeerId, serializer);
-          sse_encode_String(channel, serializer);
-          sse_encode_list_prim_u_8_loose(payload, serializer);
-          sse_encode_i_32(timeoutMs, serializer);
+          sse_encode_String(uri, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -353,28 +787,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_endpoint_result_c,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PAppNotifyConstMeta,
-        argValues: [instanceId, dstPeerId, channel, payload, timeoutMs],
+        constMeta: kCrateApiEndpointsAddPeerUriConstMeta,
+        argValues: [instanceId, uri],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PAppNotifyConstMeta => const TaskConstMeta(
-    debugName: "app_notify",
-    argNames: ["instanceId", "dstPeerId", "channel", "payload", "timeoutMs"],
-  );
+  TaskConstMeta get kCrateApiEndpointsAddPeerUriConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_peer_uri",
+        argNames: ["instanceId", "uri"],
+      );
 
   @override
-  Future<void> crateApiP2PCloseServer({required String instanceId}) {
+  Future<PortForwardC> crateApiPortForwardAddPortForward({
+    required String instanceId,
+    required Forward forward,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(instanceId, serializer);
+          sse_encode_box_autoadd_forward(forward, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -383,30 +822,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_port_forward_c,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PCloseServerConstMeta,
-        argValues: [instanceId],
+        constMeta: kCrateApiPortForwardAddPortForwardConstMeta,
+        argValues: [instanceId, forward],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PCloseServerConstMeta =>
-      const TaskConstMeta(debugName: "close_server", argNames: ["instanceId"]);
+  TaskConstMeta get kCrateApiPortForwardAddPortForwardConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_port_forward",
+        argNames: ["instanceId", "forward"],
+      );
 
   @override
-  Future<JoinHandleResultStringString> crateApiP2PCreateServer({
-    required String configToml,
-    required bool watchEvent,
+  Future<List<ProxyCidrConflictC>> crateApiProxyCidrAddProxyCidr({
+    required String instanceId,
+    required String cidr,
+    required String mappedCidr,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(configToml, serializer);
-          sse_encode_bool(watchEvent, serializer);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_String(cidr, serializer);
+          sse_encode_String(mappedCidr, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -415,49 +859,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_proxy_cidr_conflict_c,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PCreateServerConstMeta,
-        argValues: [configToml, watchEvent],
+        constMeta: kCrateApiProxyCidrAddProxyCidrConstMeta,
+        argValues: [instanceId, cidr, mappedCidr],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PCreateServerConstMeta => const TaskConstMeta(
-    debugName: "create_server",
-    argNames: ["configToml", "watchEvent"],
-  );
+  TaskConstMeta get kCrateApiProxyCidrAddProxyCidrConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_proxy_cidr",
+        argNames: ["instanceId", "cidr", "mappedCidr"],
+      );
 
   @override
-  Future<JoinHandleResultStringString> crateApiP2PCreateServerWithFlags({
-    required String username,
-    required bool enableDhcp,
-    required String specifiedIp,
-    required String roomName,
-    required String roomPassword,
-    required List<String> severurl,
-    required List<String> onurl,
-    required List<String> cidrs,
-    required List<Forward> forwards,
-    required FlagsC flag,
+  Future<List<FirewallRuleC>> crateApiFirewallAllowInstanceThroughFirewall({
+    required String instanceId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(username, serializer);
-          sse_encode_bool(enableDhcp, serializer);
-          sse_encode_String(specifiedIp, serializer);
-          sse_encode_String(roomName, serializer);
-          sse_encode_String(roomPassword, serializer);
-          sse_encode_list_String(severurl, serializer);
-          sse_encode_list_String(onurl, serializer);
-          sse_encode_list_String(cidrs, serializer);
-          sse_encode_list_forward(forwards, serializer);
-          sse_encode_box_autoadd_flags_c(flag, serializer);
+          sse_encode_String(instanceId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -466,51 +892,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_firewall_rule_c,
+          decodeErrorData: sse_decode_firewall_error,
         ),
-        constMeta: kCrateApiP2PCreateServerWithFlagsConstMeta,
-        argValues: [
-          username,
-          enableDhcp,
-          specifiedIp,
-          roomName,
-          roomPassword,
-          severurl,
-          onurl,
-          cidrs,
-          forwards,
-          flag,
-        ],
+        constMeta: kCrateApiFirewallAllowInstanceThroughFirewallConstMeta,
+        argValues: [instanceId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PCreateServerWithFlagsConstMeta =>
+  TaskConstMeta get kCrateApiFirewallAllowInstanceThroughFirewallConstMeta =>
       const TaskConstMeta(
-        debugName: "create_server_with_flags",
-        argNames: [
-          "username",
-          "enableDhcp",
-          "specifiedIp",
-          "roomName",
-          "roomPassword",
-          "severurl",
-          "onurl",
-          "cidrs",
-          "forwards",
-          "flag",
-        ],
+        debugName: "allow_instance_through_firewall",
+        argNames: ["instanceId"],
       );
 
   @override
-  Future<String> crateApiP2PEasytierVersion() {
+  Future<void> crateApiFirewallAllowTunThroughFirewall({
+    required String devName,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(devName, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -519,26 +925,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_firewall_error,
         ),
-        constMeta: kCrateApiP2PEasytierVersionConstMeta,
-        argValues: [],
+        constMeta: kCrateApiFirewallAllowTunThroughFirewallConstMeta,
+        argValues: [devName],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PEasytierVersionConstMeta =>
-      const TaskConstMeta(debugName: "easytier_version", argNames: []);
+  TaskConstMeta get kCrateApiFirewallAllowTunThroughFirewallConstMeta =>
+      const TaskConstMeta(
+        debugName: "allow_tun_through_firewall",
+        argNames: ["devName"],
+      );
 
   @override
-  Future<bool> crateApiFirewallGetFirewallStatus({required int profileIndex}) {
+  Future<List<BroadcastDeliveryC>> crateApiAppBroadcastAppBroadcast({
+    required String instanceId,
+    required String channel,
+    required List<int> payload,
+    required BroadcastScopeC scope,
+    required int timeoutMs,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(profileIndex, serializer);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_String(channel, serializer);
+          sse_encode_list_prim_u_8_loose(payload, serializer);
+          sse_encode_box_autoadd_broadcast_scope_c(scope, serializer);
+          sse_encode_i_32(timeoutMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -547,29 +966,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_list_broadcast_delivery_c,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiFirewallGetFirewallStatusConstMeta,
-        argValues: [profileIndex],
+        constMeta: kCrateApiAppBroadcastAppBroadcastConstMeta,
+        argValues: [instanceId, channel, payload, scope, timeoutMs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiFirewallGetFirewallStatusConstMeta =>
+  TaskConstMeta get kCrateApiAppBroadcastAppBroadcastConstMeta =>
       const TaskConstMeta(
-        debugName: "get_firewall_status",
-        argNames: ["profileIndex"],
+        debugName: "app_broadcast",
+        argNames: ["instanceId", "channel", "payload", "scope", "timeoutMs"],
       );
 
   @override
-  Future<List<String>> crateApiP2PGetIps({required String instanceId}) {
+  Future<AppCallResultC> crateApiP2PAppCall({
+    required String instanceId,
+    required int dstPeerId,
+    required String channel,
+    required BigInt requestId,
+    required List<int> payload,
+    required int flags,
+    required int timeoutMs,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(instanceId, serializer);
+          sse_encode_u_32(dstPeerId, serializer);
+          sse_encode_String(channel, serializer);
+          sse_encode_u_64(requestId, serializer);
+          sse_encode_list_prim_u_8_loose(payload, serializer);
+          sse_encode_u_32(flags, serializer);
+          sse_encode_i_32(timeoutMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -578,61 +1011,92 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_app_call_result_c,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PGetIpsConstMeta,
-        argValues: [instanceId],
+        constMeta: kCrateApiP2PAppCallConstMeta,
+        argValues: [
+          instanceId,
+          dstPeerId,
+          channel,
+          requestId,
+          payload,
+          flags,
+          timeoutMs,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PGetIpsConstMeta =>
-      const TaskConstMeta(debugName: "get_ips", argNames: ["instanceId"]);
+  TaskConstMeta get kCrateApiP2PAppCallConstMeta => const TaskConstMeta(
+    debugName: "app_call",
+    argNames: [
+      "instanceId",
+      "dstPeerId",
+      "channel",
+      "requestId",
+      "payload",
+      "flags",
+      "timeoutMs",
+    ],
+  );
 
   @override
-  Future<KVNetworkStatus> crateApiP2PGetNetworkStatus({
+  BigInt crateApiAppCallControlAppCallDeadline({
     required String instanceId,
+    required BigInt token,
   }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(instanceId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 10,
-            port: port_,
-          );
+          sse_encode_u_64(token, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_kv_network_status,
+          decodeSuccessData: sse_decode_u_64,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiP2PGetNetworkStatusConstMeta,
-        argValues: [instanceId],
+        constMeta: kCrateApiAppCallControlAppCallDeadlineConstMeta,
+        argValues: [instanceId, token],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PGetNetworkStatusConstMeta =>
+  TaskConstMeta get kCrateApiAppCallControlAppCallDeadlineConstMeta =>
       const TaskConstMeta(
-        debugName: "get_network_status",
-        argNames: ["instanceId"],
+        debugName: "app_call_deadline",
+        argNames: ["instanceId", "token"],
       );
 
   @override
-  Future<List<PeerRoutePair>> crateApiP2PGetPeerRoutePairs({
+  Future<AppCallDecodedC> crateApiAppCodecAppCallEncoded({
     required String instanceId,
+    required int dstPeerId,
+    required String channel,
+    required BigInt requestId,
+    required List<int> payload,
+    required PayloadCodecC codec,
+    required PayloadCodecC replyCodec,
+    required int flags,
+    required int timeoutMs,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(instanceId, serializer);
+          sse_encode_u_32(dstPeerId, serializer);
+          sse_encode_String(channel, serializer);
+          sse_encode_u_64(requestId, serializer);
+          sse_encode_list_prim_u_8_loose(payload, serializer);
+          sse_encode_payload_codec_c(codec, serializer);
+          sse_encode_payload_codec_c(replyCodec, serializer);
+          sse_encode_u_32(flags, serializer);
+          sse_encode_i_32(timeoutMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -641,30 +1105,59 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair,
+          decodeSuccessData: sse_decode_app_call_decoded_c,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PGetPeerRoutePairsConstMeta,
-        argValues: [instanceId],
+        constMeta: kCrateApiAppCodecAppCallEncodedConstMeta,
+        argValues: [
+          instanceId,
+          dstPeerId,
+          channel,
+          requestId,
+          payload,
+          codec,
+          replyCodec,
+          flags,
+          timeoutMs,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PGetPeerRoutePairsConstMeta =>
+  TaskConstMeta get kCrateApiAppCodecAppCallEncodedConstMeta =>
       const TaskConstMeta(
-        debugName: "get_peer_route_pairs",
-        argNames: ["instanceId"],
+        debugName: "app_call_encoded",
+        argNames: [
+          "instanceId",
+          "dstPeerId",
+          "channel",
+          "requestId",
+          "payload",
+          "codec",
+          "replyCodec",
+          "flags",
+          "timeoutMs",
+        ],
       );
 
   @override
-  Future<String> crateApiP2PGetRunningInfo({required String instanceId}) {
+  Future<bool> crateApiP2PAppCallReply({
+    required String instanceId,
+    required BigInt token,
+    required int status,
+    required String errorMsg,
+    required List<int> payload,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(instanceId, serializer);
+          sse_encode_u_64(token, serializer);
+          sse_encode_i_32(status, serializer);
+          sse_encode_String(errorMsg, serializer);
+          sse_encode_list_prim_u_8_loose(payload, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -673,56 +1166,103 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PGetRunningInfoConstMeta,
-        argValues: [instanceId],
+        constMeta: kCrateApiP2PAppCallReplyConstMeta,
+        argValues: [instanceId, token, status, errorMsg, payload],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PGetRunningInfoConstMeta => const TaskConstMeta(
-    debugName: "get_running_info",
-    argNames: ["instanceId"],
+  TaskConstMeta get kCrateApiP2PAppCallReplyConstMeta => const TaskConstMeta(
+    debugName: "app_call_reply",
+    argNames: ["instanceId", "token", "status", "errorMsg", "payload"],
   );
 
   @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<bool> crateApiAppCodecAppCallReplyEncoded({
+    required String instanceId,
+    required BigInt token,
+    required int status,
+    required String errorMsg,
+    required List<int> payload,
+    required PayloadCodecC codec,
+    required List<PayloadCodecC> peerAccepts,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+          sse_encode_String(instanceId, serializer);
+          sse_encode_u_64(token, serializer);
+          sse_encode_i_32(status, serializer);
+          sse_encode_String(errorMsg, serializer);
+          sse_encode_list_prim_u_8_loose(payload, serializer);
+          sse_encode_payload_codec_c(codec, serializer);
+          sse_encode_list_payload_codec_c(peerAccepts, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 13,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
+        constMeta: kCrateApiAppCodecAppCallReplyEncodedConstMeta,
+        argValues: [
+          instanceId,
+          token,
+          status,
+          errorMsg,
+          payload,
+          codec,
+          peerAccepts,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+  TaskConstMeta get kCrateApiAppCodecAppCallReplyEncodedConstMeta =>
+      const TaskConstMeta(
+        debugName: "app_call_reply_encoded",
+        argNames: [
+          "instanceId",
+          "token",
+          "status",
+          "errorMsg",
+          "payload",
+          "codec",
+          "peerAccepts",
+        ],
+      );
 
   @override
-  Future<JoinHandle> crateApiP2PHandleEvent({
-    required EventBusSubscriber events,
+  Future<AppCallResultC> crateApiAppCallControlAppCallWithDeadline({
+    required String instanceId,
+    required int dstPeerId,
+    required String channel,
+    required BigInt requestId,
+    required List<int> payload,
+    required int flags,
+    required int timeoutMs,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-            events,
-            serializer,
-          );
+          sse_encode_String(instanceId, serializer);
+          sse_encode_u_32(dstPeerId, serializer);
+          sse_encode_String(channel, serializer);
+          sse_encode_u_64(requestId, serializer);
+          sse_encode_list_prim_u_8_loose(payload, serializer);
+          sse_encode_u_32(flags, serializer);
+          sse_encode_i_32(timeoutMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -731,26 +1271,55 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_app_call_result_c,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PHandleEventConstMeta,
-        argValues: [events],
+        constMeta: kCrateApiAppCallControlAppCallWithDeadlineConstMeta,
+        argValues: [
+          instanceId,
+          dstPeerId,
+          channel,
+          requestId,
+          payload,
+          flags,
+          timeoutMs,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PHandleEventConstMeta =>
-      const TaskConstMeta(debugName: "handle_event", argNames: ["events"]);
+  TaskConstMeta get kCrateApiAppCallControlAppCallWithDeadlineConstMeta =>
+      const TaskConstMeta(
+        debugName: "app_call_with_deadline",
+        argNames: [
+          "instanceId",
+          "dstPeerId",
+          "channel",
+          "requestId",
+          "payload",
+          "flags",
+          "timeoutMs",
+        ],
+      );
 
   @override
-  Future<void> crateApiP2PInitApp() {
+  Future<void> crateApiP2PAppNotify({
+    required String instanceId,
+    required int dstPeerId,
+    required String channel,
+    required List<int> payload,
+    required int timeoutMs,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_u_32(dstPeerId, serializer);
+          sse_encode_String(channel, serializer);
+          sse_encode_list_prim_u_8_loose(payload, serializer);
+          sse_encode_i_32(timeoutMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -760,24 +1329,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiP2PAppNotifyConstMeta,
+        argValues: [instanceId, dstPeerId, channel, payload, timeoutMs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiP2PAppNotifyConstMeta => const TaskConstMeta(
+    debugName: "app_notify",
+    argNames: ["instanceId", "dstPeerId", "channel", "payload", "timeoutMs"],
+  );
 
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Future<void> crateApiAppCodecAppNotifyEncoded({
+    required String instanceId,
+    required int dstPeerId,
+    required String channel,
+    required List<int> payload,
+    required PayloadCodecC codec,
+    required int timeoutMs,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_u_32(dstPeerId, serializer);
+          sse_encode_String(channel, serializer);
+          sse_encode_list_prim_u_8_loose(payload, serializer);
+          sse_encode_payload_codec_c(codec, serializer);
+          sse_encode_i_32(timeoutMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -787,61 +1371,69 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiAppCodecAppNotifyEncodedConstMeta,
+        argValues: [instanceId, dstPeerId, channel, payload, codec, timeoutMs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiAppCodecAppNotifyEncodedConstMeta =>
+      const TaskConstMeta(
+        debugName: "app_notify_encoded",
+        argNames: [
+          "instanceId",
+          "dstPeerId",
+          "channel",
+          "payload",
+          "codec",
+          "timeoutMs",
+        ],
+      );
 
   @override
-  Future<bool> crateApiP2PIsEasytierRunning({required String instanceId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  String crateApiAppMetricsAppRpcMetricsOpenmetrics({
+    required String instanceId,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(instanceId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 17,
-            port: port_,
-          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiP2PIsEasytierRunningConstMeta,
+        constMeta: kCrateApiAppMetricsAppRpcMetricsOpenmetricsConstMeta,
         argValues: [instanceId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PIsEasytierRunningConstMeta =>
+  TaskConstMeta get kCrateApiAppMetricsAppRpcMetricsOpenmetricsConstMeta =>
       const TaskConstMeta(
-        debugName: "is_easytier_running",
+        debugName: "app_rpc_metrics_openmetrics",
         argNames: ["instanceId"],
       );
 
   @override
-  Future<String> crateApiP2PJoinHandleResult({
-    required JoinHandleResultStringString handle,
+  Future<void> crateApiAppStreamAppStreamCancel({
+    required String instanceId,
+    required int peerId,
+    required BigInt streamId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-            handle,
-            serializer,
-          );
+          sse_encode_String(instanceId, serializer);
+          sse_encode_u_32(peerId, serializer);
+          sse_encode_u_64(streamId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -850,29 +1442,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PJoinHandleResultConstMeta,
-        argValues: [handle],
+        constMeta: kCrateApiAppStreamAppStreamCancelConstMeta,
+        argValues: [instanceId, peerId, streamId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PJoinHandleResultConstMeta =>
+  TaskConstMeta get kCrateApiAppStreamAppStreamCancelConstMeta =>
       const TaskConstMeta(
-        debugName: "join_handle_result",
-        argNames: ["handle"],
+        debugName: "app_stream_cancel",
+        argNames: ["instanceId", "peerId", "streamId"],
       );
 
   @override
-  Future<int> crateApiP2PMyPeerId({required String instanceId}) {
+  Future<void> crateApiAppStreamAppStreamFinish({
+    required String instanceId,
+    required BigInt streamId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(instanceId, serializer);
+          sse_encode_u_64(streamId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -881,23 +1477,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_u_32,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PMyPeerIdConstMeta,
-        argValues: [instanceId],
+        constMeta: kCrateApiAppStreamAppStreamFinishConstMeta,
+        argValues: [instanceId, streamId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PMyPeerIdConstMeta =>
-      const TaskConstMeta(debugName: "my_peer_id", argNames: ["instanceId"]);
+  TaskConstMeta get kCrateApiAppStreamAppStreamFinishConstMeta =>
+      const TaskConstMeta(
+        debugName: "app_stream_finish",
+        argNames: ["instanceId", "streamId"],
+      );
 
   @override
-  Future<PlatformInt64> crateApiP2PPeerPing({
+  Future<BigInt> crateApiAppStreamAppStreamOpen({
     required String instanceId,
     required int dstPeerId,
+    required String channel,
+    required BigInt totalLen,
+    required List<int> metadata,
     required int timeoutMs,
   }) {
     return handler.executeNormal(
@@ -906,6 +1508,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(instanceId, serializer);
           sse_encode_u_32(dstPeerId, serializer);
+          sse_encode_String(channel, serializer);
+          sse_encode_u_64(totalLen, serializer);
+          sse_encode_list_prim_u_8_loose(metadata, serializer);
           sse_encode_i_32(timeoutMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
@@ -915,1468 +1520,9208 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_i_64,
+          decodeSuccessData: sse_decode_u_64,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PPeerPingConstMeta,
-        argValues: [instanceId, dstPeerId, timeoutMs],
+        constMeta: kCrateApiAppStreamAppStreamOpenConstMeta,
+        argValues: [
+          instanceId,
+          dstPeerId,
+          channel,
+          totalLen,
+          metadata,
+          timeoutMs,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PPeerPingConstMeta => const TaskConstMeta(
-    debugName: "peer_ping",
-    argNames: ["instanceId", "dstPeerId", "timeoutMs"],
-  );
+  TaskConstMeta get kCrateApiAppStreamAppStreamOpenConstMeta =>
+      const TaskConstMeta(
+        debugName: "app_stream_open",
+        argNames: [
+          "instanceId",
+          "dstPeerId",
+          "channel",
+          "totalLen",
+          "metadata",
+          "timeoutMs",
+        ],
+      );
 
   @override
-  Future<BigInt> crateApiP2PPendingAppCallCount({required String instanceId}) {
+  Stream<Uint8List> crateApiAppStreamAppStreamRead({
+    required String instanceId,
+    required int fromPeerId,
+    required BigInt streamId,
+  }) {
+    final sink = RustStreamSink<Uint8List>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(instanceId, serializer);
+            sse_encode_u_32(fromPeerId, serializer);
+            sse_encode_u_64(streamId, serializer);
+            sse_encode_StreamSink_list_prim_u_8_strict_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 21,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiAppStreamAppStreamReadConstMeta,
+          argValues: [instanceId, fromPeerId, streamId, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiAppStreamAppStreamReadConstMeta =>
+      const TaskConstMeta(
+        debugName: "app_stream_read",
+        argNames: ["instanceId", "fromPeerId", "streamId", "sink"],
+      );
+
+  @override
+  Future<void> crateApiAppStreamAppStreamWrite({
+    required String instanceId,
+    required BigInt streamId,
+    required List<int> data,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(instanceId, serializer);
+          sse_encode_u_64(streamId, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 22,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_usize,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PPendingAppCallCountConstMeta,
-        argValues: [instanceId],
+        constMeta: kCrateApiAppStreamAppStreamWriteConstMeta,
+        argValues: [instanceId, streamId, data],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PPendingAppCallCountConstMeta =>
+  TaskConstMeta get kCrateApiAppStreamAppStreamWriteConstMeta =>
       const TaskConstMeta(
-        debugName: "pending_app_call_count",
-        argNames: ["instanceId"],
+        debugName: "app_stream_write",
+        argNames: ["instanceId", "streamId", "data"],
       );
 
   @override
-  Future<void> crateApiP2PSendUdpToLocalhost({required String message}) {
+  Future<bool> crateApiAppCallControlCancelAppCall({
+    required String instanceId,
+    required int dstPeerId,
+    required BigInt requestId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(message, serializer);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_u_32(dstPeerId, serializer);
+          sse_encode_u_64(requestId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 22,
+            funcId: 23,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PSendUdpToLocalhostConstMeta,
-        argValues: [message],
+        constMeta: kCrateApiAppCallControlCancelAppCallConstMeta,
+        argValues: [instanceId, dstPeerId, requestId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PSendUdpToLocalhostConstMeta =>
+  TaskConstMeta get kCrateApiAppCallControlCancelAppCallConstMeta =>
       const TaskConstMeta(
-        debugName: "send_udp_to_localhost",
-        argNames: ["message"],
+        debugName: "cancel_app_call",
+        argNames: ["instanceId", "dstPeerId", "requestId"],
       );
 
   @override
-  Future<void> crateApiFirewallSetFirewallStatus({
-    required int profileIndex,
-    required bool enable,
+  Future<void> crateApiFileTransferCancelFileTransfer({
+    required String instanceId,
+    required String transferId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(profileIndex, serializer);
-          sse_encode_bool(enable, serializer);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_String(transferId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 23,
+            funcId: 24,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiFirewallSetFirewallStatusConstMeta,
-        argValues: [profileIndex, enable],
+        constMeta: kCrateApiFileTransferCancelFileTransferConstMeta,
+        argValues: [instanceId, transferId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiFirewallSetFirewallStatusConstMeta =>
+  TaskConstMeta get kCrateApiFileTransferCancelFileTransferConstMeta =>
       const TaskConstMeta(
-        debugName: "set_firewall_status",
-        argNames: ["profileIndex", "enable"],
+        debugName: "cancel_file_transfer",
+        argNames: ["instanceId", "transferId"],
       );
 
   @override
-  Future<void> crateApiP2PSetTunFd({
+  Future<List<ProxyCidrConflictC>> crateApiProxyCidrCheckProxyCidrConflicts({
     required String instanceId,
-    required int fd,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(instanceId, serializer);
-          sse_encode_i_32(fd, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 24,
+            funcId: 25,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_proxy_cidr_conflict_c,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiP2PSetTunFdConstMeta,
-        argValues: [instanceId, fd],
+        constMeta: kCrateApiProxyCidrCheckProxyCidrConflictsConstMeta,
+        argValues: [instanceId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiP2PSetTunFdConstMeta => const TaskConstMeta(
-    debugName: "set_tun_fd",
-    argNames: ["instanceId", "fd"],
-  );
+  TaskConstMeta get kCrateApiProxyCidrCheckProxyCidrConflictsConstMeta =>
+      const TaskConstMeta(
+        debugName: "check_proxy_cidr_conflicts",
+        argNames: ["instanceId"],
+      );
 
   @override
-  Stream<AppInboundEventC> crateApiP2PSubscribeAppInbound({
+  void crateApiAppPolicyClearAppRpcPolicy({required String instanceId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAppPolicyClearAppRpcPolicyConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppPolicyClearAppRpcPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "clear_app_rpc_policy",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  Future<void> crateApiPresenceClearPresenceProfile({
     required String instanceId,
   }) {
-    final sink = RustStreamSink<AppInboundEventC>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_String(instanceId, serializer);
-            sse_encode_StreamSink_app_inbound_event_c_Sse(sink, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 25,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: sse_decode_String,
-          ),
-          constMeta: kCrateApiP2PSubscribeAppInboundConstMeta,
-          argValues: [instanceId, sink],
-          apiImpl: this,
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
         ),
+        constMeta: kCrateApiPresenceClearPresenceProfileConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
       ),
     );
-    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiP2PSubscribeAppInboundConstMeta =>
+  TaskConstMeta get kCrateApiPresenceClearPresenceProfileConstMeta =>
       const TaskConstMeta(
-        debugName: "subscribe_app_inbound",
-        argNames: ["instanceId", "sink"],
+        debugName: "clear_presence_profile",
+        argNames: ["instanceId"],
       );
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_EventBusSubscriber => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber;
-
+  @override
+  Future<void> crateApiPeerControlClosePeerConnection({
+    required String instanceId,
+    required int peerId,
+    required String connId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_u_32(peerId, serializer);
+          sse_encode_String(connId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiPeerControlClosePeerConnectionConstMeta,
+        argValues: [instanceId, peerId, connId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPeerControlClosePeerConnectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "close_peer_connection",
+        argNames: ["instanceId", "peerId", "connId"],
+      );
+
+  @override
+  Future<void> crateApiP2PCloseServer({required String instanceId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiP2PCloseServerConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiP2PCloseServerConstMeta =>
+      const TaskConstMeta(debugName: "close_server", argNames: ["instanceId"]);
+
+  @override
+  Future<JoinHandleResultStringString> crateApiP2PCreateServer({
+    required String configToml,
+    required bool watchEvent,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(configToml, serializer);
+          sse_encode_bool(watchEvent, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiP2PCreateServerConstMeta,
+        argValues: [configToml, watchEvent],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiP2PCreateServerConstMeta => const TaskConstMeta(
+    debugName: "create_server",
+    argNames: ["configToml", "watchEvent"],
+  );
+
+  @override
+  Future<JoinHandleResultStringString> crateApiP2PCreateServerWithFlags({
+    required String username,
+    required bool enableDhcp,
+    required String specifiedIp,
+    required String roomName,
+    required String roomPassword,
+    required List<String> severurl,
+    required List<String> onurl,
+    required List<String> cidrs,
+    required List<Forward> forwards,
+    required FlagsC flag,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(username, serializer);
+          sse_encode_bool(enableDhcp, serializer);
+          sse_encode_String(specifiedIp, serializer);
+          sse_encode_String(roomName, serializer);
+          sse_encode_String(roomPassword, serializer);
+          sse_encode_list_String(severurl, serializer);
+          sse_encode_list_String(onurl, serializer);
+          sse_encode_list_String(cidrs, serializer);
+          sse_encode_list_forward(forwards, serializer);
+          sse_encode_box_autoadd_flags_c(flag, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiP2PCreateServerWithFlagsConstMeta,
+        argValues: [
+          username,
+          enableDhcp,
+          specifiedIp,
+          roomName,
+          roomPassword,
+          severurl,
+          onurl,
+          cidrs,
+          forwards,
+          flag,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiP2PCreateServerWithFlagsConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_server_with_flags",
+        argNames: [
+          "username",
+          "enableDhcp",
+          "specifiedIp",
+          "roomName",
+          "roomPassword",
+          "severurl",
+          "onurl",
+          "cidrs",
+          "forwards",
+          "flag",
+        ],
+      );
+
+  @override
+  DecodedPayloadC crateApiAppCodecDecodeAppPayload({
+    required List<int> payload,
+    required PayloadCodecC want,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(payload, serializer);
+          sse_encode_payload_codec_c(want, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_decoded_payload_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiAppCodecDecodeAppPayloadConstMeta,
+        argValues: [payload, want],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppCodecDecodeAppPayloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "decode_app_payload",
+        argNames: ["payload", "want"],
+      );
+
+  @override
+  Future<NatDetectionC> crateApiNatDetectNat({
+    required List<String> stunServers,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(stunServers, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_nat_detection_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiNatDetectNatConstMeta,
+        argValues: [stunServers],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNatDetectNatConstMeta =>
+      const TaskConstMeta(debugName: "detect_nat", argNames: ["stunServers"]);
+
+  @override
+  Future<FirewallDiagnosisC> crateApiFirewallDiagnoseFirewall({
+    required String instanceId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_firewall_diagnosis_c,
+          decodeErrorData: sse_decode_firewall_error,
+        ),
+        constMeta: kCrateApiFirewallDiagnoseFirewallConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFirewallDiagnoseFirewallConstMeta =>
+      const TaskConstMeta(
+        debugName: "diagnose_firewall",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  Future<void> crateApiPortMappingDisablePortMapping({
+    required String instanceId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiPortMappingDisablePortMappingConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPortMappingDisablePortMappingConstMeta =>
+      const TaskConstMeta(
+        debugName: "disable_port_mapping",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  Future<void> crateApiServerSelectionDisableServerSelection({
+    required String instanceId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerSelectionDisableServerSelectionConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerSelectionDisableServerSelectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "disable_server_selection",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  Future<String> crateApiP2PEasytierVersion() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiP2PEasytierVersionConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiP2PEasytierVersionConstMeta =>
+      const TaskConstMeta(debugName: "easytier_version", argNames: []);
+
+  @override
+  Future<List<ListenerMappingC>> crateApiPortMappingEnablePortMapping({
+    required String instanceId,
+    required List<PortMappingRequestC> requests,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_list_port_mapping_request_c(requests, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_listener_mapping_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiPortMappingEnablePortMappingConstMeta,
+        argValues: [instanceId, requests],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPortMappingEnablePortMappingConstMeta =>
+      const TaskConstMeta(
+        debugName: "enable_port_mapping",
+        argNames: ["instanceId", "requests"],
+      );
+
+  @override
+  Future<ServerSelectionStatusC> crateApiServerSelectionEnableServerSelection({
+    required String instanceId,
+    required int activeCount,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_u_32(activeCount, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_server_selection_status_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerSelectionEnableServerSelectionConstMeta,
+        argValues: [instanceId, activeCount],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerSelectionEnableServerSelectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "enable_server_selection",
+        argNames: ["instanceId", "activeCount"],
+      );
+
+  @override
+  AppRpcMetricsC crateApiAppMetricsGetAppRpcMetrics({
+    required String instanceId,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_app_rpc_metrics_c,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAppMetricsGetAppRpcMetricsConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppMetricsGetAppRpcMetricsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_app_rpc_metrics",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  AppRpcPolicyC? crateApiAppPolicyGetAppRpcPolicy({
+    required String instanceId,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_app_rpc_policy_c,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAppPolicyGetAppRpcPolicyConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppPolicyGetAppRpcPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_app_rpc_policy",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  AppRpcRejectionsC crateApiAppPolicyGetAppRpcRejections({
+    required String instanceId,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_app_rpc_rejections_c,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAppPolicyGetAppRpcRejectionsConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppPolicyGetAppRpcRejectionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_app_rpc_rejections",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  Future<bool> crateApiFirewallGetFirewallProfileStatus({
+    required FirewallProfile profile,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_firewall_profile(profile, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_firewall_error,
+        ),
+        constMeta: kCrateApiFirewallGetFirewallProfileStatusConstMeta,
+        argValues: [profile],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFirewallGetFirewallProfileStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_firewall_profile_status",
+        argNames: ["profile"],
+      );
+
+  @override
+  Future<FirewallSnapshotC> crateApiFirewallGetFirewallSnapshot() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_firewall_snapshot_c,
+          decodeErrorData: sse_decode_firewall_error,
+        ),
+        constMeta: kCrateApiFirewallGetFirewallSnapshotConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFirewallGetFirewallSnapshotConstMeta =>
+      const TaskConstMeta(debugName: "get_firewall_snapshot", argNames: []);
+
+  @override
+  Future<bool> crateApiFirewallGetFirewallStatus({required int profileIndex}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(profileIndex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_firewall_error,
+        ),
+        constMeta: kCrateApiFirewallGetFirewallStatusConstMeta,
+        argValues: [profileIndex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFirewallGetFirewallStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_firewall_status",
+        argNames: ["profileIndex"],
+      );
+
+  @override
+  Future<List<String>> crateApiP2PGetIps({required String instanceId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiP2PGetIpsConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiP2PGetIpsConstMeta =>
+      const TaskConstMeta(debugName: "get_ips", argNames: ["instanceId"]);
+
+  @override
+  Future<LocalEndpointsC> crateApiPortMappingGetLocalEndpoints({
+    required String instanceId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_local_endpoints_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiPortMappingGetLocalEndpointsConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPortMappingGetLocalEndpointsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_local_endpoints",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  Future<KVNetworkStatus> crateApiP2PGetNetworkStatus({
+    required String instanceId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_kv_network_status,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiP2PGetNetworkStatusConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiP2PGetNetworkStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_network_status",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  Future<List<PeerRoutePair>> crateApiP2PGetPeerRoutePairs({
+    required String instanceId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiP2PGetPeerRoutePairsConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiP2PGetPeerRoutePairsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_peer_route_pairs",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  BigInt crateApiPresenceGetPresenceBadAnnounces({required String instanceId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPresenceGetPresenceBadAnnouncesConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPresenceGetPresenceBadAnnouncesConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_presence_bad_announces",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  PresenceProfileC? crateApiPresenceGetPresenceProfile({
+    required String instanceId,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_presence_profile_c,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPresenceGetPresenceProfileConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPresenceGetPresenceProfileConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_presence_profile",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  Future<String> crateApiP2PGetRunningInfo({required String instanceId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiP2PGetRunningInfoConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiP2PGetRunningInfoConstMeta => const TaskConstMeta(
+    debugName: "get_running_info",
+    argNames: ["instanceId"],
+  );
+
+  @override
+  Future<ServerSelectionStatusC>
+  crateApiServerSelectionGetServerSelectionStatus({
+    required String instanceId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_server_selection_status_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiServerSelectionGetServerSelectionStatusConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerSelectionGetServerSelectionStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_server_selection_status",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  Future<TunFirewallStatusC> crateApiFirewallGetTunFirewallStatus({
+    required String devName,
+    required List<FirewallPortC> ports,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(devName, serializer);
+          sse_encode_list_firewall_port_c(ports, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tun_firewall_status_c,
+          decodeErrorData: sse_decode_firewall_error,
+        ),
+        constMeta: kCrateApiFirewallGetTunFirewallStatusConstMeta,
+        argValues: [devName, ports],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFirewallGetTunFirewallStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_tun_firewall_status",
+        argNames: ["devName", "ports"],
+      );
+
+  @override
+  String crateApiSimpleGreet({required String name}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGreetConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
+      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+
+  @override
+  Future<JoinHandle> crateApiP2PHandleEvent({
+    required EventBusSubscriber events,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+            events,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiP2PHandleEventConstMeta,
+        argValues: [events],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiP2PHandleEventConstMeta =>
+      const TaskConstMeta(debugName: "handle_event", argNames: ["events"]);
+
+  @override
+  Future<void> crateApiP2PInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiP2PInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiP2PInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<void> crateApiSimpleInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  bool crateApiAppCallControlIsAppCallCancelled({
+    required String instanceId,
+    required BigInt token,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_u_64(token, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAppCallControlIsAppCallCancelledConstMeta,
+        argValues: [instanceId, token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppCallControlIsAppCallCancelledConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_app_call_cancelled",
+        argNames: ["instanceId", "token"],
+      );
+
+  @override
+  Future<bool> crateApiP2PIsEasytierRunning({required String instanceId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiP2PIsEasytierRunningConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiP2PIsEasytierRunningConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_easytier_running",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  bool crateApiRedactIsUnredactedDebugLogging() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRedactIsUnredactedDebugLoggingConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRedactIsUnredactedDebugLoggingConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_unredacted_debug_logging",
+        argNames: [],
+      );
+
+  @override
+  Future<String> crateApiP2PJoinHandleResult({
+    required JoinHandleResultStringString handle,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+            handle,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiP2PJoinHandleResultConstMeta,
+        argValues: [handle],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiP2PJoinHandleResultConstMeta =>
+      const TaskConstMeta(
+        debugName: "join_handle_result",
+        argNames: ["handle"],
+      );
+
+  @override
+  List<String> crateApiAppRouterListAppChannels({required String instanceId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAppRouterListAppChannelsConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppRouterListAppChannelsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_app_channels",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  List<FileOfferC> crateApiFileTransferListFileOffers({
+    required String instanceId,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_file_offer_c,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiFileTransferListFileOffersConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFileTransferListFileOffersConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_file_offers",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  List<ChatMessageC> crateApiMessagingListMessages({
+    required String instanceId,
+    required int limit,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_u_32(limit, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_chat_message_c,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMessagingListMessagesConstMeta,
+        argValues: [instanceId, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessagingListMessagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_messages",
+        argNames: ["instanceId", "limit"],
+      );
+
+  @override
+  Future<List<PeerConnectionC>> crateApiPeerControlListPeerConnections({
+    required String instanceId,
+    required int peerId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_u_32(peerId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_peer_connection_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiPeerControlListPeerConnectionsConstMeta,
+        argValues: [instanceId, peerId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPeerControlListPeerConnectionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_peer_connections",
+        argNames: ["instanceId", "peerId"],
+      );
+
+  @override
+  Future<List<PeerPresenceC>> crateApiPresenceListPeerPresence({
+    required String instanceId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_peer_presence_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiPresenceListPeerPresenceConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPresenceListPeerPresenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_peer_presence",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  Future<List<PeerProxyCidrsC>> crateApiProxyCidrListPeerProxyCidrs({
+    required String instanceId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_peer_proxy_cidrs_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiProxyCidrListPeerProxyCidrsConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProxyCidrListPeerProxyCidrsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_peer_proxy_cidrs",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  List<PinnedServerKeyC> crateApiCredentialListPinnedServerKeys() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_pinned_server_key_c,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCredentialListPinnedServerKeysConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCredentialListPinnedServerKeysConstMeta =>
+      const TaskConstMeta(debugName: "list_pinned_server_keys", argNames: []);
+
+  @override
+  Future<List<PortForwardC>> crateApiPortForwardListPortForwards({
+    required String instanceId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_port_forward_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiPortForwardListPortForwardsConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPortForwardListPortForwardsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_port_forwards",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  List<ListenerMappingC> crateApiPortMappingListPortMappings({
+    required String instanceId,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_listener_mapping_c,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPortMappingListPortMappingsConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPortMappingListPortMappingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_port_mappings",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  Future<List<ProxyCidrC>> crateApiProxyCidrListProxyCidrs({
+    required String instanceId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_proxy_cidr_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiProxyCidrListProxyCidrsConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProxyCidrListProxyCidrsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_proxy_cidrs",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  Future<NodeKeyInfoC> crateApiCredentialLoadOrGenerateNodeKeypair({
+    required String storeDir,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(storeDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_node_key_info_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCredentialLoadOrGenerateNodeKeypairConstMeta,
+        argValues: [storeDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCredentialLoadOrGenerateNodeKeypairConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_or_generate_node_keypair",
+        argNames: ["storeDir"],
+      );
+
+  @override
+  String crateApiLocalChannelLocalEventSession() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLocalChannelLocalEventSessionConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLocalChannelLocalEventSessionConstMeta =>
+      const TaskConstMeta(debugName: "local_event_session", argNames: []);
+
+  @override
+  NodeKeyInfoC? crateApiCredentialLocalPublicKey() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_node_key_info_c,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCredentialLocalPublicKeyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCredentialLocalPublicKeyConstMeta =>
+      const TaskConstMeta(debugName: "local_public_key", argNames: []);
+
+  @override
+  Future<void> crateApiMessagingMarkMessagesRead({
+    required String instanceId,
+    required List<String> messageIds,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_list_String(messageIds, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMessagingMarkMessagesReadConstMeta,
+        argValues: [instanceId, messageIds],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessagingMarkMessagesReadConstMeta =>
+      const TaskConstMeta(
+        debugName: "mark_messages_read",
+        argNames: ["instanceId", "messageIds"],
+      );
+
+  @override
+  Future<int> crateApiP2PMyPeerId({required String instanceId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiP2PMyPeerIdConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiP2PMyPeerIdConstMeta =>
+      const TaskConstMeta(debugName: "my_peer_id", argNames: ["instanceId"]);
+
+  @override
+  NatCompatibilityC crateApiNatNatCompatibility({
+    required NatTypeC local,
+    required NatTypeC peer,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_nat_type_c(local, serializer);
+          sse_encode_nat_type_c(peer, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_nat_compatibility_c,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNatNatCompatibilityConstMeta,
+        argValues: [local, peer],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNatNatCompatibilityConstMeta =>
+      const TaskConstMeta(
+        debugName: "nat_compatibility",
+        argNames: ["local", "peer"],
+      );
+
+  @override
+  Future<String> crateApiFileTransferOfferFile({
+    required String instanceId,
+    required int dstPeerId,
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_u_32(dstPeerId, serializer);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiFileTransferOfferFileConstMeta,
+        argValues: [instanceId, dstPeerId, path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFileTransferOfferFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "offer_file",
+        argNames: ["instanceId", "dstPeerId", "path"],
+      );
+
+  @override
+  Future<PlatformInt64> crateApiP2PPeerPing({
+    required String instanceId,
+    required int dstPeerId,
+    required int timeoutMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_u_32(dstPeerId, serializer);
+          sse_encode_i_32(timeoutMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiP2PPeerPingConstMeta,
+        argValues: [instanceId, dstPeerId, timeoutMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiP2PPeerPingConstMeta => const TaskConstMeta(
+    debugName: "peer_ping",
+    argNames: ["instanceId", "dstPeerId", "timeoutMs"],
+  );
+
+  @override
+  Future<BigInt> crateApiP2PPendingAppCallCount({required String instanceId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiP2PPendingAppCallCountConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiP2PPendingAppCallCountConstMeta =>
+      const TaskConstMeta(
+        debugName: "pending_app_call_count",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  Future<void> crateApiCredentialPinServerPublicKey({
+    required String serverUrl,
+    required String publicKey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(serverUrl, serializer);
+          sse_encode_String(publicKey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 82,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCredentialPinServerPublicKeyConstMeta,
+        argValues: [serverUrl, publicKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCredentialPinServerPublicKeyConstMeta =>
+      const TaskConstMeta(
+        debugName: "pin_server_public_key",
+        argNames: ["serverUrl", "publicKey"],
+      );
+
+  @override
+  Future<List<ServerProbeResultC>> crateApiServerProbeProbeServers({
+    required List<String> urls,
+    required int timeoutMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(urls, serializer);
+          sse_encode_u_32(timeoutMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 83,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_server_probe_result_c,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiServerProbeProbeServersConstMeta,
+        argValues: [urls, timeoutMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiServerProbeProbeServersConstMeta =>
+      const TaskConstMeta(
+        debugName: "probe_servers",
+        argNames: ["urls", "timeoutMs"],
+      );
+
+  @override
+  String crateApiCredentialPublicKeyFingerprint({required String publicKey}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(publicKey, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCredentialPublicKeyFingerprintConstMeta,
+        argValues: [publicKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCredentialPublicKeyFingerprintConstMeta =>
+      const TaskConstMeta(
+        debugName: "public_key_fingerprint",
+        argNames: ["publicKey"],
+      );
+
+  @override
+  Future<int> crateApiPeerControlReconnectPeer({
+    required String instanceId,
+    required int peerId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_u_32(peerId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 85,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiPeerControlReconnectPeerConstMeta,
+        argValues: [instanceId, peerId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPeerControlReconnectPeerConstMeta =>
+      const TaskConstMeta(
+        debugName: "reconnect_peer",
+        argNames: ["instanceId", "peerId"],
+      );
+
+  @override
+  Future<NodeKeyInfoC> crateApiCredentialRegenerateNodeKeypair({
+    required String storeDir,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(storeDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 86,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_node_key_info_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCredentialRegenerateNodeKeypairConstMeta,
+        argValues: [storeDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCredentialRegenerateNodeKeypairConstMeta =>
+      const TaskConstMeta(
+        debugName: "regenerate_node_keypair",
+        argNames: ["storeDir"],
+      );
+
+  @override
+  Future<void> crateApiAppRouterRegisterAppChannels({
+    required String instanceId,
+    required List<String> channels,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_list_String(channels, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiAppRouterRegisterAppChannelsConstMeta,
+        argValues: [instanceId, channels],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppRouterRegisterAppChannelsConstMeta =>
+      const TaskConstMeta(
+        debugName: "register_app_channels",
+        argNames: ["instanceId", "channels"],
+      );
+
+  @override
+  Future<void> crateApiFileTransferRejectFile({
+    required String instanceId,
+    required String transferId,
+    required String reason,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_String(transferId, serializer);
+          sse_encode_String(reason, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 88,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiFileTransferRejectFileConstMeta,
+        argValues: [instanceId, transferId, reason],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFileTransferRejectFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "reject_file",
+        argNames: ["instanceId", "transferId", "reason"],
+      );
+
+  @override
+  Future<void> crateApiFirewallRemoveInstanceFirewallRules({
+    required String instanceId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 89,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_firewall_error,
+        ),
+        constMeta: kCrateApiFirewallRemoveInstanceFirewallRulesConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFirewallRemoveInstanceFirewallRulesConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_instance_firewall_rules",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  Future<EndpointResultC> crateApiEndpointsRemoveListener({
+    required String instanceId,
+    required String url,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 90,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_endpoint_result_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiEndpointsRemoveListenerConstMeta,
+        argValues: [instanceId, url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEndpointsRemoveListenerConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_listener",
+        argNames: ["instanceId", "url"],
+      );
+
+  @override
+  Future<EndpointResultC> crateApiEndpointsRemovePeerUri({
+    required String instanceId,
+    required String uri,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_String(uri, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 91,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_endpoint_result_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiEndpointsRemovePeerUriConstMeta,
+        argValues: [instanceId, uri],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEndpointsRemovePeerUriConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_peer_uri",
+        argNames: ["instanceId", "uri"],
+      );
+
+  @override
+  Future<bool> crateApiPortForwardRemovePortForward({
+    required String instanceId,
+    required String proto,
+    required String bindAddr,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_String(proto, serializer);
+          sse_encode_String(bindAddr, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 92,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiPortForwardRemovePortForwardConstMeta,
+        argValues: [instanceId, proto, bindAddr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPortForwardRemovePortForwardConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_port_forward",
+        argNames: ["instanceId", "proto", "bindAddr"],
+      );
+
+  @override
+  Future<bool> crateApiProxyCidrRemoveProxyCidr({
+    required String instanceId,
+    required String cidr,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_String(cidr, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 93,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiProxyCidrRemoveProxyCidrConstMeta,
+        argValues: [instanceId, cidr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProxyCidrRemoveProxyCidrConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_proxy_cidr",
+        argNames: ["instanceId", "cidr"],
+      );
+
+  @override
+  Future<void> crateApiFirewallRemoveTunFirewallRule({
+    required String devName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(devName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 94,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_firewall_error,
+        ),
+        constMeta: kCrateApiFirewallRemoveTunFirewallRuleConstMeta,
+        argValues: [devName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFirewallRemoveTunFirewallRuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_tun_firewall_rule",
+        argNames: ["devName"],
+      );
+
+  @override
+  void crateApiAppMetricsResetAppRpcMetrics({required String instanceId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAppMetricsResetAppRpcMetricsConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppMetricsResetAppRpcMetricsConstMeta =>
+      const TaskConstMeta(
+        debugName: "reset_app_rpc_metrics",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  void crateApiAppPolicyResetAppRpcRejections({required String instanceId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAppPolicyResetAppRpcRejectionsConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppPolicyResetAppRpcRejectionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "reset_app_rpc_rejections",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  Future<DiagnosticReportC> crateApiDiagnosticsRunDiagnostics({
+    required String instanceId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 97,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_diagnostic_report_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDiagnosticsRunDiagnosticsConstMeta,
+        argValues: [instanceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDiagnosticsRunDiagnosticsConstMeta =>
+      const TaskConstMeta(
+        debugName: "run_diagnostics",
+        argNames: ["instanceId"],
+      );
+
+  @override
+  Future<ChatMessageC> crateApiMessagingSendDirectMessage({
+    required String instanceId,
+    required int peerId,
+    required String text,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_u_32(peerId, serializer);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 98,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_chat_message_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMessagingSendDirectMessageConstMeta,
+        argValues: [instanceId, peerId, text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessagingSendDirectMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_direct_message",
+        argNames: ["instanceId", "peerId", "text"],
+      );
+
+  @override
+  Future<ChatMessageC> crateApiMessagingSendRoomMessage({
+    required String instanceId,
+    required String text,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 99,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_chat_message_c,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMessagingSendRoomMessageConstMeta,
+        argValues: [instanceId, text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessagingSendRoomMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_room_message",
+        argNames: ["instanceId", "text"],
+      );
+
+  @override
+  Future<void> crateApiP2PSendUdpToLocalhost({required String message}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(message, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiP2PSendUdpToLocalhostConstMeta,
+        argValues: [message],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiP2PSendUdpToLocalhostConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_udp_to_localhost",
+        argNames: ["message"],
+      );
+
+  @override
+  void crateApiAppPolicySetAppRpcPolicy({
+    required String instanceId,
+    required AppRpcPolicyC policy,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_box_autoadd_app_rpc_policy_c(policy, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiAppPolicySetAppRpcPolicyConstMeta,
+        argValues: [instanceId, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppPolicySetAppRpcPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_app_rpc_policy",
+        argNames: ["instanceId", "policy"],
+      );
+
+  @override
+  Future<void> crateApiFirewallSetFirewallProfileStatus({
+    required FirewallProfile profile,
+    required bool enable,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_firewall_profile(profile, serializer);
+          sse_encode_bool(enable, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 102,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_firewall_error,
+        ),
+        constMeta: kCrateApiFirewallSetFirewallProfileStatusConstMeta,
+        argValues: [profile, enable],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFirewallSetFirewallProfileStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_firewall_profile_status",
+        argNames: ["profile", "enable"],
+      );
+
+  @override
+  Future<void> crateApiFirewallSetFirewallStatus({
+    required int profileIndex,
+    required bool enable,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(profileIndex, serializer);
+          sse_encode_bool(enable, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 103,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_firewall_error,
+        ),
+        constMeta: kCrateApiFirewallSetFirewallStatusConstMeta,
+        argValues: [profileIndex, enable],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFirewallSetFirewallStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_firewall_status",
+        argNames: ["profileIndex", "enable"],
+      );
+
+  @override
+  void crateApiLocalChannelSetLocalEventPort({required int port}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_16(port, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 104,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLocalChannelSetLocalEventPortConstMeta,
+        argValues: [port],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLocalChannelSetLocalEventPortConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_local_event_port",
+        argNames: ["port"],
+      );
+
+  @override
+  Future<void> crateApiPresenceSetPresenceProfile({
+    required String instanceId,
+    required PresenceProfileC profile,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_box_autoadd_presence_profile_c(profile, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 105,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiPresenceSetPresenceProfileConstMeta,
+        argValues: [instanceId, profile],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPresenceSetPresenceProfileConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_presence_profile",
+        argNames: ["instanceId", "profile"],
+      );
+
+  @override
+  Future<void> crateApiP2PSetTunFd({
+    required String instanceId,
+    required int fd,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_i_32(fd, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 106,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiP2PSetTunFdConstMeta,
+        argValues: [instanceId, fd],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiP2PSetTunFdConstMeta => const TaskConstMeta(
+    debugName: "set_tun_fd",
+    argNames: ["instanceId", "fd"],
+  );
+
+  @override
+  void crateApiRedactSetUnredactedDebugLogging({required bool enabled}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 107,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRedactSetUnredactedDebugLoggingConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRedactSetUnredactedDebugLoggingConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_unredacted_debug_logging",
+        argNames: ["enabled"],
+      );
+
+  @override
+  Future<void> crateApiMessagingStartMessaging({
+    required String instanceId,
+    required String historyDir,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_String(historyDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 108,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMessagingStartMessagingConstMeta,
+        argValues: [instanceId, historyDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessagingStartMessagingConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_messaging",
+        argNames: ["instanceId", "historyDir"],
+      );
+
+  @override
+  Stream<AppCallControlC> crateApiAppCallControlSubscribeAppCallControl({
+    required String instanceId,
+  }) {
+    final sink = RustStreamSink<AppCallControlC>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(instanceId, serializer);
+            sse_encode_StreamSink_app_call_control_c_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 109,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiAppCallControlSubscribeAppCallControlConstMeta,
+          argValues: [instanceId, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiAppCallControlSubscribeAppCallControlConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_app_call_control",
+        argNames: ["instanceId", "sink"],
+      );
+
+  @override
+  Stream<AppInboundEventC> crateApiAppRouterSubscribeAppChannels({
+    required String instanceId,
+    required List<String> channels,
+  }) {
+    final sink = RustStreamSink<AppInboundEventC>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(instanceId, serializer);
+            sse_encode_list_String(channels, serializer);
+            sse_encode_StreamSink_app_inbound_event_c_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 110,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiAppRouterSubscribeAppChannelsConstMeta,
+          argValues: [instanceId, channels, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiAppRouterSubscribeAppChannelsConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_app_channels",
+        argNames: ["instanceId", "channels", "sink"],
+      );
+
+  @override
+  Stream<AppInboundEventC> crateApiP2PSubscribeAppInbound({
+    required String instanceId,
+  }) {
+    final sink = RustStreamSink<AppInboundEventC>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(instanceId, serializer);
+            sse_encode_StreamSink_app_inbound_event_c_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 111,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiP2PSubscribeAppInboundConstMeta,
+          argValues: [instanceId, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiP2PSubscribeAppInboundConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_app_inbound",
+        argNames: ["instanceId", "sink"],
+      );
+
+  @override
+  Stream<AppStreamProgressC> crateApiAppStreamSubscribeAppStreamProgress({
+    required String instanceId,
+    required int peerId,
+    required BigInt streamId,
+  }) {
+    final sink = RustStreamSink<AppStreamProgressC>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(instanceId, serializer);
+            sse_encode_u_32(peerId, serializer);
+            sse_encode_u_64(streamId, serializer);
+            sse_encode_StreamSink_app_stream_progress_c_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 112,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiAppStreamSubscribeAppStreamProgressConstMeta,
+          argValues: [instanceId, peerId, streamId, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiAppStreamSubscribeAppStreamProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_app_stream_progress",
+        argNames: ["instanceId", "peerId", "streamId", "sink"],
+      );
+
+  @override
+  Stream<InboundAppStreamC> crateApiAppStreamSubscribeAppStreams({
+    required String instanceId,
+    required String channel,
+  }) {
+    final sink = RustStreamSink<InboundAppStreamC>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(instanceId, serializer);
+            sse_encode_String(channel, serializer);
+            sse_encode_StreamSink_inbound_app_stream_c_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 113,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiAppStreamSubscribeAppStreamsConstMeta,
+          argValues: [instanceId, channel, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiAppStreamSubscribeAppStreamsConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_app_streams",
+        argNames: ["instanceId", "channel", "sink"],
+      );
+
+  @override
+  Stream<CredentialEventC> crateApiCredentialSubscribeCredentialEvents({
+    required String instanceId,
+  }) {
+    final sink = RustStreamSink<CredentialEventC>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(instanceId, serializer);
+            sse_encode_StreamSink_credential_event_c_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 114,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiCredentialSubscribeCredentialEventsConstMeta,
+          argValues: [instanceId, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCredentialSubscribeCredentialEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_credential_events",
+        argNames: ["instanceId", "sink"],
+      );
+
+  @override
+  Stream<FileOfferC> crateApiFileTransferSubscribeFileOffers({
+    required String instanceId,
+  }) {
+    final sink = RustStreamSink<FileOfferC>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(instanceId, serializer);
+            sse_encode_StreamSink_file_offer_c_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 115,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiFileTransferSubscribeFileOffersConstMeta,
+          argValues: [instanceId, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiFileTransferSubscribeFileOffersConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_file_offers",
+        argNames: ["instanceId", "sink"],
+      );
+
+  @override
+  Stream<FileTransferProgressC>
+  crateApiFileTransferSubscribeFileTransferProgress({
+    required String instanceId,
+  }) {
+    final sink = RustStreamSink<FileTransferProgressC>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(instanceId, serializer);
+            sse_encode_StreamSink_file_transfer_progress_c_Sse(
+              sink,
+              serializer,
+            );
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 116,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta:
+              kCrateApiFileTransferSubscribeFileTransferProgressConstMeta,
+          argValues: [instanceId, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiFileTransferSubscribeFileTransferProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_file_transfer_progress",
+        argNames: ["instanceId", "sink"],
+      );
+
+  @override
+  Stream<MessageEventC> crateApiMessagingSubscribeMessages({
+    required String instanceId,
+  }) {
+    final sink = RustStreamSink<MessageEventC>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(instanceId, serializer);
+            sse_encode_StreamSink_message_event_c_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 117,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiMessagingSubscribeMessagesConstMeta,
+          argValues: [instanceId, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiMessagingSubscribeMessagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_messages",
+        argNames: ["instanceId", "sink"],
+      );
+
+  @override
+  Stream<ListenerMappingC> crateApiPortMappingSubscribePortMappingEvents({
+    required String instanceId,
+  }) {
+    final sink = RustStreamSink<ListenerMappingC>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(instanceId, serializer);
+            sse_encode_StreamSink_listener_mapping_c_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 118,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiPortMappingSubscribePortMappingEventsConstMeta,
+          argValues: [instanceId, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiPortMappingSubscribePortMappingEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_port_mapping_events",
+        argNames: ["instanceId", "sink"],
+      );
+
+  @override
+  Stream<PresenceEventC> crateApiPresenceSubscribePresence({
+    required String instanceId,
+  }) {
+    final sink = RustStreamSink<PresenceEventC>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(instanceId, serializer);
+            sse_encode_StreamSink_presence_event_c_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 119,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiPresenceSubscribePresenceConstMeta,
+          argValues: [instanceId, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiPresenceSubscribePresenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_presence",
+        argNames: ["instanceId", "sink"],
+      );
+
+  @override
+  Future<bool> crateApiCredentialUnpinServerPublicKey({
+    required String serverUrl,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(serverUrl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 120,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCredentialUnpinServerPublicKeyConstMeta,
+        argValues: [serverUrl],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCredentialUnpinServerPublicKeyConstMeta =>
+      const TaskConstMeta(
+        debugName: "unpin_server_public_key",
+        argNames: ["serverUrl"],
+      );
+
+  @override
+  Future<void> crateApiAppRouterUnregisterAppChannels({
+    required String instanceId,
+    required List<String> channels,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(instanceId, serializer);
+          sse_encode_list_String(channels, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 121,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiAppRouterUnregisterAppChannelsConstMeta,
+        argValues: [instanceId, channels],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppRouterUnregisterAppChannelsConstMeta =>
+      const TaskConstMeta(
+        debugName: "unregister_app_channels",
+        argNames: ["instanceId", "channels"],
+      );
+
+  @override
+  LocalEventC? crateApiLocalChannelVerifyLocalEvent({
+    required List<int> datagram,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(datagram, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 122,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_local_event_c,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLocalChannelVerifyLocalEventConstMeta,
+        argValues: [datagram],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLocalChannelVerifyLocalEventConstMeta =>
+      const TaskConstMeta(
+        debugName: "verify_local_event",
+        argNames: ["datagram"],
+      );
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_EventBusSubscriber => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber;
+
   RustArcDecrementStrongCountFnType
   get rust_arc_decrement_strong_count_EventBusSubscriber => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber;
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_JoinHandle => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle;
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_JoinHandle => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_JoinHandle => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_JoinHandleResultStringString => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_JoinHandleResultStringString => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_PeerRoutePair => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_PeerRoutePair => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  EventBusSubscriber
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EventBusSubscriberImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  JoinHandle
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return JoinHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  JoinHandleResultStringString
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return JoinHandleResultStringStringImpl.frbInternalDcoDecode(
+      raw as List<dynamic>,
+    );
+  }
+
+  @protected
+  PeerRoutePair
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PeerRoutePairImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  EventBusSubscriber
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EventBusSubscriberImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  JoinHandle
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return JoinHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  JoinHandleResultStringString
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return JoinHandleResultStringStringImpl.frbInternalDcoDecode(
+      raw as List<dynamic>,
+    );
+  }
+
+  @protected
+  PeerRoutePair
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PeerRoutePairImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<AppCallControlC> dco_decode_StreamSink_app_call_control_c_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<AppInboundEventC>
+  dco_decode_StreamSink_app_inbound_event_c_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<AppStreamProgressC>
+  dco_decode_StreamSink_app_stream_progress_c_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<CredentialEventC> dco_decode_StreamSink_credential_event_c_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<FileOfferC> dco_decode_StreamSink_file_offer_c_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<FileTransferProgressC>
+  dco_decode_StreamSink_file_transfer_progress_c_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<InboundAppStreamC>
+  dco_decode_StreamSink_inbound_app_stream_c_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<Uint8List> dco_decode_StreamSink_list_prim_u_8_strict_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ListenerMappingC> dco_decode_StreamSink_listener_mapping_c_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<MessageEventC> dco_decode_StreamSink_message_event_c_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<PresenceEventC> dco_decode_StreamSink_presence_event_c_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AppCallControlC dco_decode_app_call_control_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return AppCallControlC(
+      kind: dco_decode_app_call_control_kind_c(arr[0]),
+      fromPeerId: dco_decode_u_32(arr[1]),
+      requestId: dco_decode_u_64(arr[2]),
+      token: dco_decode_u_64(arr[3]),
+      deadlineMs: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  AppCallControlKindC dco_decode_app_call_control_kind_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AppCallControlKindC.values[raw as int];
+  }
+
+  @protected
+  AppCallDecodedC dco_decode_app_call_decoded_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AppCallDecodedC(
+      status: dco_decode_i_32(arr[0]),
+      errorMsg: dco_decode_String(arr[1]),
+      codec: dco_decode_payload_codec_c(arr[2]),
+      payload: dco_decode_list_prim_u_8_strict(arr[3]),
+    );
+  }
+
+  @protected
+  AppCallResultC dco_decode_app_call_result_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AppCallResultC(
+      status: dco_decode_i_32(arr[0]),
+      errorMsg: dco_decode_String(arr[1]),
+      payload: dco_decode_list_prim_u_8_strict(arr[2]),
+    );
+  }
+
+  @protected
+  AppInboundEventC dco_decode_app_inbound_event_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return AppInboundEventC(
+      kind: dco_decode_app_inbound_kind_c(arr[0]),
+      fromPeerId: dco_decode_u_32(arr[1]),
+      channel: dco_decode_String(arr[2]),
+      requestId: dco_decode_u_64(arr[3]),
+      token: dco_decode_u_64(arr[4]),
+      payload: dco_decode_list_prim_u_8_strict(arr[5]),
+    );
+  }
+
+  @protected
+  AppInboundKindC dco_decode_app_inbound_kind_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AppInboundKindC.values[raw as int];
+  }
+
+  @protected
+  AppRpcCountersC dco_decode_app_rpc_counters_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return AppRpcCountersC(
+      callsSent: dco_decode_u_64(arr[0]),
+      callsReceived: dco_decode_u_64(arr[1]),
+      notifiesSent: dco_decode_u_64(arr[2]),
+      notifiesReceived: dco_decode_u_64(arr[3]),
+      repliesSent: dco_decode_u_64(arr[4]),
+      repliesReceived: dco_decode_u_64(arr[5]),
+      timeouts: dco_decode_u_64(arr[6]),
+      noSubscriber: dco_decode_u_64(arr[7]),
+      serviceDropped: dco_decode_u_64(arr[8]),
+      cancelled: dco_decode_u_64(arr[9]),
+      sendErrors: dco_decode_u_64(arr[10]),
+    );
+  }
+
+  @protected
+  AppRpcMetricsC dco_decode_app_rpc_metrics_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AppRpcMetricsC(
+      laggedEvents: dco_decode_u_64(arr[0]),
+      channels: dco_decode_list_channel_metrics_c(arr[1]),
+      peers: dco_decode_list_peer_metrics_c(arr[2]),
+    );
+  }
+
+  @protected
+  AppRpcPolicyC dco_decode_app_rpc_policy_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AppRpcPolicyC(
+      maxPayloadBytes: dco_decode_u_32(arr[0]),
+      peerRate: dco_decode_rate_limit_c(arr[1]),
+      channelRate: dco_decode_rate_limit_c(arr[2]),
+      channelRules: dco_decode_list_peer_channel_rule_c(arr[3]),
+    );
+  }
+
+  @protected
+  AppRpcRejectionsC dco_decode_app_rpc_rejections_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AppRpcRejectionsC(
+      total: dco_decode_u_64(arr[0]),
+      byReason: dco_decode_list_reason_rejections_c(arr[1]),
+      byPeer: dco_decode_list_peer_rejections_c(arr[2]),
+      byChannel: dco_decode_list_channel_rejections_c(arr[3]),
+    );
+  }
+
+  @protected
+  AppStreamProgressC dco_decode_app_stream_progress_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AppStreamProgressC(
+      state: dco_decode_app_stream_state_c(arr[0]),
+      bytes: dco_decode_u_64(arr[1]),
+      totalLen: dco_decode_u_64(arr[2]),
+      error: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  AppStreamStateC dco_decode_app_stream_state_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AppStreamStateC.values[raw as int];
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AppRpcPolicyC dco_decode_box_autoadd_app_rpc_policy_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_app_rpc_policy_c(raw);
+  }
+
+  @protected
+  BroadcastScopeC dco_decode_box_autoadd_broadcast_scope_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_broadcast_scope_c(raw);
+  }
+
+  @protected
+  FlagsC dco_decode_box_autoadd_flags_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_flags_c(raw);
+  }
+
+  @protected
+  Forward dco_decode_box_autoadd_forward(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_forward(raw);
+  }
+
+  @protected
+  LocalEventC dco_decode_box_autoadd_local_event_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_local_event_c(raw);
+  }
+
+  @protected
+  NodeKeyInfoC dco_decode_box_autoadd_node_key_info_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_node_key_info_c(raw);
+  }
+
+  @protected
+  PresenceProfileC dco_decode_box_autoadd_presence_profile_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_presence_profile_c(raw);
+  }
+
+  @protected
+  BroadcastDeliveryC dco_decode_broadcast_delivery_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BroadcastDeliveryC(
+      peerId: dco_decode_u_32(arr[0]),
+      delivered: dco_decode_bool(arr[1]),
+      error: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  BroadcastScopeC dco_decode_broadcast_scope_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BroadcastScopeC(
+      kind: dco_decode_broadcast_scope_kind_c(arr[0]),
+      peerIds: dco_decode_list_prim_u_32_strict(arr[1]),
+    );
+  }
+
+  @protected
+  BroadcastScopeKindC dco_decode_broadcast_scope_kind_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BroadcastScopeKindC.values[raw as int];
+  }
+
+  @protected
+  ChannelMetricsC dco_decode_channel_metrics_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ChannelMetricsC(
+      channel: dco_decode_String(arr[0]),
+      counters: dco_decode_app_rpc_counters_c(arr[1]),
+      callLatency: dco_decode_latency_histogram_c(arr[2]),
+      handlerLatency: dco_decode_latency_histogram_c(arr[3]),
+    );
+  }
+
+  @protected
+  ChannelRejectionsC dco_decode_channel_rejections_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ChannelRejectionsC(
+      channel: dco_decode_String(arr[0]),
+      count: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  ChatMessageC dco_decode_chat_message_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return ChatMessageC(
+      id: dco_decode_String(arr[0]),
+      kind: dco_decode_chat_message_kind_c(arr[1]),
+      fromPeerId: dco_decode_u_32(arr[2]),
+      toPeerId: dco_decode_u_32(arr[3]),
+      text: dco_decode_String(arr[4]),
+      sentAtMs: dco_decode_u_64(arr[5]),
+      outgoing: dco_decode_bool(arr[6]),
+      deliveredTo: dco_decode_list_prim_u_32_strict(arr[7]),
+      readBy: dco_decode_list_prim_u_32_strict(arr[8]),
+      queuedFor: dco_decode_list_prim_u_32_strict(arr[9]),
+    );
+  }
+
+  @protected
+  ChatMessageKindC dco_decode_chat_message_kind_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ChatMessageKindC.values[raw as int];
+  }
+
+  @protected
+  ConnectErrorC dco_decode_connect_error_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ConnectErrorC(
+      dst: dco_decode_String(arr[0]),
+      ipVersion: dco_decode_String(arr[1]),
+      error: dco_decode_String(arr[2]),
+      timestampMs: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  CredentialEventC dco_decode_credential_event_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CredentialEventC(
+      kind: dco_decode_credential_event_kind_c(arr[0]),
+      serverUrl: dco_decode_String(arr[1]),
+      publicKey: dco_decode_String(arr[2]),
+      fingerprint: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  CredentialEventKindC dco_decode_credential_event_kind_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CredentialEventKindC.values[raw as int];
+  }
+
+  @protected
+  DecodedPayloadC dco_decode_decoded_payload_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DecodedPayloadC(
+      codec: dco_decode_payload_codec_c(arr[0]),
+      payload: dco_decode_list_prim_u_8_strict(arr[1]),
+      tagged: dco_decode_bool(arr[2]),
+      peerAccepts: dco_decode_list_payload_codec_c(arr[3]),
+    );
+  }
+
+  @protected
+  DiagnosticCheckC dco_decode_diagnostic_check_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DiagnosticCheckC(
+      kind: dco_decode_diagnostic_check_kind_c(arr[0]),
+      status: dco_decode_diagnostic_status_c(arr[1]),
+      target: dco_decode_String(arr[2]),
+      detail: dco_decode_String(arr[3]),
+      suggestion: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  DiagnosticCheckKindC dco_decode_diagnostic_check_kind_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DiagnosticCheckKindC.values[raw as int];
+  }
+
+  @protected
+  DiagnosticReportC dco_decode_diagnostic_report_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DiagnosticReportC(
+      overall: dco_decode_diagnostic_status_c(arr[0]),
+      checks: dco_decode_list_diagnostic_check_c(arr[1]),
+      recentConnectErrors: dco_decode_list_connect_error_c(arr[2]),
+    );
+  }
+
+  @protected
+  DiagnosticStatusC dco_decode_diagnostic_status_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DiagnosticStatusC.values[raw as int];
+  }
+
+  @protected
+  EndpointOutcomeC dco_decode_endpoint_outcome_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EndpointOutcomeC.values[raw as int];
+  }
+
+  @protected
+  EndpointResultC dco_decode_endpoint_result_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return EndpointResultC(
+      url: dco_decode_String(arr[0]),
+      outcome: dco_decode_endpoint_outcome_c(arr[1]),
+      error: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FileOfferC dco_decode_file_offer_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return FileOfferC(
+      fromPeerId: dco_decode_u_32(arr[0]),
+      transferId: dco_decode_String(arr[1]),
+      name: dco_decode_String(arr[2]),
+      size: dco_decode_u_64(arr[3]),
+      sha256: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  FileTransferDirectionC dco_decode_file_transfer_direction_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FileTransferDirectionC.values[raw as int];
+  }
+
+  @protected
+  FileTransferProgressC dco_decode_file_transfer_progress_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return FileTransferProgressC(
+      transferId: dco_decode_String(arr[0]),
+      peerId: dco_decode_u_32(arr[1]),
+      direction: dco_decode_file_transfer_direction_c(arr[2]),
+      state: dco_decode_file_transfer_state_c(arr[3]),
+      name: dco_decode_String(arr[4]),
+      bytes: dco_decode_u_64(arr[5]),
+      size: dco_decode_u_64(arr[6]),
+      error: dco_decode_String(arr[7]),
+    );
+  }
+
+  @protected
+  FileTransferStateC dco_decode_file_transfer_state_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FileTransferStateC.values[raw as int];
+  }
+
+  @protected
+  FirewallBackendC dco_decode_firewall_backend_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FirewallBackendC.values[raw as int];
+  }
+
+  @protected
+  FirewallDiagnosisC dco_decode_firewall_diagnosis_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FirewallDiagnosisC(
+      backend: dco_decode_firewall_backend_c(arr[0]),
+      active: dco_decode_bool(arr[1]),
+      findings: dco_decode_list_firewall_finding_c(arr[2]),
+    );
+  }
+
+  @protected
+  FirewallError dco_decode_firewall_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FirewallError(
+      kind: dco_decode_firewall_error_kind(arr[0]),
+      message: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  FirewallErrorKind dco_decode_firewall_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FirewallErrorKind.values[raw as int];
+  }
+
+  @protected
+  FirewallFindingC dco_decode_firewall_finding_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return FirewallFindingC(
+      rule: dco_decode_firewall_rule_c(arr[0]),
+      blocked: dco_decode_bool(arr[1]),
+      blockingRule: dco_decode_String(arr[2]),
+      suggestion: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  FirewallPortC dco_decode_firewall_port_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FirewallPortC(
+      proto: dco_decode_String(arr[0]),
+      port: dco_decode_u_16(arr[1]),
+    );
+  }
+
+  @protected
+  FirewallPortStatusC dco_decode_firewall_port_status_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FirewallPortStatusC(
+      proto: dco_decode_String(arr[0]),
+      port: dco_decode_u_16(arr[1]),
+      allowed: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  FirewallProfile dco_decode_firewall_profile(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FirewallProfile.values[raw as int];
+  }
+
+  @protected
+  FirewallProfileStatusC dco_decode_firewall_profile_status_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FirewallProfileStatusC(
+      profile: dco_decode_firewall_profile(arr[0]),
+      enabled: dco_decode_bool(arr[1]),
+      current: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  FirewallRuleC dco_decode_firewall_rule_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return FirewallRuleC(
+      kind: dco_decode_firewall_rule_kind_c(arr[0]),
+      name: dco_decode_String(arr[1]),
+      proto: dco_decode_String(arr[2]),
+      port: dco_decode_u_16(arr[3]),
+      detail: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  FirewallRuleKindC dco_decode_firewall_rule_kind_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FirewallRuleKindC.values[raw as int];
+  }
+
+  @protected
+  FirewallSnapshotC dco_decode_firewall_snapshot_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FirewallSnapshotC(
+      backend: dco_decode_firewall_backend_c(arr[0]),
+      profiles: dco_decode_list_firewall_profile_status_c(arr[1]),
+      currentProfiles: dco_decode_list_firewall_profile(arr[2]),
+    );
+  }
+
+  @protected
+  FlagsC dco_decode_flags_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 29)
+      throw Exception('unexpected arr length: expect 29 but see ${arr.length}');
+    return FlagsC(
+      defaultProtocol: dco_decode_String(arr[0]),
+      devName: dco_decode_String(arr[1]),
+      enableEncryption: dco_decode_bool(arr[2]),
+      enableIpv6: dco_decode_bool(arr[3]),
+      mtu: dco_decode_u_32(arr[4]),
+      latencyFirst: dco_decode_bool(arr[5]),
+      enableExitNode: dco_decode_bool(arr[6]),
+      noTun: dco_decode_bool(arr[7]),
+      useSmoltcp: dco_decode_bool(arr[8]),
+      relayNetworkWhitelist: dco_decode_String(arr[9]),
+      disableP2P: dco_decode_bool(arr[10]),
+      relayAllPeerRpc: dco_decode_bool(arr[11]),
+      disableUdpHolePunching: dco_decode_bool(arr[12]),
+      disableTcpHolePunching: dco_decode_bool(arr[13]),
+      multiThread: dco_decode_bool(arr[14]),
+      dataCompressAlgo: dco_decode_i_32(arr[15]),
+      bindDevice: dco_decode_bool(arr[16]),
+      enableKcpProxy: dco_decode_bool(arr[17]),
+      disableKcpInput: dco_decode_bool(arr[18]),
+      disableRelayKcp: dco_decode_bool(arr[19]),
+      proxyForwardBySystem: dco_decode_bool(arr[20]),
+      acceptDns: dco_decode_bool(arr[21]),
+      privateMode: dco_decode_bool(arr[22]),
+      enableQuicProxy: dco_decode_bool(arr[23]),
+      disableQuicInput: dco_decode_bool(arr[24]),
+      disableSymHolePunching: dco_decode_bool(arr[25]),
+      tcpWhitelist: dco_decode_String(arr[26]),
+      udpWhitelist: dco_decode_String(arr[27]),
+      secureMode: dco_decode_bool(arr[28]),
+    );
+  }
+
+  @protected
+  Forward dco_decode_forward(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Forward(
+      bindAddr: dco_decode_String(arr[0]),
+      dstAddr: dco_decode_String(arr[1]),
+      proto: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  InboundAppStreamC dco_decode_inbound_app_stream_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return InboundAppStreamC(
+      fromPeerId: dco_decode_u_32(arr[0]),
+      streamId: dco_decode_u_64(arr[1]),
+      channel: dco_decode_String(arr[2]),
+      totalLen: dco_decode_u_64(arr[3]),
+      metadata: dco_decode_list_prim_u_8_strict(arr[4]),
+    );
+  }
+
+  @protected
+  KVNetworkStatus dco_decode_kv_network_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return KVNetworkStatus(
+      totalNodes: dco_decode_usize(arr[0]),
+      nodes: dco_decode_list_kv_node_info(arr[1]),
+    );
+  }
+
+  @protected
+  KVNodeConnectionStats dco_decode_kv_node_connection_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return KVNodeConnectionStats(
+      connType: dco_decode_String(arr[0]),
+      rxBytes: dco_decode_u_64(arr[1]),
+      txBytes: dco_decode_u_64(arr[2]),
+      rxPackets: dco_decode_u_64(arr[3]),
+      txPackets: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  KVNodeInfo dco_decode_kv_node_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 15)
+      throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+    return KVNodeInfo(
+      peerId: dco_decode_u_32(arr[0]),
+      hostname: dco_decode_String(arr[1]),
+      ipv4: dco_decode_String(arr[2]),
+      ipv6: dco_decode_String(arr[3]),
+      latencyMs: dco_decode_f_64(arr[4]),
+      nat: dco_decode_String(arr[5]),
+      hops: dco_decode_list_node_hop_stats(arr[6]),
+      lossRate: dco_decode_f_32(arr[7]),
+      connections: dco_decode_list_kv_node_connection_stats(arr[8]),
+      tunnelProto: dco_decode_String(arr[9]),
+      connType: dco_decode_String(arr[10]),
+      rxBytes: dco_decode_u_64(arr[11]),
+      txBytes: dco_decode_u_64(arr[12]),
+      version: dco_decode_String(arr[13]),
+      cost: dco_decode_i_32(arr[14]),
+    );
+  }
+
+  @protected
+  LatencyHistogramC dco_decode_latency_histogram_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LatencyHistogramC(
+      boundsMs: dco_decode_list_prim_f_64_strict(arr[0]),
+      counts: dco_decode_list_prim_u_64_strict(arr[1]),
+      count: dco_decode_u_64(arr[2]),
+      sumMs: dco_decode_f_64(arr[3]),
+    );
+  }
+
+  @protected
+  List<PeerRoutePair>
+  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(
+          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair,
+        )
+        .toList();
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<BroadcastDeliveryC> dco_decode_list_broadcast_delivery_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_broadcast_delivery_c).toList();
+  }
+
+  @protected
+  List<ChannelMetricsC> dco_decode_list_channel_metrics_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_channel_metrics_c).toList();
+  }
+
+  @protected
+  List<ChannelRejectionsC> dco_decode_list_channel_rejections_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_channel_rejections_c).toList();
+  }
+
+  @protected
+  List<ChatMessageC> dco_decode_list_chat_message_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_chat_message_c).toList();
+  }
+
+  @protected
+  List<ConnectErrorC> dco_decode_list_connect_error_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_connect_error_c).toList();
+  }
+
+  @protected
+  List<DiagnosticCheckC> dco_decode_list_diagnostic_check_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_diagnostic_check_c).toList();
+  }
+
+  @protected
+  List<FileOfferC> dco_decode_list_file_offer_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_file_offer_c).toList();
+  }
+
+  @protected
+  List<FirewallFindingC> dco_decode_list_firewall_finding_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_firewall_finding_c).toList();
+  }
+
+  @protected
+  List<FirewallPortC> dco_decode_list_firewall_port_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_firewall_port_c).toList();
+  }
+
+  @protected
+  List<FirewallPortStatusC> dco_decode_list_firewall_port_status_c(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_firewall_port_status_c)
+        .toList();
+  }
+
+  @protected
+  List<FirewallProfile> dco_decode_list_firewall_profile(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_firewall_profile).toList();
+  }
+
+  @protected
+  List<FirewallProfileStatusC> dco_decode_list_firewall_profile_status_c(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_firewall_profile_status_c)
+        .toList();
+  }
+
+  @protected
+  List<FirewallRuleC> dco_decode_list_firewall_rule_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_firewall_rule_c).toList();
+  }
+
+  @protected
+  List<Forward> dco_decode_list_forward(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_forward).toList();
+  }
+
+  @protected
+  List<KVNodeConnectionStats> dco_decode_list_kv_node_connection_stats(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_kv_node_connection_stats)
+        .toList();
+  }
+
+  @protected
+  List<KVNodeInfo> dco_decode_list_kv_node_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_kv_node_info).toList();
+  }
+
+  @protected
+  List<ListenerMappingC> dco_decode_list_listener_mapping_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_listener_mapping_c).toList();
+  }
+
+  @protected
+  List<NatCompatibilityC> dco_decode_list_nat_compatibility_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_nat_compatibility_c).toList();
+  }
+
+  @protected
+  List<NodeHopStats> dco_decode_list_node_hop_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_node_hop_stats).toList();
+  }
+
+  @protected
+  List<PayloadCodecC> dco_decode_list_payload_codec_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_payload_codec_c).toList();
+  }
+
+  @protected
+  List<PeerChannelRuleC> dco_decode_list_peer_channel_rule_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_peer_channel_rule_c).toList();
+  }
+
+  @protected
+  List<PeerConnectionC> dco_decode_list_peer_connection_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_peer_connection_c).toList();
+  }
+
+  @protected
+  List<PeerMetricsC> dco_decode_list_peer_metrics_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_peer_metrics_c).toList();
+  }
+
+  @protected
+  List<PeerPresenceC> dco_decode_list_peer_presence_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_peer_presence_c).toList();
+  }
+
+  @protected
+  List<PeerProxyCidrsC> dco_decode_list_peer_proxy_cidrs_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_peer_proxy_cidrs_c).toList();
+  }
+
+  @protected
+  List<PeerRejectionsC> dco_decode_list_peer_rejections_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_peer_rejections_c).toList();
+  }
+
+  @protected
+  List<PinnedServerKeyC> dco_decode_list_pinned_server_key_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pinned_server_key_c).toList();
+  }
+
+  @protected
+  List<PortForwardC> dco_decode_list_port_forward_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_port_forward_c).toList();
+  }
+
+  @protected
+  List<PortMappingRequestC> dco_decode_list_port_mapping_request_c(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_port_mapping_request_c)
+        .toList();
+  }
+
+  @protected
+  Float64List dco_decode_list_prim_f_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Float64List;
+  }
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint32List;
+  }
+
+  @protected
+  Uint64List dco_decode_list_prim_u_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64List(raw);
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<ProxyCidrC> dco_decode_list_proxy_cidr_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_proxy_cidr_c).toList();
+  }
+
+  @protected
+  List<ProxyCidrConflictC> dco_decode_list_proxy_cidr_conflict_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_proxy_cidr_conflict_c)
+        .toList();
+  }
+
+  @protected
+  List<ReasonRejectionsC> dco_decode_list_reason_rejections_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_reason_rejections_c).toList();
+  }
+
+  @protected
+  List<SelectedServerC> dco_decode_list_selected_server_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_selected_server_c).toList();
+  }
+
+  @protected
+  List<ServerProbeResultC> dco_decode_list_server_probe_result_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_server_probe_result_c)
+        .toList();
+  }
+
+  @protected
+  ListenerMappingC dco_decode_listener_mapping_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ListenerMappingC(
+      localListener: dco_decode_String(arr[0]),
+      mappedListener: dco_decode_String(arr[1]),
+      backend: dco_decode_String(arr[2]),
+      state: dco_decode_port_mapping_state_c(arr[3]),
+      managedByEasytier: dco_decode_bool(arr[4]),
+      leaseRemainingSecs: dco_decode_u_32(arr[5]),
+      error: dco_decode_String(arr[6]),
+    );
+  }
+
+  @protected
+  LocalEndpointsC dco_decode_local_endpoints_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LocalEndpointsC(
+      listeners: dco_decode_list_String(arr[0]),
+      mappedListeners: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  LocalEventC dco_decode_local_event_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LocalEventC(
+      instanceId: dco_decode_String(arr[0]),
+      message: dco_decode_String(arr[1]),
+      seq: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  MessageEventC dco_decode_message_event_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MessageEventC(
+      kind: dco_decode_message_event_kind_c(arr[0]),
+      peerId: dco_decode_u_32(arr[1]),
+      message: dco_decode_chat_message_c(arr[2]),
+      error: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  MessageEventKindC dco_decode_message_event_kind_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MessageEventKindC.values[raw as int];
+  }
+
+  @protected
+  NatCompatibilityC dco_decode_nat_compatibility_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return NatCompatibilityC(
+      peerNatType: dco_decode_nat_type_c(arr[0]),
+      likelihood: dco_decode_p_2_p_likelihood_c(arr[1]),
+      explanation: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  NatDetectionC dco_decode_nat_detection_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return NatDetectionC(
+      udpNatType: dco_decode_nat_type_c(arr[0]),
+      tcpNatType: dco_decode_nat_type_c(arr[1]),
+      udpPortMapping: dco_decode_port_mapping_c(arr[2]),
+      publicIps: dco_decode_list_String(arr[3]),
+      mappedUdpAddr: dco_decode_String(arr[4]),
+      minPort: dco_decode_u_32(arr[5]),
+      maxPort: dco_decode_u_32(arr[6]),
+      compatibility: dco_decode_list_nat_compatibility_c(arr[7]),
+    );
+  }
+
+  @protected
+  NatTypeC dco_decode_nat_type_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NatTypeC.values[raw as int];
+  }
+
+  @protected
+  NodeHopStats dco_decode_node_hop_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return NodeHopStats(
+      peerId: dco_decode_u_32(arr[0]),
+      targetIp: dco_decode_String(arr[1]),
+      latencyMs: dco_decode_f_64(arr[2]),
+      packetLoss: dco_decode_f_32(arr[3]),
+      nodeName: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  NodeKeyInfoC dco_decode_node_key_info_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return NodeKeyInfoC(
+      publicKey: dco_decode_String(arr[0]),
+      fingerprint: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  AppRpcPolicyC? dco_decode_opt_box_autoadd_app_rpc_policy_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_app_rpc_policy_c(raw);
+  }
+
+  @protected
+  LocalEventC? dco_decode_opt_box_autoadd_local_event_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_local_event_c(raw);
+  }
+
+  @protected
+  NodeKeyInfoC? dco_decode_opt_box_autoadd_node_key_info_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_node_key_info_c(raw);
+  }
+
+  @protected
+  PresenceProfileC? dco_decode_opt_box_autoadd_presence_profile_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_presence_profile_c(raw);
+  }
+
+  @protected
+  P2pLikelihoodC dco_decode_p_2_p_likelihood_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return P2pLikelihoodC.values[raw as int];
+  }
+
+  @protected
+  PayloadCodecC dco_decode_payload_codec_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PayloadCodecC.values[raw as int];
+  }
+
+  @protected
+  PeerChannelRuleC dco_decode_peer_channel_rule_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PeerChannelRuleC(
+      peerId: dco_decode_u_32(arr[0]),
+      allow: dco_decode_list_String(arr[1]),
+      deny: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  PeerConnectionC dco_decode_peer_connection_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PeerConnectionC(
+      connId: dco_decode_String(arr[0]),
+      tunnelType: dco_decode_String(arr[1]),
+      remoteAddr: dco_decode_String(arr[2]),
+      isClient: dco_decode_bool(arr[3]),
+      connectorUrl: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  PeerMetricsC dco_decode_peer_metrics_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PeerMetricsC(
+      peerId: dco_decode_u_32(arr[0]),
+      counters: dco_decode_app_rpc_counters_c(arr[1]),
+      callLatency: dco_decode_latency_histogram_c(arr[2]),
+      handlerLatency: dco_decode_latency_histogram_c(arr[3]),
+    );
+  }
+
+  @protected
+  PeerPresenceC dco_decode_peer_presence_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PeerPresenceC(
+      node: dco_decode_kv_node_info(arr[0]),
+      profile: dco_decode_opt_box_autoadd_presence_profile_c(arr[1]),
+    );
+  }
+
+  @protected
+  PeerProxyCidrsC dco_decode_peer_proxy_cidrs_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PeerProxyCidrsC(
+      peerId: dco_decode_u_32(arr[0]),
+      hostname: dco_decode_String(arr[1]),
+      cidrs: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  PeerRejectionsC dco_decode_peer_rejections_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PeerRejectionsC(
+      peerId: dco_decode_u_32(arr[0]),
+      count: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  PinnedServerKeyC dco_decode_pinned_server_key_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PinnedServerKeyC(
+      serverUrl: dco_decode_String(arr[0]),
+      publicKey: dco_decode_String(arr[1]),
+      fingerprint: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  PortForwardC dco_decode_port_forward_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return PortForwardC(
+      proto: dco_decode_String(arr[0]),
+      bindAddr: dco_decode_String(arr[1]),
+      dstAddr: dco_decode_String(arr[2]),
+      managed: dco_decode_bool(arr[3]),
+      activeConnections: dco_decode_u_32(arr[4]),
+      totalConnections: dco_decode_u_64(arr[5]),
+      txBytes: dco_decode_u_64(arr[6]),
+      rxBytes: dco_decode_u_64(arr[7]),
+      errors: dco_decode_u_64(arr[8]),
+      lastError: dco_decode_String(arr[9]),
+    );
+  }
+
+  @protected
+  PortMappingC dco_decode_port_mapping_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PortMappingC.values[raw as int];
+  }
+
+  @protected
+  PortMappingRequestC dco_decode_port_mapping_request_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PortMappingRequestC(
+      listener: dco_decode_String(arr[0]),
+      externalPort: dco_decode_u_16(arr[1]),
+    );
+  }
+
+  @protected
+  PortMappingStateC dco_decode_port_mapping_state_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PortMappingStateC.values[raw as int];
+  }
+
+  @protected
+  PresenceEventC dco_decode_presence_event_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PresenceEventC(
+      kind: dco_decode_presence_event_kind_c(arr[0]),
+      peerId: dco_decode_u_32(arr[1]),
+      profile: dco_decode_opt_box_autoadd_presence_profile_c(arr[2]),
+    );
+  }
+
+  @protected
+  PresenceEventKindC dco_decode_presence_event_kind_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PresenceEventKindC.values[raw as int];
+  }
+
+  @protected
+  PresenceProfileC dco_decode_presence_profile_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PresenceProfileC(
+      displayName: dco_decode_String(arr[0]),
+      avatarHash: dco_decode_String(arr[1]),
+      status: dco_decode_presence_status_c(arr[2]),
+      appName: dco_decode_String(arr[3]),
+      appVersion: dco_decode_String(arr[4]),
+      currentGame: dco_decode_String(arr[5]),
+    );
+  }
+
+  @protected
+  PresenceStatusC dco_decode_presence_status_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PresenceStatusC.values[raw as int];
+  }
+
+  @protected
+  ProxyCidrC dco_decode_proxy_cidr_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ProxyCidrC(
+      cidr: dco_decode_String(arr[0]),
+      mappedCidr: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ProxyCidrConflictC dco_decode_proxy_cidr_conflict_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ProxyCidrConflictC(
+      kind: dco_decode_proxy_cidr_conflict_kind_c(arr[0]),
+      cidr: dco_decode_String(arr[1]),
+      otherCidr: dco_decode_String(arr[2]),
+      peerId: dco_decode_u_32(arr[3]),
+      hostname: dco_decode_String(arr[4]),
+      interface: dco_decode_String(arr[5]),
+    );
+  }
+
+  @protected
+  ProxyCidrConflictKindC dco_decode_proxy_cidr_conflict_kind_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ProxyCidrConflictKindC.values[raw as int];
+  }
+
+  @protected
+  RateLimitC dco_decode_rate_limit_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RateLimitC(
+      perSecond: dco_decode_u_32(arr[0]),
+      burst: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  ReasonRejectionsC dco_decode_reason_rejections_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ReasonRejectionsC(
+      reason: dco_decode_reject_reason_c(arr[0]),
+      count: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  RejectReasonC dco_decode_reject_reason_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RejectReasonC.values[raw as int];
+  }
+
+  @protected
+  SelectedServerC dco_decode_selected_server_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SelectedServerC(
+      url: dco_decode_String(arr[0]),
+      role: dco_decode_server_role_c(arr[1]),
+      latencyMs: dco_decode_u_32(arr[2]),
+      connected: dco_decode_bool(arr[3]),
+      reason: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  ServerProbeResultC dco_decode_server_probe_result_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ServerProbeResultC(
+      url: dco_decode_String(arr[0]),
+      scheme: dco_decode_String(arr[1]),
+      protocolSupported: dco_decode_bool(arr[2]),
+      resolvedAddr: dco_decode_String(arr[3]),
+      dnsMs: dco_decode_u_32(arr[4]),
+      reachable: dco_decode_bool(arr[5]),
+      latencyMs: dco_decode_u_32(arr[6]),
+      error: dco_decode_String(arr[7]),
+    );
+  }
+
+  @protected
+  ServerRoleC dco_decode_server_role_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ServerRoleC.values[raw as int];
+  }
+
+  @protected
+  ServerSelectionStatusC dco_decode_server_selection_status_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ServerSelectionStatusC(
+      enabled: dco_decode_bool(arr[0]),
+      activeCount: dco_decode_u_32(arr[1]),
+      servers: dco_decode_list_selected_server_c(arr[2]),
+    );
+  }
+
+  @protected
+  TunFirewallStatusC dco_decode_tun_firewall_status_c(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TunFirewallStatusC(
+      backend: dco_decode_firewall_backend_c(arr[0]),
+      active: dco_decode_bool(arr[1]),
+      tunAllowed: dco_decode_bool(arr[2]),
+      ports: dco_decode_list_firewall_port_status_c(arr[3]),
+      detail: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  EventBusSubscriber
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return EventBusSubscriberImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  JoinHandle
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return JoinHandleImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  JoinHandleResultStringString
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return JoinHandleResultStringStringImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  PeerRoutePair
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return PeerRoutePairImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  EventBusSubscriber
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return EventBusSubscriberImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  JoinHandle
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return JoinHandleImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  JoinHandleResultStringString
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return JoinHandleResultStringStringImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  PeerRoutePair
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return PeerRoutePairImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RustStreamSink<AppCallControlC> sse_decode_StreamSink_app_call_control_c_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<AppInboundEventC>
+  sse_decode_StreamSink_app_inbound_event_c_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<AppStreamProgressC>
+  sse_decode_StreamSink_app_stream_progress_c_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<CredentialEventC> sse_decode_StreamSink_credential_event_c_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<FileOfferC> sse_decode_StreamSink_file_offer_c_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<FileTransferProgressC>
+  sse_decode_StreamSink_file_transfer_progress_c_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<InboundAppStreamC>
+  sse_decode_StreamSink_inbound_app_stream_c_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<Uint8List> sse_decode_StreamSink_list_prim_u_8_strict_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ListenerMappingC> sse_decode_StreamSink_listener_mapping_c_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<MessageEventC> sse_decode_StreamSink_message_event_c_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<PresenceEventC> sse_decode_StreamSink_presence_event_c_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AppCallControlC sse_decode_app_call_control_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_app_call_control_kind_c(deserializer);
+    var var_fromPeerId = sse_decode_u_32(deserializer);
+    var var_requestId = sse_decode_u_64(deserializer);
+    var var_token = sse_decode_u_64(deserializer);
+    var var_deadlineMs = sse_decode_u_64(deserializer);
+    return AppCallControlC(
+      kind: var_kind,
+      fromPeerId: var_fromPeerId,
+      requestId: var_requestId,
+      token: var_token,
+      deadlineMs: var_deadlineMs,
+    );
+  }
+
+  @protected
+  AppCallControlKindC sse_decode_app_call_control_kind_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AppCallControlKindC.values[inner];
+  }
+
+  @protected
+  AppCallDecodedC sse_decode_app_call_decoded_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_status = sse_decode_i_32(deserializer);
+    var var_errorMsg = sse_decode_String(deserializer);
+    var var_codec = sse_decode_payload_codec_c(deserializer);
+    var var_payload = sse_decode_list_prim_u_8_strict(deserializer);
+    return AppCallDecodedC(
+      status: var_status,
+      errorMsg: var_errorMsg,
+      codec: var_codec,
+      payload: var_payload,
+    );
+  }
+
+  @protected
+  AppCallResultC sse_decode_app_call_result_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_status = sse_decode_i_32(deserializer);
+    var var_errorMsg = sse_decode_String(deserializer);
+    var var_payload = sse_decode_list_prim_u_8_strict(deserializer);
+    return AppCallResultC(
+      status: var_status,
+      errorMsg: var_errorMsg,
+      payload: var_payload,
+    );
+  }
+
+  @protected
+  AppInboundEventC sse_decode_app_inbound_event_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_app_inbound_kind_c(deserializer);
+    var var_fromPeerId = sse_decode_u_32(deserializer);
+    var var_channel = sse_decode_String(deserializer);
+    var var_requestId = sse_decode_u_64(deserializer);
+    var var_token = sse_decode_u_64(deserializer);
+    var var_payload = sse_decode_list_prim_u_8_strict(deserializer);
+    return AppInboundEventC(
+      kind: var_kind,
+      fromPeerId: var_fromPeerId,
+      channel: var_channel,
+      requestId: var_requestId,
+      token: var_token,
+      payload: var_payload,
+    );
+  }
+
+  @protected
+  AppInboundKindC sse_decode_app_inbound_kind_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AppInboundKindC.values[inner];
+  }
+
+  @protected
+  AppRpcCountersC sse_decode_app_rpc_counters_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_callsSent = sse_decode_u_64(deserializer);
+    var var_callsReceived = sse_decode_u_64(deserializer);
+    var var_notifiesSent = sse_decode_u_64(deserializer);
+    var var_notifiesReceived = sse_decode_u_64(deserializer);
+    var var_repliesSent = sse_decode_u_64(deserializer);
+    var var_repliesReceived = sse_decode_u_64(deserializer);
+    var var_timeouts = sse_decode_u_64(deserializer);
+    var var_noSubscriber = sse_decode_u_64(deserializer);
+    var var_serviceDropped = sse_decode_u_64(deserializer);
+    var var_cancelled = sse_decode_u_64(deserializer);
+    var var_sendErrors = sse_decode_u_64(deserializer);
+    return AppRpcCountersC(
+      callsSent: var_callsSent,
+      callsReceived: var_callsReceived,
+      notifiesSent: var_notifiesSent,
+      notifiesReceived: var_notifiesReceived,
+      repliesSent: var_repliesSent,
+      repliesReceived: var_repliesReceived,
+      timeouts: var_timeouts,
+      noSubscriber: var_noSubscriber,
+      serviceDropped: var_serviceDropped,
+      cancelled: var_cancelled,
+      sendErrors: var_sendErrors,
+    );
+  }
+
+  @protected
+  AppRpcMetricsC sse_decode_app_rpc_metrics_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_laggedEvents = sse_decode_u_64(deserializer);
+    var var_channels = sse_decode_list_channel_metrics_c(deserializer);
+    var var_peers = sse_decode_list_peer_metrics_c(deserializer);
+    return AppRpcMetricsC(
+      laggedEvents: var_laggedEvents,
+      channels: var_channels,
+      peers: var_peers,
+    );
+  }
+
+  @protected
+  AppRpcPolicyC sse_decode_app_rpc_policy_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxPayloadBytes = sse_decode_u_32(deserializer);
+    var var_peerRate = sse_decode_rate_limit_c(deserializer);
+    var var_channelRate = sse_decode_rate_limit_c(deserializer);
+    var var_channelRules = sse_decode_list_peer_channel_rule_c(deserializer);
+    return AppRpcPolicyC(
+      maxPayloadBytes: var_maxPayloadBytes,
+      peerRate: var_peerRate,
+      channelRate: var_channelRate,
+      channelRules: var_channelRules,
+    );
+  }
+
+  @protected
+  AppRpcRejectionsC sse_decode_app_rpc_rejections_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_total = sse_decode_u_64(deserializer);
+    var var_byReason = sse_decode_list_reason_rejections_c(deserializer);
+    var var_byPeer = sse_decode_list_peer_rejections_c(deserializer);
+    var var_byChannel = sse_decode_list_channel_rejections_c(deserializer);
+    return AppRpcRejectionsC(
+      total: var_total,
+      byReason: var_byReason,
+      byPeer: var_byPeer,
+      byChannel: var_byChannel,
+    );
+  }
+
+  @protected
+  AppStreamProgressC sse_decode_app_stream_progress_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_state = sse_decode_app_stream_state_c(deserializer);
+    var var_bytes = sse_decode_u_64(deserializer);
+    var var_totalLen = sse_decode_u_64(deserializer);
+    var var_error = sse_decode_String(deserializer);
+    return AppStreamProgressC(
+      state: var_state,
+      bytes: var_bytes,
+      totalLen: var_totalLen,
+      error: var_error,
+    );
+  }
+
+  @protected
+  AppStreamStateC sse_decode_app_stream_state_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AppStreamStateC.values[inner];
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  AppRpcPolicyC sse_decode_box_autoadd_app_rpc_policy_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_app_rpc_policy_c(deserializer));
+  }
+
+  @protected
+  BroadcastScopeC sse_decode_box_autoadd_broadcast_scope_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_broadcast_scope_c(deserializer));
+  }
+
+  @protected
+  FlagsC sse_decode_box_autoadd_flags_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_flags_c(deserializer));
+  }
+
+  @protected
+  Forward sse_decode_box_autoadd_forward(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_forward(deserializer));
+  }
+
+  @protected
+  LocalEventC sse_decode_box_autoadd_local_event_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_local_event_c(deserializer));
+  }
+
+  @protected
+  NodeKeyInfoC sse_decode_box_autoadd_node_key_info_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_node_key_info_c(deserializer));
+  }
+
+  @protected
+  PresenceProfileC sse_decode_box_autoadd_presence_profile_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_presence_profile_c(deserializer));
+  }
+
+  @protected
+  BroadcastDeliveryC sse_decode_broadcast_delivery_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peerId = sse_decode_u_32(deserializer);
+    var var_delivered = sse_decode_bool(deserializer);
+    var var_error = sse_decode_String(deserializer);
+    return BroadcastDeliveryC(
+      peerId: var_peerId,
+      delivered: var_delivered,
+      error: var_error,
+    );
+  }
+
+  @protected
+  BroadcastScopeC sse_decode_broadcast_scope_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_broadcast_scope_kind_c(deserializer);
+    var var_peerIds = sse_decode_list_prim_u_32_strict(deserializer);
+    return BroadcastScopeC(kind: var_kind, peerIds: var_peerIds);
+  }
+
+  @protected
+  BroadcastScopeKindC sse_decode_broadcast_scope_kind_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return BroadcastScopeKindC.values[inner];
+  }
+
+  @protected
+  ChannelMetricsC sse_decode_channel_metrics_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_channel = sse_decode_String(deserializer);
+    var var_counters = sse_decode_app_rpc_counters_c(deserializer);
+    var var_callLatency = sse_decode_latency_histogram_c(deserializer);
+    var var_handlerLatency = sse_decode_latency_histogram_c(deserializer);
+    return ChannelMetricsC(
+      channel: var_channel,
+      counters: var_counters,
+      callLatency: var_callLatency,
+      handlerLatency: var_handlerLatency,
+    );
+  }
+
+  @protected
+  ChannelRejectionsC sse_decode_channel_rejections_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_channel = sse_decode_String(deserializer);
+    var var_count = sse_decode_u_64(deserializer);
+    return ChannelRejectionsC(channel: var_channel, count: var_count);
+  }
+
+  @protected
+  ChatMessageC sse_decode_chat_message_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_kind = sse_decode_chat_message_kind_c(deserializer);
+    var var_fromPeerId = sse_decode_u_32(deserializer);
+    var var_toPeerId = sse_decode_u_32(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_sentAtMs = sse_decode_u_64(deserializer);
+    var var_outgoing = sse_decode_bool(deserializer);
+    var var_deliveredTo = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_readBy = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_queuedFor = sse_decode_list_prim_u_32_strict(deserializer);
+    return ChatMessageC(
+      id: var_id,
+      kind: var_kind,
+      fromPeerId: var_fromPeerId,
+      toPeerId: var_toPeerId,
+      text: var_text,
+      sentAtMs: var_sentAtMs,
+      outgoing: var_outgoing,
+      deliveredTo: var_deliveredTo,
+      readBy: var_readBy,
+      queuedFor: var_queuedFor,
+    );
+  }
+
+  @protected
+  ChatMessageKindC sse_decode_chat_message_kind_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ChatMessageKindC.values[inner];
+  }
+
+  @protected
+  ConnectErrorC sse_decode_connect_error_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_dst = sse_decode_String(deserializer);
+    var var_ipVersion = sse_decode_String(deserializer);
+    var var_error = sse_decode_String(deserializer);
+    var var_timestampMs = sse_decode_u_64(deserializer);
+    return ConnectErrorC(
+      dst: var_dst,
+      ipVersion: var_ipVersion,
+      error: var_error,
+      timestampMs: var_timestampMs,
+    );
+  }
+
+  @protected
+  CredentialEventC sse_decode_credential_event_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_credential_event_kind_c(deserializer);
+    var var_serverUrl = sse_decode_String(deserializer);
+    var var_publicKey = sse_decode_String(deserializer);
+    var var_fingerprint = sse_decode_String(deserializer);
+    return CredentialEventC(
+      kind: var_kind,
+      serverUrl: var_serverUrl,
+      publicKey: var_publicKey,
+      fingerprint: var_fingerprint,
+    );
+  }
+
+  @protected
+  CredentialEventKindC sse_decode_credential_event_kind_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CredentialEventKindC.values[inner];
+  }
+
+  @protected
+  DecodedPayloadC sse_decode_decoded_payload_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_codec = sse_decode_payload_codec_c(deserializer);
+    var var_payload = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_tagged = sse_decode_bool(deserializer);
+    var var_peerAccepts = sse_decode_list_payload_codec_c(deserializer);
+    return DecodedPayloadC(
+      codec: var_codec,
+      payload: var_payload,
+      tagged: var_tagged,
+      peerAccepts: var_peerAccepts,
+    );
+  }
+
+  @protected
+  DiagnosticCheckC sse_decode_diagnostic_check_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_diagnostic_check_kind_c(deserializer);
+    var var_status = sse_decode_diagnostic_status_c(deserializer);
+    var var_target = sse_decode_String(deserializer);
+    var var_detail = sse_decode_String(deserializer);
+    var var_suggestion = sse_decode_String(deserializer);
+    return DiagnosticCheckC(
+      kind: var_kind,
+      status: var_status,
+      target: var_target,
+      detail: var_detail,
+      suggestion: var_suggestion,
+    );
+  }
+
+  @protected
+  DiagnosticCheckKindC sse_decode_diagnostic_check_kind_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DiagnosticCheckKindC.values[inner];
+  }
+
+  @protected
+  DiagnosticReportC sse_decode_diagnostic_report_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_overall = sse_decode_diagnostic_status_c(deserializer);
+    var var_checks = sse_decode_list_diagnostic_check_c(deserializer);
+    var var_recentConnectErrors = sse_decode_list_connect_error_c(deserializer);
+    return DiagnosticReportC(
+      overall: var_overall,
+      checks: var_checks,
+      recentConnectErrors: var_recentConnectErrors,
+    );
+  }
+
+  @protected
+  DiagnosticStatusC sse_decode_diagnostic_status_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DiagnosticStatusC.values[inner];
+  }
+
+  @protected
+  EndpointOutcomeC sse_decode_endpoint_outcome_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return EndpointOutcomeC.values[inner];
+  }
+
+  @protected
+  EndpointResultC sse_decode_endpoint_result_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_outcome = sse_decode_endpoint_outcome_c(deserializer);
+    var var_error = sse_decode_String(deserializer);
+    return EndpointResultC(
+      url: var_url,
+      outcome: var_outcome,
+      error: var_error,
+    );
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FileOfferC sse_decode_file_offer_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fromPeerId = sse_decode_u_32(deserializer);
+    var var_transferId = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    var var_sha256 = sse_decode_String(deserializer);
+    return FileOfferC(
+      fromPeerId: var_fromPeerId,
+      transferId: var_transferId,
+      name: var_name,
+      size: var_size,
+      sha256: var_sha256,
+    );
+  }
+
+  @protected
+  FileTransferDirectionC sse_decode_file_transfer_direction_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FileTransferDirectionC.values[inner];
+  }
+
+  @protected
+  FileTransferProgressC sse_decode_file_transfer_progress_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_transferId = sse_decode_String(deserializer);
+    var var_peerId = sse_decode_u_32(deserializer);
+    var var_direction = sse_decode_file_transfer_direction_c(deserializer);
+    var var_state = sse_decode_file_transfer_state_c(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_bytes = sse_decode_u_64(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    var var_error = sse_decode_String(deserializer);
+    return FileTransferProgressC(
+      transferId: var_transferId,
+      peerId: var_peerId,
+      direction: var_direction,
+      state: var_state,
+      name: var_name,
+      bytes: var_bytes,
+      size: var_size,
+      error: var_error,
+    );
+  }
+
+  @protected
+  FileTransferStateC sse_decode_file_transfer_state_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FileTransferStateC.values[inner];
+  }
+
+  @protected
+  FirewallBackendC sse_decode_firewall_backend_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FirewallBackendC.values[inner];
+  }
+
+  @protected
+  FirewallDiagnosisC sse_decode_firewall_diagnosis_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_backend = sse_decode_firewall_backend_c(deserializer);
+    var var_active = sse_decode_bool(deserializer);
+    var var_findings = sse_decode_list_firewall_finding_c(deserializer);
+    return FirewallDiagnosisC(
+      backend: var_backend,
+      active: var_active,
+      findings: var_findings,
+    );
+  }
+
+  @protected
+  FirewallError sse_decode_firewall_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_firewall_error_kind(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return FirewallError(kind: var_kind, message: var_message);
+  }
+
+  @protected
+  FirewallErrorKind sse_decode_firewall_error_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FirewallErrorKind.values[inner];
+  }
+
+  @protected
+  FirewallFindingC sse_decode_firewall_finding_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rule = sse_decode_firewall_rule_c(deserializer);
+    var var_blocked = sse_decode_bool(deserializer);
+    var var_blockingRule = sse_decode_String(deserializer);
+    var var_suggestion = sse_decode_String(deserializer);
+    return FirewallFindingC(
+      rule: var_rule,
+      blocked: var_blocked,
+      blockingRule: var_blockingRule,
+      suggestion: var_suggestion,
+    );
+  }
+
+  @protected
+  FirewallPortC sse_decode_firewall_port_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_proto = sse_decode_String(deserializer);
+    var var_port = sse_decode_u_16(deserializer);
+    return FirewallPortC(proto: var_proto, port: var_port);
+  }
+
+  @protected
+  FirewallPortStatusC sse_decode_firewall_port_status_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_proto = sse_decode_String(deserializer);
+    var var_port = sse_decode_u_16(deserializer);
+    var var_allowed = sse_decode_bool(deserializer);
+    return FirewallPortStatusC(
+      proto: var_proto,
+      port: var_port,
+      allowed: var_allowed,
+    );
+  }
+
+  @protected
+  FirewallProfile sse_decode_firewall_profile(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FirewallProfile.values[inner];
+  }
+
+  @protected
+  FirewallProfileStatusC sse_decode_firewall_profile_status_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_profile = sse_decode_firewall_profile(deserializer);
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_current = sse_decode_bool(deserializer);
+    return FirewallProfileStatusC(
+      profile: var_profile,
+      enabled: var_enabled,
+      current: var_current,
+    );
+  }
+
+  @protected
+  FirewallRuleC sse_decode_firewall_rule_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_firewall_rule_kind_c(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_proto = sse_decode_String(deserializer);
+    var var_port = sse_decode_u_16(deserializer);
+    var var_detail = sse_decode_String(deserializer);
+    return FirewallRuleC(
+      kind: var_kind,
+      name: var_name,
+      proto: var_proto,
+      port: var_port,
+      detail: var_detail,
+    );
+  }
+
+  @protected
+  FirewallRuleKindC sse_decode_firewall_rule_kind_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FirewallRuleKindC.values[inner];
+  }
+
+  @protected
+  FirewallSnapshotC sse_decode_firewall_snapshot_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_backend = sse_decode_firewall_backend_c(deserializer);
+    var var_profiles = sse_decode_list_firewall_profile_status_c(deserializer);
+    var var_currentProfiles = sse_decode_list_firewall_profile(deserializer);
+    return FirewallSnapshotC(
+      backend: var_backend,
+      profiles: var_profiles,
+      currentProfiles: var_currentProfiles,
+    );
+  }
+
+  @protected
+  FlagsC sse_decode_flags_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_defaultProtocol = sse_decode_String(deserializer);
+    var var_devName = sse_decode_String(deserializer);
+    var var_enableEncryption = sse_decode_bool(deserializer);
+    var var_enableIpv6 = sse_decode_bool(deserializer);
+    var var_mtu = sse_decode_u_32(deserializer);
+    var var_latencyFirst = sse_decode_bool(deserializer);
+    var var_enableExitNode = sse_decode_bool(deserializer);
+    var var_noTun = sse_decode_bool(deserializer);
+    var var_useSmoltcp = sse_decode_bool(deserializer);
+    var var_relayNetworkWhitelist = sse_decode_String(deserializer);
+    var var_disableP2P = sse_decode_bool(deserializer);
+    var var_relayAllPeerRpc = sse_decode_bool(deserializer);
+    var var_disableUdpHolePunching = sse_decode_bool(deserializer);
+    var var_disableTcpHolePunching = sse_decode_bool(deserializer);
+    var var_multiThread = sse_decode_bool(deserializer);
+    var var_dataCompressAlgo = sse_decode_i_32(deserializer);
+    var var_bindDevice = sse_decode_bool(deserializer);
+    var var_enableKcpProxy = sse_decode_bool(deserializer);
+    var var_disableKcpInput = sse_decode_bool(deserializer);
+    var var_disableRelayKcp = sse_decode_bool(deserializer);
+    var var_proxyForwardBySystem = sse_decode_bool(deserializer);
+    var var_acceptDns = sse_decode_bool(deserializer);
+    var var_privateMode = sse_decode_bool(deserializer);
+    var var_enableQuicProxy = sse_decode_bool(deserializer);
+    var var_disableQuicInput = sse_decode_bool(deserializer);
+    var var_disableSymHolePunching = sse_decode_bool(deserializer);
+    var var_tcpWhitelist = sse_decode_String(deserializer);
+    var var_udpWhitelist = sse_decode_String(deserializer);
+    var var_secureMode = sse_decode_bool(deserializer);
+    return FlagsC(
+      defaultProtocol: var_defaultProtocol,
+      devName: var_devName,
+      enableEncryption: var_enableEncryption,
+      enableIpv6: var_enableIpv6,
+      mtu: var_mtu,
+      latencyFirst: var_latencyFirst,
+      enableExitNode: var_enableExitNode,
+      noTun: var_noTun,
+      useSmoltcp: var_useSmoltcp,
+      relayNetworkWhitelist: var_relayNetworkWhitelist,
+      disableP2P: var_disableP2P,
+      relayAllPeerRpc: var_relayAllPeerRpc,
+      disableUdpHolePunching: var_disableUdpHolePunching,
+      disableTcpHolePunching: var_disableTcpHolePunching,
+      multiThread: var_multiThread,
+      dataCompressAlgo: var_dataCompressAlgo,
+      bindDevice: var_bindDevice,
+      enableKcpProxy: var_enableKcpProxy,
+      disableKcpInput: var_disableKcpInput,
+      disableRelayKcp: var_disableRelayKcp,
+      proxyForwardBySystem: var_proxyForwardBySystem,
+      acceptDns: var_acceptDns,
+      privateMode: var_privateMode,
+      enableQuicProxy: var_enableQuicProxy,
+      disableQuicInput: var_disableQuicInput,
+      disableSymHolePunching: var_disableSymHolePunching,
+      tcpWhitelist: var_tcpWhitelist,
+      udpWhitelist: var_udpWhitelist,
+      secureMode: var_secureMode,
+    );
+  }
+
+  @protected
+  Forward sse_decode_forward(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bindAddr = sse_decode_String(deserializer);
+    var var_dstAddr = sse_decode_String(deserializer);
+    var var_proto = sse_decode_String(deserializer);
+    return Forward(
+      bindAddr: var_bindAddr,
+      dstAddr: var_dstAddr,
+      proto: var_proto,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  InboundAppStreamC sse_decode_inbound_app_stream_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fromPeerId = sse_decode_u_32(deserializer);
+    var var_streamId = sse_decode_u_64(deserializer);
+    var var_channel = sse_decode_String(deserializer);
+    var var_totalLen = sse_decode_u_64(deserializer);
+    var var_metadata = sse_decode_list_prim_u_8_strict(deserializer);
+    return InboundAppStreamC(
+      fromPeerId: var_fromPeerId,
+      streamId: var_streamId,
+      channel: var_channel,
+      totalLen: var_totalLen,
+      metadata: var_metadata,
+    );
+  }
+
+  @protected
+  KVNetworkStatus sse_decode_kv_network_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_totalNodes = sse_decode_usize(deserializer);
+    var var_nodes = sse_decode_list_kv_node_info(deserializer);
+    return KVNetworkStatus(totalNodes: var_totalNodes, nodes: var_nodes);
+  }
+
+  @protected
+  KVNodeConnectionStats sse_decode_kv_node_connection_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_connType = sse_decode_String(deserializer);
+    var var_rxBytes = sse_decode_u_64(deserializer);
+    var var_txBytes = sse_decode_u_64(deserializer);
+    var var_rxPackets = sse_decode_u_64(deserializer);
+    var var_txPackets = sse_decode_u_64(deserializer);
+    return KVNodeConnectionStats(
+      connType: var_connType,
+      rxBytes: var_rxBytes,
+      txBytes: var_txBytes,
+      rxPackets: var_rxPackets,
+      txPackets: var_txPackets,
+    );
+  }
+
+  @protected
+  KVNodeInfo sse_decode_kv_node_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peerId = sse_decode_u_32(deserializer);
+    var var_hostname = sse_decode_String(deserializer);
+    var var_ipv4 = sse_decode_String(deserializer);
+    var var_ipv6 = sse_decode_String(deserializer);
+    var var_latencyMs = sse_decode_f_64(deserializer);
+    var var_nat = sse_decode_String(deserializer);
+    var var_hops = sse_decode_list_node_hop_stats(deserializer);
+    var var_lossRate = sse_decode_f_32(deserializer);
+    var var_connections = sse_decode_list_kv_node_connection_stats(
+      deserializer,
+    );
+    var var_tunnelProto = sse_decode_String(deserializer);
+    var var_connType = sse_decode_String(deserializer);
+    var var_rxBytes = sse_decode_u_64(deserializer);
+    var var_txBytes = sse_decode_u_64(deserializer);
+    var var_version = sse_decode_String(deserializer);
+    var var_cost = sse_decode_i_32(deserializer);
+    return KVNodeInfo(
+      peerId: var_peerId,
+      hostname: var_hostname,
+      ipv4: var_ipv4,
+      ipv6: var_ipv6,
+      latencyMs: var_latencyMs,
+      nat: var_nat,
+      hops: var_hops,
+      lossRate: var_lossRate,
+      connections: var_connections,
+      tunnelProto: var_tunnelProto,
+      connType: var_connType,
+      rxBytes: var_rxBytes,
+      txBytes: var_txBytes,
+      version: var_version,
+      cost: var_cost,
+    );
+  }
+
+  @protected
+  LatencyHistogramC sse_decode_latency_histogram_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_boundsMs = sse_decode_list_prim_f_64_strict(deserializer);
+    var var_counts = sse_decode_list_prim_u_64_strict(deserializer);
+    var var_count = sse_decode_u_64(deserializer);
+    var var_sumMs = sse_decode_f_64(deserializer);
+    return LatencyHistogramC(
+      boundsMs: var_boundsMs,
+      counts: var_counts,
+      count: var_count,
+      sumMs: var_sumMs,
+    );
+  }
+
+  @protected
+  List<PeerRoutePair>
+  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PeerRoutePair>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+          deserializer,
+        ),
+      );
+    }
+    return ans_;
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BroadcastDeliveryC> sse_decode_list_broadcast_delivery_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BroadcastDeliveryC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_broadcast_delivery_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ChannelMetricsC> sse_decode_list_channel_metrics_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ChannelMetricsC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_channel_metrics_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ChannelRejectionsC> sse_decode_list_channel_rejections_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ChannelRejectionsC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_channel_rejections_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ChatMessageC> sse_decode_list_chat_message_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ChatMessageC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_chat_message_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ConnectErrorC> sse_decode_list_connect_error_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ConnectErrorC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_connect_error_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DiagnosticCheckC> sse_decode_list_diagnostic_check_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DiagnosticCheckC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_diagnostic_check_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FileOfferC> sse_decode_list_file_offer_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FileOfferC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_file_offer_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FirewallFindingC> sse_decode_list_firewall_finding_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FirewallFindingC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_firewall_finding_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FirewallPortC> sse_decode_list_firewall_port_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FirewallPortC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_firewall_port_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FirewallPortStatusC> sse_decode_list_firewall_port_status_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FirewallPortStatusC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_firewall_port_status_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FirewallProfile> sse_decode_list_firewall_profile(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FirewallProfile>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_firewall_profile(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FirewallProfileStatusC> sse_decode_list_firewall_profile_status_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FirewallProfileStatusC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_firewall_profile_status_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FirewallRuleC> sse_decode_list_firewall_rule_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FirewallRuleC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_firewall_rule_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Forward> sse_decode_list_forward(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Forward>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_forward(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<KVNodeConnectionStats> sse_decode_list_kv_node_connection_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <KVNodeConnectionStats>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_kv_node_connection_stats(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<KVNodeInfo> sse_decode_list_kv_node_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <KVNodeInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_kv_node_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ListenerMappingC> sse_decode_list_listener_mapping_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ListenerMappingC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_listener_mapping_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NatCompatibilityC> sse_decode_list_nat_compatibility_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NatCompatibilityC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_nat_compatibility_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NodeHopStats> sse_decode_list_node_hop_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NodeHopStats>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_node_hop_stats(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PayloadCodecC> sse_decode_list_payload_codec_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PayloadCodecC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_payload_codec_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PeerChannelRuleC> sse_decode_list_peer_channel_rule_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PeerChannelRuleC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_peer_channel_rule_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PeerConnectionC> sse_decode_list_peer_connection_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PeerConnectionC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_peer_connection_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PeerMetricsC> sse_decode_list_peer_metrics_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PeerMetricsC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_peer_metrics_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PeerPresenceC> sse_decode_list_peer_presence_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PeerPresenceC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_peer_presence_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PeerProxyCidrsC> sse_decode_list_peer_proxy_cidrs_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PeerProxyCidrsC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_peer_proxy_cidrs_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PeerRejectionsC> sse_decode_list_peer_rejections_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PeerRejectionsC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_peer_rejections_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PinnedServerKeyC> sse_decode_list_pinned_server_key_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PinnedServerKeyC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pinned_server_key_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PortForwardC> sse_decode_list_port_forward_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PortForwardC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_port_forward_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PortMappingRequestC> sse_decode_list_port_mapping_request_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PortMappingRequestC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_port_mapping_request_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat64List(len_);
+  }
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint64List(len_);
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<ProxyCidrC> sse_decode_list_proxy_cidr_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ProxyCidrC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_proxy_cidr_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ProxyCidrConflictC> sse_decode_list_proxy_cidr_conflict_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ProxyCidrConflictC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_proxy_cidr_conflict_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ReasonRejectionsC> sse_decode_list_reason_rejections_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ReasonRejectionsC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_reason_rejections_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SelectedServerC> sse_decode_list_selected_server_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SelectedServerC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_selected_server_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ServerProbeResultC> sse_decode_list_server_probe_result_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ServerProbeResultC>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_server_probe_result_c(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  ListenerMappingC sse_decode_listener_mapping_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_localListener = sse_decode_String(deserializer);
+    var var_mappedListener = sse_decode_String(deserializer);
+    var var_backend = sse_decode_String(deserializer);
+    var var_state = sse_decode_port_mapping_state_c(deserializer);
+    var var_managedByEasytier = sse_decode_bool(deserializer);
+    var var_leaseRemainingSecs = sse_decode_u_32(deserializer);
+    var var_error = sse_decode_String(deserializer);
+    return ListenerMappingC(
+      localListener: var_localListener,
+      mappedListener: var_mappedListener,
+      backend: var_backend,
+      state: var_state,
+      managedByEasytier: var_managedByEasytier,
+      leaseRemainingSecs: var_leaseRemainingSecs,
+      error: var_error,
+    );
+  }
+
+  @protected
+  LocalEndpointsC sse_decode_local_endpoints_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_listeners = sse_decode_list_String(deserializer);
+    var var_mappedListeners = sse_decode_list_String(deserializer);
+    return LocalEndpointsC(
+      listeners: var_listeners,
+      mappedListeners: var_mappedListeners,
+    );
+  }
+
+  @protected
+  LocalEventC sse_decode_local_event_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_instanceId = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_seq = sse_decode_u_64(deserializer);
+    return LocalEventC(
+      instanceId: var_instanceId,
+      message: var_message,
+      seq: var_seq,
+    );
+  }
+
+  @protected
+  MessageEventC sse_decode_message_event_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_message_event_kind_c(deserializer);
+    var var_peerId = sse_decode_u_32(deserializer);
+    var var_message = sse_decode_chat_message_c(deserializer);
+    var var_error = sse_decode_String(deserializer);
+    return MessageEventC(
+      kind: var_kind,
+      peerId: var_peerId,
+      message: var_message,
+      error: var_error,
+    );
+  }
+
+  @protected
+  MessageEventKindC sse_decode_message_event_kind_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MessageEventKindC.values[inner];
+  }
+
+  @protected
+  NatCompatibilityC sse_decode_nat_compatibility_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peerNatType = sse_decode_nat_type_c(deserializer);
+    var var_likelihood = sse_decode_p_2_p_likelihood_c(deserializer);
+    var var_explanation = sse_decode_String(deserializer);
+    return NatCompatibilityC(
+      peerNatType: var_peerNatType,
+      likelihood: var_likelihood,
+      explanation: var_explanation,
+    );
+  }
+
+  @protected
+  NatDetectionC sse_decode_nat_detection_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_udpNatType = sse_decode_nat_type_c(deserializer);
+    var var_tcpNatType = sse_decode_nat_type_c(deserializer);
+    var var_udpPortMapping = sse_decode_port_mapping_c(deserializer);
+    var var_publicIps = sse_decode_list_String(deserializer);
+    var var_mappedUdpAddr = sse_decode_String(deserializer);
+    var var_minPort = sse_decode_u_32(deserializer);
+    var var_maxPort = sse_decode_u_32(deserializer);
+    var var_compatibility = sse_decode_list_nat_compatibility_c(deserializer);
+    return NatDetectionC(
+      udpNatType: var_udpNatType,
+      tcpNatType: var_tcpNatType,
+      udpPortMapping: var_udpPortMapping,
+      publicIps: var_publicIps,
+      mappedUdpAddr: var_mappedUdpAddr,
+      minPort: var_minPort,
+      maxPort: var_maxPort,
+      compatibility: var_compatibility,
+    );
+  }
+
+  @protected
+  NatTypeC sse_decode_nat_type_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return NatTypeC.values[inner];
+  }
+
+  @protected
+  NodeHopStats sse_decode_node_hop_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peerId = sse_decode_u_32(deserializer);
+    var var_targetIp = sse_decode_String(deserializer);
+    var var_latencyMs = sse_decode_f_64(deserializer);
+    var var_packetLoss = sse_decode_f_32(deserializer);
+    var var_nodeName = sse_decode_String(deserializer);
+    return NodeHopStats(
+      peerId: var_peerId,
+      targetIp: var_targetIp,
+      latencyMs: var_latencyMs,
+      packetLoss: var_packetLoss,
+      nodeName: var_nodeName,
+    );
+  }
+
+  @protected
+  NodeKeyInfoC sse_decode_node_key_info_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_publicKey = sse_decode_String(deserializer);
+    var var_fingerprint = sse_decode_String(deserializer);
+    return NodeKeyInfoC(publicKey: var_publicKey, fingerprint: var_fingerprint);
+  }
+
+  @protected
+  AppRpcPolicyC? sse_decode_opt_box_autoadd_app_rpc_policy_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_app_rpc_policy_c(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  LocalEventC? sse_decode_opt_box_autoadd_local_event_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_local_event_c(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  NodeKeyInfoC? sse_decode_opt_box_autoadd_node_key_info_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_node_key_info_c(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PresenceProfileC? sse_decode_opt_box_autoadd_presence_profile_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_presence_profile_c(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  P2pLikelihoodC sse_decode_p_2_p_likelihood_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return P2pLikelihoodC.values[inner];
+  }
+
+  @protected
+  PayloadCodecC sse_decode_payload_codec_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PayloadCodecC.values[inner];
+  }
+
+  @protected
+  PeerChannelRuleC sse_decode_peer_channel_rule_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peerId = sse_decode_u_32(deserializer);
+    var var_allow = sse_decode_list_String(deserializer);
+    var var_deny = sse_decode_list_String(deserializer);
+    return PeerChannelRuleC(
+      peerId: var_peerId,
+      allow: var_allow,
+      deny: var_deny,
+    );
+  }
+
+  @protected
+  PeerConnectionC sse_decode_peer_connection_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_connId = sse_decode_String(deserializer);
+    var var_tunnelType = sse_decode_String(deserializer);
+    var var_remoteAddr = sse_decode_String(deserializer);
+    var var_isClient = sse_decode_bool(deserializer);
+    var var_connectorUrl = sse_decode_String(deserializer);
+    return PeerConnectionC(
+      connId: var_connId,
+      tunnelType: var_tunnelType,
+      remoteAddr: var_remoteAddr,
+      isClient: var_isClient,
+      connectorUrl: var_connectorUrl,
+    );
+  }
+
+  @protected
+  PeerMetricsC sse_decode_peer_metrics_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peerId = sse_decode_u_32(deserializer);
+    var var_counters = sse_decode_app_rpc_counters_c(deserializer);
+    var var_callLatency = sse_decode_latency_histogram_c(deserializer);
+    var var_handlerLatency = sse_decode_latency_histogram_c(deserializer);
+    return PeerMetricsC(
+      peerId: var_peerId,
+      counters: var_counters,
+      callLatency: var_callLatency,
+      handlerLatency: var_handlerLatency,
+    );
+  }
+
+  @protected
+  PeerPresenceC sse_decode_peer_presence_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_node = sse_decode_kv_node_info(deserializer);
+    var var_profile = sse_decode_opt_box_autoadd_presence_profile_c(
+      deserializer,
+    );
+    return PeerPresenceC(node: var_node, profile: var_profile);
+  }
+
+  @protected
+  PeerProxyCidrsC sse_decode_peer_proxy_cidrs_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peerId = sse_decode_u_32(deserializer);
+    var var_hostname = sse_decode_String(deserializer);
+    var var_cidrs = sse_decode_list_String(deserializer);
+    return PeerProxyCidrsC(
+      peerId: var_peerId,
+      hostname: var_hostname,
+      cidrs: var_cidrs,
+    );
+  }
+
+  @protected
+  PeerRejectionsC sse_decode_peer_rejections_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peerId = sse_decode_u_32(deserializer);
+    var var_count = sse_decode_u_64(deserializer);
+    return PeerRejectionsC(peerId: var_peerId, count: var_count);
+  }
+
+  @protected
+  PinnedServerKeyC sse_decode_pinned_server_key_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_serverUrl = sse_decode_String(deserializer);
+    var var_publicKey = sse_decode_String(deserializer);
+    var var_fingerprint = sse_decode_String(deserializer);
+    return PinnedServerKeyC(
+      serverUrl: var_serverUrl,
+      publicKey: var_publicKey,
+      fingerprint: var_fingerprint,
+    );
+  }
+
+  @protected
+  PortForwardC sse_decode_port_forward_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_proto = sse_decode_String(deserializer);
+    var var_bindAddr = sse_decode_String(deserializer);
+    var var_dstAddr = sse_decode_String(deserializer);
+    var var_managed = sse_decode_bool(deserializer);
+    var var_activeConnections = sse_decode_u_32(deserializer);
+    var var_totalConnections = sse_decode_u_64(deserializer);
+    var var_txBytes = sse_decode_u_64(deserializer);
+    var var_rxBytes = sse_decode_u_64(deserializer);
+    var var_errors = sse_decode_u_64(deserializer);
+    var var_lastError = sse_decode_String(deserializer);
+    return PortForwardC(
+      proto: var_proto,
+      bindAddr: var_bindAddr,
+      dstAddr: var_dstAddr,
+      managed: var_managed,
+      activeConnections: var_activeConnections,
+      totalConnections: var_totalConnections,
+      txBytes: var_txBytes,
+      rxBytes: var_rxBytes,
+      errors: var_errors,
+      lastError: var_lastError,
+    );
+  }
+
+  @protected
+  PortMappingC sse_decode_port_mapping_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PortMappingC.values[inner];
+  }
+
+  @protected
+  PortMappingRequestC sse_decode_port_mapping_request_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_listener = sse_decode_String(deserializer);
+    var var_externalPort = sse_decode_u_16(deserializer);
+    return PortMappingRequestC(
+      listener: var_listener,
+      externalPort: var_externalPort,
+    );
+  }
+
+  @protected
+  PortMappingStateC sse_decode_port_mapping_state_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PortMappingStateC.values[inner];
+  }
+
+  @protected
+  PresenceEventC sse_decode_presence_event_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_presence_event_kind_c(deserializer);
+    var var_peerId = sse_decode_u_32(deserializer);
+    var var_profile = sse_decode_opt_box_autoadd_presence_profile_c(
+      deserializer,
+    );
+    return PresenceEventC(
+      kind: var_kind,
+      peerId: var_peerId,
+      profile: var_profile,
+    );
+  }
+
+  @protected
+  PresenceEventKindC sse_decode_presence_event_kind_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PresenceEventKindC.values[inner];
+  }
+
+  @protected
+  PresenceProfileC sse_decode_presence_profile_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_displayName = sse_decode_String(deserializer);
+    var var_avatarHash = sse_decode_String(deserializer);
+    var var_status = sse_decode_presence_status_c(deserializer);
+    var var_appName = sse_decode_String(deserializer);
+    var var_appVersion = sse_decode_String(deserializer);
+    var var_currentGame = sse_decode_String(deserializer);
+    return PresenceProfileC(
+      displayName: var_displayName,
+      avatarHash: var_avatarHash,
+      status: var_status,
+      appName: var_appName,
+      appVersion: var_appVersion,
+      currentGame: var_currentGame,
+    );
+  }
+
+  @protected
+  PresenceStatusC sse_decode_presence_status_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PresenceStatusC.values[inner];
+  }
+
+  @protected
+  ProxyCidrC sse_decode_proxy_cidr_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cidr = sse_decode_String(deserializer);
+    var var_mappedCidr = sse_decode_String(deserializer);
+    return ProxyCidrC(cidr: var_cidr, mappedCidr: var_mappedCidr);
+  }
+
+  @protected
+  ProxyCidrConflictC sse_decode_proxy_cidr_conflict_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_proxy_cidr_conflict_kind_c(deserializer);
+    var var_cidr = sse_decode_String(deserializer);
+    var var_otherCidr = sse_decode_String(deserializer);
+    var var_peerId = sse_decode_u_32(deserializer);
+    var var_hostname = sse_decode_String(deserializer);
+    var var_interface = sse_decode_String(deserializer);
+    return ProxyCidrConflictC(
+      kind: var_kind,
+      cidr: var_cidr,
+      otherCidr: var_otherCidr,
+      peerId: var_peerId,
+      hostname: var_hostname,
+      interface: var_interface,
+    );
+  }
+
+  @protected
+  ProxyCidrConflictKindC sse_decode_proxy_cidr_conflict_kind_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ProxyCidrConflictKindC.values[inner];
+  }
+
+  @protected
+  RateLimitC sse_decode_rate_limit_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_perSecond = sse_decode_u_32(deserializer);
+    var var_burst = sse_decode_u_32(deserializer);
+    return RateLimitC(perSecond: var_perSecond, burst: var_burst);
+  }
+
+  @protected
+  ReasonRejectionsC sse_decode_reason_rejections_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_reason = sse_decode_reject_reason_c(deserializer);
+    var var_count = sse_decode_u_64(deserializer);
+    return ReasonRejectionsC(reason: var_reason, count: var_count);
+  }
+
+  @protected
+  RejectReasonC sse_decode_reject_reason_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RejectReasonC.values[inner];
+  }
+
+  @protected
+  SelectedServerC sse_decode_selected_server_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_role = sse_decode_server_role_c(deserializer);
+    var var_latencyMs = sse_decode_u_32(deserializer);
+    var var_connected = sse_decode_bool(deserializer);
+    var var_reason = sse_decode_String(deserializer);
+    return SelectedServerC(
+      url: var_url,
+      role: var_role,
+      latencyMs: var_latencyMs,
+      connected: var_connected,
+      reason: var_reason,
+    );
+  }
+
+  @protected
+  ServerProbeResultC sse_decode_server_probe_result_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_scheme = sse_decode_String(deserializer);
+    var var_protocolSupported = sse_decode_bool(deserializer);
+    var var_resolvedAddr = sse_decode_String(deserializer);
+    var var_dnsMs = sse_decode_u_32(deserializer);
+    var var_reachable = sse_decode_bool(deserializer);
+    var var_latencyMs = sse_decode_u_32(deserializer);
+    var var_error = sse_decode_String(deserializer);
+    return ServerProbeResultC(
+      url: var_url,
+      scheme: var_scheme,
+      protocolSupported: var_protocolSupported,
+      resolvedAddr: var_resolvedAddr,
+      dnsMs: var_dnsMs,
+      reachable: var_reachable,
+      latencyMs: var_latencyMs,
+      error: var_error,
+    );
+  }
+
+  @protected
+  ServerRoleC sse_decode_server_role_c(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ServerRoleC.values[inner];
+  }
+
+  @protected
+  ServerSelectionStatusC sse_decode_server_selection_status_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_activeCount = sse_decode_u_32(deserializer);
+    var var_servers = sse_decode_list_selected_server_c(deserializer);
+    return ServerSelectionStatusC(
+      enabled: var_enabled,
+      activeCount: var_activeCount,
+      servers: var_servers,
+    );
+  }
+
+  @protected
+  TunFirewallStatusC sse_decode_tun_firewall_status_c(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_backend = sse_decode_firewall_backend_c(deserializer);
+    var var_active = sse_decode_bool(deserializer);
+    var var_tunAllowed = sse_decode_bool(deserializer);
+    var var_ports = sse_decode_list_firewall_port_status_c(deserializer);
+    var var_detail = sse_decode_String(deserializer);
+    return TunFirewallStatusC(
+      backend: var_backend,
+      active: var_active,
+      tunAllowed: var_tunAllowed,
+      ports: var_ports,
+      detail: var_detail,
+    );
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+    EventBusSubscriber self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as EventBusSubscriberImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
+    JoinHandle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as JoinHandleImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    JoinHandleResultStringString self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as JoinHandleResultStringStringImpl).frbInternalSseEncode(
+        move: true,
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    PeerRoutePair self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as PeerRoutePairImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+    EventBusSubscriber self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as EventBusSubscriberImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
+    JoinHandle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as JoinHandleImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    JoinHandleResultStringString self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as JoinHandleResultStringStringImpl).frbInternalSseEncode(
+        move: null,
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    PeerRoutePair self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as PeerRoutePairImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_app_call_control_c_Sse(
+    RustStreamSink<AppCallControlC> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_app_call_control_c,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_app_inbound_event_c_Sse(
+    RustStreamSink<AppInboundEventC> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_app_inbound_event_c,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_app_stream_progress_c_Sse(
+    RustStreamSink<AppStreamProgressC> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_app_stream_progress_c,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_credential_event_c_Sse(
+    RustStreamSink<CredentialEventC> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_credential_event_c,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_file_offer_c_Sse(
+    RustStreamSink<FileOfferC> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_file_offer_c,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_file_transfer_progress_c_Sse(
+    RustStreamSink<FileTransferProgressC> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_file_transfer_progress_c,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_inbound_app_stream_c_Sse(
+    RustStreamSink<InboundAppStreamC> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_inbound_app_stream_c,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_list_prim_u_8_strict_Sse(
+    RustStreamSink<Uint8List> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_listener_mapping_c_Sse(
+    RustStreamSink<ListenerMappingC> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_listener_mapping_c,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_message_event_c_Sse(
+    RustStreamSink<MessageEventC> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_message_event_c,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_presence_event_c_Sse(
+    RustStreamSink<PresenceEventC> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_presence_event_c,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_app_call_control_c(
+    AppCallControlC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_app_call_control_kind_c(self.kind, serializer);
+    sse_encode_u_32(self.fromPeerId, serializer);
+    sse_encode_u_64(self.requestId, serializer);
+    sse_encode_u_64(self.token, serializer);
+    sse_encode_u_64(self.deadlineMs, serializer);
+  }
+
+  @protected
+  void sse_encode_app_call_control_kind_c(
+    AppCallControlKindC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_app_call_decoded_c(
+    AppCallDecodedC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.status, serializer);
+    sse_encode_String(self.errorMsg, serializer);
+    sse_encode_payload_codec_c(self.codec, serializer);
+    sse_encode_list_prim_u_8_strict(self.payload, serializer);
+  }
+
+  @protected
+  void sse_encode_app_call_result_c(
+    AppCallResultC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.status, serializer);
+    sse_encode_String(self.errorMsg, serializer);
+    sse_encode_list_prim_u_8_strict(self.payload, serializer);
+  }
+
+  @protected
+  void sse_encode_app_inbound_event_c(
+    AppInboundEventC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_app_inbound_kind_c(self.kind, serializer);
+    sse_encode_u_32(self.fromPeerId, serializer);
+    sse_encode_String(self.channel, serializer);
+    sse_encode_u_64(self.requestId, serializer);
+    sse_encode_u_64(self.token, serializer);
+    sse_encode_list_prim_u_8_strict(self.payload, serializer);
+  }
+
+  @protected
+  void sse_encode_app_inbound_kind_c(
+    AppInboundKindC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_app_rpc_counters_c(
+    AppRpcCountersC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.callsSent, serializer);
+    sse_encode_u_64(self.callsReceived, serializer);
+    sse_encode_u_64(self.notifiesSent, serializer);
+    sse_encode_u_64(self.notifiesReceived, serializer);
+    sse_encode_u_64(self.repliesSent, serializer);
+    sse_encode_u_64(self.repliesReceived, serializer);
+    sse_encode_u_64(self.timeouts, serializer);
+    sse_encode_u_64(self.noSubscriber, serializer);
+    sse_encode_u_64(self.serviceDropped, serializer);
+    sse_encode_u_64(self.cancelled, serializer);
+    sse_encode_u_64(self.sendErrors, serializer);
+  }
+
+  @protected
+  void sse_encode_app_rpc_metrics_c(
+    AppRpcMetricsC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.laggedEvents, serializer);
+    sse_encode_list_channel_metrics_c(self.channels, serializer);
+    sse_encode_list_peer_metrics_c(self.peers, serializer);
+  }
+
+  @protected
+  void sse_encode_app_rpc_policy_c(
+    AppRpcPolicyC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.maxPayloadBytes, serializer);
+    sse_encode_rate_limit_c(self.peerRate, serializer);
+    sse_encode_rate_limit_c(self.channelRate, serializer);
+    sse_encode_list_peer_channel_rule_c(self.channelRules, serializer);
+  }
+
+  @protected
+  void sse_encode_app_rpc_rejections_c(
+    AppRpcRejectionsC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.total, serializer);
+    sse_encode_list_reason_rejections_c(self.byReason, serializer);
+    sse_encode_list_peer_rejections_c(self.byPeer, serializer);
+    sse_encode_list_channel_rejections_c(self.byChannel, serializer);
+  }
+
+  @protected
+  void sse_encode_app_stream_progress_c(
+    AppStreamProgressC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_app_stream_state_c(self.state, serializer);
+    sse_encode_u_64(self.bytes, serializer);
+    sse_encode_u_64(self.totalLen, serializer);
+    sse_encode_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_app_stream_state_c(
+    AppStreamStateC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_app_rpc_policy_c(
+    AppRpcPolicyC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_app_rpc_policy_c(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_broadcast_scope_c(
+    BroadcastScopeC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_broadcast_scope_c(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_flags_c(FlagsC self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_flags_c(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_forward(Forward self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_forward(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_local_event_c(
+    LocalEventC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_local_event_c(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_node_key_info_c(
+    NodeKeyInfoC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_node_key_info_c(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_presence_profile_c(
+    PresenceProfileC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_presence_profile_c(self, serializer);
+  }
+
+  @protected
+  void sse_encode_broadcast_delivery_c(
+    BroadcastDeliveryC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.peerId, serializer);
+    sse_encode_bool(self.delivered, serializer);
+    sse_encode_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_broadcast_scope_c(
+    BroadcastScopeC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_broadcast_scope_kind_c(self.kind, serializer);
+    sse_encode_list_prim_u_32_strict(self.peerIds, serializer);
+  }
+
+  @protected
+  void sse_encode_broadcast_scope_kind_c(
+    BroadcastScopeKindC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_JoinHandle => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle;
+  @protected
+  void sse_encode_channel_metrics_c(
+    ChannelMetricsC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.channel, serializer);
+    sse_encode_app_rpc_counters_c(self.counters, serializer);
+    sse_encode_latency_histogram_c(self.callLatency, serializer);
+    sse_encode_latency_histogram_c(self.handlerLatency, serializer);
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_JoinHandleResultStringString => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString;
+  @protected
+  void sse_encode_channel_rejections_c(
+    ChannelRejectionsC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.channel, serializer);
+    sse_encode_u_64(self.count, serializer);
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_JoinHandleResultStringString => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString;
+  @protected
+  void sse_encode_chat_message_c(ChatMessageC self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_chat_message_kind_c(self.kind, serializer);
+    sse_encode_u_32(self.fromPeerId, serializer);
+    sse_encode_u_32(self.toPeerId, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_u_64(self.sentAtMs, serializer);
+    sse_encode_bool(self.outgoing, serializer);
+    sse_encode_list_prim_u_32_strict(self.deliveredTo, serializer);
+    sse_encode_list_prim_u_32_strict(self.readBy, serializer);
+    sse_encode_list_prim_u_32_strict(self.queuedFor, serializer);
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_PeerRoutePair => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair;
+  @protected
+  void sse_encode_chat_message_kind_c(
+    ChatMessageKindC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_PeerRoutePair => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair;
+  @protected
+  void sse_encode_connect_error_c(
+    ConnectErrorC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.dst, serializer);
+    sse_encode_String(self.ipVersion, serializer);
+    sse_encode_String(self.error, serializer);
+    sse_encode_u_64(self.timestampMs, serializer);
+  }
 
   @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  void sse_encode_credential_event_c(
+    CredentialEventC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_credential_event_kind_c(self.kind, serializer);
+    sse_encode_String(self.serverUrl, serializer);
+    sse_encode_String(self.publicKey, serializer);
+    sse_encode_String(self.fingerprint, serializer);
   }
 
   @protected
-  EventBusSubscriber
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    dynamic raw,
+  void sse_encode_credential_event_kind_c(
+    CredentialEventKindC self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return EventBusSubscriberImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  JoinHandle
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    dynamic raw,
+  void sse_encode_decoded_payload_c(
+    DecodedPayloadC self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return JoinHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_payload_codec_c(self.codec, serializer);
+    sse_encode_list_prim_u_8_strict(self.payload, serializer);
+    sse_encode_bool(self.tagged, serializer);
+    sse_encode_list_payload_codec_c(self.peerAccepts, serializer);
   }
 
   @protected
-  JoinHandleResultStringString
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    dynamic raw,
+  void sse_encode_diagnostic_check_c(
+    DiagnosticCheckC self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return JoinHandleResultStringStringImpl.frbInternalDcoDecode(
-      raw as List<dynamic>,
-    );
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_diagnostic_check_kind_c(self.kind, serializer);
+    sse_encode_diagnostic_status_c(self.status, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_String(self.detail, serializer);
+    sse_encode_String(self.suggestion, serializer);
   }
 
   @protected
-  PeerRoutePair
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    dynamic raw,
+  void sse_encode_diagnostic_check_kind_c(
+    DiagnosticCheckKindC self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PeerRoutePairImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  EventBusSubscriber
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    dynamic raw,
+  void sse_encode_diagnostic_report_c(
+    DiagnosticReportC self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return EventBusSubscriberImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_diagnostic_status_c(self.overall, serializer);
+    sse_encode_list_diagnostic_check_c(self.checks, serializer);
+    sse_encode_list_connect_error_c(self.recentConnectErrors, serializer);
   }
 
   @protected
-  JoinHandle
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    dynamic raw,
+  void sse_encode_diagnostic_status_c(
+    DiagnosticStatusC self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return JoinHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  JoinHandleResultStringString
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    dynamic raw,
+  void sse_encode_endpoint_outcome_c(
+    EndpointOutcomeC self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return JoinHandleResultStringStringImpl.frbInternalDcoDecode(
-      raw as List<dynamic>,
-    );
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  PeerRoutePair
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    dynamic raw,
+  void sse_encode_endpoint_result_c(
+    EndpointResultC self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PeerRoutePairImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_endpoint_outcome_c(self.outcome, serializer);
+    sse_encode_String(self.error, serializer);
   }
 
   @protected
-  RustStreamSink<AppInboundEventC>
-  dco_decode_StreamSink_app_inbound_event_c_Sse(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+  void sse_encode_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat32(self);
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  AppCallResultC dco_decode_app_call_result_c(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return AppCallResultC(
-      status: dco_decode_i_32(arr[0]),
-      errorMsg: dco_decode_String(arr[1]),
-      payload: dco_decode_list_prim_u_8_strict(arr[2]),
-    );
+  void sse_encode_file_offer_c(FileOfferC self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.fromPeerId, serializer);
+    sse_encode_String(self.transferId, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_u_64(self.size, serializer);
+    sse_encode_String(self.sha256, serializer);
   }
 
   @protected
-  AppInboundEventC dco_decode_app_inbound_event_c(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return AppInboundEventC(
-      kind: dco_decode_app_inbound_kind_c(arr[0]),
-      fromPeerId: dco_decode_u_32(arr[1]),
-      channel: dco_decode_String(arr[2]),
-      requestId: dco_decode_u_64(arr[3]),
-      token: dco_decode_u_64(arr[4]),
-      payload: dco_decode_list_prim_u_8_strict(arr[5]),
-    );
+  void sse_encode_file_transfer_direction_c(
+    FileTransferDirectionC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  AppInboundKindC dco_decode_app_inbound_kind_c(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AppInboundKindC.values[raw as int];
+  void sse_encode_file_transfer_progress_c(
+    FileTransferProgressC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.transferId, serializer);
+    sse_encode_u_32(self.peerId, serializer);
+    sse_encode_file_transfer_direction_c(self.direction, serializer);
+    sse_encode_file_transfer_state_c(self.state, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_u_64(self.bytes, serializer);
+    sse_encode_u_64(self.size, serializer);
+    sse_encode_String(self.error, serializer);
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  void sse_encode_file_transfer_state_c(
+    FileTransferStateC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  FlagsC dco_decode_box_autoadd_flags_c(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_flags_c(raw);
+  void sse_encode_firewall_backend_c(
+    FirewallBackendC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void sse_encode_firewall_diagnosis_c(
+    FirewallDiagnosisC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_firewall_backend_c(self.backend, serializer);
+    sse_encode_bool(self.active, serializer);
+    sse_encode_list_firewall_finding_c(self.findings, serializer);
   }
 
   @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void sse_encode_firewall_error(FirewallError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_firewall_error_kind(self.kind, serializer);
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  FlagsC dco_decode_flags_c(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 28)
-      throw Exception('unexpected arr length: expect 28 but see ${arr.length}');
-    return FlagsC(
-      defaultProtocol: dco_decode_String(arr[0]),
-      devName: dco_decode_String(arr[1]),
-      enableEncryption: dco_decode_bool(arr[2]),
-      enableIpv6: dco_decode_bool(arr[3]),
-      mtu: dco_decode_u_32(arr[4]),
-      latencyFirst: dco_decode_bool(arr[5]),
-      enableExitNode: dco_decode_bool(arr[6]),
-      noTun: dco_decode_bool(arr[7]),
-      useSmoltcp: dco_decode_bool(arr[8]),
-      relayNetworkWhitelist: dco_decode_String(arr[9]),
-      disableP2P: dco_decode_bool(arr[10]),
-      relayAllPeerRpc: dco_decode_bool(arr[11]),
-      disableUdpHolePunching: dco_decode_bool(arr[12]),
-      disableTcpHolePunching: dco_decode_bool(arr[13]),
-      multiThread: dco_decode_bool(arr[14]),
-      dataCompressAlgo: dco_decode_i_32(arr[15]),
-      bindDevice: dco_decode_bool(arr[16]),
-      enableKcpProxy: dco_decode_bool(arr[17]),
-      disableKcpInput: dco_decode_bool(arr[18]),
-      disableRelayKcp: dco_decode_bool(arr[19]),
-      proxyForwardBySystem: dco_decode_bool(arr[20]),
-      acceptDns: dco_decode_bool(arr[21]),
-      privateMode: dco_decode_bool(arr[22]),
-      enableQuicProxy: dco_decode_bool(arr[23]),
-      disableQuicInput: dco_decode_bool(arr[24]),
-      disableSymHolePunching: dco_decode_bool(arr[25]),
-      tcpWhitelist: dco_decode_String(arr[26]),
-      udpWhitelist: dco_decode_String(arr[27]),
-    );
+  void sse_encode_firewall_error_kind(
+    FirewallErrorKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  Forward dco_decode_forward(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return Forward(
-      bindAddr: dco_decode_String(arr[0]),
-      dstAddr: dco_decode_String(arr[1]),
-      proto: dco_decode_String(arr[2]),
-    );
+  void sse_encode_firewall_finding_c(
+    FirewallFindingC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_firewall_rule_c(self.rule, serializer);
+    sse_encode_bool(self.blocked, serializer);
+    sse_encode_String(self.blockingRule, serializer);
+    sse_encode_String(self.suggestion, serializer);
+  }
+
+  @protected
+  void sse_encode_firewall_port_c(
+    FirewallPortC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.proto, serializer);
+    sse_encode_u_16(self.port, serializer);
+  }
+
+  @protected
+  void sse_encode_firewall_port_status_c(
+    FirewallPortStatusC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.proto, serializer);
+    sse_encode_u_16(self.port, serializer);
+    sse_encode_bool(self.allowed, serializer);
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_firewall_profile(
+    FirewallProfile self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+  void sse_encode_firewall_profile_status_c(
+    FirewallProfileStatusC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_firewall_profile(self.profile, serializer);
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_bool(self.current, serializer);
   }
 
   @protected
-  KVNetworkStatus dco_decode_kv_network_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return KVNetworkStatus(
-      totalNodes: dco_decode_usize(arr[0]),
-      nodes: dco_decode_list_kv_node_info(arr[1]),
-    );
+  void sse_encode_firewall_rule_c(
+    FirewallRuleC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_firewall_rule_kind_c(self.kind, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.proto, serializer);
+    sse_encode_u_16(self.port, serializer);
+    sse_encode_String(self.detail, serializer);
   }
 
   @protected
-  KVNodeConnectionStats dco_decode_kv_node_connection_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return KVNodeConnectionStats(
-      connType: dco_decode_String(arr[0]),
-      rxBytes: dco_decode_u_64(arr[1]),
-      txBytes: dco_decode_u_64(arr[2]),
-      rxPackets: dco_decode_u_64(arr[3]),
-      txPackets: dco_decode_u_64(arr[4]),
-    );
+  void sse_encode_firewall_rule_kind_c(
+    FirewallRuleKindC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  KVNodeInfo dco_decode_kv_node_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 15)
-      throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
-    return KVNodeInfo(
-      peerId: dco_decode_u_32(arr[0]),
-      hostname: dco_decode_String(arr[1]),
-      ipv4: dco_decode_String(arr[2]),
-      ipv6: dco_decode_String(arr[3]),
-      latencyMs: dco_decode_f_64(arr[4]),
-      nat: dco_decode_String(arr[5]),
-      hops: dco_decode_list_node_hop_stats(arr[6]),
-      lossRate: dco_decode_f_32(arr[7]),
-      connections: dco_decode_list_kv_node_connection_stats(arr[8]),
-      tunnelProto: dco_decode_String(arr[9]),
-      connType: dco_decode_String(arr[10]),
-      rxBytes: dco_decode_u_64(arr[11]),
-      txBytes: dco_decode_u_64(arr[12]),
-      version: dco_decode_String(arr[13]),
-      cost: dco_decode_i_32(arr[14]),
-    );
+  void sse_encode_firewall_snapshot_c(
+    FirewallSnapshotC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_firewall_backend_c(self.backend, serializer);
+    sse_encode_list_firewall_profile_status_c(self.profiles, serializer);
+    sse_encode_list_firewall_profile(self.currentProfiles, serializer);
   }
 
   @protected
-  List<PeerRoutePair>
-  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(
-          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair,
-        )
-        .toList();
+  void sse_encode_flags_c(FlagsC self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.defaultProtocol, serializer);
+    sse_encode_String(self.devName, serializer);
+    sse_encode_bool(self.enableEncryption, serializer);
+    sse_encode_bool(self.enableIpv6, serializer);
+    sse_encode_u_32(self.mtu, serializer);
+    sse_encode_bool(self.latencyFirst, serializer);
+    sse_encode_bool(self.enableExitNode, serializer);
+    sse_encode_bool(self.noTun, serializer);
+    sse_encode_bool(self.useSmoltcp, serializer);
+    sse_encode_String(self.relayNetworkWhitelist, serializer);
+    sse_encode_bool(self.disableP2P, serializer);
+    sse_encode_bool(self.relayAllPeerRpc, serializer);
+    sse_encode_bool(self.disableUdpHolePunching, serializer);
+    sse_encode_bool(self.disableTcpHolePunching, serializer);
+    sse_encode_bool(self.multiThread, serializer);
+    sse_encode_i_32(self.dataCompressAlgo, serializer);
+    sse_encode_bool(self.bindDevice, serializer);
+    sse_encode_bool(self.enableKcpProxy, serializer);
+    sse_encode_bool(self.disableKcpInput, serializer);
+    sse_encode_bool(self.disableRelayKcp, serializer);
+    sse_encode_bool(self.proxyForwardBySystem, serializer);
+    sse_encode_bool(self.acceptDns, serializer);
+    sse_encode_bool(self.privateMode, serializer);
+    sse_encode_bool(self.enableQuicProxy, serializer);
+    sse_encode_bool(self.disableQuicInput, serializer);
+    sse_encode_bool(self.disableSymHolePunching, serializer);
+    sse_encode_String(self.tcpWhitelist, serializer);
+    sse_encode_String(self.udpWhitelist, serializer);
+    sse_encode_bool(self.secureMode, serializer);
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  void sse_encode_forward(Forward self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.bindAddr, serializer);
+    sse_encode_String(self.dstAddr, serializer);
+    sse_encode_String(self.proto, serializer);
   }
 
   @protected
-  List<Forward> dco_decode_list_forward(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_forward).toList();
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  List<KVNodeConnectionStats> dco_decode_list_kv_node_connection_stats(
-    dynamic raw,
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void sse_encode_inbound_app_stream_c(
+    InboundAppStreamC self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_kv_node_connection_stats)
-        .toList();
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.fromPeerId, serializer);
+    sse_encode_u_64(self.streamId, serializer);
+    sse_encode_String(self.channel, serializer);
+    sse_encode_u_64(self.totalLen, serializer);
+    sse_encode_list_prim_u_8_strict(self.metadata, serializer);
   }
 
   @protected
-  List<KVNodeInfo> dco_decode_list_kv_node_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_kv_node_info).toList();
+  void sse_encode_kv_network_status(
+    KVNetworkStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.totalNodes, serializer);
+    sse_encode_list_kv_node_info(self.nodes, serializer);
   }
 
   @protected
-  List<NodeHopStats> dco_decode_list_node_hop_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_node_hop_stats).toList();
+  void sse_encode_kv_node_connection_stats(
+    KVNodeConnectionStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.connType, serializer);
+    sse_encode_u_64(self.rxBytes, serializer);
+    sse_encode_u_64(self.txBytes, serializer);
+    sse_encode_u_64(self.rxPackets, serializer);
+    sse_encode_u_64(self.txPackets, serializer);
   }
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
+  void sse_encode_kv_node_info(KVNodeInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.peerId, serializer);
+    sse_encode_String(self.hostname, serializer);
+    sse_encode_String(self.ipv4, serializer);
+    sse_encode_String(self.ipv6, serializer);
+    sse_encode_f_64(self.latencyMs, serializer);
+    sse_encode_String(self.nat, serializer);
+    sse_encode_list_node_hop_stats(self.hops, serializer);
+    sse_encode_f_32(self.lossRate, serializer);
+    sse_encode_list_kv_node_connection_stats(self.connections, serializer);
+    sse_encode_String(self.tunnelProto, serializer);
+    sse_encode_String(self.connType, serializer);
+    sse_encode_u_64(self.rxBytes, serializer);
+    sse_encode_u_64(self.txBytes, serializer);
+    sse_encode_String(self.version, serializer);
+    sse_encode_i_32(self.cost, serializer);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_latency_histogram_c(
+    LatencyHistogramC self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_f_64_strict(self.boundsMs, serializer);
+    sse_encode_list_prim_u_64_strict(self.counts, serializer);
+    sse_encode_u_64(self.count, serializer);
+    sse_encode_f_64(self.sumMs, serializer);
   }
 
   @protected
-  NodeHopStats dco_decode_node_hop_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return NodeHopStats(
-      peerId: dco_decode_u_32(arr[0]),
-      targetIp: dco_decode_String(arr[1]),
-      latencyMs: dco_decode_f_64(arr[2]),
-      packetLoss: dco_decode_f_32(arr[3]),
-      nodeName: dco_decode_String(arr[4]),
-    );
+  void
+  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    List<PeerRoutePair> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+        item,
+        serializer,
+      );
+    }
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_list_broadcast_delivery_c(
+    List<BroadcastDeliveryC> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_broadcast_delivery_c(item, serializer);
+    }
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_list_channel_metrics_c(
+    List<ChannelMetricsC> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_channel_metrics_c(item, serializer);
+    }
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_list_channel_rejections_c(
+    List<ChannelRejectionsC> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_channel_rejections_c(item, serializer);
+    }
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_list_chat_message_c(
+    List<ChatMessageC> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_chat_message_c(item, serializer);
+    }
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_list_connect_error_c(
+    List<ConnectErrorC> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_connect_error_c(item, serializer);
+    }
   }
 
   @protected
-  EventBusSubscriber
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    SseDeserializer deserializer,
+  void sse_encode_list_diagnostic_check_c(
+    List<DiagnosticCheckC> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return EventBusSubscriberImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_diagnostic_check_c(item, serializer);
+    }
   }
 
   @protected
-  JoinHandle
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    SseDeserializer deserializer,
+  void sse_encode_list_file_offer_c(
+    List<FileOfferC> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return JoinHandleImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_file_offer_c(item, serializer);
+    }
   }
 
   @protected
-  JoinHandleResultStringString
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    SseDeserializer deserializer,
+  void sse_encode_list_firewall_finding_c(
+    List<FirewallFindingC> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return JoinHandleResultStringStringImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_firewall_finding_c(item, serializer);
+    }
   }
 
   @protected
-  PeerRoutePair
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    SseDeserializer deserializer,
+  void sse_encode_list_firewall_port_c(
+    List<FirewallPortC> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return PeerRoutePairImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_firewall_port_c(item, serializer);
+    }
   }
 
   @protected
-  EventBusSubscriber
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    SseDeserializer deserializer,
+  void sse_encode_list_firewall_port_status_c(
+    List<FirewallPortStatusC> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return EventBusSubscriberImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_firewall_port_status_c(item, serializer);
+    }
   }
 
   @protected
-  JoinHandle
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    SseDeserializer deserializer,
+  void sse_encode_list_firewall_profile(
+    List<FirewallProfile> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return JoinHandleImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_firewall_profile(item, serializer);
+    }
   }
 
   @protected
-  JoinHandleResultStringString
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    SseDeserializer deserializer,
+  void sse_encode_list_firewall_profile_status_c(
+    List<FirewallProfileStatusC> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return JoinHandleResultStringStringImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_firewall_profile_status_c(item, serializer);
+    }
   }
 
   @protected
-  PeerRoutePair
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    SseDeserializer deserializer,
+  void sse_encode_list_firewall_rule_c(
+    List<FirewallRuleC> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return PeerRoutePairImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_firewall_rule_c(item, serializer);
+    }
   }
 
   @protected
-  RustStreamSink<AppInboundEventC>
-  sse_decode_StreamSink_app_inbound_event_c_Sse(SseDeserializer deserializer) {
+  void sse_encode_list_forward(List<Forward> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_forward(item, serializer);
+    }
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_list_kv_node_connection_stats(
+    List<KVNodeConnectionStats> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_kv_node_connection_stats(item, serializer);
+    }
   }
 
   @protected
-  AppCallResultC sse_decode_app_call_result_c(SseDeserializer deserializer) {
+  void sse_encode_list_kv_node_info(
+    List<KVNodeInfo> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_status = sse_decode_i_32(deserializer);
-    var var_errorMsg = sse_decode_String(deserializer);
-    var var_payload = sse_decode_list_prim_u_8_strict(deserializer);
-    return AppCallResultC(
-      status: var_status,
-      errorMsg: var_errorMsg,
-      payload: var_payload,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_kv_node_info(item, serializer);
+    }
   }
 
   @protected
-  AppInboundEventC sse_decode_app_inbound_event_c(
-    SseDeserializer deserializer,
+  void sse_encode_list_listener_mapping_c(
+    List<ListenerMappingC> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_kind = sse_decode_app_inbound_kind_c(deserializer);
-    var var_fromPeerId = sse_decode_u_32(deserializer);
-    var var_channel = sse_decode_String(deserializer);
-    var var_requestId = sse_decode_u_64(deserializer);
-    var var_token = sse_decode_u_64(deserializer);
-    var var_payload = sse_decode_list_prim_u_8_strict(deserializer);
-    return AppInboundEventC(
-      kind: var_kind,
-      fromPeerId: var_fromPeerId,
-      channel: var_channel,
-      requestId: var_requestId,
-      token: var_token,
-      payload: var_payload,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_listener_mapping_c(item, serializer);
+    }
   }
 
   @protected
-  AppInboundKindC sse_decode_app_inbound_kind_c(SseDeserializer deserializer) {
+  void sse_encode_list_nat_compatibility_c(
+    List<NatCompatibilityC> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return AppInboundKindC.values[inner];
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_nat_compatibility_c(item, serializer);
+    }
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_list_node_hop_stats(
+    List<NodeHopStats> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_node_hop_stats(item, serializer);
+    }
   }
 
   @protected
-  FlagsC sse_decode_box_autoadd_flags_c(SseDeserializer deserializer) {
+  void sse_encode_list_payload_codec_c(
+    List<PayloadCodecC> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_flags_c(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_payload_codec_c(item, serializer);
+    }
   }
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
+  void sse_encode_list_peer_channel_rule_c(
+    List<PeerChannelRuleC> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_peer_channel_rule_c(item, serializer);
+    }
   }
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
+  void sse_encode_list_peer_connection_c(
+    List<PeerConnectionC> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_peer_connection_c(item, serializer);
+    }
   }
 
   @protected
-  FlagsC sse_decode_flags_c(SseDeserializer deserializer) {
+  void sse_encode_list_peer_metrics_c(
+    List<PeerMetricsC> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_defaultProtocol = sse_decode_String(deserializer);
-    var var_devName = sse_decode_String(deserializer);
-    var var_enableEncryption = sse_decode_bool(deserializer);
-    var var_enableIpv6 = sse_decode_bool(deserializer);
-    var var_mtu = sse_decode_u_32(deserializer);
-    var var_latencyFirst = sse_decode_bool(deserializer);
-    var var_enableExitNode = sse_decode_bool(deserializer);
-    var var_noTun = sse_decode_bool(deserializer);
-    var var_useSmoltcp = sse_decode_bool(deserializer);
-    var var_relayNetworkWhitelist = sse_decode_String(deserializer);
-    var var_disableP2P = sse_decode_bool(deserializer);
-    var var_relayAllPeerRpc = sse_decode_bool(deserializer);
-    var var_disableUdpHolePunching = sse_decode_bool(deserializer);
-    var var_disableTcpHolePunching = sse_decode_bool(deserializer);
-    var var_multiThread = sse_decode_bool(deserializer);
-    var var_dataCompressAlgo = sse_decode_i_32(deserializer);
-    var var_bindDevice = sse_decode_bool(deserializer);
-    var var_enableKcpProxy = sse_decode_bool(deserializer);
-    var var_disableKcpInput = sse_decode_bool(deserializer);
-    var var_disableRelayKcp = sse_decode_bool(deserializer);
-    var var_proxyForwardBySystem = sse_decode_bool(deserializer);
-    var var_acceptDns = sse_decode_bool(deserializer);
-    var var_privateMode = sse_decode_bool(deserializer);
-    var var_enableQuicProxy = sse_decode_bool(deserializer);
-    var var_disableQuicInput = sse_decode_bool(deserializer);
-    var var_disableSymHolePunching = sse_decode_bool(deserializer);
-    var var_tcpWhitelist = sse_decode_String(deserializer);
-    var var_udpWhitelist = sse_decode_String(deserializer);
-    return FlagsC(
-      defaultProtocol: var_defaultProtocol,
-      devName: var_devName,
-      enableEncryption: var_enableEncryption,
-      enableIpv6: var_enableIpv6,
-      mtu: var_mtu,
-      latencyFirst: var_latencyFirst,
-      enableExitNode: var_enableExitNode,
-      noTun: var_noTun,
-      useSmoltcp: var_useSmoltcp,
-      relayNetworkWhitelist: var_relayNetworkWhitelist,
-      disableP2P: var_disableP2P,
-      relayAllPeerRpc: var_relayAllPeerRpc,
-      disableUdpHolePunching: var_disableUdpHolePunching,
-      disableTcpHolePunching: var_disableTcpHolePunching,
-      multiThread: var_multiThread,
-      dataCompressAlgo: var_dataCompressAlgo,
-      bindDevice: var_bindDevice,
-      enableKcpProxy: var_enableKcpProxy,
-      disableKcpInput: var_disableKcpInput,
-      disableRelayKcp: var_disableRelayKcp,
-      proxyForwardBySystem: var_proxyForwardBySystem,
-      acceptDns: var_acceptDns,
-      privateMode: var_privateMode,
-      enableQuicProxy: var_enableQuicProxy,
-      disableQuicInput: var_disableQuicInput,
-      disableSymHolePunching: var_disableSymHolePunching,
-      tcpWhitelist: var_tcpWhitelist,
-      udpWhitelist: var_udpWhitelist,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_peer_metrics_c(item, serializer);
+    }
   }
 
   @protected
-  Forward sse_decode_forward(SseDeserializer deserializer) {
+  void sse_encode_list_peer_presence_c(
+    List<PeerPresenceC> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_bindAddr = sse_decode_String(deserializer);
-    var var_dstAddr = sse_decode_String(deserializer);
-    var var_proto = sse_decode_String(deserializer);
-    return Forward(
-      bindAddr: var_bindAddr,
-      dstAddr: var_dstAddr,
-      proto: var_proto,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_peer_presence_c(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_list_peer_proxy_cidrs_c(
+    List<PeerProxyCidrsC> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_peer_proxy_cidrs_c(item, serializer);
+    }
   }
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_list_peer_rejections_c(
+    List<PeerRejectionsC> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_peer_rejections_c(item, serializer);
+    }
   }
 
   @protected
-  KVNetworkStatus sse_decode_kv_network_status(SseDeserializer deserializer) {
+  void sse_encode_list_pinned_server_key_c(
+    List<PinnedServerKeyC> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_totalNodes = sse_decode_usize(deserializer);
-    var var_nodes = sse_decode_list_kv_node_info(deserializer);
-    return KVNetworkStatus(totalNodes: var_totalNodes, nodes: var_nodes);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pinned_server_key_c(item, serializer);
+    }
   }
 
   @protected
-  KVNodeConnectionStats sse_decode_kv_node_connection_stats(
-    SseDeserializer deserializer,
+  void sse_encode_list_port_forward_c(
+    List<PortForwardC> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_connType = sse_decode_String(deserializer);
-    var var_rxBytes = sse_decode_u_64(deserializer);
-    var var_txBytes = sse_decode_u_64(deserializer);
-    var var_rxPackets = sse_decode_u_64(deserializer);
-    var var_txPackets = sse_decode_u_64(deserializer);
-    return KVNodeConnectionStats(
-      connType: var_connType,
-      rxBytes: var_rxBytes,
-      txBytes: var_txBytes,
-      rxPackets: var_rxPackets,
-      txPackets: var_txPackets,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_port_forward_c(item, serializer);
+    }
   }
 
   @protected
-  KVNodeInfo sse_decode_kv_node_info(SseDeserializer deserializer) {
+  void sse_encode_list_port_mapping_request_c(
+    List<PortMappingRequestC> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_peerId = sse_decode_u_32(deserializer);
-    var var_hostname = sse_decode_String(deserializer);
-    var var_ipv4 = sse_decode_String(deserializer);
-    var var_ipv6 = sse_decode_String(deserializer);
-    var var_latencyMs = sse_decode_f_64(deserializer);
-    var var_nat = sse_decode_String(deserializer);
-    var var_hops = sse_decode_list_node_hop_stats(deserializer);
-    var var_lossRate = sse_decode_f_32(deserializer);
-    var var_connections = sse_decode_list_kv_node_connection_stats(
-      deserializer,
-    );
-    var var_tunnelProto = sse_decode_String(deserializer);
-    var var_connType = sse_decode_String(deserializer);
-    var var_rxBytes = sse_decode_u_64(deserializer);
-    var var_txBytes = sse_decode_u_64(deserializer);
-    var var_version = sse_decode_String(deserializer);
-    var var_cost = sse_decode_i_32(deserializer);
-    return KVNodeInfo(
-      peerId: var_peerId,
-      hostname: var_hostname,
-      ipv4: var_ipv4,
-      ipv6: var_ipv6,
-      latencyMs: var_latencyMs,
-      nat: var_nat,
-      hops: var_hops,
-      lossRate: var_lossRate,
-      connections: var_connections,
-      tunnelProto: var_tunnelProto,
-      connType: var_connType,
-      rxBytes: var_rxBytes,
-      txBytes: var_txBytes,
-      version: var_version,
-      cost: var_cost,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_port_mapping_request_c(item, serializer);
+    }
   }
 
   @protected
-  List<PeerRoutePair>
-  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_f_64_strict(
+    Float64List self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat64List(self);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PeerRoutePair>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-          deserializer,
-        ),
-      );
-    }
-    return ans_;
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(self);
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_64_strict(
+    Uint64List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint64List(self);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
+  @protected
+  void sse_encode_list_prim_u_8_loose(
+    List<int> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(
+      self is Uint8List ? self : Uint8List.fromList(self),
+    );
   }
 
   @protected
-  List<Forward> sse_decode_list_forward(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Forward>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_forward(deserializer));
+  @protected
+  void sse_encode_list_proxy_cidr_c(
+    List<ProxyCidrC> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_proxy_cidr_c(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<KVNodeConnectionStats> sse_decode_list_kv_node_connection_stats(
-    SseDeserializer deserializer,
+  void sse_encode_list_proxy_cidr_conflict_c(
+    List<ProxyCidrConflictC> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <KVNodeConnectionStats>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_kv_node_connection_stats(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_proxy_cidr_conflict_c(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<KVNodeInfo> sse_decode_list_kv_node_info(SseDeserializer deserializer) {
+  void sse_encode_list_reason_rejections_c(
+    List<ReasonRejectionsC> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <KVNodeInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_kv_node_info(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_reason_rejections_c(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<NodeHopStats> sse_decode_list_node_hop_stats(
-    SseDeserializer deserializer,
+  void sse_encode_list_selected_server_c(
+    List<SelectedServerC> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_selected_server_c(item, serializer);
+    }
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NodeHopStats>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_node_hop_stats(deserializer));
+  @protected
+  void sse_encode_list_server_probe_result_c(
+    List<ServerProbeResultC> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_server_probe_result_c(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+  void sse_encode_listener_mapping_c(
+    ListenerMappingC self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_String(self.localListener, serializer);
+    sse_encode_String(self.mappedListener, serializer);
+    sse_encode_String(self.backend, serializer);
+    sse_encode_port_mapping_state_c(self.state, serializer);
+    sse_encode_bool(self.managedByEasytier, serializer);
+    sse_encode_u_32(self.leaseRemainingSecs, serializer);
+    sse_encode_String(self.error, serializer);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_local_endpoints_c(
+    LocalEndpointsC self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_list_String(self.listeners, serializer);
+    sse_encode_list_String(self.mappedListeners, serializer);
   }
 
   @protected
-  NodeHopStats sse_decode_node_hop_stats(SseDeserializer deserializer) {
+  void sse_encode_local_event_c(LocalEventC self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_peerId = sse_decode_u_32(deserializer);
-    var var_targetIp = sse_decode_String(deserializer);
-    var var_latencyMs = sse_decode_f_64(deserializer);
-    var var_packetLoss = sse_decode_f_32(deserializer);
-    var var_nodeName = sse_decode_String(deserializer);
-    return NodeHopStats(
-      peerId: var_peerId,
-      targetIp: var_targetIp,
-      latencyMs: var_latencyMs,
-      packetLoss: var_packetLoss,
-      nodeName: var_nodeName,
-    );
+    sse_encode_String(self.instanceId, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_u_64(self.seq, serializer);
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_message_event_c(
+    MessageEventC self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_message_event_kind_c(self.kind, serializer);
+    sse_encode_u_32(self.peerId, serializer);
+    sse_encode_chat_message_c(self.message, serializer);
+    sse_encode_String(self.error, serializer);
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_message_event_kind_c(
+    MessageEventKindC self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_nat_compatibility_c(
+    NatCompatibilityC self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_nat_type_c(self.peerNatType, serializer);
+    sse_encode_p_2_p_likelihood_c(self.likelihood, serializer);
+    sse_encode_String(self.explanation, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_nat_detection_c(
+    NatDetectionC self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_nat_type_c(self.udpNatType, serializer);
+    sse_encode_nat_type_c(self.tcpNatType, serializer);
+    sse_encode_port_mapping_c(self.udpPortMapping, serializer);
+    sse_encode_list_String(self.publicIps, serializer);
+    sse_encode_String(self.mappedUdpAddr, serializer);
+    sse_encode_u_32(self.minPort, serializer);
+    sse_encode_u_32(self.maxPort, serializer);
+    sse_encode_list_nat_compatibility_c(self.compatibility, serializer);
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_nat_type_c(NatTypeC self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_node_hop_stats(NodeHopStats self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_u_32(self.peerId, serializer);
+    sse_encode_String(self.targetIp, serializer);
+    sse_encode_f_64(self.latencyMs, serializer);
+    sse_encode_f_32(self.packetLoss, serializer);
+    sse_encode_String(self.nodeName, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    EventBusSubscriber self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_node_key_info_c(NodeKeyInfoC self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as EventBusSubscriberImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_String(self.publicKey, serializer);
+    sse_encode_String(self.fingerprint, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    JoinHandle self,
+  void sse_encode_opt_box_autoadd_app_rpc_policy_c(
+    AppRpcPolicyC? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as JoinHandleImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_app_rpc_policy_c(self, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    JoinHandleResultStringString self,
+  void sse_encode_opt_box_autoadd_local_event_c(
+    LocalEventC? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as JoinHandleResultStringStringImpl).frbInternalSseEncode(
-        move: true,
-      ),
-      serializer,
-    );
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_local_event_c(self, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    PeerRoutePair self,
+  void sse_encode_opt_box_autoadd_node_key_info_c(
+    NodeKeyInfoC? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as PeerRoutePairImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_node_key_info_c(self, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    EventBusSubscriber self,
+  void sse_encode_opt_box_autoadd_presence_profile_c(
+    PresenceProfileC? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as EventBusSubscriberImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_presence_profile_c(self, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    JoinHandle self,
+  void sse_encode_p_2_p_likelihood_c(
+    P2pLikelihoodC self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as JoinHandleImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    JoinHandleResultStringString self,
+  void sse_encode_payload_codec_c(
+    PayloadCodecC self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as JoinHandleResultStringStringImpl).frbInternalSseEncode(
-        move: null,
-      ),
-      serializer,
-    );
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    PeerRoutePair self,
+  void sse_encode_peer_channel_rule_c(
+    PeerChannelRuleC self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as PeerRoutePairImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_u_32(self.peerId, serializer);
+    sse_encode_list_String(self.allow, serializer);
+    sse_encode_list_String(self.deny, serializer);
   }
 
   @protected
-  void sse_encode_StreamSink_app_inbound_event_c_Sse(
-    RustStreamSink<AppInboundEventC> self,
+  void sse_encode_peer_connection_c(
+    PeerConnectionC self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_app_inbound_event_c,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
+    sse_encode_String(self.connId, serializer);
+    sse_encode_String(self.tunnelType, serializer);
+    sse_encode_String(self.remoteAddr, serializer);
+    sse_encode_bool(self.isClient, serializer);
+    sse_encode_String(self.connectorUrl, serializer);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_peer_metrics_c(PeerMetricsC self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_u_32(self.peerId, serializer);
+    sse_encode_app_rpc_counters_c(self.counters, serializer);
+    sse_encode_latency_histogram_c(self.callLatency, serializer);
+    sse_encode_latency_histogram_c(self.handlerLatency, serializer);
   }
 
   @protected
-  void sse_encode_app_call_result_c(
-    AppCallResultC self,
+  void sse_encode_peer_presence_c(
+    PeerPresenceC self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.status, serializer);
-    sse_encode_String(self.errorMsg, serializer);
-    sse_encode_list_prim_u_8_strict(self.payload, serializer);
+    sse_encode_kv_node_info(self.node, serializer);
+    sse_encode_opt_box_autoadd_presence_profile_c(self.profile, serializer);
   }
 
   @protected
-  void sse_encode_app_inbound_event_c(
-    AppInboundEventC self,
+  void sse_encode_peer_proxy_cidrs_c(
+    PeerProxyCidrsC self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_app_inbound_kind_c(self.kind, serializer);
-    sse_encode_u_32(self.fromPeerId, serializer);
-    sse_encode_String(self.channel, serializer);
-    sse_encode_u_64(self.requestId, serializer);
-    sse_encode_u_64(self.token, serializer);
-    sse_encode_list_prim_u_8_strict(self.payload, serializer);
+    sse_encode_u_32(self.peerId, serializer);
+    sse_encode_String(self.hostname, serializer);
+    sse_encode_list_String(self.cidrs, serializer);
   }
 
   @protected
-  void sse_encode_app_inbound_kind_c(
-    AppInboundKindC self,
+  void sse_encode_peer_rejections_c(
+    PeerRejectionsC self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
+    sse_encode_u_32(self.peerId, serializer);
+    sse_encode_u_64(self.count, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_pinned_server_key_c(
+    PinnedServerKeyC self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_String(self.serverUrl, serializer);
+    sse_encode_String(self.publicKey, serializer);
+    sse_encode_String(self.fingerprint, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_flags_c(FlagsC self, SseSerializer serializer) {
+  void sse_encode_port_forward_c(PortForwardC self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_flags_c(self, serializer);
+    sse_encode_String(self.proto, serializer);
+    sse_encode_String(self.bindAddr, serializer);
+    sse_encode_String(self.dstAddr, serializer);
+    sse_encode_bool(self.managed, serializer);
+    sse_encode_u_32(self.activeConnections, serializer);
+    sse_encode_u_64(self.totalConnections, serializer);
+    sse_encode_u_64(self.txBytes, serializer);
+    sse_encode_u_64(self.rxBytes, serializer);
+    sse_encode_u_64(self.errors, serializer);
+    sse_encode_String(self.lastError, serializer);
   }
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
+  void sse_encode_port_mapping_c(PortMappingC self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
+  void sse_encode_port_mapping_request_c(
+    PortMappingRequestC self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
+    sse_encode_String(self.listener, serializer);
+    sse_encode_u_16(self.externalPort, serializer);
   }
 
   @protected
-  void sse_encode_flags_c(FlagsC self, SseSerializer serializer) {
+  void sse_encode_port_mapping_state_c(
+    PortMappingStateC self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.defaultProtocol, serializer);
-    sse_encode_String(self.devName, serializer);
-    sse_encode_bool(self.enableEncryption, serializer);
-    sse_encode_bool(self.enableIpv6, serializer);
-    sse_encode_u_32(self.mtu, serializer);
-    sse_encode_bool(self.latencyFirst, serializer);
-    sse_encode_bool(self.enableExitNode, serializer);
-    sse_encode_bool(self.noTun, serializer);
-    sse_encode_bool(self.useSmoltcp, serializer);
-    sse_encode_String(self.relayNetworkWhitelist, serializer);
-    sse_encode_bool(self.disableP2P, serializer);
-    sse_encode_bool(self.relayAllPeerRpc, serializer);
-    sse_encode_bool(self.disableUdpHolePunching, serializer);
-    sse_encode_bool(self.disableTcpHolePunching, serializer);
-    sse_encode_bool(self.multiThread, serializer);
-    sse_encode_i_32(self.dataCompressAlgo, serializer);
-    sse_encode_bool(self.bindDevice, serializer);
-    sse_encode_bool(self.enableKcpProxy, serializer);
-    sse_encode_bool(self.disableKcpInput, serializer);
-    sse_encode_bool(self.disableRelayKcp, serializer);
-    sse_encode_bool(self.proxyForwardBySystem, serializer);
-    sse_encode_bool(self.acceptDns, serializer);
-    sse_encode_bool(self.privateMode, serializer);
-    sse_encode_bool(self.enableQuicProxy, serializer);
-    sse_encode_bool(self.disableQuicInput, serializer);
-    sse_encode_bool(self.disableSymHolePunching, serializer);
-    sse_encode_String(self.tcpWhitelist, serializer);
-    sse_encode_String(self.udpWhitelist, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_forward(Forward self, SseSerializer serializer) {
+  void sse_encode_presence_event_c(
+    PresenceEventC self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.bindAddr, serializer);
-    sse_encode_String(self.dstAddr, serializer);
-    sse_encode_String(self.proto, serializer);
+    sse_encode_presence_event_kind_c(self.kind, serializer);
+    sse_encode_u_32(self.peerId, serializer);
+    sse_encode_opt_box_autoadd_presence_profile_c(self.profile, serializer);
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_presence_event_kind_c(
+    PresenceEventKindC self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_presence_profile_c(
+    PresenceProfileC self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+    sse_encode_String(self.displayName, serializer);
+    sse_encode_String(self.avatarHash, serializer);
+    sse_encode_presence_status_c(self.status, serializer);
+    sse_encode_String(self.appName, serializer);
+    sse_encode_String(self.appVersion, serializer);
+    sse_encode_String(self.currentGame, serializer);
   }
 
   @protected
-  void sse_encode_kv_network_status(
-    KVNetworkStatus self,
+  void sse_encode_presence_status_c(
+    PresenceStatusC self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(self.totalNodes, serializer);
-    sse_encode_list_kv_node_info(self.nodes, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_kv_node_connection_stats(
-    KVNodeConnectionStats self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_proxy_cidr_c(ProxyCidrC self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.connType, serializer);
-    sse_encode_u_64(self.rxBytes, serializer);
-    sse_encode_u_64(self.txBytes, serializer);
-    sse_encode_u_64(self.rxPackets, serializer);
-    sse_encode_u_64(self.txPackets, serializer);
+    sse_encode_String(self.cidr, serializer);
+    sse_encode_String(self.mappedCidr, serializer);
   }
 
   @protected
-  void sse_encode_kv_node_info(KVNodeInfo self, SseSerializer serializer) {
+  void sse_encode_proxy_cidr_conflict_c(
+    ProxyCidrConflictC self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_proxy_cidr_conflict_kind_c(self.kind, serializer);
+    sse_encode_String(self.cidr, serializer);
+    sse_encode_String(self.otherCidr, serializer);
     sse_encode_u_32(self.peerId, serializer);
     sse_encode_String(self.hostname, serializer);
-    sse_encode_String(self.ipv4, serializer);
-    sse_encode_String(self.ipv6, serializer);
-    sse_encode_f_64(self.latencyMs, serializer);
-    sse_encode_String(self.nat, serializer);
-    sse_encode_list_node_hop_stats(self.hops, serializer);
-    sse_encode_f_32(self.lossRate, serializer);
-    sse_encode_list_kv_node_connection_stats(self.connections, serializer);
-    sse_encode_String(self.tunnelProto, serializer);
-    sse_encode_String(self.connType, serializer);
-    sse_encode_u_64(self.rxBytes, serializer);
-    sse_encode_u_64(self.txBytes, serializer);
-    sse_encode_String(self.version, serializer);
-    sse_encode_i_32(self.cost, serializer);
+    sse_encode_String(self.interface, serializer);
   }
 
   @protected
-  void
-  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    List<PeerRoutePair> self,
+  void sse_encode_proxy_cidr_conflict_kind_c(
+    ProxyCidrConflictKindC self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-        item,
-        serializer,
-      );
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_rate_limit_c(RateLimitC self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
+    sse_encode_u_32(self.perSecond, serializer);
+    sse_encode_u_32(self.burst, serializer);
   }
 
   @protected
-  void sse_encode_list_forward(List<Forward> self, SseSerializer serializer) {
+  void sse_encode_reason_rejections_c(
+    ReasonRejectionsC self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_forward(item, serializer);
-    }
+    sse_encode_reject_reason_c(self.reason, serializer);
+    sse_encode_u_64(self.count, serializer);
   }
 
   @protected
-  void sse_encode_list_kv_node_connection_stats(
-    List<KVNodeConnectionStats> self,
+  void sse_encode_reject_reason_c(
+    RejectReasonC self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_kv_node_connection_stats(item, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_list_kv_node_info(
-    List<KVNodeInfo> self,
+  void sse_encode_selected_server_c(
+    SelectedServerC self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_kv_node_info(item, serializer);
-    }
+    sse_encode_String(self.url, serializer);
+    sse_encode_server_role_c(self.role, serializer);
+    sse_encode_u_32(self.latencyMs, serializer);
+    sse_encode_bool(self.connected, serializer);
+    sse_encode_String(self.reason, serializer);
   }
 
   @protected
-  void sse_encode_list_node_hop_stats(
-    List<NodeHopStats> self,
+  void sse_encode_server_probe_result_c(
+    ServerProbeResultC self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_node_hop_stats(item, serializer);
-    }
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.scheme, serializer);
+    sse_encode_bool(self.protocolSupported, serializer);
+    sse_encode_String(self.resolvedAddr, serializer);
+    sse_encode_u_32(self.dnsMs, serializer);
+    sse_encode_bool(self.reachable, serializer);
+    sse_encode_u_32(self.latencyMs, serializer);
+    sse_encode_String(self.error, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_loose(
-    List<int> self,
+  void sse_encode_server_role_c(ServerRoleC self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_server_selection_status_c(
+    ServerSelectionStatusC self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(
-      self is Uint8List ? self : Uint8List.fromList(self),
-    );
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_u_32(self.activeCount, serializer);
+    sse_encode_list_selected_server_c(self.servers, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_tun_firewall_status_c(
+    TunFirewallStatusC self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_firewall_backend_c(self.backend, serializer);
+    sse_encode_bool(self.active, serializer);
+    sse_encode_bool(self.tunAllowed, serializer);
+    sse_encode_list_firewall_port_status_c(self.ports, serializer);
+    sse_encode_String(self.detail, serializer);
   }
 
   @protected
-  void sse_encode_node_hop_stats(NodeHopStats self, SseSerializer serializer) {
+  void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.peerId, serializer);
-    sse_encode_String(self.targetIp, serializer);
-    sse_encode_f_64(self.latencyMs, serializer);
-    sse_encode_f_32(self.packetLoss, serializer);
-    sse_encode_String(self.nodeName, serializer);
+    serializer.buffer.putUint16(self);
   }
 
   @protected
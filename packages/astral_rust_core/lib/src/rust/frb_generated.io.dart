@@ -3,8 +3,30 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/app_broadcast.dart';
+import 'api/app_call_control.dart';
+import 'api/app_codec.dart';
+import 'api/app_metrics.dart';
+import 'api/app_policy.dart';
+import 'api/app_router.dart';
+import 'api/app_stream.dart';
+import 'api/credential.dart';
+import 'api/diagnostics.dart';
+import 'api/endpoints.dart';
+import 'api/file_transfer.dart';
 import 'api/firewall.dart';
+import 'api/local_channel.dart';
+import 'api/messaging.dart';
+import 'api/nat.dart';
 import 'api/p2p.dart';
+import 'api/peer_control.dart';
+import 'api/port_forward.dart';
+import 'api/port_mapping.dart';
+import 'api/presence.dart';
+import 'api/proxy_cidr.dart';
+import 'api/redact.dart';
+import 'api/server_probe.dart';
+import 'api/server_selection.dart';
 import 'api/simple.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -87,13 +109,69 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  RustStreamSink<AppCallControlC> dco_decode_StreamSink_app_call_control_c_Sse(
+    dynamic raw,
+  );
+
   @protected
   RustStreamSink<AppInboundEventC>
   dco_decode_StreamSink_app_inbound_event_c_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<AppStreamProgressC>
+  dco_decode_StreamSink_app_stream_progress_c_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<CredentialEventC> dco_decode_StreamSink_credential_event_c_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<FileOfferC> dco_decode_StreamSink_file_offer_c_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<FileTransferProgressC>
+  dco_decode_StreamSink_file_transfer_progress_c_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<InboundAppStreamC>
+  dco_decode_StreamSink_inbound_app_stream_c_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<Uint8List> dco_decode_StreamSink_list_prim_u_8_strict_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<ListenerMappingC> dco_decode_StreamSink_listener_mapping_c_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<MessageEventC> dco_decode_StreamSink_message_event_c_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<PresenceEventC> dco_decode_StreamSink_presence_event_c_Sse(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AppCallControlC dco_decode_app_call_control_c(dynamic raw);
+
+  @protected
+  AppCallControlKindC dco_decode_app_call_control_kind_c(dynamic raw);
+
+  @protected
+  AppCallDecodedC dco_decode_app_call_decoded_c(dynamic raw);
+
   @protected
   AppCallResultC dco_decode_app_call_result_c(dynamic raw);
 
@@ -103,18 +181,153 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AppInboundKindC dco_decode_app_inbound_kind_c(dynamic raw);
 
+  @protected
+  AppRpcCountersC dco_decode_app_rpc_counters_c(dynamic raw);
+
+  @protected
+  AppRpcMetricsC dco_decode_app_rpc_metrics_c(dynamic raw);
+
+  @protected
+  AppRpcPolicyC dco_decode_app_rpc_policy_c(dynamic raw);
+
+  @protected
+  AppRpcRejectionsC dco_decode_app_rpc_rejections_c(dynamic raw);
+
+  @protected
+  AppStreamProgressC dco_decode_app_stream_progress_c(dynamic raw);
+
+  @protected
+  AppStreamStateC dco_decode_app_stream_state_c(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  AppRpcPolicyC dco_decode_box_autoadd_app_rpc_policy_c(dynamic raw);
+
+  @protected
+  BroadcastScopeC dco_decode_box_autoadd_broadcast_scope_c(dynamic raw);
+
   @protected
   FlagsC dco_decode_box_autoadd_flags_c(dynamic raw);
 
+  @protected
+  Forward dco_decode_box_autoadd_forward(dynamic raw);
+
+  @protected
+  LocalEventC dco_decode_box_autoadd_local_event_c(dynamic raw);
+
+  @protected
+  NodeKeyInfoC dco_decode_box_autoadd_node_key_info_c(dynamic raw);
+
+  @protected
+  PresenceProfileC dco_decode_box_autoadd_presence_profile_c(dynamic raw);
+
+  @protected
+  BroadcastDeliveryC dco_decode_broadcast_delivery_c(dynamic raw);
+
+  @protected
+  BroadcastScopeC dco_decode_broadcast_scope_c(dynamic raw);
+
+  @protected
+  BroadcastScopeKindC dco_decode_broadcast_scope_kind_c(dynamic raw);
+
+  @protected
+  ChannelMetricsC dco_decode_channel_metrics_c(dynamic raw);
+
+  @protected
+  ChannelRejectionsC dco_decode_channel_rejections_c(dynamic raw);
+
+  @protected
+  ChatMessageC dco_decode_chat_message_c(dynamic raw);
+
+  @protected
+  ChatMessageKindC dco_decode_chat_message_kind_c(dynamic raw);
+
+  @protected
+  ConnectErrorC dco_decode_connect_error_c(dynamic raw);
+
+  @protected
+  CredentialEventC dco_decode_credential_event_c(dynamic raw);
+
+  @protected
+  CredentialEventKindC dco_decode_credential_event_kind_c(dynamic raw);
+
+  @protected
+  DecodedPayloadC dco_decode_decoded_payload_c(dynamic raw);
+
+  @protected
+  DiagnosticCheckC dco_decode_diagnostic_check_c(dynamic raw);
+
+  @protected
+  DiagnosticCheckKindC dco_decode_diagnostic_check_kind_c(dynamic raw);
+
+  @protected
+  DiagnosticReportC dco_decode_diagnostic_report_c(dynamic raw);
+
+  @protected
+  DiagnosticStatusC dco_decode_diagnostic_status_c(dynamic raw);
+
+  @protected
+  EndpointOutcomeC dco_decode_endpoint_outcome_c(dynamic raw);
+
+  @protected
+  EndpointResultC dco_decode_endpoint_result_c(dynamic raw);
+
   @protected
   double dco_decode_f_32(dynamic raw);
 
   @protected
   double dco_decode_f_64(dynamic raw);
 
+  @protected
+  FileOfferC dco_decode_file_offer_c(dynamic raw);
+
+  @protected
+  FileTransferDirectionC dco_decode_file_transfer_direction_c(dynamic raw);
+
+  @protected
+  FileTransferProgressC dco_decode_file_transfer_progress_c(dynamic raw);
+
+  @protected
+  FileTransferStateC dco_decode_file_transfer_state_c(dynamic raw);
+
+  @protected
+  FirewallBackendC dco_decode_firewall_backend_c(dynamic raw);
+
+  @protected
+  FirewallDiagnosisC dco_decode_firewall_diagnosis_c(dynamic raw);
+
+  @protected
+  FirewallError dco_decode_firewall_error(dynamic raw);
+
+  @protected
+  FirewallErrorKind dco_decode_firewall_error_kind(dynamic raw);
+
+  @protected
+  FirewallFindingC dco_decode_firewall_finding_c(dynamic raw);
+
+  @protected
+  FirewallPortC dco_decode_firewall_port_c(dynamic raw);
+
+  @protected
+  FirewallPortStatusC dco_decode_firewall_port_status_c(dynamic raw);
+
+  @protected
+  FirewallProfile dco_decode_firewall_profile(dynamic raw);
+
+  @protected
+  FirewallProfileStatusC dco_decode_firewall_profile_status_c(dynamic raw);
+
+  @protected
+  FirewallRuleC dco_decode_firewall_rule_c(dynamic raw);
+
+  @protected
+  FirewallRuleKindC dco_decode_firewall_rule_kind_c(dynamic raw);
+
+  @protected
+  FirewallSnapshotC dco_decode_firewall_snapshot_c(dynamic raw);
+
   @protected
   FlagsC dco_decode_flags_c(dynamic raw);
 
@@ -127,6 +340,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 dco_decode_i_64(dynamic raw);
 
+  @protected
+  InboundAppStreamC dco_decode_inbound_app_stream_c(dynamic raw);
+
   @protected
   KVNetworkStatus dco_decode_kv_network_status(dynamic raw);
 
@@ -136,6 +352,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   KVNodeInfo dco_decode_kv_node_info(dynamic raw);
 
+  @protected
+  LatencyHistogramC dco_decode_latency_histogram_c(dynamic raw);
+
   @protected
   List<PeerRoutePair>
   dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
@@ -146,364 +365,1897 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   List<String> dco_decode_list_String(dynamic raw);
 
   @protected
-  List<Forward> dco_decode_list_forward(dynamic raw);
+  List<BroadcastDeliveryC> dco_decode_list_broadcast_delivery_c(dynamic raw);
+
+  @protected
+  List<ChannelMetricsC> dco_decode_list_channel_metrics_c(dynamic raw);
+
+  @protected
+  List<ChannelRejectionsC> dco_decode_list_channel_rejections_c(dynamic raw);
+
+  @protected
+  List<ChatMessageC> dco_decode_list_chat_message_c(dynamic raw);
+
+  @protected
+  List<ConnectErrorC> dco_decode_list_connect_error_c(dynamic raw);
+
+  @protected
+  List<DiagnosticCheckC> dco_decode_list_diagnostic_check_c(dynamic raw);
+
+  @protected
+  List<FileOfferC> dco_decode_list_file_offer_c(dynamic raw);
+
+  @protected
+  List<FirewallFindingC> dco_decode_list_firewall_finding_c(dynamic raw);
+
+  @protected
+  List<FirewallPortC> dco_decode_list_firewall_port_c(dynamic raw);
+
+  @protected
+  List<FirewallPortStatusC> dco_decode_list_firewall_port_status_c(dynamic raw);
+
+  @protected
+  List<FirewallProfile> dco_decode_list_firewall_profile(dynamic raw);
+
+  @protected
+  List<FirewallProfileStatusC> dco_decode_list_firewall_profile_status_c(
+    dynamic raw,
+  );
+
+  @protected
+  List<FirewallRuleC> dco_decode_list_firewall_rule_c(dynamic raw);
+
+  @protected
+  List<Forward> dco_decode_list_forward(dynamic raw);
+
+  @protected
+  List<KVNodeConnectionStats> dco_decode_list_kv_node_connection_stats(
+    dynamic raw,
+  );
+
+  @protected
+  List<KVNodeInfo> dco_decode_list_kv_node_info(dynamic raw);
+
+  @protected
+  List<ListenerMappingC> dco_decode_list_listener_mapping_c(dynamic raw);
+
+  @protected
+  List<NatCompatibilityC> dco_decode_list_nat_compatibility_c(dynamic raw);
+
+  @protected
+  List<NodeHopStats> dco_decode_list_node_hop_stats(dynamic raw);
+
+  @protected
+  List<PayloadCodecC> dco_decode_list_payload_codec_c(dynamic raw);
+
+  @protected
+  List<PeerChannelRuleC> dco_decode_list_peer_channel_rule_c(dynamic raw);
+
+  @protected
+  List<PeerConnectionC> dco_decode_list_peer_connection_c(dynamic raw);
+
+  @protected
+  List<PeerMetricsC> dco_decode_list_peer_metrics_c(dynamic raw);
+
+  @protected
+  List<PeerPresenceC> dco_decode_list_peer_presence_c(dynamic raw);
+
+  @protected
+  List<PeerProxyCidrsC> dco_decode_list_peer_proxy_cidrs_c(dynamic raw);
+
+  @protected
+  List<PeerRejectionsC> dco_decode_list_peer_rejections_c(dynamic raw);
+
+  @protected
+  List<PinnedServerKeyC> dco_decode_list_pinned_server_key_c(dynamic raw);
+
+  @protected
+  List<PortForwardC> dco_decode_list_port_forward_c(dynamic raw);
+
+  @protected
+  List<PortMappingRequestC> dco_decode_list_port_mapping_request_c(dynamic raw);
+
+  @protected
+  Float64List dco_decode_list_prim_f_64_strict(dynamic raw);
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+  @protected
+  Uint64List dco_decode_list_prim_u_64_strict(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<ProxyCidrC> dco_decode_list_proxy_cidr_c(dynamic raw);
+
+  @protected
+  List<ProxyCidrConflictC> dco_decode_list_proxy_cidr_conflict_c(dynamic raw);
+
+  @protected
+  List<ReasonRejectionsC> dco_decode_list_reason_rejections_c(dynamic raw);
+
+  @protected
+  List<SelectedServerC> dco_decode_list_selected_server_c(dynamic raw);
+
+  @protected
+  List<ServerProbeResultC> dco_decode_list_server_probe_result_c(dynamic raw);
+
+  @protected
+  ListenerMappingC dco_decode_listener_mapping_c(dynamic raw);
+
+  @protected
+  LocalEndpointsC dco_decode_local_endpoints_c(dynamic raw);
+
+  @protected
+  LocalEventC dco_decode_local_event_c(dynamic raw);
+
+  @protected
+  MessageEventC dco_decode_message_event_c(dynamic raw);
+
+  @protected
+  MessageEventKindC dco_decode_message_event_kind_c(dynamic raw);
+
+  @protected
+  NatCompatibilityC dco_decode_nat_compatibility_c(dynamic raw);
+
+  @protected
+  NatDetectionC dco_decode_nat_detection_c(dynamic raw);
+
+  @protected
+  NatTypeC dco_decode_nat_type_c(dynamic raw);
+
+  @protected
+  NodeHopStats dco_decode_node_hop_stats(dynamic raw);
+
+  @protected
+  NodeKeyInfoC dco_decode_node_key_info_c(dynamic raw);
+
+  @protected
+  AppRpcPolicyC? dco_decode_opt_box_autoadd_app_rpc_policy_c(dynamic raw);
+
+  @protected
+  LocalEventC? dco_decode_opt_box_autoadd_local_event_c(dynamic raw);
+
+  @protected
+  NodeKeyInfoC? dco_decode_opt_box_autoadd_node_key_info_c(dynamic raw);
+
+  @protected
+  PresenceProfileC? dco_decode_opt_box_autoadd_presence_profile_c(dynamic raw);
+
+  @protected
+  P2pLikelihoodC dco_decode_p_2_p_likelihood_c(dynamic raw);
+
+  @protected
+  PayloadCodecC dco_decode_payload_codec_c(dynamic raw);
+
+  @protected
+  PeerChannelRuleC dco_decode_peer_channel_rule_c(dynamic raw);
+
+  @protected
+  PeerConnectionC dco_decode_peer_connection_c(dynamic raw);
+
+  @protected
+  PeerMetricsC dco_decode_peer_metrics_c(dynamic raw);
+
+  @protected
+  PeerPresenceC dco_decode_peer_presence_c(dynamic raw);
+
+  @protected
+  PeerProxyCidrsC dco_decode_peer_proxy_cidrs_c(dynamic raw);
+
+  @protected
+  PeerRejectionsC dco_decode_peer_rejections_c(dynamic raw);
+
+  @protected
+  PinnedServerKeyC dco_decode_pinned_server_key_c(dynamic raw);
+
+  @protected
+  PortForwardC dco_decode_port_forward_c(dynamic raw);
+
+  @protected
+  PortMappingC dco_decode_port_mapping_c(dynamic raw);
+
+  @protected
+  PortMappingRequestC dco_decode_port_mapping_request_c(dynamic raw);
+
+  @protected
+  PortMappingStateC dco_decode_port_mapping_state_c(dynamic raw);
+
+  @protected
+  PresenceEventC dco_decode_presence_event_c(dynamic raw);
+
+  @protected
+  PresenceEventKindC dco_decode_presence_event_kind_c(dynamic raw);
+
+  @protected
+  PresenceProfileC dco_decode_presence_profile_c(dynamic raw);
+
+  @protected
+  PresenceStatusC dco_decode_presence_status_c(dynamic raw);
+
+  @protected
+  ProxyCidrC dco_decode_proxy_cidr_c(dynamic raw);
+
+  @protected
+  ProxyCidrConflictC dco_decode_proxy_cidr_conflict_c(dynamic raw);
+
+  @protected
+  ProxyCidrConflictKindC dco_decode_proxy_cidr_conflict_kind_c(dynamic raw);
+
+  @protected
+  RateLimitC dco_decode_rate_limit_c(dynamic raw);
+
+  @protected
+  ReasonRejectionsC dco_decode_reason_rejections_c(dynamic raw);
+
+  @protected
+  RejectReasonC dco_decode_reject_reason_c(dynamic raw);
+
+  @protected
+  SelectedServerC dco_decode_selected_server_c(dynamic raw);
+
+  @protected
+  ServerProbeResultC dco_decode_server_probe_result_c(dynamic raw);
+
+  @protected
+  ServerRoleC dco_decode_server_role_c(dynamic raw);
+
+  @protected
+  ServerSelectionStatusC dco_decode_server_selection_status_c(dynamic raw);
+
+  @protected
+  TunFirewallStatusC dco_decode_tun_firewall_status_c(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  EventBusSubscriber
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  JoinHandle
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  JoinHandleResultStringString
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PeerRoutePair
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EventBusSubscriber
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  JoinHandle
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  JoinHandleResultStringString
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PeerRoutePair
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<AppCallControlC> sse_decode_StreamSink_app_call_control_c_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<AppInboundEventC>
+  sse_decode_StreamSink_app_inbound_event_c_Sse(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<AppStreamProgressC>
+  sse_decode_StreamSink_app_stream_progress_c_Sse(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<CredentialEventC> sse_decode_StreamSink_credential_event_c_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<FileOfferC> sse_decode_StreamSink_file_offer_c_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<FileTransferProgressC>
+  sse_decode_StreamSink_file_transfer_progress_c_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<InboundAppStreamC>
+  sse_decode_StreamSink_inbound_app_stream_c_Sse(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<Uint8List> sse_decode_StreamSink_list_prim_u_8_strict_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<ListenerMappingC> sse_decode_StreamSink_listener_mapping_c_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<MessageEventC> sse_decode_StreamSink_message_event_c_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<PresenceEventC> sse_decode_StreamSink_presence_event_c_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  AppCallControlC sse_decode_app_call_control_c(SseDeserializer deserializer);
+
+  @protected
+  AppCallControlKindC sse_decode_app_call_control_kind_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AppCallDecodedC sse_decode_app_call_decoded_c(SseDeserializer deserializer);
+
+  @protected
+  AppCallResultC sse_decode_app_call_result_c(SseDeserializer deserializer);
+
+  @protected
+  AppInboundEventC sse_decode_app_inbound_event_c(SseDeserializer deserializer);
+
+  @protected
+  AppInboundKindC sse_decode_app_inbound_kind_c(SseDeserializer deserializer);
+
+  @protected
+  AppRpcCountersC sse_decode_app_rpc_counters_c(SseDeserializer deserializer);
+
+  @protected
+  AppRpcMetricsC sse_decode_app_rpc_metrics_c(SseDeserializer deserializer);
+
+  @protected
+  AppRpcPolicyC sse_decode_app_rpc_policy_c(SseDeserializer deserializer);
+
+  @protected
+  AppRpcRejectionsC sse_decode_app_rpc_rejections_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AppStreamProgressC sse_decode_app_stream_progress_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AppStreamStateC sse_decode_app_stream_state_c(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  AppRpcPolicyC sse_decode_box_autoadd_app_rpc_policy_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BroadcastScopeC sse_decode_box_autoadd_broadcast_scope_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FlagsC sse_decode_box_autoadd_flags_c(SseDeserializer deserializer);
+
+  @protected
+  Forward sse_decode_box_autoadd_forward(SseDeserializer deserializer);
+
+  @protected
+  LocalEventC sse_decode_box_autoadd_local_event_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NodeKeyInfoC sse_decode_box_autoadd_node_key_info_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PresenceProfileC sse_decode_box_autoadd_presence_profile_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BroadcastDeliveryC sse_decode_broadcast_delivery_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BroadcastScopeC sse_decode_broadcast_scope_c(SseDeserializer deserializer);
+
+  @protected
+  BroadcastScopeKindC sse_decode_broadcast_scope_kind_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ChannelMetricsC sse_decode_channel_metrics_c(SseDeserializer deserializer);
+
+  @protected
+  ChannelRejectionsC sse_decode_channel_rejections_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ChatMessageC sse_decode_chat_message_c(SseDeserializer deserializer);
+
+  @protected
+  ChatMessageKindC sse_decode_chat_message_kind_c(SseDeserializer deserializer);
+
+  @protected
+  ConnectErrorC sse_decode_connect_error_c(SseDeserializer deserializer);
+
+  @protected
+  CredentialEventC sse_decode_credential_event_c(SseDeserializer deserializer);
+
+  @protected
+  CredentialEventKindC sse_decode_credential_event_kind_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DecodedPayloadC sse_decode_decoded_payload_c(SseDeserializer deserializer);
+
+  @protected
+  DiagnosticCheckC sse_decode_diagnostic_check_c(SseDeserializer deserializer);
+
+  @protected
+  DiagnosticCheckKindC sse_decode_diagnostic_check_kind_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DiagnosticReportC sse_decode_diagnostic_report_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DiagnosticStatusC sse_decode_diagnostic_status_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EndpointOutcomeC sse_decode_endpoint_outcome_c(SseDeserializer deserializer);
+
+  @protected
+  EndpointResultC sse_decode_endpoint_result_c(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FileOfferC sse_decode_file_offer_c(SseDeserializer deserializer);
+
+  @protected
+  FileTransferDirectionC sse_decode_file_transfer_direction_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FileTransferProgressC sse_decode_file_transfer_progress_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FileTransferStateC sse_decode_file_transfer_state_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FirewallBackendC sse_decode_firewall_backend_c(SseDeserializer deserializer);
+
+  @protected
+  FirewallDiagnosisC sse_decode_firewall_diagnosis_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FirewallError sse_decode_firewall_error(SseDeserializer deserializer);
+
+  @protected
+  FirewallErrorKind sse_decode_firewall_error_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FirewallFindingC sse_decode_firewall_finding_c(SseDeserializer deserializer);
+
+  @protected
+  FirewallPortC sse_decode_firewall_port_c(SseDeserializer deserializer);
+
+  @protected
+  FirewallPortStatusC sse_decode_firewall_port_status_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FirewallProfile sse_decode_firewall_profile(SseDeserializer deserializer);
+
+  @protected
+  FirewallProfileStatusC sse_decode_firewall_profile_status_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FirewallRuleC sse_decode_firewall_rule_c(SseDeserializer deserializer);
+
+  @protected
+  FirewallRuleKindC sse_decode_firewall_rule_kind_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FirewallSnapshotC sse_decode_firewall_snapshot_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FlagsC sse_decode_flags_c(SseDeserializer deserializer);
+
+  @protected
+  Forward sse_decode_forward(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  InboundAppStreamC sse_decode_inbound_app_stream_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  KVNetworkStatus sse_decode_kv_network_status(SseDeserializer deserializer);
+
+  @protected
+  KVNodeConnectionStats sse_decode_kv_node_connection_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  KVNodeInfo sse_decode_kv_node_info(SseDeserializer deserializer);
+
+  @protected
+  LatencyHistogramC sse_decode_latency_histogram_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PeerRoutePair>
+  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<BroadcastDeliveryC> sse_decode_list_broadcast_delivery_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ChannelMetricsC> sse_decode_list_channel_metrics_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ChannelRejectionsC> sse_decode_list_channel_rejections_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ChatMessageC> sse_decode_list_chat_message_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ConnectErrorC> sse_decode_list_connect_error_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DiagnosticCheckC> sse_decode_list_diagnostic_check_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FileOfferC> sse_decode_list_file_offer_c(SseDeserializer deserializer);
+
+  @protected
+  List<FirewallFindingC> sse_decode_list_firewall_finding_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FirewallPortC> sse_decode_list_firewall_port_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FirewallPortStatusC> sse_decode_list_firewall_port_status_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FirewallProfile> sse_decode_list_firewall_profile(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FirewallProfileStatusC> sse_decode_list_firewall_profile_status_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FirewallRuleC> sse_decode_list_firewall_rule_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<Forward> sse_decode_list_forward(SseDeserializer deserializer);
+
+  @protected
+  List<KVNodeConnectionStats> sse_decode_list_kv_node_connection_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<KVNodeInfo> sse_decode_list_kv_node_info(SseDeserializer deserializer);
+
+  @protected
+  List<ListenerMappingC> sse_decode_list_listener_mapping_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<NatCompatibilityC> sse_decode_list_nat_compatibility_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<NodeHopStats> sse_decode_list_node_hop_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PayloadCodecC> sse_decode_list_payload_codec_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PeerChannelRuleC> sse_decode_list_peer_channel_rule_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PeerConnectionC> sse_decode_list_peer_connection_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PeerMetricsC> sse_decode_list_peer_metrics_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PeerPresenceC> sse_decode_list_peer_presence_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PeerProxyCidrsC> sse_decode_list_peer_proxy_cidrs_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PeerRejectionsC> sse_decode_list_peer_rejections_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PinnedServerKeyC> sse_decode_list_pinned_server_key_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PortForwardC> sse_decode_list_port_forward_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PortMappingRequestC> sse_decode_list_port_mapping_request_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<ProxyCidrC> sse_decode_list_proxy_cidr_c(SseDeserializer deserializer);
+
+  @protected
+  List<ProxyCidrConflictC> sse_decode_list_proxy_cidr_conflict_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ReasonRejectionsC> sse_decode_list_reason_rejections_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SelectedServerC> sse_decode_list_selected_server_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ServerProbeResultC> sse_decode_list_server_probe_result_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ListenerMappingC sse_decode_listener_mapping_c(SseDeserializer deserializer);
+
+  @protected
+  LocalEndpointsC sse_decode_local_endpoints_c(SseDeserializer deserializer);
+
+  @protected
+  LocalEventC sse_decode_local_event_c(SseDeserializer deserializer);
+
+  @protected
+  MessageEventC sse_decode_message_event_c(SseDeserializer deserializer);
+
+  @protected
+  MessageEventKindC sse_decode_message_event_kind_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NatCompatibilityC sse_decode_nat_compatibility_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NatDetectionC sse_decode_nat_detection_c(SseDeserializer deserializer);
+
+  @protected
+  NatTypeC sse_decode_nat_type_c(SseDeserializer deserializer);
+
+  @protected
+  NodeHopStats sse_decode_node_hop_stats(SseDeserializer deserializer);
+
+  @protected
+  NodeKeyInfoC sse_decode_node_key_info_c(SseDeserializer deserializer);
+
+  @protected
+  AppRpcPolicyC? sse_decode_opt_box_autoadd_app_rpc_policy_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LocalEventC? sse_decode_opt_box_autoadd_local_event_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NodeKeyInfoC? sse_decode_opt_box_autoadd_node_key_info_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PresenceProfileC? sse_decode_opt_box_autoadd_presence_profile_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  P2pLikelihoodC sse_decode_p_2_p_likelihood_c(SseDeserializer deserializer);
+
+  @protected
+  PayloadCodecC sse_decode_payload_codec_c(SseDeserializer deserializer);
+
+  @protected
+  PeerChannelRuleC sse_decode_peer_channel_rule_c(SseDeserializer deserializer);
+
+  @protected
+  PeerConnectionC sse_decode_peer_connection_c(SseDeserializer deserializer);
+
+  @protected
+  PeerMetricsC sse_decode_peer_metrics_c(SseDeserializer deserializer);
+
+  @protected
+  PeerPresenceC sse_decode_peer_presence_c(SseDeserializer deserializer);
+
+  @protected
+  PeerProxyCidrsC sse_decode_peer_proxy_cidrs_c(SseDeserializer deserializer);
+
+  @protected
+  PeerRejectionsC sse_decode_peer_rejections_c(SseDeserializer deserializer);
+
+  @protected
+  PinnedServerKeyC sse_decode_pinned_server_key_c(SseDeserializer deserializer);
+
+  @protected
+  PortForwardC sse_decode_port_forward_c(SseDeserializer deserializer);
+
+  @protected
+  PortMappingC sse_decode_port_mapping_c(SseDeserializer deserializer);
+
+  @protected
+  PortMappingRequestC sse_decode_port_mapping_request_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PortMappingStateC sse_decode_port_mapping_state_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PresenceEventC sse_decode_presence_event_c(SseDeserializer deserializer);
+
+  @protected
+  PresenceEventKindC sse_decode_presence_event_kind_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PresenceProfileC sse_decode_presence_profile_c(SseDeserializer deserializer);
+
+  @protected
+  PresenceStatusC sse_decode_presence_status_c(SseDeserializer deserializer);
+
+  @protected
+  ProxyCidrC sse_decode_proxy_cidr_c(SseDeserializer deserializer);
+
+  @protected
+  ProxyCidrConflictC sse_decode_proxy_cidr_conflict_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProxyCidrConflictKindC sse_decode_proxy_cidr_conflict_kind_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RateLimitC sse_decode_rate_limit_c(SseDeserializer deserializer);
+
+  @protected
+  ReasonRejectionsC sse_decode_reason_rejections_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RejectReasonC sse_decode_reject_reason_c(SseDeserializer deserializer);
+
+  @protected
+  SelectedServerC sse_decode_selected_server_c(SseDeserializer deserializer);
+
+  @protected
+  ServerProbeResultC sse_decode_server_probe_result_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ServerRoleC sse_decode_server_role_c(SseDeserializer deserializer);
+
+  @protected
+  ServerSelectionStatusC sse_decode_server_selection_status_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TunFirewallStatusC sse_decode_tun_firewall_status_c(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+    EventBusSubscriber self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
+    JoinHandle self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    JoinHandleResultStringString self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    PeerRoutePair self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
+    EventBusSubscriber self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
+    JoinHandle self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
+    JoinHandleResultStringString self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    PeerRoutePair self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_app_call_control_c_Sse(
+    RustStreamSink<AppCallControlC> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_app_inbound_event_c_Sse(
+    RustStreamSink<AppInboundEventC> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_app_stream_progress_c_Sse(
+    RustStreamSink<AppStreamProgressC> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_credential_event_c_Sse(
+    RustStreamSink<CredentialEventC> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_file_offer_c_Sse(
+    RustStreamSink<FileOfferC> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_file_transfer_progress_c_Sse(
+    RustStreamSink<FileTransferProgressC> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_inbound_app_stream_c_Sse(
+    RustStreamSink<InboundAppStreamC> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_list_prim_u_8_strict_Sse(
+    RustStreamSink<Uint8List> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_listener_mapping_c_Sse(
+    RustStreamSink<ListenerMappingC> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_message_event_c_Sse(
+    RustStreamSink<MessageEventC> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_presence_event_c_Sse(
+    RustStreamSink<PresenceEventC> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_app_call_control_c(
+    AppCallControlC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_app_call_control_kind_c(
+    AppCallControlKindC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_app_call_decoded_c(
+    AppCallDecodedC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_app_call_result_c(
+    AppCallResultC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_app_inbound_event_c(
+    AppInboundEventC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_app_inbound_kind_c(
+    AppInboundKindC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_app_rpc_counters_c(
+    AppRpcCountersC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_app_rpc_metrics_c(
+    AppRpcMetricsC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_app_rpc_policy_c(
+    AppRpcPolicyC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_app_rpc_rejections_c(
+    AppRpcRejectionsC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_app_stream_progress_c(
+    AppStreamProgressC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_app_stream_state_c(
+    AppStreamStateC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_app_rpc_policy_c(
+    AppRpcPolicyC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_broadcast_scope_c(
+    BroadcastScopeC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_flags_c(FlagsC self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_forward(Forward self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_local_event_c(
+    LocalEventC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_node_key_info_c(
+    NodeKeyInfoC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_presence_profile_c(
+    PresenceProfileC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_broadcast_delivery_c(
+    BroadcastDeliveryC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_broadcast_scope_c(
+    BroadcastScopeC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_broadcast_scope_kind_c(
+    BroadcastScopeKindC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_channel_metrics_c(
+    ChannelMetricsC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_channel_rejections_c(
+    ChannelRejectionsC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_chat_message_c(ChatMessageC self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_chat_message_kind_c(
+    ChatMessageKindC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_connect_error_c(ConnectErrorC self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_credential_event_c(
+    CredentialEventC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_credential_event_kind_c(
+    CredentialEventKindC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_decoded_payload_c(
+    DecodedPayloadC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_diagnostic_check_c(
+    DiagnosticCheckC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_diagnostic_check_kind_c(
+    DiagnosticCheckKindC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_diagnostic_report_c(
+    DiagnosticReportC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_diagnostic_status_c(
+    DiagnosticStatusC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_endpoint_outcome_c(
+    EndpointOutcomeC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_endpoint_result_c(
+    EndpointResultC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_file_offer_c(FileOfferC self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_file_transfer_direction_c(
+    FileTransferDirectionC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_file_transfer_progress_c(
+    FileTransferProgressC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_file_transfer_state_c(
+    FileTransferStateC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_firewall_backend_c(
+    FirewallBackendC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_firewall_diagnosis_c(
+    FirewallDiagnosisC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_firewall_error(FirewallError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_firewall_error_kind(
+    FirewallErrorKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_firewall_finding_c(
+    FirewallFindingC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_firewall_port_c(FirewallPortC self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_firewall_port_status_c(
+    FirewallPortStatusC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_firewall_profile(
+    FirewallProfile self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_firewall_profile_status_c(
+    FirewallProfileStatusC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_firewall_rule_c(FirewallRuleC self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_firewall_rule_kind_c(
+    FirewallRuleKindC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_firewall_snapshot_c(
+    FirewallSnapshotC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_flags_c(FlagsC self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_forward(Forward self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_inbound_app_stream_c(
+    InboundAppStreamC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_kv_network_status(
+    KVNetworkStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_kv_node_connection_stats(
+    KVNodeConnectionStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_kv_node_info(KVNodeInfo self, SseSerializer serializer);
 
   @protected
-  List<KVNodeConnectionStats> dco_decode_list_kv_node_connection_stats(
-    dynamic raw,
+  void sse_encode_latency_histogram_c(
+    LatencyHistogramC self,
+    SseSerializer serializer,
   );
 
   @protected
-  List<KVNodeInfo> dco_decode_list_kv_node_info(dynamic raw);
+  void
+  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
+    List<PeerRoutePair> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<NodeHopStats> dco_decode_list_node_hop_stats(dynamic raw);
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+  void sse_encode_list_broadcast_delivery_c(
+    List<BroadcastDeliveryC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  void sse_encode_list_channel_metrics_c(
+    List<ChannelMetricsC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  NodeHopStats dco_decode_node_hop_stats(dynamic raw);
+  void sse_encode_list_channel_rejections_c(
+    List<ChannelRejectionsC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  void sse_encode_list_chat_message_c(
+    List<ChatMessageC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw);
+  void sse_encode_list_connect_error_c(
+    List<ConnectErrorC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  void sse_encode_list_diagnostic_check_c(
+    List<DiagnosticCheckC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  void sse_encode_list_file_offer_c(
+    List<FileOfferC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt dco_decode_usize(dynamic raw);
+  void sse_encode_list_firewall_finding_c(
+    List<FirewallFindingC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  void sse_encode_list_firewall_port_c(
+    List<FirewallPortC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  EventBusSubscriber
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    SseDeserializer deserializer,
+  void sse_encode_list_firewall_port_status_c(
+    List<FirewallPortStatusC> self,
+    SseSerializer serializer,
   );
 
   @protected
-  JoinHandle
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    SseDeserializer deserializer,
+  void sse_encode_list_firewall_profile(
+    List<FirewallProfile> self,
+    SseSerializer serializer,
   );
 
   @protected
-  JoinHandleResultStringString
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    SseDeserializer deserializer,
+  void sse_encode_list_firewall_profile_status_c(
+    List<FirewallProfileStatusC> self,
+    SseSerializer serializer,
   );
 
   @protected
-  PeerRoutePair
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    SseDeserializer deserializer,
+  void sse_encode_list_firewall_rule_c(
+    List<FirewallRuleC> self,
+    SseSerializer serializer,
   );
 
   @protected
-  EventBusSubscriber
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    SseDeserializer deserializer,
-  );
+  void sse_encode_list_forward(List<Forward> self, SseSerializer serializer);
 
   @protected
-  JoinHandle
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    SseDeserializer deserializer,
+  void sse_encode_list_kv_node_connection_stats(
+    List<KVNodeConnectionStats> self,
+    SseSerializer serializer,
   );
 
   @protected
-  JoinHandleResultStringString
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    SseDeserializer deserializer,
+  void sse_encode_list_kv_node_info(
+    List<KVNodeInfo> self,
+    SseSerializer serializer,
   );
 
   @protected
-  PeerRoutePair
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    SseDeserializer deserializer,
+  void sse_encode_list_listener_mapping_c(
+    List<ListenerMappingC> self,
+    SseSerializer serializer,
   );
 
   @protected
-  RustStreamSink<AppInboundEventC>
-  sse_decode_StreamSink_app_inbound_event_c_Sse(SseDeserializer deserializer);
+  void sse_encode_list_nat_compatibility_c(
+    List<NatCompatibilityC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_list_node_hop_stats(
+    List<NodeHopStats> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AppCallResultC sse_decode_app_call_result_c(SseDeserializer deserializer);
+  void sse_encode_list_payload_codec_c(
+    List<PayloadCodecC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AppInboundEventC sse_decode_app_inbound_event_c(SseDeserializer deserializer);
+  void sse_encode_list_peer_channel_rule_c(
+    List<PeerChannelRuleC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AppInboundKindC sse_decode_app_inbound_kind_c(SseDeserializer deserializer);
+  void sse_encode_list_peer_connection_c(
+    List<PeerConnectionC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_list_peer_metrics_c(
+    List<PeerMetricsC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  FlagsC sse_decode_box_autoadd_flags_c(SseDeserializer deserializer);
+  void sse_encode_list_peer_presence_c(
+    List<PeerPresenceC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+  void sse_encode_list_peer_proxy_cidrs_c(
+    List<PeerProxyCidrsC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+  void sse_encode_list_peer_rejections_c(
+    List<PeerRejectionsC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  FlagsC sse_decode_flags_c(SseDeserializer deserializer);
+  void sse_encode_list_pinned_server_key_c(
+    List<PinnedServerKeyC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Forward sse_decode_forward(SseDeserializer deserializer);
+  void sse_encode_list_port_forward_c(
+    List<PortForwardC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_list_port_mapping_request_c(
+    List<PortMappingRequestC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+  void sse_encode_list_prim_f_64_strict(
+    Float64List self,
+    SseSerializer serializer,
+  );
 
   @protected
-  KVNetworkStatus sse_decode_kv_network_status(SseDeserializer deserializer);
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  );
 
   @protected
-  KVNodeConnectionStats sse_decode_kv_node_connection_stats(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_u_64_strict(
+    Uint64List self,
+    SseSerializer serializer,
   );
 
   @protected
-  KVNodeInfo sse_decode_kv_node_info(SseDeserializer deserializer);
+  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
 
   @protected
-  List<PeerRoutePair>
-  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
   );
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  void sse_encode_list_proxy_cidr_c(
+    List<ProxyCidrC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<Forward> sse_decode_list_forward(SseDeserializer deserializer);
+  void sse_encode_list_proxy_cidr_conflict_c(
+    List<ProxyCidrConflictC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<KVNodeConnectionStats> sse_decode_list_kv_node_connection_stats(
-    SseDeserializer deserializer,
+  void sse_encode_list_reason_rejections_c(
+    List<ReasonRejectionsC> self,
+    SseSerializer serializer,
   );
 
   @protected
-  List<KVNodeInfo> sse_decode_list_kv_node_info(SseDeserializer deserializer);
+  void sse_encode_list_selected_server_c(
+    List<SelectedServerC> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<NodeHopStats> sse_decode_list_node_hop_stats(
-    SseDeserializer deserializer,
+  void sse_encode_list_server_probe_result_c(
+    List<ServerProbeResultC> self,
+    SseSerializer serializer,
   );
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+  void sse_encode_listener_mapping_c(
+    ListenerMappingC self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_local_endpoints_c(
+    LocalEndpointsC self,
+    SseSerializer serializer,
+  );
 
   @protected
-  NodeHopStats sse_decode_node_hop_stats(SseDeserializer deserializer);
+  void sse_encode_local_event_c(LocalEventC self, SseSerializer serializer);
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  void sse_encode_message_event_c(MessageEventC self, SseSerializer serializer);
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  void sse_encode_message_event_kind_c(
+    MessageEventKindC self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_nat_compatibility_c(
+    NatCompatibilityC self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_nat_detection_c(NatDetectionC self, SseSerializer serializer);
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  void sse_encode_nat_type_c(NatTypeC self, SseSerializer serializer);
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  );
+  void sse_encode_node_hop_stats(NodeHopStats self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    EventBusSubscriber self,
-    SseSerializer serializer,
-  );
+  void sse_encode_node_key_info_c(NodeKeyInfoC self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    JoinHandle self,
+  void sse_encode_opt_box_autoadd_app_rpc_policy_c(
+    AppRpcPolicyC? self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    JoinHandleResultStringString self,
+  void sse_encode_opt_box_autoadd_local_event_c(
+    LocalEventC? self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    PeerRoutePair self,
+  void sse_encode_opt_box_autoadd_node_key_info_c(
+    NodeKeyInfoC? self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEventBusSubscriber(
-    EventBusSubscriber self,
+  void sse_encode_opt_box_autoadd_presence_profile_c(
+    PresenceProfileC? self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandle(
-    JoinHandle self,
+  void sse_encode_p_2_p_likelihood_c(
+    P2pLikelihoodC self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJoinHandleResultStringString(
-    JoinHandleResultStringString self,
-    SseSerializer serializer,
-  );
+  void sse_encode_payload_codec_c(PayloadCodecC self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    PeerRoutePair self,
+  void sse_encode_peer_channel_rule_c(
+    PeerChannelRuleC self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_StreamSink_app_inbound_event_c_Sse(
-    RustStreamSink<AppInboundEventC> self,
+  void sse_encode_peer_connection_c(
+    PeerConnectionC self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_peer_metrics_c(PeerMetricsC self, SseSerializer serializer);
 
   @protected
-  void sse_encode_app_call_result_c(
-    AppCallResultC self,
-    SseSerializer serializer,
-  );
+  void sse_encode_peer_presence_c(PeerPresenceC self, SseSerializer serializer);
 
   @protected
-  void sse_encode_app_inbound_event_c(
-    AppInboundEventC self,
+  void sse_encode_peer_proxy_cidrs_c(
+    PeerProxyCidrsC self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_app_inbound_kind_c(
-    AppInboundKindC self,
+  void sse_encode_peer_rejections_c(
+    PeerRejectionsC self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_flags_c(FlagsC self, SseSerializer serializer);
+  void sse_encode_pinned_server_key_c(
+    PinnedServerKeyC self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
+  void sse_encode_port_forward_c(PortForwardC self, SseSerializer serializer);
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
+  void sse_encode_port_mapping_c(PortMappingC self, SseSerializer serializer);
 
   @protected
-  void sse_encode_flags_c(FlagsC self, SseSerializer serializer);
+  void sse_encode_port_mapping_request_c(
+    PortMappingRequestC self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_forward(Forward self, SseSerializer serializer);
+  void sse_encode_port_mapping_state_c(
+    PortMappingStateC self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_presence_event_c(
+    PresenceEventC self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+  void sse_encode_presence_event_kind_c(
+    PresenceEventKindC self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_kv_network_status(
-    KVNetworkStatus self,
+  void sse_encode_presence_profile_c(
+    PresenceProfileC self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_kv_node_connection_stats(
-    KVNodeConnectionStats self,
+  void sse_encode_presence_status_c(
+    PresenceStatusC self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_kv_node_info(KVNodeInfo self, SseSerializer serializer);
+  void sse_encode_proxy_cidr_c(ProxyCidrC self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPeerRoutePair(
-    List<PeerRoutePair> self,
+  void sse_encode_proxy_cidr_conflict_c(
+    ProxyCidrConflictC self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+  void sse_encode_proxy_cidr_conflict_kind_c(
+    ProxyCidrConflictKindC self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_list_forward(List<Forward> self, SseSerializer serializer);
+  void sse_encode_rate_limit_c(RateLimitC self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_kv_node_connection_stats(
-    List<KVNodeConnectionStats> self,
+  void sse_encode_reason_rejections_c(
+    ReasonRejectionsC self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_kv_node_info(
-    List<KVNodeInfo> self,
+  void sse_encode_reject_reason_c(RejectReasonC self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_selected_server_c(
+    SelectedServerC self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_node_hop_stats(
-    List<NodeHopStats> self,
+  void sse_encode_server_probe_result_c(
+    ServerProbeResultC self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+  void sse_encode_server_role_c(ServerRoleC self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_server_selection_status_c(
+    ServerSelectionStatusC self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_node_hop_stats(NodeHopStats self, SseSerializer serializer);
+  void sse_encode_tun_firewall_status_c(
+    TunFirewallStatusC self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
 
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
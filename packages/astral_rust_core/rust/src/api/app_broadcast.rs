@@ -0,0 +1,100 @@
+// Room-wide app notifies.
+//
+// `app_notify` addresses exactly one peer, so chat, "game starting" or
+// presence messages used to loop over `get_network_status` in Dart. Here the
+// target set is resolved from the instance's route table in Rust and the
+// notifies are sent with bounded concurrency, returning one delivery result
+// per peer.
+
+use std::collections::BTreeSet;
+
+use futures::stream::{self, StreamExt};
+
+use super::app_metrics;
+use super::p2p::{get_instance_info, lookup_app_rpc};
+
+/// Notifies in flight at once per broadcast.
+const MAX_CONCURRENCY: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BroadcastScopeKindC {
+    /// Every peer in the route table, including ones reached through relays.
+    All,
+    /// Only peers with a direct connection to this node.
+    DirectOnly,
+    /// Exactly the ids in [`BroadcastScopeC::peer_ids`].
+    Peers,
+}
+
+/// Flat struct rather than an enum with data, like `AppInboundEventC`.
+#[derive(Debug, Clone)]
+pub struct BroadcastScopeC {
+    pub kind: BroadcastScopeKindC,
+    /// Only used with [`BroadcastScopeKindC::Peers`].
+    pub peer_ids: Vec<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BroadcastDeliveryC {
+    pub peer_id: u32,
+    /// The peer acknowledged the notify within `timeout_ms`.
+    pub delivered: bool,
+    pub error: String,
+}
+
+/// Send the same notify to every peer in `scope`. The local node is never a
+/// target. Results are sorted by peer id.
+pub async fn app_broadcast(
+    instance_id: String,
+    channel: String,
+    payload: Vec<u8>,
+    scope: BroadcastScopeC,
+    timeout_ms: i32,
+) -> Result<Vec<BroadcastDeliveryC>, String> {
+    let svc = lookup_app_rpc(&instance_id)?;
+    let my_peer_id = svc.my_peer_id();
+    let targets: BTreeSet<u32> = match scope.kind {
+        BroadcastScopeKindC::Peers => scope.peer_ids.into_iter().collect(),
+        BroadcastScopeKindC::All => {
+            let info = get_instance_info(&instance_id).await?;
+            info.routes.iter().map(|r| r.peer_id).collect()
+        }
+        BroadcastScopeKindC::DirectOnly => {
+            let info = get_instance_info(&instance_id).await?;
+            info.peers.iter().map(|p| p.peer_id).collect()
+        }
+    };
+
+    // Awaited on the caller's executor rather than spawned, like `app_call`.
+    let mut results: Vec<BroadcastDeliveryC> =
+        stream::iter(targets.into_iter().filter(|id| *id != my_peer_id))
+            .map(|peer_id| {
+                let svc = svc.clone();
+                let channel = channel.clone();
+                let payload = payload.clone();
+                let instance_id = &instance_id;
+                async move {
+                    let result = svc
+                        .notify(peer_id, channel.clone(), payload, timeout_ms)
+                        .await;
+                    app_metrics::record_notify(instance_id, peer_id, &channel, result.is_ok());
+                    match result {
+                        Ok(()) => BroadcastDeliveryC {
+                            peer_id,
+                            delivered: true,
+                            error: String::new(),
+                        },
+                        Err(e) => BroadcastDeliveryC {
+                            peer_id,
+                            delivered: false,
+                            error: e.to_string(),
+                        },
+                    }
+                }
+            })
+            .buffer_unordered(MAX_CONCURRENCY)
+            .collect()
+            .await;
+    results.sort_by_key(|r| r.peer_id);
+    Ok(results)
+}
@@ -0,0 +1,439 @@
+// Deadlines and cancellation for app RPC calls.
+//
+// The EasyTier envelope only carries `channel`, `request_id` and the payload,
+// so call metadata travels next to the call as `Notify`s on the reserved
+// channel [`CALL_CHANNEL`], correlated by `(caller peer id, request_id)`:
+//
+//   deadline { request_id, timeout_ms }    sent alongside the call
+//   cancel   { request_id }                sent by `cancel_app_call`
+//
+// The caller sends its timeout rather than an absolute time, so the two
+// clocks don't have to agree: the callee turns it into a deadline on its own
+// clock when the metadata arrives. The callee matches the metadata with the
+// inbound call (whichever arrives first) and reports it by the call's
+// `token`: [`subscribe_app_call_control`] streams deadline and cancellation
+// events, [`is_app_call_cancelled`] and [`app_call_deadline`] can be polled
+// from a running handler. Tracking starts when the instance does.
+//
+// Only calls made with [`app_call_with_deadline`] carry metadata, and their
+// `request_id` must be unique per caller and callee while in flight.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use lazy_static::lazy_static;
+use serde_json::{json, Value};
+use tokio::sync::{broadcast, watch};
+
+use super::app_metrics;
+use super::app_router::{is_reserved, subscribe_inbound};
+use super::p2p::{
+    app_rpc_status, lookup_app_rpc, runtime, tracing_log_lagged, AppCallResultC, AppInboundEventC,
+    AppInboundKindC,
+};
+use crate::frb_generated::StreamSink;
+
+/// Reserved channel carrying call deadlines and cancellations.
+pub(crate) const CALL_CHANNEL: &str = "astral.call";
+
+/// Inbound calls (and metadata for calls not seen yet) are forgotten after
+/// this long even if they were never replied to.
+const TRACK_TTL: Duration = Duration::from_secs(120);
+
+lazy_static! {
+    /// Cancel switches of outgoing calls by (instance, callee, request_id).
+    static ref OUTGOING: Mutex<HashMap<(String, u32, u64), watch::Sender<bool>>> =
+        Mutex::new(HashMap::new());
+    static ref TRACKERS: Mutex<HashMap<String, Tracker>> = Mutex::new(HashMap::new());
+    static ref EVENTS: broadcast::Sender<(String, AppCallControlC)> = broadcast::channel(256).0;
+}
+
+static GENERATION: AtomicU64 = AtomicU64::new(1);
+
+struct Tracker {
+    /// Generation of the running handler task.
+    generation: u64,
+    /// Inbound calls by (caller peer id, request_id).
+    calls: HashMap<(u32, u64), InboundCall>,
+}
+
+#[derive(Default)]
+struct InboundCall {
+    /// 0 until the call itself has arrived.
+    token: u64,
+    /// Unix ms on this node's clock; 0 when unknown.
+    deadline_ms: u64,
+    cancelled: bool,
+    since: Option<Instant>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppCallControlKindC {
+    Deadline,
+    Cancelled,
+}
+
+/// Flat struct rather than an enum with data, like `AppInboundEventC`.
+#[derive(Debug, Clone)]
+pub struct AppCallControlC {
+    pub kind: AppCallControlKindC,
+    pub from_peer_id: u32,
+    pub request_id: u64,
+    /// The inbound call's token, as in `AppInboundEventC`.
+    pub token: u64,
+    /// Unix ms on this node's clock; 0 when the caller sent no deadline.
+    pub deadline_ms: u64,
+}
+
+/// `app_call` that sends its `timeout_ms` to the callee as a deadline and
+/// can be cancelled with [`cancel_app_call`] using the same `dst_peer_id` and
+/// `request_id`. A cancelled call resolves right away with
+/// `app_rpc_status::CALL_CANCELLED`.
+pub async fn app_call_with_deadline(
+    instance_id: String,
+    dst_peer_id: u32,
+    channel: String,
+    request_id: u64,
+    payload: Vec<u8>,
+    flags: u32,
+    timeout_ms: i32,
+) -> Result<AppCallResultC, String> {
+    if request_id == 0 {
+        return Err("request_id must be non-zero to correlate the deadline".to_string());
+    }
+    let svc = lookup_app_rpc(&instance_id)?;
+    let key = (instance_id.clone(), dst_peer_id, request_id);
+    let (cancel_tx, mut cancel_rx) = watch::channel(false);
+    {
+        let mut outgoing = OUTGOING.lock().unwrap();
+        if outgoing.contains_key(&key) {
+            return Err(format!(
+                "call {} to peer {} is already in flight",
+                request_id, dst_peer_id
+            ));
+        }
+        outgoing.insert(key.clone(), cancel_tx);
+    }
+
+    let deadline = json!({
+        "op": "deadline",
+        "request_id": request_id,
+        "timeout_ms": timeout_ms.max(0),
+    });
+    // Fire and forget: the call must not wait for the notify, and nothing
+    // awaits this task (see the note on `app_call` about awaiting `RT` tasks).
+    // The callee may see the deadline or the call first.
+    let notify_svc = svc.clone();
+    runtime().spawn(async move {
+        let _ = notify_svc
+            .notify(
+                dst_peer_id,
+                CALL_CHANNEL.to_string(),
+                deadline.to_string().into_bytes(),
+                timeout_ms,
+            )
+            .await;
+    });
+    let started = Instant::now();
+    // Awaited on the FRB executor like `app_call`; see the note there.
+    let call = svc.call(
+        dst_peer_id,
+        channel.clone(),
+        request_id,
+        payload,
+        flags,
+        timeout_ms,
+    );
+    let cancelled = async {
+        if cancel_rx.wait_for(|c| *c).await.is_err() {
+            // Instance closed; the call itself fails shortly.
+            std::future::pending::<()>().await;
+        }
+    };
+    let result = tokio::select! {
+        resp = call => Some(resp),
+        _ = cancelled => None,
+    };
+    OUTGOING.lock().unwrap().remove(&key);
+
+    let Some(resp) = result else {
+        app_metrics::record_call(
+            &instance_id,
+            dst_peer_id,
+            &channel,
+            started,
+            Some(app_rpc_status::CALL_CANCELLED),
+        );
+        return Ok(AppCallResultC {
+            status: app_rpc_status::CALL_CANCELLED,
+            error_msg: "call cancelled".to_string(),
+            payload: Vec::new(),
+        });
+    };
+    app_metrics::record_call(
+        &instance_id,
+        dst_peer_id,
+        &channel,
+        started,
+        resp.as_ref().ok().map(|r| r.status),
+    );
+    let resp = resp.map_err(|e| e.to_string())?;
+    Ok(AppCallResultC {
+        status: resp.status,
+        error_msg: resp.error_msg,
+        payload: resp.payload,
+    })
+}
+
+/// Cancel a call made with [`app_call_with_deadline`] and tell the callee.
+/// Returns `false` if no such call is in flight.
+pub async fn cancel_app_call(
+    instance_id: String,
+    dst_peer_id: u32,
+    request_id: u64,
+) -> Result<bool, String> {
+    let cancel_tx =
+        OUTGOING
+            .lock()
+            .unwrap()
+            .remove(&(instance_id.clone(), dst_peer_id, request_id));
+    let Some(cancel_tx) = cancel_tx else {
+        return Ok(false);
+    };
+    let _ = cancel_tx.send(true);
+    let svc = lookup_app_rpc(&instance_id)?;
+    let cancel = json!({ "op": "cancel", "request_id": request_id });
+    // Best effort: the callee may already have replied or gone away.
+    let _ = svc
+        .notify(
+            dst_peer_id,
+            CALL_CHANNEL.to_string(),
+            cancel.to_string().into_bytes(),
+            5_000,
+        )
+        .await;
+    Ok(true)
+}
+
+/// Deadlines and cancellations of inbound calls. Calls are tracked from the
+/// start of the instance, so the state of calls that arrived before the
+/// subscription can still be polled; events are only streamed from now on.
+pub async fn subscribe_app_call_control(
+    instance_id: String,
+    sink: StreamSink<AppCallControlC>,
+) -> Result<(), String> {
+    lookup_app_rpc(&instance_id)?;
+    let mut events = EVENTS.subscribe();
+    loop {
+        match events.recv().await {
+            Ok((id, event)) => {
+                if id == instance_id && sink.add(event).is_err() {
+                    break;
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                tracing_log_lagged(&instance_id, skipped);
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+    Ok(())
+}
+
+/// Whether the caller cancelled the inbound call `token`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_app_call_cancelled(instance_id: String, token: u64) -> bool {
+    with_call(&instance_id, token, |c| c.cancelled).unwrap_or_default()
+}
+
+/// Deadline of the inbound call `token` in Unix ms; 0 when unknown.
+#[flutter_rust_bridge::frb(sync)]
+pub fn app_call_deadline(instance_id: String, token: u64) -> u64 {
+    with_call(&instance_id, token, |c| c.deadline_ms).unwrap_or_default()
+}
+
+/// The inbound call was replied to; stop tracking it.
+pub(crate) fn forget_call(instance_id: &str, token: u64) {
+    if let Some(tracker) = TRACKERS.lock().unwrap().get_mut(instance_id) {
+        tracker.calls.retain(|_, c| c.token != token);
+    }
+}
+
+/// Whether the channel router must leave `channel` to this module.
+pub(crate) fn is_handled(instance_id: &str, channel: &str) -> bool {
+    channel == CALL_CHANNEL && TRACKERS.lock().unwrap().contains_key(instance_id)
+}
+
+/// Start tracking inbound calls once the instance's app RPC service is up,
+/// or move tracking onto the new service after a restart. Calls in flight at
+/// the restart are forgotten.
+pub(crate) fn watch_instance(instance_id: &str) {
+    let Ok(svc) = lookup_app_rpc(instance_id) else {
+        return;
+    };
+    let generation = GENERATION.fetch_add(1, Ordering::Relaxed);
+    TRACKERS.lock().unwrap().insert(
+        instance_id.to_string(),
+        Tracker {
+            generation,
+            calls: HashMap::new(),
+        },
+    );
+    let rx = subscribe_inbound(instance_id, &svc);
+    runtime().spawn(track(instance_id.to_string(), generation, rx));
+}
+
+pub(crate) fn forget_instance(instance_id: &str) {
+    TRACKERS.lock().unwrap().remove(instance_id);
+    OUTGOING
+        .lock()
+        .unwrap()
+        .retain(|(id, _, _), _| id != instance_id);
+}
+
+fn with_call<T>(instance_id: &str, token: u64, f: impl FnOnce(&InboundCall) -> T) -> Option<T> {
+    let trackers = TRACKERS.lock().unwrap();
+    trackers
+        .get(instance_id)?
+        .calls
+        .values()
+        .find(|c| c.token == token)
+        .map(f)
+}
+
+/// Match inbound calls with their metadata until the instance stops.
+async fn track(
+    instance_id: String,
+    generation: u64,
+    mut rx: broadcast::Receiver<AppInboundEventC>,
+) {
+    loop {
+        let evt = match rx.recv().await {
+            Ok(evt) => evt,
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                tracing_log_lagged(&instance_id, skipped);
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+        let mut trackers = TRACKERS.lock().unwrap();
+        let Some(tracker) = trackers
+            .get_mut(&instance_id)
+            .filter(|t| t.generation == generation)
+        else {
+            return;
+        };
+        let now = Instant::now();
+        tracker
+            .calls
+            .retain(|_, c| c.since.is_some_and(|t| now.duration_since(t) < TRACK_TTL));
+
+        let AppInboundEventC {
+            kind,
+            from_peer_id,
+            channel,
+            request_id,
+            token,
+            payload,
+        } = evt;
+        let (key, event) = match kind {
+            AppInboundKindC::Call => {
+                if is_reserved(&channel) || request_id == 0 {
+                    continue;
+                }
+                let call = tracker.calls.entry((from_peer_id, request_id)).or_default();
+                // Metadata that arrived before the call is reported now.
+                let early = call.token == 0 && call.since.is_some();
+                if !early {
+                    *call = InboundCall::default();
+                }
+                call.token = token;
+                call.since = Some(now);
+                let mut pending = Vec::new();
+                if early && call.deadline_ms != 0 {
+                    pending.push(AppCallControlKindC::Deadline);
+                }
+                if early && call.cancelled {
+                    pending.push(AppCallControlKindC::Cancelled);
+                }
+                let deadline_ms = call.deadline_ms;
+                for kind in pending {
+                    emit(
+                        &instance_id,
+                        AppCallControlC {
+                            kind,
+                            from_peer_id,
+                            request_id,
+                            token,
+                            deadline_ms,
+                        },
+                    );
+                }
+                continue;
+            }
+            AppInboundKindC::Notify => {
+                if channel != CALL_CHANNEL {
+                    continue;
+                }
+                let Ok(msg) = serde_json::from_slice::<Value>(&payload) else {
+                    continue;
+                };
+                let Some(request_id) = msg["request_id"].as_u64() else {
+                    continue;
+                };
+                let kind = match msg["op"].as_str() {
+                    Some("deadline") => AppCallControlKindC::Deadline,
+                    Some("cancel") => AppCallControlKindC::Cancelled,
+                    _ => continue,
+                };
+                ((from_peer_id, request_id), (kind, msg))
+            }
+        };
+
+        let (kind, msg) = event;
+        let call = tracker.calls.entry(key).or_default();
+        call.since.get_or_insert(now);
+        match kind {
+            AppCallControlKindC::Deadline => {
+                call.deadline_ms = msg["timeout_ms"]
+                    .as_u64()
+                    .map_or(0, |timeout_ms| now_ms() + timeout_ms)
+            }
+            AppCallControlKindC::Cancelled => call.cancelled = true,
+        }
+        if call.token != 0 {
+            emit(
+                &instance_id,
+                AppCallControlC {
+                    kind,
+                    from_peer_id: key.0,
+                    request_id: key.1,
+                    token: call.token,
+                    deadline_ms: call.deadline_ms,
+                },
+            );
+        }
+    }
+
+    let mut trackers = TRACKERS.lock().unwrap();
+    if trackers
+        .get(&instance_id)
+        .is_some_and(|t| t.generation == generation)
+    {
+        trackers.remove(&instance_id);
+    }
+}
+
+fn emit(instance_id: &str, event: AppCallControlC) {
+    let _ = EVENTS.send((instance_id.to_string(), event));
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
@@ -0,0 +1,549 @@
+// Codec-aware variants of the app RPC calls.
+//
+// Payloads stay opaque bytes on the wire; these wrappers prepend a small
+// header naming the encoding so both sides can validate, transcode and
+// compress them in Rust instead of every app hand-rolling UTF-8 JSON:
+//
+//   magic 0xA5 | version | codec | flags | accept mask
+//
+// `flags` bit 0 marks a zstd-compressed body (only used above
+// `COMPRESS_THRESHOLD` and only when it actually shrinks). Decompression stops
+// at `MAX_DECOMPRESSED_LEN`, so a small frame from a peer cannot expand into
+// an unbounded allocation. `accept mask` lists
+// the codecs the sender can read, so the callee replies in one of them.
+// 0xA5 can never start a UTF-8 JSON document, so untagged payloads from older
+// clients are still recognised and treated as JSON.
+//
+// Transcoding goes through the JSON data model (`serde_json::Value`):
+// MessagePack / CBOR binary strings and tags cannot be transcoded and are
+// reported as errors; send them with the codec the peer asked for instead.
+
+use std::collections::HashSet;
+use std::io::Read;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use lazy_static::lazy_static;
+use serde_json::Value;
+use tokio::sync::broadcast;
+
+use super::p2p::{lookup_app_rpc, runtime, subscribe_instance_events, GlobalCtxEvent};
+use super::{app_call_control, app_metrics};
+
+const MAGIC: u8 = 0xA5;
+const VERSION: u8 = 1;
+const HEADER_LEN: usize = 5;
+const FLAG_ZSTD: u8 = 0x01;
+const COMPRESS_THRESHOLD: usize = 8 * 1024;
+const ZSTD_LEVEL: i32 = 3;
+/// Largest body a compressed payload may expand to; anything bigger is
+/// rejected rather than decoded.
+const MAX_DECOMPRESSED_LEN: usize = 16 * 1024 * 1024;
+
+/// Status and message the old Dart `PeerRpcRouter` answers when it cannot
+/// parse a tagged payload as JSON; the caller then falls back to untagged
+/// JSON. Handlers may use -32700 for their own errors, so both must match.
+const LEGACY_PARSE_ERROR: i32 = -32700;
+const LEGACY_PARSE_ERROR_MSG: &str = "Parse error";
+
+lazy_static! {
+    /// (instance id, peer id) pairs known to only understand untagged JSON.
+    /// Cleared when the peer leaves, so an upgraded peer gets tagged payloads.
+    static ref LEGACY_PEERS: Mutex<HashSet<(String, u32)>> = Mutex::new(HashSet::new());
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadCodecC {
+    /// Opaque bytes: never validated or transcoded.
+    Raw,
+    Json,
+    MessagePack,
+    Cbor,
+}
+
+impl PayloadCodecC {
+    const ALL: [PayloadCodecC; 4] = [
+        PayloadCodecC::Raw,
+        PayloadCodecC::Json,
+        PayloadCodecC::MessagePack,
+        PayloadCodecC::Cbor,
+    ];
+
+    fn to_raw(self) -> u8 {
+        match self {
+            PayloadCodecC::Raw => 0,
+            PayloadCodecC::Json => 1,
+            PayloadCodecC::MessagePack => 2,
+            PayloadCodecC::Cbor => 3,
+        }
+    }
+
+    fn from_raw(raw: u8) -> Result<Self, String> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.to_raw() == raw)
+            .ok_or_else(|| format!("unknown payload codec: {}", raw))
+    }
+
+    fn mask(codecs: &[PayloadCodecC]) -> u8 {
+        codecs.iter().fold(0, |m, c| m | (1 << c.to_raw()))
+    }
+
+    fn from_mask(mask: u8) -> Vec<PayloadCodecC> {
+        Self::ALL
+            .into_iter()
+            .filter(|c| mask & (1 << c.to_raw()) != 0)
+            .collect()
+    }
+}
+
+/// A payload with its header stripped, decompressed and converted to the
+/// codec the caller asked for.
+#[derive(Debug, Clone)]
+pub struct DecodedPayloadC {
+    pub codec: PayloadCodecC,
+    pub payload: Vec<u8>,
+    /// The sender tagged the payload; `false` means an older client that only
+    /// speaks untagged JSON.
+    pub tagged: bool,
+    /// Codecs the sender can read. Pass to [`app_call_reply_encoded`] so the
+    /// reply is in a codec the caller understands.
+    pub peer_accepts: Vec<PayloadCodecC>,
+}
+
+/// Result of [`app_call_encoded`]; `payload` is already in `codec`.
+#[derive(Debug, Clone)]
+pub struct AppCallDecodedC {
+    pub status: i32,
+    pub error_msg: String,
+    pub codec: PayloadCodecC,
+    pub payload: Vec<u8>,
+}
+
+/// `app_call` with a tagged payload. `payload` must be valid `codec`; the
+/// reply is converted to `reply_codec`. Peers still on untagged JSON are
+/// detected and retried once with plain JSON.
+#[allow(clippy::too_many_arguments)]
+pub async fn app_call_encoded(
+    instance_id: String,
+    dst_peer_id: u32,
+    channel: String,
+    request_id: u64,
+    payload: Vec<u8>,
+    codec: PayloadCodecC,
+    reply_codec: PayloadCodecC,
+    flags: u32,
+    timeout_ms: i32,
+) -> Result<AppCallDecodedC, String> {
+    validate(&payload, codec)?;
+    let legacy_key = (instance_id.clone(), dst_peer_id);
+    let mut legacy = LEGACY_PEERS.lock().unwrap().contains(&legacy_key);
+    let accepts = accepted_by(reply_codec);
+    let svc = lookup_app_rpc(&instance_id)?;
+    loop {
+        let body = if legacy {
+            untagged(&payload, codec)?
+        } else {
+            encode(&payload, codec, &accepts)?
+        };
+        // Awaited on the FRB executor like `app_call`; see the note there.
+        let started = Instant::now();
+        let resp = svc
+            .call(
+                dst_peer_id,
+                channel.clone(),
+                request_id,
+                body,
+                flags,
+                timeout_ms,
+            )
+            .await;
+        app_metrics::record_call(
+            &instance_id,
+            dst_peer_id,
+            &channel,
+            started,
+            resp.as_ref().ok().map(|r| r.status),
+        );
+        let resp = resp.map_err(|e| e.to_string())?;
+        if !legacy
+            && is_legacy_parse_error(resp.status, &resp.error_msg)
+            && codec != PayloadCodecC::Raw
+        {
+            LEGACY_PEERS.lock().unwrap().insert(legacy_key.clone());
+            legacy = true;
+            continue;
+        }
+        let decoded = decode(&resp.payload, reply_codec)?;
+        return Ok(AppCallDecodedC {
+            status: resp.status,
+            error_msg: resp.error_msg,
+            codec: decoded.codec,
+            payload: decoded.payload,
+        });
+    }
+}
+
+/// `app_notify` with a tagged payload. Peers previously found to be on
+/// untagged JSON get plain JSON.
+pub async fn app_notify_encoded(
+    instance_id: String,
+    dst_peer_id: u32,
+    channel: String,
+    payload: Vec<u8>,
+    codec: PayloadCodecC,
+    timeout_ms: i32,
+) -> Result<(), String> {
+    validate(&payload, codec)?;
+    let legacy = LEGACY_PEERS
+        .lock()
+        .unwrap()
+        .contains(&(instance_id.clone(), dst_peer_id));
+    let body = if legacy {
+        untagged(&payload, codec)?
+    } else {
+        encode(&payload, codec, &accepted_by(codec))?
+    };
+    let svc = lookup_app_rpc(&instance_id)?;
+    let result = svc
+        .notify(dst_peer_id, channel.clone(), body, timeout_ms)
+        .await;
+    app_metrics::record_notify(&instance_id, dst_peer_id, &channel, result.is_ok());
+    result.map_err(|e| e.to_string())
+}
+
+/// `app_call_reply` with a tagged payload. `peer_accepts` comes from
+/// [`decode_app_payload`] on the inbound call: the reply is transcoded to the
+/// first accepted codec if `codec` is not among them, and sent as untagged
+/// JSON when the caller did not tag its request.
+pub async fn app_call_reply_encoded(
+    instance_id: String,
+    token: u64,
+    status: i32,
+    error_msg: String,
+    payload: Vec<u8>,
+    codec: PayloadCodecC,
+    peer_accepts: Vec<PayloadCodecC>,
+) -> Result<bool, String> {
+    validate(&payload, codec)?;
+    let body = match peer_accepts.first() {
+        None => untagged(&payload, codec)?,
+        Some(_) if peer_accepts.contains(&codec) => encode(&payload, codec, &[codec])?,
+        Some(&target) => {
+            let transcoded = transcode(&payload, codec, target)?;
+            encode(&transcoded, target, &[target])?
+        }
+    };
+    let svc = lookup_app_rpc(&instance_id)?;
+    app_metrics::record_reply(&instance_id, token);
+    app_call_control::forget_call(&instance_id, token);
+    Ok(svc.reply_call(token, status, error_msg, body))
+}
+
+/// Decode an inbound payload (from `subscribe_app_inbound` or a call reply)
+/// into `want`. Untagged payloads are treated as JSON.
+#[flutter_rust_bridge::frb(sync)]
+pub fn decode_app_payload(
+    payload: Vec<u8>,
+    want: PayloadCodecC,
+) -> Result<DecodedPayloadC, String> {
+    decode(&payload, want)
+}
+
+/// Forgets legacy peers as they leave. Started with the instance.
+pub(crate) fn watch_instance(instance_id: &str) {
+    let Ok(mut events) = subscribe_instance_events(instance_id) else {
+        return;
+    };
+    let instance_id = instance_id.to_string();
+    runtime().spawn(async move {
+        loop {
+            match events.recv().await {
+                Ok(GlobalCtxEvent::PeerRemoved(peer_id)) => {
+                    LEGACY_PEERS
+                        .lock()
+                        .unwrap()
+                        .remove(&(instance_id.clone(), peer_id));
+                }
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+pub(crate) fn forget_instance(instance_id: &str) {
+    LEGACY_PEERS
+        .lock()
+        .unwrap()
+        .retain(|(id, _)| id != instance_id);
+}
+
+fn is_legacy_parse_error(status: i32, error_msg: &str) -> bool {
+    status == LEGACY_PARSE_ERROR && error_msg == LEGACY_PARSE_ERROR_MSG
+}
+
+/// Tag `payload` with a header, compressing it when large.
+fn encode(
+    payload: &[u8],
+    codec: PayloadCodecC,
+    accepts: &[PayloadCodecC],
+) -> Result<Vec<u8>, String> {
+    let mut flags = 0;
+    let mut body = None;
+    if payload.len() >= COMPRESS_THRESHOLD {
+        let compressed = zstd::bulk::compress(payload, ZSTD_LEVEL)
+            .map_err(|e| format!("zstd compress failed: {}", e))?;
+        if compressed.len() < payload.len() {
+            flags |= FLAG_ZSTD;
+            body = Some(compressed);
+        }
+    }
+    let body = body.as_deref().unwrap_or(payload);
+    let mut out = Vec::with_capacity(HEADER_LEN + body.len());
+    out.extend_from_slice(&[
+        MAGIC,
+        VERSION,
+        codec.to_raw(),
+        flags,
+        PayloadCodecC::mask(accepts),
+    ]);
+    out.extend_from_slice(body);
+    Ok(out)
+}
+
+fn decode(payload: &[u8], want: PayloadCodecC) -> Result<DecodedPayloadC, String> {
+    if payload.first() != Some(&MAGIC) {
+        // Older clients: untagged JSON (possibly empty for "no params").
+        let codec = if want == PayloadCodecC::Raw {
+            PayloadCodecC::Json
+        } else {
+            want
+        };
+        let payload = transcode(payload, PayloadCodecC::Json, want)?;
+        return Ok(DecodedPayloadC {
+            codec,
+            payload,
+            tagged: false,
+            peer_accepts: Vec::new(),
+        });
+    }
+
+    if payload.len() < HEADER_LEN {
+        return Err("payload header truncated".to_string());
+    }
+    if payload[1] != VERSION {
+        return Err(format!(
+            "unsupported payload header version: {}",
+            payload[1]
+        ));
+    }
+    let codec = PayloadCodecC::from_raw(payload[2])?;
+    let flags = payload[3];
+    let peer_accepts = PayloadCodecC::from_mask(payload[4]);
+    let body = &payload[HEADER_LEN..];
+    let body = if flags & FLAG_ZSTD != 0 {
+        decompress(body)?
+    } else {
+        body.to_vec()
+    };
+
+    let (codec, payload) = if want == PayloadCodecC::Raw || want == codec {
+        (codec, body)
+    } else {
+        (want, transcode(&body, codec, want)?)
+    };
+    Ok(DecodedPayloadC {
+        codec,
+        payload,
+        tagged: true,
+        peer_accepts,
+    })
+}
+
+/// Inflate a zstd body through a bounded reader: one byte past the cap is
+/// enough to know the payload is too large.
+fn decompress(body: &[u8]) -> Result<Vec<u8>, String> {
+    let decoder = zstd::stream::read::Decoder::new(body)
+        .map_err(|e| format!("zstd decompress failed: {}", e))?;
+    let mut out = Vec::new();
+    decoder
+        .take(MAX_DECOMPRESSED_LEN as u64 + 1)
+        .read_to_end(&mut out)
+        .map_err(|e| format!("zstd decompress failed: {}", e))?;
+    if out.len() > MAX_DECOMPRESSED_LEN {
+        return Err(format!(
+            "decompressed payload exceeds {} bytes",
+            MAX_DECOMPRESSED_LEN
+        ));
+    }
+    Ok(out)
+}
+
+/// Codecs advertised as readable when we want replies in `preferred`: the
+/// preferred one first, then everything we can transcode from.
+fn accepted_by(preferred: PayloadCodecC) -> Vec<PayloadCodecC> {
+    let mut accepts = vec![preferred];
+    accepts.extend(PayloadCodecC::ALL.into_iter().filter(|c| *c != preferred));
+    accepts
+}
+
+/// Body for peers on untagged JSON; raw payloads are passed through as-is.
+fn untagged(payload: &[u8], codec: PayloadCodecC) -> Result<Vec<u8>, String> {
+    if codec == PayloadCodecC::Raw {
+        return Ok(payload.to_vec());
+    }
+    transcode(payload, codec, PayloadCodecC::Json)
+}
+
+fn validate(payload: &[u8], codec: PayloadCodecC) -> Result<(), String> {
+    if codec == PayloadCodecC::Raw || payload.is_empty() {
+        return Ok(());
+    }
+    to_value(payload, codec).map(|_| ())
+}
+
+fn transcode(payload: &[u8], from: PayloadCodecC, to: PayloadCodecC) -> Result<Vec<u8>, String> {
+    if from == to || to == PayloadCodecC::Raw || payload.is_empty() {
+        return Ok(payload.to_vec());
+    }
+    if from == PayloadCodecC::Raw {
+        return Err(format!("cannot transcode raw payload to {:?}", to));
+    }
+    from_value(&to_value(payload, from)?, to)
+}
+
+fn to_value(payload: &[u8], codec: PayloadCodecC) -> Result<Value, String> {
+    match codec {
+        PayloadCodecC::Json => {
+            serde_json::from_slice(payload).map_err(|e| format!("invalid json payload: {}", e))
+        }
+        PayloadCodecC::MessagePack => rmp_serde::from_slice(payload)
+            .map_err(|e| format!("invalid messagepack payload: {}", e)),
+        PayloadCodecC::Cbor => {
+            ciborium::from_reader(payload).map_err(|e| format!("invalid cbor payload: {}", e))
+        }
+        PayloadCodecC::Raw => Err("raw payload has no structure".to_string()),
+    }
+}
+
+fn from_value(value: &Value, codec: PayloadCodecC) -> Result<Vec<u8>, String> {
+    match codec {
+        PayloadCodecC::Json => {
+            serde_json::to_vec(value).map_err(|e| format!("json encode failed: {}", e))
+        }
+        PayloadCodecC::MessagePack => {
+            rmp_serde::to_vec_named(value).map_err(|e| format!("messagepack encode failed: {}", e))
+        }
+        PayloadCodecC::Cbor => {
+            let mut out = Vec::new();
+            ciborium::into_writer(value, &mut out)
+                .map_err(|e| format!("cbor encode failed: {}", e))?;
+            Ok(out)
+        }
+        PayloadCodecC::Raw => Err("raw payload has no structure".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_STRUCTURED: [PayloadCodecC; 3] = [
+        PayloadCodecC::Json,
+        PayloadCodecC::MessagePack,
+        PayloadCodecC::Cbor,
+    ];
+
+    fn sample(codec: PayloadCodecC) -> Vec<u8> {
+        let value = serde_json::json!({"name": "astral", "port": 11010, "tags": ["a", "b"]});
+        from_value(&value, codec).unwrap()
+    }
+
+    #[test]
+    fn encode_decode_round_trip() {
+        for codec in ALL_STRUCTURED {
+            let payload = sample(codec);
+            let encoded = encode(&payload, codec, &[codec, PayloadCodecC::Raw]).unwrap();
+            assert_eq!(encoded[0], MAGIC);
+            let decoded = decode(&encoded, codec).unwrap();
+            assert!(decoded.tagged);
+            assert_eq!(decoded.codec, codec);
+            assert_eq!(decoded.payload, payload);
+            assert_eq!(decoded.peer_accepts.len(), 2);
+            assert!(decoded.peer_accepts.contains(&codec));
+        }
+    }
+
+    #[test]
+    fn decode_transcodes_between_codecs() {
+        for from in ALL_STRUCTURED {
+            for to in ALL_STRUCTURED {
+                let encoded = encode(&sample(from), from, &[from]).unwrap();
+                let decoded = decode(&encoded, to).unwrap();
+                assert_eq!(decoded.codec, to);
+                assert_eq!(
+                    to_value(&decoded.payload, to).unwrap(),
+                    to_value(&sample(from), from).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn untagged_payload_is_json() {
+        let decoded = decode(br#"{"a":1}"#, PayloadCodecC::MessagePack).unwrap();
+        assert!(!decoded.tagged);
+        assert!(decoded.peer_accepts.is_empty());
+        assert_eq!(
+            to_value(&decoded.payload, PayloadCodecC::MessagePack).unwrap(),
+            serde_json::json!({"a": 1})
+        );
+    }
+
+    #[test]
+    fn raw_payload_cannot_be_transcoded() {
+        assert!(transcode(b"\x00\x01", PayloadCodecC::Raw, PayloadCodecC::Json).is_err());
+        assert_eq!(
+            untagged(b"\x00\x01", PayloadCodecC::Raw).unwrap(),
+            b"\x00\x01"
+        );
+    }
+
+    #[test]
+    fn large_payload_is_compressed() {
+        let payload = serde_json::to_vec(&vec!["astral"; 4096]).unwrap();
+        let encoded = encode(&payload, PayloadCodecC::Json, &[PayloadCodecC::Json]).unwrap();
+        assert_eq!(encoded[3] & FLAG_ZSTD, FLAG_ZSTD);
+        assert!(encoded.len() < payload.len());
+        assert_eq!(
+            decode(&encoded, PayloadCodecC::Json).unwrap().payload,
+            payload
+        );
+    }
+
+    #[test]
+    fn decompress_stops_at_cap() {
+        let bomb = zstd::bulk::compress(&vec![0u8; MAX_DECOMPRESSED_LEN + 1], ZSTD_LEVEL).unwrap();
+        let mut frame = vec![MAGIC, VERSION, PayloadCodecC::Raw.to_raw(), FLAG_ZSTD, 0];
+        frame.extend_from_slice(&bomb);
+        let err = decode(&frame, PayloadCodecC::Raw).unwrap_err();
+        assert!(err.contains("exceeds"), "{}", err);
+
+        let fits = zstd::bulk::compress(&vec![0u8; 1024], ZSTD_LEVEL).unwrap();
+        assert_eq!(decompress(&fits).unwrap().len(), 1024);
+    }
+
+    #[test]
+    fn truncated_header_is_rejected() {
+        assert!(decode(&[MAGIC, VERSION], PayloadCodecC::Json).is_err());
+        assert!(decode(&[MAGIC, 9, 1, 0, 0], PayloadCodecC::Json).is_err());
+    }
+
+    #[test]
+    fn only_the_router_parse_error_means_legacy() {
+        assert!(is_legacy_parse_error(-32700, "Parse error"));
+        assert!(!is_legacy_parse_error(-32700, "Parse response error: bad"));
+        assert!(!is_legacy_parse_error(-32601, "Parse error"));
+    }
+}
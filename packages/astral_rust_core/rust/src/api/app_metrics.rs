@@ -0,0 +1,419 @@
+// Per-channel and per-peer app RPC metrics.
+//
+// Counters and latency histograms are recorded by the app RPC entry points
+// (`app_call*`, `app_notify*`, `app_broadcast`, `app_call_reply*`) and, for
+// inbound events, by the channel router (`app_router`) once per event the
+// policy admitted, however many Dart subscriptions it reaches. Besides the
+// round trip of outgoing calls, the time between an inbound call being
+// admitted and its reply is recorded as handler latency, so slow
+// `PeerRpcRouter` handlers show up per channel.
+//
+// Traffic of the crate's own services (`astral.*`) is not recorded, and
+// channels that are neither registered nor named by the `app_policy` are
+// recorded together as `"other"` (see `app_policy::tracked_channel`). Inbound
+// events the router missed because it fell behind EasyTier can't be
+// attributed to a channel and are counted per instance; subscribers falling
+// behind the router are only logged. [`app_rpc_metrics_openmetrics`] renders
+// everything in the OpenMetrics text format for scraping.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use lazy_static::lazy_static;
+
+use super::app_policy::tracked_channel;
+use super::app_router::is_reserved;
+use super::p2p::{app_rpc_status, AppInboundEventC, AppInboundKindC};
+
+/// Upper bounds of the latency buckets in milliseconds; a last, unbounded
+/// bucket follows.
+const BUCKETS_MS: [f64; 12] = [
+    1.0, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 2500.0, 5000.0, 10000.0,
+];
+/// Inbound calls not replied to within this long are forgotten (EasyTier has
+/// answered them with `REPLY_TIMEOUT` by then).
+const PENDING_TTL: Duration = Duration::from_secs(120);
+
+lazy_static! {
+    static ref METRICS: Mutex<HashMap<String, InstanceMetrics>> = Mutex::new(HashMap::new());
+}
+
+#[derive(Default)]
+struct InstanceMetrics {
+    lagged_events: u64,
+    channels: BTreeMap<String, Metrics>,
+    peers: BTreeMap<u32, Metrics>,
+    /// Inbound calls forwarded to Dart and not yet replied to, by token.
+    pending: HashMap<u64, (Instant, u32, String)>,
+}
+
+#[derive(Default, Clone)]
+struct Metrics {
+    counters: AppRpcCountersC,
+    call_latency: Histogram,
+    handler_latency: Histogram,
+}
+
+#[derive(Clone)]
+struct Histogram {
+    counts: [u64; BUCKETS_MS.len() + 1],
+    sum_ms: f64,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Histogram {
+            counts: [0; BUCKETS_MS.len() + 1],
+            sum_ms: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AppRpcCountersC {
+    pub calls_sent: u64,
+    pub calls_received: u64,
+    pub notifies_sent: u64,
+    pub notifies_received: u64,
+    /// Replies to inbound calls sent from this node.
+    pub replies_sent: u64,
+    /// Replies received for outgoing calls, whatever their status.
+    pub replies_received: u64,
+    /// Outgoing calls answered with `REPLY_TIMEOUT`.
+    pub timeouts: u64,
+    pub no_subscriber: u64,
+    pub service_dropped: u64,
+    /// Outgoing calls cancelled by the caller (see `app_call_control`).
+    pub cancelled: u64,
+    /// Calls and notifies that failed before any reply (routing, ack
+    /// timeout).
+    pub send_errors: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct LatencyHistogramC {
+    /// Upper bounds in milliseconds; `counts` has one more entry for values
+    /// above the last bound.
+    pub bounds_ms: Vec<f64>,
+    /// Per-bucket (not cumulative) counts.
+    pub counts: Vec<u64>,
+    pub count: u64,
+    pub sum_ms: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChannelMetricsC {
+    pub channel: String,
+    pub counters: AppRpcCountersC,
+    /// Round trip of outgoing calls.
+    pub call_latency: LatencyHistogramC,
+    /// Inbound call reaching Dart until its reply.
+    pub handler_latency: LatencyHistogramC,
+}
+
+#[derive(Debug, Clone)]
+pub struct PeerMetricsC {
+    pub peer_id: u32,
+    pub counters: AppRpcCountersC,
+    pub call_latency: LatencyHistogramC,
+    pub handler_latency: LatencyHistogramC,
+}
+
+#[derive(Debug, Clone)]
+pub struct AppRpcMetricsC {
+    pub lagged_events: u64,
+    /// Sorted by channel; unregistered channels the policy doesn't name are
+    /// reported as `"other"`.
+    pub channels: Vec<ChannelMetricsC>,
+    /// Sorted by peer id.
+    pub peers: Vec<PeerMetricsC>,
+}
+
+/// Metrics of an instance since it started or was last reset.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_app_rpc_metrics(instance_id: String) -> AppRpcMetricsC {
+    let metrics = METRICS.lock().unwrap();
+    let Some(m) = metrics.get(&instance_id) else {
+        return AppRpcMetricsC {
+            lagged_events: 0,
+            channels: Vec::new(),
+            peers: Vec::new(),
+        };
+    };
+    AppRpcMetricsC {
+        lagged_events: m.lagged_events,
+        channels: m
+            .channels
+            .iter()
+            .map(|(channel, m)| ChannelMetricsC {
+                channel: channel.clone(),
+                counters: m.counters.clone(),
+                call_latency: m.call_latency.export(),
+                handler_latency: m.handler_latency.export(),
+            })
+            .collect(),
+        peers: m
+            .peers
+            .iter()
+            .map(|(peer_id, m)| PeerMetricsC {
+                peer_id: *peer_id,
+                counters: m.counters.clone(),
+                call_latency: m.call_latency.export(),
+                handler_latency: m.handler_latency.export(),
+            })
+            .collect(),
+    }
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn reset_app_rpc_metrics(instance_id: String) {
+    METRICS.lock().unwrap().remove(&instance_id);
+}
+
+/// The instance's metrics in the OpenMetrics text format, labelled by
+/// `instance`, and `channel` or `peer`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn app_rpc_metrics_openmetrics(instance_id: String) -> String {
+    let metrics = METRICS.lock().unwrap();
+    let empty = InstanceMetrics::default();
+    let m = metrics.get(&instance_id).unwrap_or(&empty);
+    let instance = escape_label(&instance_id);
+    let mut out = String::new();
+
+    let _ = writeln!(out, "# TYPE astral_app_rpc_lagged_events counter");
+    let _ = writeln!(
+        out,
+        "astral_app_rpc_lagged_events_total{{instance=\"{}\"}} {}",
+        instance, m.lagged_events
+    );
+
+    let by_channel: Vec<(String, &Metrics)> = m
+        .channels
+        .iter()
+        .map(|(c, m)| (format!("channel=\"{}\"", escape_label(c)), m))
+        .collect();
+    let by_peer: Vec<(String, &Metrics)> = m
+        .peers
+        .iter()
+        .map(|(p, m)| (format!("peer=\"{}\"", p), m))
+        .collect();
+    for (scope, rows) in [("channel", &by_channel), ("peer", &by_peer)] {
+        for (i, name) in COUNTER_NAMES.iter().enumerate() {
+            let _ = writeln!(out, "# TYPE astral_app_rpc_{}_{} counter", scope, name);
+            for (label, m) in rows.iter() {
+                let _ = writeln!(
+                    out,
+                    "astral_app_rpc_{}_{}_total{{instance=\"{}\",{}}} {}",
+                    scope,
+                    name,
+                    instance,
+                    label,
+                    m.counters.values()[i]
+                );
+            }
+        }
+        for (i, name) in ["call_latency", "handler_latency"].iter().enumerate() {
+            let metric = format!("astral_app_rpc_{}_{}_seconds", scope, name);
+            let _ = writeln!(out, "# TYPE {} histogram", metric);
+            for (label, m) in rows.iter() {
+                let histogram = [&m.call_latency, &m.handler_latency][i];
+                histogram.write_openmetrics(
+                    &mut out,
+                    &metric,
+                    &format!("instance=\"{}\",{}", instance, label),
+                );
+            }
+        }
+    }
+    out.push_str("# EOF\n");
+    out
+}
+
+/// An outgoing call finished; `status` is `None` when it failed before a
+/// reply.
+pub(crate) fn record_call(
+    instance_id: &str,
+    peer_id: u32,
+    channel: &str,
+    started: Instant,
+    status: Option<i32>,
+) {
+    let elapsed = started.elapsed();
+    update(instance_id, peer_id, channel, |m| {
+        m.counters.calls_sent += 1;
+        match status {
+            None => m.counters.send_errors += 1,
+            Some(app_rpc_status::REPLY_TIMEOUT) => m.counters.timeouts += 1,
+            Some(app_rpc_status::NO_SUBSCRIBER) => m.counters.no_subscriber += 1,
+            Some(app_rpc_status::SERVICE_DROPPED) => m.counters.service_dropped += 1,
+            Some(app_rpc_status::CALL_CANCELLED) => m.counters.cancelled += 1,
+            Some(_) => {
+                m.counters.replies_received += 1;
+                m.call_latency.observe(elapsed);
+            }
+        }
+    });
+}
+
+pub(crate) fn record_notify(instance_id: &str, peer_id: u32, channel: &str, sent: bool) {
+    update(instance_id, peer_id, channel, |m| {
+        m.counters.notifies_sent += 1;
+        if !sent {
+            m.counters.send_errors += 1;
+        }
+    });
+}
+
+/// The channel router admitted an inbound event.
+pub(crate) fn record_inbound(instance_id: &str, evt: &AppInboundEventC) {
+    if is_reserved(&evt.channel) {
+        return;
+    }
+    let channel = tracked_channel(instance_id, &evt.channel);
+    let mut metrics = METRICS.lock().unwrap();
+    let m = metrics.entry(instance_id.to_string()).or_default();
+    let now = Instant::now();
+    if evt.kind == AppInboundKindC::Call {
+        m.pending
+            .retain(|_, (at, _, _)| now.duration_since(*at) < PENDING_TTL);
+        m.pending
+            .insert(evt.token, (now, evt.from_peer_id, channel.clone()));
+    }
+    for metrics in [
+        m.channels.entry(channel).or_default(),
+        m.peers.entry(evt.from_peer_id).or_default(),
+    ] {
+        match evt.kind {
+            AppInboundKindC::Call => metrics.counters.calls_received += 1,
+            AppInboundKindC::Notify => metrics.counters.notifies_received += 1,
+        }
+    }
+}
+
+/// Dart replied to an inbound call.
+pub(crate) fn record_reply(instance_id: &str, token: u64) {
+    let mut metrics = METRICS.lock().unwrap();
+    let Some(m) = metrics.get_mut(instance_id) else {
+        return;
+    };
+    let Some((at, peer_id, channel)) = m.pending.remove(&token) else {
+        return;
+    };
+    let elapsed = at.elapsed();
+    for metrics in [
+        m.channels.entry(channel).or_default(),
+        m.peers.entry(peer_id).or_default(),
+    ] {
+        metrics.counters.replies_sent += 1;
+        metrics.handler_latency.observe(elapsed);
+    }
+}
+
+/// The channel router fell behind EasyTier's inbound channel.
+pub(crate) fn record_lagged(instance_id: &str, skipped: u64) {
+    METRICS
+        .lock()
+        .unwrap()
+        .entry(instance_id.to_string())
+        .or_default()
+        .lagged_events += skipped;
+}
+
+pub(crate) fn forget_instance(instance_id: &str) {
+    METRICS.lock().unwrap().remove(instance_id);
+}
+
+fn update(instance_id: &str, peer_id: u32, channel: &str, f: impl Fn(&mut Metrics)) {
+    if is_reserved(channel) {
+        return;
+    }
+    let channel = tracked_channel(instance_id, channel);
+    let mut metrics = METRICS.lock().unwrap();
+    let m = metrics.entry(instance_id.to_string()).or_default();
+    f(m.channels.entry(channel).or_default());
+    f(m.peers.entry(peer_id).or_default());
+}
+
+/// OpenMetrics names of the fields of [`AppRpcCountersC`], in the order of
+/// `AppRpcCountersC::values`.
+const COUNTER_NAMES: [&str; 11] = [
+    "calls_sent",
+    "calls_received",
+    "notifies_sent",
+    "notifies_received",
+    "replies_sent",
+    "replies_received",
+    "timeouts",
+    "no_subscriber",
+    "service_dropped",
+    "cancelled",
+    "send_errors",
+];
+
+impl AppRpcCountersC {
+    fn values(&self) -> [u64; 11] {
+        [
+            self.calls_sent,
+            self.calls_received,
+            self.notifies_sent,
+            self.notifies_received,
+            self.replies_sent,
+            self.replies_received,
+            self.timeouts,
+            self.no_subscriber,
+            self.service_dropped,
+            self.cancelled,
+            self.send_errors,
+        ]
+    }
+}
+
+impl Histogram {
+    fn observe(&mut self, elapsed: Duration) {
+        let ms = elapsed.as_secs_f64() * 1000.0;
+        let bucket = BUCKETS_MS
+            .iter()
+            .position(|b| ms <= *b)
+            .unwrap_or(BUCKETS_MS.len());
+        self.counts[bucket] += 1;
+        self.sum_ms += ms;
+    }
+
+    fn export(&self) -> LatencyHistogramC {
+        LatencyHistogramC {
+            bounds_ms: BUCKETS_MS.to_vec(),
+            counts: self.counts.to_vec(),
+            count: self.counts.iter().sum(),
+            sum_ms: self.sum_ms,
+        }
+    }
+
+    /// Cumulative buckets in seconds, as OpenMetrics expects.
+    fn write_openmetrics(&self, out: &mut String, metric: &str, labels: &str) {
+        let mut cumulative = 0;
+        for (bound, count) in BUCKETS_MS.iter().zip(self.counts) {
+            cumulative += count;
+            let _ = writeln!(
+                out,
+                "{}_bucket{{{},le=\"{}\"}} {}",
+                metric,
+                labels,
+                bound / 1000.0,
+                cumulative
+            );
+        }
+        let count: u64 = self.counts.iter().sum();
+        let _ = writeln!(out, "{}_bucket{{{},le=\"+Inf\"}} {}", metric, labels, count);
+        let _ = writeln!(out, "{}_count{{{}}} {}", metric, labels, count);
+        let _ = writeln!(out, "{}_sum{{{}}} {}", metric, labels, self.sum_ms / 1000.0);
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -0,0 +1,488 @@
+// Inbound policy for app RPC.
+//
+// Every peer in a room can reach every channel a node serves, so without a
+// policy one peer can flood a channel or push huge payloads straight into
+// Dart. A per-instance [`AppRpcPolicyC`] is checked by the instance's channel
+// router (`app_router`) once per inbound event, before the event is fanned
+// out to Dart subscriptions and crate services: oversized payloads, peers or
+// channels over their rate limit and channels denied to the sender are
+// dropped, and rejected `Call`s are answered with
+// `app_rpc_status::POLICY_REJECTED` right away. Rejections are counted per
+// reason, peer and channel.
+//
+// The policy runs before routing, so it also covers channels nobody serves.
+// Per-channel state (rate limit buckets, rejections, and the metrics in
+// `app_metrics`) is only kept by name for channels the app registered or the
+// policy names; everything else shares [`OTHER_CHANNEL`], so a peer can't
+// grow it by sending to made-up channels.
+//
+// Rate limits are token buckets charged once per admitted event. The
+// crate's own channels (`astral.*`) are subject to the payload size and
+// per-peer limits too, so a peer can't get around them through streams,
+// file transfers, messages or presence; channel rules and per-channel
+// limits only cover app channels. Keep `max_payload_bytes` above the
+// 64 KiB file transfer chunks when file transfers are used.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::time::Instant;
+
+use lazy_static::lazy_static;
+
+use super::app_router;
+use super::p2p::{app_rpc_status, lookup_app_rpc, AppInboundEventC, AppInboundKindC};
+
+/// Per-channel state of channels that are neither registered nor named by
+/// the policy is kept under this name.
+pub(crate) const OTHER_CHANNEL: &str = "other";
+
+lazy_static! {
+    static ref POLICIES: Mutex<HashMap<String, PolicyState>> = Mutex::new(HashMap::new());
+}
+
+struct PolicyState {
+    policy: AppRpcPolicyC,
+    peer_buckets: HashMap<u32, Bucket>,
+    /// By tracked channel name, see [`tracked_channel`].
+    channel_buckets: HashMap<String, Bucket>,
+    rejections: Rejections,
+}
+
+#[derive(Default)]
+struct Rejections {
+    by_reason: BTreeMap<u8, u64>,
+    by_peer: BTreeMap<u32, u64>,
+    by_channel: BTreeMap<String, u64>,
+}
+
+struct Bucket {
+    tokens: f64,
+    last: Instant,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitC {
+    /// Sustained events per second; 0 disables the limit.
+    pub per_second: u32,
+    /// Events allowed in a burst above the sustained rate; at least 1 is used.
+    pub burst: u32,
+}
+
+/// Channel allow/deny lists for one peer, or for every peer without a rule
+/// of its own when `peer_id` is 0.
+#[derive(Debug, Clone)]
+pub struct PeerChannelRuleC {
+    pub peer_id: u32,
+    /// When non-empty, only these channels are accepted from the peer.
+    pub allow: Vec<String>,
+    /// Never accepted from the peer; wins over `allow`.
+    pub deny: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AppRpcPolicyC {
+    /// Largest accepted payload in bytes, on every channel; 0 disables the
+    /// check.
+    pub max_payload_bytes: u32,
+    /// Applied to each sending peer separately, counting every channel.
+    pub peer_rate: RateLimitC,
+    /// Applied to each app channel separately, across all peers.
+    pub channel_rate: RateLimitC,
+    pub channel_rules: Vec<PeerChannelRuleC>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RejectReasonC {
+    PayloadTooLarge,
+    PeerRateLimited,
+    ChannelRateLimited,
+    ChannelDenied,
+}
+
+const REASONS: [RejectReasonC; 4] = [
+    RejectReasonC::PayloadTooLarge,
+    RejectReasonC::PeerRateLimited,
+    RejectReasonC::ChannelRateLimited,
+    RejectReasonC::ChannelDenied,
+];
+
+#[derive(Debug, Clone)]
+pub struct ReasonRejectionsC {
+    pub reason: RejectReasonC,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct PeerRejectionsC {
+    pub peer_id: u32,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChannelRejectionsC {
+    pub channel: String,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct AppRpcRejectionsC {
+    pub total: u64,
+    pub by_reason: Vec<ReasonRejectionsC>,
+    /// Sorted by peer id.
+    pub by_peer: Vec<PeerRejectionsC>,
+    /// Sorted by channel. Channels that are neither registered nor named by
+    /// the policy are counted as `"other"`.
+    pub by_channel: Vec<ChannelRejectionsC>,
+}
+
+/// Set (or replace) the inbound policy of an instance. Rate limit state
+/// starts over; rejection counters are kept.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_app_rpc_policy(instance_id: String, policy: AppRpcPolicyC) -> Result<(), String> {
+    let mut seen = Vec::new();
+    for rule in &policy.channel_rules {
+        if seen.contains(&rule.peer_id) {
+            return Err(format!("duplicate channel rule for peer {}", rule.peer_id));
+        }
+        seen.push(rule.peer_id);
+    }
+    let mut policies = POLICIES.lock().unwrap();
+    let rejections = policies
+        .remove(&instance_id)
+        .map(|s| s.rejections)
+        .unwrap_or_default();
+    policies.insert(
+        instance_id,
+        PolicyState {
+            policy,
+            peer_buckets: HashMap::new(),
+            channel_buckets: HashMap::new(),
+            rejections,
+        },
+    );
+    Ok(())
+}
+
+/// Remove the policy; every event is forwarded again and counters are
+/// dropped.
+#[flutter_rust_bridge::frb(sync)]
+pub fn clear_app_rpc_policy(instance_id: String) {
+    POLICIES.lock().unwrap().remove(&instance_id);
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_app_rpc_policy(instance_id: String) -> Option<AppRpcPolicyC> {
+    POLICIES
+        .lock()
+        .unwrap()
+        .get(&instance_id)
+        .map(|s| s.policy.clone())
+}
+
+/// Rejections since the policy was first set or the counters were reset.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_app_rpc_rejections(instance_id: String) -> AppRpcRejectionsC {
+    let policies = POLICIES.lock().unwrap();
+    let Some(r) = policies.get(&instance_id).map(|s| &s.rejections) else {
+        return AppRpcRejectionsC {
+            total: 0,
+            by_reason: Vec::new(),
+            by_peer: Vec::new(),
+            by_channel: Vec::new(),
+        };
+    };
+    AppRpcRejectionsC {
+        total: r.by_reason.values().sum(),
+        by_reason: r
+            .by_reason
+            .iter()
+            .map(|(reason, count)| ReasonRejectionsC {
+                reason: REASONS[*reason as usize],
+                count: *count,
+            })
+            .collect(),
+        by_peer: r
+            .by_peer
+            .iter()
+            .map(|(peer_id, count)| PeerRejectionsC {
+                peer_id: *peer_id,
+                count: *count,
+            })
+            .collect(),
+        by_channel: r
+            .by_channel
+            .iter()
+            .map(|(channel, count)| ChannelRejectionsC {
+                channel: channel.clone(),
+                count: *count,
+            })
+            .collect(),
+    }
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn reset_app_rpc_rejections(instance_id: String) {
+    if let Some(state) = POLICIES.lock().unwrap().get_mut(&instance_id) {
+        state.rejections = Rejections::default();
+    }
+}
+
+/// Check an inbound event against the instance's policy, charging its rate
+/// limits. Called once per event by the channel router. A rejected `Call`
+/// is answered with `app_rpc_status::POLICY_REJECTED`; the caller must not
+/// forward rejected events.
+pub(crate) fn admit(instance_id: &str, evt: &AppInboundEventC) -> bool {
+    let reason = {
+        let mut policies = POLICIES.lock().unwrap();
+        let Some(state) = policies.get_mut(instance_id) else {
+            return true;
+        };
+        let channel = track(
+            instance_id,
+            &evt.channel,
+            names_channel(&state.policy, &evt.channel),
+        );
+        let Some(reason) = state.check(evt, &channel) else {
+            return true;
+        };
+        let r = &mut state.rejections;
+        *r.by_reason.entry(reason as u8).or_default() += 1;
+        *r.by_peer.entry(evt.from_peer_id).or_default() += 1;
+        *r.by_channel.entry(channel).or_default() += 1;
+        reason
+    };
+    if evt.kind == AppInboundKindC::Call {
+        if let Ok(svc) = lookup_app_rpc(instance_id) {
+            svc.reply_call(
+                evt.token,
+                app_rpc_status::POLICY_REJECTED,
+                format!("rejected by policy: {:?}", reason),
+                Vec::new(),
+            );
+        }
+    }
+    false
+}
+
+pub(crate) fn forget_instance(instance_id: &str) {
+    POLICIES.lock().unwrap().remove(instance_id);
+}
+
+/// Name under which per-channel state of `channel` is kept: the channel
+/// itself when the app registered it or the policy names it, otherwise
+/// [`OTHER_CHANNEL`].
+pub(crate) fn tracked_channel(instance_id: &str, channel: &str) -> String {
+    let named = POLICIES
+        .lock()
+        .unwrap()
+        .get(instance_id)
+        .is_some_and(|s| names_channel(&s.policy, channel));
+    track(instance_id, channel, named)
+}
+
+fn track(instance_id: &str, channel: &str, named: bool) -> String {
+    if named || app_router::is_registered(instance_id, channel) {
+        channel.to_string()
+    } else {
+        OTHER_CHANNEL.to_string()
+    }
+}
+
+fn names_channel(policy: &AppRpcPolicyC, channel: &str) -> bool {
+    policy
+        .channel_rules
+        .iter()
+        .any(|r| r.allow.iter().chain(&r.deny).any(|c| c == channel))
+}
+
+impl PolicyState {
+    /// `channel` is the tracked name of the event's channel.
+    fn check(&mut self, evt: &AppInboundEventC, channel: &str) -> Option<RejectReasonC> {
+        let max = self.policy.max_payload_bytes as usize;
+        if max != 0 && evt.payload.len() > max {
+            return Some(RejectReasonC::PayloadTooLarge);
+        }
+        // Crate services only get the size and per-peer limits.
+        let reserved = app_router::is_reserved(&evt.channel);
+        let rule = self
+            .policy
+            .channel_rules
+            .iter()
+            .find(|r| r.peer_id == evt.from_peer_id)
+            .or_else(|| self.policy.channel_rules.iter().find(|r| r.peer_id == 0))
+            .filter(|_| !reserved);
+        if let Some(rule) = rule {
+            if rule.deny.contains(&evt.channel)
+                || (!rule.allow.is_empty() && !rule.allow.contains(&evt.channel))
+            {
+                return Some(RejectReasonC::ChannelDenied);
+            }
+        }
+
+        // Check both buckets before charging either, so an event rejected by
+        // one limit doesn't use up the other.
+        let now = Instant::now();
+        let peer_rate = self.policy.peer_rate;
+        let channel_rate = if reserved {
+            RateLimitC {
+                per_second: 0,
+                burst: 0,
+            }
+        } else {
+            self.policy.channel_rate
+        };
+        let peer_ok = peer_rate.per_second == 0
+            || self
+                .peer_buckets
+                .entry(evt.from_peer_id)
+                .or_insert_with(|| Bucket::full(peer_rate, now))
+                .refill(peer_rate, now);
+        if !peer_ok {
+            return Some(RejectReasonC::PeerRateLimited);
+        }
+        let channel_ok = channel_rate.per_second == 0
+            || self
+                .channel_buckets
+                .entry(channel.to_string())
+                .or_insert_with(|| Bucket::full(channel_rate, now))
+                .refill(channel_rate, now);
+        if !channel_ok {
+            return Some(RejectReasonC::ChannelRateLimited);
+        }
+        if peer_rate.per_second != 0 {
+            self.peer_buckets.get_mut(&evt.from_peer_id).unwrap().tokens -= 1.0;
+        }
+        if channel_rate.per_second != 0 {
+            self.channel_buckets.get_mut(channel).unwrap().tokens -= 1.0;
+        }
+        None
+    }
+}
+
+impl Bucket {
+    fn full(rate: RateLimitC, now: Instant) -> Self {
+        Bucket {
+            tokens: rate.burst.max(1) as f64,
+            last: now,
+        }
+    }
+
+    /// Add tokens for the time passed; true when one is available.
+    fn refill(&mut self, rate: RateLimitC, now: Instant) -> bool {
+        let elapsed = now.duration_since(self.last).as_secs_f64();
+        self.tokens =
+            (self.tokens + elapsed * rate.per_second as f64).min(rate.burst.max(1) as f64);
+        self.last = now;
+        self.tokens >= 1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notify(from_peer_id: u32, channel: &str, len: usize) -> AppInboundEventC {
+        AppInboundEventC {
+            kind: AppInboundKindC::Notify,
+            from_peer_id,
+            channel: channel.to_string(),
+            request_id: 0,
+            token: 0,
+            payload: vec![0; len],
+        }
+    }
+
+    #[test]
+    fn reserved_channels_get_size_and_peer_limits_only() {
+        let instance_id = "policy-test-reserved";
+        set_app_rpc_policy(
+            instance_id.to_string(),
+            AppRpcPolicyC {
+                max_payload_bytes: 16,
+                peer_rate: RateLimitC {
+                    per_second: 1,
+                    burst: 2,
+                },
+                channel_rate: RateLimitC {
+                    per_second: 1,
+                    burst: 1,
+                },
+                channel_rules: vec![PeerChannelRuleC {
+                    peer_id: 0,
+                    allow: vec!["game".to_string()],
+                    deny: Vec::new(),
+                }],
+            },
+        )
+        .unwrap();
+
+        assert!(!admit(instance_id, &notify(1, "astral.msg", 17)));
+        // Not in `allow`, and one event over the channel burst, yet admitted.
+        assert!(admit(instance_id, &notify(1, "astral.msg", 16)));
+        assert!(admit(instance_id, &notify(1, "astral.msg", 16)));
+        // The peer's burst is used up by its reserved-channel traffic.
+        assert!(!admit(instance_id, &notify(1, "game", 1)));
+        assert!(!admit(instance_id, &notify(2, "chat", 1)));
+
+        let rejections = get_app_rpc_rejections(instance_id.to_string());
+        let reasons: Vec<_> = rejections
+            .by_reason
+            .iter()
+            .map(|r| (r.reason, r.count))
+            .collect();
+        assert_eq!(
+            reasons,
+            [
+                (RejectReasonC::PayloadTooLarge, 1),
+                (RejectReasonC::PeerRateLimited, 1),
+                (RejectReasonC::ChannelDenied, 1),
+            ]
+        );
+        forget_instance(instance_id);
+    }
+
+    #[test]
+    fn unnamed_channels_are_tracked_as_other() {
+        let instance_id = "policy-test-other";
+        set_app_rpc_policy(
+            instance_id.to_string(),
+            AppRpcPolicyC {
+                max_payload_bytes: 4,
+                peer_rate: RateLimitC {
+                    per_second: 0,
+                    burst: 0,
+                },
+                channel_rate: RateLimitC {
+                    per_second: 1,
+                    burst: 1,
+                },
+                channel_rules: vec![PeerChannelRuleC {
+                    peer_id: 0,
+                    allow: Vec::new(),
+                    deny: vec!["admin".to_string()],
+                }],
+            },
+        )
+        .unwrap();
+
+        for channel in ["a", "b", "c", "admin"] {
+            assert!(!admit(instance_id, &notify(1, channel, 5)));
+        }
+        // Made-up channels share one rate limit bucket.
+        assert!(admit(instance_id, &notify(1, "d", 1)));
+        assert!(!admit(instance_id, &notify(1, "e", 1)));
+
+        let rejections = get_app_rpc_rejections(instance_id.to_string());
+        let by_channel: Vec<_> = rejections
+            .by_channel
+            .iter()
+            .map(|c| (c.channel.as_str(), c.count))
+            .collect();
+        assert_eq!(by_channel, [("admin", 1), (OTHER_CHANNEL, 4)]);
+        let buckets = POLICIES.lock().unwrap()[instance_id].channel_buckets.len();
+        assert_eq!(buckets, 1);
+        assert_eq!(tracked_channel(instance_id, "admin"), "admin");
+        assert_eq!(tracked_channel(instance_id, "x"), OTHER_CHANNEL);
+        forget_instance(instance_id);
+    }
+}
@@ -0,0 +1,312 @@
+// Channel router for the app RPC layer.
+//
+// Every instance gets a router task when it starts. It is the only reader of
+// the instance's EasyTier inbound channel: each event is checked against the
+// instance's `app_policy` once, then routed, and only then fanned out to the
+// Dart subscriptions and the crate's own services through
+// [`subscribe_inbound`]. An event is therefore charged against the rate
+// limits once, however many subscribers it reaches, and the policy holds
+// while nobody is subscribed. Events for channels nobody handles are charged
+// too, so probing unknown channels is rate limited like any other traffic.
+//
+// The router answers admitted calls nobody will handle right away with
+// `app_rpc_status::CHANNEL_NOT_FOUND` instead of letting the caller wait out
+// the 30s reply timeout:
+// - calls to a reserved `astral.*` channel whose crate service is not
+//   running, whether or not the app registered any channels;
+// - once the app declared the channels it serves with
+//   [`register_app_channels`], calls to any other channel. Only registered
+//   channels reach Dart then, and separate Dart services subscribe to their
+//   own channels with [`subscribe_app_channels`].
+//
+// Instances that never register a channel keep the old behaviour for app
+// channels: every event is streamed and nothing is answered on their behalf.
+// Notifies nobody handles are dropped.
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use easytier::peers::astral_app_rpc as app_rpc;
+use lazy_static::lazy_static;
+use tokio::sync::broadcast;
+
+use super::p2p::{
+    app_rpc_status, inbound_event, lookup_app_rpc, runtime, tracing_log_lagged, AppInboundEventC,
+    AppInboundKindC,
+};
+use super::{
+    app_call_control, app_metrics, app_policy, app_stream, file_transfer, messaging, presence,
+};
+use crate::frb_generated::StreamSink;
+
+/// Channels under this prefix are used by the crate's own services
+/// (`app_stream`, `file_transfer`, `messaging`, `presence`,
+/// `app_call_control`) and never reach Dart.
+const RESERVED_PREFIX: &str = "astral.";
+
+/// Events buffered per subscriber of the routed inbound stream.
+const INBOUND_CAPACITY: usize = 1024;
+
+lazy_static! {
+    static ref ROUTERS: Mutex<HashMap<String, Router>> = Mutex::new(HashMap::new());
+}
+
+/// Bumped whenever a router task is (re)started; stale tasks see the change
+/// and exit.
+static GENERATION: AtomicU64 = AtomicU64::new(1);
+
+#[derive(Default)]
+struct Router {
+    /// Channels registered by the app; empty means every app channel is
+    /// streamed to Dart.
+    channels: BTreeSet<String>,
+    /// Generation of the running router task, 0 when none is running.
+    generation: u64,
+    /// Admitted events of the running router task; `None` when none is
+    /// running.
+    inbound: Option<broadcast::Sender<AppInboundEventC>>,
+}
+
+/// Declare channels served by this node. Calls to any other app channel are
+/// answered with `app_rpc_status::CHANNEL_NOT_FOUND` from then on.
+/// Registering an already known channel is a no-op.
+pub async fn register_app_channels(
+    instance_id: String,
+    channels: Vec<String>,
+) -> Result<(), String> {
+    if channels.iter().any(|c| c.is_empty()) {
+        return Err("channel name must not be empty".to_string());
+    }
+    if let Some(c) = channels.iter().find(|c| is_reserved(c)) {
+        return Err(format!(
+            "channel {} uses the reserved prefix {}",
+            c, RESERVED_PREFIX
+        ));
+    }
+    let svc = lookup_app_rpc(&instance_id)?;
+    ROUTERS
+        .lock()
+        .unwrap()
+        .entry(instance_id.clone())
+        .or_default()
+        .channels
+        .extend(channels);
+    ensure_router(&instance_id, &svc);
+    Ok(())
+}
+
+/// Stop serving the given channels. Once the last channel is gone the
+/// instance falls back to streaming every app event.
+pub async fn unregister_app_channels(
+    instance_id: String,
+    channels: Vec<String>,
+) -> Result<(), String> {
+    let mut routers = ROUTERS.lock().unwrap();
+    let Some(router) = routers.get_mut(&instance_id) else {
+        return Ok(());
+    };
+    for channel in &channels {
+        router.channels.remove(channel);
+    }
+    Ok(())
+}
+
+/// Channels currently registered for the instance, sorted.
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_app_channels(instance_id: String) -> Vec<String> {
+    ROUTERS
+        .lock()
+        .unwrap()
+        .get(&instance_id)
+        .map(|r| r.channels.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Like `subscribe_app_inbound`, but only delivers events for `channels`,
+/// which must already be registered. Each Dart service can hold its own
+/// subscription for the channels it handles.
+pub async fn subscribe_app_channels(
+    instance_id: String,
+    channels: Vec<String>,
+    sink: StreamSink<AppInboundEventC>,
+) -> Result<(), String> {
+    let unknown: Vec<&String> = {
+        let routers = ROUTERS.lock().unwrap();
+        let registered = routers.get(&instance_id).map(|r| &r.channels);
+        channels
+            .iter()
+            .filter(|c| registered.is_none_or(|r| !r.contains(*c)))
+            .collect()
+    };
+    if !unknown.is_empty() {
+        return Err(format!(
+            "channels not registered: {:?}; call register_app_channels first",
+            unknown
+        ));
+    }
+
+    let svc = lookup_app_rpc(&instance_id)?;
+    let mut rx = subscribe_inbound(&instance_id, &svc);
+    drop(svc);
+    loop {
+        match rx.recv().await {
+            Ok(evt) => {
+                if !channels.contains(&evt.channel) {
+                    continue;
+                }
+                if sink.add(evt).is_err() {
+                    break;
+                }
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                tracing_log_lagged(&instance_id, skipped);
+                continue;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Inbound events of the instance that were routed and admitted by the
+/// policy, starting the router task if needed. The stream closes when the
+/// instance's app RPC service stops.
+pub(crate) fn subscribe_inbound(
+    instance_id: &str,
+    svc: &app_rpc::AstralAppRpcService,
+) -> broadcast::Receiver<AppInboundEventC> {
+    ensure_router(instance_id, svc).subscribe()
+}
+
+pub(crate) fn is_reserved(channel: &str) -> bool {
+    channel.starts_with(RESERVED_PREFIX)
+}
+
+/// Whether the app registered `channel` for the instance.
+pub(crate) fn is_registered(instance_id: &str, channel: &str) -> bool {
+    ROUTERS
+        .lock()
+        .unwrap()
+        .get(instance_id)
+        .is_some_and(|r| r.channels.contains(channel))
+}
+
+/// Start the router task once the instance's app RPC service is up. After a
+/// restart (new service) the task is replaced and registered channels kept.
+pub(crate) fn watch_instance(instance_id: &str) {
+    let Ok(svc) = lookup_app_rpc(instance_id) else {
+        return;
+    };
+    {
+        let mut routers = ROUTERS.lock().unwrap();
+        let router = routers.entry(instance_id.to_string()).or_default();
+        router.generation = 0;
+        router.inbound = None;
+    }
+    ensure_router(instance_id, &svc);
+}
+
+/// Start the router task unless one is running for the instance; returns
+/// the sender of its admitted events.
+fn ensure_router(
+    instance_id: &str,
+    svc: &app_rpc::AstralAppRpcService,
+) -> broadcast::Sender<AppInboundEventC> {
+    let mut routers = ROUTERS.lock().unwrap();
+    let router = routers.entry(instance_id.to_string()).or_default();
+    if let Some(inbound) = &router.inbound {
+        return inbound.clone();
+    }
+    router.generation = GENERATION.fetch_add(1, Ordering::Relaxed);
+    let inbound = broadcast::channel(INBOUND_CAPACITY).0;
+    router.inbound = Some(inbound.clone());
+    runtime().spawn(route(
+        instance_id.to_string(),
+        router.generation,
+        svc.subscribe_inbound(),
+        inbound.clone(),
+    ));
+    inbound
+}
+
+pub(crate) fn forget_instance(instance_id: &str) {
+    ROUTERS.lock().unwrap().remove(instance_id);
+}
+
+/// Route every inbound event of the instance: apply the policy once, answer
+/// calls nobody handles (reserved channels whose service is not running, app
+/// channels outside the registered set), drop notifies nobody handles and
+/// pass the rest to `inbound`.
+///
+/// The service is looked up per reply rather than held, so the task does not
+/// keep a stopped instance's service (and its inbound channel) alive. Once it
+/// closes, the task drops `inbound`, which ends every subscription.
+async fn route(
+    instance_id: String,
+    generation: u64,
+    mut rx: broadcast::Receiver<app_rpc::AppInboundEvent>,
+    inbound: broadcast::Sender<AppInboundEventC>,
+) {
+    loop {
+        let evt = match rx.recv().await {
+            Ok(evt) => inbound_event(evt),
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                app_metrics::record_lagged(&instance_id, skipped);
+                tracing_log_lagged(&instance_id, skipped);
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+        if !app_policy::admit(&instance_id, &evt) {
+            continue;
+        }
+        let channel = &evt.channel;
+        let routed = {
+            let routers = ROUTERS.lock().unwrap();
+            let Some(router) = routers
+                .get(&instance_id)
+                .filter(|r| r.generation == generation)
+            else {
+                return;
+            };
+            router.channels.is_empty() || router.channels.contains(channel)
+        };
+        let handled = if is_reserved(channel) {
+            app_stream::is_handled(&instance_id, channel)
+                || file_transfer::is_handled(&instance_id, channel)
+                || messaging::is_handled(&instance_id, channel)
+                || presence::is_handled(&instance_id, channel)
+                || app_call_control::is_handled(&instance_id, channel)
+        } else {
+            routed
+        };
+        if !handled {
+            if evt.kind == AppInboundKindC::Call {
+                if let Ok(svc) = lookup_app_rpc(&instance_id) {
+                    svc.reply_call(
+                        evt.token,
+                        app_rpc_status::CHANNEL_NOT_FOUND,
+                        format!("channel not found: {}", channel),
+                        Vec::new(),
+                    );
+                }
+            }
+            continue;
+        }
+        app_metrics::record_inbound(&instance_id, &evt);
+        // No subscriber is not an error: app events then simply go unseen,
+        // as before anyone subscribed.
+        let _ = inbound.send(evt);
+    }
+
+    // Instance shut down; a later register or restart starts a new task.
+    let mut routers = ROUTERS.lock().unwrap();
+    if let Some(router) = routers
+        .get_mut(&instance_id)
+        .filter(|r| r.generation == generation)
+    {
+        router.generation = 0;
+        router.inbound = None;
+    }
+}
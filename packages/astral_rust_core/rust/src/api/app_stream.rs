@@ -0,0 +1,1057 @@
+// Streaming app RPC for payloads too large for a single `app_call`
+// (screenshots, save files, mod packs).
+//
+// Built on plain `Call`s on the reserved channel [`STREAM_CHANNEL`]; every
+// call carries one frame:
+//
+//   Open   | stream id | total len | channel len | channel | metadata
+//   Data   | stream id | seq | bytes
+//   End    | stream id | seq
+//   Cancel | stream id | reason
+//
+// Ordering: data and end frames carry a sequence number and the receiver
+// delivers them strictly in order, whatever order the calls arrive in.
+// Flow control: the sender keeps at most `WINDOW` chunk calls in flight, and
+// the receiver only replies to a chunk once it fits into the reader's bounded
+// buffer, so a slow Dart reader stalls the sender. Readers must start reading
+// within the call timeout (and EasyTier's 30s reply timeout), otherwise the
+// sender's pending chunks fail.
+// The receiver only accepts sequence numbers inside the sender's window
+// (`next_seq .. next_seq + WINDOW`); a duplicate or out-of-window frame fails
+// the stream with `STREAM_CANCELLED`, so a misbehaving peer cannot make it
+// queue more than `WINDOW` frames. An inbound stream with no frame from the
+// sender, or a reader that stops draining, for `IDLE_TIMEOUT` is closed.
+// Cancellation: either side can cancel. A sender cancel is forwarded as a
+// cancel frame; a reader cancel fails the sender's next chunk with
+// `app_rpc_status::STREAM_CANCELLED`. Progress subscribers on both sides get
+// a `Cancelled` event.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use futures::stream::{self, StreamExt};
+use lazy_static::lazy_static;
+use tokio::sync::{broadcast, mpsc, watch};
+
+use super::app_router::subscribe_inbound;
+use super::p2p::{
+    app_rpc_status, lookup_app_rpc, runtime, tracing_log_lagged, AppInboundEventC, AppInboundKindC,
+};
+use crate::frb_generated::StreamSink;
+
+/// Reserved channel carrying stream frames; never delivered to Dart as an
+/// app inbound event.
+pub(crate) const STREAM_CHANNEL: &str = "astral.stream";
+
+const CHUNK_SIZE: usize = 32 * 1024;
+/// Chunk calls in flight per stream.
+const WINDOW: usize = 4;
+/// Chunks buffered on the receiver before the sender is stalled.
+const READ_BUFFER: usize = 8;
+/// How long an inbound stream may go without a frame from the sender or
+/// without the reader taking a chunk. Longer than EasyTier's 30s reply
+/// timeout, so the sender has given up on its pending chunks by then.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+const FRAME_OPEN: u8 = 1;
+const FRAME_DATA: u8 = 2;
+const FRAME_END: u8 = 3;
+const FRAME_CANCEL: u8 = 4;
+
+lazy_static! {
+    static ref OUTBOUND: Mutex<HashMap<(String, u64), Outbound>> = Mutex::new(HashMap::new());
+    static ref INBOUND: Mutex<HashMap<(String, u32, u64), Inbound>> = Mutex::new(HashMap::new());
+    static ref RECEIVERS: Mutex<HashMap<String, Receiver>> = Mutex::new(HashMap::new());
+    static ref OFFERS: broadcast::Sender<(String, InboundAppStreamC)> = broadcast::channel(64).0;
+    static ref PROGRESS: broadcast::Sender<(String, u32, u64, AppStreamProgressC)> =
+        broadcast::channel(256).0;
+}
+
+static GENERATION: AtomicU64 = AtomicU64::new(1);
+
+struct Outbound {
+    dst_peer_id: u32,
+    total_len: u64,
+    timeout_ms: i32,
+    next_seq: u64,
+    bytes: u64,
+}
+
+struct Inbound {
+    total_len: u64,
+    /// Bytes delivered to the reader so far.
+    bytes: u64,
+    /// Sequence number the delivery task waits for next.
+    next_seq: u64,
+    /// Accepted but not yet delivered sequence numbers, at most `WINDOW`.
+    queued: BTreeSet<u64>,
+    /// Frames go to the delivery task, which orders them; dropped on cancel.
+    frames: mpsc::Sender<InboundFrame>,
+    /// Dropped on cancel so a delivery task blocked on a full reader buffer
+    /// wakes up.
+    _cancel: watch::Sender<()>,
+    /// Taken by [`app_stream_read`].
+    reader: Option<mpsc::Receiver<Vec<u8>>>,
+}
+
+struct InboundFrame {
+    seq: u64,
+    token: u64,
+    /// `None` for the end frame.
+    data: Option<Vec<u8>>,
+}
+
+#[derive(Default)]
+struct Receiver {
+    /// Generation of the running frame handler, 0 when none is running.
+    generation: u64,
+    /// Open [`subscribe_app_streams`] subscriptions per channel.
+    listeners: HashMap<String, usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppStreamStateC {
+    Open,
+    Finished,
+    Cancelled,
+    Failed,
+}
+
+#[derive(Debug, Clone)]
+pub struct AppStreamProgressC {
+    pub state: AppStreamStateC,
+    /// Bytes acknowledged by the receiver (sender side) or delivered to the
+    /// reader (receiver side).
+    pub bytes: u64,
+    /// Announced size; 0 when unknown.
+    pub total_len: u64,
+    pub error: String,
+}
+
+/// A stream offered by a peer; read it with [`app_stream_read`].
+#[derive(Debug, Clone)]
+pub struct InboundAppStreamC {
+    pub from_peer_id: u32,
+    pub stream_id: u64,
+    pub channel: String,
+    pub total_len: u64,
+    pub metadata: Vec<u8>,
+}
+
+/// Open a stream to `dst_peer_id` on `channel`. Fails if the peer has no
+/// [`subscribe_app_streams`] listener for the channel. `total_len` is only
+/// used for progress (0 when unknown); `timeout_ms` applies to every frame.
+pub async fn app_stream_open(
+    instance_id: String,
+    dst_peer_id: u32,
+    channel: String,
+    total_len: u64,
+    metadata: Vec<u8>,
+    timeout_ms: i32,
+) -> Result<u64, String> {
+    let svc = lookup_app_rpc(&instance_id)?;
+    let stream_id = uuid::Uuid::new_v4().as_u64_pair().0;
+    let mut frame = frame_header(FRAME_OPEN, stream_id);
+    frame.extend_from_slice(&total_len.to_be_bytes());
+    frame.extend_from_slice(&(channel.len() as u16).to_be_bytes());
+    frame.extend_from_slice(channel.as_bytes());
+    frame.extend_from_slice(&metadata);
+    // Awaited on the FRB executor like `app_call`; see the note there.
+    let resp = svc
+        .call(
+            dst_peer_id,
+            STREAM_CHANNEL.to_string(),
+            0,
+            frame,
+            0,
+            timeout_ms,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    if resp.status != app_rpc_status::OK {
+        return Err(format!(
+            "stream rejected by peer {}: status {} {}",
+            dst_peer_id, resp.status, resp.error_msg
+        ));
+    }
+    OUTBOUND.lock().unwrap().insert(
+        (instance_id, stream_id),
+        Outbound {
+            dst_peer_id,
+            total_len,
+            timeout_ms,
+            next_seq: 0,
+            bytes: 0,
+        },
+    );
+    Ok(stream_id)
+}
+
+/// Send `data` on an open stream, split into chunks with at most `WINDOW` in
+/// flight. Resolves once the receiver has buffered every chunk. Writes on the
+/// same stream must not overlap.
+pub async fn app_stream_write(
+    instance_id: String,
+    stream_id: u64,
+    data: Vec<u8>,
+) -> Result<(), String> {
+    let svc = lookup_app_rpc(&instance_id)?;
+    let chunks: Vec<&[u8]> = data.chunks(CHUNK_SIZE).collect();
+    let (dst_peer_id, first_seq, timeout_ms) = {
+        let mut outbound = OUTBOUND.lock().unwrap();
+        let stream = outbound
+            .get_mut(&(instance_id.clone(), stream_id))
+            .ok_or_else(|| format!("stream {} is not open", stream_id))?;
+        let first_seq = stream.next_seq;
+        stream.next_seq += chunks.len() as u64;
+        (stream.dst_peer_id, first_seq, stream.timeout_ms)
+    };
+
+    let mut acked = stream::iter(chunks.into_iter().enumerate())
+        .map(|(i, chunk)| {
+            let svc = svc.clone();
+            let seq = first_seq + i as u64;
+            let mut frame = frame_header(FRAME_DATA, stream_id);
+            frame.extend_from_slice(&seq.to_be_bytes());
+            frame.extend_from_slice(chunk);
+            let len = chunk.len() as u64;
+            async move {
+                let resp = svc
+                    .call(
+                        dst_peer_id,
+                        STREAM_CHANNEL.to_string(),
+                        seq,
+                        frame,
+                        0,
+                        timeout_ms,
+                    )
+                    .await
+                    .map_err(|e| (AppStreamStateC::Failed, e.to_string()))?;
+                match resp.status {
+                    app_rpc_status::OK => Ok(len),
+                    app_rpc_status::STREAM_CANCELLED => Err((
+                        AppStreamStateC::Cancelled,
+                        "stream cancelled by peer".to_string(),
+                    )),
+                    status => Err((
+                        AppStreamStateC::Failed,
+                        format!("chunk rejected: status {} {}", status, resp.error_msg),
+                    )),
+                }
+            }
+        })
+        .buffered(WINDOW);
+
+    while let Some(result) = acked.next().await {
+        match result {
+            Ok(len) => {
+                let progress = {
+                    let mut outbound = OUTBOUND.lock().unwrap();
+                    let Some(stream) = outbound.get_mut(&(instance_id.clone(), stream_id)) else {
+                        return Err("stream cancelled".to_string());
+                    };
+                    stream.bytes += len;
+                    progress(AppStreamStateC::Open, stream.bytes, stream.total_len, "")
+                };
+                emit(&instance_id, dst_peer_id, stream_id, progress);
+            }
+            Err((state, error)) => {
+                close_outbound(&instance_id, stream_id, state, &error);
+                return Err(error);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Mark the end of the stream. Resolves once the receiver has everything.
+pub async fn app_stream_finish(instance_id: String, stream_id: u64) -> Result<(), String> {
+    let svc = lookup_app_rpc(&instance_id)?;
+    let (dst_peer_id, seq, timeout_ms) = {
+        let outbound = OUTBOUND.lock().unwrap();
+        let stream = outbound
+            .get(&(instance_id.clone(), stream_id))
+            .ok_or_else(|| format!("stream {} is not open", stream_id))?;
+        (stream.dst_peer_id, stream.next_seq, stream.timeout_ms)
+    };
+    let mut frame = frame_header(FRAME_END, stream_id);
+    frame.extend_from_slice(&seq.to_be_bytes());
+    let result = svc
+        .call(
+            dst_peer_id,
+            STREAM_CHANNEL.to_string(),
+            seq,
+            frame,
+            0,
+            timeout_ms,
+        )
+        .await
+        .map_err(|e| e.to_string())
+        .and_then(|resp| match resp.status {
+            app_rpc_status::OK => Ok(()),
+            status => Err(format!(
+                "end rejected: status {} {}",
+                status, resp.error_msg
+            )),
+        });
+    match &result {
+        Ok(()) => close_outbound(&instance_id, stream_id, AppStreamStateC::Finished, ""),
+        Err(e) => close_outbound(&instance_id, stream_id, AppStreamStateC::Failed, e),
+    }
+    result
+}
+
+/// Cancel a stream from either side: `peer_id` is the destination for
+/// streams we opened, or the sender for streams we are reading.
+pub async fn app_stream_cancel(
+    instance_id: String,
+    peer_id: u32,
+    stream_id: u64,
+) -> Result<(), String> {
+    let outbound = OUTBOUND
+        .lock()
+        .unwrap()
+        .get(&(instance_id.clone(), stream_id))
+        .filter(|s| s.dst_peer_id == peer_id)
+        .map(|s| s.timeout_ms);
+    if let Some(timeout_ms) = outbound {
+        close_outbound(
+            &instance_id,
+            stream_id,
+            AppStreamStateC::Cancelled,
+            "cancelled locally",
+        );
+        // Best effort: the receiver also notices on the next missing chunk.
+        let svc = lookup_app_rpc(&instance_id)?;
+        let mut frame = frame_header(FRAME_CANCEL, stream_id);
+        frame.extend_from_slice(b"cancelled by sender");
+        let _ = svc
+            .call(peer_id, STREAM_CHANNEL.to_string(), 0, frame, 0, timeout_ms)
+            .await;
+        return Ok(());
+    }
+    if close_inbound(
+        &instance_id,
+        peer_id,
+        stream_id,
+        AppStreamStateC::Cancelled,
+        "cancelled locally",
+    ) {
+        return Ok(());
+    }
+    Err(format!("stream {} not found", stream_id))
+}
+
+/// Receive stream offers for `channel`. While at least one subscription is
+/// open, peers can open streams on the channel; otherwise they are rejected
+/// with `app_rpc_status::CHANNEL_NOT_FOUND`.
+pub async fn subscribe_app_streams(
+    instance_id: String,
+    channel: String,
+    sink: StreamSink<InboundAppStreamC>,
+) -> Result<(), String> {
+    let svc = lookup_app_rpc(&instance_id)?;
+    let mut offers = OFFERS.subscribe();
+    let generation = {
+        let mut receivers = RECEIVERS.lock().unwrap();
+        let receiver = receivers.entry(instance_id.clone()).or_default();
+        *receiver.listeners.entry(channel.clone()).or_default() += 1;
+        if receiver.generation == 0 {
+            receiver.generation = GENERATION.fetch_add(1, Ordering::Relaxed);
+            Some(receiver.generation)
+        } else {
+            None
+        }
+    };
+    if let Some(generation) = generation {
+        let rx = subscribe_inbound(&instance_id, &svc);
+        runtime().spawn(handle_frames(instance_id.clone(), generation, rx));
+    }
+    drop(svc);
+
+    loop {
+        match offers.recv().await {
+            Ok((id, offer)) => {
+                if id == instance_id && offer.channel == channel && sink.add(offer).is_err() {
+                    break;
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                tracing_log_lagged(&instance_id, skipped);
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+
+    let mut receivers = RECEIVERS.lock().unwrap();
+    if let Some(receiver) = receivers.get_mut(&instance_id) {
+        if let Some(count) = receiver.listeners.get_mut(&channel) {
+            *count -= 1;
+            if *count == 0 {
+                receiver.listeners.remove(&channel);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Read an offered stream's chunks in order. The Dart stream ends when the
+/// sender finishes; a cancelled stream ends early (see
+/// [`subscribe_app_stream_progress`] for why). Can only be called once per
+/// stream.
+pub async fn app_stream_read(
+    instance_id: String,
+    from_peer_id: u32,
+    stream_id: u64,
+    sink: StreamSink<Vec<u8>>,
+) -> Result<(), String> {
+    let mut reader = INBOUND
+        .lock()
+        .unwrap()
+        .get_mut(&(instance_id.clone(), from_peer_id, stream_id))
+        .ok_or_else(|| format!("stream {} not found", stream_id))?
+        .reader
+        .take()
+        .ok_or_else(|| format!("stream {} is already being read", stream_id))?;
+    while let Some(chunk) = reader.recv().await {
+        if sink.add(chunk).is_err() {
+            // Dart cancelled the subscription: stop the sender too.
+            close_inbound(
+                &instance_id,
+                from_peer_id,
+                stream_id,
+                AppStreamStateC::Cancelled,
+                "reader closed",
+            );
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Progress of one stream, on either side. `peer_id` as in
+/// [`app_stream_cancel`]. Ends after the final (non-`Open`) event.
+pub async fn subscribe_app_stream_progress(
+    instance_id: String,
+    peer_id: u32,
+    stream_id: u64,
+    sink: StreamSink<AppStreamProgressC>,
+) -> Result<(), String> {
+    let mut events = PROGRESS.subscribe();
+    loop {
+        match events.recv().await {
+            Ok((id, peer, stream, progress)) => {
+                if id != instance_id || peer != peer_id || stream != stream_id {
+                    continue;
+                }
+                let done = progress.state != AppStreamStateC::Open;
+                if sink.add(progress).is_err() || done {
+                    break;
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+    Ok(())
+}
+
+/// Whether `channel` is answered by the stream handler for this instance,
+/// so the channel router must leave it alone.
+pub(crate) fn is_handled(instance_id: &str, channel: &str) -> bool {
+    channel == STREAM_CHANNEL
+        && RECEIVERS
+            .lock()
+            .unwrap()
+            .get(instance_id)
+            .is_some_and(|r| r.generation != 0)
+}
+
+/// Move the frame handler onto the new service after the instance was
+/// restarted; the old handler's inbound stream closed with the old service.
+/// Streams that were open are gone and have to be reopened.
+pub(crate) fn watch_instance(instance_id: &str) {
+    let Ok(svc) = lookup_app_rpc(instance_id) else {
+        return;
+    };
+    let generation = {
+        let mut receivers = RECEIVERS.lock().unwrap();
+        let Some(receiver) = receivers
+            .get_mut(instance_id)
+            .filter(|r| r.generation != 0)
+        else {
+            return;
+        };
+        receiver.generation = GENERATION.fetch_add(1, Ordering::Relaxed);
+        receiver.generation
+    };
+    let rx = subscribe_inbound(instance_id, &svc);
+    runtime().spawn(handle_frames(instance_id.to_string(), generation, rx));
+}
+
+pub(crate) fn forget_instance(instance_id: &str) {
+    RECEIVERS.lock().unwrap().remove(instance_id);
+    OUTBOUND
+        .lock()
+        .unwrap()
+        .retain(|(id, _), _| id != instance_id);
+    INBOUND
+        .lock()
+        .unwrap()
+        .retain(|(id, ..), _| id != instance_id);
+}
+
+/// Answer stream frames for one instance until the instance stops or the
+/// handler is replaced.
+async fn handle_frames(
+    instance_id: String,
+    generation: u64,
+    mut rx: broadcast::Receiver<AppInboundEventC>,
+) {
+    loop {
+        let evt = match rx.recv().await {
+            Ok(evt) => evt,
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                tracing_log_lagged(&instance_id, skipped);
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+        let AppInboundEventC {
+            kind: AppInboundKindC::Call,
+            from_peer_id,
+            channel,
+            token,
+            payload,
+            ..
+        } = evt
+        else {
+            continue;
+        };
+        if channel != STREAM_CHANNEL {
+            continue;
+        }
+        let current = RECEIVERS
+            .lock()
+            .unwrap()
+            .get(&instance_id)
+            .is_some_and(|r| r.generation == generation);
+        if !current {
+            return;
+        }
+        if let Err((status, error)) = handle_frame(&instance_id, from_peer_id, token, &payload) {
+            reply(&instance_id, token, status, error);
+        }
+    }
+
+    let mut receivers = RECEIVERS.lock().unwrap();
+    if let Some(receiver) = receivers
+        .get_mut(&instance_id)
+        .filter(|r| r.generation == generation)
+    {
+        receiver.generation = 0;
+    }
+}
+
+/// Handle one frame. Data and end frames are replied to by the delivery
+/// task; errors are replied to by the caller.
+fn handle_frame(
+    instance_id: &str,
+    from_peer_id: u32,
+    token: u64,
+    frame: &[u8],
+) -> Result<(), (i32, String)> {
+    let malformed = || {
+        (
+            app_rpc_status::STREAM_CANCELLED,
+            "malformed stream frame".to_string(),
+        )
+    };
+    if frame.len() < 9 {
+        return Err(malformed());
+    }
+    let kind = frame[0];
+    let stream_id = read_u64(&frame[1..]).ok_or_else(malformed)?;
+    let body = &frame[9..];
+    let key = (instance_id.to_string(), from_peer_id, stream_id);
+    match kind {
+        FRAME_OPEN => {
+            let total_len = read_u64(body).ok_or_else(malformed)?;
+            let channel_len = body
+                .get(8..10)
+                .map(|b| u16::from_be_bytes([b[0], b[1]]) as usize)
+                .ok_or_else(malformed)?;
+            let channel = body
+                .get(10..10 + channel_len)
+                .and_then(|b| std::str::from_utf8(b).ok())
+                .ok_or_else(malformed)?
+                .to_string();
+            let metadata = body[10 + channel_len..].to_vec();
+            let listening = RECEIVERS
+                .lock()
+                .unwrap()
+                .get(instance_id)
+                .is_some_and(|r| r.listeners.contains_key(&channel));
+            if !listening {
+                return Err((
+                    app_rpc_status::CHANNEL_NOT_FOUND,
+                    format!("no stream listener for channel: {}", channel),
+                ));
+            }
+
+            let (frames_tx, frames_rx) = mpsc::channel(WINDOW);
+            let (chunks_tx, chunks_rx) = mpsc::channel(READ_BUFFER);
+            let (cancel_tx, cancel_rx) = watch::channel(());
+            INBOUND.lock().unwrap().insert(
+                key,
+                Inbound {
+                    total_len,
+                    bytes: 0,
+                    next_seq: 0,
+                    queued: BTreeSet::new(),
+                    frames: frames_tx,
+                    _cancel: cancel_tx,
+                    reader: Some(chunks_rx),
+                },
+            );
+            runtime().spawn(deliver(
+                instance_id.to_string(),
+                from_peer_id,
+                stream_id,
+                frames_rx,
+                chunks_tx,
+                cancel_rx,
+                IDLE_TIMEOUT,
+            ));
+            let _ = OFFERS.send((
+                instance_id.to_string(),
+                InboundAppStreamC {
+                    from_peer_id,
+                    stream_id,
+                    channel,
+                    total_len,
+                    metadata,
+                },
+            ));
+            reply(instance_id, token, app_rpc_status::OK, String::new());
+            Ok(())
+        }
+        FRAME_DATA | FRAME_END => {
+            let seq = read_u64(body).ok_or_else(malformed)?;
+            let data = (kind == FRAME_DATA).then(|| body[8..].to_vec());
+            let error = {
+                let mut inbound = INBOUND.lock().unwrap();
+                let Some(stream) = inbound.get_mut(&key) else {
+                    return Err((
+                        app_rpc_status::STREAM_CANCELLED,
+                        "stream cancelled".to_string(),
+                    ));
+                };
+                if seq < stream.next_seq || stream.queued.contains(&seq) {
+                    format!("duplicate stream frame {}", seq)
+                } else if seq >= stream.next_seq + WINDOW as u64 {
+                    format!("stream frame {} is outside the window", seq)
+                } else if stream
+                    .frames
+                    .try_send(InboundFrame { seq, token, data })
+                    .is_ok()
+                {
+                    stream.queued.insert(seq);
+                    return Ok(());
+                } else {
+                    "stream cancelled".to_string()
+                }
+            };
+            close_inbound(
+                instance_id,
+                from_peer_id,
+                stream_id,
+                AppStreamStateC::Failed,
+                &error,
+            );
+            Err((app_rpc_status::STREAM_CANCELLED, error))
+        }
+        FRAME_CANCEL => {
+            let reason = String::from_utf8_lossy(body).into_owned();
+            close_inbound(
+                instance_id,
+                from_peer_id,
+                stream_id,
+                AppStreamStateC::Cancelled,
+                &reason,
+            );
+            reply(instance_id, token, app_rpc_status::OK, String::new());
+            Ok(())
+        }
+        _ => Err(malformed()),
+    }
+}
+
+/// Push frames to the reader in sequence order, replying to each chunk once
+/// it is buffered. Closes the stream after `idle` without progress.
+async fn deliver(
+    instance_id: String,
+    from_peer_id: u32,
+    stream_id: u64,
+    mut frames: mpsc::Receiver<InboundFrame>,
+    chunks: mpsc::Sender<Vec<u8>>,
+    mut cancelled: watch::Receiver<()>,
+    idle: Duration,
+) {
+    let key = (instance_id.clone(), from_peer_id, stream_id);
+    let mut next_seq = 0;
+    // Bounded by the window check in `handle_frame`.
+    let mut pending: BTreeMap<u64, InboundFrame> = BTreeMap::new();
+    loop {
+        let frame = match tokio::time::timeout(idle, frames.recv()).await {
+            Ok(Some(frame)) => frame,
+            Ok(None) => break,
+            Err(_) => {
+                close_inbound(
+                    &instance_id,
+                    from_peer_id,
+                    stream_id,
+                    AppStreamStateC::Failed,
+                    "no frame from the sender in time",
+                );
+                break;
+            }
+        };
+        pending.insert(frame.seq, frame);
+        while let Some(frame) = pending.remove(&next_seq) {
+            let Some(data) = frame.data else {
+                reply(&instance_id, frame.token, app_rpc_status::OK, String::new());
+                close_inbound(
+                    &instance_id,
+                    from_peer_id,
+                    stream_id,
+                    AppStreamStateC::Finished,
+                    "",
+                );
+                return;
+            };
+            let len = data.len() as u64;
+            let failed = tokio::select! {
+                sent = chunks.send(data) => {
+                    sent.is_err().then_some((AppStreamStateC::Cancelled, "reader closed"))
+                }
+                _ = cancelled.changed() => Some((AppStreamStateC::Cancelled, "reader closed")),
+                _ = tokio::time::sleep(idle) => {
+                    Some((AppStreamStateC::Failed, "reader stopped reading"))
+                }
+            };
+            if let Some((state, error)) = failed {
+                reply(
+                    &instance_id,
+                    frame.token,
+                    app_rpc_status::STREAM_CANCELLED,
+                    "stream cancelled".to_string(),
+                );
+                close_inbound(&instance_id, from_peer_id, stream_id, state, error);
+                break;
+            }
+            // Advance the window before replying, so the sender's next chunk
+            // is never judged against the old one.
+            let progress = {
+                let mut inbound = INBOUND.lock().unwrap();
+                let Some(stream) = inbound.get_mut(&key) else {
+                    reply(
+                        &instance_id,
+                        frame.token,
+                        app_rpc_status::STREAM_CANCELLED,
+                        "stream cancelled".to_string(),
+                    );
+                    break;
+                };
+                stream.queued.remove(&next_seq);
+                next_seq += 1;
+                stream.next_seq = next_seq;
+                stream.bytes += len;
+                progress(AppStreamStateC::Open, stream.bytes, stream.total_len, "")
+            };
+            reply(&instance_id, frame.token, app_rpc_status::OK, String::new());
+            emit(&instance_id, from_peer_id, stream_id, progress);
+        }
+        if chunks.is_closed() || !INBOUND.lock().unwrap().contains_key(&key) {
+            break;
+        }
+    }
+
+    // Cancelled (frames sender dropped) or reader gone: fail whatever the
+    // sender is still waiting on.
+    for frame in pending.into_values() {
+        reply(
+            &instance_id,
+            frame.token,
+            app_rpc_status::STREAM_CANCELLED,
+            "stream cancelled".to_string(),
+        );
+    }
+    frames.close();
+    while let Some(frame) = frames.recv().await {
+        reply(
+            &instance_id,
+            frame.token,
+            app_rpc_status::STREAM_CANCELLED,
+            "stream cancelled".to_string(),
+        );
+    }
+}
+
+fn close_outbound(instance_id: &str, stream_id: u64, state: AppStreamStateC, error: &str) {
+    let Some(stream) = OUTBOUND
+        .lock()
+        .unwrap()
+        .remove(&(instance_id.to_string(), stream_id))
+    else {
+        return;
+    };
+    emit(
+        instance_id,
+        stream.dst_peer_id,
+        stream_id,
+        progress(state, stream.bytes, stream.total_len, error),
+    );
+}
+
+/// Drop an inbound stream; the delivery task then fails pending chunks.
+/// Returns whether the stream existed.
+fn close_inbound(
+    instance_id: &str,
+    from_peer_id: u32,
+    stream_id: u64,
+    state: AppStreamStateC,
+    error: &str,
+) -> bool {
+    let Some(stream) =
+        INBOUND
+            .lock()
+            .unwrap()
+            .remove(&(instance_id.to_string(), from_peer_id, stream_id))
+    else {
+        return false;
+    };
+    emit(
+        instance_id,
+        from_peer_id,
+        stream_id,
+        progress(state, stream.bytes, stream.total_len, error),
+    );
+    true
+}
+
+fn reply(instance_id: &str, token: u64, status: i32, error_msg: String) {
+    if let Ok(svc) = lookup_app_rpc(instance_id) {
+        svc.reply_call(token, status, error_msg, Vec::new());
+    }
+}
+
+fn emit(instance_id: &str, peer_id: u32, stream_id: u64, progress: AppStreamProgressC) {
+    let _ = PROGRESS.send((instance_id.to_string(), peer_id, stream_id, progress));
+}
+
+fn progress(state: AppStreamStateC, bytes: u64, total_len: u64, error: &str) -> AppStreamProgressC {
+    AppStreamProgressC {
+        state,
+        bytes,
+        total_len,
+        error: error.to_string(),
+    }
+}
+
+fn frame_header(kind: u8, stream_id: u64) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(CHUNK_SIZE + 17);
+    frame.push(kind);
+    frame.extend_from_slice(&stream_id.to_be_bytes());
+    frame
+}
+
+fn read_u64(bytes: &[u8]) -> Option<u64> {
+    bytes
+        .get(..8)
+        .map(|b| u64::from_be_bytes(b.try_into().unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data_frame(stream_id: u64, seq: u64, data: &[u8]) -> Vec<u8> {
+        let mut frame = frame_header(FRAME_DATA, stream_id);
+        frame.extend_from_slice(&seq.to_be_bytes());
+        frame.extend_from_slice(data);
+        frame
+    }
+
+    fn end_frame(stream_id: u64, seq: u64) -> Vec<u8> {
+        let mut frame = frame_header(FRAME_END, stream_id);
+        frame.extend_from_slice(&seq.to_be_bytes());
+        frame
+    }
+
+    /// Register an inbound stream without a delivery task; the test drives
+    /// `deliver` itself with the returned receivers.
+    fn open_inbound(
+        instance_id: &str,
+        stream_id: u64,
+        total_len: u64,
+    ) -> (mpsc::Receiver<InboundFrame>, watch::Receiver<()>) {
+        let (frames_tx, frames_rx) = mpsc::channel(WINDOW);
+        let (cancel_tx, cancel_rx) = watch::channel(());
+        INBOUND.lock().unwrap().insert(
+            (instance_id.to_string(), 1, stream_id),
+            Inbound {
+                total_len,
+                bytes: 0,
+                next_seq: 0,
+                queued: BTreeSet::new(),
+                frames: frames_tx,
+                _cancel: cancel_tx,
+                reader: None,
+            },
+        );
+        (frames_rx, cancel_rx)
+    }
+
+    fn is_open(instance_id: &str, stream_id: u64) -> bool {
+        INBOUND
+            .lock()
+            .unwrap()
+            .contains_key(&(instance_id.to_string(), 1, stream_id))
+    }
+
+    #[test]
+    fn open_without_listener_is_rejected() {
+        let mut frame = frame_header(FRAME_OPEN, 1);
+        frame.extend_from_slice(&0u64.to_be_bytes());
+        frame.extend_from_slice(&4u16.to_be_bytes());
+        frame.extend_from_slice(b"chat");
+        let err = handle_frame("stream-test-open", 1, 0, &frame).unwrap_err();
+        assert_eq!(err.0, app_rpc_status::CHANNEL_NOT_FOUND);
+        assert!(!is_open("stream-test-open", 1));
+    }
+
+    #[test]
+    fn truncated_frame_is_rejected() {
+        let err = handle_frame("stream-test-short", 1, 0, &[FRAME_DATA, 0, 0]).unwrap_err();
+        assert_eq!(err.0, app_rpc_status::STREAM_CANCELLED);
+    }
+
+    #[test]
+    fn frames_inside_window_are_accepted_in_any_order() {
+        let instance = "stream-test-window";
+        let (_frames, _cancel) = open_inbound(instance, 1, 0);
+        for seq in [3, 0, 2, 1] {
+            handle_frame(instance, 1, seq, &data_frame(1, seq, b"x")).unwrap();
+        }
+        assert!(is_open(instance, 1));
+    }
+
+    #[test]
+    fn frame_outside_window_fails_stream() {
+        let instance = "stream-test-outside";
+        let (_frames, _cancel) = open_inbound(instance, 1, 0);
+        let err = handle_frame(instance, 1, 0, &data_frame(1, WINDOW as u64, b"x")).unwrap_err();
+        assert_eq!(err.0, app_rpc_status::STREAM_CANCELLED);
+        assert!(!is_open(instance, 1));
+    }
+
+    #[test]
+    fn duplicate_frame_fails_stream() {
+        let instance = "stream-test-duplicate";
+        let (_frames, _cancel) = open_inbound(instance, 1, 0);
+        handle_frame(instance, 1, 0, &data_frame(1, 1, b"x")).unwrap();
+        let err = handle_frame(instance, 1, 0, &data_frame(1, 1, b"x")).unwrap_err();
+        assert_eq!(err.0, app_rpc_status::STREAM_CANCELLED);
+        assert!(!is_open(instance, 1));
+    }
+
+    #[test]
+    fn frame_for_unknown_stream_is_cancelled() {
+        let err = handle_frame("stream-test-unknown", 1, 0, &data_frame(1, 0, b"x")).unwrap_err();
+        assert_eq!(err.0, app_rpc_status::STREAM_CANCELLED);
+    }
+
+    #[tokio::test]
+    async fn deliver_reorders_frames_and_finishes() {
+        let instance = "stream-test-deliver";
+        let mut events = PROGRESS.subscribe();
+        let (frames, cancel) = open_inbound(instance, 1, 2);
+        handle_frame(instance, 1, 0, &data_frame(1, 1, b"b")).unwrap();
+        handle_frame(instance, 1, 0, &data_frame(1, 0, b"a")).unwrap();
+        let (chunks_tx, mut chunks_rx) = mpsc::channel(READ_BUFFER);
+        let task = tokio::spawn(deliver(
+            instance.to_string(),
+            1,
+            1,
+            frames,
+            chunks_tx,
+            cancel,
+            Duration::from_secs(5),
+        ));
+        assert_eq!(chunks_rx.recv().await.unwrap(), b"a");
+        assert_eq!(chunks_rx.recv().await.unwrap(), b"b");
+        handle_frame(instance, 1, 0, &end_frame(1, 2)).unwrap();
+        task.await.unwrap();
+        assert!(chunks_rx.recv().await.is_none());
+        assert!(!is_open(instance, 1));
+
+        let mut last = None;
+        while let Ok((id, _, _, progress)) = events.try_recv() {
+            if id == instance {
+                last = Some(progress);
+            }
+        }
+        let last = last.unwrap();
+        assert_eq!(last.state, AppStreamStateC::Finished);
+        assert_eq!(last.bytes, 2);
+    }
+
+    #[tokio::test]
+    async fn deliver_closes_idle_stream() {
+        let instance = "stream-test-idle";
+        let mut events = PROGRESS.subscribe();
+        let (frames, cancel) = open_inbound(instance, 1, 10);
+        let (chunks_tx, _chunks_rx) = mpsc::channel(READ_BUFFER);
+        deliver(
+            instance.to_string(),
+            1,
+            1,
+            frames,
+            chunks_tx,
+            cancel,
+            Duration::from_millis(50),
+        )
+        .await;
+        assert!(!is_open(instance, 1));
+
+        let mut failed = false;
+        while let Ok((id, _, _, progress)) = events.try_recv() {
+            failed |= id == instance && progress.state == AppStreamStateC::Failed;
+        }
+        assert!(failed);
+    }
+
+    #[tokio::test]
+    async fn deliver_closes_stream_when_reader_stalls() {
+        let instance = "stream-test-stall";
+        let (frames, cancel) = open_inbound(instance, 1, 2);
+        handle_frame(instance, 1, 0, &data_frame(1, 0, b"a")).unwrap();
+        handle_frame(instance, 1, 0, &data_frame(1, 1, b"b")).unwrap();
+        let (chunks_tx, _chunks_rx) = mpsc::channel(1);
+        deliver(
+            instance.to_string(),
+            1,
+            1,
+            frames,
+            chunks_tx,
+            cancel,
+            Duration::from_millis(50),
+        )
+        .await;
+        assert!(!is_open(instance, 1));
+    }
+}
@@ -0,0 +1,381 @@
+// 节点密钥与服务器公钥固定（pinning）。
+//
+// EasyTier 的安全模式用 x25519 静态密钥做握手：本机持有一对密钥，连接服务器时
+// 如果 `PeerConfig.peer_public_key` 有值，握手阶段会校验对端公钥，不匹配直接断开。
+// 私有中继的用户固定好服务器公钥，就能防止中间人冒充中继。
+//
+// 密钥和固定列表保存在调用方指定目录下的 `node_key.json`（Unix 上权限 0600）。
+// 没有加载过密钥库时，固定列表只保存在内存里。
+//
+// 安全模式按实例开启：`FlagsC.secure_mode`，或 TOML 配置里的
+// `[secure_mode] enabled = true`。开启但没写密钥的实例用这里加载的节点密钥；
+// 没开启的实例不受影响，照常能加入非安全模式的服务器。
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use easytier::common::config::{ConfigLoader, PeerConfig, TomlConfigLoader};
+use easytier::common::global_ctx::GlobalCtxEvent;
+use easytier::proto::common::SecureModeConfig;
+use lazy_static::lazy_static;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use tokio::sync::broadcast;
+use x25519_dalek::{PublicKey, StaticSecret};
+
+use super::p2p::subscribe_instance_events;
+use super::redact;
+use crate::frb_generated::StreamSink;
+
+const KEY_FILE_NAME: &str = "node_key.json";
+
+lazy_static! {
+    static ref STORE: Mutex<KeyStore> = Mutex::new(KeyStore::default());
+    static ref CHANGES: broadcast::Sender<CredentialEventC> = broadcast::channel(16).0;
+}
+
+#[derive(Default)]
+struct KeyStore {
+    dir: Option<PathBuf>,
+    secret: Option<[u8; 32]>,
+    /// 服务器 URL -> base64 公钥。
+    pinned: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NodeKeyInfoC {
+    /// base64 编码的 x25519 公钥。
+    pub public_key: String,
+    pub fingerprint: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PinnedServerKeyC {
+    pub server_url: String,
+    pub public_key: String,
+    pub fingerprint: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CredentialEventKindC {
+    /// 本机密钥被生成 / 重新生成。
+    LocalKeyChanged,
+    /// 某个服务器的固定公钥被新增、替换或移除。
+    PinnedKeyChanged,
+    /// EasyTier 实例报告凭据变更（`GlobalCtxEvent::CredentialChanged`）。
+    InstanceCredentialChanged,
+}
+
+/// [`subscribe_credential_events`] 推送的事件。和 `AppInboundEventC` 一样用扁平结构，
+/// 不相关的字段留空串。
+#[derive(Debug, Clone)]
+pub struct CredentialEventC {
+    pub kind: CredentialEventKindC,
+    /// `PinnedKeyChanged` 对应的服务器 URL。
+    pub server_url: String,
+    /// 变更后的公钥；本机密钥事件为本机公钥，固定被移除时为空串。
+    pub public_key: String,
+    pub fingerprint: String,
+}
+
+/// 从 `store_dir` 加载节点密钥；不存在时生成一对新密钥并写盘。
+/// 加载后，开启了安全模式的实例会用这对密钥握手。
+pub fn load_or_generate_node_keypair(store_dir: String) -> Result<NodeKeyInfoC, String> {
+    let dir = PathBuf::from(store_dir);
+    let path = dir.join(KEY_FILE_NAME);
+    let mut store = STORE.lock().unwrap();
+
+    if path.exists() {
+        let (secret, pinned) = read_key_file(&path)?;
+        store.dir = Some(dir);
+        store.secret = Some(secret);
+        // 内存里先固定过的条目保留，同名以文件为准。
+        store.pinned.extend(pinned);
+        write_key_file(&store)?;
+        return Ok(node_key_info(&secret));
+    }
+
+    let secret = generate_secret()?;
+    store.dir = Some(dir);
+    store.secret = Some(secret);
+    write_key_file(&store)?;
+    drop(store);
+
+    let info = node_key_info(&secret);
+    notify_local_key_changed(&info);
+    Ok(info)
+}
+
+/// 丢弃旧密钥并生成新的一对。已固定本机旧公钥的对端需要重新固定。
+pub fn regenerate_node_keypair(store_dir: String) -> Result<NodeKeyInfoC, String> {
+    let mut store = STORE.lock().unwrap();
+    let secret = generate_secret()?;
+    store.dir = Some(PathBuf::from(store_dir));
+    store.secret = Some(secret);
+    write_key_file(&store)?;
+    drop(store);
+
+    let info = node_key_info(&secret);
+    notify_local_key_changed(&info);
+    Ok(info)
+}
+
+/// 本机公钥与指纹；还没加载密钥库时返回 `None`。
+#[flutter_rust_bridge::frb(sync)]
+pub fn local_public_key() -> Option<NodeKeyInfoC> {
+    STORE.lock().unwrap().secret.as_ref().map(node_key_info)
+}
+
+/// 计算任意 base64 公钥的指纹，方便 UI 让用户和服务器管理员核对。
+#[flutter_rust_bridge::frb(sync)]
+pub fn public_key_fingerprint(public_key: String) -> Result<String, String> {
+    Ok(fingerprint(&decode_public_key(&public_key)?))
+}
+
+/// 固定某个服务器 URL 的公钥。之后新建的实例连接该 URL 时会校验对端公钥。
+pub fn pin_server_public_key(server_url: String, public_key: String) -> Result<(), String> {
+    let server_url = server_url.trim().to_string();
+    if server_url.is_empty() {
+        return Err("server url is empty".to_string());
+    }
+    let raw = decode_public_key(&public_key)?;
+    let public_key = BASE64.encode(raw);
+
+    let mut store = STORE.lock().unwrap();
+    store.pinned.insert(server_url.clone(), public_key.clone());
+    write_key_file(&store)?;
+    drop(store);
+
+    let _ = CHANGES.send(CredentialEventC {
+        kind: CredentialEventKindC::PinnedKeyChanged,
+        server_url,
+        fingerprint: fingerprint(&raw),
+        public_key,
+    });
+    Ok(())
+}
+
+/// 取消固定。返回 `false` 表示该 URL 本来就没有固定公钥。
+pub fn unpin_server_public_key(server_url: String) -> Result<bool, String> {
+    let server_url = server_url.trim().to_string();
+    let mut store = STORE.lock().unwrap();
+    if store.pinned.remove(&server_url).is_none() {
+        return Ok(false);
+    }
+    write_key_file(&store)?;
+    drop(store);
+
+    let _ = CHANGES.send(CredentialEventC {
+        kind: CredentialEventKindC::PinnedKeyChanged,
+        server_url,
+        public_key: String::new(),
+        fingerprint: String::new(),
+    });
+    Ok(true)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_pinned_server_keys() -> Vec<PinnedServerKeyC> {
+    let store = STORE.lock().unwrap();
+    store
+        .pinned
+        .iter()
+        .map(|(url, key)| PinnedServerKeyC {
+            server_url: url.clone(),
+            public_key: key.clone(),
+            fingerprint: decode_public_key(key)
+                .map(|raw| fingerprint(&raw))
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
+/// 推送凭据变更：本机密钥 / 固定列表的改动，以及该实例上 EasyTier 报告的
+/// `CredentialChanged`。实例关闭后 future 结束。
+pub async fn subscribe_credential_events(
+    instance_id: String,
+    sink: StreamSink<CredentialEventC>,
+) -> Result<(), String> {
+    let mut events = subscribe_instance_events(&instance_id)?;
+    let mut local = CHANGES.subscribe();
+    loop {
+        let evt = tokio::select! {
+            e = events.recv() => match e {
+                Ok(GlobalCtxEvent::CredentialChanged) => {
+                    let local_key = local_public_key();
+                    CredentialEventC {
+                        kind: CredentialEventKindC::InstanceCredentialChanged,
+                        server_url: String::new(),
+                        public_key: local_key.as_ref().map(|k| k.public_key.clone()).unwrap_or_default(),
+                        fingerprint: local_key.map(|k| k.fingerprint).unwrap_or_default(),
+                    }
+                }
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            e = local.recv() => match e {
+                Ok(evt) => evt,
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+        };
+        if sink.add(evt).is_err() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// 为服务器 URL 构造 `PeerConfig`，带上固定的公钥（如果有）。
+pub(crate) fn peer_config(server_url: &str) -> Result<PeerConfig, String> {
+    let uri = server_url.parse().map_err(|e| {
+        format!(
+            "invalid server url: {}, error: {}",
+            redact::url(server_url),
+            e
+        )
+    })?;
+    let peer_public_key = STORE.lock().unwrap().pinned.get(server_url.trim()).cloned();
+    Ok(PeerConfig {
+        uri,
+        peer_public_key,
+    })
+}
+
+/// 实例配置开启了安全模式但没给本机密钥时，填入已加载的节点密钥。
+/// 没开启安全模式的配置原样返回；开启了却还没加载密钥库时报错，
+/// 不会悄悄退回非安全模式。
+pub(crate) fn apply_node_key(cfg: &TomlConfigLoader) -> Result<(), String> {
+    let Some(secure) = cfg.get_secure_mode().filter(|s| s.enabled) else {
+        return Ok(());
+    };
+    if secure.local_private_key.is_some() {
+        return Ok(());
+    }
+    let secret = STORE.lock().unwrap().secret.ok_or_else(|| {
+        "secure mode is enabled but no node key is loaded; call load_or_generate_node_keypair first"
+            .to_string()
+    })?;
+    let public = PublicKey::from(&StaticSecret::from(secret));
+    cfg.set_secure_mode(Some(SecureModeConfig {
+        enabled: true,
+        local_private_key: Some(BASE64.encode(secret)),
+        local_public_key: Some(BASE64.encode(public.as_bytes())),
+    }));
+    Ok(())
+}
+
+fn generate_secret() -> Result<[u8; 32], String> {
+    let mut secret = [0u8; 32];
+    getrandom::getrandom(&mut secret).map_err(|e| format!("generate node key failed: {}", e))?;
+    Ok(secret)
+}
+
+fn node_key_info(secret: &[u8; 32]) -> NodeKeyInfoC {
+    let public = PublicKey::from(&StaticSecret::from(*secret));
+    NodeKeyInfoC {
+        public_key: BASE64.encode(public.as_bytes()),
+        fingerprint: fingerprint(public.as_bytes()),
+    }
+}
+
+fn notify_local_key_changed(info: &NodeKeyInfoC) {
+    let _ = CHANGES.send(CredentialEventC {
+        kind: CredentialEventKindC::LocalKeyChanged,
+        server_url: String::new(),
+        public_key: info.public_key.clone(),
+        fingerprint: info.fingerprint.clone(),
+    });
+}
+
+fn decode_public_key(public_key: &str) -> Result<[u8; 32], String> {
+    let raw = BASE64
+        .decode(public_key.trim())
+        .map_err(|e| format!("invalid public key: {}", e))?;
+    raw.try_into()
+        .map_err(|raw: Vec<u8>| format!("invalid public key length: {} (expect 32)", raw.len()))
+}
+
+/// SHA-256 前 16 字节，按 4 个十六进制字符分组：`ab12:cd34:...`。
+fn fingerprint(public_key: &[u8]) -> String {
+    let digest = Sha256::digest(public_key);
+    hex::encode(&digest[..16])
+        .as_bytes()
+        .chunks(4)
+        .map(|c| String::from_utf8_lossy(c).into_owned())
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+fn read_key_file(path: &Path) -> Result<([u8; 32], BTreeMap<String, String>), String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("read {} failed: {}", path.display(), e))?;
+    let value: Value = serde_json::from_str(&text)
+        .map_err(|e| format!("parse {} failed: {}", path.display(), e))?;
+
+    let secret = value
+        .get("private_key")
+        .and_then(Value::as_str)
+        .ok_or_else(|| format!("{} has no private_key", path.display()))?;
+    let secret: [u8; 32] = BASE64
+        .decode(secret)
+        .ok()
+        .and_then(|raw| raw.try_into().ok())
+        .ok_or_else(|| format!("{} has an invalid private_key", path.display()))?;
+
+    let pinned = value
+        .get("pinned")
+        .and_then(Value::as_object)
+        .map(|m| {
+            m.iter()
+                .filter_map(|(url, key)| Some((url.clone(), key.as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok((secret, pinned))
+}
+
+/// 没有目录（还没加载过密钥库）时只保留在内存里。
+fn write_key_file(store: &KeyStore) -> Result<(), String> {
+    let (Some(dir), Some(secret)) = (&store.dir, &store.secret) else {
+        return Ok(());
+    };
+    std::fs::create_dir_all(dir).map_err(|e| format!("create {} failed: {}", dir.display(), e))?;
+
+    let path = dir.join(KEY_FILE_NAME);
+    let public = PublicKey::from(&StaticSecret::from(*secret));
+    let text = serde_json::to_string_pretty(&json!({
+        "private_key": BASE64.encode(secret),
+        "public_key": BASE64.encode(public.as_bytes()),
+        "pinned": store.pinned,
+    }))
+    .map_err(|e| format!("json serialize failed: {}", e))?;
+
+    write_private_file(&path, text.as_bytes())
+        .map_err(|e| format!("write {} failed: {}", path.display(), e))
+}
+
+#[cfg(unix)]
+fn write_private_file(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+    use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    // 文件之前可能以更宽的权限存在，`mode` 只对新建生效。
+    file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    file.write_all(data)
+}
+
+#[cfg(not(unix))]
+fn write_private_file(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    std::fs::write(path, data)
+}
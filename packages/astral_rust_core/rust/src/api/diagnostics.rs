@@ -0,0 +1,545 @@
+// 连接诊断："为什么连不上？"
+//
+// 以前只能从零散的事件字符串里猜原因。[`run_diagnostics`] 把常见原因逐项检查一遍，
+// 每项给出 pass / warn / fail 和修复建议：
+// - 服务器能否解析、按协议能否连通；
+// - 监听端口是否绑定成功；
+// - TUN 设备状态；
+// - STUN 探测到的 NAT 类型；
+// - 打洞是否被 flags 关掉；
+// - 防火墙是否拦截；
+// - MTU 是否合理；
+// - 最近的连接错误。
+//
+// 监听失败、TUN 错误和连接错误只能从事件里拿到，由 [`watch_instance`] 在后台记录
+// 最近的若干条。监听失败在实例一启动就会发出，所以订阅要在启动后立刻拿到，
+// 不能等其他初始化做完。
+//
+// UDP / QUIC 等服务器的可达性用 [`probe_server`] 做一次真实的隧道握手来判断。
+
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use easytier::common::config::ConfigLoader;
+use easytier::common::global_ctx::{EventBusSubscriber, GlobalCtxEvent};
+use easytier::proto::common::NatType;
+use lazy_static::lazy_static;
+use tokio::net::lookup_host;
+use tokio::sync::broadcast;
+use tokio::time::timeout;
+
+use super::firewall::{self, FirewallErrorKind};
+use super::p2p::{connected_remote_addrs, get_instance_info, instance_config, runtime};
+use super::redact;
+use super::server_probe::probe_server;
+
+/// 每个实例保留的最近连接错误条数。
+const MAX_CONNECT_ERRORS: usize = 20;
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+/// EasyTier 的默认端口，URL 里没写端口时使用。
+const DEFAULT_PORT: u16 = 11010;
+
+lazy_static! {
+    static ref RECORDS: Mutex<HashMap<String, EventRecord>> = Mutex::new(HashMap::new());
+}
+
+#[derive(Default)]
+struct EventRecord {
+    connect_errors: VecDeque<ConnectErrorC>,
+    /// 监听 URL（已脱敏）-> 失败原因。
+    listener_failures: HashMap<String, String>,
+    tun_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DiagnosticStatusC {
+    Pass,
+    Warn,
+    Fail,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticCheckKindC {
+    ServerResolve,
+    ServerReachability,
+    Listener,
+    TunDevice,
+    NatType,
+    HolePunching,
+    Firewall,
+    Mtu,
+    ConnectErrors,
+}
+
+#[derive(Debug, Clone)]
+pub struct DiagnosticCheckC {
+    pub kind: DiagnosticCheckKindC,
+    pub status: DiagnosticStatusC,
+    /// 检查对象，例如服务器 / 监听 URL（已脱敏）；整体性检查为空串。
+    pub target: String,
+    pub detail: String,
+    /// 修复建议；`Pass` 时为空串。
+    pub suggestion: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConnectErrorC {
+    /// 目标地址（已脱敏）。
+    pub dst: String,
+    pub ip_version: String,
+    pub error: String,
+    pub timestamp_ms: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct DiagnosticReportC {
+    /// 所有检查里最差的状态。
+    pub overall: DiagnosticStatusC,
+    pub checks: Vec<DiagnosticCheckC>,
+    /// 最近的连接错误，按时间先后排列。
+    pub recent_connect_errors: Vec<ConnectErrorC>,
+}
+
+/// 对实例做一次完整的连接诊断。
+pub async fn run_diagnostics(instance_id: String) -> Result<DiagnosticReportC, String> {
+    let cfg = instance_config(&instance_id)?;
+    let info = get_instance_info(&instance_id).await.ok();
+    let flags = cfg.get_flags();
+    let (connect_errors, listener_failures, tun_error) = {
+        let records = RECORDS.lock().unwrap();
+        match records.get(&instance_id) {
+            Some(r) => (
+                r.connect_errors.iter().cloned().collect::<Vec<_>>(),
+                r.listener_failures.clone(),
+                r.tun_error.clone(),
+            ),
+            None => (Vec::new(), HashMap::new(), None),
+        }
+    };
+
+    let mut checks = Vec::new();
+
+    // 服务器：解析 + 连通性
+    let connected = connected_remote_addrs(&instance_id).await;
+    for peer in cfg.get_peers() {
+        checks.extend(check_server(&peer.uri, &connected).await);
+    }
+
+    // 监听端口
+    let bound: Vec<String> = info
+        .as_ref()
+        .and_then(|i| i.my_node_info.as_ref())
+        .map(|n| n.listeners.iter().map(|u| u.url.clone()).collect())
+        .unwrap_or_default();
+    for listener in cfg.get_listeners().unwrap_or_default() {
+        let target = redact::url(listener.as_str());
+        let failure = listener_failures.get(&target);
+        let status = if failure.is_some() {
+            DiagnosticStatusC::Fail
+        } else if bound.iter().any(|b| same_listener(b, &listener)) {
+            DiagnosticStatusC::Pass
+        } else {
+            DiagnosticStatusC::Warn
+        };
+        checks.push(DiagnosticCheckC {
+            kind: DiagnosticCheckKindC::Listener,
+            status,
+            target,
+            detail: match (status, failure) {
+                (_, Some(err)) => format!("bind failed: {}", err),
+                (DiagnosticStatusC::Pass, _) => "listening".to_string(),
+                _ => "not reported as listening".to_string(),
+            },
+            suggestion: match status {
+                DiagnosticStatusC::Pass => String::new(),
+                _ => "the port may be used by another program; pick a different listener port"
+                    .to_string(),
+            },
+        });
+    }
+
+    // TUN
+    let dev_name = info
+        .as_ref()
+        .map(|i| i.dev_name.clone())
+        .unwrap_or_default();
+    checks.push(if flags.no_tun {
+        DiagnosticCheckC {
+            kind: DiagnosticCheckKindC::TunDevice,
+            status: DiagnosticStatusC::Warn,
+            target: String::new(),
+            detail: "no_tun is enabled; other programs can only reach peers via port forwards"
+                .to_string(),
+            suggestion: "disable no_tun if games need the virtual network".to_string(),
+        }
+    } else if let Some(err) = tun_error {
+        DiagnosticCheckC {
+            kind: DiagnosticCheckKindC::TunDevice,
+            status: DiagnosticStatusC::Fail,
+            target: dev_name,
+            detail: err,
+            suggestion:
+                "run as administrator / root and make sure the TUN driver (wintun) is available"
+                    .to_string(),
+        }
+    } else if dev_name.is_empty() {
+        DiagnosticCheckC {
+            kind: DiagnosticCheckKindC::TunDevice,
+            status: DiagnosticStatusC::Warn,
+            target: String::new(),
+            detail: "TUN device is not ready yet".to_string(),
+            suggestion:
+                "wait a few seconds; if it stays missing, restart with administrator / root rights"
+                    .to_string(),
+        }
+    } else {
+        pass(
+            DiagnosticCheckKindC::TunDevice,
+            &dev_name,
+            "TUN device is up",
+        )
+    });
+
+    // NAT 类型
+    let nat = info
+        .as_ref()
+        .and_then(|i| i.my_node_info.as_ref())
+        .and_then(|n| n.stun_info.as_ref())
+        .and_then(|s| NatType::try_from(s.udp_nat_type).ok())
+        .unwrap_or(NatType::Unknown);
+    checks.push(check_nat(nat));
+
+    // 打洞开关
+    let disabled: Vec<&str> = [
+        ("disable_p2p", flags.disable_p2p),
+        ("disable_udp_hole_punching", flags.disable_udp_hole_punching),
+        ("disable_tcp_hole_punching", flags.disable_tcp_hole_punching),
+        ("disable_sym_hole_punching", flags.disable_sym_hole_punching),
+    ]
+    .into_iter()
+    .filter_map(|(name, on)| on.then_some(name))
+    .collect();
+    checks.push(if disabled.is_empty() {
+        pass(
+            DiagnosticCheckKindC::HolePunching,
+            "",
+            "hole punching is enabled",
+        )
+    } else {
+        DiagnosticCheckC {
+            kind: DiagnosticCheckKindC::HolePunching,
+            status: DiagnosticStatusC::Warn,
+            target: String::new(),
+            detail: format!("disabled by flags: {}", disabled.join(", ")),
+            suggestion: "re-enable these flags unless you intentionally relay all traffic"
+                .to_string(),
+        }
+    });
+
+    // 防火墙
+    match firewall::diagnose_firewall(instance_id.clone()).await {
+        Ok(diagnosis) => {
+            let blocked: Vec<_> = diagnosis.findings.iter().filter(|f| f.blocked).collect();
+            if blocked.is_empty() {
+                checks.push(pass(
+                    DiagnosticCheckKindC::Firewall,
+                    "",
+                    if diagnosis.active {
+                        "firewall allows overlay and listener traffic"
+                    } else {
+                        "firewall is not filtering inbound traffic"
+                    },
+                ));
+            }
+            for finding in blocked {
+                checks.push(DiagnosticCheckC {
+                    kind: DiagnosticCheckKindC::Firewall,
+                    status: DiagnosticStatusC::Fail,
+                    target: finding.rule.name.clone(),
+                    detail: format!("blocked by {}", finding.blocking_rule),
+                    suggestion: finding.suggestion.clone(),
+                });
+            }
+        }
+        Err(e) if e.kind == FirewallErrorKind::Unsupported => {}
+        Err(e) => checks.push(DiagnosticCheckC {
+            kind: DiagnosticCheckKindC::Firewall,
+            status: DiagnosticStatusC::Warn,
+            target: String::new(),
+            detail: format!("could not inspect firewall: {}", e),
+            suggestion: "check the system firewall manually".to_string(),
+        }),
+    }
+
+    // MTU
+    checks.push(check_mtu(flags.mtu, flags.enable_ipv6));
+
+    // 最近的连接错误
+    checks.push(match connect_errors.last() {
+        None => pass(
+            DiagnosticCheckKindC::ConnectErrors,
+            "",
+            "no recent connect errors",
+        ),
+        Some(last) => DiagnosticCheckC {
+            kind: DiagnosticCheckKindC::ConnectErrors,
+            status: DiagnosticStatusC::Warn,
+            target: last.dst.clone(),
+            detail: format!(
+                "{} recent connect errors, last: {}",
+                connect_errors.len(),
+                last.error
+            ),
+            suggestion: "check the server address and that the server is online".to_string(),
+        },
+    });
+
+    let overall = checks
+        .iter()
+        .map(|c| c.status)
+        .max()
+        .unwrap_or(DiagnosticStatusC::Pass);
+    Ok(DiagnosticReportC {
+        overall,
+        checks,
+        recent_connect_errors: connect_errors,
+    })
+}
+
+/// 开始记录诊断需要的事件；实例关闭、事件总线关闭后任务自动退出。
+///
+/// `events` 由调用方在 `run_network_instance` 返回后立刻订阅，中间不做别的事，
+/// 否则启动时的监听失败等事件会漏掉。
+pub(crate) fn watch_instance(instance_id: &str, mut events: EventBusSubscriber) {
+    let instance_id = instance_id.to_string();
+    RECORDS
+        .lock()
+        .unwrap()
+        .insert(instance_id.clone(), EventRecord::default());
+    runtime().spawn(async move {
+        loop {
+            let event = match events.recv().await {
+                Ok(e) => e,
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+            let mut records = RECORDS.lock().unwrap();
+            let Some(record) = records.get_mut(&instance_id) else {
+                break;
+            };
+            match event {
+                GlobalCtxEvent::ConnectError(dst, ip_version, error) => {
+                    if record.connect_errors.len() >= MAX_CONNECT_ERRORS {
+                        record.connect_errors.pop_front();
+                    }
+                    record.connect_errors.push_back(ConnectErrorC {
+                        dst: redact::url(&dst),
+                        ip_version,
+                        error,
+                        timestamp_ms: now_ms(),
+                    });
+                }
+                GlobalCtxEvent::ListenerAddFailed(url, error) => {
+                    record
+                        .listener_failures
+                        .insert(redact::url(url.as_str()), error);
+                }
+                GlobalCtxEvent::ListenerAdded(url) => {
+                    record.listener_failures.remove(&redact::url(url.as_str()));
+                }
+                GlobalCtxEvent::TunDeviceError(error) => record.tun_error = Some(error),
+                GlobalCtxEvent::TunDeviceReady(_) => record.tun_error = None,
+                _ => {}
+            }
+        }
+    });
+}
+
+/// 实例关闭时清理记录。
+pub(crate) fn forget_instance(instance_id: &str) {
+    RECORDS.lock().unwrap().remove(instance_id);
+}
+
+async fn check_server(uri: &url::Url, connected: &[SocketAddr]) -> Vec<DiagnosticCheckC> {
+    let target = redact::url(uri.as_str());
+    let Some(host) = uri.host_str() else {
+        return vec![DiagnosticCheckC {
+            kind: DiagnosticCheckKindC::ServerResolve,
+            status: DiagnosticStatusC::Fail,
+            target,
+            detail: "server url has no host".to_string(),
+            suggestion: "use a url like tcp://example.com:11010".to_string(),
+        }];
+    };
+    let port = uri.port_or_known_default().unwrap_or(DEFAULT_PORT);
+
+    let addrs: Vec<_> = match timeout(PROBE_TIMEOUT, lookup_host((host, port))).await {
+        Ok(Ok(addrs)) => addrs.collect(),
+        Ok(Err(e)) => {
+            return vec![DiagnosticCheckC {
+                kind: DiagnosticCheckKindC::ServerResolve,
+                status: DiagnosticStatusC::Fail,
+                target,
+                detail: format!("resolve failed: {}", e),
+                suggestion: "check the server address and your DNS settings".to_string(),
+            }]
+        }
+        Err(_) => {
+            return vec![DiagnosticCheckC {
+                kind: DiagnosticCheckKindC::ServerResolve,
+                status: DiagnosticStatusC::Fail,
+                target,
+                detail: "resolve timed out".to_string(),
+                suggestion: "check your DNS settings or use the server's IP address".to_string(),
+            }]
+        }
+    };
+    let resolved = addrs
+        .iter()
+        .map(|a| a.ip().to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut checks = vec![pass(
+        DiagnosticCheckKindC::ServerResolve,
+        &target,
+        &format!("resolved to {}", resolved),
+    )];
+
+    if addrs.iter().any(|a| connected.contains(a)) {
+        checks.push(pass(
+            DiagnosticCheckKindC::ServerReachability,
+            &target,
+            &format!("connected over {}", uri.scheme()),
+        ));
+        return checks;
+    }
+
+    // 按协议做一次真实的隧道握手；WireGuard 等无法不带凭据握手的协议只能给出提示。
+    let probe = probe_server(uri.as_str(), PROBE_TIMEOUT).await;
+    checks.push(if !probe.protocol_supported {
+        DiagnosticCheckC {
+            kind: DiagnosticCheckKindC::ServerReachability,
+            status: DiagnosticStatusC::Warn,
+            target,
+            detail: format!("not connected; {}", probe.error),
+            suggestion: "try a tcp://, udp:// or wss:// server".to_string(),
+        }
+    } else if probe.reachable {
+        DiagnosticCheckC {
+            kind: DiagnosticCheckKindC::ServerReachability,
+            status: DiagnosticStatusC::Warn,
+            target,
+            detail: format!(
+                "{} handshake succeeded in {} ms but not connected",
+                uri.scheme(),
+                probe.latency_ms
+            ),
+            suggestion: "check the network name / secret and the pinned server key".to_string(),
+        }
+    } else {
+        DiagnosticCheckC {
+            kind: DiagnosticCheckKindC::ServerReachability,
+            status: DiagnosticStatusC::Fail,
+            target,
+            detail: format!("{} {}", uri.scheme(), probe.error),
+            suggestion: "the server may be offline, or a firewall blocks outgoing connections; try another protocol or server"
+                .to_string(),
+        }
+    });
+    checks
+}
+
+fn check_nat(nat: NatType) -> DiagnosticCheckC {
+    let (status, detail, suggestion) = match nat {
+        NatType::OpenInternet | NatType::NoPat | NatType::FullCone => (
+            DiagnosticStatusC::Pass,
+            "direct connections should work",
+            "",
+        ),
+        NatType::Restricted | NatType::PortRestricted => (
+            DiagnosticStatusC::Pass,
+            "direct connections usually work via hole punching",
+            "",
+        ),
+        NatType::Symmetric
+        | NatType::SymUdpFirewall
+        | NatType::SymmetricEasyInc
+        | NatType::SymmetricEasyDec => (
+            DiagnosticStatusC::Warn,
+            "symmetric NAT: direct connections often fail and traffic goes through relays",
+            "enable UPnP on the router or add a listener with a forwarded port",
+        ),
+        _ => (
+            DiagnosticStatusC::Warn,
+            "NAT type not detected yet",
+            "wait for STUN detection, or check that UDP is not blocked",
+        ),
+    };
+    DiagnosticCheckC {
+        kind: DiagnosticCheckKindC::NatType,
+        status,
+        target: format!("{:?}", nat),
+        detail: detail.to_string(),
+        suggestion: suggestion.to_string(),
+    }
+}
+
+fn check_mtu(mtu: u32, enable_ipv6: bool) -> DiagnosticCheckC {
+    // 0 表示使用 EasyTier 的默认值。
+    let min = if enable_ipv6 { 1280 } else { 576 };
+    if mtu == 0 || (min..=1420).contains(&mtu) {
+        return pass(
+            DiagnosticCheckKindC::Mtu,
+            "",
+            &format!(
+                "mtu {}",
+                if mtu == 0 {
+                    "default".to_string()
+                } else {
+                    mtu.to_string()
+                }
+            ),
+        );
+    }
+    DiagnosticCheckC {
+        kind: DiagnosticCheckKindC::Mtu,
+        status: DiagnosticStatusC::Warn,
+        target: String::new(),
+        detail: if mtu < min {
+            format!("mtu {} is below the minimum {}", mtu, min)
+        } else {
+            format!(
+                "mtu {} leaves no room for tunnel headers and may fragment",
+                mtu
+            )
+        },
+        suggestion: "use the default mtu (0) or a value around 1380".to_string(),
+    }
+}
+
+fn pass(kind: DiagnosticCheckKindC, target: &str, detail: &str) -> DiagnosticCheckC {
+    DiagnosticCheckC {
+        kind,
+        status: DiagnosticStatusC::Pass,
+        target: target.to_string(),
+        detail: detail.to_string(),
+        suggestion: String::new(),
+    }
+}
+
+/// 运行信息里的监听 URL 会把 `0.0.0.0` 换成实际地址，只比较协议和端口。
+fn same_listener(bound: &str, configured: &url::Url) -> bool {
+    let Ok(bound) = url::Url::parse(bound) else {
+        return false;
+    };
+    bound.scheme() == configured.scheme() && bound.port() == configured.port()
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
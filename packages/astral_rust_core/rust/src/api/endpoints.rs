@@ -0,0 +1,222 @@
+// 运行中增删服务器地址（connector）和监听地址。
+//
+// 服务器地址通过配置补丁的 `connectors` 增删，不重启实例，也不影响其他连接；
+// 移除的地址对应的连接随之关闭。补丁的 connector 项只有 URL，固定了公钥
+// （`PeerConfig.peer_public_key`）的服务器这样加进去不会校验公钥，所以添加这种地址时
+// 和监听地址一样改配置后重启实例。
+//
+// EasyTier 的配置补丁没有监听地址这一项，所以监听地址的修改只能用新的监听列表重启
+// 实例（instance id 不变），然后等待 `ListenerAdded` / `ListenerAddFailed` 事件，把绑定
+// 结果直接返回给调用方。重启会断开所有连接，Dart 侧的订阅需要重新建立，具体影响和
+// 哪些服务会自动恢复见 `restart_instance`。新配置起不来时实例会恢复到修改前的配置。
+
+use std::time::Duration;
+
+use easytier::common::config::ConfigLoader;
+use easytier::common::global_ctx::{EventBusSubscriber, GlobalCtxEvent};
+use easytier::proto::api::config::{ConfigPatchAction, InstanceConfigPatch};
+use tokio::sync::broadcast;
+use tokio::time::timeout;
+
+use super::credential;
+use super::p2p::{
+    config_snapshot, connector_patch, get_instance_info, instance_config, patch_instance_config,
+    restart_instance,
+};
+use super::redact;
+
+/// 重启后等待监听结果的时间。
+const LISTENER_WAIT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndpointOutcomeC {
+    Added,
+    AlreadyPresent,
+    Removed,
+    NotFound,
+    /// 仅监听：绑定失败，`error` 为原因。失败的监听不会保留在配置里。
+    Failed,
+}
+
+#[derive(Debug, Clone)]
+pub struct EndpointResultC {
+    /// 操作的 URL（已脱敏）。
+    pub url: String,
+    pub outcome: EndpointOutcomeC,
+    pub error: String,
+}
+
+/// 运行中添加一个服务器 / 节点地址，例如好友的直连 IP `tcp://1.2.3.4:11010`。
+/// 地址固定了公钥时会重启实例，见模块说明。
+pub async fn add_peer_uri(instance_id: String, uri: String) -> Result<EndpointResultC, String> {
+    let peer = credential::peer_config(&uri)?;
+    let cfg = instance_config(&instance_id)?;
+    let mut peers = cfg.get_peers();
+    if peers.iter().any(|p| p.uri == peer.uri) {
+        return Ok(result(&uri, EndpointOutcomeC::AlreadyPresent));
+    }
+
+    if peer.peer_public_key.is_some() {
+        let previous = config_snapshot(&cfg)?;
+        let old = peers.clone();
+        peers.push(peer);
+        cfg.set_peers(peers);
+        if let Err(e) = restart_instance(&instance_id, cfg.clone(), previous).await {
+            cfg.set_peers(old);
+            return Err(e);
+        }
+        return Ok(result(&uri, EndpointOutcomeC::Added));
+    }
+
+    patch_instance_config(
+        &instance_id,
+        InstanceConfigPatch {
+            connectors: vec![connector_patch(peer.uri.as_str(), ConfigPatchAction::Add)],
+            ..Default::default()
+        },
+    )
+    .await?;
+    peers.push(peer);
+    cfg.set_peers(peers);
+    Ok(result(&uri, EndpointOutcomeC::Added))
+}
+
+/// 运行中移除一个服务器 / 节点地址，已建立的连接随之关闭。
+pub async fn remove_peer_uri(instance_id: String, uri: String) -> Result<EndpointResultC, String> {
+    let parsed: url::Url = uri
+        .parse()
+        .map_err(|e| format!("invalid peer url: {}, error: {}", redact::url(&uri), e))?;
+    let cfg = instance_config(&instance_id)?;
+    let mut peers = cfg.get_peers();
+    let Some(index) = peers.iter().position(|p| p.uri == parsed) else {
+        return Ok(result(&uri, EndpointOutcomeC::NotFound));
+    };
+
+    patch_instance_config(
+        &instance_id,
+        InstanceConfigPatch {
+            connectors: vec![connector_patch(parsed.as_str(), ConfigPatchAction::Remove)],
+            ..Default::default()
+        },
+    )
+    .await?;
+    peers.remove(index);
+    cfg.set_peers(peers);
+    Ok(result(&uri, EndpointOutcomeC::Removed))
+}
+
+/// 添加监听地址并返回绑定结果。会重启实例，断开所有连接，见模块说明。
+pub async fn add_listener(instance_id: String, url: String) -> Result<EndpointResultC, String> {
+    let parsed: url::Url = url
+        .parse()
+        .map_err(|e| format!("invalid listener url: {}, error: {}", redact::url(&url), e))?;
+    let cfg = instance_config(&instance_id)?;
+    let old = cfg.get_listeners().unwrap_or_default();
+    if old.contains(&parsed) {
+        return Ok(result(&url, EndpointOutcomeC::AlreadyPresent));
+    }
+
+    let previous = config_snapshot(&cfg)?;
+    let mut listeners = old.clone();
+    listeners.push(parsed.clone());
+    cfg.set_listeners(listeners);
+    let events = match restart_instance(&instance_id, cfg.clone(), previous).await {
+        Ok(events) => events,
+        Err(e) => {
+            cfg.set_listeners(old);
+            return Err(e);
+        }
+    };
+
+    match wait_listener(&instance_id, &parsed, events).await {
+        Ok(()) => Ok(result(&url, EndpointOutcomeC::Added)),
+        Err(error) => {
+            // 只回滚配置快照，不再重启一次；绑定失败的监听本来就没在工作。
+            cfg.set_listeners(old);
+            Ok(EndpointResultC {
+                url: redact::url(&url),
+                outcome: EndpointOutcomeC::Failed,
+                error,
+            })
+        }
+    }
+}
+
+/// 移除监听地址。会重启实例，断开所有连接，见模块说明。
+pub async fn remove_listener(instance_id: String, url: String) -> Result<EndpointResultC, String> {
+    let parsed: url::Url = url
+        .parse()
+        .map_err(|e| format!("invalid listener url: {}, error: {}", redact::url(&url), e))?;
+    let cfg = instance_config(&instance_id)?;
+    let old = cfg.get_listeners().unwrap_or_default();
+    if !old.contains(&parsed) {
+        return Ok(result(&url, EndpointOutcomeC::NotFound));
+    }
+
+    let previous = config_snapshot(&cfg)?;
+    cfg.set_listeners(old.iter().filter(|l| **l != parsed).cloned().collect());
+    if let Err(e) = restart_instance(&instance_id, cfg.clone(), previous).await {
+        cfg.set_listeners(old);
+        return Err(e);
+    }
+    Ok(result(&url, EndpointOutcomeC::Removed))
+}
+
+/// 等待新实例报告这个监听的结果；事件可能在订阅前就发出了，超时后再看一眼运行信息。
+async fn wait_listener(
+    instance_id: &str,
+    listener: &url::Url,
+    mut events: EventBusSubscriber,
+) -> Result<(), String> {
+    let waited = timeout(LISTENER_WAIT, async {
+        loop {
+            match events.recv().await {
+                Ok(GlobalCtxEvent::ListenerAdded(u)) if same_listener(&u, listener) => {
+                    return Ok(());
+                }
+                Ok(GlobalCtxEvent::ListenerAddFailed(u, err)) if same_listener(&u, listener) => {
+                    return Err(err);
+                }
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => {
+                    return Err("instance stopped".to_string());
+                }
+            }
+        }
+    })
+    .await;
+    if let Ok(outcome) = waited {
+        return outcome;
+    }
+
+    let bound = get_instance_info(instance_id)
+        .await
+        .ok()
+        .and_then(|info| info.my_node_info)
+        .map(|node| node.listeners)
+        .unwrap_or_default();
+    if bound
+        .iter()
+        .filter_map(|u| u.url.parse::<url::Url>().ok())
+        .any(|u| same_listener(&u, listener))
+    {
+        Ok(())
+    } else {
+        Err("listener was not reported as bound".to_string())
+    }
+}
+
+/// 端口为 0 时由系统分配，事件里是实际端口，只能按协议匹配。
+fn same_listener(reported: &url::Url, requested: &url::Url) -> bool {
+    reported.scheme() == requested.scheme()
+        && (requested.port().unwrap_or(0) == 0 || reported.port() == requested.port())
+}
+
+/// 修改前的配置副本；快照是共享的，修改前要先复制一份，重启失败时用它恢复。
+fn result(url: &str, outcome: EndpointOutcomeC) -> EndpointResultC {
+    EndpointResultC {
+        url: redact::url(url),
+        outcome,
+        error: String::new(),
+    }
+}
@@ -0,0 +1,984 @@
+// Peer-to-peer file transfer on top of app RPC.
+//
+// Control messages are small JSON objects sent as `Call`s on the reserved
+// channel [`FILE_CHANNEL`]:
+//
+//   offer  { id, name, size, sha256 }   sender -> receiver
+//   get    { id, offset, len }          receiver -> sender, reply = file bytes
+//   done   { id }                       receiver -> sender
+//   reject { id, reason }               receiver -> sender
+//   cancel { id }                       either way
+//
+// The receiver pulls chunks, so resuming is just pulling from the end of the
+// partial file: data lands in `.<sha256 prefix>.part` inside the target
+// directory (offers whose sha256 is not 64 hex digits are refused, so the
+// name can never leave the directory), and accepting the same transfer again (or a new offer of the same
+// file) continues where it stopped. The whole file is checked against the
+// offered SHA-256 before it is renamed into place.
+//
+// Several instances in one process work independently (everything is keyed
+// by instance id), so two local instances can exchange files with each other.
+
+use std::collections::HashMap;
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use easytier::peers::astral_app_rpc as app_rpc;
+use lazy_static::lazy_static;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use tokio::fs::{self, File, OpenOptions};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use tokio::sync::broadcast;
+
+use super::app_router::subscribe_inbound;
+use super::p2p::{
+    app_rpc_status, lookup_app_rpc, runtime, tracing_log_lagged, AppInboundEventC, AppInboundKindC,
+};
+use crate::frb_generated::StreamSink;
+
+/// Reserved channel carrying transfer control messages and chunks.
+pub(crate) const FILE_CHANNEL: &str = "astral.file";
+
+const CHUNK_SIZE: u64 = 64 * 1024;
+const CALL_TIMEOUT_MS: i32 = 15_000;
+/// Application status (positive, see `app_call_reply`) for an unknown or
+/// already closed transfer id.
+const TRANSFER_NOT_FOUND: i32 = 404;
+
+lazy_static! {
+    static ref OUTGOING: Mutex<HashMap<(String, String), Outgoing>> = Mutex::new(HashMap::new());
+    static ref INCOMING: Mutex<HashMap<(String, String), FileOfferC>> = Mutex::new(HashMap::new());
+    /// Generation of the running control handler per instance.
+    static ref HANDLERS: Mutex<HashMap<String, u64>> = Mutex::new(HashMap::new());
+    static ref OFFERS: broadcast::Sender<(String, FileOfferC)> = broadcast::channel(64).0;
+    static ref PROGRESS: broadcast::Sender<(String, FileTransferProgressC)> =
+        broadcast::channel(256).0;
+}
+
+static GENERATION: AtomicU64 = AtomicU64::new(1);
+
+struct Outgoing {
+    dst_peer_id: u32,
+    path: PathBuf,
+    name: String,
+    size: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct FileOfferC {
+    pub from_peer_id: u32,
+    pub transfer_id: String,
+    pub name: String,
+    pub size: u64,
+    /// Hex SHA-256 of the whole file.
+    pub sha256: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileTransferDirectionC {
+    Sending,
+    Receiving,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileTransferStateC {
+    Offered,
+    Transferring,
+    Completed,
+    Rejected,
+    Cancelled,
+    /// Interrupted or failed verification; a receiver can accept again to
+    /// resume.
+    Failed,
+}
+
+#[derive(Debug, Clone)]
+pub struct FileTransferProgressC {
+    pub transfer_id: String,
+    /// The other side of the transfer.
+    pub peer_id: u32,
+    pub direction: FileTransferDirectionC,
+    pub state: FileTransferStateC,
+    pub name: String,
+    pub bytes: u64,
+    pub size: u64,
+    pub error: String,
+}
+
+/// Offer a local file to `dst_peer_id`. Hashes the file first, so this takes
+/// a while for large files. Returns the transfer id once the peer has seen
+/// the offer; whether it accepts shows up in the progress stream.
+pub async fn offer_file(
+    instance_id: String,
+    dst_peer_id: u32,
+    path: String,
+) -> Result<String, String> {
+    let svc = lookup_app_rpc(&instance_id)?;
+    let path = PathBuf::from(path);
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .ok_or_else(|| format!("not a file path: {}", path.display()))?;
+    let size = fs::metadata(&path)
+        .await
+        .map_err(|e| format!("read file metadata failed: {}", e))?
+        .len();
+    let mut file = File::open(&path)
+        .await
+        .map_err(|e| format!("open file failed: {}", e))?;
+    let sha256 = hash_prefix(&mut file, size).await?;
+    ensure_handler(&instance_id, &svc);
+
+    let transfer_id = uuid::Uuid::new_v4().to_string();
+    OUTGOING.lock().unwrap().insert(
+        (instance_id.clone(), transfer_id.clone()),
+        Outgoing {
+            dst_peer_id,
+            path,
+            name: name.clone(),
+            size,
+        },
+    );
+    let offer = json!({
+        "op": "offer",
+        "id": transfer_id,
+        "name": name,
+        "size": size,
+        "sha256": sha256,
+    });
+    // Awaited on the FRB executor like `app_call`; see the note there.
+    if let Err(e) = control_call(&svc, dst_peer_id, &offer).await {
+        OUTGOING
+            .lock()
+            .unwrap()
+            .remove(&(instance_id.clone(), transfer_id.clone()));
+        return Err(format!("send offer failed: {}", e));
+    }
+    emit(
+        &instance_id,
+        FileTransferProgressC {
+            transfer_id: transfer_id.clone(),
+            peer_id: dst_peer_id,
+            direction: FileTransferDirectionC::Sending,
+            state: FileTransferStateC::Offered,
+            name,
+            bytes: 0,
+            size,
+            error: String::new(),
+        },
+    );
+    Ok(transfer_id)
+}
+
+/// Receive file offers. Offers only arrive while at least one subscription is
+/// open (or a transfer was offered from this instance); earlier offers that are
+/// still pending can be listed with [`list_file_offers`].
+pub async fn subscribe_file_offers(
+    instance_id: String,
+    sink: StreamSink<FileOfferC>,
+) -> Result<(), String> {
+    let svc = lookup_app_rpc(&instance_id)?;
+    let mut offers = OFFERS.subscribe();
+    ensure_handler(&instance_id, &svc);
+    drop(svc);
+    loop {
+        match offers.recv().await {
+            Ok((id, offer)) => {
+                if id == instance_id && sink.add(offer).is_err() {
+                    break;
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                tracing_log_lagged(&instance_id, skipped);
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+    Ok(())
+}
+
+/// Offers received and not yet completed, rejected or cancelled.
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_file_offers(instance_id: String) -> Vec<FileOfferC> {
+    INCOMING
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|((id, _), _)| *id == instance_id)
+        .map(|(_, offer)| offer.clone())
+        .collect()
+}
+
+/// Accept an offer and download it into `dir`. Resolves with the final path
+/// once the file is complete and verified. If the transfer is interrupted the
+/// partial data is kept; calling this again resumes it.
+pub async fn accept_file(
+    instance_id: String,
+    transfer_id: String,
+    dir: String,
+) -> Result<String, String> {
+    let svc = lookup_app_rpc(&instance_id)?;
+    let key = (instance_id.clone(), transfer_id.clone());
+    let offer = INCOMING
+        .lock()
+        .unwrap()
+        .get(&key)
+        .cloned()
+        .ok_or_else(|| format!("file offer {} not found", transfer_id))?;
+    let dir = PathBuf::from(dir);
+    let progress = |state, bytes, error: &str| FileTransferProgressC {
+        transfer_id: transfer_id.clone(),
+        peer_id: offer.from_peer_id,
+        direction: FileTransferDirectionC::Receiving,
+        state,
+        name: offer.name.clone(),
+        bytes,
+        size: offer.size,
+        error: error.to_string(),
+    };
+
+    let result = download(&svc, &instance_id, &offer, &dir, |bytes| {
+        emit(
+            &instance_id,
+            progress(FileTransferStateC::Transferring, bytes, ""),
+        )
+    })
+    .await;
+    match result {
+        Ok(path) => {
+            INCOMING.lock().unwrap().remove(&key);
+            let done = json!({ "op": "done", "id": transfer_id });
+            let _ = control_call(&svc, offer.from_peer_id, &done).await;
+            emit(
+                &instance_id,
+                progress(FileTransferStateC::Completed, offer.size, ""),
+            );
+            Ok(path.to_string_lossy().into_owned())
+        }
+        Err((bytes, error)) => {
+            let state = if INCOMING.lock().unwrap().contains_key(&key) {
+                FileTransferStateC::Failed
+            } else {
+                FileTransferStateC::Cancelled
+            };
+            emit(&instance_id, progress(state, bytes, &error));
+            Err(error)
+        }
+    }
+}
+
+/// Decline an offer.
+pub async fn reject_file(
+    instance_id: String,
+    transfer_id: String,
+    reason: String,
+) -> Result<(), String> {
+    let svc = lookup_app_rpc(&instance_id)?;
+    let offer = INCOMING
+        .lock()
+        .unwrap()
+        .remove(&(instance_id.clone(), transfer_id.clone()))
+        .ok_or_else(|| format!("file offer {} not found", transfer_id))?;
+    let reject = json!({ "op": "reject", "id": transfer_id, "reason": reason });
+    let _ = control_call(&svc, offer.from_peer_id, &reject).await;
+    emit(
+        &instance_id,
+        FileTransferProgressC {
+            transfer_id,
+            peer_id: offer.from_peer_id,
+            direction: FileTransferDirectionC::Receiving,
+            state: FileTransferStateC::Rejected,
+            name: offer.name,
+            bytes: 0,
+            size: offer.size,
+            error: reason,
+        },
+    );
+    Ok(())
+}
+
+/// Cancel a transfer from either side. A cancelled download keeps its partial
+/// file, so a new offer of the same file resumes it.
+pub async fn cancel_file_transfer(instance_id: String, transfer_id: String) -> Result<(), String> {
+    let svc = lookup_app_rpc(&instance_id)?;
+    let key = (instance_id.clone(), transfer_id.clone());
+    let cancel = json!({ "op": "cancel", "id": transfer_id });
+    let outgoing = OUTGOING.lock().unwrap().remove(&key);
+    if let Some(outgoing) = outgoing {
+        let _ = control_call(&svc, outgoing.dst_peer_id, &cancel).await;
+        emit(
+            &instance_id,
+            FileTransferProgressC {
+                transfer_id,
+                peer_id: outgoing.dst_peer_id,
+                direction: FileTransferDirectionC::Sending,
+                state: FileTransferStateC::Cancelled,
+                name: outgoing.name,
+                bytes: 0,
+                size: outgoing.size,
+                error: String::new(),
+            },
+        );
+        return Ok(());
+    }
+    // A running `accept_file` notices the missing offer before its next chunk
+    // and reports the cancellation itself.
+    let incoming = INCOMING.lock().unwrap().remove(&key);
+    if let Some(offer) = incoming {
+        let _ = control_call(&svc, offer.from_peer_id, &cancel).await;
+        return Ok(());
+    }
+    Err(format!("file transfer {} not found", transfer_id))
+}
+
+/// Progress of every transfer on the instance, in both directions.
+pub async fn subscribe_file_transfer_progress(
+    instance_id: String,
+    sink: StreamSink<FileTransferProgressC>,
+) -> Result<(), String> {
+    let mut events = PROGRESS.subscribe();
+    loop {
+        match events.recv().await {
+            Ok((id, progress)) => {
+                if id == instance_id && sink.add(progress).is_err() {
+                    break;
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+    Ok(())
+}
+
+/// Whether the channel router must leave `channel` to this module.
+pub(crate) fn is_handled(instance_id: &str, channel: &str) -> bool {
+    channel == FILE_CHANNEL && HANDLERS.lock().unwrap().contains_key(instance_id)
+}
+
+/// Move the control handler onto the new service after the instance was
+/// restarted; the old handler's inbound stream closed with the old service.
+/// Transfers that were in flight fail and can be retried.
+pub(crate) fn watch_instance(instance_id: &str) {
+    let Ok(svc) = lookup_app_rpc(instance_id) else {
+        return;
+    };
+    if HANDLERS.lock().unwrap().remove(instance_id).is_some() {
+        ensure_handler(instance_id, &svc);
+    }
+}
+
+pub(crate) fn forget_instance(instance_id: &str) {
+    HANDLERS.lock().unwrap().remove(instance_id);
+    OUTGOING
+        .lock()
+        .unwrap()
+        .retain(|(id, _), _| id != instance_id);
+    INCOMING
+        .lock()
+        .unwrap()
+        .retain(|(id, _), _| id != instance_id);
+}
+
+/// Pull the file chunk by chunk into a `.part` file, then verify and move it
+/// into place. Errors carry the bytes received so far.
+async fn download(
+    svc: &app_rpc::AstralAppRpcService,
+    instance_id: &str,
+    offer: &FileOfferC,
+    dir: &Path,
+    on_progress: impl Fn(u64),
+) -> Result<PathBuf, (u64, String)> {
+    fs::create_dir_all(dir)
+        .await
+        .map_err(|e| (0, format!("create directory failed: {}", e)))?;
+    let part_path = part_path(dir, &offer.sha256).map_err(|e| (0, e))?;
+    let mut part = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(&part_path)
+        .await
+        .map_err(|e| (0, format!("open partial file failed: {}", e)))?;
+
+    // Resume from the last whole chunk already on disk.
+    let existing = part
+        .metadata()
+        .await
+        .map_err(|e| (0, format!("read partial file failed: {}", e)))?
+        .len();
+    let mut offset = existing - existing % CHUNK_SIZE;
+    if offset > offer.size {
+        offset = 0;
+    }
+    part.set_len(offset)
+        .await
+        .map_err(|e| (0, format!("truncate partial file failed: {}", e)))?;
+    let mut hasher = Sha256::new();
+    hash_into(&mut part, offset, &mut hasher)
+        .await
+        .map_err(|e| (0, e))?;
+    part.seek(SeekFrom::Start(offset))
+        .await
+        .map_err(|e| (0, format!("seek partial file failed: {}", e)))?;
+    on_progress(offset);
+
+    let key = (instance_id.to_string(), offer.transfer_id.clone());
+    while offset < offer.size {
+        if !INCOMING.lock().unwrap().contains_key(&key) {
+            return Err((offset, "transfer cancelled".to_string()));
+        }
+        let len = CHUNK_SIZE.min(offer.size - offset);
+        let get = json!({ "op": "get", "id": offer.transfer_id, "offset": offset, "len": len });
+        let chunk = control_call(svc, offer.from_peer_id, &get)
+            .await
+            .map_err(|e| (offset, format!("fetch chunk failed: {}", e)))?;
+        if chunk.is_empty() || chunk.len() as u64 > len {
+            return Err((offset, "sender returned a bad chunk".to_string()));
+        }
+        part.write_all(&chunk)
+            .await
+            .map_err(|e| (offset, format!("write partial file failed: {}", e)))?;
+        hasher.update(&chunk);
+        offset += chunk.len() as u64;
+        on_progress(offset);
+    }
+    part.flush()
+        .await
+        .map_err(|e| (offset, format!("write partial file failed: {}", e)))?;
+    drop(part);
+
+    if hex::encode(hasher.finalize()) != offer.sha256 {
+        let _ = fs::remove_file(&part_path).await;
+        return Err((
+            0,
+            "sha256 mismatch; the partial file was discarded".to_string(),
+        ));
+    }
+    let target = unique_path(dir, &offer.name).await;
+    fs::rename(&part_path, &target)
+        .await
+        .map_err(|e| (offset, format!("move file into place failed: {}", e)))?;
+    Ok(target)
+}
+
+fn ensure_handler(instance_id: &str, svc: &app_rpc::AstralAppRpcService) {
+    let generation = {
+        let mut handlers = HANDLERS.lock().unwrap();
+        if handlers.contains_key(instance_id) {
+            return;
+        }
+        let generation = GENERATION.fetch_add(1, Ordering::Relaxed);
+        handlers.insert(instance_id.to_string(), generation);
+        generation
+    };
+    let rx = subscribe_inbound(instance_id, svc);
+    runtime().spawn(handle_control(instance_id.to_string(), generation, rx));
+}
+
+/// Answer control messages for one instance until it stops.
+async fn handle_control(
+    instance_id: String,
+    generation: u64,
+    mut rx: broadcast::Receiver<AppInboundEventC>,
+) {
+    loop {
+        let evt = match rx.recv().await {
+            Ok(evt) => evt,
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                tracing_log_lagged(&instance_id, skipped);
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+        let AppInboundEventC {
+            kind: AppInboundKindC::Call,
+            from_peer_id,
+            channel,
+            token,
+            payload,
+            ..
+        } = evt
+        else {
+            continue;
+        };
+        if channel != FILE_CHANNEL {
+            continue;
+        }
+        if HANDLERS.lock().unwrap().get(&instance_id) != Some(&generation) {
+            return;
+        }
+        let Ok(msg) = serde_json::from_slice::<Value>(&payload) else {
+            reply(
+                &instance_id,
+                token,
+                TRANSFER_NOT_FOUND,
+                "malformed message",
+                Vec::new(),
+            );
+            continue;
+        };
+        // Chunk reads hit the disk; don't hold up other messages.
+        let instance_id = instance_id.clone();
+        runtime().spawn(async move {
+            let (status, error, payload) =
+                match handle_message(&instance_id, from_peer_id, &msg).await {
+                    Ok(payload) => (app_rpc_status::OK, String::new(), payload),
+                    Err(error) => (TRANSFER_NOT_FOUND, error, Vec::new()),
+                };
+            reply(&instance_id, token, status, &error, payload);
+        });
+    }
+
+    let mut handlers = HANDLERS.lock().unwrap();
+    if handlers.get(&instance_id) == Some(&generation) {
+        handlers.remove(&instance_id);
+    }
+}
+
+async fn handle_message(
+    instance_id: &str,
+    from_peer_id: u32,
+    msg: &Value,
+) -> Result<Vec<u8>, String> {
+    let id = msg["id"].as_str().unwrap_or_default().to_string();
+    let key = (instance_id.to_string(), id.clone());
+    match msg["op"].as_str().unwrap_or_default() {
+        "offer" => {
+            let offer = FileOfferC {
+                from_peer_id,
+                transfer_id: id,
+                name: sanitize_name(msg["name"].as_str().unwrap_or_default()),
+                size: msg["size"].as_u64().ok_or("offer without size")?,
+                sha256: msg["sha256"].as_str().unwrap_or_default().to_lowercase(),
+            };
+            if !is_sha256_hex(&offer.sha256) {
+                return Err("offer without a valid sha256".to_string());
+            }
+            {
+                let mut incoming = INCOMING.lock().unwrap();
+                // Transfer ids are picked by the sender; another peer must not
+                // replace an offer by reusing one.
+                if incoming
+                    .get(&key)
+                    .is_some_and(|o| o.from_peer_id != from_peer_id)
+                {
+                    return Err("transfer id already offered by another peer".to_string());
+                }
+                incoming.insert(key, offer.clone());
+            }
+            emit(
+                instance_id,
+                FileTransferProgressC {
+                    transfer_id: offer.transfer_id.clone(),
+                    peer_id: from_peer_id,
+                    direction: FileTransferDirectionC::Receiving,
+                    state: FileTransferStateC::Offered,
+                    name: offer.name.clone(),
+                    bytes: 0,
+                    size: offer.size,
+                    error: String::new(),
+                },
+            );
+            let _ = OFFERS.send((instance_id.to_string(), offer));
+            Ok(Vec::new())
+        }
+        "get" => {
+            let (path, name, size) = {
+                let outgoing = OUTGOING.lock().unwrap();
+                let o = outgoing
+                    .get(&key)
+                    .filter(|o| o.dst_peer_id == from_peer_id)
+                    .ok_or("transfer not found")?;
+                (o.path.clone(), o.name.clone(), o.size)
+            };
+            let offset = msg["offset"].as_u64().unwrap_or_default();
+            let len = msg["len"].as_u64().unwrap_or_default().min(CHUNK_SIZE);
+            let chunk = read_chunk(&path, offset, len).await?;
+            emit(
+                instance_id,
+                FileTransferProgressC {
+                    transfer_id: id,
+                    peer_id: from_peer_id,
+                    direction: FileTransferDirectionC::Sending,
+                    state: FileTransferStateC::Transferring,
+                    name,
+                    bytes: offset + chunk.len() as u64,
+                    size,
+                    error: String::new(),
+                },
+            );
+            Ok(chunk)
+        }
+        op @ ("done" | "reject" | "cancel") => {
+            let outgoing = {
+                let mut outgoing = OUTGOING.lock().unwrap();
+                match outgoing.get(&key) {
+                    Some(o) if o.dst_peer_id == from_peer_id => outgoing.remove(&key),
+                    _ => None,
+                }
+            };
+            if let Some(o) = outgoing {
+                let state = match op {
+                    "done" => FileTransferStateC::Completed,
+                    "reject" => FileTransferStateC::Rejected,
+                    _ => FileTransferStateC::Cancelled,
+                };
+                emit(
+                    instance_id,
+                    FileTransferProgressC {
+                        transfer_id: id,
+                        peer_id: from_peer_id,
+                        direction: FileTransferDirectionC::Sending,
+                        state,
+                        name: o.name,
+                        bytes: if state == FileTransferStateC::Completed {
+                            o.size
+                        } else {
+                            0
+                        },
+                        size: o.size,
+                        error: msg["reason"].as_str().unwrap_or_default().to_string(),
+                    },
+                );
+                return Ok(Vec::new());
+            }
+            if op == "cancel" {
+                // The sender withdrew an offer we hold; a running download
+                // reports the cancellation itself.
+                let mut incoming = INCOMING.lock().unwrap();
+                if incoming
+                    .get(&key)
+                    .is_some_and(|o| o.from_peer_id == from_peer_id)
+                {
+                    incoming.remove(&key);
+                    return Ok(Vec::new());
+                }
+            }
+            Err("transfer not found".to_string())
+        }
+        op => Err(format!("unknown file transfer op: {}", op)),
+    }
+}
+
+/// Send a control message and return the reply payload.
+async fn control_call(
+    svc: &app_rpc::AstralAppRpcService,
+    dst_peer_id: u32,
+    msg: &Value,
+) -> Result<Vec<u8>, String> {
+    let resp = svc
+        .call(
+            dst_peer_id,
+            FILE_CHANNEL.to_string(),
+            0,
+            msg.to_string().into_bytes(),
+            0,
+            CALL_TIMEOUT_MS,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    if resp.status != app_rpc_status::OK {
+        return Err(format!("status {} {}", resp.status, resp.error_msg));
+    }
+    Ok(resp.payload)
+}
+
+async fn read_chunk(path: &Path, offset: u64, len: u64) -> Result<Vec<u8>, String> {
+    let mut file = File::open(path)
+        .await
+        .map_err(|e| format!("open file failed: {}", e))?;
+    file.seek(SeekFrom::Start(offset))
+        .await
+        .map_err(|e| format!("seek file failed: {}", e))?;
+    let mut chunk = Vec::with_capacity(len as usize);
+    file.take(len)
+        .read_to_end(&mut chunk)
+        .await
+        .map_err(|e| format!("read file failed: {}", e))?;
+    Ok(chunk)
+}
+
+/// Hex SHA-256 of the first `len` bytes of `file`.
+async fn hash_prefix(file: &mut File, len: u64) -> Result<String, String> {
+    let mut hasher = Sha256::new();
+    hash_into(file, len, &mut hasher).await?;
+    Ok(hex::encode(hasher.finalize()))
+}
+
+async fn hash_into(file: &mut File, len: u64, hasher: &mut Sha256) -> Result<(), String> {
+    file.seek(SeekFrom::Start(0))
+        .await
+        .map_err(|e| format!("seek file failed: {}", e))?;
+    let mut remaining = len;
+    let mut buf = vec![0u8; 256 * 1024];
+    while remaining > 0 {
+        let want = buf.len().min(remaining as usize);
+        let n = file
+            .read(&mut buf[..want])
+            .await
+            .map_err(|e| format!("read file failed: {}", e))?;
+        if n == 0 {
+            return Err("file is shorter than expected".to_string());
+        }
+        hasher.update(&buf[..n]);
+        remaining -= n as u64;
+    }
+    Ok(())
+}
+
+/// 64 ASCII hex digits, as produced by [`hash_prefix`].
+fn is_sha256_hex(sha256: &str) -> bool {
+    sha256.len() == 64 && sha256.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// `dir/.<first 16 hex digits>.part`. Offers are validated on arrival; this
+/// checks again so a bad hash can never name a file outside `dir`.
+fn part_path(dir: &Path, sha256: &str) -> Result<PathBuf, String> {
+    if !is_sha256_hex(sha256) {
+        return Err("offer has an invalid sha256".to_string());
+    }
+    let path = dir.join(format!(".{}.part", &sha256[..16]));
+    if path.parent() != Some(dir) {
+        return Err("partial file would leave the target directory".to_string());
+    }
+    Ok(path)
+}
+
+/// Only the final path component, so an offer cannot write outside `dir`.
+fn sanitize_name(name: &str) -> String {
+    Path::new(name)
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .filter(|n| !n.starts_with('.'))
+        .unwrap_or_else(|| "file".to_string())
+}
+
+/// `dir/name`, or `dir/stem (n).ext` if that already exists.
+async fn unique_path(dir: &Path, name: &str) -> PathBuf {
+    let candidate = dir.join(name);
+    if fs::metadata(&candidate).await.is_err() {
+        return candidate;
+    }
+    let path = Path::new(name);
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let ext = path
+        .extension()
+        .map(|e| format!(".{}", e.to_string_lossy()))
+        .unwrap_or_default();
+    let mut n = 1;
+    loop {
+        let candidate = dir.join(format!("{} ({}){}", stem, n, ext));
+        if fs::metadata(&candidate).await.is_err() {
+            return candidate;
+        }
+        n += 1;
+    }
+}
+
+fn reply(instance_id: &str, token: u64, status: i32, error_msg: &str, payload: Vec<u8>) {
+    if let Ok(svc) = lookup_app_rpc(instance_id) {
+        svc.reply_call(token, status, error_msg.to_string(), payload);
+    }
+}
+
+fn emit(instance_id: &str, progress: FileTransferProgressC) {
+    let _ = PROGRESS.send((instance_id.to_string(), progress));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::api::p2p::{
+        close_server, create_server, get_instance_info, get_peer_route_pairs, join_handle_result,
+    };
+
+    #[test]
+    fn offer_requires_hex_sha256() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        for sha256 in [
+            "",
+            "../../../../../../../../../../../../../../../../../../../etc/passwd",
+            "é23456789abcdef0123456789abcdef0123456789abcdef0123456789abcde",
+            &"g".repeat(64),
+        ] {
+            let msg =
+                json!({ "op": "offer", "id": "t", "name": "a.txt", "size": 1, "sha256": sha256 });
+            let result = rt.block_on(handle_message("offer-test", 1, &msg));
+            assert!(result.is_err(), "accepted sha256 {:?}", sha256);
+        }
+        assert!(INCOMING
+            .lock()
+            .unwrap()
+            .keys()
+            .all(|(id, _)| id != "offer-test"));
+    }
+
+    #[test]
+    fn offer_cannot_replace_another_peers_offer() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let sha256 = "0123456789abcdef".repeat(4);
+        let msg = json!({ "op": "offer", "id": "t", "name": "a.txt", "size": 1, "sha256": sha256 });
+        rt.block_on(handle_message("offer-collision-test", 1, &msg))
+            .unwrap();
+        assert!(rt
+            .block_on(handle_message("offer-collision-test", 2, &msg))
+            .is_err());
+        // The same peer may repeat its own offer.
+        rt.block_on(handle_message("offer-collision-test", 1, &msg))
+            .unwrap();
+        let key = ("offer-collision-test".to_string(), "t".to_string());
+        assert_eq!(INCOMING.lock().unwrap()[&key].from_peer_id, 1);
+    }
+
+    #[test]
+    fn part_path_stays_in_dir() {
+        let dir = Path::new("/tmp/downloads");
+        let sha256 = "0123456789abcdef".repeat(4);
+        assert_eq!(
+            part_path(dir, &sha256).unwrap(),
+            dir.join(".0123456789abcdef.part")
+        );
+        assert!(part_path(
+            dir,
+            "../../0123456789abcdef0123456789abcdef0123456789abcdef01234567"
+        )
+        .is_err());
+    }
+
+    fn config(name: &str, peer_port: Option<u16>) -> String {
+        let peer = peer_port
+            .map(|p| format!("[[peer]]\nuri = \"tcp://127.0.0.1:{}\"\n", p))
+            .unwrap_or_default();
+        format!(
+            "instance_name = \"{name}\"\nhostname = \"{name}\"\nlisteners = [\"tcp://127.0.0.1:0\"]\n\n\
+             [network_identity]\nnetwork_name = \"file-transfer-test\"\nnetwork_secret = \"file-transfer-test\"\n\n\
+             {peer}\n[flags]\nno_tun = true\n"
+        )
+    }
+
+    /// The port the system picked for the instance's tcp listener.
+    async fn bound_tcp_port(instance_id: &str) -> u16 {
+        for _ in 0..300 {
+            let port = get_instance_info(instance_id)
+                .await
+                .ok()
+                .and_then(|info| info.my_node_info)
+                .into_iter()
+                .flat_map(|node| node.listeners)
+                .filter_map(|u| u.url.parse::<url::Url>().ok())
+                .filter(|u| u.scheme() == "tcp")
+                .find_map(|u| u.port().filter(|p| *p != 0));
+            if let Some(port) = port {
+                return port;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        panic!("listener did not bind");
+    }
+
+    async fn wait_for_peer(instance_id: &str, peer_instance_id: &str) -> u32 {
+        for _ in 0..300 {
+            let route = get_peer_route_pairs(instance_id.to_string())
+                .await
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|p| p.route)
+                .find(|r| r.inst_id == peer_instance_id);
+            if let Some(route) = route {
+                return route.peer_id;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        panic!("instances did not connect");
+    }
+
+    /// Two instances in this process: the sender offers a file, the receiver
+    /// already holds the first chunk in its `.part` file, and the download
+    /// resumes from there and verifies the whole file.
+    #[tokio::test(flavor = "multi_thread")]
+    async fn loopback_send_resume_verify() {
+        let sender = join_handle_result(create_server(config("ft-sender", None), false))
+            .await
+            .unwrap();
+        let sender_port = bound_tcp_port(&sender).await;
+        let receiver = join_handle_result(create_server(
+            config("ft-receiver", Some(sender_port)),
+            false,
+        ))
+        .await
+        .unwrap();
+        let receiver_peer_id = wait_for_peer(&sender, &receiver).await;
+        let receiver_svc = lookup_app_rpc(&receiver).unwrap();
+        ensure_handler(&receiver, &receiver_svc);
+
+        let root = std::env::temp_dir().join(format!("astral-ft-{}", uuid::Uuid::new_v4()));
+        let src = root.join("src").join("payload.bin");
+        let dst = root.join("dst");
+        fs::create_dir_all(src.parent().unwrap()).await.unwrap();
+        fs::create_dir_all(&dst).await.unwrap();
+        let data: Vec<u8> = (0..CHUNK_SIZE * 3 + 1234)
+            .map(|i| (i % 251) as u8)
+            .collect();
+        fs::write(&src, &data).await.unwrap();
+        let sha256 = hex::encode(Sha256::digest(&data));
+        fs::write(
+            part_path(&dst, &sha256).unwrap(),
+            &data[..CHUNK_SIZE as usize],
+        )
+        .await
+        .unwrap();
+
+        let mut progress = PROGRESS.subscribe();
+        let transfer_id = offer_file(
+            sender.clone(),
+            receiver_peer_id,
+            src.to_string_lossy().into_owned(),
+        )
+        .await
+        .unwrap();
+        let offer = list_file_offers(receiver.clone())
+            .into_iter()
+            .find(|o| o.transfer_id == transfer_id)
+            .expect("receiver has the offer");
+        assert_eq!(offer.sha256, sha256);
+
+        let path = accept_file(
+            receiver.clone(),
+            transfer_id.clone(),
+            dst.to_string_lossy().into_owned(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(fs::read(&path).await.unwrap(), data);
+        assert!(fs::metadata(part_path(&dst, &sha256).unwrap())
+            .await
+            .is_err());
+
+        // The first receiving progress starts at the chunk already on disk.
+        let resumed_from = loop {
+            let (id, p) = progress.recv().await.unwrap();
+            if id == receiver
+                && p.transfer_id == transfer_id
+                && p.state == FileTransferStateC::Transferring
+            {
+                break p.bytes;
+            }
+        };
+        assert_eq!(resumed_from, CHUNK_SIZE);
+
+        let _ = fs::remove_dir_all(&root).await;
+        close_server(receiver).unwrap();
+        close_server(sender).unwrap();
+    }
+}
@@ -1,65 +1,637 @@
+#[cfg(target_os = "linux")]
+pub(crate) mod linux;
+#[cfg(target_os = "linux")]
+use linux::{LinuxFirewall, SystemCommandRunner};
 #[cfg(target_os = "windows")]
-use windows::{
-    core::{Result, HRESULT, Error},
-    Win32::Foundation::VARIANT_BOOL,
-    Win32::{
-        NetworkManagement::WindowsFirewall::{
-            INetFwPolicy2, NetFwPolicy2, NET_FW_PROFILE2_DOMAIN, NET_FW_PROFILE2_PRIVATE,
-            NET_FW_PROFILE2_PUBLIC,
-        },
-        System::Com::{CoCreateInstance, CoInitializeEx, CLSCTX_INPROC_SERVER, COINIT_APARTMENTTHREADED},
-    },
-};
+pub(crate) mod win32;
 
-#[cfg(target_os = "windows")]
-pub fn get_firewall_status(profile_index: u32) -> Result<bool> {
-    unsafe {
-        // CoInitializeEx 可能返回 S_FALSE (0x00000001) 表示已初始化，这是正常的
-        // RPC_E_CHANGED_MODE (0x80010106) 表示以不同模式初始化，也可以忽略
-        let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
-
-        let policy: INetFwPolicy2 = CoCreateInstance(&NetFwPolicy2, None, CLSCTX_INPROC_SERVER)?;
-
-        let profile_type = match profile_index {
-            1 => NET_FW_PROFILE2_DOMAIN,
-            2 => NET_FW_PROFILE2_PRIVATE,
-            3 => NET_FW_PROFILE2_PUBLIC,
-            _ => return Err(Error::new(HRESULT(-1), "Invalid profile index".into())),
-        };
+use std::net::Ipv4Addr;
+
+use cidr::Ipv4Inet;
+use easytier::common::config::ConfigLoader;
+
+use super::p2p::{get_instance_info, instance_config};
+
+/// 当前生效的防火墙实现。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FirewallBackendC {
+    None,
+    Firewalld,
+    Ufw,
+    Nftables,
+    Iptables,
+    WindowsFirewall,
+    /// 检测命令因权限不足失败，无法判断有没有防火墙在拦截（Linux 非 root 运行时）。
+    Unknown,
+}
+
+#[derive(Debug, Clone)]
+pub struct FirewallPortC {
+    /// `tcp` / `udp`
+    pub proto: String,
+    pub port: u16,
+}
 
-        // 正确调用 COM 接口方法
-        let enabled = policy.get_FirewallEnabled(profile_type)?;
-        Ok(enabled.as_bool())
+#[derive(Debug, Clone)]
+pub struct FirewallPortStatusC {
+    pub proto: String,
+    pub port: u16,
+    pub allowed: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct TunFirewallStatusC {
+    pub backend: FirewallBackendC,
+    /// 防火墙是否在拦截入站流量；为 `false` 时下面的放行状态都视为 `true`。
+    pub active: bool,
+    pub tun_allowed: bool,
+    pub ports: Vec<FirewallPortStatusC>,
+    /// 补充信息，例如 firewalld 的 zone，或读取规则失败的原因。
+    pub detail: String,
+}
+
+/// Windows 防火墙的网络配置文件。Linux 没有 profile 的概念，三个 profile 都返回同一个状态。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FirewallProfile {
+    Domain,
+    Private,
+    Public,
+}
+
+impl FirewallProfile {
+    pub(crate) const ALL: [FirewallProfile; 3] = [
+        FirewallProfile::Domain,
+        FirewallProfile::Private,
+        FirewallProfile::Public,
+    ];
+
+    /// 兼容旧接口的编号：1=domain，2=private，3=public。
+    pub(crate) fn from_index(profile_index: u32) -> Result<Self, FirewallError> {
+        match profile_index {
+            1 => Ok(FirewallProfile::Domain),
+            2 => Ok(FirewallProfile::Private),
+            3 => Ok(FirewallProfile::Public),
+            _ => Err(FirewallError::invalid_profile(profile_index)),
+        }
     }
 }
-/// 不是window就返回false
-#[cfg(not(target_os = "windows"))]
-pub fn get_firewall_status(_profile_index: u32) -> Result<bool, std::io::Error> {
+
+/// 防火墙错误的类别。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FirewallErrorKind {
+    /// 旧接口传入了 1..=3 以外的 profile 编号。
+    InvalidProfile,
+    /// 当前平台不支持该操作。
+    Unsupported,
+    /// 实例不存在或配置读取失败。
+    Instance,
+    /// 系统防火墙调用失败（COM 错误、命令执行失败、权限不足等）。
+    Backend,
+    /// 没有权限读取防火墙规则，状态未知。
+    NeedsPrivileges,
+}
+
+/// 防火墙接口统一的错误类型，各平台的底层错误都转成这个。
+///
+/// 用 `kind` + `message` 的扁平结构而不是带数据的 enum，Dart 侧生成的是普通 class，
+/// 不需要引入 freezed。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FirewallError {
+    pub kind: FirewallErrorKind,
+    pub message: String,
+}
+
+impl FirewallError {
+    pub(crate) fn invalid_profile(index: u32) -> Self {
+        Self {
+            kind: FirewallErrorKind::InvalidProfile,
+            message: format!("invalid profile index: {}", index),
+        }
+    }
+
+    pub(crate) fn unsupported(what: &str) -> Self {
+        Self {
+            kind: FirewallErrorKind::Unsupported,
+            message: format!("{} is not supported on this platform", what),
+        }
+    }
+
+    pub(crate) fn instance(e: impl std::fmt::Display) -> Self {
+        Self {
+            kind: FirewallErrorKind::Instance,
+            message: format!("instance error: {}", e),
+        }
+    }
+
+    pub(crate) fn backend(e: impl std::fmt::Display) -> Self {
+        Self {
+            kind: FirewallErrorKind::Backend,
+            message: format!("firewall backend error: {}", e),
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    pub(crate) fn needs_privileges() -> Self {
+        Self {
+            kind: FirewallErrorKind::NeedsPrivileges,
+            message: linux::NEEDS_ROOT.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for FirewallError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for FirewallError {}
+
+#[cfg(target_os = "windows")]
+impl From<windows::core::Error> for FirewallError {
+    fn from(e: windows::core::Error) -> Self {
+        FirewallError::backend(e)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FirewallProfileStatusC {
+    pub profile: FirewallProfile,
+    pub enabled: bool,
+    /// 该 profile 是当前网络正在使用的。
+    pub current: bool,
+}
+
+/// 所有 profile 的开关状态，以及当前网络使用的 profile。
+#[derive(Debug, Clone)]
+pub struct FirewallSnapshotC {
+    pub backend: FirewallBackendC,
+    pub profiles: Vec<FirewallProfileStatusC>,
+    /// Windows 上多块网卡可能同时处于不同 profile；Linux 上为空。
+    pub current_profiles: Vec<FirewallProfile>,
+}
+
+/// 旧接口，`profile_index` 含义见 [`FirewallProfile::from_index`]。
+/// 新代码用 [`get_firewall_profile_status`] 或 [`get_firewall_snapshot`]。
+pub fn get_firewall_status(profile_index: u32) -> Result<bool, FirewallError> {
+    get_firewall_profile_status(FirewallProfile::from_index(profile_index)?)
+}
+
+/// 整体开关某个 profile 的防火墙。影响整机所有程序，只应作为最后手段：
+/// 优先用 [`allow_instance_through_firewall`] 只放行虚拟网和监听端口。
+pub fn set_firewall_status(profile_index: u32, enable: bool) -> Result<(), FirewallError> {
+    set_firewall_profile_status(FirewallProfile::from_index(profile_index)?, enable)
+}
+
+#[cfg(target_os = "windows")]
+pub fn get_firewall_profile_status(profile: FirewallProfile) -> Result<bool, FirewallError> {
+    Ok(win32::WindowsFirewall::new()?.is_enabled(profile)?)
+}
+
+/// Linux 没有 profile 的概念：任一后端（firewalld / ufw / nftables / iptables）
+/// 在拦截入站流量就视为开启。没有权限读取规则时返回
+/// [`FirewallErrorKind::NeedsPrivileges`]。
+#[cfg(target_os = "linux")]
+pub fn get_firewall_profile_status(_profile: FirewallProfile) -> Result<bool, FirewallError> {
+    LinuxFirewall::new(SystemCommandRunner)
+        .is_active()
+        .ok_or_else(FirewallError::needs_privileges)
+}
+
+/// 其他平台没有实现，返回false
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+pub fn get_firewall_profile_status(_profile: FirewallProfile) -> Result<bool, FirewallError> {
     Ok(false)
 }
+
+/// 见 [`set_firewall_status`]，同样只应作为最后手段。
 #[cfg(target_os = "windows")]
-pub fn set_firewall_status(profile_index: u32, enable: bool) -> Result<()> {
-    unsafe {
-        // CoInitializeEx 可能返回 S_FALSE (0x00000001) 表示已初始化，这是正常的
-        // RPC_E_CHANGED_MODE (0x80010106) 表示以不同模式初始化，也可以忽略
-        let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
-
-        let policy: INetFwPolicy2 = CoCreateInstance(&NetFwPolicy2, None, CLSCTX_INPROC_SERVER)?;
-
-        let profile_type = match profile_index {
-            1 => NET_FW_PROFILE2_DOMAIN,
-            2 => NET_FW_PROFILE2_PRIVATE,
-            3 => NET_FW_PROFILE2_PUBLIC,
-            _ => return Err(Error::new(HRESULT(-1), "Invalid profile index".into())),
-        };
+pub fn set_firewall_profile_status(
+    profile: FirewallProfile,
+    enable: bool,
+) -> Result<(), FirewallError> {
+    Ok(win32::WindowsFirewall::new()?.set_enabled(profile, enable)?)
+}
 
-        policy.put_FirewallEnabled(profile_type, VARIANT_BOOL::from(enable))?;
-        Ok(())
+/// 非 Windows 不支持整体开关防火墙，调用是空操作；Linux 上用
+/// [`allow_instance_through_firewall`] 只放行需要的流量。
+#[cfg(not(target_os = "windows"))]
+pub fn set_firewall_profile_status(
+    _profile: FirewallProfile,
+    _enable: bool,
+) -> Result<(), FirewallError> {
+    Ok(())
+}
+
+/// 一次读出所有 profile 的状态。
+#[cfg(target_os = "windows")]
+pub fn get_firewall_snapshot() -> Result<FirewallSnapshotC, FirewallError> {
+    let fw = win32::WindowsFirewall::new()?;
+    let current_profiles = fw.current_profiles()?;
+    let mut profiles = Vec::new();
+    for profile in FirewallProfile::ALL {
+        profiles.push(FirewallProfileStatusC {
+            profile,
+            enabled: fw.is_enabled(profile)?,
+            current: current_profiles.contains(&profile),
+        });
     }
+    Ok(FirewallSnapshotC {
+        backend: FirewallBackendC::WindowsFirewall,
+        profiles,
+        current_profiles,
+    })
 }
 
-/// 不是window就返回false
 #[cfg(not(target_os = "windows"))]
-pub fn set_firewall_status(_profile_index: u32, _enable: bool) -> Result<(), std::io::Error> {
-    Ok(())
+pub fn get_firewall_snapshot() -> Result<FirewallSnapshotC, FirewallError> {
+    #[cfg(target_os = "linux")]
+    let backend = LinuxFirewall::new(SystemCommandRunner).detect_backend();
+    #[cfg(not(target_os = "linux"))]
+    let backend = FirewallBackendC::None;
+
+    // 状态未知时 backend 已经是 Unknown，enabled 按未开启报。
+    let enabled = match get_firewall_profile_status(FirewallProfile::Public) {
+        Err(e) if e.kind == FirewallErrorKind::NeedsPrivileges => false,
+        result => result?,
+    };
+    Ok(FirewallSnapshotC {
+        backend,
+        profiles: FirewallProfile::ALL
+            .into_iter()
+            .map(|profile| FirewallProfileStatusC {
+                profile,
+                enabled,
+                current: false,
+            })
+            .collect(),
+        current_profiles: Vec::new(),
+    })
+}
+
+/// TUN 设备和监听端口在防火墙里是否放行。
+#[cfg(target_os = "linux")]
+pub fn get_tun_firewall_status(
+    dev_name: String,
+    ports: Vec<FirewallPortC>,
+) -> Result<TunFirewallStatusC, FirewallError> {
+    Ok(LinuxFirewall::new(SystemCommandRunner).tun_status(&dev_name, &ports))
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn get_tun_firewall_status(
+    _dev_name: String,
+    _ports: Vec<FirewallPortC>,
+) -> Result<TunFirewallStatusC, FirewallError> {
+    Err(FirewallError::unsupported("tun firewall status"))
+}
+
+/// 添加只针对 TUN 设备的放行规则（需要 root）。
+#[cfg(target_os = "linux")]
+pub fn allow_tun_through_firewall(dev_name: String) -> Result<(), FirewallError> {
+    LinuxFirewall::new(SystemCommandRunner)
+        .allow_tun(&dev_name)
+        .map_err(FirewallError::backend)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn allow_tun_through_firewall(_dev_name: String) -> Result<(), FirewallError> {
+    Err(FirewallError::unsupported("tun firewall rule"))
+}
+
+/// 删除 [`allow_tun_through_firewall`] 添加的规则。
+#[cfg(target_os = "linux")]
+pub fn remove_tun_firewall_rule(dev_name: String) -> Result<(), FirewallError> {
+    LinuxFirewall::new(SystemCommandRunner)
+        .remove_tun(&dev_name)
+        .map_err(FirewallError::backend)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn remove_tun_firewall_rule(_dev_name: String) -> Result<(), FirewallError> {
+    Err(FirewallError::unsupported("tun firewall rule"))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FirewallRuleKindC {
+    /// 虚拟网流量：Linux 上按 TUN 设备放行，Windows 上按虚拟网网段放行。
+    Overlay,
+    /// 某个监听端口。
+    ListenerPort,
+}
+
+/// 一条 Astral 管理的放行规则。
+#[derive(Debug, Clone)]
+pub struct FirewallRuleC {
+    pub kind: FirewallRuleKindC,
+    /// 规则名；Windows 防火墙界面里显示的就是这个名字。
+    pub name: String,
+    /// `ListenerPort` 时为 `tcp` / `udp`，否则为空串。
+    pub proto: String,
+    /// `ListenerPort` 时为端口号，否则为 0。
+    pub port: u16,
+    /// `Overlay` 时为 TUN 设备名 / 虚拟网网段。
+    pub detail: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct FirewallFindingC {
+    pub rule: FirewallRuleC,
+    /// 该流量当前会被拦截。
+    pub blocked: bool,
+    /// 拦截它的规则或策略，例如 `iptables -P INPUT DROP`；Windows 上命中多条 block
+    /// 规则时是逗号分隔的规则名。未拦截时为空串。
+    pub blocking_rule: String,
+    /// 给用户看的修复建议；未拦截时为空串。
+    pub suggestion: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct FirewallDiagnosisC {
+    pub backend: FirewallBackendC,
+    pub active: bool,
+    pub findings: Vec<FirewallFindingC>,
+}
+
+/// 放行的目标。规则名由目标推出，增删时不需要额外记录状态。
+pub(crate) enum RuleTarget {
+    Overlay { dev_name: String, cidr: String },
+    Port { proto: String, port: u16 },
+}
+
+impl RuleTarget {
+    pub(crate) fn rule_name(&self) -> String {
+        match self {
+            RuleTarget::Overlay { cidr, .. } => format!("Astral overlay {}", cidr),
+            RuleTarget::Port { proto, port } => format!("Astral listener {}/{}", port, proto),
+        }
+    }
+
+    pub(crate) fn describe(&self) -> String {
+        match self {
+            RuleTarget::Overlay { dev_name, cidr } => {
+                format!("Astral virtual network {} on {}", cidr, dev_name)
+            }
+            RuleTarget::Port { proto, port } => format!("Astral listener {} port {}", proto, port),
+        }
+    }
+
+    fn to_c(&self) -> FirewallRuleC {
+        match self {
+            RuleTarget::Overlay { dev_name, cidr } => FirewallRuleC {
+                kind: FirewallRuleKindC::Overlay,
+                name: self.rule_name(),
+                proto: String::new(),
+                port: 0,
+                detail: format!("{} {}", dev_name, cidr),
+            },
+            RuleTarget::Port { proto, port } => FirewallRuleC {
+                kind: FirewallRuleKindC::ListenerPort,
+                name: self.rule_name(),
+                proto: proto.clone(),
+                port: *port,
+                detail: String::new(),
+            },
+        }
+    }
+}
+
+/// 为实例添加精确放行规则：虚拟网流量 + 每个监听端口。需要管理员 / root 权限。
+pub async fn allow_instance_through_firewall(
+    instance_id: String,
+) -> Result<Vec<FirewallRuleC>, FirewallError> {
+    let targets = instance_targets(&instance_id).await?;
+    blocking(move || {
+        for target in &targets {
+            add_rule(target)?;
+        }
+        Ok(targets.iter().map(RuleTarget::to_c).collect())
+    })
+    .await
+}
+
+/// 删除 [`allow_instance_through_firewall`] 添加的规则。
+pub async fn remove_instance_firewall_rules(instance_id: String) -> Result<(), FirewallError> {
+    let targets = instance_targets(&instance_id).await?;
+    blocking(move || targets.iter().try_for_each(remove_rule)).await
+}
+
+/// 检查虚拟网和各监听端口的入站流量是否会被防火墙拦截，以及拦截它的规则。
+pub async fn diagnose_firewall(instance_id: String) -> Result<FirewallDiagnosisC, FirewallError> {
+    let targets = instance_targets(&instance_id).await?;
+    blocking(move || diagnose(&targets)).await
+}
+
+/// 防火墙操作会执行外部命令 / 调 COM，放到阻塞线程池里，不占 async 执行器。
+async fn blocking<T, F>(f: F) -> Result<T, FirewallError>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, FirewallError> + Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| FirewallError::backend(format!("firewall task failed: {}", e)))?
+}
+
+async fn instance_targets(instance_id: &str) -> Result<Vec<RuleTarget>, FirewallError> {
+    let cfg = instance_config(instance_id).map_err(FirewallError::instance)?;
+    let flags = cfg.get_flags();
+    let info = get_instance_info(instance_id).await.ok();
+    let mut targets = Vec::new();
+
+    if !flags.no_tun {
+        let dev_name = info
+            .as_ref()
+            .map(|i| i.dev_name.clone())
+            .filter(|d| !d.is_empty())
+            .unwrap_or(flags.dev_name);
+        // DHCP 模式下配置里没有地址，取运行时分配到的。
+        let cidr = cfg.get_ipv4().map(|inet| inet.network()).or_else(|| {
+            let addr = info.as_ref()?.my_node_info.as_ref()?.virtual_ipv4.as_ref()?;
+            let inet = Ipv4Inet::new(
+                Ipv4Addr::from(addr.address.as_ref()?.addr),
+                addr.network_length as u8,
+            )
+            .ok()?;
+            Some(inet.network())
+        });
+        if let Some(cidr) = cidr {
+            targets.push(RuleTarget::Overlay {
+                dev_name,
+                cidr: cidr.to_string(),
+            });
+        }
+    }
+
+    for listener in cfg.get_listeners().unwrap_or_default() {
+        let proto = match listener.scheme() {
+            "tcp" | "ws" | "wss" | "faketcp" => "tcp",
+            "udp" | "quic" | "wg" | "kcp" => "udp",
+            _ => continue,
+        };
+        let Some(port) = listener.port().filter(|p| *p != 0) else {
+            continue;
+        };
+        let target = RuleTarget::Port {
+            proto: proto.to_string(),
+            port,
+        };
+        if !targets.iter().any(|t| t.rule_name() == target.rule_name()) {
+            targets.push(target);
+        }
+    }
+    Ok(targets)
+}
+
+#[cfg(target_os = "linux")]
+fn add_rule(target: &RuleTarget) -> Result<(), FirewallError> {
+    let fw = LinuxFirewall::new(SystemCommandRunner);
+    let result = match target {
+        RuleTarget::Overlay { dev_name, .. } => fw.allow_tun(dev_name),
+        RuleTarget::Port { proto, port } => fw.allow_port(proto, *port),
+    };
+    result.map_err(FirewallError::backend)
+}
+
+#[cfg(target_os = "linux")]
+fn remove_rule(target: &RuleTarget) -> Result<(), FirewallError> {
+    let fw = LinuxFirewall::new(SystemCommandRunner);
+    let result = match target {
+        RuleTarget::Overlay { dev_name, .. } => fw.remove_tun(dev_name),
+        RuleTarget::Port { proto, port } => fw.remove_port(proto, *port),
+    };
+    result.map_err(FirewallError::backend)
+}
+
+#[cfg(target_os = "linux")]
+fn diagnose(targets: &[RuleTarget]) -> Result<FirewallDiagnosisC, FirewallError> {
+    let fw = LinuxFirewall::new(SystemCommandRunner);
+    let dev_name = targets
+        .iter()
+        .find_map(|t| match t {
+            RuleTarget::Overlay { dev_name, .. } => Some(dev_name.clone()),
+            _ => None,
+        })
+        .unwrap_or_default();
+    let ports: Vec<FirewallPortC> = targets
+        .iter()
+        .filter_map(|t| match t {
+            RuleTarget::Port { proto, port } => Some(FirewallPortC {
+                proto: proto.clone(),
+                port: *port,
+            }),
+            _ => None,
+        })
+        .collect();
+    let status = fw.tun_status(&dev_name, &ports);
+    if status.backend == FirewallBackendC::Unknown {
+        return Err(FirewallError::needs_privileges());
+    }
+    let blocking_rule = fw.blocking_rule().unwrap_or_else(|| status.detail.clone());
+
+    let findings = targets
+        .iter()
+        .map(|target| {
+            let allowed = match target {
+                RuleTarget::Overlay { .. } => status.tun_allowed,
+                RuleTarget::Port { proto, port } => status
+                    .ports
+                    .iter()
+                    .any(|p| p.allowed && p.port == *port && &p.proto == proto),
+            };
+            finding(target, status.active && !allowed, &blocking_rule)
+        })
+        .collect();
+    Ok(FirewallDiagnosisC {
+        backend: status.backend,
+        active: status.active,
+        findings,
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn add_rule(target: &RuleTarget) -> Result<(), FirewallError> {
+    Ok(win32::WindowsFirewall::new()?.add_rule(target)?)
+}
+
+#[cfg(target_os = "windows")]
+fn remove_rule(target: &RuleTarget) -> Result<(), FirewallError> {
+    Ok(win32::WindowsFirewall::new()?.remove_rule(target)?)
+}
+
+#[cfg(target_os = "windows")]
+fn diagnose(targets: &[RuleTarget]) -> Result<FirewallDiagnosisC, FirewallError> {
+    let fw = win32::WindowsFirewall::new()?;
+    let active = fw.is_active()?;
+    let blocking = fw.blocking_rule()?;
+
+    let mut findings = Vec::new();
+    for target in targets {
+        // block 规则优先于 allow 规则，默认动作是放行时也会拦截。
+        let block_rules = fw.block_rules(target)?;
+        findings.push(match &blocking {
+            // "阻止所有入站连接" 连 allow 规则也不看，只能让用户去系统设置里关掉。
+            Some((rule, true)) => FirewallFindingC {
+                rule: target.to_c(),
+                blocked: true,
+                blocking_rule: rule.clone(),
+                suggestion: "turn off \"Block all incoming connections\" in Windows Firewall settings"
+                    .to_string(),
+            },
+            _ if !block_rules.is_empty() => FirewallFindingC {
+                rule: target.to_c(),
+                blocked: true,
+                blocking_rule: block_rules.join(", "),
+                suggestion: "disable or narrow these inbound block rules in Windows Firewall"
+                    .to_string(),
+            },
+            Some((rule, false)) => finding(target, !fw.has_rule(target), rule),
+            None => finding(target, false, ""),
+        });
+    }
+    Ok(FirewallDiagnosisC {
+        backend: if active {
+            FirewallBackendC::WindowsFirewall
+        } else {
+            FirewallBackendC::None
+        },
+        active,
+        findings,
+    })
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+fn add_rule(_target: &RuleTarget) -> Result<(), FirewallError> {
+    Err(FirewallError::unsupported("firewall rules"))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+fn remove_rule(_target: &RuleTarget) -> Result<(), FirewallError> {
+    Err(FirewallError::unsupported("firewall rules"))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+fn diagnose(_targets: &[RuleTarget]) -> Result<FirewallDiagnosisC, FirewallError> {
+    Err(FirewallError::unsupported("firewall diagnosis"))
+}
+
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+fn finding(target: &RuleTarget, blocked: bool, blocking_rule: &str) -> FirewallFindingC {
+    FirewallFindingC {
+        rule: target.to_c(),
+        blocked,
+        blocking_rule: if blocked {
+            blocking_rule.to_string()
+        } else {
+            String::new()
+        },
+        suggestion: if blocked {
+            format!(
+                "add rule \"{}\" with allow_instance_through_firewall",
+                target.rule_name()
+            )
+        } else {
+            String::new()
+        },
+    }
 }
@@ -0,0 +1,1085 @@
+// Linux 防火墙后端：firewalld / ufw / nftables / iptables。
+//
+// 检测顺序按"谁在管理规则"排：firewalld 和 ufw 是前端，底下分别跑 nftables 或
+// iptables；只有前端都没启用时才直接看 nft / iptables 的规则。所有外部命令都经过
+// [`CommandRunner`]，测试时可以换成假的 runner，不需要 root 也不会真的改系统规则。
+//
+// 放行规则只针对 Astral 的 TUN 设备（`-i <dev>` / `iifname "<dev>"`），删除时只删
+// 自己加的那条：
+//
+// - ufw / nftables / iptables 的规则都带 [`RULE_COMMENT`]，删除时只认带注释的规则；
+//   同样的规则用户已经加过时 ufw / iptables 不会再加，也就不会被我们删掉；
+// - firewalld 的 zone 绑定和端口没有注释可带，只在真正改动了配置时记进
+//   [`RuleLedger`]，删除时只撤销记录过的改动。改的是运行时配置，重载 firewalld
+//   后本来就会消失，账本只放内存里。
+
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use lazy_static::lazy_static;
+
+use super::{FirewallBackendC, FirewallPortC, FirewallPortStatusC, TunFirewallStatusC};
+
+/// 我们添加的规则都带这个注释，方便识别和清理。
+pub(crate) const RULE_COMMENT: &str = "astral-tun";
+
+/// 检测命令没有权限时的说明。
+pub(crate) const NEEDS_ROOT: &str =
+    "firewall state unknown: reading firewall rules requires root privileges";
+
+lazy_static! {
+    static ref LEDGER: RuleLedger = RuleLedger::default();
+}
+
+/// 记录没法带注释的改动（firewalld），删除时只撤销这里有的。
+#[derive(Clone, Default)]
+pub struct RuleLedger(Arc<Mutex<HashSet<String>>>);
+
+impl RuleLedger {
+    fn insert(&self, key: String) {
+        self.0.lock().unwrap().insert(key);
+    }
+
+    fn contains(&self, key: &str) -> bool {
+        self.0.lock().unwrap().contains(key)
+    }
+
+    fn remove(&self, key: &str) {
+        self.0.lock().unwrap().remove(key);
+    }
+
+    /// 按 [`firewalld_port_key`] 的前缀找到加规则时用的 zone。
+    fn find_zone(&self, prefix: &str) -> Option<String> {
+        self.0
+            .lock()
+            .unwrap()
+            .iter()
+            .find_map(|k| k.strip_prefix(prefix).map(str::to_string))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CommandOutput {
+    pub success: bool,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// 执行外部命令的抽象。
+pub trait CommandRunner: Send + Sync {
+    fn run(&self, program: &str, args: &[&str]) -> std::io::Result<CommandOutput>;
+}
+
+/// 真正调用系统命令的 runner。
+pub struct SystemCommandRunner;
+
+impl CommandRunner for SystemCommandRunner {
+    fn run(&self, program: &str, args: &[&str]) -> std::io::Result<CommandOutput> {
+        let output = std::process::Command::new(program).args(args).output()?;
+        Ok(CommandOutput {
+            success: output.status.success(),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        })
+    }
+}
+
+pub struct LinuxFirewall<R: CommandRunner> {
+    runner: R,
+    ledger: RuleLedger,
+}
+
+impl<R: CommandRunner> LinuxFirewall<R> {
+    /// 使用进程内共享的账本。
+    pub fn new(runner: R) -> Self {
+        Self::with_ledger(runner, LEDGER.clone())
+    }
+
+    pub fn with_ledger(runner: R, ledger: RuleLedger) -> Self {
+        Self { runner, ledger }
+    }
+
+    /// 当前生效的防火墙后端；命令不存在或没有启用时跳过。没检测到任何后端、
+    /// 但有检测命令因权限不足失败时返回 [`FirewallBackendC::Unknown`]，不能当成
+    /// 没有防火墙。
+    pub fn detect_backend(&self) -> FirewallBackendC {
+        let probes: [BackendProbe; 4] = [
+            (
+                "firewall-cmd",
+                &["--state"],
+                |out| out.trim() == "running",
+                FirewallBackendC::Firewalld,
+            ),
+            (
+                "ufw",
+                &["status"],
+                |out| out.contains("Status: active"),
+                FirewallBackendC::Ufw,
+            ),
+            (
+                "nft",
+                &["list", "ruleset"],
+                |out| out.contains("hook input"),
+                FirewallBackendC::Nftables,
+            ),
+            (
+                "iptables",
+                &["-S", "INPUT"],
+                |_| true,
+                FirewallBackendC::Iptables,
+            ),
+        ];
+        let mut denied = false;
+        for (program, args, enabled, backend) in probes {
+            match self.probe(program, args) {
+                Probe::Output(out) if enabled(&out) => return backend,
+                Probe::Denied => denied = true,
+                _ => {}
+            }
+        }
+        if denied {
+            FirewallBackendC::Unknown
+        } else {
+            FirewallBackendC::None
+        }
+    }
+
+    /// 防火墙是否在拦截入站流量（默认策略为 drop/reject，或有兜底拒绝规则）；
+    /// 没有权限读取规则时返回 `None`。
+    pub fn is_active(&self) -> Option<bool> {
+        match self.detect_backend() {
+            FirewallBackendC::Firewalld | FirewallBackendC::Ufw => Some(true),
+            FirewallBackendC::Nftables => match self.probe("nft", &["list", "ruleset"]) {
+                Probe::Output(out) => Some(nft_input_drops(&out)),
+                Probe::Denied => None,
+                Probe::Failed => Some(false),
+            },
+            FirewallBackendC::Iptables => match self.probe("iptables", &["-S", "INPUT"]) {
+                Probe::Output(out) => Some(iptables_input_drops(&out)),
+                Probe::Denied => None,
+                Probe::Failed => Some(false),
+            },
+            FirewallBackendC::None | FirewallBackendC::WindowsFirewall => Some(false),
+            FirewallBackendC::Unknown => None,
+        }
+    }
+
+    /// TUN 设备和监听端口是否被放行。
+    pub fn tun_status(&self, dev_name: &str, ports: &[FirewallPortC]) -> TunFirewallStatusC {
+        let backend = self.detect_backend();
+        let mut status = TunFirewallStatusC {
+            backend,
+            active: false,
+            tun_allowed: true,
+            ports: ports
+                .iter()
+                .map(|p| FirewallPortStatusC {
+                    proto: p.proto.clone(),
+                    port: p.port,
+                    allowed: true,
+                })
+                .collect(),
+            detail: String::new(),
+        };
+
+        match backend {
+            FirewallBackendC::None | FirewallBackendC::WindowsFirewall => {}
+            FirewallBackendC::Unknown => status.detail = NEEDS_ROOT.to_string(),
+            FirewallBackendC::Firewalld => {
+                status.active = true;
+                let zone = self.firewalld_zone_of(dev_name);
+                status.tun_allowed = zone == "trusted";
+                for p in &mut status.ports {
+                    let query = format!("{}/{}", p.port, p.proto);
+                    let zone_arg = format!("--zone={}", zone);
+                    p.allowed = self
+                        .stdout("firewall-cmd", &[&zone_arg, "--query-port", &query])
+                        .is_some_and(|out| out.trim() == "yes");
+                }
+                status.detail = format!("zone: {}", zone);
+            }
+            FirewallBackendC::Ufw => {
+                status.active = true;
+                let out = self.stdout("ufw", &["status"]).unwrap_or_default();
+                status.tun_allowed = ufw_allows_interface(&out, dev_name);
+                for p in &mut status.ports {
+                    p.allowed = ufw_allows_port(&out, p.port, &p.proto);
+                }
+            }
+            FirewallBackendC::Nftables => match self.probe("nft", &["list", "ruleset"]) {
+                Probe::Output(out) => {
+                    status.active = nft_input_drops(&out);
+                    if status.active {
+                        status.tun_allowed =
+                            out.contains(&format!("iifname \"{}\" accept", dev_name));
+                        for p in &mut status.ports {
+                            p.allowed = nft_allows_port(&out, p.port, &p.proto);
+                        }
+                    }
+                }
+                Probe::Denied => {
+                    status.detail = "nft list ruleset failed (root required)".to_string()
+                }
+                Probe::Failed => status.detail = "nft list ruleset failed".to_string(),
+            },
+            FirewallBackendC::Iptables => match self.probe("iptables", &["-S", "INPUT"]) {
+                Probe::Output(out) => {
+                    status.active = iptables_input_drops(&out);
+                    if status.active {
+                        status.tun_allowed = iptables_allows_interface(&out, dev_name);
+                        for p in &mut status.ports {
+                            p.allowed = iptables_allows_port(&out, p.port, &p.proto);
+                        }
+                    }
+                }
+                Probe::Denied => {
+                    status.detail = "iptables -S INPUT failed (root required)".to_string()
+                }
+                Probe::Failed => status.detail = "iptables -S INPUT failed".to_string(),
+            },
+        }
+        status
+    }
+
+    /// 放行来自 TUN 设备的入站流量。已放行时什么都不做。
+    pub fn allow_tun(&self, dev_name: &str) -> Result<(), String> {
+        match self.detect_backend() {
+            FirewallBackendC::None | FirewallBackendC::WindowsFirewall => Ok(()),
+            FirewallBackendC::Unknown => Err(NEEDS_ROOT.to_string()),
+            // 只改运行时配置：TUN 每次启动重建，不需要 --permanent。
+            FirewallBackendC::Firewalld => {
+                if self.firewalld_zone_of(dev_name) == "trusted" {
+                    return Ok(());
+                }
+                self.check(
+                    "firewall-cmd",
+                    &["--zone=trusted", &format!("--add-interface={}", dev_name)],
+                )?;
+                self.ledger.insert(firewalld_interface_key(dev_name));
+                Ok(())
+            }
+            FirewallBackendC::Ufw => self.check(
+                "ufw",
+                &["allow", "in", "on", dev_name, "comment", RULE_COMMENT],
+            ),
+            FirewallBackendC::Nftables => self.nft_insert(&format!("iifname \"{}\"", dev_name)),
+            FirewallBackendC::Iptables => self.iptables_insert(&iptables_tun_rule(dev_name)),
+        }
+    }
+
+    /// 删除 [`allow_tun`](Self::allow_tun) 加的规则；不是我们加的（或不存在）时
+    /// 什么都不做。
+    pub fn remove_tun(&self, dev_name: &str) -> Result<(), String> {
+        match self.detect_backend() {
+            FirewallBackendC::None | FirewallBackendC::WindowsFirewall => Ok(()),
+            FirewallBackendC::Unknown => Err(NEEDS_ROOT.to_string()),
+            FirewallBackendC::Firewalld => {
+                let key = firewalld_interface_key(dev_name);
+                if !self.ledger.contains(&key) {
+                    return Ok(());
+                }
+                if self.firewalld_zone_of(dev_name) == "trusted" {
+                    self.check(
+                        "firewall-cmd",
+                        &[
+                            "--zone=trusted",
+                            &format!("--remove-interface={}", dev_name),
+                        ],
+                    )?;
+                }
+                self.ledger.remove(&key);
+                Ok(())
+            }
+            FirewallBackendC::Ufw => {
+                let out = self.stdout("ufw", &["status"]).unwrap_or_default();
+                if !ufw_tagged_interface(&out, dev_name) {
+                    return Ok(());
+                }
+                self.check("ufw", &["delete", "allow", "in", "on", dev_name])
+            }
+            FirewallBackendC::Nftables => self.nft_delete(&format!("iifname \"{}\"", dev_name)),
+            FirewallBackendC::Iptables => self.iptables_delete(&iptables_tun_rule(dev_name)),
+        }
+    }
+
+    /// 放行某个监听端口。已放行时什么都不做。
+    pub fn allow_port(&self, proto: &str, port: u16) -> Result<(), String> {
+        let port_arg = format!("{}/{}", port, proto);
+        match self.detect_backend() {
+            FirewallBackendC::None | FirewallBackendC::WindowsFirewall => Ok(()),
+            FirewallBackendC::Unknown => Err(NEEDS_ROOT.to_string()),
+            FirewallBackendC::Firewalld => {
+                let zone = self.firewalld_default_zone();
+                let zone_arg = format!("--zone={}", zone);
+                let query = format!("--query-port={}", port_arg);
+                if self.stdout("firewall-cmd", &[&zone_arg, &query]).is_some() {
+                    return Ok(());
+                }
+                self.check(
+                    "firewall-cmd",
+                    &[&zone_arg, &format!("--add-port={}", port_arg)],
+                )?;
+                self.ledger.insert(firewalld_port_key(&zone, &port_arg));
+                Ok(())
+            }
+            FirewallBackendC::Ufw => {
+                self.check("ufw", &["allow", &port_arg, "comment", RULE_COMMENT])
+            }
+            FirewallBackendC::Nftables => self.nft_insert(&format!("{} dport {}", proto, port)),
+            FirewallBackendC::Iptables => {
+                self.iptables_insert(&iptables_port_rule(proto, &port.to_string()))
+            }
+        }
+    }
+
+    /// 删除 [`allow_port`](Self::allow_port) 加的规则；不是我们加的（或不存在）时
+    /// 什么都不做。
+    pub fn remove_port(&self, proto: &str, port: u16) -> Result<(), String> {
+        let port_arg = format!("{}/{}", port, proto);
+        match self.detect_backend() {
+            FirewallBackendC::None | FirewallBackendC::WindowsFirewall => Ok(()),
+            FirewallBackendC::Unknown => Err(NEEDS_ROOT.to_string()),
+            FirewallBackendC::Firewalld => {
+                // 加规则时的默认 zone 可能已经变了，按账本里记的 zone 撤销。
+                let prefix = firewalld_port_key("", &port_arg);
+                let Some(zone) = self.ledger.find_zone(&prefix) else {
+                    return Ok(());
+                };
+                let zone_arg = format!("--zone={}", zone);
+                let query = format!("--query-port={}", port_arg);
+                if self.stdout("firewall-cmd", &[&zone_arg, &query]).is_some() {
+                    self.check(
+                        "firewall-cmd",
+                        &[&zone_arg, &format!("--remove-port={}", port_arg)],
+                    )?;
+                }
+                self.ledger.remove(&firewalld_port_key(&zone, &port_arg));
+                Ok(())
+            }
+            FirewallBackendC::Ufw => {
+                let out = self.stdout("ufw", &["status"]).unwrap_or_default();
+                if !ufw_tagged_port(&out, &port_arg) {
+                    return Ok(());
+                }
+                self.check("ufw", &["delete", "allow", &port_arg])
+            }
+            FirewallBackendC::Nftables => self.nft_delete(&format!("{} dport {}", proto, port)),
+            FirewallBackendC::Iptables => {
+                self.iptables_delete(&iptables_port_rule(proto, &port.to_string()))
+            }
+        }
+    }
+
+    /// 描述拦截入站流量的那条规则 / 策略；没有在拦截时返回 `None`。
+    pub fn blocking_rule(&self) -> Option<String> {
+        match self.detect_backend() {
+            FirewallBackendC::None
+            | FirewallBackendC::WindowsFirewall
+            | FirewallBackendC::Unknown => None,
+            FirewallBackendC::Firewalld => Some(format!(
+                "firewalld zone {} (default target rejects unlisted traffic)",
+                self.firewalld_default_zone()
+            )),
+            FirewallBackendC::Ufw => {
+                let out = self
+                    .stdout("ufw", &["status", "verbose"])
+                    .unwrap_or_default();
+                out.lines()
+                    .find(|l| l.starts_with("Default:"))
+                    .map(|l| format!("ufw {}", l.trim()))
+                    .or_else(|| Some("ufw default incoming policy".to_string()))
+            }
+            FirewallBackendC::Nftables => {
+                let out = self.stdout("nft", &["list", "ruleset"])?;
+                nft_drop_chain(&out)
+            }
+            FirewallBackendC::Iptables => {
+                let out = self.stdout("iptables", &["-S", "INPUT"])?;
+                out.lines()
+                    .map(str::trim)
+                    .find(|l| {
+                        *l == "-P INPUT DROP"
+                            || *l == "-P INPUT REJECT"
+                            || (l.starts_with("-A INPUT")
+                                && (l.ends_with("-j DROP") || l.contains("-j REJECT")))
+                    })
+                    .map(|l| format!("iptables {}", l))
+            }
+        }
+    }
+
+    fn firewalld_default_zone(&self) -> String {
+        self.stdout("firewall-cmd", &["--get-default-zone"])
+            .map(|out| out.trim().to_string())
+            .filter(|zone| !zone.is_empty())
+            .unwrap_or_else(|| "public".to_string())
+    }
+
+    /// 在第一个 input filter chain 头部插入 `<match> accept comment "astral-tun"`。
+    fn nft_insert(&self, match_expr: &str) -> Result<(), String> {
+        if !self.nft_rule_handles(match_expr).is_empty() {
+            return Ok(());
+        }
+        let out = self.stdout("nft", &["list", "ruleset"]).unwrap_or_default();
+        let (family, table, chain) =
+            nft_input_chain(&out).ok_or_else(|| "no nftables input chain found".to_string())?;
+        let rule = format!(
+            "insert rule {} {} {} {} accept comment \"{}\"",
+            family, table, chain, match_expr, RULE_COMMENT
+        );
+        self.check("nft", &[&rule])
+    }
+
+    fn nft_delete(&self, match_expr: &str) -> Result<(), String> {
+        for (family, table, chain, handle) in self.nft_rule_handles(match_expr) {
+            let rule = format!(
+                "delete rule {} {} {} handle {}",
+                family, table, chain, handle
+            );
+            self.check("nft", &[&rule])?;
+        }
+        Ok(())
+    }
+
+    fn iptables_insert(&self, rule: &[&str]) -> Result<(), String> {
+        let exists: Vec<&str> = ["-C", "INPUT"]
+            .into_iter()
+            .chain(rule.iter().copied())
+            .collect();
+        if self.check("iptables", &exists).is_ok() {
+            return Ok(());
+        }
+        let insert: Vec<&str> = ["-I", "INPUT"]
+            .into_iter()
+            .chain(rule.iter().copied())
+            .collect();
+        self.check("iptables", &insert)
+    }
+
+    fn iptables_delete(&self, rule: &[&str]) -> Result<(), String> {
+        let delete: Vec<&str> = ["-D", "INPUT"]
+            .into_iter()
+            .chain(rule.iter().copied())
+            .collect();
+        // 可能重复插入过多次，删到 -D 失败为止。
+        while self.check("iptables", &delete).is_ok() {}
+        Ok(())
+    }
+
+    fn firewalld_zone_of(&self, dev_name: &str) -> String {
+        let arg = format!("--get-zone-of-interface={}", dev_name);
+        self.stdout("firewall-cmd", &[&arg])
+            .map(|out| out.trim().to_string())
+            .filter(|zone| !zone.is_empty())
+            .or_else(|| {
+                self.stdout("firewall-cmd", &["--get-default-zone"])
+                    .map(|out| out.trim().to_string())
+            })
+            .unwrap_or_else(|| "public".to_string())
+    }
+
+    /// 找出我们加过的 nft 规则：(family, table, chain, handle)。
+    fn nft_rule_handles(&self, match_expr: &str) -> Vec<(String, String, String, String)> {
+        let Some(out) = self.stdout("nft", &["-a", "list", "ruleset"]) else {
+            return Vec::new();
+        };
+        let needle = format!("{} accept comment \"{}\"", match_expr, RULE_COMMENT);
+        let mut result = Vec::new();
+        let mut table: Option<(String, String)> = None;
+        let mut chain: Option<String> = None;
+        for line in out.lines().map(str::trim) {
+            if let Some(rest) = line.strip_prefix("table ") {
+                let mut parts = rest.split_whitespace();
+                table = parts
+                    .next()
+                    .zip(parts.next())
+                    .map(|(f, t)| (f.to_string(), t.to_string()));
+            } else if let Some(rest) = line.strip_prefix("chain ") {
+                chain = rest.split_whitespace().next().map(str::to_string);
+            } else if line.starts_with(&needle) {
+                let handle = line.split_once("# handle ").map(|(_, h)| h.trim());
+                if let (Some((family, table)), Some(chain), Some(handle)) = (&table, &chain, handle)
+                {
+                    result.push((
+                        family.clone(),
+                        table.clone(),
+                        chain.clone(),
+                        handle.to_string(),
+                    ));
+                }
+            }
+        }
+        result
+    }
+
+    /// 和 [`stdout`](Self::stdout) 一样执行命令，但把权限不足单独区分出来。
+    fn probe(&self, program: &str, args: &[&str]) -> Probe {
+        match self.runner.run(program, args) {
+            Ok(out) if out.success => Probe::Output(out.stdout),
+            Ok(out) if is_permission_denied(&out.stderr) => Probe::Denied,
+            Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => Probe::Denied,
+            _ => Probe::Failed,
+        }
+    }
+
+    /// 命令成功时返回 stdout；命令不存在、没权限或退出码非 0 都返回 `None`。
+    fn stdout(&self, program: &str, args: &[&str]) -> Option<String> {
+        match self.runner.run(program, args) {
+            Ok(out) if out.success => Some(out.stdout),
+            _ => None,
+        }
+    }
+
+    fn check(&self, program: &str, args: &[&str]) -> Result<(), String> {
+        let out = self
+            .runner
+            .run(program, args)
+            .map_err(|e| format!("run {} failed: {}", program, e))?;
+        if out.success {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} {} failed: {}",
+                program,
+                args.join(" "),
+                out.stderr.trim()
+            ))
+        }
+    }
+}
+
+/// 检测命令的结果。
+enum Probe {
+    Output(String),
+    /// 命令因权限不足失败，看不到真实的规则。
+    Denied,
+    /// 命令不存在或因其他原因失败。
+    Failed,
+}
+
+/// (程序, 参数, 输出是否表示后端已启用, 对应的后端)
+type BackendProbe = (
+    &'static str,
+    &'static [&'static str],
+    fn(&str) -> bool,
+    FirewallBackendC,
+);
+
+/// 各个工具没有 root 时的报错：nft / iptables 是 "Operation not permitted" /
+/// "Permission denied (you must be root)"，ufw 是 "You need to be root"，
+/// firewall-cmd 经 polkit 拒绝时是 "Authorization failed"。
+fn is_permission_denied(stderr: &str) -> bool {
+    let stderr = stderr.to_ascii_lowercase();
+    [
+        "permission denied",
+        "operation not permitted",
+        "must be root",
+        "need to be root",
+        "not authorized",
+        "authorization failed",
+    ]
+    .iter()
+    .any(|needle| stderr.contains(needle))
+}
+
+fn firewalld_interface_key(dev_name: &str) -> String {
+    format!("firewalld interface trusted {}", dev_name)
+}
+
+/// `firewalld port <port/proto> <zone>`；zone 放最后，删除时按前缀找回来。
+fn firewalld_port_key(zone: &str, port_arg: &str) -> String {
+    format!("firewalld port {} {}", port_arg, zone)
+}
+
+fn iptables_tun_rule(dev_name: &str) -> [&str; 8] {
+    [
+        "-i",
+        dev_name,
+        "-m",
+        "comment",
+        "--comment",
+        RULE_COMMENT,
+        "-j",
+        "ACCEPT",
+    ]
+}
+
+fn iptables_port_rule<'a>(proto: &'a str, port: &'a str) -> [&'a str; 12] {
+    [
+        "-p",
+        proto,
+        "-m",
+        proto,
+        "--dport",
+        port,
+        "-m",
+        "comment",
+        "--comment",
+        RULE_COMMENT,
+        "-j",
+        "ACCEPT",
+    ]
+}
+
+fn iptables_input_drops(out: &str) -> bool {
+    out.lines().any(|l| {
+        let l = l.trim();
+        l == "-P INPUT DROP"
+            || l == "-P INPUT REJECT"
+            || (l.starts_with("-A INPUT")
+                && !l.contains(" -p ")
+                && !l.contains(" -i ")
+                && (l.ends_with("-j DROP") || l.contains("-j REJECT")))
+    })
+}
+
+fn iptables_allows_interface(out: &str, dev_name: &str) -> bool {
+    let iface = format!("-i {} ", dev_name);
+    out.lines().any(|l| {
+        l.starts_with("-A INPUT") && l.contains(&iface) && l.trim_end().ends_with("-j ACCEPT")
+    })
+}
+
+fn iptables_allows_port(out: &str, port: u16, proto: &str) -> bool {
+    let proto = format!("-p {}", proto);
+    let dport = format!("--dport {}", port);
+    out.lines().any(|l| {
+        l.starts_with("-A INPUT")
+            && l.contains(&proto)
+            && l.contains(&dport)
+            && l.trim_end().ends_with("-j ACCEPT")
+    })
+}
+
+/// 只要有一个 `hook input` 的 base chain 默认策略是 drop，就认为在拦截入站。
+fn nft_input_drops(out: &str) -> bool {
+    out.lines()
+        .any(|l| l.contains("hook input") && l.contains("policy drop"))
+}
+
+fn nft_allows_port(out: &str, port: u16, proto: &str) -> bool {
+    let dport = format!("{} dport {} accept", proto, port);
+    out.lines().any(|l| l.contains(&dport))
+}
+
+/// 默认策略为 drop 的 input chain，格式化成 `nftables chain <family> <table> <chain>`。
+fn nft_drop_chain(out: &str) -> Option<String> {
+    let mut table: Option<(String, String)> = None;
+    let mut chain: Option<String> = None;
+    for line in out.lines().map(str::trim) {
+        if let Some(rest) = line.strip_prefix("table ") {
+            let mut parts = rest.split_whitespace();
+            table = parts
+                .next()
+                .zip(parts.next())
+                .map(|(f, t)| (f.to_string(), t.to_string()));
+        } else if let Some(rest) = line.strip_prefix("chain ") {
+            chain = rest.split_whitespace().next().map(str::to_string);
+        } else if line.contains("hook input") && line.contains("policy drop") {
+            if let (Some((family, table)), Some(chain)) = (&table, &chain) {
+                return Some(format!(
+                    "nftables chain {} {} {} (policy drop)",
+                    family, table, chain
+                ));
+            }
+        }
+    }
+    None
+}
+
+/// 第一个 `hook input` 的 filter base chain：(family, table, chain)。
+fn nft_input_chain(out: &str) -> Option<(String, String, String)> {
+    let mut table: Option<(String, String)> = None;
+    let mut chain: Option<String> = None;
+    for line in out.lines().map(str::trim) {
+        if let Some(rest) = line.strip_prefix("table ") {
+            let mut parts = rest.split_whitespace();
+            table = parts
+                .next()
+                .zip(parts.next())
+                .map(|(f, t)| (f.to_string(), t.to_string()));
+        } else if let Some(rest) = line.strip_prefix("chain ") {
+            chain = rest.split_whitespace().next().map(str::to_string);
+        } else if line.contains("type filter hook input") {
+            if let (Some((family, table)), Some(chain)) = (&table, &chain) {
+                return Some((family.clone(), table.clone(), chain.clone()));
+            }
+        }
+    }
+    None
+}
+
+fn ufw_allows_interface(out: &str, dev_name: &str) -> bool {
+    let on = format!("on {}", dev_name);
+    // `ufw status` 显示 `ALLOW`，`status verbose` 显示 `ALLOW IN`。
+    out.lines().any(|l| l.contains(&on) && l.contains("ALLOW"))
+}
+
+/// ufw status 里带 `# astral-tun` 注释、放行 TUN 设备的规则。
+fn ufw_tagged_interface(out: &str, dev_name: &str) -> bool {
+    let on = format!("on {}", dev_name);
+    let comment = format!("# {}", RULE_COMMENT);
+    out.lines()
+        .any(|l| l.contains(&on) && l.contains("ALLOW") && l.trim_end().ends_with(&comment))
+}
+
+/// ufw status 里带 `# astral-tun` 注释、放行 `<port>/<proto>` 的规则。
+fn ufw_tagged_port(out: &str, port_arg: &str) -> bool {
+    let comment = format!("# {}", RULE_COMMENT);
+    out.lines().any(|l| {
+        l.split_whitespace().next() == Some(port_arg)
+            && l.contains("ALLOW")
+            && l.trim_end().ends_with(&comment)
+    })
+}
+
+fn ufw_allows_port(out: &str, port: u16, proto: &str) -> bool {
+    let exact = format!("{}/{}", port, proto);
+    let any = port.to_string();
+    out.lines().any(|l| {
+        let first = l.split_whitespace().next().unwrap_or_default();
+        (first == exact || first == any) && l.contains("ALLOW")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 按完整命令行返回预设输出，并记录执行过的命令。没有预设的命令按失败处理
+    /// （相当于命令不存在）。
+    #[derive(Default)]
+    struct FakeRunner {
+        responses: Mutex<Vec<(String, CommandOutput, Option<usize>)>>,
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl FakeRunner {
+        fn ok(self, command: &str, stdout: &str) -> Self {
+            self.push(command, stdout, None)
+        }
+
+        /// 只成功 `times` 次，之后按失败处理。
+        fn ok_times(self, command: &str, times: usize) -> Self {
+            self.push(command, "", Some(times))
+        }
+
+        /// 模拟没有 root 时命令失败。
+        fn denied(self, command: &str, stderr: &str) -> Self {
+            self.respond(
+                command,
+                CommandOutput {
+                    success: false,
+                    stdout: String::new(),
+                    stderr: stderr.to_string(),
+                },
+                None,
+            )
+        }
+
+        fn push(self, command: &str, stdout: &str, times: Option<usize>) -> Self {
+            self.respond(
+                command,
+                CommandOutput {
+                    success: true,
+                    stdout: stdout.to_string(),
+                    stderr: String::new(),
+                },
+                times,
+            )
+        }
+
+        fn respond(self, command: &str, output: CommandOutput, times: Option<usize>) -> Self {
+            self.responses
+                .lock()
+                .unwrap()
+                .push((command.to_string(), output, times));
+            self
+        }
+
+        fn called(&self, command: &str) -> bool {
+            self.calls.lock().unwrap().iter().any(|c| c == command)
+        }
+
+        fn count(&self, command: &str) -> usize {
+            self.calls
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|c| *c == command)
+                .count()
+        }
+    }
+
+    impl CommandRunner for &FakeRunner {
+        fn run(&self, program: &str, args: &[&str]) -> std::io::Result<CommandOutput> {
+            let command = std::iter::once(program)
+                .chain(args.iter().copied())
+                .collect::<Vec<_>>()
+                .join(" ");
+            self.calls.lock().unwrap().push(command.clone());
+            let mut responses = self.responses.lock().unwrap();
+            let found = responses
+                .iter_mut()
+                .find(|(c, _, times)| *c == command && *times != Some(0));
+            Ok(match found {
+                Some((_, out, times)) => {
+                    if let Some(n) = times {
+                        *n -= 1;
+                    }
+                    out.clone()
+                }
+                None => CommandOutput {
+                    success: false,
+                    stdout: String::new(),
+                    stderr: "not found".to_string(),
+                },
+            })
+        }
+    }
+
+    const UFW_UNTAGGED: &str = "Status: active\n\n\
+        To                         Action      From\n\
+        --                         ------      ----\n\
+        Anywhere on astral         ALLOW       Anywhere\n\
+        11010/tcp                  ALLOW       Anywhere\n";
+
+    const UFW_TAGGED: &str = "Status: active\n\n\
+        To                         Action      From\n\
+        --                         ------      ----\n\
+        Anywhere on astral         ALLOW       Anywhere                   # astral-tun\n\
+        11010/tcp                  ALLOW       Anywhere                   # astral-tun\n";
+
+    #[test]
+    fn detects_frontend_before_packet_filter() {
+        let runner = FakeRunner::default()
+            .ok("ufw status", UFW_UNTAGGED)
+            .ok("iptables -S INPUT", "-P INPUT DROP\n");
+        let fw = LinuxFirewall::with_ledger(&runner, RuleLedger::default());
+        assert_eq!(fw.detect_backend(), FirewallBackendC::Ufw);
+    }
+
+    #[test]
+    fn permission_denied_is_unknown_not_none() {
+        let runner = FakeRunner::default()
+            .denied(
+                "ufw status",
+                "ERROR: You need to be root to run this script\n",
+            )
+            .denied(
+                "nft list ruleset",
+                "Error: Operation not permitted (you must be root)\n",
+            )
+            .denied(
+                "iptables -S INPUT",
+                "iptables v1.8.9 (nf_tables): Permission denied (you must be root)\n",
+            );
+        let fw = LinuxFirewall::with_ledger(&runner, RuleLedger::default());
+        assert_eq!(fw.detect_backend(), FirewallBackendC::Unknown);
+        assert_eq!(fw.is_active(), None);
+        assert_eq!(fw.tun_status("astral", &[]).detail, NEEDS_ROOT);
+        assert!(fw.allow_tun("astral").is_err());
+        assert_eq!(fw.blocking_rule(), None);
+    }
+
+    #[test]
+    fn missing_commands_mean_no_firewall() {
+        let runner = FakeRunner::default();
+        let fw = LinuxFirewall::with_ledger(&runner, RuleLedger::default());
+        assert_eq!(fw.detect_backend(), FirewallBackendC::None);
+        assert_eq!(fw.is_active(), Some(false));
+        assert!(fw.allow_tun("astral").is_ok());
+    }
+
+    #[test]
+    fn detected_backend_wins_over_denied_probe() {
+        let runner = FakeRunner::default()
+            .denied(
+                "ufw status",
+                "ERROR: You need to be root to run this script\n",
+            )
+            .ok("iptables -S INPUT", "-P INPUT DROP\n");
+        let fw = LinuxFirewall::with_ledger(&runner, RuleLedger::default());
+        assert_eq!(fw.detect_backend(), FirewallBackendC::Iptables);
+        assert_eq!(fw.is_active(), Some(true));
+    }
+
+    #[test]
+    fn ufw_keeps_rules_the_user_added() {
+        let runner = FakeRunner::default()
+            .ok("ufw status", UFW_UNTAGGED)
+            .ok("ufw delete allow in on astral", "")
+            .ok("ufw delete allow 11010/tcp", "");
+        let fw = LinuxFirewall::with_ledger(&runner, RuleLedger::default());
+        fw.remove_tun("astral").unwrap();
+        fw.remove_port("tcp", 11010).unwrap();
+        assert!(!runner.called("ufw delete allow in on astral"));
+        assert!(!runner.called("ufw delete allow 11010/tcp"));
+    }
+
+    #[test]
+    fn ufw_removes_tagged_rules() {
+        let runner = FakeRunner::default()
+            .ok("ufw status", UFW_TAGGED)
+            .ok("ufw delete allow in on astral", "")
+            .ok("ufw delete allow 11010/tcp", "");
+        let fw = LinuxFirewall::with_ledger(&runner, RuleLedger::default());
+        fw.remove_tun("astral").unwrap();
+        fw.remove_port("tcp", 11010).unwrap();
+        assert!(runner.called("ufw delete allow in on astral"));
+        assert!(runner.called("ufw delete allow 11010/tcp"));
+    }
+
+    #[test]
+    fn firewalld_keeps_interface_the_user_trusted() {
+        let runner = FakeRunner::default()
+            .ok("firewall-cmd --state", "running\n")
+            .ok("firewall-cmd --get-zone-of-interface=astral", "trusted\n")
+            .ok("firewall-cmd --zone=trusted --remove-interface=astral", "");
+        let fw = LinuxFirewall::with_ledger(&runner, RuleLedger::default());
+        fw.allow_tun("astral").unwrap();
+        fw.remove_tun("astral").unwrap();
+        assert!(!runner.called("firewall-cmd --zone=trusted --add-interface=astral"));
+        assert!(!runner.called("firewall-cmd --zone=trusted --remove-interface=astral"));
+    }
+
+    #[test]
+    fn firewalld_undoes_its_own_interface_change() {
+        let ledger = RuleLedger::default();
+        let before = FakeRunner::default()
+            .ok("firewall-cmd --state", "running\n")
+            .ok("firewall-cmd --get-zone-of-interface=astral", "public\n")
+            .ok(
+                "firewall-cmd --zone=trusted --add-interface=astral",
+                "success\n",
+            );
+        LinuxFirewall::with_ledger(&before, ledger.clone())
+            .allow_tun("astral")
+            .unwrap();
+        assert!(before.called("firewall-cmd --zone=trusted --add-interface=astral"));
+
+        let after = FakeRunner::default()
+            .ok("firewall-cmd --state", "running\n")
+            .ok("firewall-cmd --get-zone-of-interface=astral", "trusted\n")
+            .ok(
+                "firewall-cmd --zone=trusted --remove-interface=astral",
+                "success\n",
+            );
+        let fw = LinuxFirewall::with_ledger(&after, ledger);
+        fw.remove_tun("astral").unwrap();
+        fw.remove_tun("astral").unwrap();
+        assert_eq!(
+            after.count("firewall-cmd --zone=trusted --remove-interface=astral"),
+            1
+        );
+    }
+
+    #[test]
+    fn firewalld_port_is_removed_from_the_zone_it_was_added_to() {
+        let ledger = RuleLedger::default();
+        let before = FakeRunner::default()
+            .ok("firewall-cmd --state", "running\n")
+            .ok("firewall-cmd --get-default-zone", "public\n")
+            .ok(
+                "firewall-cmd --zone=public --add-port=11010/udp",
+                "success\n",
+            );
+        LinuxFirewall::with_ledger(&before, ledger.clone())
+            .allow_port("udp", 11010)
+            .unwrap();
+
+        // 默认 zone 之后被改成了 home。
+        let after = FakeRunner::default()
+            .ok("firewall-cmd --state", "running\n")
+            .ok("firewall-cmd --get-default-zone", "home\n")
+            .ok("firewall-cmd --zone=public --query-port=11010/udp", "yes\n")
+            .ok(
+                "firewall-cmd --zone=public --remove-port=11010/udp",
+                "success\n",
+            );
+        LinuxFirewall::with_ledger(&after, ledger)
+            .remove_port("udp", 11010)
+            .unwrap();
+        assert!(after.called("firewall-cmd --zone=public --remove-port=11010/udp"));
+    }
+
+    #[test]
+    fn firewalld_keeps_port_the_user_opened() {
+        let ledger = RuleLedger::default();
+        let runner = FakeRunner::default()
+            .ok("firewall-cmd --state", "running\n")
+            .ok("firewall-cmd --get-default-zone", "public\n")
+            .ok("firewall-cmd --zone=public --query-port=11010/tcp", "yes\n")
+            .ok(
+                "firewall-cmd --zone=public --remove-port=11010/tcp",
+                "success\n",
+            );
+        let fw = LinuxFirewall::with_ledger(&runner, ledger);
+        fw.allow_port("tcp", 11010).unwrap();
+        fw.remove_port("tcp", 11010).unwrap();
+        assert!(!runner.called("firewall-cmd --zone=public --add-port=11010/tcp"));
+        assert!(!runner.called("firewall-cmd --zone=public --remove-port=11010/tcp"));
+    }
+
+    #[test]
+    fn iptables_rules_carry_the_comment() {
+        let runner = FakeRunner::default()
+            .ok("iptables -S INPUT", "-P INPUT DROP\n")
+            .ok(
+                "iptables -I INPUT -i astral -m comment --comment astral-tun -j ACCEPT",
+                "",
+            )
+            .ok_times(
+                "iptables -D INPUT -i astral -m comment --comment astral-tun -j ACCEPT",
+                2,
+            );
+        let fw = LinuxFirewall::with_ledger(&runner, RuleLedger::default());
+        fw.allow_tun("astral").unwrap();
+        assert!(
+            runner.called("iptables -I INPUT -i astral -m comment --comment astral-tun -j ACCEPT")
+        );
+        fw.remove_tun("astral").unwrap();
+        // 重复插入过的也删干净，直到 -D 失败。
+        assert_eq!(
+            runner.count("iptables -D INPUT -i astral -m comment --comment astral-tun -j ACCEPT"),
+            3
+        );
+    }
+
+    #[test]
+    fn nft_deletes_only_tagged_rules() {
+        let ruleset = "table inet filter {\n\
+            \tchain input { # handle 1\n\
+            \t\ttype filter hook input priority filter; policy drop;\n\
+            \t\tiifname \"astral\" accept # handle 5\n\
+            \t\tiifname \"astral\" accept comment \"astral-tun\" # handle 7\n\
+            \t}\n\
+            }\n";
+        let runner = FakeRunner::default()
+            .ok("nft list ruleset", ruleset)
+            .ok("nft -a list ruleset", ruleset)
+            .ok("nft delete rule inet filter input handle 7", "");
+        let fw = LinuxFirewall::with_ledger(&runner, RuleLedger::default());
+        fw.remove_tun("astral").unwrap();
+        assert!(runner.called("nft delete rule inet filter input handle 7"));
+        assert!(!runner.called("nft delete rule inet filter input handle 5"));
+    }
+
+    #[test]
+    fn nft_inserts_into_the_input_chain() {
+        let ruleset = "table inet filter {\n\
+            \tchain input {\n\
+            \t\ttype filter hook input priority filter; policy drop;\n\
+            \t}\n\
+            }\n";
+        let insert =
+            "nft insert rule inet filter input udp dport 11010 accept comment \"astral-tun\"";
+        let runner = FakeRunner::default()
+            .ok("nft list ruleset", ruleset)
+            .ok("nft -a list ruleset", ruleset)
+            .ok(insert, "");
+        let fw = LinuxFirewall::with_ledger(&runner, RuleLedger::default());
+        fw.allow_port("udp", 11010).unwrap();
+        assert!(runner.called(insert));
+    }
+}
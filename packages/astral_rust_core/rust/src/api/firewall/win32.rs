@@ -0,0 +1,334 @@
+// Windows 防火墙的精确放行规则。
+//
+// 不再整体关掉某个 profile，而是在 Windows 防火墙里加入站 allow 规则：
+// - 虚拟网规则：本地和远端地址都限定为虚拟网网段，不限程序。游戏等本机进程
+//   收到的虚拟网入站流量都要放行，这条规则不能绑到 Astral 自己的可执行文件上；
+// - 监听端口规则：限定协议和本地端口，只放行当前可执行文件（监听的就是它）。
+// 规则分组为 "Astral"，方便用户在系统界面里识别。
+//
+// 诊断时除了 profile 级别的默认动作，还会枚举启用的入站 block 规则：Windows 上
+// block 规则优先于 allow 规则，命中的话加了放行规则也没用。
+
+use std::net::Ipv4Addr;
+
+use windows::{
+    core::{ComInterface, Result, BSTR},
+    Win32::Foundation::VARIANT_BOOL,
+    Win32::NetworkManagement::WindowsFirewall::{
+        INetFwPolicy2, INetFwRule, NetFwPolicy2, NetFwRule, NET_FW_ACTION_ALLOW,
+        NET_FW_ACTION_BLOCK, NET_FW_IP_PROTOCOL_ANY, NET_FW_IP_PROTOCOL_TCP,
+        NET_FW_IP_PROTOCOL_UDP, NET_FW_PROFILE2_ALL, NET_FW_PROFILE2_DOMAIN,
+        NET_FW_PROFILE2_PRIVATE, NET_FW_PROFILE2_PUBLIC, NET_FW_PROFILE_TYPE2, NET_FW_RULE_DIR_IN,
+    },
+    Win32::System::Com::{
+        CoCreateInstance, CoInitializeEx, IDispatch, CLSCTX_INPROC_SERVER, COINIT_APARTMENTTHREADED,
+    },
+    Win32::System::Ole::IEnumVARIANT,
+    Win32::System::Variant::{VariantClear, VARIANT, VT_DISPATCH},
+};
+
+use super::{FirewallProfile, RuleTarget};
+
+const RULE_GROUP: &str = "Astral";
+
+pub(crate) struct WindowsFirewall {
+    policy: INetFwPolicy2,
+}
+
+impl WindowsFirewall {
+    pub fn new() -> Result<Self> {
+        unsafe {
+            // 同 get_firewall_status：S_FALSE / RPC_E_CHANGED_MODE 都可以忽略
+            let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+            let policy: INetFwPolicy2 =
+                CoCreateInstance(&NetFwPolicy2, None, CLSCTX_INPROC_SERVER)?;
+            Ok(Self { policy })
+        }
+    }
+
+    pub fn is_enabled(&self, profile: FirewallProfile) -> Result<bool> {
+        Ok(unsafe { self.policy.get_FirewallEnabled(profile_type(profile))? }.as_bool())
+    }
+
+    pub fn set_enabled(&self, profile: FirewallProfile, enable: bool) -> Result<()> {
+        unsafe {
+            self.policy
+                .put_FirewallEnabled(profile_type(profile), VARIANT_BOOL::from(enable))
+        }
+    }
+
+    /// 当前生效的 profile 里是否有任何一个开着防火墙。
+    pub fn is_active(&self) -> Result<bool> {
+        for profile in self.current_profiles()? {
+            if self.is_enabled(profile)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// 当前网络正在使用的 profile。
+    pub fn current_profiles(&self) -> Result<Vec<FirewallProfile>> {
+        let current = unsafe { self.policy.get_CurrentProfileTypes()? };
+        Ok(FirewallProfile::ALL
+            .into_iter()
+            .filter(|p| current & profile_type(*p).0 != 0)
+            .collect())
+    }
+
+    /// 添加规则；同名规则已存在时先删掉再加，保证参数是最新的。
+    pub fn add_rule(&self, target: &RuleTarget) -> Result<()> {
+        let name = BSTR::from(target.rule_name());
+        unsafe {
+            let rules = self.policy.get_Rules()?;
+            if rules.Item(&name).is_ok() {
+                rules.Remove(&name)?;
+            }
+
+            let rule: INetFwRule = CoCreateInstance(&NetFwRule, None, CLSCTX_INPROC_SERVER)?;
+            rule.put_Name(&name)?;
+            rule.put_Grouping(&BSTR::from(RULE_GROUP))?;
+            rule.put_Description(&BSTR::from(target.describe()))?;
+            rule.put_Direction(NET_FW_RULE_DIR_IN)?;
+            rule.put_Action(NET_FW_ACTION_ALLOW)?;
+            rule.put_Profiles(NET_FW_PROFILE2_ALL.0)?;
+            match target {
+                RuleTarget::Overlay { cidr, .. } => {
+                    rule.put_LocalAddresses(&BSTR::from(cidr.as_str()))?;
+                    rule.put_RemoteAddresses(&BSTR::from(cidr.as_str()))?;
+                }
+                RuleTarget::Port { proto, port } => {
+                    if let Ok(exe) = std::env::current_exe() {
+                        rule.put_ApplicationName(&BSTR::from(exe.to_string_lossy().as_ref()))?;
+                    }
+                    let protocol = if proto == "udp" {
+                        NET_FW_IP_PROTOCOL_UDP
+                    } else {
+                        NET_FW_IP_PROTOCOL_TCP
+                    };
+                    rule.put_Protocol(protocol.0)?;
+                    rule.put_LocalPorts(&BSTR::from(port.to_string()))?;
+                }
+            }
+            rule.put_Enabled(VARIANT_BOOL::from(true))?;
+            rules.Add(&rule)
+        }
+    }
+
+    /// 删除规则；不存在时返回 `Ok`。
+    pub fn remove_rule(&self, target: &RuleTarget) -> Result<()> {
+        let name = BSTR::from(target.rule_name());
+        unsafe {
+            let rules = self.policy.get_Rules()?;
+            if rules.Item(&name).is_err() {
+                return Ok(());
+            }
+            rules.Remove(&name)
+        }
+    }
+
+    /// 规则存在且启用。
+    pub fn has_rule(&self, target: &RuleTarget) -> bool {
+        let name = BSTR::from(target.rule_name());
+        unsafe {
+            self.policy
+                .get_Rules()
+                .and_then(|rules| rules.Item(&name))
+                .and_then(|rule| rule.get_Enabled())
+                .map(|enabled| enabled.as_bool())
+                .unwrap_or(false)
+        }
+    }
+
+    /// 描述会拦住未放行流量的 profile 设置；都没在拦截时返回 `None`。
+    ///
+    /// "阻止所有入站连接" 会连 allow 规则一起忽略，所以单独返回 `overrides_rules = true`。
+    pub fn blocking_rule(&self) -> Result<Option<(String, bool)>> {
+        for profile in self.current_profiles()? {
+            if !self.is_enabled(profile)? {
+                continue;
+            }
+            let name = profile_name(profile);
+            let profile = profile_type(profile);
+            unsafe {
+                if self.policy.get_BlockAllInboundTraffic(profile)?.as_bool() {
+                    return Ok(Some((
+                        format!("{} profile: block all incoming connections", name),
+                        true,
+                    )));
+                }
+                if self.policy.get_DefaultInboundAction(profile)? == NET_FW_ACTION_BLOCK {
+                    return Ok(Some((
+                        format!("{} profile: default inbound action is block", name),
+                        false,
+                    )));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// 会拦住 `target` 的启用中的入站 block 规则名。
+    ///
+    /// 只看当前生效且开着防火墙的 profile；规则的接口类型、地址、协议、端口和
+    /// 程序都要覆盖到 `target` 才算命中。
+    pub fn block_rules(&self, target: &RuleTarget) -> Result<Vec<String>> {
+        let mut profiles = 0;
+        for profile in self.current_profiles()? {
+            if self.is_enabled(profile)? {
+                profiles |= profile_type(profile).0;
+            }
+        }
+        if profiles == 0 {
+            return Ok(Vec::new());
+        }
+        let exe = std::env::current_exe()
+            .map(|p| p.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let mut names = Vec::new();
+        unsafe {
+            let rules = self.policy.get_Rules()?;
+            let items: IEnumVARIANT = rules.get__NewEnum()?.cast()?;
+            loop {
+                let mut item = [VARIANT::default()];
+                let mut fetched = 0u32;
+                items.Next(&mut item, &mut fetched).ok()?;
+                if fetched == 0 {
+                    break;
+                }
+                let value = &item[0].Anonymous.Anonymous;
+                let rule = if value.vt == VT_DISPATCH {
+                    (*value.Anonymous.pdispVal)
+                        .as_ref()
+                        .and_then(|dispatch: &IDispatch| dispatch.cast::<INetFwRule>().ok())
+                } else {
+                    None
+                };
+                VariantClear(&mut item[0])?;
+                let Some(rule) = rule else {
+                    continue;
+                };
+                if rule.get_Enabled()?.as_bool()
+                    && rule.get_Direction()? == NET_FW_RULE_DIR_IN
+                    && rule.get_Action()? == NET_FW_ACTION_BLOCK
+                    && rule.get_Profiles()? & profiles != 0
+                    && block_rule_matches(&rule, target, &exe)?
+                {
+                    names.push(rule.get_Name()?.to_string());
+                }
+            }
+        }
+        Ok(names)
+    }
+}
+
+unsafe fn block_rule_matches(rule: &INetFwRule, target: &RuleTarget, exe: &str) -> Result<bool> {
+    if !interface_types_match(&rule.get_InterfaceTypes()?.to_string()) {
+        return Ok(false);
+    }
+    match target {
+        // 虚拟网流量发给本机任意进程，规则限定的程序不影响是否命中。
+        RuleTarget::Overlay { cidr, .. } => {
+            let Some(overlay) = parse_range(cidr) else {
+                return Ok(false);
+            };
+            Ok(
+                address_list_matches(&rule.get_LocalAddresses()?.to_string(), overlay)
+                    && address_list_matches(&rule.get_RemoteAddresses()?.to_string(), overlay),
+            )
+        }
+        RuleTarget::Port { proto, port } => {
+            let protocol = rule.get_Protocol()?;
+            if protocol == NET_FW_IP_PROTOCOL_ANY.0 {
+                return Ok(application_matches(
+                    &rule.get_ApplicationName()?.to_string(),
+                    exe,
+                ));
+            }
+            let wanted = if proto == "udp" {
+                NET_FW_IP_PROTOCOL_UDP
+            } else {
+                NET_FW_IP_PROTOCOL_TCP
+            };
+            Ok(protocol == wanted.0
+                && port_list_matches(&rule.get_LocalPorts()?.to_string(), *port)
+                && application_matches(&rule.get_ApplicationName()?.to_string(), exe))
+        }
+    }
+}
+
+/// TUN（wintun）网卡在防火墙里归为 LAN。
+fn interface_types_match(types: &str) -> bool {
+    let types = types.to_ascii_lowercase();
+    types.is_empty() || types.contains("all") || types.contains("lan")
+}
+
+/// 没限定程序，或限定的就是当前可执行文件。
+fn application_matches(app: &str, exe: &str) -> bool {
+    app.is_empty() || app.eq_ignore_ascii_case(exe)
+}
+
+/// 端口列表形如 `80,443,5000-5010`；`*` 或空串表示任意端口。
+fn port_list_matches(list: &str, port: u16) -> bool {
+    list.is_empty()
+        || list.split(',').map(str::trim).any(|entry| {
+            if entry == "*" {
+                return true;
+            }
+            match entry.split_once('-') {
+                Some((start, end)) => match (start.parse::<u16>(), end.parse::<u16>()) {
+                    (Ok(start), Ok(end)) => (start..=end).contains(&port),
+                    _ => false,
+                },
+                None => entry.parse::<u16>() == Ok(port),
+            }
+        })
+}
+
+/// 地址列表形如 `10.0.0.1,10.0.0.0/255.255.255.0,10.0.1.1-10.0.1.9`；`*` 或空串
+/// 表示任意地址。和虚拟网网段有交集就算命中；关键字（`LocalSubnet` 等）和 IPv6
+/// 地址不看。
+fn address_list_matches(list: &str, overlay: (u32, u32)) -> bool {
+    list.is_empty()
+        || list.split(',').map(str::trim).any(|entry| {
+            entry == "*"
+                || parse_range(entry)
+                    .is_some_and(|(start, end)| start <= overlay.1 && overlay.0 <= end)
+        })
+}
+
+/// 把 `a.b.c.d`、`a.b.c.d/len`、`a.b.c.d/mask` 或 `a-b` 解析成闭区间。
+fn parse_range(entry: &str) -> Option<(u32, u32)> {
+    let ip = |s: &str| s.trim().parse::<Ipv4Addr>().ok().map(u32::from);
+    if let Some((start, end)) = entry.split_once('-') {
+        return Some((ip(start)?, ip(end)?));
+    }
+    let Some((addr, mask)) = entry.split_once('/') else {
+        let addr = ip(entry)?;
+        return Some((addr, addr));
+    };
+    let mask = match mask.parse::<u32>() {
+        Ok(len) if len <= 32 => u32::MAX.checked_shl(32 - len).unwrap_or(0),
+        Ok(_) => return None,
+        Err(_) => ip(mask)?,
+    };
+    let start = ip(addr)? & mask;
+    Some((start, start | !mask))
+}
+
+fn profile_type(profile: FirewallProfile) -> NET_FW_PROFILE_TYPE2 {
+    match profile {
+        FirewallProfile::Domain => NET_FW_PROFILE2_DOMAIN,
+        FirewallProfile::Private => NET_FW_PROFILE2_PRIVATE,
+        FirewallProfile::Public => NET_FW_PROFILE2_PUBLIC,
+    }
+}
+
+fn profile_name(profile: FirewallProfile) -> &'static str {
+    match profile {
+        FirewallProfile::Domain => "domain",
+        FirewallProfile::Private => "private",
+        FirewallProfile::Public => "public",
+    }
+}
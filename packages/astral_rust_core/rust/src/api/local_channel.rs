@@ -0,0 +1,150 @@
+// 本机事件通道（127.0.0.1 UDP）的鉴权。
+//
+// 旧实现把事件明文发到固定的 127.0.0.1:9999，同机任何进程（包括其他用户的进程）
+// 都能抢先绑定端口读取，或者伪造事件发给 UI。现在：
+//
+// - 每次进程启动生成随机的 32 字节密钥和 session id，密钥只留在本进程内存里；
+// - 每条消息带 `session` / 单调递增的 `seq` / HMAC-SHA256 `mac`；
+// - 接收方（同进程里的 Dart）通过 [`verify_local_event`] 校验签名并做重放检查，
+//   别的进程、别的 app 实例发来的包都会被拒掉；
+// - Dart 可以先绑定随机端口再用 [`set_local_event_port`] 告诉 Rust，避免端口被抢占。
+//
+// 只做鉴权，不加密：消息体仍是明文 JSON，同机能抓 loopback 流量的进程照样能读到
+// 内容（日志里的地址、凭据已经过 `redact`）。不要通过这个通道传需要保密的数据。
+
+use std::net::UdpSocket;
+use std::sync::atomic::{AtomicU16, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use hmac::{Hmac, Mac};
+use lazy_static::lazy_static;
+use serde_json::{json, Value};
+use sha2::Sha256;
+use uuid::Uuid;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const DEFAULT_LOCAL_EVENT_PORT: u16 = 9999;
+/// 允许乱序到达的最大跨度（loopback 上基本不会乱序，这里只是兜住多任务并发发送）。
+const REPLAY_WINDOW: u64 = 64;
+
+lazy_static! {
+    static ref SESSION: String = Uuid::new_v4().simple().to_string();
+    static ref KEY: [u8; 32] = {
+        let mut key = [0u8; 32];
+        getrandom::getrandom(&mut key).expect("failed to generate local event key");
+        key
+    };
+    static ref REPLAY: Mutex<ReplayWindow> = Mutex::new(ReplayWindow::default());
+}
+
+static PORT: AtomicU16 = AtomicU16::new(DEFAULT_LOCAL_EVENT_PORT);
+static SEQ: AtomicU64 = AtomicU64::new(1);
+
+/// 校验通过的本机事件。
+#[derive(Debug, Clone)]
+pub struct LocalEventC {
+    /// 来源实例；不属于某个实例的消息为空串。
+    pub instance_id: String,
+    pub message: String,
+    pub seq: u64,
+}
+
+/// 设置事件接收端口。Dart 应先绑定 `127.0.0.1:0` 拿到随机端口再调用，
+/// 不调用时沿用默认的 9999。
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_local_event_port(port: u16) {
+    PORT.store(port, Ordering::Relaxed);
+}
+
+/// 本次启动的 session id，接收方可以据此快速丢弃其他进程的包。
+#[flutter_rust_bridge::frb(sync)]
+pub fn local_event_session() -> String {
+    SESSION.clone()
+}
+
+/// 校验收到的 UDP 包：session 必须是本进程的、签名正确、且不是重放。
+/// 任一条件不满足返回 `None`。
+#[flutter_rust_bridge::frb(sync)]
+pub fn verify_local_event(datagram: Vec<u8>) -> Option<LocalEventC> {
+    let value: Value = serde_json::from_slice(&datagram).ok()?;
+    let session = value.get("session")?.as_str()?;
+    let seq = value.get("seq")?.as_u64()?;
+    let instance_id = value.get("instance_id")?.as_str()?;
+    let message = value.get("message")?.as_str()?;
+    let mac = hex::decode(value.get("mac")?.as_str()?).ok()?;
+
+    if session != SESSION.as_str() {
+        return None;
+    }
+    mac_for(seq, instance_id, message).verify_slice(&mac).ok()?;
+    if !REPLAY.lock().unwrap().accept(seq) {
+        return None;
+    }
+
+    Some(LocalEventC {
+        instance_id: instance_id.to_string(),
+        message: message.to_string(),
+        seq,
+    })
+}
+
+/// 签名后发送一条本机事件。只绑定 loopback，不再从 `0.0.0.0` 发包。
+pub(crate) fn send(instance_id: &str, message: &str) -> Result<(), String> {
+    let seq = SEQ.fetch_add(1, Ordering::Relaxed);
+    let mac = hex::encode(mac_for(seq, instance_id, message).finalize().into_bytes());
+
+    let json_msg = json!({
+        "instance_id": instance_id,
+        "message": message,
+        "session": SESSION.as_str(),
+        "seq": seq,
+        "mac": mac,
+    });
+    let json_str =
+        serde_json::to_string(&json_msg).map_err(|e| format!("json serialize failed: {}", e))?;
+
+    let socket = UdpSocket::bind("127.0.0.1:0").map_err(|e| format!("udp bind failed: {}", e))?;
+    socket
+        .send_to(json_str.as_bytes(), ("127.0.0.1", PORT.load(Ordering::Relaxed)))
+        .map(|_| ())
+        .map_err(|e| format!("udp send failed: {}", e))
+}
+
+fn mac_for(seq: u64, instance_id: &str, message: &str) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(KEY.as_slice()).expect("hmac accepts any key length");
+    // session / seq / instance_id 都不含换行，message 放最后，拼接不会有歧义。
+    mac.update(SESSION.as_bytes());
+    mac.update(b"\n");
+    mac.update(seq.to_string().as_bytes());
+    mac.update(b"\n");
+    mac.update(instance_id.as_bytes());
+    mac.update(b"\n");
+    mac.update(message.as_bytes());
+    mac
+}
+
+/// IPsec 风格的滑动窗口：记录最近 [`REPLAY_WINDOW`] 个 seq 是否已收过。
+#[derive(Default)]
+struct ReplayWindow {
+    highest: u64,
+    seen: u64,
+}
+
+impl ReplayWindow {
+    fn accept(&mut self, seq: u64) -> bool {
+        if seq > self.highest {
+            let shift = seq - self.highest;
+            self.seen = if shift >= REPLAY_WINDOW { 0 } else { self.seen << shift };
+            self.seen |= 1;
+            self.highest = seq;
+            return true;
+        }
+        let offset = self.highest - seq;
+        if offset >= REPLAY_WINDOW || self.seen & (1 << offset) != 0 {
+            return false;
+        }
+        self.seen |= 1 << offset;
+        true
+    }
+}
@@ -0,0 +1,1043 @@
+// Chat messages with receipts, an offline queue and local history.
+//
+// Messages are small JSON objects sent as `Call`s on the reserved channel
+// [`MSG_CHANNEL`], so `astral`, `astral_game` and `astral_tv` all speak the
+// same protocol:
+//
+//   msg  { id, kind: "direct" | "room", text, sent_at_ms }   reply = delivered
+//   read { ids }                                             read receipt
+//
+// A successful reply is the delivery receipt. When a peer can't be reached
+// the message (or receipt) is queued in memory and sent again as soon as the
+// instance reports `PeerAdded` for that peer. Peer ids only live as long as
+// the network session, so the queue is not persisted.
+//
+// History is an append-only JSON lines file in a caller-supplied directory:
+// every change to a message appends its full current state and the last line
+// for an id wins. The file is compacted when it is loaded. Writing history
+// never holds up a message; a failed write is reported to Dart as a
+// `HistoryWriteFailed` event instead.
+
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use easytier::peers::astral_app_rpc as app_rpc;
+use futures::stream::{self, StreamExt};
+use lazy_static::lazy_static;
+use serde_json::{json, Value};
+use tokio::fs::{self, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::broadcast;
+
+use super::app_router::subscribe_inbound;
+use super::p2p::{
+    app_rpc_status, get_instance_info, lookup_app_rpc, runtime, subscribe_instance_events,
+    tracing_log_lagged, AppInboundEventC, AppInboundKindC, EventBusSubscriber, GlobalCtxEvent,
+};
+use crate::frb_generated::StreamSink;
+
+/// Reserved channel carrying messages and receipts.
+pub(crate) const MSG_CHANNEL: &str = "astral.msg";
+
+const HISTORY_FILE: &str = "messages.jsonl";
+/// Messages kept in memory and on disk; older ones are dropped on compaction.
+const MAX_HISTORY: usize = 2000;
+const CALL_TIMEOUT_MS: i32 = 5_000;
+/// Room sends in flight at once, like `app_broadcast`.
+const MAX_CONCURRENCY: usize = 8;
+/// Application status (positive, see `app_call_reply`) for a malformed
+/// message.
+const BAD_MESSAGE: i32 = 400;
+
+lazy_static! {
+    static ref MESSENGERS: Mutex<HashMap<String, Messenger>> = Mutex::new(HashMap::new());
+    static ref EVENTS: broadcast::Sender<(String, MessageEventC)> = broadcast::channel(256).0;
+}
+
+static GENERATION: AtomicU64 = AtomicU64::new(1);
+
+struct Messenger {
+    /// Generation of the running handler tasks.
+    generation: u64,
+    history_path: PathBuf,
+    messages: Vec<ChatMessageC>,
+    /// Ops waiting for a peer to come back, oldest first.
+    outbox: HashMap<u32, VecDeque<Value>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatMessageKindC {
+    Direct,
+    /// Sent to every peer of the network instance.
+    Room,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChatMessageC {
+    pub id: String,
+    pub kind: ChatMessageKindC,
+    pub from_peer_id: u32,
+    /// 0 for room messages.
+    pub to_peer_id: u32,
+    pub text: String,
+    pub sent_at_ms: u64,
+    /// Sent by this node.
+    pub outgoing: bool,
+    /// Peers that acknowledged the message.
+    pub delivered_to: Vec<u32>,
+    /// Peers that read it; for received messages, this node once marked read.
+    pub read_by: Vec<u32>,
+    /// Peers the message is queued for until they reappear.
+    pub queued_for: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageEventKindC {
+    Received,
+    /// The message was handed to the network (or queued, see `queued_for`).
+    Sent,
+    Delivered,
+    Read,
+    Queued,
+    /// Saving `message` to the history file failed; see `error`. The message
+    /// itself was still sent or received.
+    HistoryWriteFailed,
+}
+
+/// Flat struct rather than an enum with data, like `AppInboundEventC`.
+/// `message` is always the full current state.
+#[derive(Debug, Clone)]
+pub struct MessageEventC {
+    pub kind: MessageEventKindC,
+    /// The peer the event is about: sender for `Received`, recipient otherwise.
+    pub peer_id: u32,
+    pub message: ChatMessageC,
+    /// Why the history write failed; empty for every other kind.
+    pub error: String,
+}
+
+/// Start the messaging service for an instance, loading history from
+/// `history_dir`. Calling it again for the same instance is a no-op.
+pub async fn start_messaging(instance_id: String, history_dir: String) -> Result<(), String> {
+    let svc = lookup_app_rpc(&instance_id)?;
+    if MESSENGERS.lock().unwrap().contains_key(&instance_id) {
+        return Ok(());
+    }
+    let dir = PathBuf::from(history_dir);
+    fs::create_dir_all(&dir)
+        .await
+        .map_err(|e| format!("create history directory failed: {}", e))?;
+    let history_path = dir.join(HISTORY_FILE);
+    let messages = load_history(&history_path).await?;
+    let events = subscribe_instance_events(&instance_id)?;
+
+    let generation = GENERATION.fetch_add(1, Ordering::Relaxed);
+    {
+        let mut messengers = MESSENGERS.lock().unwrap();
+        if messengers.contains_key(&instance_id) {
+            return Ok(());
+        }
+        messengers.insert(
+            instance_id.clone(),
+            Messenger {
+                generation,
+                history_path,
+                messages,
+                outbox: HashMap::new(),
+            },
+        );
+    }
+    spawn_tasks(&instance_id, generation, &svc, events);
+    Ok(())
+}
+
+/// Send a message to one peer. Resolves once it is delivered or queued.
+pub async fn send_direct_message(
+    instance_id: String,
+    peer_id: u32,
+    text: String,
+) -> Result<ChatMessageC, String> {
+    let svc = lookup_app_rpc(&instance_id)?;
+    let message = new_message(&svc, ChatMessageKindC::Direct, peer_id, text);
+    record(&instance_id, message.clone()).await?;
+    emit(
+        &instance_id,
+        MessageEventKindC::Sent,
+        peer_id,
+        message.clone(),
+    );
+
+    // Awaited on the FRB executor like `app_call`; see the note there.
+    let op = message_op(&message);
+    let message = match call(&svc, peer_id, &op).await {
+        Ok(()) => mark_delivered(&instance_id, &message.id, peer_id).await,
+        Err(_) => enqueue(&instance_id, peer_id, op, Some(&message.id)).await,
+    };
+    message.ok_or_else(|| "messaging stopped".to_string())
+}
+
+/// Send a message to every peer currently in the route table. Peers that
+/// can't be reached get it queued; peers that join later don't get it.
+pub async fn send_room_message(instance_id: String, text: String) -> Result<ChatMessageC, String> {
+    let svc = lookup_app_rpc(&instance_id)?;
+    let my_peer_id = svc.my_peer_id();
+    let info = get_instance_info(&instance_id).await?;
+    let mut targets: Vec<u32> = info
+        .routes
+        .iter()
+        .map(|r| r.peer_id)
+        .filter(|id| *id != my_peer_id)
+        .collect();
+    targets.sort_unstable();
+    targets.dedup();
+
+    let message = new_message(&svc, ChatMessageKindC::Room, 0, text);
+    record(&instance_id, message.clone()).await?;
+    emit(&instance_id, MessageEventKindC::Sent, 0, message.clone());
+
+    let op = message_op(&message);
+    let results: Vec<(u32, bool)> = stream::iter(targets)
+        .map(|peer_id| {
+            let svc = svc.clone();
+            let op = &op;
+            async move { (peer_id, call(&svc, peer_id, op).await.is_ok()) }
+        })
+        .buffer_unordered(MAX_CONCURRENCY)
+        .collect()
+        .await;
+    let mut latest = Some(message.clone());
+    for (peer_id, delivered) in results {
+        latest = if delivered {
+            mark_delivered(&instance_id, &message.id, peer_id).await
+        } else {
+            enqueue(&instance_id, peer_id, op.clone(), Some(&message.id)).await
+        };
+    }
+    latest.ok_or_else(|| "messaging stopped".to_string())
+}
+
+/// Mark received messages as read and send read receipts to their senders.
+/// Unknown ids and messages already read are ignored.
+pub async fn mark_messages_read(
+    instance_id: String,
+    message_ids: Vec<String>,
+) -> Result<(), String> {
+    let svc = lookup_app_rpc(&instance_id)?;
+    let my_peer_id = svc.my_peer_id();
+    let mut by_sender: HashMap<u32, Vec<String>> = HashMap::new();
+    let mut changed = Vec::new();
+    {
+        let mut messengers = MESSENGERS.lock().unwrap();
+        let messenger = messengers
+            .get_mut(&instance_id)
+            .ok_or("messaging not started")?;
+        for m in messenger.messages.iter_mut() {
+            if m.outgoing || !message_ids.contains(&m.id) || !m.read_by.is_empty() {
+                continue;
+            }
+            m.read_by.push(my_peer_id);
+            by_sender
+                .entry(m.from_peer_id)
+                .or_default()
+                .push(m.id.clone());
+            changed.push(m.clone());
+        }
+    }
+    for message in changed {
+        persist(&instance_id, &message).await;
+    }
+    for (peer_id, ids) in by_sender {
+        let op = json!({ "op": "read", "ids": ids });
+        if call(&svc, peer_id, &op).await.is_err() {
+            enqueue(&instance_id, peer_id, op, None).await;
+        }
+    }
+    Ok(())
+}
+
+/// Stored messages, oldest first. `limit` 0 returns everything.
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_messages(instance_id: String, limit: u32) -> Vec<ChatMessageC> {
+    let messengers = MESSENGERS.lock().unwrap();
+    let Some(messenger) = messengers.get(&instance_id) else {
+        return Vec::new();
+    };
+    let skip = match limit as usize {
+        0 => 0,
+        n => messenger.messages.len().saturating_sub(n),
+    };
+    messenger.messages[skip..].to_vec()
+}
+
+/// Incoming messages and status changes of outgoing ones.
+pub async fn subscribe_messages(
+    instance_id: String,
+    sink: StreamSink<MessageEventC>,
+) -> Result<(), String> {
+    let mut events = EVENTS.subscribe();
+    loop {
+        match events.recv().await {
+            Ok((id, event)) => {
+                if id == instance_id && sink.add(event).is_err() {
+                    break;
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                tracing_log_lagged(&instance_id, skipped);
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+    Ok(())
+}
+
+/// Whether the channel router must leave `channel` to this module.
+pub(crate) fn is_handled(instance_id: &str, channel: &str) -> bool {
+    channel == MSG_CHANNEL && MESSENGERS.lock().unwrap().contains_key(instance_id)
+}
+
+/// Restart the handler tasks for a restarted instance, keeping history. The
+/// outbox is dropped since peer ids don't survive the restart.
+pub(crate) fn watch_instance(instance_id: &str) {
+    let Ok(svc) = lookup_app_rpc(instance_id) else {
+        return;
+    };
+    let Ok(events) = subscribe_instance_events(instance_id) else {
+        return;
+    };
+    let generation = {
+        let mut messengers = MESSENGERS.lock().unwrap();
+        let Some(messenger) = messengers.get_mut(instance_id) else {
+            return;
+        };
+        messenger.generation = GENERATION.fetch_add(1, Ordering::Relaxed);
+        messenger.outbox.clear();
+        for m in messenger.messages.iter_mut() {
+            m.queued_for.clear();
+        }
+        messenger.generation
+    };
+    spawn_tasks(instance_id, generation, &svc, events);
+}
+
+pub(crate) fn forget_instance(instance_id: &str) {
+    MESSENGERS.lock().unwrap().remove(instance_id);
+}
+
+fn spawn_tasks(
+    instance_id: &str,
+    generation: u64,
+    svc: &app_rpc::AstralAppRpcService,
+    mut events: EventBusSubscriber,
+) {
+    let rx = subscribe_inbound(instance_id, svc);
+    runtime().spawn(handle_inbound(instance_id.to_string(), generation, rx));
+
+    let instance_id = instance_id.to_string();
+    runtime().spawn(async move {
+        loop {
+            let peer_id = match events.recv().await {
+                Ok(GlobalCtxEvent::PeerAdded(peer_id)) => peer_id,
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+            if !is_current(&instance_id, generation) {
+                break;
+            }
+            flush(&instance_id, peer_id).await;
+        }
+    });
+}
+
+fn is_current(instance_id: &str, generation: u64) -> bool {
+    MESSENGERS
+        .lock()
+        .unwrap()
+        .get(instance_id)
+        .is_some_and(|m| m.generation == generation)
+}
+
+/// Answer messages and receipts until the instance stops. The service is
+/// looked up per reply rather than held, like the channel router.
+async fn handle_inbound(
+    instance_id: String,
+    generation: u64,
+    mut rx: broadcast::Receiver<AppInboundEventC>,
+) {
+    loop {
+        let evt = match rx.recv().await {
+            Ok(evt) => evt,
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                tracing_log_lagged(&instance_id, skipped);
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+        let AppInboundEventC {
+            kind: AppInboundKindC::Call,
+            from_peer_id,
+            channel,
+            token,
+            payload,
+            ..
+        } = evt
+        else {
+            continue;
+        };
+        if channel != MSG_CHANNEL {
+            continue;
+        }
+        if !is_current(&instance_id, generation) {
+            return;
+        }
+        let (status, error) = match serde_json::from_slice::<Value>(&payload) {
+            Ok(msg) => match handle_message(&instance_id, from_peer_id, &msg).await {
+                Ok(()) => (app_rpc_status::OK, String::new()),
+                Err(e) => (BAD_MESSAGE, e),
+            },
+            Err(_) => (BAD_MESSAGE, "malformed message".to_string()),
+        };
+        if let Ok(svc) = lookup_app_rpc(&instance_id) {
+            svc.reply_call(token, status, error, Vec::new());
+        }
+    }
+}
+
+async fn handle_message(instance_id: &str, from_peer_id: u32, msg: &Value) -> Result<(), String> {
+    match msg["op"].as_str().unwrap_or_default() {
+        "msg" => {
+            let id = msg["id"].as_str().unwrap_or_default();
+            if id.is_empty() {
+                return Err("message without id".to_string());
+            }
+            let kind = match msg["kind"].as_str() {
+                Some("room") => ChatMessageKindC::Room,
+                _ => ChatMessageKindC::Direct,
+            };
+            let message = ChatMessageC {
+                id: id.to_string(),
+                kind,
+                from_peer_id,
+                to_peer_id: match kind {
+                    ChatMessageKindC::Direct => lookup_app_rpc(instance_id)
+                        .map(|svc| svc.my_peer_id())
+                        .unwrap_or_default(),
+                    ChatMessageKindC::Room => 0,
+                },
+                text: msg["text"].as_str().unwrap_or_default().to_string(),
+                sent_at_ms: msg["sent_at_ms"].as_u64().unwrap_or_else(now_ms),
+                outgoing: false,
+                delivered_to: Vec::new(),
+                read_by: Vec::new(),
+                queued_for: Vec::new(),
+            };
+            // A retry after a lost reply delivers the same id again; ack it
+            // without storing a duplicate. Ids are only unique per sender.
+            let known = MESSENGERS
+                .lock()
+                .unwrap()
+                .get(instance_id)
+                .is_some_and(|m| {
+                    m.messages.iter().any(|m| {
+                        !m.outgoing && m.from_peer_id == from_peer_id && m.id == message.id
+                    })
+                });
+            if !known {
+                record(instance_id, message.clone()).await?;
+                emit(
+                    instance_id,
+                    MessageEventKindC::Received,
+                    from_peer_id,
+                    message,
+                );
+            }
+            Ok(())
+        }
+        "read" => {
+            let ids: Vec<&str> = msg["ids"]
+                .as_array()
+                .map(|ids| ids.iter().filter_map(|id| id.as_str()).collect())
+                .unwrap_or_default();
+            for id in ids {
+                let message = update(instance_id, id, |m| {
+                    // Only the recipient can read a direct message.
+                    if m.kind == ChatMessageKindC::Direct && m.to_peer_id != from_peer_id {
+                        return false;
+                    }
+                    if m.read_by.contains(&from_peer_id) {
+                        return false;
+                    }
+                    m.read_by.push(from_peer_id);
+                    if !m.delivered_to.contains(&from_peer_id) {
+                        m.delivered_to.push(from_peer_id);
+                    }
+                    true
+                })
+                .await;
+                if let Some(message) = message {
+                    emit(instance_id, MessageEventKindC::Read, from_peer_id, message);
+                }
+            }
+            Ok(())
+        }
+        op => Err(format!("unknown message op: {}", op)),
+    }
+}
+
+/// Send everything queued for `peer_id`, stopping at the first failure.
+async fn flush(instance_id: &str, peer_id: u32) {
+    let Ok(svc) = lookup_app_rpc(instance_id) else {
+        return;
+    };
+    loop {
+        let op = {
+            let messengers = MESSENGERS.lock().unwrap();
+            let Some(op) = messengers
+                .get(instance_id)
+                .and_then(|m| m.outbox.get(&peer_id))
+                .and_then(|q| q.front())
+            else {
+                return;
+            };
+            op.clone()
+        };
+        if call(&svc, peer_id, &op).await.is_err() {
+            return;
+        }
+        {
+            let mut messengers = MESSENGERS.lock().unwrap();
+            if let Some(queue) = messengers
+                .get_mut(instance_id)
+                .and_then(|m| m.outbox.get_mut(&peer_id))
+            {
+                queue.pop_front();
+            }
+        }
+        if op["op"] == "msg" {
+            if let Some(id) = op["id"].as_str() {
+                mark_delivered(instance_id, id, peer_id).await;
+            }
+        }
+    }
+}
+
+/// Queue `op` for `peer_id`; with a message id, record the message as queued
+/// for that peer and return its new state.
+async fn enqueue(
+    instance_id: &str,
+    peer_id: u32,
+    op: Value,
+    message_id: Option<&str>,
+) -> Option<ChatMessageC> {
+    {
+        let mut messengers = MESSENGERS.lock().unwrap();
+        let messenger = messengers.get_mut(instance_id)?;
+        messenger.outbox.entry(peer_id).or_default().push_back(op);
+    }
+    let id = message_id?;
+    let message = update(instance_id, id, |m| {
+        if m.queued_for.contains(&peer_id) {
+            return false;
+        }
+        m.queued_for.push(peer_id);
+        true
+    })
+    .await;
+    if let Some(message) = &message {
+        emit(
+            instance_id,
+            MessageEventKindC::Queued,
+            peer_id,
+            message.clone(),
+        );
+    }
+    message.or_else(|| find(instance_id, id))
+}
+
+async fn mark_delivered(instance_id: &str, id: &str, peer_id: u32) -> Option<ChatMessageC> {
+    let message = update(instance_id, id, |m| {
+        m.queued_for.retain(|p| *p != peer_id);
+        if m.delivered_to.contains(&peer_id) {
+            return false;
+        }
+        m.delivered_to.push(peer_id);
+        true
+    })
+    .await;
+    if let Some(message) = &message {
+        emit(
+            instance_id,
+            MessageEventKindC::Delivered,
+            peer_id,
+            message.clone(),
+        );
+    }
+    message.or_else(|| find(instance_id, id))
+}
+
+fn find(instance_id: &str, id: &str) -> Option<ChatMessageC> {
+    MESSENGERS
+        .lock()
+        .unwrap()
+        .get(instance_id)?
+        .messages
+        .iter()
+        .find(|m| m.outgoing && m.id == id)
+        .cloned()
+}
+
+/// Apply `f` to a stored outgoing message and persist it if `f` reports a
+/// change. Returns the new state only when something changed.
+async fn update(
+    instance_id: &str,
+    id: &str,
+    f: impl FnOnce(&mut ChatMessageC) -> bool,
+) -> Option<ChatMessageC> {
+    let message = {
+        let mut messengers = MESSENGERS.lock().unwrap();
+        let message = messengers
+            .get_mut(instance_id)?
+            .messages
+            .iter_mut()
+            .find(|m| m.outgoing && m.id == id)?;
+        if !f(message) {
+            return None;
+        }
+        message.clone()
+    };
+    persist(instance_id, &message).await;
+    Some(message)
+}
+
+/// Add a new message to memory and history.
+async fn record(instance_id: &str, message: ChatMessageC) -> Result<(), String> {
+    {
+        let mut messengers = MESSENGERS.lock().unwrap();
+        let messenger = messengers
+            .get_mut(instance_id)
+            .ok_or("messaging not started")?;
+        messenger.messages.push(message.clone());
+        if messenger.messages.len() > MAX_HISTORY {
+            let excess = messenger.messages.len() - MAX_HISTORY;
+            messenger.messages.drain(..excess);
+        }
+    }
+    persist(instance_id, &message).await;
+    Ok(())
+}
+
+/// Append the message's current state to the history file. History is best
+/// effort: a failed write is reported as a `HistoryWriteFailed` event and the
+/// message still goes out.
+async fn persist(instance_id: &str, message: &ChatMessageC) {
+    let Some(path) = MESSENGERS
+        .lock()
+        .unwrap()
+        .get(instance_id)
+        .map(|m| m.history_path.clone())
+    else {
+        return;
+    };
+    let mut line = message_json(message).to_string();
+    line.push('\n');
+    let result = async {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .await?;
+        file.write_all(line.as_bytes()).await
+    }
+    .await;
+    if let Err(e) = result {
+        let _ = EVENTS.send((
+            instance_id.to_string(),
+            MessageEventC {
+                kind: MessageEventKindC::HistoryWriteFailed,
+                peer_id: 0,
+                message: message.clone(),
+                error: format!("write message history failed: {}", e),
+            },
+        ));
+    }
+}
+
+/// Read the history file, keeping the last state per message in first-seen
+/// order, and rewrite it compactly when it has grown. A message is its id
+/// together with its sender, since ids are only unique per sender.
+async fn load_history(path: &Path) -> Result<Vec<ChatMessageC>, String> {
+    let content = match fs::read_to_string(path).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("read message history failed: {}", e)),
+    };
+    let mut order: Vec<(bool, u32, String)> = Vec::new();
+    let mut latest: HashMap<(bool, u32, String), ChatMessageC> = HashMap::new();
+    let mut lines = 0;
+    for line in content.lines() {
+        lines += 1;
+        // A torn last line from a crash is skipped.
+        let Some(message) = serde_json::from_str(line).ok().and_then(parse_message) else {
+            continue;
+        };
+        let key = (message.outgoing, message.from_peer_id, message.id.clone());
+        if !latest.contains_key(&key) {
+            order.push(key.clone());
+        }
+        latest.insert(key, message);
+    }
+    let skip = order.len().saturating_sub(MAX_HISTORY);
+    let messages: Vec<ChatMessageC> = order
+        .iter()
+        .skip(skip)
+        .filter_map(|key| latest.remove(key))
+        .map(|mut m| {
+            // The outbox didn't survive; see the module note.
+            m.queued_for.clear();
+            m
+        })
+        .collect();
+
+    if lines > messages.len() {
+        let mut compact = String::new();
+        for m in &messages {
+            compact.push_str(&message_json(m).to_string());
+            compact.push('\n');
+        }
+        let tmp = path.with_extension("jsonl.tmp");
+        fs::write(&tmp, compact)
+            .await
+            .map_err(|e| format!("write message history failed: {}", e))?;
+        fs::rename(&tmp, path)
+            .await
+            .map_err(|e| format!("write message history failed: {}", e))?;
+    }
+    Ok(messages)
+}
+
+fn new_message(
+    svc: &app_rpc::AstralAppRpcService,
+    kind: ChatMessageKindC,
+    to_peer_id: u32,
+    text: String,
+) -> ChatMessageC {
+    ChatMessageC {
+        id: uuid::Uuid::new_v4().to_string(),
+        kind,
+        from_peer_id: svc.my_peer_id(),
+        to_peer_id,
+        text,
+        sent_at_ms: now_ms(),
+        outgoing: true,
+        delivered_to: Vec::new(),
+        read_by: Vec::new(),
+        queued_for: Vec::new(),
+    }
+}
+
+fn message_op(message: &ChatMessageC) -> Value {
+    json!({
+        "op": "msg",
+        "id": message.id,
+        "kind": kind_str(message.kind),
+        "text": message.text,
+        "sent_at_ms": message.sent_at_ms,
+    })
+}
+
+fn message_json(message: &ChatMessageC) -> Value {
+    json!({
+        "id": message.id,
+        "kind": kind_str(message.kind),
+        "from": message.from_peer_id,
+        "to": message.to_peer_id,
+        "text": message.text,
+        "sent_at_ms": message.sent_at_ms,
+        "outgoing": message.outgoing,
+        "delivered_to": message.delivered_to,
+        "read_by": message.read_by,
+    })
+}
+
+fn parse_message(v: Value) -> Option<ChatMessageC> {
+    let peers = |key: &str| -> Vec<u32> {
+        v[key]
+            .as_array()
+            .map(|a| {
+                a.iter()
+                    .filter_map(|p| p.as_u64().map(|p| p as u32))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    Some(ChatMessageC {
+        id: v["id"].as_str()?.to_string(),
+        kind: match v["kind"].as_str()? {
+            "room" => ChatMessageKindC::Room,
+            _ => ChatMessageKindC::Direct,
+        },
+        from_peer_id: v["from"].as_u64()? as u32,
+        to_peer_id: v["to"].as_u64().unwrap_or_default() as u32,
+        text: v["text"].as_str().unwrap_or_default().to_string(),
+        sent_at_ms: v["sent_at_ms"].as_u64().unwrap_or_default(),
+        outgoing: v["outgoing"].as_bool().unwrap_or_default(),
+        delivered_to: peers("delivered_to"),
+        read_by: peers("read_by"),
+        queued_for: Vec::new(),
+    })
+}
+
+fn kind_str(kind: ChatMessageKindC) -> &'static str {
+    match kind {
+        ChatMessageKindC::Direct => "direct",
+        ChatMessageKindC::Room => "room",
+    }
+}
+
+async fn call(
+    svc: &app_rpc::AstralAppRpcService,
+    dst_peer_id: u32,
+    op: &Value,
+) -> Result<(), String> {
+    let resp = svc
+        .call(
+            dst_peer_id,
+            MSG_CHANNEL.to_string(),
+            0,
+            op.to_string().into_bytes(),
+            0,
+            CALL_TIMEOUT_MS,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    if resp.status != app_rpc_status::OK {
+        return Err(format!("status {} {}", resp.status, resp.error_msg));
+    }
+    Ok(())
+}
+
+fn emit(instance_id: &str, kind: MessageEventKindC, peer_id: u32, message: ChatMessageC) {
+    let _ = EVENTS.send((
+        instance_id.to_string(),
+        MessageEventC {
+            kind,
+            peer_id,
+            message,
+            error: String::new(),
+        },
+    ));
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Register a messenger for a made-up instance id, as `start_messaging`
+    /// would, without an app RPC service behind it.
+    fn start(instance_id: &str, history_path: PathBuf) {
+        MESSENGERS.lock().unwrap().insert(
+            instance_id.to_string(),
+            Messenger {
+                generation: GENERATION.fetch_add(1, Ordering::Relaxed),
+                history_path,
+                messages: Vec::new(),
+                outbox: HashMap::new(),
+            },
+        );
+    }
+
+    fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("astral-msg-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn outgoing(id: &str, to_peer_id: u32) -> ChatMessageC {
+        ChatMessageC {
+            id: id.to_string(),
+            kind: ChatMessageKindC::Direct,
+            from_peer_id: 1,
+            to_peer_id,
+            text: format!("text of {}", id),
+            sent_at_ms: 1_700_000_000_000,
+            outgoing: true,
+            delivered_to: Vec::new(),
+            read_by: Vec::new(),
+            queued_for: Vec::new(),
+        }
+    }
+
+    fn next_event(
+        events: &mut broadcast::Receiver<(String, MessageEventC)>,
+        instance_id: &str,
+    ) -> MessageEventC {
+        loop {
+            let (id, event) = events.try_recv().expect("event emitted");
+            if id == instance_id {
+                return event;
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn read_receipt_marks_outgoing_message() {
+        let dir = temp_dir();
+        let instance_id = "msg-test-receipt";
+        start(instance_id, dir.join(HISTORY_FILE));
+        record(instance_id, outgoing("m1", 7)).await.unwrap();
+        let mut events = EVENTS.subscribe();
+
+        let receipt = json!({ "op": "read", "ids": ["m1", "unknown"] });
+        handle_message(instance_id, 7, &receipt).await.unwrap();
+        let event = next_event(&mut events, instance_id);
+        assert_eq!(event.kind, MessageEventKindC::Read);
+        assert_eq!(event.peer_id, 7);
+        assert_eq!(event.message.read_by, vec![7]);
+        // A read receipt implies delivery.
+        assert_eq!(event.message.delivered_to, vec![7]);
+
+        // A repeated receipt changes nothing and emits nothing.
+        handle_message(instance_id, 7, &receipt).await.unwrap();
+        assert!(events.try_recv().is_err());
+
+        forget_instance(instance_id);
+        let _ = fs::remove_dir_all(&dir).await;
+    }
+
+    #[tokio::test]
+    async fn read_receipt_from_other_peer_is_ignored() {
+        let dir = temp_dir();
+        let instance_id = "msg-test-receipt-other";
+        start(instance_id, dir.join(HISTORY_FILE));
+        record(instance_id, outgoing("m1", 7)).await.unwrap();
+        let mut events = EVENTS.subscribe();
+
+        let receipt = json!({ "op": "read", "ids": ["m1"] });
+        handle_message(instance_id, 8, &receipt).await.unwrap();
+        assert!(events.try_recv().is_err());
+        assert!(find(instance_id, "m1").unwrap().read_by.is_empty());
+
+        forget_instance(instance_id);
+        let _ = fs::remove_dir_all(&dir).await;
+    }
+
+    #[tokio::test]
+    async fn same_message_id_from_two_peers_is_kept_twice() {
+        let dir = temp_dir();
+        let instance_id = "msg-test-same-id";
+        start(instance_id, dir.join(HISTORY_FILE));
+        record(instance_id, outgoing("m1", 7)).await.unwrap();
+
+        let msg = json!({ "op": "msg", "id": "m1", "kind": "direct", "text": "hi" });
+        handle_message(instance_id, 7, &msg).await.unwrap();
+        handle_message(instance_id, 8, &msg).await.unwrap();
+        // A retry from the same peer is not stored again.
+        handle_message(instance_id, 8, &msg).await.unwrap();
+
+        let received: Vec<u32> = MESSENGERS.lock().unwrap()[instance_id]
+            .messages
+            .iter()
+            .filter(|m| !m.outgoing)
+            .map(|m| m.from_peer_id)
+            .collect();
+        assert_eq!(received, vec![7, 8]);
+        let history = load_history(&dir.join(HISTORY_FILE)).await.unwrap();
+        assert_eq!(history.len(), 3);
+
+        forget_instance(instance_id);
+        let _ = fs::remove_dir_all(&dir).await;
+    }
+
+    #[tokio::test]
+    async fn queued_message_is_delivered_later() {
+        let dir = temp_dir();
+        let instance_id = "msg-test-queue";
+        start(instance_id, dir.join(HISTORY_FILE));
+        let message = outgoing("m1", 9);
+        record(instance_id, message.clone()).await.unwrap();
+        let mut events = EVENTS.subscribe();
+
+        let queued = enqueue(instance_id, 9, message_op(&message), Some("m1"))
+            .await
+            .unwrap();
+        assert_eq!(queued.queued_for, vec![9]);
+        assert_eq!(
+            next_event(&mut events, instance_id).kind,
+            MessageEventKindC::Queued
+        );
+        let queue_len = MESSENGERS.lock().unwrap()[instance_id].outbox[&9].len();
+        assert_eq!(queue_len, 1);
+
+        let delivered = mark_delivered(instance_id, "m1", 9).await.unwrap();
+        assert!(delivered.queued_for.is_empty());
+        assert_eq!(delivered.delivered_to, vec![9]);
+        assert_eq!(
+            next_event(&mut events, instance_id).kind,
+            MessageEventKindC::Delivered
+        );
+
+        forget_instance(instance_id);
+        let _ = fs::remove_dir_all(&dir).await;
+    }
+
+    #[tokio::test]
+    async fn history_keeps_latest_state_and_compacts() {
+        let dir = temp_dir();
+        let path = dir.join(HISTORY_FILE);
+        let instance_id = "msg-test-history";
+        start(instance_id, path.clone());
+        record(instance_id, outgoing("m1", 3)).await.unwrap();
+        record(instance_id, outgoing("m2", 3)).await.unwrap();
+        enqueue(instance_id, 3, json!({}), Some("m2")).await;
+        mark_delivered(instance_id, "m1", 3).await;
+        forget_instance(instance_id);
+
+        // A torn line from a crash mid-write is skipped.
+        let mut file = OpenOptions::new().append(true).open(&path).await.unwrap();
+        file.write_all(b"{\"id\":\"m3\",\"ki").await.unwrap();
+        drop(file);
+
+        let loaded = load_history(&path).await.unwrap();
+        let ids: Vec<&str> = loaded.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["m1", "m2"]);
+        assert_eq!(loaded[0].delivered_to, vec![3]);
+        assert_eq!(loaded[0].text, "text of m1");
+        // The outbox is not persisted.
+        assert!(loaded[1].queued_for.is_empty());
+        let lines = fs::read_to_string(&path).await.unwrap().lines().count();
+        assert_eq!(lines, 2);
+
+        let _ = fs::remove_dir_all(&dir).await;
+    }
+
+    #[tokio::test]
+    async fn failed_history_write_is_reported() {
+        let dir = temp_dir();
+        let instance_id = "msg-test-write-error";
+        // A directory where the history file should be makes every append fail.
+        start(instance_id, dir.clone());
+        let mut events = EVENTS.subscribe();
+
+        record(instance_id, outgoing("m1", 5)).await.unwrap();
+        let event = next_event(&mut events, instance_id);
+        assert_eq!(event.kind, MessageEventKindC::HistoryWriteFailed);
+        assert_eq!(event.message.id, "m1");
+        assert!(!event.error.is_empty());
+        // The message is kept in memory regardless.
+        assert_eq!(list_messages(instance_id.to_string(), 0).len(), 1);
+
+        forget_instance(instance_id);
+        let _ = fs::remove_dir_all(&dir).await;
+    }
+}
@@ -1,3 +1,25 @@
 pub mod simple;
 pub mod p2p;
-pub mod firewall;
\ No newline at end of file
+pub mod firewall;
+pub mod redact;
+pub mod local_channel;
+pub mod credential;
+pub mod port_forward;
+pub mod proxy_cidr;
+pub mod diagnostics;
+pub mod server_probe;
+pub mod server_selection;
+pub mod nat;
+pub mod peer_control;
+pub mod endpoints;
+pub mod port_mapping;
+pub mod app_router;
+pub mod app_codec;
+pub mod app_broadcast;
+pub mod app_stream;
+pub mod file_transfer;
+pub mod messaging;
+pub mod presence;
+pub mod app_policy;
+pub mod app_metrics;
+pub mod app_call_control;
@@ -0,0 +1,308 @@
+// 不依赖实例的 NAT 类型检测。
+//
+// 以前只能在连上其他节点后从 `get_udp_nat_type()` 拿到字符串。这里直接跑 EasyTier 的
+// STUN 检测（UDP + TCP），返回类型化的 NAT 类型、公网映射地址和端口映射行为，并按
+// 对端的每种 NAT 类型给出 P2P 直连的可能性，加入房间前就能提示 "你的网络：对称型 NAT"。
+
+use std::time::{Duration, Instant};
+
+use easytier::common::stun::{StunInfoCollector, StunInfoCollectorTrait};
+use easytier::proto::common::NatType;
+
+/// 检测的最长等待时间，STUN 服务器全部不可达时超时报错。
+const DETECT_TIMEOUT: Duration = Duration::from_secs(10);
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NatTypeC {
+    Unknown,
+    OpenInternet,
+    NoPat,
+    FullCone,
+    Restricted,
+    PortRestricted,
+    Symmetric,
+    SymUdpFirewall,
+    SymmetricEasyInc,
+    SymmetricEasyDec,
+}
+
+impl NatTypeC {
+    const ALL: [NatTypeC; 10] = [
+        NatTypeC::Unknown,
+        NatTypeC::OpenInternet,
+        NatTypeC::NoPat,
+        NatTypeC::FullCone,
+        NatTypeC::Restricted,
+        NatTypeC::PortRestricted,
+        NatTypeC::Symmetric,
+        NatTypeC::SymUdpFirewall,
+        NatTypeC::SymmetricEasyInc,
+        NatTypeC::SymmetricEasyDec,
+    ];
+
+    pub(crate) fn from_raw(raw: i32) -> Self {
+        match NatType::try_from(raw).unwrap_or(NatType::Unknown) {
+            NatType::OpenInternet => NatTypeC::OpenInternet,
+            NatType::NoPat => NatTypeC::NoPat,
+            NatType::FullCone => NatTypeC::FullCone,
+            NatType::Restricted => NatTypeC::Restricted,
+            NatType::PortRestricted => NatTypeC::PortRestricted,
+            NatType::Symmetric => NatTypeC::Symmetric,
+            NatType::SymUdpFirewall => NatTypeC::SymUdpFirewall,
+            NatType::SymmetricEasyInc => NatTypeC::SymmetricEasyInc,
+            NatType::SymmetricEasyDec => NatTypeC::SymmetricEasyDec,
+            _ => NatTypeC::Unknown,
+        }
+    }
+
+    fn is_open(self) -> bool {
+        matches!(self, NatTypeC::OpenInternet | NatTypeC::NoPat)
+    }
+
+    fn is_cone(self) -> bool {
+        matches!(
+            self,
+            NatTypeC::FullCone | NatTypeC::Restricted | NatTypeC::PortRestricted
+        )
+    }
+
+    /// 端口变化有规律，可以预测。
+    fn is_easy_symmetric(self) -> bool {
+        matches!(
+            self,
+            NatTypeC::SymmetricEasyInc | NatTypeC::SymmetricEasyDec
+        )
+    }
+}
+
+/// 端口映射行为（RFC 4787 的 mapping behavior）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortMappingC {
+    Unknown,
+    /// 没有 NAT，公网地址就是本机地址。
+    NoTranslation,
+    /// 同一个本地端口对所有目标映射到同一个公网端口（cone）。
+    EndpointIndependent,
+    /// 对不同目标映射到不同公网端口，但按固定步长递增 / 递减，可以预测。
+    EndpointDependentPredictable,
+    /// 对不同目标映射到不同且无规律的公网端口（symmetric）。
+    EndpointDependent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum P2pLikelihoodC {
+    Likely,
+    Possible,
+    Unlikely,
+}
+
+#[derive(Debug, Clone)]
+pub struct NatCompatibilityC {
+    pub peer_nat_type: NatTypeC,
+    pub likelihood: P2pLikelihoodC,
+    /// 给用户看的解释。
+    pub explanation: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct NatDetectionC {
+    pub udp_nat_type: NatTypeC,
+    pub tcp_nat_type: NatTypeC,
+    pub udp_port_mapping: PortMappingC,
+    /// STUN 看到的公网 IP。
+    pub public_ips: Vec<String>,
+    /// 一次实际的 UDP 端口映射结果 `ip:port`；拿不到时为空串。
+    pub mapped_udp_addr: String,
+    /// 对称型 NAT 观察到的公网端口范围；cone 型时两者相同或为 0。
+    pub min_port: u32,
+    pub max_port: u32,
+    /// 和每种 NAT 类型的对端直连的可能性。
+    pub compatibility: Vec<NatCompatibilityC>,
+}
+
+/// 独立跑一次 STUN 检测。`stun_servers` 为空时使用 EasyTier 内置的服务器列表。
+pub async fn detect_nat(stun_servers: Vec<String>) -> Result<NatDetectionC, String> {
+    let collector = if stun_servers.is_empty() {
+        StunInfoCollector::new_with_default_servers()
+    } else {
+        StunInfoCollector::new(stun_servers, Vec::new())
+    };
+
+    // 后台检测完成后 last_update_time 才会变成非 0。
+    let deadline = Instant::now() + DETECT_TIMEOUT;
+    let mut info = collector.get_stun_info();
+    while info.last_update_time == 0 && Instant::now() < deadline {
+        tokio::time::sleep(POLL_INTERVAL).await;
+        info = collector.get_stun_info();
+    }
+    if info.last_update_time == 0 {
+        return Err("nat detection timed out; stun servers may be unreachable".to_string());
+    }
+
+    let mapped_udp_addr = collector
+        .get_udp_port_mapping(0)
+        .await
+        .map(|addr| addr.to_string())
+        .unwrap_or_default();
+
+    let udp_nat_type = NatTypeC::from_raw(info.udp_nat_type);
+    Ok(NatDetectionC {
+        udp_nat_type,
+        tcp_nat_type: NatTypeC::from_raw(info.tcp_nat_type),
+        udp_port_mapping: port_mapping(udp_nat_type),
+        public_ips: info.public_ip,
+        mapped_udp_addr,
+        min_port: info.min_port,
+        max_port: info.max_port,
+        compatibility: NatTypeC::ALL
+            .into_iter()
+            .map(|peer| compatibility(udp_nat_type, peer))
+            .collect(),
+    })
+}
+
+/// 两种 NAT 类型之间直连的可能性，给 UI 在加入房间前提示用。
+#[flutter_rust_bridge::frb(sync)]
+pub fn nat_compatibility(local: NatTypeC, peer: NatTypeC) -> NatCompatibilityC {
+    compatibility(local, peer)
+}
+
+fn port_mapping(nat: NatTypeC) -> PortMappingC {
+    match nat {
+        NatTypeC::OpenInternet | NatTypeC::NoPat => PortMappingC::NoTranslation,
+        NatTypeC::FullCone | NatTypeC::Restricted | NatTypeC::PortRestricted => {
+            PortMappingC::EndpointIndependent
+        }
+        NatTypeC::SymmetricEasyInc | NatTypeC::SymmetricEasyDec => {
+            PortMappingC::EndpointDependentPredictable
+        }
+        NatTypeC::Symmetric | NatTypeC::SymUdpFirewall => PortMappingC::EndpointDependent,
+        NatTypeC::Unknown => PortMappingC::Unknown,
+    }
+}
+
+fn compatibility(local: NatTypeC, peer: NatTypeC) -> NatCompatibilityC {
+    let (likelihood, explanation) = if local == NatTypeC::Unknown || peer == NatTypeC::Unknown {
+        (
+            P2pLikelihoodC::Possible,
+            "NAT type unknown; a direct connection may or may not work",
+        )
+    } else if local.is_open() || peer.is_open() {
+        (
+            P2pLikelihoodC::Likely,
+            "one side is directly reachable, so a direct connection should work",
+        )
+    } else if local.is_cone() && peer.is_cone() {
+        (
+            P2pLikelihoodC::Likely,
+            "both sides keep stable ports, so hole punching usually succeeds",
+        )
+    } else if local.is_cone() || peer.is_cone() {
+        // 一边对称、一边 cone。
+        let (cone, sym) = if local.is_cone() {
+            (local, peer)
+        } else {
+            (peer, local)
+        };
+        if cone == NatTypeC::PortRestricted && !sym.is_easy_symmetric() {
+            (
+                P2pLikelihoodC::Unlikely,
+                "port-restricted NAT against random symmetric ports rarely punches through; traffic will likely be relayed",
+            )
+        } else if sym == NatTypeC::SymUdpFirewall {
+            (
+                P2pLikelihoodC::Possible,
+                "the symmetric side filters UDP; hole punching may need several attempts",
+            )
+        } else {
+            (
+                P2pLikelihoodC::Possible,
+                "one side is symmetric; hole punching works by guessing ports and may take a while",
+            )
+        }
+    } else if local.is_easy_symmetric() && peer.is_easy_symmetric() {
+        (
+            P2pLikelihoodC::Possible,
+            "both sides are symmetric but with predictable ports; hole punching sometimes succeeds",
+        )
+    } else {
+        (
+            P2pLikelihoodC::Unlikely,
+            "both sides are symmetric; traffic will almost always go through a relay",
+        )
+    };
+    NatCompatibilityC {
+        peer_nat_type: peer,
+        likelihood,
+        explanation: explanation.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use NatTypeC::*;
+    use P2pLikelihoodC::*;
+
+    #[test]
+    fn compatibility_table() {
+        let table = [
+            (OpenInternet, OpenInternet, Likely),
+            (OpenInternet, Symmetric, Likely),
+            (NoPat, SymUdpFirewall, Likely),
+            (FullCone, FullCone, Likely),
+            (Restricted, PortRestricted, Likely),
+            (PortRestricted, PortRestricted, Likely),
+            (FullCone, Symmetric, Possible),
+            (Restricted, SymmetricEasyInc, Possible),
+            (PortRestricted, SymmetricEasyDec, Possible),
+            (PortRestricted, Symmetric, Unlikely),
+            (PortRestricted, SymUdpFirewall, Unlikely),
+            (FullCone, SymUdpFirewall, Possible),
+            (SymmetricEasyInc, SymmetricEasyDec, Possible),
+            (SymmetricEasyInc, Symmetric, Unlikely),
+            (Symmetric, Symmetric, Unlikely),
+            (SymUdpFirewall, Symmetric, Unlikely),
+            (Unknown, OpenInternet, Possible),
+            (Symmetric, Unknown, Possible),
+        ];
+        for (local, peer, likelihood) in table {
+            let result = nat_compatibility(local, peer);
+            assert_eq!(result.likelihood, likelihood, "{:?} -> {:?}", local, peer);
+            assert_eq!(result.peer_nat_type, peer);
+            assert!(!result.explanation.is_empty());
+        }
+    }
+
+    #[test]
+    fn compatibility_is_symmetric() {
+        for local in NatTypeC::ALL {
+            for peer in NatTypeC::ALL {
+                assert_eq!(
+                    compatibility(local, peer).likelihood,
+                    compatibility(peer, local).likelihood,
+                    "{:?} <-> {:?}",
+                    local,
+                    peer
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn port_mapping_by_nat_type() {
+        assert_eq!(port_mapping(OpenInternet), PortMappingC::NoTranslation);
+        assert_eq!(port_mapping(FullCone), PortMappingC::EndpointIndependent);
+        assert_eq!(
+            port_mapping(PortRestricted),
+            PortMappingC::EndpointIndependent
+        );
+        assert_eq!(
+            port_mapping(SymmetricEasyInc),
+            PortMappingC::EndpointDependentPredictable
+        );
+        assert_eq!(port_mapping(Symmetric), PortMappingC::EndpointDependent);
+        assert_eq!(port_mapping(Unknown), PortMappingC::Unknown);
+    }
+}
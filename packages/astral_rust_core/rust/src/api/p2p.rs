@@ -5,25 +5,55 @@ pub use easytier::instance_manager::NetworkInstanceManager;
 pub use easytier::proto;
 pub use easytier::proto::api::instance::{PeerRoutePair, Route};
 pub use easytier::proto::common::NatType;
+use easytier::proto::common::SecureModeConfig;
+use easytier::proto::api::config::{
+    ConfigPatchAction, ConfigRpc, InstanceConfigPatch, PatchConfigRequest, UrlPatch,
+};
+use easytier::proto::rpc_types::controller::BaseController;
 use lazy_static::lazy_static;
 use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Mutex;
 use tokio::runtime::Runtime;
 pub use tokio::task::JoinHandle;
 use uuid::Uuid;
 
+use super::{
+    app_call_control, app_codec, app_metrics, app_policy, app_router, app_stream, credential, diagnostics, file_transfer, local_channel,
+    messaging, port_forward, port_mapping, presence, proxy_cidr, redact, server_selection,
+};
+
 pub static DEFAULT_ET_DNS_ZONE: &str = "as.net.";
 const LOCAL_SYNTHETIC_PEER_ID: u32 = 0;
 
 lazy_static! {
     static ref RT: Runtime = Runtime::new().expect("failed to create tokio runtime");
     static ref MANAGER: NetworkInstanceManager = NetworkInstanceManager::new();
+    /// 实例启动时使用的配置，供运行时管理接口（端口转发等）读取。
+    static ref INSTANCE_CONFIGS: Mutex<HashMap<Uuid, TomlConfigLoader>> = Mutex::new(HashMap::new());
 }
 
 fn parse_instance_id(instance_id: &str) -> Result<Uuid, String> {
     Uuid::parse_str(instance_id).map_err(|e| format!("invalid instance_id: {}", e))
 }
 
-async fn get_instance_info(
+/// crate 自己的 tokio runtime，给需要常驻后台任务的模块（端口转发等）使用。
+pub(crate) fn runtime() -> &'static Runtime {
+    &RT
+}
+
+/// 实例启动时的配置快照。
+pub(crate) fn instance_config(instance_id: &str) -> Result<TomlConfigLoader, String> {
+    let id = parse_instance_id(instance_id)?;
+    INSTANCE_CONFIGS
+        .lock()
+        .unwrap()
+        .get(&id)
+        .cloned()
+        .ok_or_else(|| "instance not found".to_string())
+}
+
+pub(crate) async fn get_instance_info(
     instance_id: &str,
 ) -> Result<easytier::launcher::NetworkInstanceRunningInfo, String> {
     let id = parse_instance_id(instance_id)?;
@@ -34,192 +64,148 @@ async fn get_instance_info(
 }
 
 fn peer_conn_info_to_string(p: proto::api::instance::PeerConnInfo) -> String {
+    let tunnel_info = match &p.tunnel {
+        Some(t) => format!(
+            "{} local: {} remote: {}",
+            t.tunnel_type,
+            t.local_addr
+                .as_ref()
+                .map(|u| redact::url(&u.url))
+                .unwrap_or_else(|| "none".to_string()),
+            t.remote_addr
+                .as_ref()
+                .map(|u| redact::url(&u.url))
+                .unwrap_or_else(|| "none".to_string()),
+        ),
+        None => "none".to_string(),
+    };
     format!(
-        "my_peer_id: {}, dst_peer_id: {}, tunnel_info: {:?}",
-        p.my_peer_id, p.peer_id, p.tunnel
+        "my_peer_id: {}, dst_peer_id: {}, tunnel_info: {}",
+        p.my_peer_id, p.peer_id, tunnel_info
     )
 }
 
 fn send_udp_to_localhost_with_instance_id(instance_id: &str, message: &str) -> Result<(), String> {
-    use std::net::UdpSocket;
-    use serde_json::json;
-
-    let socket = match UdpSocket::bind("0.0.0.0:0") {
-        Ok(s) => s,
-        Err(e) => return Err(format!("udp bind failed: {}", e)),
-    };
-
-    let json_msg = json!({
-        "instance_id": instance_id,
-        "message": message,
-    });
-
-    let json_str = serde_json::to_string(&json_msg).map_err(|e| format!("json serialize failed: {}", e))?;
-
-    match socket.send_to(json_str.as_bytes(), "127.0.0.1:9999") {
-        Ok(_) => Ok(()),
-        Err(e) => Err(format!("udp send failed: {}", e)),
-    }
+    local_channel::send(instance_id, message)
 }
 
+/// 发送一条不属于任何实例的本机事件；和实例事件一样经过签名，见 [`local_channel`]。
 pub fn send_udp_to_localhost(message: &str) -> Result<(), String> {
-    use std::net::UdpSocket;
+    local_channel::send("", message)
+}
 
-    let socket = match UdpSocket::bind("0.0.0.0:0") {
-        Ok(s) => s,
-        Err(e) => return Err(format!("udp bind failed: {}", e)),
+/// 把 EasyTier 事件格式化成一行日志。所有地址 / 凭据都经过 [`redact`]，
+/// 返回 `None` 表示该事件不需要往外发。
+fn describe_event(e: GlobalCtxEvent) -> Option<String> {
+    let msg = match e {
+        GlobalCtxEvent::PeerAdded(p) => format!("peer added. peer_id: {}", p),
+        GlobalCtxEvent::PeerRemoved(p) => format!("peer removed. peer_id: {}", p),
+        GlobalCtxEvent::PeerConnAdded(p) => {
+            format!("peer connection added. conn_info: {}", peer_conn_info_to_string(p))
+        }
+        GlobalCtxEvent::PeerConnRemoved(p) => format!(
+            "peer connection removed. conn_info: {}",
+            peer_conn_info_to_string(p)
+        ),
+        GlobalCtxEvent::ListenerAddFailed(p, msg) => format!(
+            "listener add failed. listener: {}, msg: {}",
+            redact::url(&p.to_string()),
+            msg
+        ),
+        GlobalCtxEvent::ListenerAcceptFailed(p, msg) => format!(
+            "listener accept failed. listener: {}, msg: {}",
+            redact::url(&p.to_string()),
+            msg
+        ),
+        GlobalCtxEvent::ListenerAdded(p) => {
+            if p.scheme() == "ring" {
+                return None;
+            }
+            format!("listener added. listener: {}", redact::url(&p.to_string()))
+        }
+        GlobalCtxEvent::ConnectionAccepted(local, remote) => format!(
+            "connection accepted. local: {}, remote: {}",
+            redact::url(&local),
+            redact::url(&remote)
+        ),
+        GlobalCtxEvent::ConnectionError(local, remote, err) => format!(
+            "connection error. local: {}, remote: {}, err: {}",
+            redact::url(&local),
+            redact::url(&remote),
+            err
+        ),
+        GlobalCtxEvent::TunDeviceReady(dev) => format!("tun device ready. dev: {}", dev),
+        GlobalCtxEvent::TunDeviceError(err) => format!("tun device error. err: {}", err),
+        GlobalCtxEvent::Connecting(dst) => {
+            format!("connecting to peer. dst: {}", redact::url(&dst.to_string()))
+        }
+        GlobalCtxEvent::ConnectError(dst, ip_version, err) => format!(
+            "connect error. dst: {}, ip_version: {}, err: {}",
+            redact::url(&dst),
+            ip_version,
+            err
+        ),
+        GlobalCtxEvent::VpnPortalStarted(portal) => {
+            format!("vpn portal started. portal: {}", redact::url(&portal))
+        }
+        GlobalCtxEvent::VpnPortalClientConnected(portal, client_addr) => format!(
+            "vpn portal client connected. portal: {}, client_addr: {}",
+            redact::url(&portal),
+            client_addr
+        ),
+        GlobalCtxEvent::VpnPortalClientDisconnected(portal, client_addr) => format!(
+            "vpn portal client disconnected. portal: {}, client_addr: {}",
+            redact::url(&portal),
+            client_addr
+        ),
+        GlobalCtxEvent::DhcpIpv4Changed(old, new) => {
+            format!("dhcp ip changed. old: {:?}, new: {:?}", old, new)
+        }
+        GlobalCtxEvent::DhcpIpv4Conflicted(ip) => format!("dhcp ip conflict. ip: {:?}", ip),
+        GlobalCtxEvent::PortForwardAdded(cfg) => format!("port forward added. cfg: {:?}", cfg),
+        GlobalCtxEvent::ListenerPortMappingEstablished {
+            local_listener,
+            mapped_listener,
+            backend,
+        } => format!(
+            "listener port mapping established. local: {}, mapped: {}, backend: {}",
+            redact::url(&local_listener.to_string()),
+            redact::url(&mapped_listener.to_string()),
+            backend
+        ),
+        GlobalCtxEvent::PublicIpv6Changed(old, new) => {
+            format!("public ipv6 changed. old: {:?}, new: {:?}", old, new)
+        }
+        GlobalCtxEvent::PublicIpv6RoutesUpdated(added, removed) => format!(
+            "public ipv6 routes updated. added: {:?}, removed: {:?}",
+            added, removed
+        ),
+        GlobalCtxEvent::UdpBroadcastRelayStartResult {
+            capture_backend,
+            error,
+        } => format!(
+            "udp broadcast relay start result. backend: {:?}, error: {:?}",
+            capture_backend, error
+        ),
+        GlobalCtxEvent::CredentialChanged => "credential changed".to_string(),
+        GlobalCtxEvent::ConfigPatched(_) => return None,
+        GlobalCtxEvent::ProxyCidrsUpdated(added, removed) => format!(
+            "proxy cidrs updated. added: {:?}, removed: {:?}",
+            added, removed
+        ),
     };
-
-    match socket.send_to(message.as_bytes(), "127.0.0.1:9999") {
-        Ok(_) => Ok(()),
-        Err(e) => Err(format!("udp send failed: {}", e)),
-    }
+    Some(msg)
 }
 
 fn handle_event_with_instance_id(mut events: EventBusSubscriber, instance_id: String) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
         loop {
             match events.recv().await {
-                Ok(e) => match e {
-                    GlobalCtxEvent::PeerAdded(p) => {
-                        let msg = format!("peer added. peer_id: {}", p);
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::PeerRemoved(p) => {
-                        let msg = format!("peer removed. peer_id: {}", p);
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::PeerConnAdded(p) => {
-                        let conn_info = peer_conn_info_to_string(p);
-                        let msg = format!("peer connection added. conn_info: {}", conn_info);
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::PeerConnRemoved(p) => {
-                        let msg = format!(
-                            "peer connection removed. conn_info: {}",
-                            peer_conn_info_to_string(p)
-                        );
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::ListenerAddFailed(p, msg) => {
-                        let msg = format!("listener add failed. listener: {}, msg: {}", p, msg);
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::ListenerAcceptFailed(p, msg) => {
-                        let msg =
-                            format!("listener accept failed. listener: {}, msg: {}", p, msg);
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::ListenerAdded(p) => {
-                        if p.scheme() == "ring" {
-                            continue;
-                        }
-                        let msg = format!("listener added. listener: {}", p);
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::ConnectionAccepted(local, remote) => {
-                        let msg =
-                            format!("connection accepted. local: {}, remote: {}", local, remote);
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::ConnectionError(local, remote, err) => {
-                        let msg = format!(
-                            "connection error. local: {}, remote: {}, err: {}",
-                            local, remote, err
-                        );
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::TunDeviceReady(dev) => {
-                        let msg = format!("tun device ready. dev: {}", dev);
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::TunDeviceError(err) => {
-                        let msg = format!("tun device error. err: {}", err);
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::Connecting(dst) => {
-                        let msg = format!("connecting to peer. dst: {}", dst);
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::ConnectError(dst, ip_version, err) => {
-                        let msg = format!(
-                            "connect error. dst: {}, ip_version: {}, err: {}",
-                            dst, ip_version, err
-                        );
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::VpnPortalStarted(portal) => {
-                        let msg = format!("vpn portal started. portal: {}", portal);
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::VpnPortalClientConnected(portal, client_addr) => {
-                        let msg = format!(
-                            "vpn portal client connected. portal: {}, client_addr: {}",
-                            portal, client_addr
-                        );
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::VpnPortalClientDisconnected(portal, client_addr) => {
-                        let msg = format!(
-                            "vpn portal client disconnected. portal: {}, client_addr: {}",
-                            portal, client_addr
-                        );
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::DhcpIpv4Changed(old, new) => {
-                        let msg = format!("dhcp ip changed. old: {:?}, new: {:?}", old, new);
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::DhcpIpv4Conflicted(ip) => {
-                        let msg = format!("dhcp ip conflict. ip: {:?}", ip);
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::PortForwardAdded(cfg) => {
-                        let msg = format!("port forward added. cfg: {:?}", cfg);
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::ListenerPortMappingEstablished {
-                        local_listener,
-                        mapped_listener,
-                        backend,
-                    } => {
-                        let msg = format!(
-                            "listener port mapping established. local: {}, mapped: {}, backend: {}",
-                            local_listener, mapped_listener, backend
-                        );
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::PublicIpv6Changed(old, new) => {
-                        let msg = format!(
-                            "public ipv6 changed. old: {:?}, new: {:?}",
-                            old, new
-                        );
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::PublicIpv6RoutesUpdated(added, removed) => {
-                        let msg = format!(
-                            "public ipv6 routes updated. added: {:?}, removed: {:?}",
-                            added, removed
-                        );
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
-                    }
-                    GlobalCtxEvent::UdpBroadcastRelayStartResult {
-                        capture_backend,
-                        error,
-                    } => {
-                        let msg = format!(
-                            "udp broadcast relay start result. backend: {:?}, error: {:?}",
-                            capture_backend, error
-                        );
+                Ok(e) => {
+                    if let Some(msg) = describe_event(e) {
                         let _ = send_udp_to_localhost_with_instance_id(&instance_id, &msg);
                     }
-                    GlobalCtxEvent::CredentialChanged => {
-                        let msg = "credential changed";
-                        let _ = send_udp_to_localhost_with_instance_id(&instance_id, msg);
-                    }
-                    GlobalCtxEvent::ConfigPatched(_) => {}
-                    GlobalCtxEvent::ProxyCidrsUpdated(_, _) => {}
-                },
+                }
                 Err(err) => {
                     eprintln!("event receive error: {:?}", err);
                     match err {
@@ -244,145 +230,11 @@ pub fn handle_event(mut events: EventBusSubscriber) -> tokio::task::JoinHandle<(
     tokio::spawn(async move {
         loop {
             match events.recv().await {
-                Ok(e) => match e {
-                    GlobalCtxEvent::PeerAdded(p) => {
-                        let msg = format!("peer added. peer_id: {}", p);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::PeerRemoved(p) => {
-                        let msg = format!("peer removed. peer_id: {}", p);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::PeerConnAdded(p) => {
-                        let conn_info = peer_conn_info_to_string(p);
-                        let msg = format!("peer connection added. conn_info: {}", conn_info);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::PeerConnRemoved(p) => {
-                        let msg = format!(
-                            "peer connection removed. conn_info: {}",
-                            peer_conn_info_to_string(p)
-                        );
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::ListenerAddFailed(p, msg) => {
-                        let msg = format!("listener add failed. listener: {}, msg: {}", p, msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::ListenerAcceptFailed(p, msg) => {
-                        let msg =
-                            format!("listener accept failed. listener: {}, msg: {}", p, msg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::ListenerAdded(p) => {
-                        if p.scheme() == "ring" {
-                            continue;
-                        }
-                        let msg = format!("listener added. listener: {}", p);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::ConnectionAccepted(local, remote) => {
-                        let msg =
-                            format!("connection accepted. local: {}, remote: {}", local, remote);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::ConnectionError(local, remote, err) => {
-                        let msg = format!(
-                            "connection error. local: {}, remote: {}, err: {}",
-                            local, remote, err
-                        );
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::TunDeviceReady(dev) => {
-                        let msg = format!("tun device ready. dev: {}", dev);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::TunDeviceError(err) => {
-                        let msg = format!("tun device error. err: {}", err);
+                Ok(e) => {
+                    if let Some(msg) = describe_event(e) {
                         let _ = send_udp_to_localhost(&msg);
                     }
-                    GlobalCtxEvent::Connecting(dst) => {
-                        let msg = format!("connecting to peer. dst: {}", dst);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::ConnectError(dst, ip_version, err) => {
-                        let msg = format!(
-                            "connect error. dst: {}, ip_version: {}, err: {}",
-                            dst, ip_version, err
-                        );
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::VpnPortalStarted(portal) => {
-                        let msg = format!("vpn portal started. portal: {}", portal);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::VpnPortalClientConnected(portal, client_addr) => {
-                        let msg = format!(
-                            "vpn portal client connected. portal: {}, client_addr: {}",
-                            portal, client_addr
-                        );
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::VpnPortalClientDisconnected(portal, client_addr) => {
-                        let msg = format!(
-                            "vpn portal client disconnected. portal: {}, client_addr: {}",
-                            portal, client_addr
-                        );
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::DhcpIpv4Changed(old, new) => {
-                        let msg = format!("dhcp ip changed. old: {:?}, new: {:?}", old, new);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::DhcpIpv4Conflicted(ip) => {
-                        let msg = format!("dhcp ip conflict. ip: {:?}", ip);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::PortForwardAdded(cfg) => {
-                        let msg = format!("port forward added. cfg: {:?}", cfg);
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::ListenerPortMappingEstablished {
-                        local_listener,
-                        mapped_listener,
-                        backend,
-                    } => {
-                        let msg = format!(
-                            "listener port mapping established. local: {}, mapped: {}, backend: {}",
-                            local_listener, mapped_listener, backend
-                        );
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::PublicIpv6Changed(old, new) => {
-                        let msg = format!(
-                            "public ipv6 changed. old: {:?}, new: {:?}",
-                            old, new
-                        );
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::PublicIpv6RoutesUpdated(added, removed) => {
-                        let msg = format!(
-                            "public ipv6 routes updated. added: {:?}, removed: {:?}",
-                            added, removed
-                        );
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::UdpBroadcastRelayStartResult {
-                        capture_backend,
-                        error,
-                    } => {
-                        let msg = format!(
-                            "udp broadcast relay start result. backend: {:?}, error: {:?}",
-                            capture_backend, error
-                        );
-                        let _ = send_udp_to_localhost(&msg);
-                    }
-                    GlobalCtxEvent::CredentialChanged => {
-                        let _ = send_udp_to_localhost("credential changed");
-                    }
-                    GlobalCtxEvent::ConfigPatched(_) => {}
-                    GlobalCtxEvent::ProxyCidrsUpdated(_, _) => {}
-                },
+                }
                 Err(err) => {
                     eprintln!("event receive error: {:?}", err);
                     match err {
@@ -562,6 +414,8 @@ pub struct FlagsC {
     pub disable_sym_hole_punching: bool,
     pub tcp_whitelist: String,
     pub udp_whitelist: String,
+    /// 用本机节点密钥启用 EasyTier 安全模式，需要先加载密钥库（见 `credential`）。
+    pub secure_mode: bool,
 }
 
 pub struct Forward {
@@ -573,6 +427,7 @@ pub fn create_server(config_toml: String, watch_event: bool) -> JoinHandle<Resul
     RT.spawn(async move {
         let cfg = TomlConfigLoader::new_from_str(&config_toml)
             .map_err(|e| format!("invalid config toml: {}", e))?;
+        credential::apply_node_key(&cfg)?;
         let instance_id = cfg.get_id();
         let instance_id_str = instance_id.to_string();
 
@@ -581,11 +436,14 @@ pub fn create_server(config_toml: String, watch_event: bool) -> JoinHandle<Resul
         let dhcp = cfg.get_dhcp();
         let ipv4 = cfg.get_ipv4().map(|ip| ip.to_string()).unwrap_or_else(|| "none".to_string());
         let listeners = cfg.get_listeners()
-            .map(|l| l.iter().map(|u| u.to_string()).collect::<Vec<_>>().join(", "))
+            .map(|l| l.iter().map(|u| redact::url(u.as_str())).collect::<Vec<_>>().join(", "))
             .unwrap_or_else(|| "none".to_string());
         let peers = cfg.get_peers()
             .iter()
-            .map(|p| p.uri.to_string())
+            .map(|p| match &p.peer_public_key {
+                Some(key) => format!("{} (key: {})", redact::url(p.uri.as_str()), redact::public_key(key)),
+                None => redact::url(p.uri.as_str()),
+            })
             .collect::<Vec<_>>()
             .join(", ");
 
@@ -593,7 +451,7 @@ pub fn create_server(config_toml: String, watch_event: bool) -> JoinHandle<Resul
             "instance starting. instance_id: {}, network_name: {}, network_secret: {}, hostname: {}, dhcp: {}, ipv4: {}, listeners: [{}], peers: [{}]",
             instance_id,
             network_identity.network_name,
-            network_identity.network_secret.as_deref().unwrap_or("none"),
+            redact::secret(network_identity.network_secret.as_deref()),
             hostname,
             dhcp,
             ipv4,
@@ -602,15 +460,25 @@ pub fn create_server(config_toml: String, watch_event: bool) -> JoinHandle<Resul
         );
         let _ = send_udp_to_localhost_with_instance_id(&instance_id_str, &config_msg);
 
+        let forwards = port_forward::take_config_forwards(&cfg)?;
+        let cfg_snapshot = cfg.clone();
         MANAGER
             .run_network_instance(cfg, false, ConfigFileControl::STATIC_CONFIG)
             .map_err(|e| format!("start instance failed: {}", e))?;
+        // 紧跟启动订阅，启动阶段的监听失败等事件才不会在诊断里漏掉。
+        diagnostics::watch_instance(&instance_id_str, subscribe_instance_events(&instance_id_str)?);
+        INSTANCE_CONFIGS.lock().unwrap().insert(instance_id, cfg_snapshot);
+        port_forward::start_prepared(&instance_id.to_string(), forwards)?;
+        port_mapping::watch_instance(&instance_id.to_string());
 
         // EasyTier 的 NetworkInstance::start 是 spawn-thread 异步的，`run_network_instance`
         // 返回时 Instance::run 还没跑到 `astral_app_rpc::install`。如果直接把 instance_id
         // 交回给 dart，紧跟着的 `subscribeAppInbound` / `myPeerId` 会拿到
         // "astral app rpc service not found" 报错（broadcast 流立刻 onDone）。
         wait_for_app_rpc_service(&instance_id, std::time::Duration::from_secs(5)).await;
+        app_router::watch_instance(&instance_id_str);
+        app_codec::watch_instance(&instance_id_str);
+        app_call_control::watch_instance(&instance_id_str);
 
         if watch_event {
             if let Some(instance) = MANAGER.iter().find(|item| *item.key() == instance_id) {
@@ -647,7 +515,13 @@ pub fn create_server_with_flags(
         for url in onurl {
             match url.parse() {
                 Ok(parsed) => listeners.push(parsed),
-                Err(e) => return Err(format!("invalid listener url: {}, error: {}", url, e)),
+                Err(e) => {
+                    return Err(format!(
+                        "invalid listener url: {}, error: {}",
+                        redact::url(&url),
+                        e
+                    ))
+                }
             }
         }
         cfg.set_listeners(listeners);
@@ -655,14 +529,20 @@ pub fn create_server_with_flags(
         cfg.set_hostname(Some(username));
         cfg.set_dhcp(enable_dhcp);
         for c in cidrs {
-            let _ = cfg.add_proxy_cidr(c.parse().unwrap(), None);
+            let (cidr, mapped) = proxy_cidr::parse_proxy_cidr_spec(&c)?;
+            cfg.add_proxy_cidr(cidr, mapped)
+                .map_err(|e| format!("invalid proxy cidr: {}, error: {}", c, e))?;
         }
         let mut old = cfg.get_port_forwards();
 
         for c in forwards {
             let port_forward_item = PortForwardConfig {
-                bind_addr: c.bind_addr.parse().unwrap(),
-                dst_addr: c.dst_addr.parse().unwrap(),
+                bind_addr: c.bind_addr.parse().map_err(|e| {
+                    format!("invalid forward bind address: {}, error: {}", c.bind_addr, e)
+                })?,
+                dst_addr: c.dst_addr.parse().map_err(|e| {
+                    format!("invalid forward destination address: {}, error: {}", c.dst_addr, e)
+                })?,
                 proto: c.proto,
             };
             old.push(port_forward_item);
@@ -719,13 +599,7 @@ pub fn create_server_with_flags(
 
         let mut peer_configs = Vec::new();
         for url in severurl {
-            match url.parse() {
-                Ok(uri) => peer_configs.push(PeerConfig {
-                    uri,
-                    peer_public_key: None,
-                }),
-                Err(e) => return Err(format!("invalid server url: {}, error: {}", url, e)),
-            }
+            peer_configs.push(credential::peer_config(&url)?);
         }
         cfg.set_peers(peer_configs);
 
@@ -740,14 +614,32 @@ pub fn create_server_with_flags(
         }
 
         cfg.set_network_identity(NetworkIdentity::new(room_name, room_password));
+        if flag.secure_mode {
+            cfg.set_secure_mode(Some(SecureModeConfig {
+                enabled: true,
+                local_private_key: None,
+                local_public_key: None,
+            }));
+        }
+        credential::apply_node_key(&cfg)?;
         let instance_id = cfg.get_id();
         let instance_id_str = instance_id.to_string();
 
+        let forwards = port_forward::take_config_forwards(&cfg)?;
+        let cfg_snapshot = cfg.clone();
         MANAGER
             .run_network_instance(cfg, false, ConfigFileControl::STATIC_CONFIG)
             .map_err(|e| format!("start instance failed: {}", e))?;
+        // 紧跟启动订阅，启动阶段的监听失败等事件才不会在诊断里漏掉。
+        diagnostics::watch_instance(&instance_id_str, subscribe_instance_events(&instance_id_str)?);
+        INSTANCE_CONFIGS.lock().unwrap().insert(instance_id, cfg_snapshot);
+        port_forward::start_prepared(&instance_id.to_string(), forwards)?;
+        port_mapping::watch_instance(&instance_id.to_string());
 
         wait_for_app_rpc_service(&instance_id, std::time::Duration::from_secs(5)).await;
+        app_router::watch_instance(&instance_id_str);
+        app_codec::watch_instance(&instance_id_str);
+        app_call_control::watch_instance(&instance_id_str);
 
         if let Some(instance) = MANAGER.iter().find(|item| *item.key() == instance_id) {
             if let Some(subscriber) = instance.subscribe_event() {
@@ -781,13 +673,203 @@ async fn wait_for_app_rpc_service(instance_id: &uuid::Uuid, timeout: std::time::
     }
 }
 
+/// 通过 EasyTier 的配置补丁接口修改运行中实例的配置（子网代理、连接器等），
+/// 不需要重启实例。
+pub(crate) async fn patch_instance_config(
+    instance_id: &str,
+    patch: InstanceConfigPatch,
+) -> Result<(), String> {
+    let id = parse_instance_id(instance_id)?;
+    let service = MANAGER
+        .get_instance_service(&id)
+        .ok_or_else(|| "instance not found".to_string())?;
+    service
+        .get_config_service()
+        .patch_config(
+            BaseController::default(),
+            PatchConfigRequest {
+                instance: None,
+                patch: Some(patch),
+            },
+        )
+        .await
+        .map_err(|e| format!("patch config failed: {}", e))?;
+    Ok(())
+}
+
+/// 增删 connector（服务器 / 手动添加的节点地址）的配置补丁项。
+pub(crate) fn connector_patch(url: &str, action: ConfigPatchAction) -> UrlPatch {
+    UrlPatch {
+        action: action as i32,
+        url: Some(proto::common::Url {
+            url: url.to_string(),
+        }),
+    }
+}
+
+/// 用实例当前的配置重启，让配置里的 peer 原样（带 `peer_public_key`）重新加进 connector。
+///
+/// 配置补丁的 connector 项只有 URL，用它加回固定了公钥的服务器会丢掉握手时的公钥校验，
+/// 这种情况只能重启实例，代价见 [`restart_instance`]。
+pub(crate) async fn restart_with_pinned_peers(instance_id: &str) -> Result<(), String> {
+    let cfg = instance_config(instance_id)?;
+    let previous = config_snapshot(&cfg)?;
+    restart_instance(instance_id, cfg, previous).await.map(|_| ())
+}
+
+/// 配置的独立副本，之后修改 `cfg` 不会影响它，用作重启失败时恢复的快照。
+pub(crate) fn config_snapshot(cfg: &TomlConfigLoader) -> Result<TomlConfigLoader, String> {
+    TomlConfigLoader::new_from_str(&cfg.dump()).map_err(|e| format!("copy config failed: {}", e))
+}
+
+/// 当前已建立的连接的远端地址（tunnel 的 remote_addr），用来判断连上了哪些服务器。
+pub(crate) async fn connected_remote_urls(instance_id: &str) -> Vec<String> {
+    get_peer_route_pairs(instance_id.to_string())
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|p| p.peer)
+        .flat_map(|p| p.conns)
+        .filter_map(|c| c.tunnel.and_then(|t| t.remote_addr).map(|u| u.url))
+        .collect()
+}
+
+/// 已建立连接的对端地址（remote_addr 形如 `tcp://1.2.3.4:11010`），用来判断某个服务器
+/// 是否连着。按地址相等比较，不能拿字符串做子串匹配（`1.2.3.4` 会匹配 `11.2.3.4`）。
+pub(crate) async fn connected_remote_addrs(instance_id: &str) -> Vec<std::net::SocketAddr> {
+    connected_remote_urls(instance_id)
+        .await
+        .iter()
+        .filter_map(|u| url::Url::parse(u).ok())
+        .filter_map(|u| {
+            let ip: std::net::IpAddr = match u.host()? {
+                url::Host::Ipv4(ip) => ip.into(),
+                url::Host::Ipv6(ip) => ip.into(),
+                url::Host::Domain(_) => return None,
+            };
+            Some(std::net::SocketAddr::new(ip, u.port()?))
+        })
+        .collect()
+}
+
+/// 给 crate 内其他模块单独订阅某个实例的 EasyTier 事件总线（broadcast，多订阅互不影响）。
+pub(crate) fn subscribe_instance_events(instance_id: &str) -> Result<EventBusSubscriber, String> {
+    let id = parse_instance_id(instance_id)?;
+    MANAGER
+        .iter()
+        .find(|item| *item.key() == id)
+        .and_then(|instance| instance.subscribe_event())
+        .ok_or_else(|| "instance not found".to_string())
+}
+
 pub fn close_server(instance_id: String) -> Result<(), String> {
     let id = parse_instance_id(&instance_id)?;
+    // 先撤端口映射、停续期，再删实例。
+    port_mapping::forget_instance(&instance_id);
     MANAGER
         .delete_network_instance(vec![id])
         .map_err(|e| format!("delete instance failed: {}", e))?;
+    INSTANCE_CONFIGS.lock().unwrap().remove(&id);
+    port_forward::stop_all(&instance_id);
+    diagnostics::forget_instance(&instance_id);
+    server_selection::forget_instance(&instance_id);
+    app_router::forget_instance(&instance_id);
+    app_codec::forget_instance(&instance_id);
+    app_stream::forget_instance(&instance_id);
+    file_transfer::forget_instance(&instance_id);
+    messaging::forget_instance(&instance_id);
+    presence::forget_instance(&instance_id);
+    app_policy::forget_instance(&instance_id);
+    app_metrics::forget_instance(&instance_id);
+    app_call_control::forget_instance(&instance_id);
     Ok(())
 }
+/// 用新的配置重启实例，instance id 不变。用于 EasyTier 不支持运行中修改的配置（监听地址）。
+///
+/// 重启的代价：
+/// - 已有连接会断开重连，进行中的文件传输和 app stream 会失败，需要重试；
+/// - Dart 侧的订阅（app inbound、频道、文件 offer 等）随旧实例结束，需要重新建立；
+/// - crate 内按实例运行的服务（频道路由、文件传输、stream、消息、在线状态、调用控制、
+///   端口映射、诊断）会挂到新实例上；启用了服务器选择的实例按原来的数量重新测速选择。
+///
+/// 新配置起不来时用 `previous` 把实例恢复回去并返回错误，实例不会就此消失。
+/// 成功时返回新实例的事件订阅，调用方可以据此等待启动结果（例如 `ListenerAdded`）。
+pub(crate) async fn restart_instance(
+    instance_id: &str,
+    cfg: TomlConfigLoader,
+    previous: TomlConfigLoader,
+) -> Result<EventBusSubscriber, String> {
+    let id = parse_instance_id(instance_id)?;
+    let instance_id_str = instance_id.to_string();
+    RT.spawn(async move {
+        MANAGER
+            .delete_network_instance(vec![id])
+            .map_err(|e| format!("delete instance failed: {}", e))?;
+        let selection = server_selection::forget_instance(&instance_id_str);
+
+        let result = match start_restarted_instance(&instance_id_str, cfg) {
+            Ok(events) => Ok(events),
+            Err(e) => match start_restarted_instance(&instance_id_str, previous) {
+                Ok(_) => Err(format!("{}; restored the previous configuration", e)),
+                Err(restore) => {
+                    return Err(format!(
+                        "{}; restoring the previous configuration also failed: {}",
+                        e, restore
+                    ))
+                }
+            },
+        };
+
+        port_mapping::watch_instance(&instance_id_str);
+        wait_for_app_rpc_service(&id, std::time::Duration::from_secs(5)).await;
+        app_router::watch_instance(&instance_id_str);
+        app_codec::watch_instance(&instance_id_str);
+        app_stream::watch_instance(&instance_id_str);
+        file_transfer::watch_instance(&instance_id_str);
+        messaging::watch_instance(&instance_id_str);
+        presence::watch_instance(&instance_id_str);
+        app_call_control::watch_instance(&instance_id_str);
+        if let Some(active_count) = selection {
+            // 新实例会连所有服务器，重新选择一次；测速要几秒，不阻塞重启返回。
+            let instance_id = instance_id_str.clone();
+            RT.spawn(async move {
+                if let Err(e) =
+                    server_selection::enable_server_selection(instance_id.clone(), active_count)
+                        .await
+                {
+                    eprintln!(
+                        "[astral_rust_core] WARN: re-applying server selection after restart failed for instance {}: {}",
+                        instance_id, e,
+                    );
+                }
+            });
+        }
+        handle_event_with_instance_id(
+            subscribe_instance_events(&instance_id_str)?,
+            instance_id_str,
+        );
+        result
+    })
+    .await
+    .map_err(|e| format!("restart instance failed: {}", e))?
+}
+
+/// 重启流程里启动实例并登记配置快照，返回给调用方等待启动结果用的事件订阅。
+fn start_restarted_instance(
+    instance_id: &str,
+    cfg: TomlConfigLoader,
+) -> Result<EventBusSubscriber, String> {
+    let id = cfg.get_id();
+    let cfg_snapshot = cfg.clone();
+    MANAGER
+        .run_network_instance(cfg, false, ConfigFileControl::STATIC_CONFIG)
+        .map_err(|e| format!("start instance failed: {}", e))?;
+    let events = subscribe_instance_events(instance_id)?;
+    diagnostics::watch_instance(instance_id, subscribe_instance_events(instance_id)?);
+    INSTANCE_CONFIGS.lock().unwrap().insert(id, cfg_snapshot);
+    Ok(events)
+}
+
 pub async fn get_peer_route_pairs(instance_id: String) -> Result<Vec<PeerRoutePair>, String> {
     let info = get_instance_info(&instance_id).await?;
 
@@ -974,6 +1056,18 @@ pub mod app_rpc_status {
     pub const NO_SUBSCRIBER: i32 = -1;
     pub const REPLY_TIMEOUT: i32 = -2;
     pub const SERVICE_DROPPED: i32 = -3;
+    /// Not produced by EasyTier: the crate's channel router answers calls to
+    /// channels nobody registered with this status (see `app_router`).
+    pub const CHANNEL_NOT_FOUND: i32 = -4;
+    /// Not produced by EasyTier: a chunk of a cancelled stream (see
+    /// `app_stream`).
+    pub const STREAM_CANCELLED: i32 = -5;
+    /// Not produced by EasyTier: the call was dropped by the instance's
+    /// inbound policy (see `app_policy`).
+    pub const POLICY_REJECTED: i32 = -6;
+    /// Not produced by EasyTier: the caller cancelled the call (see
+    /// `app_call_control`).
+    pub const CALL_CANCELLED: i32 = -7;
 }
 
 /// Result of [`app_call`] — directly maps `AppCallResponse` to a Dart record.
@@ -1014,7 +1108,7 @@ pub struct AppInboundEventC {
     pub payload: Vec<u8>,
 }
 
-fn lookup_app_rpc(
+pub(crate) fn lookup_app_rpc(
     instance_id: &str,
 ) -> Result<std::sync::Arc<app_rpc::AstralAppRpcService>, String> {
     let id = parse_instance_id(instance_id)?;
@@ -1023,6 +1117,7 @@ fn lookup_app_rpc(
 }
 
 /// Send a request-response RPC to `dst_peer_id` and await the typed reply.
+/// `app_call_control::app_call_with_deadline` is the cancellable variant.
 ///
 /// 不要用 `RT.spawn(...).await` 把这次调用搬到 `RT` —— `RT` 既不是 FRB 的
 /// runtime，也不是 EasyTier 每个 instance 自己的 runtime（见 `EasyTierLauncher::start`
@@ -1041,10 +1136,25 @@ pub async fn app_call(
     timeout_ms: i32,
 ) -> Result<AppCallResultC, String> {
     let svc = lookup_app_rpc(&instance_id)?;
+    let started = std::time::Instant::now();
     let resp = svc
-        .call(dst_peer_id, channel, request_id, payload, flags, timeout_ms)
-        .await
-        .map_err(|e| e.to_string())?;
+        .call(
+            dst_peer_id,
+            channel.clone(),
+            request_id,
+            payload,
+            flags,
+            timeout_ms,
+        )
+        .await;
+    app_metrics::record_call(
+        &instance_id,
+        dst_peer_id,
+        &channel,
+        started,
+        resp.as_ref().ok().map(|r| r.status),
+    );
+    let resp = resp.map_err(|e| e.to_string())?;
     Ok(AppCallResultC {
         status: resp.status,
         error_msg: resp.error_msg,
@@ -1062,9 +1172,11 @@ pub async fn app_notify(
     timeout_ms: i32,
 ) -> Result<(), String> {
     let svc = lookup_app_rpc(&instance_id)?;
-    svc.notify(dst_peer_id, channel, payload, timeout_ms)
-        .await
-        .map_err(|e| e.to_string())
+    let result = svc
+        .notify(dst_peer_id, channel.clone(), payload, timeout_ms)
+        .await;
+    app_metrics::record_notify(&instance_id, dst_peer_id, &channel, result.is_ok());
+    result.map_err(|e| e.to_string())
 }
 
 /// Round-trip ping. Returns the measured RTT in milliseconds.
@@ -1083,45 +1195,27 @@ pub async fn peer_ping(
 /// Dart. The future resolves once the EasyTier instance shuts down (the
 /// underlying broadcast channel is closed); Dart can re-subscribe after a
 /// subsequent `create_server` call.
+///
+/// Events come from the instance's channel router (see `app_router`): once
+/// channels are registered only events for registered channels are
+/// delivered, and events rejected by the instance's `app_policy` never
+/// arrive. Use `app_router::subscribe_app_channels` to receive a subset.
 pub async fn subscribe_app_inbound(
     instance_id: String,
     sink: StreamSink<AppInboundEventC>,
 ) -> Result<(), String> {
     let svc = lookup_app_rpc(&instance_id)?;
-    let mut rx = svc.subscribe_inbound();
+    let mut rx = app_router::subscribe_inbound(&instance_id, &svc);
     drop(svc);
     loop {
         match rx.recv().await {
             Ok(evt) => {
-                let mapped = match evt {
-                    app_rpc::AppInboundEvent::Call {
-                        from_peer_id,
-                        channel,
-                        request_id,
-                        token,
-                        payload,
-                    } => AppInboundEventC {
-                        kind: AppInboundKindC::Call,
-                        from_peer_id,
-                        channel,
-                        request_id,
-                        token,
-                        payload,
-                    },
-                    app_rpc::AppInboundEvent::Notify {
-                        from_peer_id,
-                        channel,
-                        payload,
-                    } => AppInboundEventC {
-                        kind: AppInboundKindC::Notify,
-                        from_peer_id,
-                        channel,
-                        request_id: 0,
-                        token: 0,
-                        payload,
-                    },
-                };
-                if sink.add(mapped).is_err() {
+                if app_router::is_reserved(&evt.channel) {
+                    // Crate services (streams, files, messages, presence) are
+                    // handled in Rust.
+                    continue;
+                }
+                if sink.add(evt).is_err() {
                     // Dart cancelled the stream.
                     break;
                 }
@@ -1152,11 +1246,14 @@ pub async fn app_call_reply(
     payload: Vec<u8>,
 ) -> Result<bool, String> {
     let svc = lookup_app_rpc(&instance_id)?;
+    app_metrics::record_reply(&instance_id, token);
+    app_call_control::forget_call(&instance_id, token);
     Ok(svc.reply_call(token, status, error_msg, payload))
 }
 
 /// Number of `Call` events currently awaiting application replies for the
-/// given instance. Useful for diagnostics / liveness checks from Dart.
+/// given instance. Useful for diagnostics / liveness checks from Dart; see
+/// `app_metrics` for per-channel counters and latencies.
 pub async fn pending_app_call_count(instance_id: String) -> Result<usize, String> {
     let svc = lookup_app_rpc(&instance_id)?;
     Ok(svc.pending_call_count())
@@ -1169,7 +1266,40 @@ pub async fn my_peer_id(instance_id: String) -> Result<u32, String> {
     Ok(svc.my_peer_id())
 }
 
-fn tracing_log_lagged(instance_id: &str, skipped: u64) {
+pub(crate) fn inbound_event(evt: app_rpc::AppInboundEvent) -> AppInboundEventC {
+    match evt {
+        app_rpc::AppInboundEvent::Call {
+            from_peer_id,
+            channel,
+            request_id,
+            token,
+            payload,
+        } => AppInboundEventC {
+            kind: AppInboundKindC::Call,
+            from_peer_id,
+            channel,
+            request_id,
+            token,
+            payload,
+        },
+        app_rpc::AppInboundEvent::Notify {
+            from_peer_id,
+            channel,
+            payload,
+        } => AppInboundEventC {
+            kind: AppInboundKindC::Notify,
+            from_peer_id,
+            channel,
+            request_id: 0,
+            token: 0,
+            payload,
+        },
+    }
+}
+
+/// Lag of the channel router itself is also counted in `app_metrics`; see
+/// `app_router`.
+pub(crate) fn tracing_log_lagged(instance_id: &str, skipped: u64) {
     // We don't pull `tracing` into AstralNext; just write to stderr at debug
     // verbosity since this is a slow-consumer signal and not a hard error.
     eprintln!(
@@ -0,0 +1,470 @@
+// 实例的端口转发管理。
+//
+// 转发由这里自己处理：在本机绑定 `bind_addr`，把连接转发到虚拟网里的 `dst_addr`，
+// 依赖 TUN 设备把虚拟 IP 路由进 overlay。创建实例时配置的转发也一样：实例启动前
+// 由 [`take_config_forwards`] 从配置里拿出来并绑定好端口，启动后接管。所以每条转发
+// 都有统计（活跃 / 累计连接数、双向字节数、失败次数和最近一次错误），都能删除。
+//
+// `no_tun` 实例没有 TUN 路由，创建时配置的转发仍交给 EasyTier 自己的转发
+// （socks5 / smoltcp），列出时 `managed` 为 `false`，没有统计、不能删除；
+// 运行时也不能再添加。
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use easytier::common::config::{ConfigLoader, PortForwardConfig, TomlConfigLoader};
+use lazy_static::lazy_static;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream, UdpSocket};
+use tokio::task::{JoinHandle, JoinSet};
+
+use super::p2p::{instance_config, runtime, Forward};
+
+/// UDP 会话多久没有流量就回收。
+const UDP_SESSION_IDLE: Duration = Duration::from_secs(60);
+const UDP_BUF_SIZE: usize = 64 * 1024;
+const TCP_BUF_SIZE: usize = 16 * 1024;
+
+lazy_static! {
+    /// instance_id -> 该实例上由这里处理的转发。
+    static ref FORWARDS: Mutex<HashMap<String, Vec<ManagedForward>>> = Mutex::new(HashMap::new());
+}
+
+struct ManagedForward {
+    proto: String,
+    bind_addr: SocketAddr,
+    dst_addr: SocketAddr,
+    stats: Arc<ForwardStats>,
+    task: JoinHandle<()>,
+}
+
+#[derive(Default)]
+struct ForwardStats {
+    active_connections: AtomicU32,
+    total_connections: AtomicU64,
+    tx_bytes: AtomicU64,
+    rx_bytes: AtomicU64,
+    errors: AtomicU64,
+    last_error: Mutex<String>,
+}
+
+/// 实例启动前已经绑定好端口的转发，见 [`take_config_forwards`]。
+pub(crate) struct PreparedForward {
+    proto: String,
+    bind_addr: SocketAddr,
+    dst_addr: SocketAddr,
+    socket: BoundSocket,
+}
+
+enum BoundSocket {
+    Tcp(std::net::TcpListener),
+    Udp(std::net::UdpSocket),
+}
+
+#[derive(Debug, Clone)]
+pub struct PortForwardC {
+    pub proto: String,
+    pub bind_addr: String,
+    pub dst_addr: String,
+    /// `false` 表示交给 EasyTier 处理的转发（`no_tun` 实例创建时配置的），
+    /// 没有统计且不能删除。
+    pub managed: bool,
+    pub active_connections: u32,
+    pub total_connections: u64,
+    /// 本机客户端 -> `dst_addr` 方向的字节数。
+    pub tx_bytes: u64,
+    /// `dst_addr` -> 本机客户端方向的字节数。
+    pub rx_bytes: u64,
+    /// accept / 连接目标 / 收包失败的次数。
+    pub errors: u64,
+    /// 最近一次失败的原因，没有失败时为空串。
+    pub last_error: String,
+}
+
+/// 列出实例上的全部转发：交给 EasyTier 的在前，其余按添加顺序。
+pub async fn list_port_forwards(instance_id: String) -> Result<Vec<PortForwardC>, String> {
+    let cfg = instance_config(&instance_id)?;
+    let mut result: Vec<PortForwardC> = cfg
+        .get_port_forwards()
+        .into_iter()
+        .map(|f| PortForwardC {
+            proto: f.proto,
+            bind_addr: f.bind_addr.to_string(),
+            dst_addr: f.dst_addr.to_string(),
+            managed: false,
+            active_connections: 0,
+            total_connections: 0,
+            tx_bytes: 0,
+            rx_bytes: 0,
+            errors: 0,
+            last_error: String::new(),
+        })
+        .collect();
+
+    let forwards = FORWARDS.lock().unwrap();
+    if let Some(list) = forwards.get(&instance_id) {
+        result.extend(list.iter().map(ManagedForward::to_c));
+    }
+    Ok(result)
+}
+
+/// 在运行中的实例上新增一条转发。`bind_addr` 被占用时返回明确的错误。
+pub async fn add_port_forward(
+    instance_id: String,
+    forward: Forward,
+) -> Result<PortForwardC, String> {
+    let cfg = instance_config(&instance_id)?;
+    if cfg.get_flags().no_tun {
+        return Err(
+            "port forward requires the tun device, but instance runs with no_tun".to_string(),
+        );
+    }
+
+    let proto = forward.proto.to_ascii_lowercase();
+    let bind_addr: SocketAddr = forward
+        .bind_addr
+        .parse()
+        .map_err(|e| format!("invalid bind address: {}, error: {}", forward.bind_addr, e))?;
+    let dst_addr: SocketAddr = forward.dst_addr.parse().map_err(|e| {
+        format!(
+            "invalid destination address: {}, error: {}",
+            forward.dst_addr, e
+        )
+    })?;
+
+    let exists = FORWARDS
+        .lock()
+        .unwrap()
+        .get(&instance_id)
+        .is_some_and(|list| {
+            list.iter()
+                .any(|f| f.proto == proto && f.bind_addr == bind_addr)
+        });
+    if exists {
+        return Err(format!(
+            "port forward {} {} already exists on this instance",
+            proto, bind_addr
+        ));
+    }
+
+    let entry = spawn_forward(prepare(proto, bind_addr, dst_addr)?)?;
+    let result = entry.to_c();
+    FORWARDS
+        .lock()
+        .unwrap()
+        .entry(instance_id)
+        .or_default()
+        .push(entry);
+    Ok(result)
+}
+
+/// 删除一条转发，已建立的连接一并断开。返回 `false` 表示没有找到
+/// （交给 EasyTier 的转发也找不到）。
+pub async fn remove_port_forward(
+    instance_id: String,
+    proto: String,
+    bind_addr: String,
+) -> Result<bool, String> {
+    let bind_addr: SocketAddr = bind_addr
+        .parse()
+        .map_err(|e| format!("invalid bind address: {}, error: {}", bind_addr, e))?;
+    let mut forwards = FORWARDS.lock().unwrap();
+    let Some(list) = forwards.get_mut(&instance_id) else {
+        return Ok(false);
+    };
+    let Some(pos) = list
+        .iter()
+        .position(|f| f.proto.eq_ignore_ascii_case(&proto) && f.bind_addr == bind_addr)
+    else {
+        return Ok(false);
+    };
+    list.remove(pos).task.abort();
+    Ok(true)
+}
+
+/// 有 TUN 的实例：把配置里的转发拿出来（EasyTier 不再处理）并立刻绑定端口，
+/// 端口被占用时创建实例直接失败。`no_tun` 实例的配置原样保留，返回空列表。
+pub(crate) fn take_config_forwards(cfg: &TomlConfigLoader) -> Result<Vec<PreparedForward>, String> {
+    if cfg.get_flags().no_tun {
+        return Ok(Vec::new());
+    }
+    let forwards: Vec<PortForwardConfig> = cfg.get_port_forwards();
+    let prepared = forwards
+        .into_iter()
+        .map(|f| prepare(f.proto.to_ascii_lowercase(), f.bind_addr, f.dst_addr))
+        .collect::<Result<Vec<_>, _>>()?;
+    cfg.set_port_forwards(Vec::new());
+    Ok(prepared)
+}
+
+/// 实例启动后接管 [`take_config_forwards`] 绑定好的转发。
+pub(crate) fn start_prepared(
+    instance_id: &str,
+    prepared: Vec<PreparedForward>,
+) -> Result<(), String> {
+    let entries = prepared
+        .into_iter()
+        .map(spawn_forward)
+        .collect::<Result<Vec<_>, _>>()?;
+    FORWARDS
+        .lock()
+        .unwrap()
+        .entry(instance_id.to_string())
+        .or_default()
+        .extend(entries);
+    Ok(())
+}
+
+/// 实例关闭时停掉它的全部转发。
+pub(crate) fn stop_all(instance_id: &str) {
+    if let Some(list) = FORWARDS.lock().unwrap().remove(instance_id) {
+        for f in list {
+            f.task.abort();
+        }
+    }
+}
+
+impl ManagedForward {
+    fn to_c(&self) -> PortForwardC {
+        PortForwardC {
+            proto: self.proto.clone(),
+            bind_addr: self.bind_addr.to_string(),
+            dst_addr: self.dst_addr.to_string(),
+            managed: true,
+            active_connections: self.stats.active_connections.load(Ordering::Relaxed),
+            total_connections: self.stats.total_connections.load(Ordering::Relaxed),
+            tx_bytes: self.stats.tx_bytes.load(Ordering::Relaxed),
+            rx_bytes: self.stats.rx_bytes.load(Ordering::Relaxed),
+            errors: self.stats.errors.load(Ordering::Relaxed),
+            last_error: self.stats.last_error.lock().unwrap().clone(),
+        }
+    }
+}
+
+impl ForwardStats {
+    /// 连接 / 收包失败记到这条转发的统计里，不按连接刷日志。
+    fn record_error(&self, error: String) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+        *self.last_error.lock().unwrap() = error;
+    }
+}
+
+/// 同步绑定端口，端口占用能立刻报给调用方，而不是在后台任务里悄悄失败。
+fn prepare(
+    proto: String,
+    bind_addr: SocketAddr,
+    dst_addr: SocketAddr,
+) -> Result<PreparedForward, String> {
+    let socket = match proto.as_str() {
+        "tcp" => BoundSocket::Tcp(bind_std(std::net::TcpListener::bind(bind_addr), bind_addr)?),
+        "udp" => BoundSocket::Udp(bind_std(std::net::UdpSocket::bind(bind_addr), bind_addr)?),
+        other => return Err(format!("unsupported port forward protocol: {}", other)),
+    };
+    Ok(PreparedForward {
+        proto,
+        bind_addr,
+        dst_addr,
+        socket,
+    })
+}
+
+fn spawn_forward(prepared: PreparedForward) -> Result<ManagedForward, String> {
+    let PreparedForward {
+        proto,
+        bind_addr,
+        dst_addr,
+        socket,
+    } = prepared;
+    let stats = Arc::new(ForwardStats::default());
+    let _guard = runtime().enter();
+    let task = match socket {
+        BoundSocket::Tcp(listener) => {
+            listener
+                .set_nonblocking(true)
+                .map_err(|e| format!("set tcp listener nonblocking failed: {}", e))?;
+            let listener = TcpListener::from_std(listener)
+                .map_err(|e| format!("register tcp listener failed: {}", e))?;
+            runtime().spawn(run_tcp_forward(listener, dst_addr, stats.clone()))
+        }
+        BoundSocket::Udp(socket) => {
+            socket
+                .set_nonblocking(true)
+                .map_err(|e| format!("set udp socket nonblocking failed: {}", e))?;
+            let socket = UdpSocket::from_std(socket)
+                .map_err(|e| format!("register udp socket failed: {}", e))?;
+            runtime().spawn(run_udp_forward(socket, dst_addr, stats.clone()))
+        }
+    };
+    Ok(ManagedForward {
+        proto,
+        bind_addr,
+        dst_addr,
+        stats,
+        task,
+    })
+}
+
+fn bind_std<T>(result: std::io::Result<T>, bind_addr: SocketAddr) -> Result<T, String> {
+    result.map_err(|e| match e.kind() {
+        std::io::ErrorKind::AddrInUse => format!("bind address {} is already in use", bind_addr),
+        std::io::ErrorKind::AddrNotAvailable => {
+            format!("bind address {} is not available on this host", bind_addr)
+        }
+        std::io::ErrorKind::PermissionDenied => {
+            format!("permission denied when binding {}", bind_addr)
+        }
+        _ => format!("bind {} failed: {}", bind_addr, e),
+    })
+}
+
+/// 连接计数的 RAII 守卫，连接任务被 abort 时也能正确减一。
+struct ActiveGuard(Arc<ForwardStats>);
+
+impl ActiveGuard {
+    fn new(stats: Arc<ForwardStats>) -> Self {
+        stats.active_connections.fetch_add(1, Ordering::Relaxed);
+        stats.total_connections.fetch_add(1, Ordering::Relaxed);
+        Self(stats)
+    }
+}
+
+impl Drop for ActiveGuard {
+    fn drop(&mut self) {
+        self.0.active_connections.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+async fn run_tcp_forward(listener: TcpListener, dst_addr: SocketAddr, stats: Arc<ForwardStats>) {
+    // JoinSet 随 accept 任务一起被 drop，abort 转发时所有连接一并断开。
+    let mut conns = JoinSet::new();
+    loop {
+        while conns.try_join_next().is_some() {}
+        let (inbound, _) = match listener.accept().await {
+            Ok(v) => v,
+            Err(e) => {
+                stats.record_error(format!("tcp accept failed: {}", e));
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                continue;
+            }
+        };
+        let stats = stats.clone();
+        conns.spawn(async move {
+            let _active = ActiveGuard::new(stats.clone());
+            let outbound = match TcpStream::connect(dst_addr).await {
+                Ok(s) => s,
+                Err(e) => {
+                    stats.record_error(format!("connect {} failed: {}", dst_addr, e));
+                    return;
+                }
+            };
+            let _ = inbound.set_nodelay(true);
+            let _ = outbound.set_nodelay(true);
+            let (in_r, in_w) = inbound.into_split();
+            let (out_r, out_w) = outbound.into_split();
+            tokio::join!(
+                pipe(in_r, out_w, &stats.tx_bytes),
+                pipe(out_r, in_w, &stats.rx_bytes),
+            );
+        });
+    }
+}
+
+async fn pipe<R, W>(mut reader: R, mut writer: W, counter: &AtomicU64)
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let mut buf = vec![0u8; TCP_BUF_SIZE];
+    loop {
+        let n = match reader.read(&mut buf).await {
+            Ok(0) | Err(_) => break,
+            Ok(n) => n,
+        };
+        if writer.write_all(&buf[..n]).await.is_err() {
+            break;
+        }
+        counter.fetch_add(n as u64, Ordering::Relaxed);
+    }
+    let _ = writer.shutdown().await;
+}
+
+async fn run_udp_forward(socket: UdpSocket, dst_addr: SocketAddr, stats: Arc<ForwardStats>) {
+    let socket = Arc::new(socket);
+    // 每个客户端地址一个 connect 到 dst 的 socket，回程包由会话任务发回客户端。
+    let mut sessions: HashMap<SocketAddr, Arc<UdpSocket>> = HashMap::new();
+    let mut session_tasks = JoinSet::new();
+    let (closed_tx, mut closed_rx) = tokio::sync::mpsc::unbounded_channel::<SocketAddr>();
+    let mut buf = vec![0u8; UDP_BUF_SIZE];
+    loop {
+        let (n, client) = tokio::select! {
+            r = socket.recv_from(&mut buf) => match r {
+                Ok(v) => v,
+                Err(e) => {
+                    // Windows 上对端不可达时 recv_from 会报 ConnectionReset，忽略继续。
+                    stats.record_error(format!("udp recv failed: {}", e));
+                    continue;
+                }
+            },
+            Some(client) = closed_rx.recv() => {
+                sessions.remove(&client);
+                while session_tasks.try_join_next().is_some() {}
+                continue;
+            }
+        };
+
+        let session = match sessions.get(&client) {
+            Some(s) => s.clone(),
+            None => {
+                let bind: SocketAddr = if dst_addr.is_ipv4() {
+                    ([0, 0, 0, 0], 0).into()
+                } else {
+                    ([0u16; 8], 0).into()
+                };
+                let upstream = match UdpSocket::bind(bind).await {
+                    Ok(s) => s,
+                    Err(e) => {
+                        stats.record_error(format!("udp session bind failed: {}", e));
+                        continue;
+                    }
+                };
+                if let Err(e) = upstream.connect(dst_addr).await {
+                    stats.record_error(format!("udp connect {} failed: {}", dst_addr, e));
+                    continue;
+                }
+                let upstream = Arc::new(upstream);
+                sessions.insert(client, upstream.clone());
+                session_tasks.spawn(run_udp_session(
+                    upstream.clone(),
+                    socket.clone(),
+                    client,
+                    stats.clone(),
+                    closed_tx.clone(),
+                ));
+                upstream
+            }
+        };
+        if session.send(&buf[..n]).await.is_ok() {
+            stats.tx_bytes.fetch_add(n as u64, Ordering::Relaxed);
+        }
+    }
+}
+
+async fn run_udp_session(
+    upstream: Arc<UdpSocket>,
+    downstream: Arc<UdpSocket>,
+    client: SocketAddr,
+    stats: Arc<ForwardStats>,
+    closed: tokio::sync::mpsc::UnboundedSender<SocketAddr>,
+) {
+    let _active = ActiveGuard::new(stats.clone());
+    let mut buf = vec![0u8; UDP_BUF_SIZE];
+    while let Ok(Ok(n)) = tokio::time::timeout(UDP_SESSION_IDLE, upstream.recv(&mut buf)).await {
+        if downstream.send_to(&buf[..n], client).await.is_ok() {
+            stats.rx_bytes.fetch_add(n as u64, Ordering::Relaxed);
+        }
+    }
+    let _ = closed.send(client);
+}
@@ -0,0 +1,695 @@
+// 监听端口的 UPnP / NAT-PMP 映射。
+//
+// 两个来源：
+// - EasyTier 自己建立的映射，通过 `ListenerPortMappingEstablished` 事件上报，这里只记录；
+// - 由本模块按实例管理的映射：可以开关、可以指定外部端口，先试 UPnP，失败再试 NAT-PMP，
+//   租约过半时续期，续期失败上报 `Failed`，租约到期仍未续上上报 `Expired`。
+//
+// 建立成功的映射作为 mapped listener 通过配置补丁公布给其他节点，本机的公网入口可以用
+// [`get_local_endpoints`] 查询（邀请信息里带上这些地址即可）。
+//
+// 对外展示的 `local_listener` 是脱敏后的，内部一律按真实的监听 URL 对应映射。
+// 实例关闭时在删除实例之前撤掉映射、停掉续期任务，见 [`forget_instance`]。
+
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use easytier::common::config::ConfigLoader;
+use easytier::common::global_ctx::GlobalCtxEvent;
+use easytier::proto::api::config::{ConfigPatchAction, InstanceConfigPatch};
+use igd_next::aio::tokio::search_gateway;
+use igd_next::{PortMappingProtocol, SearchOptions};
+use lazy_static::lazy_static;
+use tokio::sync::broadcast;
+use tokio::task::AbortHandle;
+
+use super::p2p::{
+    connector_patch, instance_config, patch_instance_config, runtime, subscribe_instance_events,
+};
+use super::redact;
+use crate::frb_generated::StreamSink;
+
+const LEASE_SECS: u32 = 3600;
+const RETRY_INTERVAL: Duration = Duration::from_secs(30);
+const MAPPING_DESCRIPTION: &str = "Astral";
+
+lazy_static! {
+    static ref INSTANCES: Mutex<HashMap<String, InstanceMappings>> = Mutex::new(HashMap::new());
+    static ref EVENTS: broadcast::Sender<(String, ListenerMappingC)> = broadcast::channel(64).0;
+}
+
+static GENERATION: AtomicU64 = AtomicU64::new(1);
+
+#[derive(Default)]
+struct InstanceMappings {
+    /// 0 表示本模块的映射未启用。
+    generation: u64,
+    mappings: Vec<Mapping>,
+    /// 本模块映射的续期任务，撤销映射时直接中止。
+    renewals: Vec<AbortHandle>,
+}
+
+struct Mapping {
+    /// 本模块管理的映射对应的真实监听 URL；EasyTier 上报的为 `None`。
+    listener: Option<url::Url>,
+    info: ListenerMappingC,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortMappingStateC {
+    Active,
+    /// 续期失败，映射在租约到期前仍然有效。
+    Failed,
+    Expired,
+    Removed,
+}
+
+#[derive(Debug, Clone)]
+pub struct ListenerMappingC {
+    /// 本机监听 URL（已脱敏）。
+    pub local_listener: String,
+    /// 公网地址，例如 `tcp://203.0.113.5:11010`；建立失败时为空串。
+    pub mapped_listener: String,
+    /// `upnp` / `natpmp`，EasyTier 上报的映射为其原始 backend 名。
+    pub backend: String,
+    pub state: PortMappingStateC,
+    /// 由 EasyTier 建立（只读），而不是本模块管理的。
+    pub managed_by_easytier: bool,
+    /// 剩余租约秒数；未知或已失效时为 0。
+    pub lease_remaining_secs: u32,
+    pub error: String,
+}
+
+/// 请求映射的监听；`external_port` 为 0 表示和本地端口相同。
+#[derive(Debug, Clone)]
+pub struct PortMappingRequestC {
+    pub listener: String,
+    pub external_port: u16,
+}
+
+#[derive(Debug, Clone)]
+pub struct LocalEndpointsC {
+    pub listeners: Vec<String>,
+    /// 对外公布的公网入口（端口映射建立的、配置里手动写的）。
+    pub mapped_listeners: Vec<String>,
+}
+
+/// 为实例的监听建立端口映射。`requests` 为空时映射所有监听、外部端口与本地相同。
+/// 重复调用会先撤掉之前的映射。返回每个监听的映射结果。
+pub async fn enable_port_mapping(
+    instance_id: String,
+    requests: Vec<PortMappingRequestC>,
+) -> Result<Vec<ListenerMappingC>, String> {
+    disable_port_mapping(instance_id.clone()).await?;
+    let cfg = instance_config(&instance_id)?;
+    let requests: Vec<(url::Url, u16)> = if requests.is_empty() {
+        cfg.get_listeners()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|l| (l, 0))
+            .collect()
+    } else {
+        requests
+            .into_iter()
+            .map(|r| {
+                r.listener
+                    .parse()
+                    .map(|l| (l, r.external_port))
+                    .map_err(|e| {
+                        format!(
+                            "invalid listener url: {}, error: {}",
+                            redact::url(&r.listener),
+                            e
+                        )
+                    })
+            })
+            .collect::<Result<_, _>>()?
+    };
+
+    let generation = GENERATION.fetch_add(1, Ordering::Relaxed);
+    let mut results: Vec<(url::Url, ListenerMappingC)> = Vec::new();
+    let mut established = Vec::new();
+    for (listener, external_port) in requests {
+        let Some((protocol, local_port)) = listener_protocol(&listener) else {
+            results.push((
+                listener.clone(),
+                ListenerMappingC {
+                    local_listener: redact::url(listener.as_str()),
+                    mapped_listener: String::new(),
+                    backend: String::new(),
+                    state: PortMappingStateC::Failed,
+                    managed_by_easytier: false,
+                    lease_remaining_secs: 0,
+                    error: format!(
+                        "cannot map a {} listener: unsupported protocol or port 0",
+                        listener.scheme()
+                    ),
+                },
+            ));
+            continue;
+        };
+        let external_port = if external_port == 0 {
+            local_port
+        } else {
+            external_port
+        };
+        match map_port(protocol, local_port, external_port).await {
+            Ok(mapped) => {
+                let mapped_listener = mapped_url(&listener, mapped.external);
+                results.push((
+                    listener.clone(),
+                    ListenerMappingC {
+                        local_listener: redact::url(listener.as_str()),
+                        mapped_listener: mapped_listener.to_string(),
+                        backend: mapped.backend.name().to_string(),
+                        state: PortMappingStateC::Active,
+                        managed_by_easytier: false,
+                        lease_remaining_secs: mapped.lease_secs,
+                        error: String::new(),
+                    },
+                ));
+                established.push((listener, mapped_listener, protocol, local_port, mapped));
+            }
+            Err(error) => results.push((
+                listener.clone(),
+                ListenerMappingC {
+                    local_listener: redact::url(listener.as_str()),
+                    mapped_listener: String::new(),
+                    backend: String::new(),
+                    state: PortMappingStateC::Failed,
+                    managed_by_easytier: false,
+                    lease_remaining_secs: 0,
+                    error,
+                },
+            )),
+        }
+    }
+
+    if let Err(e) = advertise(
+        &instance_id,
+        established.iter().map(|(_, m, ..)| m.to_string()).collect(),
+        ConfigPatchAction::Add,
+    )
+    .await
+    {
+        // 没公布出去的映射也没有续期任务管，直接撤掉，不留在网关上。
+        for (_, _, protocol, local_port, mapped) in established {
+            unmap_port(
+                mapped.backend.name(),
+                protocol,
+                local_port,
+                mapped.external.port(),
+            )
+            .await;
+        }
+        return Err(e);
+    }
+
+    let renewals = established
+        .into_iter()
+        .map(|(listener, mapped_listener, protocol, local_port, mapped)| {
+            runtime()
+                .spawn(renew(
+                    instance_id.clone(),
+                    generation,
+                    listener,
+                    mapped_listener,
+                    protocol,
+                    local_port,
+                    mapped,
+                ))
+                .abort_handle()
+        })
+        .collect();
+    {
+        let mut instances = INSTANCES.lock().unwrap();
+        let entry = instances.entry(instance_id.clone()).or_default();
+        entry.generation = generation;
+        entry.renewals = renewals;
+        entry.mappings.retain(|m| m.listener.is_none());
+        entry
+            .mappings
+            .extend(results.iter().map(|(listener, info)| Mapping {
+                listener: Some(listener.clone()),
+                info: info.clone(),
+            }));
+    }
+    let results: Vec<ListenerMappingC> = results.into_iter().map(|(_, info)| info).collect();
+    for m in &results {
+        let _ = EVENTS.send((instance_id.clone(), m.clone()));
+    }
+    Ok(results)
+}
+
+/// 撤掉本模块为实例建立的所有映射，并停止对外公布。EasyTier 自己的映射不受影响。
+pub async fn disable_port_mapping(instance_id: String) -> Result<(), String> {
+    let removed = take_managed(&instance_id, false);
+    let active: Vec<String> = removed
+        .iter()
+        .filter(|m| is_live(&m.info))
+        .map(|m| m.info.mapped_listener.clone())
+        .collect();
+    release(&instance_id, removed).await;
+    // 实例已关闭时补丁会失败，这时也不需要再撤公布了。
+    let _ = advertise(&instance_id, active, ConfigPatchAction::Remove).await;
+    Ok(())
+}
+
+/// 当前所有映射：本模块管理的和 EasyTier 上报的。
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_port_mappings(instance_id: String) -> Vec<ListenerMappingC> {
+    INSTANCES
+        .lock()
+        .unwrap()
+        .get(&instance_id)
+        .map(|e| e.mappings.iter().map(|m| m.info.clone()).collect())
+        .unwrap_or_default()
+}
+
+/// 映射建立、续期失败、到期、撤销时推送。
+pub async fn subscribe_port_mapping_events(
+    instance_id: String,
+    sink: StreamSink<ListenerMappingC>,
+) -> Result<(), String> {
+    let mut events = EVENTS.subscribe();
+    loop {
+        match events.recv().await {
+            Ok((id, mapping)) => {
+                if id == instance_id && sink.add(mapping).is_err() {
+                    break;
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+    Ok(())
+}
+
+/// 本机的监听地址和对外公布的公网入口，用于生成邀请信息、展示本机节点信息。
+pub async fn get_local_endpoints(instance_id: String) -> Result<LocalEndpointsC, String> {
+    let cfg = instance_config(&instance_id)?;
+    let mut mapped_listeners: Vec<String> = cfg
+        .get_mapped_listeners()
+        .into_iter()
+        .map(|u| u.to_string())
+        .collect();
+    for m in list_port_mappings(instance_id) {
+        if m.state == PortMappingStateC::Active && !mapped_listeners.contains(&m.mapped_listener) {
+            mapped_listeners.push(m.mapped_listener);
+        }
+    }
+    Ok(LocalEndpointsC {
+        listeners: cfg
+            .get_listeners()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|u| u.to_string())
+            .collect(),
+        mapped_listeners,
+    })
+}
+
+/// 记录 EasyTier 自己建立的映射。实例启动时调用；实例重启（`restart_instance`）后
+/// 新实例没有之前的公布，这里把仍然有效的映射重新公布一次。
+pub(crate) fn watch_instance(instance_id: &str) {
+    let Ok(mut events) = subscribe_instance_events(instance_id) else {
+        return;
+    };
+    let instance_id = instance_id.to_string();
+    runtime().spawn(async move {
+        let active: Vec<String> = list_port_mappings(instance_id.clone())
+            .into_iter()
+            .filter(|m| !m.managed_by_easytier && is_live(m))
+            .map(|m| m.mapped_listener)
+            .collect();
+        let _ = advertise(&instance_id, active, ConfigPatchAction::Add).await;
+
+        loop {
+            let event = match events.recv().await {
+                Ok(e) => e,
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+            let GlobalCtxEvent::ListenerPortMappingEstablished {
+                local_listener,
+                mapped_listener,
+                backend,
+            } = event
+            else {
+                continue;
+            };
+            let mapping = ListenerMappingC {
+                local_listener: redact::url(local_listener.as_str()),
+                mapped_listener: mapped_listener.to_string(),
+                backend: backend.to_string(),
+                state: PortMappingStateC::Active,
+                managed_by_easytier: true,
+                lease_remaining_secs: 0,
+                error: String::new(),
+            };
+            {
+                let mut instances = INSTANCES.lock().unwrap();
+                let entry = instances.entry(instance_id.clone()).or_default();
+                entry.mappings.retain(|m| {
+                    !(m.info.managed_by_easytier
+                        && m.info.local_listener == mapping.local_listener)
+                });
+                entry.mappings.push(Mapping {
+                    listener: None,
+                    info: mapping.clone(),
+                });
+            }
+            let _ = EVENTS.send((instance_id.clone(), mapping));
+        }
+    });
+}
+
+/// 实例关闭时撤掉映射，要在删除实例之前调用：这里同步停掉续期任务、清掉记录，
+/// 再在后台撤掉网关上的映射。公布随实例一起消失，不用再打补丁撤回。
+pub(crate) fn forget_instance(instance_id: &str) {
+    let removed = take_managed(instance_id, true);
+    if removed.is_empty() {
+        return;
+    }
+    let instance_id = instance_id.to_string();
+    runtime().spawn(async move { release(&instance_id, removed).await });
+}
+
+/// 取出本模块管理的映射并中止续期任务；`forget` 时连 EasyTier 上报的记录一起删掉。
+fn take_managed(instance_id: &str, forget: bool) -> Vec<Mapping> {
+    let mut instances = INSTANCES.lock().unwrap();
+    let Some(entry) = instances.get_mut(instance_id) else {
+        return Vec::new();
+    };
+    entry.generation = 0;
+    for renewal in entry.renewals.drain(..) {
+        renewal.abort();
+    }
+    let (ours, theirs) = entry
+        .mappings
+        .drain(..)
+        .partition(|m| m.listener.is_some());
+    entry.mappings = theirs;
+    if forget {
+        instances.remove(instance_id);
+    }
+    ours
+}
+
+/// 撤掉网关上仍然有效的映射，并上报 `Removed`。
+async fn release(instance_id: &str, removed: Vec<Mapping>) {
+    for m in &removed {
+        if !is_live(&m.info) {
+            continue;
+        }
+        let (Some(listener), Ok(mapped)) = (
+            m.listener.as_ref(),
+            m.info.mapped_listener.parse::<url::Url>(),
+        ) else {
+            continue;
+        };
+        if let (Some((protocol, local_port)), Some(external_port)) =
+            (listener_protocol(listener), mapped.port())
+        {
+            unmap_port(&m.info.backend, protocol, local_port, external_port).await;
+        }
+    }
+    for m in removed {
+        let mut info = m.info;
+        info.state = PortMappingStateC::Removed;
+        info.lease_remaining_secs = 0;
+        let _ = EVENTS.send((instance_id.to_string(), info));
+    }
+}
+
+/// 映射建立成功且租约还没到期。
+fn is_live(m: &ListenerMappingC) -> bool {
+    !m.mapped_listener.is_empty() && m.state != PortMappingStateC::Expired
+}
+
+#[derive(Clone, Copy)]
+enum Backend {
+    Upnp,
+    NatPmp,
+}
+
+impl Backend {
+    fn name(self) -> &'static str {
+        match self {
+            Backend::Upnp => "upnp",
+            Backend::NatPmp => "natpmp",
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Mapped {
+    backend: Backend,
+    external: SocketAddr,
+    lease_secs: u32,
+}
+
+async fn map_port(
+    protocol: PortMappingProtocol,
+    local_port: u16,
+    external_port: u16,
+) -> Result<Mapped, String> {
+    let upnp_err = match map_upnp(protocol, local_port, external_port).await {
+        Ok(mapped) => return Ok(mapped),
+        Err(e) => e,
+    };
+    map_natpmp(protocol, local_port, external_port)
+        .await
+        .map_err(|natpmp_err| format!("upnp: {}; nat-pmp: {}", upnp_err, natpmp_err))
+}
+
+async fn map_upnp(
+    protocol: PortMappingProtocol,
+    local_port: u16,
+    external_port: u16,
+) -> Result<Mapped, String> {
+    let gateway = search_gateway(SearchOptions::default())
+        .await
+        .map_err(|e| format!("gateway search failed: {}", e))?;
+    let local_ip = local_ip_towards(gateway.addr)?;
+    gateway
+        .add_port(
+            protocol,
+            external_port,
+            SocketAddr::new(local_ip, local_port),
+            LEASE_SECS,
+            MAPPING_DESCRIPTION,
+        )
+        .await
+        .map_err(|e| format!("add port failed: {}", e))?;
+    let external_ip = gateway
+        .get_external_ip()
+        .await
+        .map_err(|e| format!("get external ip failed: {}", e))?;
+    Ok(Mapped {
+        backend: Backend::Upnp,
+        external: SocketAddr::new(external_ip, external_port),
+        lease_secs: LEASE_SECS,
+    })
+}
+
+async fn map_natpmp(
+    protocol: PortMappingProtocol,
+    local_port: u16,
+    external_port: u16,
+) -> Result<Mapped, String> {
+    let client = natpmp::new_tokio_natpmp()
+        .await
+        .map_err(|e| format!("no nat-pmp gateway: {:?}", e))?;
+    client
+        .send_public_address_request()
+        .await
+        .map_err(|e| format!("public address request failed: {:?}", e))?;
+    let external_ip = match client.read_response_or_retry().await {
+        Ok(natpmp::Response::Gateway(gw)) => IpAddr::V4(*gw.public_address()),
+        Ok(_) => return Err("unexpected nat-pmp response".to_string()),
+        Err(e) => return Err(format!("public address request failed: {:?}", e)),
+    };
+    let natpmp_protocol = match protocol {
+        PortMappingProtocol::TCP => natpmp::Protocol::TCP,
+        PortMappingProtocol::UDP => natpmp::Protocol::UDP,
+    };
+    client
+        .send_port_mapping_request(natpmp_protocol, local_port, external_port, LEASE_SECS)
+        .await
+        .map_err(|e| format!("mapping request failed: {:?}", e))?;
+    match client.read_response_or_retry().await {
+        Ok(natpmp::Response::TCP(m)) | Ok(natpmp::Response::UDP(m)) => Ok(Mapped {
+            backend: Backend::NatPmp,
+            external: SocketAddr::new(external_ip, m.public_port()),
+            lease_secs: m.lifetime().as_secs() as u32,
+        }),
+        Ok(_) => Err("unexpected nat-pmp response".to_string()),
+        Err(e) => Err(format!("mapping request failed: {:?}", e)),
+    }
+}
+
+async fn unmap_port(
+    backend: &str,
+    protocol: PortMappingProtocol,
+    local_port: u16,
+    external_port: u16,
+) {
+    if backend == Backend::Upnp.name() {
+        if let Ok(gateway) = search_gateway(SearchOptions::default()).await {
+            let _ = gateway.remove_port(protocol, external_port).await;
+        }
+    } else if let Ok(client) = natpmp::new_tokio_natpmp().await {
+        // NAT-PMP 按内网端口删除：外部端口和 lifetime 都填 0。
+        let natpmp_protocol = match protocol {
+            PortMappingProtocol::TCP => natpmp::Protocol::TCP,
+            PortMappingProtocol::UDP => natpmp::Protocol::UDP,
+        };
+        if client
+            .send_port_mapping_request(natpmp_protocol, local_port, 0, 0)
+            .await
+            .is_ok()
+        {
+            let _ = client.read_response_or_retry().await;
+        }
+    }
+}
+
+/// 租约过半时续期；续期失败每 [`RETRY_INTERVAL`] 重试，直到租约到期。
+async fn renew(
+    instance_id: String,
+    generation: u64,
+    listener: url::Url,
+    mapped_listener: url::Url,
+    protocol: PortMappingProtocol,
+    local_port: u16,
+    mut mapped: Mapped,
+) {
+    let mut expires_at = Instant::now() + Duration::from_secs(mapped.lease_secs as u64);
+    let mut wait = Duration::from_secs((mapped.lease_secs / 2).max(1) as u64);
+    loop {
+        tokio::time::sleep(wait).await;
+        if !still_current(&instance_id, generation) {
+            return;
+        }
+        let result = match mapped.backend {
+            Backend::Upnp => map_upnp(protocol, local_port, mapped.external.port()).await,
+            Backend::NatPmp => map_natpmp(protocol, local_port, mapped.external.port()).await,
+        };
+        let (state, error) = match result {
+            Ok(renewed) => {
+                mapped = renewed;
+                expires_at = Instant::now() + Duration::from_secs(mapped.lease_secs as u64);
+                wait = Duration::from_secs((mapped.lease_secs / 2).max(1) as u64);
+                (PortMappingStateC::Active, String::new())
+            }
+            Err(e) if Instant::now() < expires_at => {
+                wait = RETRY_INTERVAL.min(expires_at - Instant::now());
+                (PortMappingStateC::Failed, e)
+            }
+            Err(e) => (PortMappingStateC::Expired, e),
+        };
+
+        let mapping = {
+            let mut instances = INSTANCES.lock().unwrap();
+            let Some(entry) = instances
+                .get_mut(&instance_id)
+                .filter(|e| e.generation == generation)
+            else {
+                return;
+            };
+            let Some(m) = entry
+                .mappings
+                .iter_mut()
+                .find(|m| m.listener.as_ref() == Some(&listener))
+                .map(|m| &mut m.info)
+            else {
+                return;
+            };
+            let changed = m.state != state;
+            m.state = state;
+            m.error = error;
+            m.lease_remaining_secs = expires_at
+                .saturating_duration_since(Instant::now())
+                .as_secs() as u32;
+            changed.then(|| m.clone())
+        };
+        if let Some(mapping) = mapping {
+            let _ = EVENTS.send((instance_id.clone(), mapping));
+        }
+        if state == PortMappingStateC::Expired {
+            let _ = advertise(
+                &instance_id,
+                vec![mapped_listener.to_string()],
+                ConfigPatchAction::Remove,
+            )
+            .await;
+            return;
+        }
+    }
+}
+
+fn still_current(instance_id: &str, generation: u64) -> bool {
+    INSTANCES
+        .lock()
+        .unwrap()
+        .get(instance_id)
+        .is_some_and(|e| e.generation == generation)
+}
+
+/// 配置补丁的 `mapped_listeners` 和 connector 用的是同一种 `UrlPatch`。
+async fn advertise(
+    instance_id: &str,
+    mapped_listeners: Vec<String>,
+    action: ConfigPatchAction,
+) -> Result<(), String> {
+    if mapped_listeners.is_empty() {
+        return Ok(());
+    }
+    patch_instance_config(
+        instance_id,
+        InstanceConfigPatch {
+            mapped_listeners: mapped_listeners
+                .iter()
+                .map(|u| connector_patch(u, action))
+                .collect(),
+            ..Default::default()
+        },
+    )
+    .await
+}
+
+/// 基于 TCP 的监听映射 TCP 端口，其余映射 UDP 端口。端口为 0 的监听无法映射。
+fn listener_protocol(listener: &url::Url) -> Option<(PortMappingProtocol, u16)> {
+    let port = listener.port_or_known_default().filter(|p| *p != 0)?;
+    let protocol = match listener.scheme() {
+        "tcp" | "ws" | "wss" | "faketcp" => PortMappingProtocol::TCP,
+        "udp" | "quic" | "wg" | "kcp" => PortMappingProtocol::UDP,
+        _ => return None,
+    };
+    Some((protocol, port))
+}
+
+fn mapped_url(listener: &url::Url, external: SocketAddr) -> url::Url {
+    let mut mapped = listener.clone();
+    let _ = mapped.set_ip_host(external.ip());
+    let _ = mapped.set_port(Some(external.port()));
+    mapped
+}
+
+/// 连向网关时本机使用的地址，作为映射的内网目标。
+fn local_ip_towards(gateway: SocketAddr) -> Result<IpAddr, String> {
+    let socket =
+        std::net::UdpSocket::bind(("0.0.0.0", 0)).map_err(|e| format!("udp bind failed: {}", e))?;
+    socket
+        .connect(gateway)
+        .map_err(|e| format!("route to gateway failed: {}", e))?;
+    socket
+        .local_addr()
+        .map(|a| a.ip())
+        .map_err(|e| format!("local address failed: {}", e))
+}
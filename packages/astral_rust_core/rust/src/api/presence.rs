@@ -0,0 +1,646 @@
+// Presence and user profiles.
+//
+// `Route.hostname` was the only identity peers had; apps swapped names and
+// avatars through their own RPC methods. Here every node that publishes a
+// profile announces it to each peer in the route table as a `Notify` on the
+// reserved channel [`PRESENCE_CHANNEL`]:
+//
+//   announce { peer_id, seq, profile fields, reply }
+//   leave    { peer_id, seq }
+//
+// Announces are not signed. What ties a profile to a node is the `peer_id`
+// check against the sender EasyTier reports for the notify; announces that
+// fail it, or carry no valid profile, are dropped and counted (see
+// [`get_presence_bad_announces`]). `seq` is the publish time in milliseconds;
+// older announces are ignored.
+//
+// A background task greets peers as they appear (immediately on `PeerAdded`,
+// otherwise on the next sweep, which also catches peers behind relays) and
+// drops profiles of peers that left the route table.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use easytier::peers::astral_app_rpc as app_rpc;
+use lazy_static::lazy_static;
+use serde_json::{json, Value};
+use tokio::sync::broadcast;
+
+use super::app_broadcast::{app_broadcast, BroadcastScopeC, BroadcastScopeKindC};
+use super::app_router::subscribe_inbound;
+use super::p2p::{
+    get_instance_info, get_network_status, lookup_app_rpc, runtime, subscribe_instance_events,
+    tracing_log_lagged, AppInboundEventC, AppInboundKindC, EventBusSubscriber, GlobalCtxEvent,
+    KVNodeInfo,
+};
+use crate::frb_generated::StreamSink;
+
+/// Reserved channel carrying profile announces.
+pub(crate) const PRESENCE_CHANNEL: &str = "astral.presence";
+
+const NOTIFY_TIMEOUT_MS: i32 = 5_000;
+const SWEEP_INTERVAL: Duration = Duration::from_secs(10);
+/// Longest accepted value for any text field, in bytes.
+const MAX_FIELD_LEN: usize = 256;
+
+lazy_static! {
+    static ref PRESENCE: Mutex<HashMap<String, Presence>> = Mutex::new(HashMap::new());
+    static ref EVENTS: broadcast::Sender<(String, PresenceEventC)> = broadcast::channel(256).0;
+}
+
+static GENERATION: AtomicU64 = AtomicU64::new(1);
+
+struct Presence {
+    /// Generation of the running tasks.
+    generation: u64,
+    local: PresenceProfileC,
+    seq: u64,
+    /// Accepted profiles by peer id, with their `seq`.
+    peers: HashMap<u32, (u64, PresenceProfileC)>,
+    /// Peers that already got our current profile.
+    greeted: HashSet<u32>,
+    /// Dropped messages on the presence channel.
+    bad_announces: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresenceStatusC {
+    Online,
+    Away,
+    Busy,
+}
+
+#[derive(Debug, Clone)]
+pub struct PresenceProfileC {
+    pub display_name: String,
+    /// Hex hash of the avatar image; the image itself is fetched separately
+    /// (e.g. with `offer_file`) when the hash isn't cached.
+    pub avatar_hash: String,
+    pub status: PresenceStatusC,
+    pub app_name: String,
+    pub app_version: String,
+    /// Empty when not in a game.
+    pub current_game: String,
+}
+
+/// A node from `get_network_status` joined with its profile, if it published
+/// one.
+#[derive(Debug)]
+pub struct PeerPresenceC {
+    pub node: KVNodeInfo,
+    pub profile: Option<PresenceProfileC>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresenceEventKindC {
+    Updated,
+    /// The peer withdrew its profile or left the network.
+    Left,
+}
+
+/// Flat struct rather than an enum with data, like `AppInboundEventC`.
+#[derive(Debug, Clone)]
+pub struct PresenceEventC {
+    pub kind: PresenceEventKindC,
+    pub peer_id: u32,
+    /// Set for [`PresenceEventKindC::Updated`].
+    pub profile: Option<PresenceProfileC>,
+}
+
+/// Publish or update this node's profile and announce it to every peer. The
+/// first call starts the presence service for the instance; peers' profiles
+/// are only collected while it runs.
+pub async fn set_presence_profile(
+    instance_id: String,
+    profile: PresenceProfileC,
+) -> Result<(), String> {
+    check_profile(&profile)?;
+    let svc = lookup_app_rpc(&instance_id)?;
+    let seq = now_ms();
+    let start = {
+        let mut presence = PRESENCE.lock().unwrap();
+        match presence.get_mut(&instance_id) {
+            Some(p) => {
+                p.local = profile;
+                p.seq = seq.max(p.seq + 1);
+                p.greeted.clear();
+                None
+            }
+            None => {
+                let generation = GENERATION.fetch_add(1, Ordering::Relaxed);
+                presence.insert(
+                    instance_id.clone(),
+                    Presence {
+                        generation,
+                        local: profile,
+                        seq,
+                        peers: HashMap::new(),
+                        greeted: HashSet::new(),
+                        bad_announces: 0,
+                    },
+                );
+                Some(generation)
+            }
+        }
+    };
+    if let Some(generation) = start {
+        let events = subscribe_instance_events(&instance_id)?;
+        spawn_tasks(&instance_id, generation, &svc, events);
+    }
+    drop(svc);
+
+    // Awaited on the FRB executor like `app_call`; peers that miss it are
+    // greeted again by the sweep.
+    let Some(announce) = local_message(&instance_id, "announce", false) else {
+        return Ok(());
+    };
+    let deliveries = app_broadcast(
+        instance_id.clone(),
+        PRESENCE_CHANNEL.to_string(),
+        announce.to_string().into_bytes(),
+        BroadcastScopeC {
+            kind: BroadcastScopeKindC::All,
+            peer_ids: Vec::new(),
+        },
+        NOTIFY_TIMEOUT_MS,
+    )
+    .await?;
+    if let Some(p) = PRESENCE.lock().unwrap().get_mut(&instance_id) {
+        p.greeted
+            .extend(deliveries.iter().filter(|d| d.delivered).map(|d| d.peer_id));
+    }
+    Ok(())
+}
+
+/// Withdraw this node's profile: peers drop it and the service stops until
+/// the next [`set_presence_profile`].
+pub async fn clear_presence_profile(instance_id: String) -> Result<(), String> {
+    let Some(leave) = local_message(&instance_id, "leave", false) else {
+        return Ok(());
+    };
+    PRESENCE.lock().unwrap().remove(&instance_id);
+    app_broadcast(
+        instance_id,
+        PRESENCE_CHANNEL.to_string(),
+        leave.to_string().into_bytes(),
+        BroadcastScopeC {
+            kind: BroadcastScopeKindC::All,
+            peer_ids: Vec::new(),
+        },
+        NOTIFY_TIMEOUT_MS,
+    )
+    .await?;
+    Ok(())
+}
+
+/// This node's published profile, if any.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_presence_profile(instance_id: String) -> Option<PresenceProfileC> {
+    PRESENCE
+        .lock()
+        .unwrap()
+        .get(&instance_id)
+        .map(|p| p.local.clone())
+}
+
+/// Presence messages dropped since the service started for the instance:
+/// malformed, sent for another peer id, or with an invalid profile.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_presence_bad_announces(instance_id: String) -> u64 {
+    PRESENCE
+        .lock()
+        .unwrap()
+        .get(&instance_id)
+        .map(|p| p.bad_announces)
+        .unwrap_or_default()
+}
+
+/// `get_network_status` nodes joined with cached profiles by peer id. The
+/// local node carries this node's own profile.
+pub async fn list_peer_presence(instance_id: String) -> Result<Vec<PeerPresenceC>, String> {
+    let my_peer_id = lookup_app_rpc(&instance_id)?.my_peer_id();
+    let status = get_network_status(instance_id.clone()).await;
+    let presence = PRESENCE.lock().unwrap();
+    let presence = presence.get(&instance_id);
+    Ok(status
+        .nodes
+        .into_iter()
+        .map(|node| {
+            let profile = presence.and_then(|p| {
+                if node.peer_id == my_peer_id {
+                    Some(p.local.clone())
+                } else {
+                    p.peers
+                        .get(&node.peer_id)
+                        .map(|(_, profile)| profile.clone())
+                }
+            });
+            PeerPresenceC { node, profile }
+        })
+        .collect())
+}
+
+/// Profile changes and departures of peers.
+pub async fn subscribe_presence(
+    instance_id: String,
+    sink: StreamSink<PresenceEventC>,
+) -> Result<(), String> {
+    let mut events = EVENTS.subscribe();
+    loop {
+        match events.recv().await {
+            Ok((id, event)) => {
+                if id == instance_id && sink.add(event).is_err() {
+                    break;
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                tracing_log_lagged(&instance_id, skipped);
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+    Ok(())
+}
+
+/// Whether the channel router must leave `channel` to this module.
+pub(crate) fn is_handled(instance_id: &str, channel: &str) -> bool {
+    channel == PRESENCE_CHANNEL && PRESENCE.lock().unwrap().contains_key(instance_id)
+}
+
+/// Restart the tasks for a restarted instance, keeping the local profile.
+/// Peer ids don't survive a restart, so cached profiles are dropped.
+pub(crate) fn watch_instance(instance_id: &str) {
+    let Ok(svc) = lookup_app_rpc(instance_id) else {
+        return;
+    };
+    let Ok(events) = subscribe_instance_events(instance_id) else {
+        return;
+    };
+    let (generation, left) = {
+        let mut presence = PRESENCE.lock().unwrap();
+        let Some(p) = presence.get_mut(instance_id) else {
+            return;
+        };
+        p.generation = GENERATION.fetch_add(1, Ordering::Relaxed);
+        p.greeted.clear();
+        let left: Vec<u32> = p.peers.drain().map(|(peer_id, _)| peer_id).collect();
+        (p.generation, left)
+    };
+    for peer_id in left {
+        emit_left(instance_id, peer_id);
+    }
+    spawn_tasks(instance_id, generation, &svc, events);
+}
+
+pub(crate) fn forget_instance(instance_id: &str) {
+    PRESENCE.lock().unwrap().remove(instance_id);
+}
+
+fn spawn_tasks(
+    instance_id: &str,
+    generation: u64,
+    svc: &app_rpc::AstralAppRpcService,
+    mut events: EventBusSubscriber,
+) {
+    let mut rx = subscribe_inbound(instance_id, svc);
+    let id = instance_id.to_string();
+    runtime().spawn(async move {
+        loop {
+            let evt = match rx.recv().await {
+                Ok(evt) => evt,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing_log_lagged(&id, skipped);
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+            let AppInboundEventC {
+                kind: AppInboundKindC::Notify,
+                from_peer_id,
+                channel,
+                payload,
+                ..
+            } = evt
+            else {
+                continue;
+            };
+            if channel != PRESENCE_CHANNEL {
+                continue;
+            }
+            if !is_current(&id, generation) {
+                break;
+            }
+            match serde_json::from_slice::<Value>(&payload) {
+                Ok(msg) => handle_message(&id, from_peer_id, &msg).await,
+                Err(_) => count_bad_announce(&id),
+            }
+        }
+    });
+
+    let id = instance_id.to_string();
+    runtime().spawn(async move {
+        let mut sweep = tokio::time::interval(SWEEP_INTERVAL);
+        loop {
+            tokio::select! {
+                event = events.recv() => match event {
+                    Ok(GlobalCtxEvent::PeerAdded(_) | GlobalCtxEvent::PeerRemoved(_)) => {}
+                    Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+                _ = sweep.tick() => {}
+            }
+            if !is_current(&id, generation) {
+                break;
+            }
+            sync_with_routes(&id).await;
+        }
+    });
+}
+
+fn is_current(instance_id: &str, generation: u64) -> bool {
+    PRESENCE
+        .lock()
+        .unwrap()
+        .get(instance_id)
+        .is_some_and(|p| p.generation == generation)
+}
+
+/// Drop profiles of peers no longer routed and greet new ones.
+async fn sync_with_routes(instance_id: &str) {
+    let Ok(info) = get_instance_info(instance_id).await else {
+        return;
+    };
+    let routed: HashSet<u32> = info.routes.iter().map(|r| r.peer_id).collect();
+    let (left, new) = {
+        let mut presence = PRESENCE.lock().unwrap();
+        let Some(p) = presence.get_mut(instance_id) else {
+            return;
+        };
+        let left: Vec<u32> = p
+            .peers
+            .keys()
+            .filter(|id| !routed.contains(id))
+            .copied()
+            .collect();
+        for peer_id in &left {
+            p.peers.remove(peer_id);
+        }
+        p.greeted.retain(|id| routed.contains(id));
+        let new: Vec<u32> = routed
+            .iter()
+            .filter(|id| !p.greeted.contains(id))
+            .copied()
+            .collect();
+        (left, new)
+    };
+    for peer_id in left {
+        emit_left(instance_id, peer_id);
+    }
+    for peer_id in new {
+        greet(instance_id, peer_id, true).await;
+    }
+}
+
+/// Send our profile to one peer, asking for theirs back if `reply`.
+async fn greet(instance_id: &str, peer_id: u32, reply: bool) {
+    let Ok(svc) = lookup_app_rpc(instance_id) else {
+        return;
+    };
+    if peer_id == svc.my_peer_id() {
+        return;
+    }
+    let Some(announce) = local_message(instance_id, "announce", reply) else {
+        return;
+    };
+    let sent = svc
+        .notify(
+            peer_id,
+            PRESENCE_CHANNEL.to_string(),
+            announce.to_string().into_bytes(),
+            NOTIFY_TIMEOUT_MS,
+        )
+        .await
+        .is_ok();
+    if sent {
+        if let Some(p) = PRESENCE.lock().unwrap().get_mut(instance_id) {
+            p.greeted.insert(peer_id);
+        }
+    }
+}
+
+async fn handle_message(instance_id: &str, from_peer_id: u32, msg: &Value) {
+    let op = msg["op"].as_str().unwrap_or_default();
+    let seq = msg["seq"].as_u64().unwrap_or_default();
+    // Only the node itself may speak for its peer id; this is what binds the
+    // profile to the sender.
+    if msg["peer_id"].as_u64() != Some(from_peer_id as u64) {
+        count_bad_announce(instance_id);
+        return;
+    }
+    let profile = match op {
+        "announce" => match parse_profile(msg) {
+            Some(profile) => Some(profile),
+            None => {
+                count_bad_announce(instance_id);
+                return;
+            }
+        },
+        "leave" => None,
+        _ => {
+            count_bad_announce(instance_id);
+            return;
+        }
+    };
+
+    let changed = {
+        let mut presence = PRESENCE.lock().unwrap();
+        let Some(p) = presence.get_mut(instance_id) else {
+            return;
+        };
+        // A leave reuses the seq of the announce it withdraws.
+        let stale = match profile {
+            Some(_) => p.peers.get(&from_peer_id).is_some_and(|(s, _)| *s >= seq),
+            None => p.peers.get(&from_peer_id).is_none_or(|(s, _)| *s > seq),
+        };
+        if stale {
+            false
+        } else {
+            match &profile {
+                Some(profile) => {
+                    p.peers.insert(from_peer_id, (seq, profile.clone()));
+                }
+                None => {
+                    p.peers.remove(&from_peer_id);
+                }
+            }
+            true
+        }
+    };
+    if changed {
+        match profile {
+            Some(profile) => emit(
+                instance_id,
+                PresenceEventC {
+                    kind: PresenceEventKindC::Updated,
+                    peer_id: from_peer_id,
+                    profile: Some(profile),
+                },
+            ),
+            None => emit_left(instance_id, from_peer_id),
+        }
+    }
+    if op == "announce" && msg["reply"].as_bool().unwrap_or_default() {
+        greet(instance_id, from_peer_id, false).await;
+    }
+}
+
+/// A message about this node's profile, or `None` when presence isn't
+/// running for the instance.
+fn local_message(instance_id: &str, op: &str, reply: bool) -> Option<Value> {
+    let my_peer_id = lookup_app_rpc(instance_id).ok()?.my_peer_id();
+    let (seq, profile) = {
+        let presence = PRESENCE.lock().unwrap();
+        let p = presence.get(instance_id)?;
+        (p.seq, p.local.clone())
+    };
+    let profile = (op == "announce").then_some(profile);
+    let mut msg = json!({ "op": op, "peer_id": my_peer_id, "seq": seq });
+    if let Some(profile) = profile {
+        msg["display_name"] = json!(profile.display_name);
+        msg["avatar_hash"] = json!(profile.avatar_hash);
+        msg["status"] = json!(status_str(profile.status));
+        msg["app_name"] = json!(profile.app_name);
+        msg["app_version"] = json!(profile.app_version);
+        msg["current_game"] = json!(profile.current_game);
+        msg["reply"] = json!(reply);
+    }
+    Some(msg)
+}
+
+fn count_bad_announce(instance_id: &str) {
+    if let Some(p) = PRESENCE.lock().unwrap().get_mut(instance_id) {
+        p.bad_announces += 1;
+    }
+}
+
+fn parse_profile(msg: &Value) -> Option<PresenceProfileC> {
+    let text = |key: &str| msg[key].as_str().map(str::to_string);
+    let profile = PresenceProfileC {
+        display_name: text("display_name")?,
+        avatar_hash: text("avatar_hash").unwrap_or_default(),
+        status: match msg["status"].as_str() {
+            Some("away") => PresenceStatusC::Away,
+            Some("busy") => PresenceStatusC::Busy,
+            _ => PresenceStatusC::Online,
+        },
+        app_name: text("app_name").unwrap_or_default(),
+        app_version: text("app_version").unwrap_or_default(),
+        current_game: text("current_game").unwrap_or_default(),
+    };
+    check_profile(&profile).ok()?;
+    Some(profile)
+}
+
+fn check_profile(profile: &PresenceProfileC) -> Result<(), String> {
+    let fields = [
+        &profile.display_name,
+        &profile.avatar_hash,
+        &profile.app_name,
+        &profile.app_version,
+        &profile.current_game,
+    ];
+    if fields.iter().any(|f| f.len() > MAX_FIELD_LEN) {
+        return Err(format!(
+            "profile fields must be at most {} bytes",
+            MAX_FIELD_LEN
+        ));
+    }
+    Ok(())
+}
+
+fn status_str(status: PresenceStatusC) -> &'static str {
+    match status {
+        PresenceStatusC::Online => "online",
+        PresenceStatusC::Away => "away",
+        PresenceStatusC::Busy => "busy",
+    }
+}
+
+fn emit_left(instance_id: &str, peer_id: u32) {
+    emit(
+        instance_id,
+        PresenceEventC {
+            kind: PresenceEventKindC::Left,
+            peer_id,
+            profile: None,
+        },
+    );
+}
+
+fn emit(instance_id: &str, event: PresenceEventC) {
+    let _ = EVENTS.send((instance_id.to_string(), event));
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start(instance_id: &str) {
+        PRESENCE.lock().unwrap().insert(
+            instance_id.to_string(),
+            Presence {
+                generation: GENERATION.fetch_add(1, Ordering::Relaxed),
+                local: PresenceProfileC {
+                    display_name: "me".to_string(),
+                    avatar_hash: String::new(),
+                    status: PresenceStatusC::Online,
+                    app_name: String::new(),
+                    app_version: String::new(),
+                    current_game: String::new(),
+                },
+                seq: 1,
+                peers: HashMap::new(),
+                greeted: HashSet::new(),
+                bad_announces: 0,
+            },
+        );
+    }
+
+    #[tokio::test]
+    async fn bad_announces_are_counted_and_dropped() {
+        let instance_id = "presence-test-bad";
+        start(instance_id);
+
+        // Claims another peer id than the sender's.
+        let spoofed = json!({ "op": "announce", "peer_id": 8, "seq": 5, "display_name": "x" });
+        handle_message(instance_id, 7, &spoofed).await;
+        let no_name = json!({ "op": "announce", "peer_id": 7, "seq": 5 });
+        handle_message(instance_id, 7, &no_name).await;
+        let unknown = json!({ "op": "poke", "peer_id": 7, "seq": 5 });
+        handle_message(instance_id, 7, &unknown).await;
+        assert_eq!(get_presence_bad_announces(instance_id.to_string()), 3);
+        assert!(PRESENCE.lock().unwrap()[instance_id].peers.is_empty());
+
+        let announce = json!({ "op": "announce", "peer_id": 7, "seq": 5, "display_name": "x" });
+        handle_message(instance_id, 7, &announce).await;
+        assert_eq!(get_presence_bad_announces(instance_id.to_string()), 3);
+        assert_eq!(
+            PRESENCE.lock().unwrap()[instance_id].peers[&7]
+                .1
+                .display_name,
+            "x"
+        );
+
+        forget_instance(instance_id);
+    }
+}
@@ -0,0 +1,290 @@
+// 子网代理（proxy CIDR / subnet router）的运行时管理与冲突检测。
+//
+// 本机导出的子网通过 EasyTier 的配置补丁（`ConfigRpc::patch_config`）在运行中增删，
+// 不需要重启实例；导出格式与 EasyTier 命令行一致：`10.0.0.0/24`，或带地址映射的
+// `10.0.0.0/24->192.168.1.0/24`（真实网段 -> 对外公布的网段）。
+//
+// 冲突检测覆盖三类问题：
+// - 导出的网段和虚拟网网段重叠；
+// - 和其他节点导出的网段重叠（路由不确定走哪个节点）；
+// - 其他节点导出的网段和本机网卡所在网段重叠（本机走本地路由，访问不到对端子网）。
+
+use std::net::Ipv4Addr;
+
+use cidr::{Ipv4Cidr, Ipv4Inet};
+use easytier::common::config::{ConfigLoader, TomlConfigLoader};
+use easytier::proto::api::config::{ConfigPatchAction, InstanceConfigPatch, ProxyNetworkPatch};
+use network_interface::{Addr, NetworkInterface, NetworkInterfaceConfig};
+
+use super::p2p::{get_instance_info, get_peer_route_pairs, instance_config, patch_instance_config};
+
+#[derive(Debug, Clone)]
+pub struct ProxyCidrC {
+    pub cidr: String,
+    /// 对外公布的映射网段；没有映射时为空串。
+    pub mapped_cidr: String,
+}
+
+/// 某个节点导出的子网，来自路由表的 `Route.proxy_cidrs`。
+#[derive(Debug, Clone)]
+pub struct PeerProxyCidrsC {
+    pub peer_id: u32,
+    pub hostname: String,
+    pub cidrs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProxyCidrConflictKindC {
+    /// 和虚拟网网段重叠。
+    VirtualNetwork,
+    /// 和其他节点导出的网段重叠。
+    PeerExport,
+    /// 对端导出的网段和本机网卡网段重叠。
+    LocalInterface,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProxyCidrConflictC {
+    pub kind: ProxyCidrConflictKindC,
+    pub cidr: String,
+    pub other_cidr: String,
+    /// `PeerExport` / `LocalInterface` 时为导出该网段的节点，否则为 0。
+    pub peer_id: u32,
+    pub hostname: String,
+    /// `LocalInterface` 时为本机网卡名，否则为空串。
+    pub interface: String,
+}
+
+/// 本机当前导出的子网。
+pub async fn list_proxy_cidrs(instance_id: String) -> Result<Vec<ProxyCidrC>, String> {
+    let cfg = instance_config(&instance_id)?;
+    Ok(cfg
+        .get_proxy_cidrs()
+        .into_iter()
+        .map(|p| ProxyCidrC {
+            cidr: p.cidr.to_string(),
+            mapped_cidr: p.mapped_cidr.map(|c| c.to_string()).unwrap_or_default(),
+        })
+        .collect())
+}
+
+/// 运行中新增导出子网。`mapped_cidr` 为空串表示不做地址映射。
+///
+/// 冲突不会阻止添加（例如和其他节点做主备导出是合法用法），检测结果原样返回给调用方展示。
+pub async fn add_proxy_cidr(
+    instance_id: String,
+    cidr: String,
+    mapped_cidr: String,
+) -> Result<Vec<ProxyCidrConflictC>, String> {
+    let cidr = parse_cidr(&cidr)?;
+    let mapped = if mapped_cidr.trim().is_empty() {
+        None
+    } else {
+        Some(parse_cidr(&mapped_cidr)?)
+    };
+    if let Some(mapped) = mapped {
+        if mapped.network_length() != cidr.network_length() {
+            return Err(format!(
+                "mapped cidr {} must have the same prefix length as {}",
+                mapped, cidr
+            ));
+        }
+    }
+
+    let cfg = instance_config(&instance_id)?;
+    patch_instance_config(
+        &instance_id,
+        InstanceConfigPatch {
+            proxy_networks: vec![ProxyNetworkPatch {
+                action: ConfigPatchAction::Add as i32,
+                cidr: Some(cidr.first().into()),
+                mapped_cidr: mapped.map(|m| m.first().into()),
+            }],
+            ..Default::default()
+        },
+    )
+    .await?;
+    // 快照和运行中的配置保持一致；EasyTier 对重复网段是幂等的。
+    cfg.add_proxy_cidr(cidr, mapped)
+        .map_err(|e| format!("add proxy cidr failed: {}", e))?;
+
+    let advertised = mapped.unwrap_or(cidr);
+    Ok(check_proxy_cidr_conflicts(instance_id)
+        .await?
+        .into_iter()
+        .filter(|c| c.cidr == advertised.to_string() || c.cidr == cidr.to_string())
+        .collect())
+}
+
+/// 运行中移除导出子网。返回 `false` 表示本机没有导出该网段。
+pub async fn remove_proxy_cidr(instance_id: String, cidr: String) -> Result<bool, String> {
+    let cidr = parse_cidr(&cidr)?;
+    let cfg = instance_config(&instance_id)?;
+    let Some(existing) = cfg.get_proxy_cidrs().into_iter().find(|p| p.cidr == cidr) else {
+        return Ok(false);
+    };
+
+    patch_instance_config(
+        &instance_id,
+        InstanceConfigPatch {
+            proxy_networks: vec![ProxyNetworkPatch {
+                action: ConfigPatchAction::Remove as i32,
+                cidr: Some(cidr.first().into()),
+                mapped_cidr: existing.mapped_cidr.map(|m| m.first().into()),
+            }],
+            ..Default::default()
+        },
+    )
+    .await?;
+    cfg.remove_proxy_cidr(cidr);
+    Ok(true)
+}
+
+/// 每个节点导出了哪些子网，不含本机（本机用 [`list_proxy_cidrs`]）。
+pub async fn list_peer_proxy_cidrs(instance_id: String) -> Result<Vec<PeerProxyCidrsC>, String> {
+    let pairs = get_peer_route_pairs(instance_id).await?;
+    let mut result: Vec<PeerProxyCidrsC> = pairs
+        .into_iter()
+        .filter_map(|p| p.route)
+        .filter(|r| r.inst_id != "local" && !r.proxy_cidrs.is_empty())
+        .map(|r| PeerProxyCidrsC {
+            peer_id: r.peer_id,
+            hostname: r.hostname,
+            cidrs: r.proxy_cidrs,
+        })
+        .collect();
+    result.sort_by_key(|p| p.peer_id);
+    result.dedup_by_key(|p| p.peer_id);
+    Ok(result)
+}
+
+/// 对本机导出、对端导出、虚拟网网段和本机网卡做一次全量冲突检测。
+pub async fn check_proxy_cidr_conflicts(
+    instance_id: String,
+) -> Result<Vec<ProxyCidrConflictC>, String> {
+    let cfg = instance_config(&instance_id)?;
+    let local: Vec<Ipv4Cidr> = cfg
+        .get_proxy_cidrs()
+        .into_iter()
+        .map(|p| p.mapped_cidr.unwrap_or(p.cidr))
+        .collect();
+    let virtual_net = virtual_network(&instance_id, &cfg).await;
+    let peers = list_peer_proxy_cidrs(instance_id).await?;
+
+    let mut conflicts = Vec::new();
+    for cidr in &local {
+        if let Some(vnet) = virtual_net.filter(|v| overlaps(cidr, v)) {
+            conflicts.push(ProxyCidrConflictC {
+                kind: ProxyCidrConflictKindC::VirtualNetwork,
+                cidr: cidr.to_string(),
+                other_cidr: vnet.to_string(),
+                peer_id: 0,
+                hostname: String::new(),
+                interface: String::new(),
+            });
+        }
+        for peer in &peers {
+            for other in peer.cidrs.iter().filter_map(|c| parse_advertised(c)) {
+                if overlaps(cidr, &other) {
+                    conflicts.push(ProxyCidrConflictC {
+                        kind: ProxyCidrConflictKindC::PeerExport,
+                        cidr: cidr.to_string(),
+                        other_cidr: other.to_string(),
+                        peer_id: peer.peer_id,
+                        hostname: peer.hostname.clone(),
+                        interface: String::new(),
+                    });
+                }
+            }
+        }
+    }
+
+    let interfaces = local_interface_networks(&cfg.get_flags().dev_name);
+    for peer in &peers {
+        for other in peer.cidrs.iter().filter_map(|c| parse_advertised(c)) {
+            for (name, net) in &interfaces {
+                if overlaps(&other, net) {
+                    conflicts.push(ProxyCidrConflictC {
+                        kind: ProxyCidrConflictKindC::LocalInterface,
+                        cidr: other.to_string(),
+                        other_cidr: net.to_string(),
+                        peer_id: peer.peer_id,
+                        hostname: peer.hostname.clone(),
+                        interface: name.clone(),
+                    });
+                }
+            }
+        }
+    }
+    Ok(conflicts)
+}
+
+/// 虚拟网网段取运行时分配到的地址：DHCP 模式下配置里没有地址。
+/// 实例信息暂时拿不到（还没启动完）时退回配置里的静态地址。
+async fn virtual_network(instance_id: &str, cfg: &TomlConfigLoader) -> Option<Ipv4Cidr> {
+    let assigned = get_instance_info(instance_id).await.ok().and_then(|info| {
+        let addr = info.my_node_info?.virtual_ipv4?;
+        Ipv4Inet::new(
+            Ipv4Addr::from(addr.address?.addr),
+            addr.network_length as u8,
+        )
+        .ok()
+    });
+    assigned
+        .or_else(|| cfg.get_ipv4())
+        .map(|inet| inet.network())
+}
+
+/// 解析创建实例时传入的 `cidrs` 条目：`a.b.c.d/n` 或 `a.b.c.d/n->e.f.g.h/n`。
+pub(crate) fn parse_proxy_cidr_spec(spec: &str) -> Result<(Ipv4Cidr, Option<Ipv4Cidr>), String> {
+    match spec.split_once("->") {
+        Some((real, mapped)) => Ok((parse_cidr(real)?, Some(parse_cidr(mapped)?))),
+        None => Ok((parse_cidr(spec)?, None)),
+    }
+}
+
+fn parse_cidr(s: &str) -> Result<Ipv4Cidr, String> {
+    s.trim()
+        .parse()
+        .map_err(|e| format!("invalid cidr: {}, error: {}", s.trim(), e))
+}
+
+/// 路由里公布的网段；带映射时取对外公布的那一段。
+fn parse_advertised(s: &str) -> Option<Ipv4Cidr> {
+    let advertised = s.rsplit("->").next().unwrap_or(s);
+    advertised.trim().parse().ok()
+}
+
+fn overlaps(a: &Ipv4Cidr, b: &Ipv4Cidr) -> bool {
+    a.contains(&b.first_address()) || b.contains(&a.first_address())
+}
+
+/// 本机网卡所在的 IPv4 网段（排除回环和实例自己的 TUN 设备）。
+fn local_interface_networks(tun_dev_name: &str) -> Vec<(String, Ipv4Cidr)> {
+    let Ok(interfaces) = NetworkInterface::show() else {
+        return Vec::new();
+    };
+    let mut result = Vec::new();
+    for iface in interfaces {
+        if iface.name == tun_dev_name {
+            continue;
+        }
+        for addr in &iface.addr {
+            let Addr::V4(v4) = addr else {
+                continue;
+            };
+            if v4.ip.is_loopback() {
+                continue;
+            }
+            let Some(netmask) = v4.netmask else {
+                continue;
+            };
+            let len = u32::from(netmask).leading_ones() as u8;
+            let network = Ipv4Addr::from(u32::from(v4.ip) & u32::from(netmask));
+            if let Ok(net) = Ipv4Cidr::new(network, len) {
+                result.push((iface.name.clone(), net));
+            }
+        }
+    }
+    result
+}
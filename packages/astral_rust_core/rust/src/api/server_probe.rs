@@ -0,0 +1,148 @@
+// 不建实例直接测服务器：DNS 解析 + 按协议做一次隧道握手，得到握手延迟。
+//
+// 握手用的是 EasyTier 自己的隧道 connector，测到的是真实的协议握手（UDP 隧道的
+// SYN/ACK、WebSocket upgrade、QUIC 握手），而不只是端口可达。WireGuard 服务器不回应
+// 未认证的握手包，没有网络凭据时无法探测，直接报告不支持。
+
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+
+use easytier::tunnel::quic::QUICTunnelConnector;
+use easytier::tunnel::tcp::TcpTunnelConnector;
+use easytier::tunnel::udp::UdpTunnelConnector;
+use easytier::tunnel::websocket::WSTunnelConnector;
+use easytier::tunnel::TunnelConnector;
+use tokio::net::lookup_host;
+use tokio::task::JoinSet;
+use tokio::time::timeout;
+
+use super::redact;
+
+/// URL 里没写端口时使用 EasyTier 的默认端口。
+const DEFAULT_PORT: u16 = 11010;
+
+#[derive(Debug, Clone)]
+pub struct ServerProbeResultC {
+    /// 传入的 URL（已脱敏）。
+    pub url: String,
+    pub scheme: String,
+    /// 该协议能否在不加入网络的情况下探测。
+    pub protocol_supported: bool,
+    /// 实际握手的地址；解析失败时为空串。
+    pub resolved_addr: String,
+    pub dns_ms: u32,
+    pub reachable: bool,
+    /// 隧道握手耗时，不含 DNS；不可达时为 0。
+    pub latency_ms: u32,
+    pub error: String,
+}
+
+/// 并发探测一组服务器，结果顺序与 `urls` 一致。`timeout_ms` 是单个服务器的总超时。
+pub async fn probe_servers(urls: Vec<String>, timeout_ms: u32) -> Vec<ServerProbeResultC> {
+    let limit = Duration::from_millis(timeout_ms.max(1) as u64);
+    let mut tasks = JoinSet::new();
+    for (index, url) in urls.into_iter().enumerate() {
+        tasks.spawn(async move { (index, probe_server(&url, limit).await) });
+    }
+    let mut results = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        if let Ok(result) = joined {
+            results.push(result);
+        }
+    }
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, r)| r).collect()
+}
+
+/// 探测单个服务器，供服务器选择等模块复用。
+pub(crate) async fn probe_server(url: &str, limit: Duration) -> ServerProbeResultC {
+    let mut result = ServerProbeResultC {
+        url: redact::url(url),
+        scheme: String::new(),
+        protocol_supported: false,
+        resolved_addr: String::new(),
+        dns_ms: 0,
+        reachable: false,
+        latency_ms: 0,
+        error: String::new(),
+    };
+    let parsed: url::Url = match url.parse() {
+        Ok(u) => u,
+        Err(e) => {
+            result.error = format!("invalid server url: {}", e);
+            return result;
+        }
+    };
+    result.scheme = parsed.scheme().to_string();
+    result.protocol_supported = matches!(parsed.scheme(), "tcp" | "udp" | "ws" | "wss" | "quic");
+    if parsed.scheme() == "wg" {
+        result.error =
+            "wireguard servers only answer authenticated handshakes; probe with another protocol"
+                .to_string();
+        return result;
+    }
+    if !result.protocol_supported {
+        result.error = format!("unsupported protocol: {}", parsed.scheme());
+        return result;
+    }
+    let Some(host) = parsed.host_str() else {
+        result.error = "server url has no host".to_string();
+        return result;
+    };
+    let port = parsed.port_or_known_default().unwrap_or(DEFAULT_PORT);
+
+    let started = Instant::now();
+    let addr = match timeout(limit, lookup_host((host, port))).await {
+        Ok(Ok(mut addrs)) => match addrs.next() {
+            Some(addr) => addr,
+            None => {
+                result.error = "resolve returned no address".to_string();
+                return result;
+            }
+        },
+        Ok(Err(e)) => {
+            result.error = format!("resolve failed: {}", e);
+            return result;
+        }
+        Err(_) => {
+            result.error = "resolve timed out".to_string();
+            return result;
+        }
+    };
+    result.dns_ms = started.elapsed().as_millis() as u32;
+    result.resolved_addr = addr.to_string();
+
+    let remaining = limit.saturating_sub(started.elapsed());
+    let started = Instant::now();
+    match timeout(remaining, handshake(&parsed, addr)).await {
+        Ok(Ok(())) => {
+            result.reachable = true;
+            result.latency_ms = started.elapsed().as_millis() as u32;
+        }
+        Ok(Err(e)) => result.error = format!("handshake failed: {}", e),
+        Err(_) => result.error = "handshake timed out".to_string(),
+    }
+    result
+}
+
+/// 对已解析的地址做一次隧道握手，成功后立即断开。
+async fn handshake(parsed: &url::Url, addr: SocketAddr) -> Result<(), String> {
+    // connector 只认 IP，先把主机名换成解析结果；wss 需要 SNI，保留原 URL。
+    let mut target = parsed.clone();
+    if parsed.scheme() != "wss" {
+        target
+            .set_ip_host(addr.ip())
+            .map_err(|_| "invalid resolved address".to_string())?;
+        target
+            .set_port(Some(addr.port()))
+            .map_err(|_| "invalid resolved port".to_string())?;
+    }
+    let tunnel = match parsed.scheme() {
+        "tcp" => TcpTunnelConnector::new(target).connect().await,
+        "udp" => UdpTunnelConnector::new(target).connect().await,
+        "ws" | "wss" => WSTunnelConnector::new(target).connect().await,
+        "quic" => QUICTunnelConnector::new(target).connect().await,
+        scheme => return Err(format!("unsupported protocol: {}", scheme)),
+    };
+    tunnel.map(drop).map_err(|e| e.to_string())
+}
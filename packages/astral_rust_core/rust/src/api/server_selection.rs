@@ -0,0 +1,443 @@
+// 多服务器时的自动选择与故障切换。
+//
+// `create_server_with_flags` 会把所有服务器都加成静态 peer。启用选择策略后：
+// - 先用 [`probe_server`] 测一遍所有服务器，延迟最低的 N 个保持连接（active），
+//   其余通过配置补丁从 connector 里移除，作为备用（standby）；
+// - 后台定期检查 active 服务器是否还连着，掉线超过宽限期就从备用里重新测速，
+//   换上延迟最低的那个；
+// - 每个服务器当前的角色和原因通过 [`get_server_selection_status`] 查询。
+//
+// 测速是并发的，服务器再多也只等一个超时。实例因修改监听地址重启后，所有服务器会
+// 重新加回 connector，由 p2p 的重启流程按原来的数量重新选择一次。
+//
+// 配置补丁的 connector 项只有 URL，固定了公钥（`PeerConfig.peer_public_key`）的服务器
+// 用补丁加回会丢掉公钥校验。所以这种服务器需要从 standby 换回 active 时改为重启实例，
+// 配置里的 peer 原样加回，再由重启流程重新选择；移除不受影响，仍走补丁。
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use easytier::common::config::ConfigLoader;
+use easytier::proto::api::config::{ConfigPatchAction, InstanceConfigPatch};
+use futures::stream::{self, StreamExt};
+use lazy_static::lazy_static;
+use tokio::net::lookup_host;
+use tokio::time::timeout;
+
+use super::p2p::{
+    connected_remote_addrs, connector_patch, instance_config, patch_instance_config,
+    restart_with_pinned_peers, runtime,
+};
+use super::redact;
+use super::server_probe::{probe_server, ServerProbeResultC};
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+const CHECK_INTERVAL: Duration = Duration::from_secs(5);
+/// active 服务器连续这么久没连上才切换，给首次连接和 EasyTier 自己的重连留时间。
+const FAILOVER_GRACE: Duration = Duration::from_secs(20);
+/// URL 里没写端口时使用 EasyTier 的默认端口。
+const DEFAULT_PORT: u16 = 11010;
+/// 同时进行的测速数。
+const MAX_CONCURRENCY: usize = 8;
+
+lazy_static! {
+    static ref SELECTIONS: Mutex<HashMap<String, Selection>> = Mutex::new(HashMap::new());
+}
+
+/// 每次启用都换一个代号，旧的后台任务看到代号变了就退出。
+static GENERATION: AtomicU64 = AtomicU64::new(1);
+
+struct Selection {
+    generation: u64,
+    active_count: usize,
+    servers: Vec<ServerEntry>,
+}
+
+struct ServerEntry {
+    url: String,
+    role: ServerRoleC,
+    latency_ms: u32,
+    resolved_addr: String,
+    reason: String,
+    connected: bool,
+    /// 固定了公钥，从 standby 加回时要重启实例，见模块说明。
+    pinned: bool,
+    /// 最近一次连上（或被设为 active）的时间，用于判断掉线多久了。
+    last_ok: Instant,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServerRoleC {
+    Active,
+    Standby,
+}
+
+#[derive(Debug, Clone)]
+pub struct SelectedServerC {
+    /// 服务器 URL（已脱敏）。
+    pub url: String,
+    pub role: ServerRoleC,
+    /// 最近一次测速结果；不可达时为 0。
+    pub latency_ms: u32,
+    pub connected: bool,
+    /// 为什么是当前角色，例如 "lowest latency (23 ms)"、"failed over from ..."。
+    pub reason: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ServerSelectionStatusC {
+    pub enabled: bool,
+    pub active_count: u32,
+    pub servers: Vec<SelectedServerC>,
+}
+
+/// 对实例启用服务器选择：只连延迟最低的 `active_count` 个服务器，其余备用。
+/// 重复调用会按新的数量重新测速选择。
+pub async fn enable_server_selection(
+    instance_id: String,
+    active_count: u32,
+) -> Result<ServerSelectionStatusC, String> {
+    if active_count == 0 {
+        return Err("active_count must be at least 1".to_string());
+    }
+    let cfg = instance_config(&instance_id)?;
+    let peers = cfg.get_peers();
+    let urls: Vec<String> = peers.iter().map(|p| p.uri.to_string()).collect();
+    if urls.is_empty() {
+        return Err("instance has no servers".to_string());
+    }
+
+    let mut probed = probe_all(urls).await;
+    // 可达的按延迟排在前面，不可达的排在最后。
+    probed.sort_by_key(|(_, r)| (!r.reachable, r.latency_ms));
+
+    let active_count = (active_count as usize).min(probed.len());
+    let mut servers = Vec::new();
+    for (index, (url, result)) in probed.into_iter().enumerate() {
+        let active = index < active_count;
+        let reason = match (active, result.reachable) {
+            (true, true) => format!("lowest latency ({} ms)", result.latency_ms),
+            (true, false) => format!(
+                "not enough reachable servers; probe failed: {}",
+                result.error
+            ),
+            (false, true) => format!(
+                "standby: slower than active servers ({} ms)",
+                result.latency_ms
+            ),
+            (false, false) => format!("standby: probe failed: {}", result.error),
+        };
+        let pinned = peers
+            .iter()
+            .any(|p| p.uri.as_str() == url && p.peer_public_key.is_some());
+        servers.push(ServerEntry {
+            url,
+            role: if active {
+                ServerRoleC::Active
+            } else {
+                ServerRoleC::Standby
+            },
+            latency_ms: result.latency_ms,
+            resolved_addr: result.resolved_addr,
+            reason,
+            connected: false,
+            pinned,
+            last_ok: Instant::now(),
+        });
+    }
+
+    // 所有服务器都在 connector 里（启动时加的），先把 standby 的移掉；
+    // 重复启用时，之前是 standby、现在是 active 的要重新加回。
+    let previous_standby: Vec<String> = SELECTIONS
+        .lock()
+        .unwrap()
+        .get(&instance_id)
+        .map(|s| {
+            s.servers
+                .iter()
+                .filter(|s| s.role == ServerRoleC::Standby)
+                .map(|s| s.url.clone())
+                .collect()
+        })
+        .unwrap_or_default();
+    let readd: Vec<&ServerEntry> = servers
+        .iter()
+        .filter(|s| s.role == ServerRoleC::Active && previous_standby.contains(&s.url))
+        .collect();
+    let restart = readd.iter().any(|s| s.pinned);
+    let patches = servers
+        .iter()
+        .filter(|s| s.role == ServerRoleC::Standby)
+        .map(|s| connector_patch(&s.url, ConfigPatchAction::Remove))
+        .chain(
+            readd
+                .iter()
+                .filter(|_| !restart)
+                .map(|s| connector_patch(&s.url, ConfigPatchAction::Add)),
+        )
+        .collect();
+    patch_instance_config(
+        &instance_id,
+        InstanceConfigPatch {
+            connectors: patches,
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    let generation = GENERATION.fetch_add(1, Ordering::Relaxed);
+    SELECTIONS.lock().unwrap().insert(
+        instance_id.clone(),
+        Selection {
+            generation,
+            active_count,
+            servers,
+        },
+    );
+    let status = get_server_selection_status(instance_id.clone()).await;
+    if restart {
+        // 重启流程会按新的数量重新选择一次，这里不再起后台任务。
+        restart_with_pinned_peers(&instance_id).await?;
+    } else {
+        runtime().spawn(supervise(instance_id, generation));
+    }
+    status
+}
+
+/// 停用服务器选择，恢复连接所有服务器。有固定公钥的 standby 服务器时会重启实例，
+/// 见模块说明。
+pub async fn disable_server_selection(instance_id: String) -> Result<(), String> {
+    let Some(selection) = SELECTIONS.lock().unwrap().remove(&instance_id) else {
+        return Ok(());
+    };
+    if selection
+        .servers
+        .iter()
+        .any(|s| s.role == ServerRoleC::Standby && s.pinned)
+    {
+        return restart_with_pinned_peers(&instance_id).await;
+    }
+    patch_instance_config(
+        &instance_id,
+        InstanceConfigPatch {
+            connectors: selection
+                .servers
+                .iter()
+                .filter(|s| s.role == ServerRoleC::Standby)
+                .map(|s| connector_patch(&s.url, ConfigPatchAction::Add))
+                .collect(),
+            ..Default::default()
+        },
+    )
+    .await
+}
+
+/// 每个服务器当前是 active 还是 standby，以及原因。未启用选择时所有服务器都是 active。
+pub async fn get_server_selection_status(
+    instance_id: String,
+) -> Result<ServerSelectionStatusC, String> {
+    let connected = connected_remote_addrs(&instance_id).await;
+    if let Some(status) = selection_status(&instance_id, &connected) {
+        return Ok(status);
+    }
+
+    let mut servers = Vec::new();
+    for peer in instance_config(&instance_id)?.get_peers() {
+        servers.push(SelectedServerC {
+            url: redact::url(peer.uri.as_str()),
+            role: ServerRoleC::Active,
+            latency_ms: 0,
+            connected: resolves_to_connected(&peer.uri, &connected).await,
+            reason: "server selection disabled; connecting to every server".to_string(),
+        });
+    }
+    Ok(ServerSelectionStatusC {
+        enabled: false,
+        active_count: servers.len() as u32,
+        servers,
+    })
+}
+
+fn selection_status(
+    instance_id: &str,
+    connected: &[SocketAddr],
+) -> Option<ServerSelectionStatusC> {
+    let mut selections = SELECTIONS.lock().unwrap();
+    let selection = selections.get_mut(instance_id)?;
+    for server in &mut selection.servers {
+        server.connected = is_connected(&server.resolved_addr, connected);
+    }
+    Some(ServerSelectionStatusC {
+        enabled: true,
+        active_count: selection.active_count as u32,
+        servers: selection
+            .servers
+            .iter()
+            .map(|s| SelectedServerC {
+                url: redact::url(&s.url),
+                role: s.role,
+                latency_ms: s.latency_ms,
+                connected: s.connected,
+                reason: s.reason.clone(),
+            })
+            .collect(),
+    })
+}
+
+/// 实例关闭或重启时停止后台任务，返回原来的 active 数量，重启后用它重新选择。
+pub(crate) fn forget_instance(instance_id: &str) -> Option<u32> {
+    SELECTIONS
+        .lock()
+        .unwrap()
+        .remove(instance_id)
+        .map(|s| s.active_count as u32)
+}
+
+async fn supervise(instance_id: String, generation: u64) {
+    loop {
+        tokio::time::sleep(CHECK_INTERVAL).await;
+        let connected = connected_remote_addrs(&instance_id).await;
+
+        // 找出掉线超过宽限期的 active 服务器和候选的 standby。
+        let (dropped, standby, pinned) = {
+            let mut selections = SELECTIONS.lock().unwrap();
+            let Some(selection) = selections
+                .get_mut(&instance_id)
+                .filter(|s| s.generation == generation)
+            else {
+                return;
+            };
+            let mut dropped = None;
+            for server in &mut selection.servers {
+                server.connected = is_connected(&server.resolved_addr, &connected);
+                if server.connected {
+                    server.last_ok = Instant::now();
+                } else if server.role == ServerRoleC::Active
+                    && dropped.is_none()
+                    && server.last_ok.elapsed() >= FAILOVER_GRACE
+                {
+                    dropped = Some(server.url.clone());
+                }
+            }
+            let standby: Vec<String> = selection
+                .servers
+                .iter()
+                .filter(|s| s.role == ServerRoleC::Standby)
+                .map(|s| s.url.clone())
+                .collect();
+            let pinned: Vec<String> = selection
+                .servers
+                .iter()
+                .filter(|s| s.pinned)
+                .map(|s| s.url.clone())
+                .collect();
+            (dropped, standby, pinned)
+        };
+        let Some(dropped) = dropped else {
+            continue;
+        };
+        if standby.is_empty() {
+            continue;
+        }
+
+        let Some((replacement, result)) = probe_all(standby)
+            .await
+            .into_iter()
+            .filter(|(_, r)| r.reachable)
+            .min_by_key(|(_, r)| r.latency_ms)
+        else {
+            continue;
+        };
+
+        if pinned.contains(&replacement) {
+            // 重启后按原来的数量重新测速选择，掉线的服务器测不通自然会被换掉；
+            // 这一代的选择随重启结束，本任务也到此为止。
+            if let Err(e) = restart_with_pinned_peers(&instance_id).await {
+                eprintln!(
+                    "[astral_rust_core] WARN: failing over to pinned server {} failed for instance {}: {}",
+                    redact::url(&replacement),
+                    instance_id,
+                    e,
+                );
+            }
+            return;
+        }
+
+        let patched = patch_instance_config(
+            &instance_id,
+            InstanceConfigPatch {
+                connectors: vec![
+                    connector_patch(&replacement, ConfigPatchAction::Add),
+                    connector_patch(&dropped, ConfigPatchAction::Remove),
+                ],
+                ..Default::default()
+            },
+        )
+        .await;
+        if patched.is_err() {
+            // 实例已经关闭。
+            return;
+        }
+
+        let mut selections = SELECTIONS.lock().unwrap();
+        let Some(selection) = selections
+            .get_mut(&instance_id)
+            .filter(|s| s.generation == generation)
+        else {
+            return;
+        };
+        for server in &mut selection.servers {
+            if server.url == replacement {
+                server.role = ServerRoleC::Active;
+                server.latency_ms = result.latency_ms;
+                server.resolved_addr = result.resolved_addr.clone();
+                server.last_ok = Instant::now();
+                server.reason = format!(
+                    "failed over from {} (fastest standby, {} ms)",
+                    redact::url(&dropped),
+                    result.latency_ms
+                );
+            } else if server.url == dropped {
+                server.role = ServerRoleC::Standby;
+                server.reason = format!(
+                    "standby: disconnected for more than {} s",
+                    FAILOVER_GRACE.as_secs()
+                );
+            }
+        }
+    }
+}
+
+/// 并发测速，结果顺序不定。
+async fn probe_all(urls: Vec<String>) -> Vec<(String, ServerProbeResultC)> {
+    stream::iter(urls)
+        .map(|url| async move {
+            let result = probe_server(&url, PROBE_TIMEOUT).await;
+            (url, result)
+        })
+        .buffer_unordered(MAX_CONCURRENCY)
+        .collect()
+        .await
+}
+
+/// 测速时解析到的地址是否在已建立的连接里。
+fn is_connected(resolved_addr: &str, connected: &[SocketAddr]) -> bool {
+    resolved_addr
+        .parse::<SocketAddr>()
+        .is_ok_and(|addr| connected.contains(&addr))
+}
+
+/// 未启用选择时没有测速结果，现解析服务器地址再比对。
+async fn resolves_to_connected(uri: &url::Url, connected: &[SocketAddr]) -> bool {
+    let Some(host) = uri.host_str() else {
+        return false;
+    };
+    let port = uri.port_or_known_default().unwrap_or(DEFAULT_PORT);
+    match timeout(PROBE_TIMEOUT, lookup_host((host, port))).await {
+        Ok(Ok(mut addrs)) => addrs.any(|addr| connected.contains(&addr)),
+        _ => false,
+    }
+}
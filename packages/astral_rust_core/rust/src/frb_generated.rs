@@ -39,7 +39,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.12.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -2008553100;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1022946600;
 
 // Section: executor
 
@@ -47,7 +47,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__p2p__app_call_impl(
+fn wire__crate__api__file_transfer__accept_file_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -55,7 +55,7 @@ fn wire__crate__api__p2p__app_call_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "app_call",
+            debug_name: "accept_file",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -70,24 +70,16 @@ fn wire__crate__api__p2p__app_call_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_instance_id = <String>::sse_decode(&mut deserializer);
-            let api_dst_peer_id = <u32>::sse_decode(&mut deserializer);
-            let api_channel = <String>::sse_decode(&mut deserializer);
-            let api_request_id = <u64>::sse_decode(&mut deserializer);
-            let api_payload = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_flags = <u32>::sse_decode(&mut deserializer);
-            let api_timeout_ms = <i32>::sse_decode(&mut deserializer);
+            let api_transfer_id = <String>::sse_decode(&mut deserializer);
+            let api_dir = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::p2p::app_call(
+                        let output_ok = crate::api::file_transfer::accept_file(
                             api_instance_id,
-                            api_dst_peer_id,
-                            api_channel,
-                            api_request_id,
-                            api_payload,
-                            api_flags,
-                            api_timeout_ms,
+                            api_transfer_id,
+                            api_dir,
                         )
                         .await?;
                         Ok(output_ok)
@@ -98,7 +90,7 @@ fn wire__crate__api__p2p__app_call_impl(
         },
     )
 }
-fn wire__crate__api__p2p__app_call_reply_impl(
+fn wire__crate__api__endpoints__add_listener_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -106,7 +98,7 @@ fn wire__crate__api__p2p__app_call_reply_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "app_call_reply",
+            debug_name: "add_listener",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -121,22 +113,13 @@ fn wire__crate__api__p2p__app_call_reply_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_instance_id = <String>::sse_decode(&mut deserializer);
-            let api_token = <u64>::sse_decode(&mut deserializer);
-            let api_status = <i32>::sse_decode(&mut deserializer);
-            let api_error_msg = <String>::sse_decode(&mut deserializer);
-            let api_payload = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::p2p::app_call_reply(
-                            api_instance_id,
-                            api_token,
-                            api_status,
-                            api_error_msg,
-                            api_payload,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::endpoints::add_listener(api_instance_id, api_url).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -145,7 +128,7 @@ fn wire__crate__api__p2p__app_call_reply_impl(
         },
     )
 }
-fn wire__crate__api__p2p__app_notify_impl(
+fn wire__crate__api__endpoints__add_peer_uri_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -153,7 +136,7 @@ fn wire__crate__api__p2p__app_notify_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "app_notify",
+            debug_name: "add_peer_uri",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -168,22 +151,13 @@ fn wire__crate__api__p2p__app_notify_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_instance_id = <String>::sse_decode(&mut deserializer);
-            let api_dst_peer_id = <u32>::sse_decode(&mut deserializer);
-            let api_channel = <String>::sse_decode(&mut deserializer);
-            let api_payload = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_timeout_ms = <i32>::sse_decode(&mut deserializer);
+            let api_uri = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::p2p::app_notify(
-                            api_instance_id,
-                            api_dst_peer_id,
-                            api_channel,
-                            api_payload,
-                            api_timeout_ms,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::endpoints::add_peer_uri(api_instance_id, api_uri).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -192,15 +166,15 @@ fn wire__crate__api__p2p__app_notify_impl(
         },
     )
 }
-fn wire__crate__api__p2p__close_server_impl(
+fn wire__crate__api__port_forward__add_port_forward_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "close_server",
+            debug_name: "add_port_forward",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -215,25 +189,33 @@ fn wire__crate__api__p2p__close_server_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_forward = <crate::api::p2p::Forward>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::p2p::close_server(api_instance_id)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::port_forward::add_port_forward(
+                            api_instance_id,
+                            api_forward,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__p2p__create_server_impl(
+fn wire__crate__api__proxy_cidr__add_proxy_cidr_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_server",
+            debug_name: "add_proxy_cidr",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -247,30 +229,36 @@ fn wire__crate__api__p2p__create_server_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_config_toml = <String>::sse_decode(&mut deserializer);
-            let api_watch_event = <bool>::sse_decode(&mut deserializer);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_cidr = <String>::sse_decode(&mut deserializer);
+            let api_mapped_cidr = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::p2p::create_server(
-                        api_config_toml,
-                        api_watch_event,
-                    ))?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::proxy_cidr::add_proxy_cidr(
+                            api_instance_id,
+                            api_cidr,
+                            api_mapped_cidr,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__p2p__create_server_with_flags_impl(
+fn wire__crate__api__firewall__allow_instance_through_firewall_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_server_with_flags",
+            debug_name: "allow_instance_through_firewall",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -284,39 +272,23 @@ fn wire__crate__api__p2p__create_server_with_flags_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_username = <String>::sse_decode(&mut deserializer);
-            let api_enable_dhcp = <bool>::sse_decode(&mut deserializer);
-            let api_specified_ip = <String>::sse_decode(&mut deserializer);
-            let api_room_name = <String>::sse_decode(&mut deserializer);
-            let api_room_password = <String>::sse_decode(&mut deserializer);
-            let api_severurl = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_onurl = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_cidrs = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_forwards = <Vec<crate::api::p2p::Forward>>::sse_decode(&mut deserializer);
-            let api_flag = <crate::api::p2p::FlagsC>::sse_decode(&mut deserializer);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::p2p::create_server_with_flags(
-                            api_username,
-                            api_enable_dhcp,
-                            api_specified_ip,
-                            api_room_name,
-                            api_room_password,
-                            api_severurl,
-                            api_onurl,
-                            api_cidrs,
-                            api_forwards,
-                            api_flag,
-                        ))?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::firewall::FirewallError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::firewall::allow_instance_through_firewall(api_instance_id)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__p2p__easytier_version_impl(
+fn wire__crate__api__firewall__allow_tun_through_firewall_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -324,7 +296,7 @@ fn wire__crate__api__p2p__easytier_version_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "easytier_version",
+            debug_name: "allow_tun_through_firewall",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -338,25 +310,27 @@ fn wire__crate__api__p2p__easytier_version_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api__dev_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::p2p::easytier_version()?;
+                transform_result_sse::<_, crate::api::firewall::FirewallError>((move || {
+                    let output_ok =
+                        crate::api::firewall::allow_tun_through_firewall(api__dev_name)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__firewall__get_firewall_status_impl(
+fn wire__crate__api__app_broadcast__app_broadcast_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_firewall_status",
+            debug_name: "app_broadcast",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -370,21 +344,33 @@ fn wire__crate__api__firewall__get_firewall_status_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_profile_index = <u32>::sse_decode(&mut deserializer);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_channel = <String>::sse_decode(&mut deserializer);
+            let api_payload = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_scope =
+                <crate::api::app_broadcast::BroadcastScopeC>::sse_decode(&mut deserializer);
+            let api_timeout_ms = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::firewall::get_firewall_status(api_profile_index)?;
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::app_broadcast::app_broadcast(
+                            api_instance_id,
+                            api_channel,
+                            api_payload,
+                            api_scope,
+                            api_timeout_ms,
+                        )
+                        .await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__p2p__get_ips_impl(
+fn wire__crate__api__p2p__app_call_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -392,7 +378,7 @@ fn wire__crate__api__p2p__get_ips_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_ips",
+            debug_name: "app_call",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -407,12 +393,26 @@ fn wire__crate__api__p2p__get_ips_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_dst_peer_id = <u32>::sse_decode(&mut deserializer);
+            let api_channel = <String>::sse_decode(&mut deserializer);
+            let api_request_id = <u64>::sse_decode(&mut deserializer);
+            let api_payload = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_flags = <u32>::sse_decode(&mut deserializer);
+            let api_timeout_ms = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::p2p::get_ips(api_instance_id).await)?;
+                        let output_ok = crate::api::p2p::app_call(
+                            api_instance_id,
+                            api_dst_peer_id,
+                            api_channel,
+                            api_request_id,
+                            api_payload,
+                            api_flags,
+                            api_timeout_ms,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -421,17 +421,16 @@ fn wire__crate__api__p2p__get_ips_impl(
         },
     )
 }
-fn wire__crate__api__p2p__get_network_status_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__app_call_control__app_call_deadline_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_network_status",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "app_call_deadline",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -444,22 +443,18 @@ fn wire__crate__api__p2p__get_network_status_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_token = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::p2p::get_network_status(api_instance_id).await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::app_call_control::app_call_deadline(api_instance_id, api_token),
+                )?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__p2p__get_peer_route_pairs_impl(
+fn wire__crate__api__app_codec__app_call_encoded_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -467,7 +462,7 @@ fn wire__crate__api__p2p__get_peer_route_pairs_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_peer_route_pairs",
+            debug_name: "app_call_encoded",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -482,12 +477,31 @@ fn wire__crate__api__p2p__get_peer_route_pairs_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_dst_peer_id = <u32>::sse_decode(&mut deserializer);
+            let api_channel = <String>::sse_decode(&mut deserializer);
+            let api_request_id = <u64>::sse_decode(&mut deserializer);
+            let api_payload = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_codec = <crate::api::app_codec::PayloadCodecC>::sse_decode(&mut deserializer);
+            let api_reply_codec =
+                <crate::api::app_codec::PayloadCodecC>::sse_decode(&mut deserializer);
+            let api_flags = <u32>::sse_decode(&mut deserializer);
+            let api_timeout_ms = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::p2p::get_peer_route_pairs(api_instance_id).await?;
+                        let output_ok = crate::api::app_codec::app_call_encoded(
+                            api_instance_id,
+                            api_dst_peer_id,
+                            api_channel,
+                            api_request_id,
+                            api_payload,
+                            api_codec,
+                            api_reply_codec,
+                            api_flags,
+                            api_timeout_ms,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -496,7 +510,7 @@ fn wire__crate__api__p2p__get_peer_route_pairs_impl(
         },
     )
 }
-fn wire__crate__api__p2p__get_running_info_impl(
+fn wire__crate__api__p2p__app_call_reply_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -504,7 +518,7 @@ fn wire__crate__api__p2p__get_running_info_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_running_info",
+            debug_name: "app_call_reply",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -519,13 +533,22 @@ fn wire__crate__api__p2p__get_running_info_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_token = <u64>::sse_decode(&mut deserializer);
+            let api_status = <i32>::sse_decode(&mut deserializer);
+            let api_error_msg = <String>::sse_decode(&mut deserializer);
+            let api_payload = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::p2p::get_running_info(api_instance_id).await,
-                        )?;
+                        let output_ok = crate::api::p2p::app_call_reply(
+                            api_instance_id,
+                            api_token,
+                            api_status,
+                            api_error_msg,
+                            api_payload,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -534,17 +557,18 @@ fn wire__crate__api__p2p__get_running_info_impl(
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
+fn wire__crate__api__app_codec__app_call_reply_encoded_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
+            debug_name: "app_call_reply_encoded",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
         move || {
             let message = unsafe {
                 flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
@@ -555,24 +579,45 @@ fn wire__crate__api__simple__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_token = <u64>::sse_decode(&mut deserializer);
+            let api_status = <i32>::sse_decode(&mut deserializer);
+            let api_error_msg = <String>::sse_decode(&mut deserializer);
+            let api_payload = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_codec = <crate::api::app_codec::PayloadCodecC>::sse_decode(&mut deserializer);
+            let api_peer_accepts =
+                <Vec<crate::api::app_codec::PayloadCodecC>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::app_codec::app_call_reply_encoded(
+                            api_instance_id,
+                            api_token,
+                            api_status,
+                            api_error_msg,
+                            api_payload,
+                            api_codec,
+                            api_peer_accepts,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__p2p__handle_event_impl(
+fn wire__crate__api__app_call_control__app_call_with_deadline_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "handle_event",
+            debug_name: "app_call_with_deadline",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -586,26 +631,44 @@ fn wire__crate__api__p2p__handle_event_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_events = <EventBusSubscriber>::sse_decode(&mut deserializer);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_dst_peer_id = <u32>::sse_decode(&mut deserializer);
+            let api_channel = <String>::sse_decode(&mut deserializer);
+            let api_request_id = <u64>::sse_decode(&mut deserializer);
+            let api_payload = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_flags = <u32>::sse_decode(&mut deserializer);
+            let api_timeout_ms = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::p2p::handle_event(api_events))?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::app_call_control::app_call_with_deadline(
+                            api_instance_id,
+                            api_dst_peer_id,
+                            api_channel,
+                            api_request_id,
+                            api_payload,
+                            api_flags,
+                            api_timeout_ms,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__p2p__init_app_impl(
+fn wire__crate__api__p2p__app_notify_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "app_notify",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -619,27 +682,40 @@ fn wire__crate__api__p2p__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_dst_peer_id = <u32>::sse_decode(&mut deserializer);
+            let api_channel = <String>::sse_decode(&mut deserializer);
+            let api_payload = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_timeout_ms = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::p2p::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::p2p::app_notify(
+                            api_instance_id,
+                            api_dst_peer_id,
+                            api_channel,
+                            api_payload,
+                            api_timeout_ms,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__app_codec__app_notify_encoded_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "app_notify_encoded",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -653,29 +729,43 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_dst_peer_id = <u32>::sse_decode(&mut deserializer);
+            let api_channel = <String>::sse_decode(&mut deserializer);
+            let api_payload = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_codec = <crate::api::app_codec::PayloadCodecC>::sse_decode(&mut deserializer);
+            let api_timeout_ms = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::app_codec::app_notify_encoded(
+                            api_instance_id,
+                            api_dst_peer_id,
+                            api_channel,
+                            api_payload,
+                            api_codec,
+                            api_timeout_ms,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__p2p__is_easytier_running_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__app_metrics__app_rpc_metrics_openmetrics_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_easytier_running",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "app_rpc_metrics_openmetrics",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -689,21 +779,16 @@ fn wire__crate__api__p2p__is_easytier_running_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_instance_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::p2p::is_easytier_running(api_instance_id).await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::app_metrics::app_rpc_metrics_openmetrics(api_instance_id),
+                )?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__p2p__join_handle_result_impl(
+fn wire__crate__api__app_stream__app_stream_cancel_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -711,7 +796,7 @@ fn wire__crate__api__p2p__join_handle_result_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "join_handle_result",
+            debug_name: "app_stream_cancel",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -725,12 +810,19 @@ fn wire__crate__api__p2p__join_handle_result_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_handle = <JoinHandle<Result<String, String>>>::sse_decode(&mut deserializer);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_peer_id = <u32>::sse_decode(&mut deserializer);
+            let api_stream_id = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::p2p::join_handle_result(api_handle).await?;
+                        let output_ok = crate::api::app_stream::app_stream_cancel(
+                            api_instance_id,
+                            api_peer_id,
+                            api_stream_id,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -739,7 +831,7 @@ fn wire__crate__api__p2p__join_handle_result_impl(
         },
     )
 }
-fn wire__crate__api__p2p__my_peer_id_impl(
+fn wire__crate__api__app_stream__app_stream_finish_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -747,7 +839,7 @@ fn wire__crate__api__p2p__my_peer_id_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "my_peer_id",
+            debug_name: "app_stream_finish",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -762,11 +854,16 @@ fn wire__crate__api__p2p__my_peer_id_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_stream_id = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::p2p::my_peer_id(api_instance_id).await?;
+                        let output_ok = crate::api::app_stream::app_stream_finish(
+                            api_instance_id,
+                            api_stream_id,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -775,7 +872,7 @@ fn wire__crate__api__p2p__my_peer_id_impl(
         },
     )
 }
-fn wire__crate__api__p2p__peer_ping_impl(
+fn wire__crate__api__app_stream__app_stream_open_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -783,7 +880,7 @@ fn wire__crate__api__p2p__peer_ping_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "peer_ping",
+            debug_name: "app_stream_open",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -799,14 +896,20 @@ fn wire__crate__api__p2p__peer_ping_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_instance_id = <String>::sse_decode(&mut deserializer);
             let api_dst_peer_id = <u32>::sse_decode(&mut deserializer);
+            let api_channel = <String>::sse_decode(&mut deserializer);
+            let api_total_len = <u64>::sse_decode(&mut deserializer);
+            let api_metadata = <Vec<u8>>::sse_decode(&mut deserializer);
             let api_timeout_ms = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::p2p::peer_ping(
+                        let output_ok = crate::api::app_stream::app_stream_open(
                             api_instance_id,
                             api_dst_peer_id,
+                            api_channel,
+                            api_total_len,
+                            api_metadata,
                             api_timeout_ms,
                         )
                         .await?;
@@ -818,7 +921,7 @@ fn wire__crate__api__p2p__peer_ping_impl(
         },
     )
 }
-fn wire__crate__api__p2p__pending_app_call_count_impl(
+fn wire__crate__api__app_stream__app_stream_read_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -826,7 +929,7 @@ fn wire__crate__api__p2p__pending_app_call_count_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "pending_app_call_count",
+            debug_name: "app_stream_read",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -841,12 +944,23 @@ fn wire__crate__api__p2p__pending_app_call_count_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_from_peer_id = <u32>::sse_decode(&mut deserializer);
+            let api_stream_id = <u64>::sse_decode(&mut deserializer);
+            let api_sink =
+                <StreamSink<Vec<u8>, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+                    &mut deserializer,
+                );
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::p2p::pending_app_call_count(api_instance_id).await?;
+                        let output_ok = crate::api::app_stream::app_stream_read(
+                            api_instance_id,
+                            api_from_peer_id,
+                            api_stream_id,
+                            api_sink,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -855,15 +969,15 @@ fn wire__crate__api__p2p__pending_app_call_count_impl(
         },
     )
 }
-fn wire__crate__api__p2p__send_udp_to_localhost_impl(
+fn wire__crate__api__app_stream__app_stream_write_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_udp_to_localhost",
+            debug_name: "app_stream_write",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -877,26 +991,36 @@ fn wire__crate__api__p2p__send_udp_to_localhost_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_stream_id = <u64>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::p2p::send_udp_to_localhost(&api_message)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::app_stream::app_stream_write(
+                            api_instance_id,
+                            api_stream_id,
+                            api_data,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__firewall__set_firewall_status_impl(
+fn wire__crate__api__app_call_control__cancel_app_call_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_firewall_status",
+            debug_name: "cancel_app_call",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -910,24 +1034,28 @@ fn wire__crate__api__firewall__set_firewall_status_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_profile_index = <u32>::sse_decode(&mut deserializer);
-            let api_enable = <bool>::sse_decode(&mut deserializer);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_dst_peer_id = <u32>::sse_decode(&mut deserializer);
+            let api_request_id = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::firewall::set_firewall_status(
-                            api_profile_index,
-                            api_enable,
-                        )?;
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::app_call_control::cancel_app_call(
+                            api_instance_id,
+                            api_dst_peer_id,
+                            api_request_id,
+                        )
+                        .await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__p2p__set_tun_fd_impl(
+fn wire__crate__api__file_transfer__cancel_file_transfer_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -935,7 +1063,7 @@ fn wire__crate__api__p2p__set_tun_fd_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_tun_fd",
+            debug_name: "cancel_file_transfer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -950,13 +1078,16 @@ fn wire__crate__api__p2p__set_tun_fd_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_instance_id = <String>::sse_decode(&mut deserializer);
-            let api_fd = <i32>::sse_decode(&mut deserializer);
+            let api_transfer_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::p2p::set_tun_fd(api_instance_id, api_fd).await?;
+                        let output_ok = crate::api::file_transfer::cancel_file_transfer(
+                            api_instance_id,
+                            api_transfer_id,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -965,7 +1096,7 @@ fn wire__crate__api__p2p__set_tun_fd_impl(
         },
     )
 }
-fn wire__crate__api__p2p__subscribe_app_inbound_impl(
+fn wire__crate__api__proxy_cidr__check_proxy_cidr_conflicts_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -973,7 +1104,7 @@ fn wire__crate__api__p2p__subscribe_app_inbound_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "subscribe_app_inbound",
+            debug_name: "check_proxy_cidr_conflicts",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -988,16 +1119,12 @@ fn wire__crate__api__p2p__subscribe_app_inbound_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_instance_id = <String>::sse_decode(&mut deserializer);
-            let api_sink = <StreamSink<
-                crate::api::p2p::AppInboundEventC,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
                         let output_ok =
-                            crate::api::p2p::subscribe_app_inbound(api_instance_id, api_sink)
+                            crate::api::proxy_cidr::check_proxy_cidr_conflicts(api_instance_id)
                                 .await?;
                         Ok(output_ok)
                     })()
@@ -1007,982 +1134,10213 @@ fn wire__crate__api__p2p__subscribe_app_inbound_impl(
         },
     )
 }
-
-// Section: related_funcs
-
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<()>>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<String, String>>>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>
-);
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
+fn wire__crate__api__app_policy__clear_app_rpc_policy_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "clear_app_rpc_policy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::app_policy::clear_app_rpc_policy(api_instance_id);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__presence__clear_presence_profile_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "clear_presence_profile",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::presence::clear_presence_profile(api_instance_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__peer_control__close_peer_connection_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "close_peer_connection",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_peer_id = <u32>::sse_decode(&mut deserializer);
+            let api_conn_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::peer_control::close_peer_connection(
+                            api_instance_id,
+                            api_peer_id,
+                            api_conn_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__p2p__close_server_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "close_server",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::p2p::close_server(api_instance_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__p2p__create_server_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_server",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config_toml = <String>::sse_decode(&mut deserializer);
+            let api_watch_event = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::p2p::create_server(
+                        api_config_toml,
+                        api_watch_event,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__p2p__create_server_with_flags_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_server_with_flags",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_username = <String>::sse_decode(&mut deserializer);
+            let api_enable_dhcp = <bool>::sse_decode(&mut deserializer);
+            let api_specified_ip = <String>::sse_decode(&mut deserializer);
+            let api_room_name = <String>::sse_decode(&mut deserializer);
+            let api_room_password = <String>::sse_decode(&mut deserializer);
+            let api_severurl = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_onurl = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_cidrs = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_forwards = <Vec<crate::api::p2p::Forward>>::sse_decode(&mut deserializer);
+            let api_flag = <crate::api::p2p::FlagsC>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::p2p::create_server_with_flags(
+                            api_username,
+                            api_enable_dhcp,
+                            api_specified_ip,
+                            api_room_name,
+                            api_room_password,
+                            api_severurl,
+                            api_onurl,
+                            api_cidrs,
+                            api_forwards,
+                            api_flag,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__app_codec__decode_app_payload_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "decode_app_payload",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_payload = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_want = <crate::api::app_codec::PayloadCodecC>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::app_codec::decode_app_payload(api_payload, api_want)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__nat__detect_nat_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "detect_nat",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stun_servers = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::nat::detect_nat(api_stun_servers).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__firewall__diagnose_firewall_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "diagnose_firewall",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::firewall::FirewallError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::firewall::diagnose_firewall(api_instance_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__port_mapping__disable_port_mapping_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "disable_port_mapping",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::port_mapping::disable_port_mapping(api_instance_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__server_selection__disable_server_selection_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "disable_server_selection",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::server_selection::disable_server_selection(api_instance_id)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__p2p__easytier_version_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "easytier_version",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::p2p::easytier_version()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__port_mapping__enable_port_mapping_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "enable_port_mapping",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_requests =
+                <Vec<crate::api::port_mapping::PortMappingRequestC>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::port_mapping::enable_port_mapping(
+                            api_instance_id,
+                            api_requests,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__server_selection__enable_server_selection_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "enable_server_selection",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_active_count = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::server_selection::enable_server_selection(
+                            api_instance_id,
+                            api_active_count,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__app_metrics__get_app_rpc_metrics_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_app_rpc_metrics",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::app_metrics::get_app_rpc_metrics(
+                    api_instance_id,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__app_policy__get_app_rpc_policy_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_app_rpc_policy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::app_policy::get_app_rpc_policy(
+                    api_instance_id,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__app_policy__get_app_rpc_rejections_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_app_rpc_rejections",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::app_policy::get_app_rpc_rejections(api_instance_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__firewall__get_firewall_profile_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_firewall_profile_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_profile =
+                <crate::api::firewall::FirewallProfile>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::firewall::FirewallError>((move || {
+                    let output_ok = crate::api::firewall::get_firewall_profile_status(api_profile)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__firewall__get_firewall_snapshot_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_firewall_snapshot",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::firewall::FirewallError>((move || {
+                    let output_ok = crate::api::firewall::get_firewall_snapshot()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__firewall__get_firewall_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_firewall_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_profile_index = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::firewall::FirewallError>((move || {
+                    let output_ok = crate::api::firewall::get_firewall_status(api_profile_index)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__p2p__get_ips_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_ips",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::p2p::get_ips(api_instance_id).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__port_mapping__get_local_endpoints_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_local_endpoints",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::port_mapping::get_local_endpoints(api_instance_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__p2p__get_network_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_network_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::p2p::get_network_status(api_instance_id).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__p2p__get_peer_route_pairs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_peer_route_pairs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::p2p::get_peer_route_pairs(api_instance_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__presence__get_presence_bad_announces_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_presence_bad_announces",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::presence::get_presence_bad_announces(api_instance_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__presence__get_presence_profile_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_presence_profile",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::presence::get_presence_profile(
+                    api_instance_id,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__p2p__get_running_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_running_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::p2p::get_running_info(api_instance_id).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__server_selection__get_server_selection_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_server_selection_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::server_selection::get_server_selection_status(
+                            api_instance_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__firewall__get_tun_firewall_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_tun_firewall_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api__dev_name = <String>::sse_decode(&mut deserializer);
+            let api__ports =
+                <Vec<crate::api::firewall::FirewallPortC>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::firewall::FirewallError>((move || {
+                    let output_ok =
+                        crate::api::firewall::get_tun_firewall_status(api__dev_name, api__ports)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__greet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__p2p__handle_event_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "handle_event",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_events = <EventBusSubscriber>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::p2p::handle_event(api_events))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__p2p__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::p2p::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__app_call_control__is_app_call_cancelled_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_app_call_cancelled",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_token = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::app_call_control::is_app_call_cancelled(api_instance_id, api_token),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__p2p__is_easytier_running_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_easytier_running",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::p2p::is_easytier_running(api_instance_id).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__redact__is_unredacted_debug_logging_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_unredacted_debug_logging",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::redact::is_unredacted_debug_logging())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__p2p__join_handle_result_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "join_handle_result",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <JoinHandle<Result<String, String>>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::p2p::join_handle_result(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__app_router__list_app_channels_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_app_channels",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::app_router::list_app_channels(
+                    api_instance_id,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__file_transfer__list_file_offers_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_file_offers",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::file_transfer::list_file_offers(
+                    api_instance_id,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__messaging__list_messages_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_messages",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::messaging::list_messages(
+                    api_instance_id,
+                    api_limit,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__peer_control__list_peer_connections_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_peer_connections",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_peer_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::peer_control::list_peer_connections(
+                            api_instance_id,
+                            api_peer_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__presence__list_peer_presence_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_peer_presence",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::presence::list_peer_presence(api_instance_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__proxy_cidr__list_peer_proxy_cidrs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_peer_proxy_cidrs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::proxy_cidr::list_peer_proxy_cidrs(api_instance_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__credential__list_pinned_server_keys_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_pinned_server_keys",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::credential::list_pinned_server_keys())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__port_forward__list_port_forwards_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_port_forwards",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::port_forward::list_port_forwards(api_instance_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__port_mapping__list_port_mappings_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_port_mappings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::port_mapping::list_port_mappings(
+                    api_instance_id,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__proxy_cidr__list_proxy_cidrs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_proxy_cidrs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::proxy_cidr::list_proxy_cidrs(api_instance_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__credential__load_or_generate_node_keypair_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_or_generate_node_keypair",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_store_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::credential::load_or_generate_node_keypair(api_store_dir)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__local_channel__local_event_session_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "local_event_session",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::local_channel::local_event_session())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__credential__local_public_key_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "local_public_key",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::credential::local_public_key())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__messaging__mark_messages_read_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mark_messages_read",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_message_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::messaging::mark_messages_read(
+                            api_instance_id,
+                            api_message_ids,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__p2p__my_peer_id_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "my_peer_id",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::p2p::my_peer_id(api_instance_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__nat__nat_compatibility_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "nat_compatibility",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_local = <crate::api::nat::NatTypeC>::sse_decode(&mut deserializer);
+            let api_peer = <crate::api::nat::NatTypeC>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::nat::nat_compatibility(api_local, api_peer))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__file_transfer__offer_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "offer_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_dst_peer_id = <u32>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::file_transfer::offer_file(
+                            api_instance_id,
+                            api_dst_peer_id,
+                            api_path,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__p2p__peer_ping_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "peer_ping",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_dst_peer_id = <u32>::sse_decode(&mut deserializer);
+            let api_timeout_ms = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::p2p::peer_ping(
+                            api_instance_id,
+                            api_dst_peer_id,
+                            api_timeout_ms,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__p2p__pending_app_call_count_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pending_app_call_count",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::p2p::pending_app_call_count(api_instance_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__credential__pin_server_public_key_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pin_server_public_key",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_server_url = <String>::sse_decode(&mut deserializer);
+            let api_public_key = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::credential::pin_server_public_key(
+                        api_server_url,
+                        api_public_key,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__server_probe__probe_servers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "probe_servers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_urls = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_timeout_ms = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::server_probe::probe_servers(api_urls, api_timeout_ms).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__credential__public_key_fingerprint_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "public_key_fingerprint",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_public_key = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::credential::public_key_fingerprint(api_public_key)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__peer_control__reconnect_peer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reconnect_peer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_peer_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::peer_control::reconnect_peer(api_instance_id, api_peer_id)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__credential__regenerate_node_keypair_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "regenerate_node_keypair",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_store_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::credential::regenerate_node_keypair(api_store_dir)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__app_router__register_app_channels_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "register_app_channels",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_channels = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::app_router::register_app_channels(
+                            api_instance_id,
+                            api_channels,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__file_transfer__reject_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reject_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_transfer_id = <String>::sse_decode(&mut deserializer);
+            let api_reason = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::file_transfer::reject_file(
+                            api_instance_id,
+                            api_transfer_id,
+                            api_reason,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__firewall__remove_instance_firewall_rules_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_instance_firewall_rules",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::firewall::FirewallError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::firewall::remove_instance_firewall_rules(api_instance_id)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__endpoints__remove_listener_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_listener",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::endpoints::remove_listener(api_instance_id, api_url)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__endpoints__remove_peer_uri_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_peer_uri",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_uri = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::endpoints::remove_peer_uri(api_instance_id, api_uri)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__port_forward__remove_port_forward_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_port_forward",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_proto = <String>::sse_decode(&mut deserializer);
+            let api_bind_addr = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::port_forward::remove_port_forward(
+                            api_instance_id,
+                            api_proto,
+                            api_bind_addr,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__proxy_cidr__remove_proxy_cidr_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_proxy_cidr",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_cidr = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::proxy_cidr::remove_proxy_cidr(api_instance_id, api_cidr)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__firewall__remove_tun_firewall_rule_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_tun_firewall_rule",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api__dev_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::firewall::FirewallError>((move || {
+                    let output_ok = crate::api::firewall::remove_tun_firewall_rule(api__dev_name)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__app_metrics__reset_app_rpc_metrics_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_app_rpc_metrics",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::app_metrics::reset_app_rpc_metrics(api_instance_id);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__app_policy__reset_app_rpc_rejections_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_app_rpc_rejections",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::app_policy::reset_app_rpc_rejections(api_instance_id);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__diagnostics__run_diagnostics_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "run_diagnostics",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::diagnostics::run_diagnostics(api_instance_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__messaging__send_direct_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_direct_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_peer_id = <u32>::sse_decode(&mut deserializer);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::messaging::send_direct_message(
+                            api_instance_id,
+                            api_peer_id,
+                            api_text,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__messaging__send_room_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_room_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::messaging::send_room_message(api_instance_id, api_text)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__p2p__send_udp_to_localhost_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_udp_to_localhost",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::p2p::send_udp_to_localhost(&api_message)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__app_policy__set_app_rpc_policy_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_app_rpc_policy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_policy = <crate::api::app_policy::AppRpcPolicyC>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::app_policy::set_app_rpc_policy(api_instance_id, api_policy)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__firewall__set_firewall_profile_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_firewall_profile_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_profile =
+                <crate::api::firewall::FirewallProfile>::sse_decode(&mut deserializer);
+            let api_enable = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::firewall::FirewallError>((move || {
+                    let output_ok =
+                        crate::api::firewall::set_firewall_profile_status(api_profile, api_enable)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__firewall__set_firewall_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_firewall_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_profile_index = <u32>::sse_decode(&mut deserializer);
+            let api_enable = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::firewall::FirewallError>((move || {
+                    let output_ok =
+                        crate::api::firewall::set_firewall_status(api_profile_index, api_enable)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__local_channel__set_local_event_port_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_local_event_port",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_port = <u16>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::local_channel::set_local_event_port(api_port);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__presence__set_presence_profile_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_presence_profile",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_profile =
+                <crate::api::presence::PresenceProfileC>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::presence::set_presence_profile(
+                            api_instance_id,
+                            api_profile,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__p2p__set_tun_fd_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_tun_fd",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_fd = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::p2p::set_tun_fd(api_instance_id, api_fd).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__redact__set_unredacted_debug_logging_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_unredacted_debug_logging",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::redact::set_unredacted_debug_logging(api_enabled);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__messaging__start_messaging_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_messaging",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_history_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::messaging::start_messaging(
+                            api_instance_id,
+                            api_history_dir,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__app_call_control__subscribe_app_call_control_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_app_call_control",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::app_call_control::AppCallControlC,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::app_call_control::subscribe_app_call_control(
+                            api_instance_id,
+                            api_sink,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__app_router__subscribe_app_channels_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_app_channels",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_channels = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::p2p::AppInboundEventC,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::app_router::subscribe_app_channels(
+                            api_instance_id,
+                            api_channels,
+                            api_sink,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__p2p__subscribe_app_inbound_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_app_inbound",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::p2p::AppInboundEventC,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::p2p::subscribe_app_inbound(api_instance_id, api_sink)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__app_stream__subscribe_app_stream_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_app_stream_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_peer_id = <u32>::sse_decode(&mut deserializer);
+            let api_stream_id = <u64>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::app_stream::AppStreamProgressC,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::app_stream::subscribe_app_stream_progress(
+                            api_instance_id,
+                            api_peer_id,
+                            api_stream_id,
+                            api_sink,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__app_stream__subscribe_app_streams_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_app_streams",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_channel = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::app_stream::InboundAppStreamC,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::app_stream::subscribe_app_streams(
+                            api_instance_id,
+                            api_channel,
+                            api_sink,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__credential__subscribe_credential_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_credential_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::credential::CredentialEventC,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::credential::subscribe_credential_events(
+                            api_instance_id,
+                            api_sink,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__file_transfer__subscribe_file_offers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_file_offers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::file_transfer::FileOfferC,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::file_transfer::subscribe_file_offers(
+                            api_instance_id,
+                            api_sink,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__file_transfer__subscribe_file_transfer_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_file_transfer_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::file_transfer::FileTransferProgressC,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::file_transfer::subscribe_file_transfer_progress(
+                                api_instance_id,
+                                api_sink,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__messaging__subscribe_messages_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_messages",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::messaging::MessageEventC,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::messaging::subscribe_messages(api_instance_id, api_sink)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__port_mapping__subscribe_port_mapping_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_port_mapping_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::port_mapping::ListenerMappingC,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::port_mapping::subscribe_port_mapping_events(
+                            api_instance_id,
+                            api_sink,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__presence__subscribe_presence_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_presence",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::presence::PresenceEventC,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::presence::subscribe_presence(api_instance_id, api_sink)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__credential__unpin_server_public_key_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unpin_server_public_key",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_server_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::credential::unpin_server_public_key(api_server_url)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__app_router__unregister_app_channels_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unregister_app_channels",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_instance_id = <String>::sse_decode(&mut deserializer);
+            let api_channels = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::app_router::unregister_app_channels(
+                            api_instance_id,
+                            api_channels,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__local_channel__verify_local_event_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "verify_local_event",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_datagram = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::local_channel::verify_local_event(api_datagram),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+
+// Section: related_funcs
+
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<()>>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<String, String>>>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>
+);
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for EventBusSubscriber {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for JoinHandle<()> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<()>>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for JoinHandle<Result<String, String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+                JoinHandle<Result<String, String>>,
+            >,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for PeerRoutePair {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<()>>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<String, String>>>,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::app_call_control::AppCallControlC,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::p2p::AppInboundEventC, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::app_stream::AppStreamProgressC,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::credential::CredentialEventC,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::file_transfer::FileOfferC,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::file_transfer::FileTransferProgressC,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::app_stream::InboundAppStreamC,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for StreamSink<Vec<u8>, flutter_rust_bridge::for_generated::SseCodec> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::port_mapping::ListenerMappingC,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::messaging::MessageEventC,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::presence::PresenceEventC,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::app_call_control::AppCallControlC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind =
+            <crate::api::app_call_control::AppCallControlKindC>::sse_decode(deserializer);
+        let mut var_fromPeerId = <u32>::sse_decode(deserializer);
+        let mut var_requestId = <u64>::sse_decode(deserializer);
+        let mut var_token = <u64>::sse_decode(deserializer);
+        let mut var_deadlineMs = <u64>::sse_decode(deserializer);
+        return crate::api::app_call_control::AppCallControlC {
+            kind: var_kind,
+            from_peer_id: var_fromPeerId,
+            request_id: var_requestId,
+            token: var_token,
+            deadline_ms: var_deadlineMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_call_control::AppCallControlKindC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::app_call_control::AppCallControlKindC::Deadline,
+            1 => crate::api::app_call_control::AppCallControlKindC::Cancelled,
+            _ => unreachable!("Invalid variant for AppCallControlKindC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_codec::AppCallDecodedC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_status = <i32>::sse_decode(deserializer);
+        let mut var_errorMsg = <String>::sse_decode(deserializer);
+        let mut var_codec = <crate::api::app_codec::PayloadCodecC>::sse_decode(deserializer);
+        let mut var_payload = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::app_codec::AppCallDecodedC {
+            status: var_status,
+            error_msg: var_errorMsg,
+            codec: var_codec,
+            payload: var_payload,
+        };
+    }
+}
+
+impl SseDecode for crate::api::p2p::AppCallResultC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_status = <i32>::sse_decode(deserializer);
+        let mut var_errorMsg = <String>::sse_decode(deserializer);
+        let mut var_payload = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::p2p::AppCallResultC {
+            status: var_status,
+            error_msg: var_errorMsg,
+            payload: var_payload,
+        };
+    }
+}
+
+impl SseDecode for crate::api::p2p::AppInboundEventC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::p2p::AppInboundKindC>::sse_decode(deserializer);
+        let mut var_fromPeerId = <u32>::sse_decode(deserializer);
+        let mut var_channel = <String>::sse_decode(deserializer);
+        let mut var_requestId = <u64>::sse_decode(deserializer);
+        let mut var_token = <u64>::sse_decode(deserializer);
+        let mut var_payload = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::p2p::AppInboundEventC {
+            kind: var_kind,
+            from_peer_id: var_fromPeerId,
+            channel: var_channel,
+            request_id: var_requestId,
+            token: var_token,
+            payload: var_payload,
+        };
+    }
+}
+
+impl SseDecode for crate::api::p2p::AppInboundKindC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::p2p::AppInboundKindC::Call,
+            1 => crate::api::p2p::AppInboundKindC::Notify,
+            _ => unreachable!("Invalid variant for AppInboundKindC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_metrics::AppRpcCountersC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_callsSent = <u64>::sse_decode(deserializer);
+        let mut var_callsReceived = <u64>::sse_decode(deserializer);
+        let mut var_notifiesSent = <u64>::sse_decode(deserializer);
+        let mut var_notifiesReceived = <u64>::sse_decode(deserializer);
+        let mut var_repliesSent = <u64>::sse_decode(deserializer);
+        let mut var_repliesReceived = <u64>::sse_decode(deserializer);
+        let mut var_timeouts = <u64>::sse_decode(deserializer);
+        let mut var_noSubscriber = <u64>::sse_decode(deserializer);
+        let mut var_serviceDropped = <u64>::sse_decode(deserializer);
+        let mut var_cancelled = <u64>::sse_decode(deserializer);
+        let mut var_sendErrors = <u64>::sse_decode(deserializer);
+        return crate::api::app_metrics::AppRpcCountersC {
+            calls_sent: var_callsSent,
+            calls_received: var_callsReceived,
+            notifies_sent: var_notifiesSent,
+            notifies_received: var_notifiesReceived,
+            replies_sent: var_repliesSent,
+            replies_received: var_repliesReceived,
+            timeouts: var_timeouts,
+            no_subscriber: var_noSubscriber,
+            service_dropped: var_serviceDropped,
+            cancelled: var_cancelled,
+            send_errors: var_sendErrors,
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_metrics::AppRpcMetricsC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_laggedEvents = <u64>::sse_decode(deserializer);
+        let mut var_channels =
+            <Vec<crate::api::app_metrics::ChannelMetricsC>>::sse_decode(deserializer);
+        let mut var_peers = <Vec<crate::api::app_metrics::PeerMetricsC>>::sse_decode(deserializer);
+        return crate::api::app_metrics::AppRpcMetricsC {
+            lagged_events: var_laggedEvents,
+            channels: var_channels,
+            peers: var_peers,
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_policy::AppRpcPolicyC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxPayloadBytes = <u32>::sse_decode(deserializer);
+        let mut var_peerRate = <crate::api::app_policy::RateLimitC>::sse_decode(deserializer);
+        let mut var_channelRate = <crate::api::app_policy::RateLimitC>::sse_decode(deserializer);
+        let mut var_channelRules =
+            <Vec<crate::api::app_policy::PeerChannelRuleC>>::sse_decode(deserializer);
+        return crate::api::app_policy::AppRpcPolicyC {
+            max_payload_bytes: var_maxPayloadBytes,
+            peer_rate: var_peerRate,
+            channel_rate: var_channelRate,
+            channel_rules: var_channelRules,
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_policy::AppRpcRejectionsC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_total = <u64>::sse_decode(deserializer);
+        let mut var_byReason =
+            <Vec<crate::api::app_policy::ReasonRejectionsC>>::sse_decode(deserializer);
+        let mut var_byPeer =
+            <Vec<crate::api::app_policy::PeerRejectionsC>>::sse_decode(deserializer);
+        let mut var_byChannel =
+            <Vec<crate::api::app_policy::ChannelRejectionsC>>::sse_decode(deserializer);
+        return crate::api::app_policy::AppRpcRejectionsC {
+            total: var_total,
+            by_reason: var_byReason,
+            by_peer: var_byPeer,
+            by_channel: var_byChannel,
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_stream::AppStreamProgressC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_state = <crate::api::app_stream::AppStreamStateC>::sse_decode(deserializer);
+        let mut var_bytes = <u64>::sse_decode(deserializer);
+        let mut var_totalLen = <u64>::sse_decode(deserializer);
+        let mut var_error = <String>::sse_decode(deserializer);
+        return crate::api::app_stream::AppStreamProgressC {
+            state: var_state,
+            bytes: var_bytes,
+            total_len: var_totalLen,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_stream::AppStreamStateC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::app_stream::AppStreamStateC::Open,
+            1 => crate::api::app_stream::AppStreamStateC::Finished,
+            2 => crate::api::app_stream::AppStreamStateC::Cancelled,
+            3 => crate::api::app_stream::AppStreamStateC::Failed,
+            _ => unreachable!("Invalid variant for AppStreamStateC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::app_broadcast::BroadcastDeliveryC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_peerId = <u32>::sse_decode(deserializer);
+        let mut var_delivered = <bool>::sse_decode(deserializer);
+        let mut var_error = <String>::sse_decode(deserializer);
+        return crate::api::app_broadcast::BroadcastDeliveryC {
+            peer_id: var_peerId,
+            delivered: var_delivered,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_broadcast::BroadcastScopeC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind =
+            <crate::api::app_broadcast::BroadcastScopeKindC>::sse_decode(deserializer);
+        let mut var_peerIds = <Vec<u32>>::sse_decode(deserializer);
+        return crate::api::app_broadcast::BroadcastScopeC {
+            kind: var_kind,
+            peer_ids: var_peerIds,
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_broadcast::BroadcastScopeKindC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::app_broadcast::BroadcastScopeKindC::All,
+            1 => crate::api::app_broadcast::BroadcastScopeKindC::DirectOnly,
+            2 => crate::api::app_broadcast::BroadcastScopeKindC::Peers,
+            _ => unreachable!("Invalid variant for BroadcastScopeKindC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_metrics::ChannelMetricsC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_channel = <String>::sse_decode(deserializer);
+        let mut var_counters = <crate::api::app_metrics::AppRpcCountersC>::sse_decode(deserializer);
+        let mut var_callLatency =
+            <crate::api::app_metrics::LatencyHistogramC>::sse_decode(deserializer);
+        let mut var_handlerLatency =
+            <crate::api::app_metrics::LatencyHistogramC>::sse_decode(deserializer);
+        return crate::api::app_metrics::ChannelMetricsC {
+            channel: var_channel,
+            counters: var_counters,
+            call_latency: var_callLatency,
+            handler_latency: var_handlerLatency,
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_policy::ChannelRejectionsC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_channel = <String>::sse_decode(deserializer);
+        let mut var_count = <u64>::sse_decode(deserializer);
+        return crate::api::app_policy::ChannelRejectionsC {
+            channel: var_channel,
+            count: var_count,
+        };
+    }
+}
+
+impl SseDecode for crate::api::messaging::ChatMessageC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_kind = <crate::api::messaging::ChatMessageKindC>::sse_decode(deserializer);
+        let mut var_fromPeerId = <u32>::sse_decode(deserializer);
+        let mut var_toPeerId = <u32>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_sentAtMs = <u64>::sse_decode(deserializer);
+        let mut var_outgoing = <bool>::sse_decode(deserializer);
+        let mut var_deliveredTo = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_readBy = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_queuedFor = <Vec<u32>>::sse_decode(deserializer);
+        return crate::api::messaging::ChatMessageC {
+            id: var_id,
+            kind: var_kind,
+            from_peer_id: var_fromPeerId,
+            to_peer_id: var_toPeerId,
+            text: var_text,
+            sent_at_ms: var_sentAtMs,
+            outgoing: var_outgoing,
+            delivered_to: var_deliveredTo,
+            read_by: var_readBy,
+            queued_for: var_queuedFor,
+        };
+    }
+}
+
+impl SseDecode for crate::api::messaging::ChatMessageKindC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::messaging::ChatMessageKindC::Direct,
+            1 => crate::api::messaging::ChatMessageKindC::Room,
+            _ => unreachable!("Invalid variant for ChatMessageKindC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::diagnostics::ConnectErrorC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_dst = <String>::sse_decode(deserializer);
+        let mut var_ipVersion = <String>::sse_decode(deserializer);
+        let mut var_error = <String>::sse_decode(deserializer);
+        let mut var_timestampMs = <u64>::sse_decode(deserializer);
+        return crate::api::diagnostics::ConnectErrorC {
+            dst: var_dst,
+            ip_version: var_ipVersion,
+            error: var_error,
+            timestamp_ms: var_timestampMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::credential::CredentialEventC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::credential::CredentialEventKindC>::sse_decode(deserializer);
+        let mut var_serverUrl = <String>::sse_decode(deserializer);
+        let mut var_publicKey = <String>::sse_decode(deserializer);
+        let mut var_fingerprint = <String>::sse_decode(deserializer);
+        return crate::api::credential::CredentialEventC {
+            kind: var_kind,
+            server_url: var_serverUrl,
+            public_key: var_publicKey,
+            fingerprint: var_fingerprint,
+        };
+    }
+}
+
+impl SseDecode for crate::api::credential::CredentialEventKindC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::credential::CredentialEventKindC::LocalKeyChanged,
+            1 => crate::api::credential::CredentialEventKindC::PinnedKeyChanged,
+            2 => crate::api::credential::CredentialEventKindC::InstanceCredentialChanged,
+            _ => unreachable!("Invalid variant for CredentialEventKindC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_codec::DecodedPayloadC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_codec = <crate::api::app_codec::PayloadCodecC>::sse_decode(deserializer);
+        let mut var_payload = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_tagged = <bool>::sse_decode(deserializer);
+        let mut var_peerAccepts =
+            <Vec<crate::api::app_codec::PayloadCodecC>>::sse_decode(deserializer);
+        return crate::api::app_codec::DecodedPayloadC {
+            codec: var_codec,
+            payload: var_payload,
+            tagged: var_tagged,
+            peer_accepts: var_peerAccepts,
+        };
+    }
+}
+
+impl SseDecode for crate::api::diagnostics::DiagnosticCheckC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind =
+            <crate::api::diagnostics::DiagnosticCheckKindC>::sse_decode(deserializer);
+        let mut var_status = <crate::api::diagnostics::DiagnosticStatusC>::sse_decode(deserializer);
+        let mut var_target = <String>::sse_decode(deserializer);
+        let mut var_detail = <String>::sse_decode(deserializer);
+        let mut var_suggestion = <String>::sse_decode(deserializer);
+        return crate::api::diagnostics::DiagnosticCheckC {
+            kind: var_kind,
+            status: var_status,
+            target: var_target,
+            detail: var_detail,
+            suggestion: var_suggestion,
+        };
+    }
+}
+
+impl SseDecode for crate::api::diagnostics::DiagnosticCheckKindC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::diagnostics::DiagnosticCheckKindC::ServerResolve,
+            1 => crate::api::diagnostics::DiagnosticCheckKindC::ServerReachability,
+            2 => crate::api::diagnostics::DiagnosticCheckKindC::Listener,
+            3 => crate::api::diagnostics::DiagnosticCheckKindC::TunDevice,
+            4 => crate::api::diagnostics::DiagnosticCheckKindC::NatType,
+            5 => crate::api::diagnostics::DiagnosticCheckKindC::HolePunching,
+            6 => crate::api::diagnostics::DiagnosticCheckKindC::Firewall,
+            7 => crate::api::diagnostics::DiagnosticCheckKindC::Mtu,
+            8 => crate::api::diagnostics::DiagnosticCheckKindC::ConnectErrors,
+            _ => unreachable!("Invalid variant for DiagnosticCheckKindC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::diagnostics::DiagnosticReportC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_overall =
+            <crate::api::diagnostics::DiagnosticStatusC>::sse_decode(deserializer);
+        let mut var_checks =
+            <Vec<crate::api::diagnostics::DiagnosticCheckC>>::sse_decode(deserializer);
+        let mut var_recentConnectErrors =
+            <Vec<crate::api::diagnostics::ConnectErrorC>>::sse_decode(deserializer);
+        return crate::api::diagnostics::DiagnosticReportC {
+            overall: var_overall,
+            checks: var_checks,
+            recent_connect_errors: var_recentConnectErrors,
+        };
+    }
+}
+
+impl SseDecode for crate::api::diagnostics::DiagnosticStatusC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::diagnostics::DiagnosticStatusC::Pass,
+            1 => crate::api::diagnostics::DiagnosticStatusC::Warn,
+            2 => crate::api::diagnostics::DiagnosticStatusC::Fail,
+            _ => unreachable!("Invalid variant for DiagnosticStatusC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::endpoints::EndpointOutcomeC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::endpoints::EndpointOutcomeC::Added,
+            1 => crate::api::endpoints::EndpointOutcomeC::AlreadyPresent,
+            2 => crate::api::endpoints::EndpointOutcomeC::Removed,
+            3 => crate::api::endpoints::EndpointOutcomeC::NotFound,
+            4 => crate::api::endpoints::EndpointOutcomeC::Failed,
+            _ => unreachable!("Invalid variant for EndpointOutcomeC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::endpoints::EndpointResultC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_outcome = <crate::api::endpoints::EndpointOutcomeC>::sse_decode(deserializer);
+        let mut var_error = <String>::sse_decode(deserializer);
+        return crate::api::endpoints::EndpointResultC {
+            url: var_url,
+            outcome: var_outcome,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::file_transfer::FileOfferC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fromPeerId = <u32>::sse_decode(deserializer);
+        let mut var_transferId = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        let mut var_sha256 = <String>::sse_decode(deserializer);
+        return crate::api::file_transfer::FileOfferC {
+            from_peer_id: var_fromPeerId,
+            transfer_id: var_transferId,
+            name: var_name,
+            size: var_size,
+            sha256: var_sha256,
+        };
+    }
+}
+
+impl SseDecode for crate::api::file_transfer::FileTransferDirectionC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::file_transfer::FileTransferDirectionC::Sending,
+            1 => crate::api::file_transfer::FileTransferDirectionC::Receiving,
+            _ => unreachable!("Invalid variant for FileTransferDirectionC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::file_transfer::FileTransferProgressC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_transferId = <String>::sse_decode(deserializer);
+        let mut var_peerId = <u32>::sse_decode(deserializer);
+        let mut var_direction =
+            <crate::api::file_transfer::FileTransferDirectionC>::sse_decode(deserializer);
+        let mut var_state =
+            <crate::api::file_transfer::FileTransferStateC>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_bytes = <u64>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        let mut var_error = <String>::sse_decode(deserializer);
+        return crate::api::file_transfer::FileTransferProgressC {
+            transfer_id: var_transferId,
+            peer_id: var_peerId,
+            direction: var_direction,
+            state: var_state,
+            name: var_name,
+            bytes: var_bytes,
+            size: var_size,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::file_transfer::FileTransferStateC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::file_transfer::FileTransferStateC::Offered,
+            1 => crate::api::file_transfer::FileTransferStateC::Transferring,
+            2 => crate::api::file_transfer::FileTransferStateC::Completed,
+            3 => crate::api::file_transfer::FileTransferStateC::Rejected,
+            4 => crate::api::file_transfer::FileTransferStateC::Cancelled,
+            5 => crate::api::file_transfer::FileTransferStateC::Failed,
+            _ => unreachable!("Invalid variant for FileTransferStateC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::firewall::FirewallBackendC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::firewall::FirewallBackendC::None,
+            1 => crate::api::firewall::FirewallBackendC::Firewalld,
+            2 => crate::api::firewall::FirewallBackendC::Ufw,
+            3 => crate::api::firewall::FirewallBackendC::Nftables,
+            4 => crate::api::firewall::FirewallBackendC::Iptables,
+            5 => crate::api::firewall::FirewallBackendC::WindowsFirewall,
+            6 => crate::api::firewall::FirewallBackendC::Unknown,
+            _ => unreachable!("Invalid variant for FirewallBackendC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::firewall::FirewallDiagnosisC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_backend = <crate::api::firewall::FirewallBackendC>::sse_decode(deserializer);
+        let mut var_active = <bool>::sse_decode(deserializer);
+        let mut var_findings =
+            <Vec<crate::api::firewall::FirewallFindingC>>::sse_decode(deserializer);
+        return crate::api::firewall::FirewallDiagnosisC {
+            backend: var_backend,
+            active: var_active,
+            findings: var_findings,
+        };
+    }
+}
+
+impl SseDecode for crate::api::firewall::FirewallError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::firewall::FirewallErrorKind>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::firewall::FirewallError {
+            kind: var_kind,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::api::firewall::FirewallErrorKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::firewall::FirewallErrorKind::InvalidProfile,
+            1 => crate::api::firewall::FirewallErrorKind::Unsupported,
+            2 => crate::api::firewall::FirewallErrorKind::Instance,
+            3 => crate::api::firewall::FirewallErrorKind::Backend,
+            4 => crate::api::firewall::FirewallErrorKind::NeedsPrivileges,
+            _ => unreachable!("Invalid variant for FirewallErrorKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::firewall::FirewallFindingC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rule = <crate::api::firewall::FirewallRuleC>::sse_decode(deserializer);
+        let mut var_blocked = <bool>::sse_decode(deserializer);
+        let mut var_blockingRule = <String>::sse_decode(deserializer);
+        let mut var_suggestion = <String>::sse_decode(deserializer);
+        return crate::api::firewall::FirewallFindingC {
+            rule: var_rule,
+            blocked: var_blocked,
+            blocking_rule: var_blockingRule,
+            suggestion: var_suggestion,
+        };
+    }
+}
+
+impl SseDecode for crate::api::firewall::FirewallPortC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_proto = <String>::sse_decode(deserializer);
+        let mut var_port = <u16>::sse_decode(deserializer);
+        return crate::api::firewall::FirewallPortC {
+            proto: var_proto,
+            port: var_port,
+        };
+    }
+}
+
+impl SseDecode for crate::api::firewall::FirewallPortStatusC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_proto = <String>::sse_decode(deserializer);
+        let mut var_port = <u16>::sse_decode(deserializer);
+        let mut var_allowed = <bool>::sse_decode(deserializer);
+        return crate::api::firewall::FirewallPortStatusC {
+            proto: var_proto,
+            port: var_port,
+            allowed: var_allowed,
+        };
+    }
+}
+
+impl SseDecode for crate::api::firewall::FirewallProfile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::firewall::FirewallProfile::Domain,
+            1 => crate::api::firewall::FirewallProfile::Private,
+            2 => crate::api::firewall::FirewallProfile::Public,
+            _ => unreachable!("Invalid variant for FirewallProfile: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::firewall::FirewallProfileStatusC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_profile = <crate::api::firewall::FirewallProfile>::sse_decode(deserializer);
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_current = <bool>::sse_decode(deserializer);
+        return crate::api::firewall::FirewallProfileStatusC {
+            profile: var_profile,
+            enabled: var_enabled,
+            current: var_current,
+        };
+    }
+}
+
+impl SseDecode for crate::api::firewall::FirewallRuleC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::firewall::FirewallRuleKindC>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_proto = <String>::sse_decode(deserializer);
+        let mut var_port = <u16>::sse_decode(deserializer);
+        let mut var_detail = <String>::sse_decode(deserializer);
+        return crate::api::firewall::FirewallRuleC {
+            kind: var_kind,
+            name: var_name,
+            proto: var_proto,
+            port: var_port,
+            detail: var_detail,
+        };
+    }
+}
+
+impl SseDecode for crate::api::firewall::FirewallRuleKindC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::firewall::FirewallRuleKindC::Overlay,
+            1 => crate::api::firewall::FirewallRuleKindC::ListenerPort,
+            _ => unreachable!("Invalid variant for FirewallRuleKindC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::firewall::FirewallSnapshotC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_backend = <crate::api::firewall::FirewallBackendC>::sse_decode(deserializer);
+        let mut var_profiles =
+            <Vec<crate::api::firewall::FirewallProfileStatusC>>::sse_decode(deserializer);
+        let mut var_currentProfiles =
+            <Vec<crate::api::firewall::FirewallProfile>>::sse_decode(deserializer);
+        return crate::api::firewall::FirewallSnapshotC {
+            backend: var_backend,
+            profiles: var_profiles,
+            current_profiles: var_currentProfiles,
+        };
+    }
+}
+
+impl SseDecode for crate::api::p2p::FlagsC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_defaultProtocol = <String>::sse_decode(deserializer);
+        let mut var_devName = <String>::sse_decode(deserializer);
+        let mut var_enableEncryption = <bool>::sse_decode(deserializer);
+        let mut var_enableIpv6 = <bool>::sse_decode(deserializer);
+        let mut var_mtu = <u32>::sse_decode(deserializer);
+        let mut var_latencyFirst = <bool>::sse_decode(deserializer);
+        let mut var_enableExitNode = <bool>::sse_decode(deserializer);
+        let mut var_noTun = <bool>::sse_decode(deserializer);
+        let mut var_useSmoltcp = <bool>::sse_decode(deserializer);
+        let mut var_relayNetworkWhitelist = <String>::sse_decode(deserializer);
+        let mut var_disableP2P = <bool>::sse_decode(deserializer);
+        let mut var_relayAllPeerRpc = <bool>::sse_decode(deserializer);
+        let mut var_disableUdpHolePunching = <bool>::sse_decode(deserializer);
+        let mut var_disableTcpHolePunching = <bool>::sse_decode(deserializer);
+        let mut var_multiThread = <bool>::sse_decode(deserializer);
+        let mut var_dataCompressAlgo = <i32>::sse_decode(deserializer);
+        let mut var_bindDevice = <bool>::sse_decode(deserializer);
+        let mut var_enableKcpProxy = <bool>::sse_decode(deserializer);
+        let mut var_disableKcpInput = <bool>::sse_decode(deserializer);
+        let mut var_disableRelayKcp = <bool>::sse_decode(deserializer);
+        let mut var_proxyForwardBySystem = <bool>::sse_decode(deserializer);
+        let mut var_acceptDns = <bool>::sse_decode(deserializer);
+        let mut var_privateMode = <bool>::sse_decode(deserializer);
+        let mut var_enableQuicProxy = <bool>::sse_decode(deserializer);
+        let mut var_disableQuicInput = <bool>::sse_decode(deserializer);
+        let mut var_disableSymHolePunching = <bool>::sse_decode(deserializer);
+        let mut var_tcpWhitelist = <String>::sse_decode(deserializer);
+        let mut var_udpWhitelist = <String>::sse_decode(deserializer);
+        let mut var_secureMode = <bool>::sse_decode(deserializer);
+        return crate::api::p2p::FlagsC {
+            default_protocol: var_defaultProtocol,
+            dev_name: var_devName,
+            enable_encryption: var_enableEncryption,
+            enable_ipv6: var_enableIpv6,
+            mtu: var_mtu,
+            latency_first: var_latencyFirst,
+            enable_exit_node: var_enableExitNode,
+            no_tun: var_noTun,
+            use_smoltcp: var_useSmoltcp,
+            relay_network_whitelist: var_relayNetworkWhitelist,
+            disable_p2p: var_disableP2P,
+            relay_all_peer_rpc: var_relayAllPeerRpc,
+            disable_udp_hole_punching: var_disableUdpHolePunching,
+            disable_tcp_hole_punching: var_disableTcpHolePunching,
+            multi_thread: var_multiThread,
+            data_compress_algo: var_dataCompressAlgo,
+            bind_device: var_bindDevice,
+            enable_kcp_proxy: var_enableKcpProxy,
+            disable_kcp_input: var_disableKcpInput,
+            disable_relay_kcp: var_disableRelayKcp,
+            proxy_forward_by_system: var_proxyForwardBySystem,
+            accept_dns: var_acceptDns,
+            private_mode: var_privateMode,
+            enable_quic_proxy: var_enableQuicProxy,
+            disable_quic_input: var_disableQuicInput,
+            disable_sym_hole_punching: var_disableSymHolePunching,
+            tcp_whitelist: var_tcpWhitelist,
+            udp_whitelist: var_udpWhitelist,
+            secure_mode: var_secureMode,
+        };
+    }
+}
+
+impl SseDecode for crate::api::p2p::Forward {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_bindAddr = <String>::sse_decode(deserializer);
+        let mut var_dstAddr = <String>::sse_decode(deserializer);
+        let mut var_proto = <String>::sse_decode(deserializer);
+        return crate::api::p2p::Forward {
+            bind_addr: var_bindAddr,
+            dst_addr: var_dstAddr,
+            proto: var_proto,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::app_stream::InboundAppStreamC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fromPeerId = <u32>::sse_decode(deserializer);
+        let mut var_streamId = <u64>::sse_decode(deserializer);
+        let mut var_channel = <String>::sse_decode(deserializer);
+        let mut var_totalLen = <u64>::sse_decode(deserializer);
+        let mut var_metadata = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::app_stream::InboundAppStreamC {
+            from_peer_id: var_fromPeerId,
+            stream_id: var_streamId,
+            channel: var_channel,
+            total_len: var_totalLen,
+            metadata: var_metadata,
+        };
+    }
+}
+
+impl SseDecode for crate::api::p2p::KVNetworkStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_totalNodes = <usize>::sse_decode(deserializer);
+        let mut var_nodes = <Vec<crate::api::p2p::KVNodeInfo>>::sse_decode(deserializer);
+        return crate::api::p2p::KVNetworkStatus {
+            total_nodes: var_totalNodes,
+            nodes: var_nodes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::p2p::KVNodeConnectionStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_connType = <String>::sse_decode(deserializer);
+        let mut var_rxBytes = <u64>::sse_decode(deserializer);
+        let mut var_txBytes = <u64>::sse_decode(deserializer);
+        let mut var_rxPackets = <u64>::sse_decode(deserializer);
+        let mut var_txPackets = <u64>::sse_decode(deserializer);
+        return crate::api::p2p::KVNodeConnectionStats {
+            conn_type: var_connType,
+            rx_bytes: var_rxBytes,
+            tx_bytes: var_txBytes,
+            rx_packets: var_rxPackets,
+            tx_packets: var_txPackets,
+        };
+    }
+}
+
+impl SseDecode for crate::api::p2p::KVNodeInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_peerId = <u32>::sse_decode(deserializer);
+        let mut var_hostname = <String>::sse_decode(deserializer);
+        let mut var_ipv4 = <String>::sse_decode(deserializer);
+        let mut var_ipv6 = <String>::sse_decode(deserializer);
+        let mut var_latencyMs = <f64>::sse_decode(deserializer);
+        let mut var_nat = <String>::sse_decode(deserializer);
+        let mut var_hops = <Vec<crate::api::p2p::NodeHopStats>>::sse_decode(deserializer);
+        let mut var_lossRate = <f32>::sse_decode(deserializer);
+        let mut var_connections =
+            <Vec<crate::api::p2p::KVNodeConnectionStats>>::sse_decode(deserializer);
+        let mut var_tunnelProto = <String>::sse_decode(deserializer);
+        let mut var_connType = <String>::sse_decode(deserializer);
+        let mut var_rxBytes = <u64>::sse_decode(deserializer);
+        let mut var_txBytes = <u64>::sse_decode(deserializer);
+        let mut var_version = <String>::sse_decode(deserializer);
+        let mut var_cost = <i32>::sse_decode(deserializer);
+        return crate::api::p2p::KVNodeInfo {
+            peer_id: var_peerId,
+            hostname: var_hostname,
+            ipv4: var_ipv4,
+            ipv6: var_ipv6,
+            latency_ms: var_latencyMs,
+            nat: var_nat,
+            hops: var_hops,
+            loss_rate: var_lossRate,
+            connections: var_connections,
+            tunnel_proto: var_tunnelProto,
+            conn_type: var_connType,
+            rx_bytes: var_rxBytes,
+            tx_bytes: var_txBytes,
+            version: var_version,
+            cost: var_cost,
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_metrics::LatencyHistogramC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_boundsMs = <Vec<f64>>::sse_decode(deserializer);
+        let mut var_counts = <Vec<u64>>::sse_decode(deserializer);
+        let mut var_count = <u64>::sse_decode(deserializer);
+        let mut var_sumMs = <f64>::sse_decode(deserializer);
+        return crate::api::app_metrics::LatencyHistogramC {
+            bounds_ms: var_boundsMs,
+            counts: var_counts,
+            count: var_count,
+            sum_ms: var_sumMs,
+        };
+    }
+}
+
+impl SseDecode for Vec<PeerRoutePair> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<PeerRoutePair>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::app_broadcast::BroadcastDeliveryC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::app_broadcast::BroadcastDeliveryC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::app_metrics::ChannelMetricsC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::app_metrics::ChannelMetricsC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::app_policy::ChannelRejectionsC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::app_policy::ChannelRejectionsC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::messaging::ChatMessageC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::messaging::ChatMessageC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::diagnostics::ConnectErrorC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::diagnostics::ConnectErrorC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::diagnostics::DiagnosticCheckC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::diagnostics::DiagnosticCheckC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::file_transfer::FileOfferC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::file_transfer::FileOfferC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::firewall::FirewallFindingC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::firewall::FirewallFindingC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::firewall::FirewallPortC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::firewall::FirewallPortC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::firewall::FirewallPortStatusC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::firewall::FirewallPortStatusC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::firewall::FirewallProfile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::firewall::FirewallProfile>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::firewall::FirewallProfileStatusC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::firewall::FirewallProfileStatusC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::firewall::FirewallRuleC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::firewall::FirewallRuleC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::p2p::Forward> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::p2p::Forward>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::p2p::KVNodeConnectionStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::p2p::KVNodeConnectionStats>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::p2p::KVNodeInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::p2p::KVNodeInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::port_mapping::ListenerMappingC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::port_mapping::ListenerMappingC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::nat::NatCompatibilityC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::nat::NatCompatibilityC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::p2p::NodeHopStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::p2p::NodeHopStats>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::app_codec::PayloadCodecC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::app_codec::PayloadCodecC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::app_policy::PeerChannelRuleC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::app_policy::PeerChannelRuleC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::peer_control::PeerConnectionC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::peer_control::PeerConnectionC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::app_metrics::PeerMetricsC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::app_metrics::PeerMetricsC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::presence::PeerPresenceC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::presence::PeerPresenceC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::proxy_cidr::PeerProxyCidrsC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::proxy_cidr::PeerProxyCidrsC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::app_policy::PeerRejectionsC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::app_policy::PeerRejectionsC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::credential::PinnedServerKeyC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::credential::PinnedServerKeyC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::port_forward::PortForwardC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::port_forward::PortForwardC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::port_mapping::PortMappingRequestC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::port_mapping::PortMappingRequestC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<f64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<u32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<u64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::proxy_cidr::ProxyCidrC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::proxy_cidr::ProxyCidrC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::proxy_cidr::ProxyCidrConflictC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::proxy_cidr::ProxyCidrConflictC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::app_policy::ReasonRejectionsC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::app_policy::ReasonRejectionsC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::server_selection::SelectedServerC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::server_selection::SelectedServerC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::server_probe::ServerProbeResultC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::server_probe::ServerProbeResultC>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::port_mapping::ListenerMappingC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_localListener = <String>::sse_decode(deserializer);
+        let mut var_mappedListener = <String>::sse_decode(deserializer);
+        let mut var_backend = <String>::sse_decode(deserializer);
+        let mut var_state = <crate::api::port_mapping::PortMappingStateC>::sse_decode(deserializer);
+        let mut var_managedByEasytier = <bool>::sse_decode(deserializer);
+        let mut var_leaseRemainingSecs = <u32>::sse_decode(deserializer);
+        let mut var_error = <String>::sse_decode(deserializer);
+        return crate::api::port_mapping::ListenerMappingC {
+            local_listener: var_localListener,
+            mapped_listener: var_mappedListener,
+            backend: var_backend,
+            state: var_state,
+            managed_by_easytier: var_managedByEasytier,
+            lease_remaining_secs: var_leaseRemainingSecs,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::port_mapping::LocalEndpointsC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_listeners = <Vec<String>>::sse_decode(deserializer);
+        let mut var_mappedListeners = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::port_mapping::LocalEndpointsC {
+            listeners: var_listeners,
+            mapped_listeners: var_mappedListeners,
+        };
+    }
+}
+
+impl SseDecode for crate::api::local_channel::LocalEventC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_instanceId = <String>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_seq = <u64>::sse_decode(deserializer);
+        return crate::api::local_channel::LocalEventC {
+            instance_id: var_instanceId,
+            message: var_message,
+            seq: var_seq,
+        };
+    }
+}
+
+impl SseDecode for crate::api::messaging::MessageEventC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::messaging::MessageEventKindC>::sse_decode(deserializer);
+        let mut var_peerId = <u32>::sse_decode(deserializer);
+        let mut var_message = <crate::api::messaging::ChatMessageC>::sse_decode(deserializer);
+        let mut var_error = <String>::sse_decode(deserializer);
+        return crate::api::messaging::MessageEventC {
+            kind: var_kind,
+            peer_id: var_peerId,
+            message: var_message,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::messaging::MessageEventKindC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::messaging::MessageEventKindC::Received,
+            1 => crate::api::messaging::MessageEventKindC::Sent,
+            2 => crate::api::messaging::MessageEventKindC::Delivered,
+            3 => crate::api::messaging::MessageEventKindC::Read,
+            4 => crate::api::messaging::MessageEventKindC::Queued,
+            5 => crate::api::messaging::MessageEventKindC::HistoryWriteFailed,
+            _ => unreachable!("Invalid variant for MessageEventKindC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::nat::NatCompatibilityC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_peerNatType = <crate::api::nat::NatTypeC>::sse_decode(deserializer);
+        let mut var_likelihood = <crate::api::nat::P2pLikelihoodC>::sse_decode(deserializer);
+        let mut var_explanation = <String>::sse_decode(deserializer);
+        return crate::api::nat::NatCompatibilityC {
+            peer_nat_type: var_peerNatType,
+            likelihood: var_likelihood,
+            explanation: var_explanation,
+        };
+    }
+}
+
+impl SseDecode for crate::api::nat::NatDetectionC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_udpNatType = <crate::api::nat::NatTypeC>::sse_decode(deserializer);
+        let mut var_tcpNatType = <crate::api::nat::NatTypeC>::sse_decode(deserializer);
+        let mut var_udpPortMapping = <crate::api::nat::PortMappingC>::sse_decode(deserializer);
+        let mut var_publicIps = <Vec<String>>::sse_decode(deserializer);
+        let mut var_mappedUdpAddr = <String>::sse_decode(deserializer);
+        let mut var_minPort = <u32>::sse_decode(deserializer);
+        let mut var_maxPort = <u32>::sse_decode(deserializer);
+        let mut var_compatibility =
+            <Vec<crate::api::nat::NatCompatibilityC>>::sse_decode(deserializer);
+        return crate::api::nat::NatDetectionC {
+            udp_nat_type: var_udpNatType,
+            tcp_nat_type: var_tcpNatType,
+            udp_port_mapping: var_udpPortMapping,
+            public_ips: var_publicIps,
+            mapped_udp_addr: var_mappedUdpAddr,
+            min_port: var_minPort,
+            max_port: var_maxPort,
+            compatibility: var_compatibility,
+        };
+    }
+}
+
+impl SseDecode for crate::api::nat::NatTypeC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::nat::NatTypeC::Unknown,
+            1 => crate::api::nat::NatTypeC::OpenInternet,
+            2 => crate::api::nat::NatTypeC::NoPat,
+            3 => crate::api::nat::NatTypeC::FullCone,
+            4 => crate::api::nat::NatTypeC::Restricted,
+            5 => crate::api::nat::NatTypeC::PortRestricted,
+            6 => crate::api::nat::NatTypeC::Symmetric,
+            7 => crate::api::nat::NatTypeC::SymUdpFirewall,
+            8 => crate::api::nat::NatTypeC::SymmetricEasyInc,
+            9 => crate::api::nat::NatTypeC::SymmetricEasyDec,
+            _ => unreachable!("Invalid variant for NatTypeC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::p2p::NodeHopStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_peerId = <u32>::sse_decode(deserializer);
+        let mut var_targetIp = <String>::sse_decode(deserializer);
+        let mut var_latencyMs = <f64>::sse_decode(deserializer);
+        let mut var_packetLoss = <f32>::sse_decode(deserializer);
+        let mut var_nodeName = <String>::sse_decode(deserializer);
+        return crate::api::p2p::NodeHopStats {
+            peer_id: var_peerId,
+            target_ip: var_targetIp,
+            latency_ms: var_latencyMs,
+            packet_loss: var_packetLoss,
+            node_name: var_nodeName,
+        };
+    }
+}
+
+impl SseDecode for crate::api::credential::NodeKeyInfoC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_publicKey = <String>::sse_decode(deserializer);
+        let mut var_fingerprint = <String>::sse_decode(deserializer);
+        return crate::api::credential::NodeKeyInfoC {
+            public_key: var_publicKey,
+            fingerprint: var_fingerprint,
+        };
+    }
+}
+
+impl SseDecode for Option<crate::api::app_policy::AppRpcPolicyC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::app_policy::AppRpcPolicyC>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::local_channel::LocalEventC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::local_channel::LocalEventC>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::credential::NodeKeyInfoC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::credential::NodeKeyInfoC>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::presence::PresenceProfileC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::presence::PresenceProfileC>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::nat::P2pLikelihoodC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::nat::P2pLikelihoodC::Likely,
+            1 => crate::api::nat::P2pLikelihoodC::Possible,
+            2 => crate::api::nat::P2pLikelihoodC::Unlikely,
+            _ => unreachable!("Invalid variant for P2pLikelihoodC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_codec::PayloadCodecC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::app_codec::PayloadCodecC::Raw,
+            1 => crate::api::app_codec::PayloadCodecC::Json,
+            2 => crate::api::app_codec::PayloadCodecC::MessagePack,
+            3 => crate::api::app_codec::PayloadCodecC::Cbor,
+            _ => unreachable!("Invalid variant for PayloadCodecC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_policy::PeerChannelRuleC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_peerId = <u32>::sse_decode(deserializer);
+        let mut var_allow = <Vec<String>>::sse_decode(deserializer);
+        let mut var_deny = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::app_policy::PeerChannelRuleC {
+            peer_id: var_peerId,
+            allow: var_allow,
+            deny: var_deny,
+        };
+    }
+}
+
+impl SseDecode for crate::api::peer_control::PeerConnectionC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_connId = <String>::sse_decode(deserializer);
+        let mut var_tunnelType = <String>::sse_decode(deserializer);
+        let mut var_remoteAddr = <String>::sse_decode(deserializer);
+        let mut var_isClient = <bool>::sse_decode(deserializer);
+        let mut var_connectorUrl = <String>::sse_decode(deserializer);
+        return crate::api::peer_control::PeerConnectionC {
+            conn_id: var_connId,
+            tunnel_type: var_tunnelType,
+            remote_addr: var_remoteAddr,
+            is_client: var_isClient,
+            connector_url: var_connectorUrl,
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_metrics::PeerMetricsC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_peerId = <u32>::sse_decode(deserializer);
+        let mut var_counters = <crate::api::app_metrics::AppRpcCountersC>::sse_decode(deserializer);
+        let mut var_callLatency =
+            <crate::api::app_metrics::LatencyHistogramC>::sse_decode(deserializer);
+        let mut var_handlerLatency =
+            <crate::api::app_metrics::LatencyHistogramC>::sse_decode(deserializer);
+        return crate::api::app_metrics::PeerMetricsC {
+            peer_id: var_peerId,
+            counters: var_counters,
+            call_latency: var_callLatency,
+            handler_latency: var_handlerLatency,
+        };
+    }
+}
+
+impl SseDecode for crate::api::presence::PeerPresenceC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_node = <crate::api::p2p::KVNodeInfo>::sse_decode(deserializer);
+        let mut var_profile =
+            <Option<crate::api::presence::PresenceProfileC>>::sse_decode(deserializer);
+        return crate::api::presence::PeerPresenceC {
+            node: var_node,
+            profile: var_profile,
+        };
+    }
+}
+
+impl SseDecode for crate::api::proxy_cidr::PeerProxyCidrsC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_peerId = <u32>::sse_decode(deserializer);
+        let mut var_hostname = <String>::sse_decode(deserializer);
+        let mut var_cidrs = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::proxy_cidr::PeerProxyCidrsC {
+            peer_id: var_peerId,
+            hostname: var_hostname,
+            cidrs: var_cidrs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_policy::PeerRejectionsC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_peerId = <u32>::sse_decode(deserializer);
+        let mut var_count = <u64>::sse_decode(deserializer);
+        return crate::api::app_policy::PeerRejectionsC {
+            peer_id: var_peerId,
+            count: var_count,
+        };
+    }
+}
+
+impl SseDecode for crate::api::credential::PinnedServerKeyC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_serverUrl = <String>::sse_decode(deserializer);
+        let mut var_publicKey = <String>::sse_decode(deserializer);
+        let mut var_fingerprint = <String>::sse_decode(deserializer);
+        return crate::api::credential::PinnedServerKeyC {
+            server_url: var_serverUrl,
+            public_key: var_publicKey,
+            fingerprint: var_fingerprint,
+        };
+    }
+}
+
+impl SseDecode for crate::api::port_forward::PortForwardC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_proto = <String>::sse_decode(deserializer);
+        let mut var_bindAddr = <String>::sse_decode(deserializer);
+        let mut var_dstAddr = <String>::sse_decode(deserializer);
+        let mut var_managed = <bool>::sse_decode(deserializer);
+        let mut var_activeConnections = <u32>::sse_decode(deserializer);
+        let mut var_totalConnections = <u64>::sse_decode(deserializer);
+        let mut var_txBytes = <u64>::sse_decode(deserializer);
+        let mut var_rxBytes = <u64>::sse_decode(deserializer);
+        let mut var_errors = <u64>::sse_decode(deserializer);
+        let mut var_lastError = <String>::sse_decode(deserializer);
+        return crate::api::port_forward::PortForwardC {
+            proto: var_proto,
+            bind_addr: var_bindAddr,
+            dst_addr: var_dstAddr,
+            managed: var_managed,
+            active_connections: var_activeConnections,
+            total_connections: var_totalConnections,
+            tx_bytes: var_txBytes,
+            rx_bytes: var_rxBytes,
+            errors: var_errors,
+            last_error: var_lastError,
+        };
+    }
+}
+
+impl SseDecode for crate::api::nat::PortMappingC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::nat::PortMappingC::Unknown,
+            1 => crate::api::nat::PortMappingC::NoTranslation,
+            2 => crate::api::nat::PortMappingC::EndpointIndependent,
+            3 => crate::api::nat::PortMappingC::EndpointDependentPredictable,
+            4 => crate::api::nat::PortMappingC::EndpointDependent,
+            _ => unreachable!("Invalid variant for PortMappingC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::port_mapping::PortMappingRequestC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_listener = <String>::sse_decode(deserializer);
+        let mut var_externalPort = <u16>::sse_decode(deserializer);
+        return crate::api::port_mapping::PortMappingRequestC {
+            listener: var_listener,
+            external_port: var_externalPort,
+        };
+    }
+}
+
+impl SseDecode for crate::api::port_mapping::PortMappingStateC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::port_mapping::PortMappingStateC::Active,
+            1 => crate::api::port_mapping::PortMappingStateC::Failed,
+            2 => crate::api::port_mapping::PortMappingStateC::Expired,
+            3 => crate::api::port_mapping::PortMappingStateC::Removed,
+            _ => unreachable!("Invalid variant for PortMappingStateC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::presence::PresenceEventC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::presence::PresenceEventKindC>::sse_decode(deserializer);
+        let mut var_peerId = <u32>::sse_decode(deserializer);
+        let mut var_profile =
+            <Option<crate::api::presence::PresenceProfileC>>::sse_decode(deserializer);
+        return crate::api::presence::PresenceEventC {
+            kind: var_kind,
+            peer_id: var_peerId,
+            profile: var_profile,
+        };
+    }
+}
+
+impl SseDecode for crate::api::presence::PresenceEventKindC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::presence::PresenceEventKindC::Updated,
+            1 => crate::api::presence::PresenceEventKindC::Left,
+            _ => unreachable!("Invalid variant for PresenceEventKindC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::presence::PresenceProfileC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_displayName = <String>::sse_decode(deserializer);
+        let mut var_avatarHash = <String>::sse_decode(deserializer);
+        let mut var_status = <crate::api::presence::PresenceStatusC>::sse_decode(deserializer);
+        let mut var_appName = <String>::sse_decode(deserializer);
+        let mut var_appVersion = <String>::sse_decode(deserializer);
+        let mut var_currentGame = <String>::sse_decode(deserializer);
+        return crate::api::presence::PresenceProfileC {
+            display_name: var_displayName,
+            avatar_hash: var_avatarHash,
+            status: var_status,
+            app_name: var_appName,
+            app_version: var_appVersion,
+            current_game: var_currentGame,
+        };
+    }
+}
+
+impl SseDecode for crate::api::presence::PresenceStatusC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::presence::PresenceStatusC::Online,
+            1 => crate::api::presence::PresenceStatusC::Away,
+            2 => crate::api::presence::PresenceStatusC::Busy,
+            _ => unreachable!("Invalid variant for PresenceStatusC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::proxy_cidr::ProxyCidrC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cidr = <String>::sse_decode(deserializer);
+        let mut var_mappedCidr = <String>::sse_decode(deserializer);
+        return crate::api::proxy_cidr::ProxyCidrC {
+            cidr: var_cidr,
+            mapped_cidr: var_mappedCidr,
+        };
+    }
+}
+
+impl SseDecode for crate::api::proxy_cidr::ProxyCidrConflictC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind =
+            <crate::api::proxy_cidr::ProxyCidrConflictKindC>::sse_decode(deserializer);
+        let mut var_cidr = <String>::sse_decode(deserializer);
+        let mut var_otherCidr = <String>::sse_decode(deserializer);
+        let mut var_peerId = <u32>::sse_decode(deserializer);
+        let mut var_hostname = <String>::sse_decode(deserializer);
+        let mut var_interface = <String>::sse_decode(deserializer);
+        return crate::api::proxy_cidr::ProxyCidrConflictC {
+            kind: var_kind,
+            cidr: var_cidr,
+            other_cidr: var_otherCidr,
+            peer_id: var_peerId,
+            hostname: var_hostname,
+            interface: var_interface,
+        };
+    }
+}
+
+impl SseDecode for crate::api::proxy_cidr::ProxyCidrConflictKindC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::proxy_cidr::ProxyCidrConflictKindC::VirtualNetwork,
+            1 => crate::api::proxy_cidr::ProxyCidrConflictKindC::PeerExport,
+            2 => crate::api::proxy_cidr::ProxyCidrConflictKindC::LocalInterface,
+            _ => unreachable!("Invalid variant for ProxyCidrConflictKindC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_policy::RateLimitC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_perSecond = <u32>::sse_decode(deserializer);
+        let mut var_burst = <u32>::sse_decode(deserializer);
+        return crate::api::app_policy::RateLimitC {
+            per_second: var_perSecond,
+            burst: var_burst,
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_policy::ReasonRejectionsC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_reason = <crate::api::app_policy::RejectReasonC>::sse_decode(deserializer);
+        let mut var_count = <u64>::sse_decode(deserializer);
+        return crate::api::app_policy::ReasonRejectionsC {
+            reason: var_reason,
+            count: var_count,
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_policy::RejectReasonC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::app_policy::RejectReasonC::PayloadTooLarge,
+            1 => crate::api::app_policy::RejectReasonC::PeerRateLimited,
+            2 => crate::api::app_policy::RejectReasonC::ChannelRateLimited,
+            3 => crate::api::app_policy::RejectReasonC::ChannelDenied,
+            _ => unreachable!("Invalid variant for RejectReasonC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::server_selection::SelectedServerC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_role = <crate::api::server_selection::ServerRoleC>::sse_decode(deserializer);
+        let mut var_latencyMs = <u32>::sse_decode(deserializer);
+        let mut var_connected = <bool>::sse_decode(deserializer);
+        let mut var_reason = <String>::sse_decode(deserializer);
+        return crate::api::server_selection::SelectedServerC {
+            url: var_url,
+            role: var_role,
+            latency_ms: var_latencyMs,
+            connected: var_connected,
+            reason: var_reason,
+        };
+    }
+}
+
+impl SseDecode for crate::api::server_probe::ServerProbeResultC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_scheme = <String>::sse_decode(deserializer);
+        let mut var_protocolSupported = <bool>::sse_decode(deserializer);
+        let mut var_resolvedAddr = <String>::sse_decode(deserializer);
+        let mut var_dnsMs = <u32>::sse_decode(deserializer);
+        let mut var_reachable = <bool>::sse_decode(deserializer);
+        let mut var_latencyMs = <u32>::sse_decode(deserializer);
+        let mut var_error = <String>::sse_decode(deserializer);
+        return crate::api::server_probe::ServerProbeResultC {
+            url: var_url,
+            scheme: var_scheme,
+            protocol_supported: var_protocolSupported,
+            resolved_addr: var_resolvedAddr,
+            dns_ms: var_dnsMs,
+            reachable: var_reachable,
+            latency_ms: var_latencyMs,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::server_selection::ServerRoleC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::server_selection::ServerRoleC::Active,
+            1 => crate::api::server_selection::ServerRoleC::Standby,
+            _ => unreachable!("Invalid variant for ServerRoleC: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::server_selection::ServerSelectionStatusC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_activeCount = <u32>::sse_decode(deserializer);
+        let mut var_servers =
+            <Vec<crate::api::server_selection::SelectedServerC>>::sse_decode(deserializer);
+        return crate::api::server_selection::ServerSelectionStatusC {
+            enabled: var_enabled,
+            active_count: var_activeCount,
+            servers: var_servers,
+        };
+    }
+}
+
+impl SseDecode for crate::api::firewall::TunFirewallStatusC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_backend = <crate::api::firewall::FirewallBackendC>::sse_decode(deserializer);
+        let mut var_active = <bool>::sse_decode(deserializer);
+        let mut var_tunAllowed = <bool>::sse_decode(deserializer);
+        let mut var_ports =
+            <Vec<crate::api::firewall::FirewallPortStatusC>>::sse_decode(deserializer);
+        let mut var_detail = <String>::sse_decode(deserializer);
+        return crate::api::firewall::TunFirewallStatusC {
+            backend: var_backend,
+            active: var_active,
+            tun_allowed: var_tunAllowed,
+            ports: var_ports,
+            detail: var_detail,
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__file_transfer__accept_file_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__endpoints__add_listener_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__endpoints__add_peer_uri_impl(port, ptr, rust_vec_len, data_len),
+        4 => {
+            wire__crate__api__port_forward__add_port_forward_impl(port, ptr, rust_vec_len, data_len)
+        }
+        5 => wire__crate__api__proxy_cidr__add_proxy_cidr_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__firewall__allow_instance_through_firewall_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        7 => wire__crate__api__firewall__allow_tun_through_firewall_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__app_broadcast__app_broadcast_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__p2p__app_call_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__app_codec__app_call_encoded_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__p2p__app_call_reply_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__app_codec__app_call_reply_encoded_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        14 => wire__crate__api__app_call_control__app_call_with_deadline_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        15 => wire__crate__api__p2p__app_notify_impl(port, ptr, rust_vec_len, data_len),
+        16 => {
+            wire__crate__api__app_codec__app_notify_encoded_impl(port, ptr, rust_vec_len, data_len)
+        }
+        18 => {
+            wire__crate__api__app_stream__app_stream_cancel_impl(port, ptr, rust_vec_len, data_len)
+        }
+        19 => {
+            wire__crate__api__app_stream__app_stream_finish_impl(port, ptr, rust_vec_len, data_len)
+        }
+        20 => wire__crate__api__app_stream__app_stream_open_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__app_stream__app_stream_read_impl(port, ptr, rust_vec_len, data_len),
+        22 => {
+            wire__crate__api__app_stream__app_stream_write_impl(port, ptr, rust_vec_len, data_len)
+        }
+        23 => wire__crate__api__app_call_control__cancel_app_call_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        24 => wire__crate__api__file_transfer__cancel_file_transfer_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        25 => wire__crate__api__proxy_cidr__check_proxy_cidr_conflicts_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        27 => wire__crate__api__presence__clear_presence_profile_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        28 => wire__crate__api__peer_control__close_peer_connection_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        29 => wire__crate__api__p2p__close_server_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__p2p__create_server_impl(port, ptr, rust_vec_len, data_len),
+        31 => {
+            wire__crate__api__p2p__create_server_with_flags_impl(port, ptr, rust_vec_len, data_len)
+        }
+        33 => wire__crate__api__nat__detect_nat_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__firewall__diagnose_firewall_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__port_mapping__disable_port_mapping_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        36 => wire__crate__api__server_selection__disable_server_selection_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        37 => wire__crate__api__p2p__easytier_version_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__port_mapping__enable_port_mapping_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        39 => wire__crate__api__server_selection__enable_server_selection_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        43 => wire__crate__api__firewall__get_firewall_profile_status_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        44 => wire__crate__api__firewall__get_firewall_snapshot_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        45 => {
+            wire__crate__api__firewall__get_firewall_status_impl(port, ptr, rust_vec_len, data_len)
+        }
+        46 => wire__crate__api__p2p__get_ips_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__port_mapping__get_local_endpoints_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        48 => wire__crate__api__p2p__get_network_status_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__p2p__get_peer_route_pairs_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__p2p__get_running_info_impl(port, ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__server_selection__get_server_selection_status_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        54 => wire__crate__api__firewall__get_tun_firewall_status_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        56 => wire__crate__api__p2p__handle_event_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__p2p__init_app_impl(port, ptr, rust_vec_len, data_len),
+        58 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__p2p__is_easytier_running_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__p2p__join_handle_result_impl(port, ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__peer_control__list_peer_connections_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        67 => {
+            wire__crate__api__presence__list_peer_presence_impl(port, ptr, rust_vec_len, data_len)
+        }
+        68 => wire__crate__api__proxy_cidr__list_peer_proxy_cidrs_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        70 => wire__crate__api__port_forward__list_port_forwards_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        72 => {
+            wire__crate__api__proxy_cidr__list_proxy_cidrs_impl(port, ptr, rust_vec_len, data_len)
+        }
+        73 => wire__crate__api__credential__load_or_generate_node_keypair_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        76 => {
+            wire__crate__api__messaging__mark_messages_read_impl(port, ptr, rust_vec_len, data_len)
+        }
+        77 => wire__crate__api__p2p__my_peer_id_impl(port, ptr, rust_vec_len, data_len),
+        79 => wire__crate__api__file_transfer__offer_file_impl(port, ptr, rust_vec_len, data_len),
+        80 => wire__crate__api__p2p__peer_ping_impl(port, ptr, rust_vec_len, data_len),
+        81 => wire__crate__api__p2p__pending_app_call_count_impl(port, ptr, rust_vec_len, data_len),
+        82 => wire__crate__api__credential__pin_server_public_key_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        83 => wire__crate__api__server_probe__probe_servers_impl(port, ptr, rust_vec_len, data_len),
+        85 => {
+            wire__crate__api__peer_control__reconnect_peer_impl(port, ptr, rust_vec_len, data_len)
+        }
+        86 => wire__crate__api__credential__regenerate_node_keypair_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        87 => wire__crate__api__app_router__register_app_channels_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        88 => wire__crate__api__file_transfer__reject_file_impl(port, ptr, rust_vec_len, data_len),
+        89 => wire__crate__api__firewall__remove_instance_firewall_rules_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        90 => wire__crate__api__endpoints__remove_listener_impl(port, ptr, rust_vec_len, data_len),
+        91 => wire__crate__api__endpoints__remove_peer_uri_impl(port, ptr, rust_vec_len, data_len),
+        92 => wire__crate__api__port_forward__remove_port_forward_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        93 => {
+            wire__crate__api__proxy_cidr__remove_proxy_cidr_impl(port, ptr, rust_vec_len, data_len)
+        }
+        94 => wire__crate__api__firewall__remove_tun_firewall_rule_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        97 => {
+            wire__crate__api__diagnostics__run_diagnostics_impl(port, ptr, rust_vec_len, data_len)
+        }
+        98 => {
+            wire__crate__api__messaging__send_direct_message_impl(port, ptr, rust_vec_len, data_len)
+        }
+        99 => {
+            wire__crate__api__messaging__send_room_message_impl(port, ptr, rust_vec_len, data_len)
+        }
+        100 => wire__crate__api__p2p__send_udp_to_localhost_impl(port, ptr, rust_vec_len, data_len),
+        102 => wire__crate__api__firewall__set_firewall_profile_status_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        103 => {
+            wire__crate__api__firewall__set_firewall_status_impl(port, ptr, rust_vec_len, data_len)
+        }
+        105 => {
+            wire__crate__api__presence__set_presence_profile_impl(port, ptr, rust_vec_len, data_len)
+        }
+        106 => wire__crate__api__p2p__set_tun_fd_impl(port, ptr, rust_vec_len, data_len),
+        108 => wire__crate__api__messaging__start_messaging_impl(port, ptr, rust_vec_len, data_len),
+        109 => wire__crate__api__app_call_control__subscribe_app_call_control_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        110 => wire__crate__api__app_router__subscribe_app_channels_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        111 => wire__crate__api__p2p__subscribe_app_inbound_impl(port, ptr, rust_vec_len, data_len),
+        112 => wire__crate__api__app_stream__subscribe_app_stream_progress_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        113 => wire__crate__api__app_stream__subscribe_app_streams_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        114 => wire__crate__api__credential__subscribe_credential_events_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        115 => wire__crate__api__file_transfer__subscribe_file_offers_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        116 => wire__crate__api__file_transfer__subscribe_file_transfer_progress_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        117 => {
+            wire__crate__api__messaging__subscribe_messages_impl(port, ptr, rust_vec_len, data_len)
+        }
+        118 => wire__crate__api__port_mapping__subscribe_port_mapping_events_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        119 => {
+            wire__crate__api__presence__subscribe_presence_impl(port, ptr, rust_vec_len, data_len)
+        }
+        120 => wire__crate__api__credential__unpin_server_public_key_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        121 => wire__crate__api__app_router__unregister_app_channels_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        10 => {
+            wire__crate__api__app_call_control__app_call_deadline_impl(ptr, rust_vec_len, data_len)
+        }
+        17 => wire__crate__api__app_metrics__app_rpc_metrics_openmetrics_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        26 => wire__crate__api__app_policy__clear_app_rpc_policy_impl(ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__app_codec__decode_app_payload_impl(ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__app_metrics__get_app_rpc_metrics_impl(ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__app_policy__get_app_rpc_policy_impl(ptr, rust_vec_len, data_len),
+        42 => {
+            wire__crate__api__app_policy__get_app_rpc_rejections_impl(ptr, rust_vec_len, data_len)
+        }
+        50 => {
+            wire__crate__api__presence__get_presence_bad_announces_impl(ptr, rust_vec_len, data_len)
+        }
+        51 => wire__crate__api__presence__get_presence_profile_impl(ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__app_call_control__is_app_call_cancelled_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        61 => {
+            wire__crate__api__redact__is_unredacted_debug_logging_impl(ptr, rust_vec_len, data_len)
+        }
+        63 => wire__crate__api__app_router__list_app_channels_impl(ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__file_transfer__list_file_offers_impl(ptr, rust_vec_len, data_len),
+        65 => wire__crate__api__messaging__list_messages_impl(ptr, rust_vec_len, data_len),
+        69 => {
+            wire__crate__api__credential__list_pinned_server_keys_impl(ptr, rust_vec_len, data_len)
+        }
+        71 => wire__crate__api__port_mapping__list_port_mappings_impl(ptr, rust_vec_len, data_len),
+        74 => {
+            wire__crate__api__local_channel__local_event_session_impl(ptr, rust_vec_len, data_len)
+        }
+        75 => wire__crate__api__credential__local_public_key_impl(ptr, rust_vec_len, data_len),
+        78 => wire__crate__api__nat__nat_compatibility_impl(ptr, rust_vec_len, data_len),
+        84 => {
+            wire__crate__api__credential__public_key_fingerprint_impl(ptr, rust_vec_len, data_len)
+        }
+        95 => {
+            wire__crate__api__app_metrics__reset_app_rpc_metrics_impl(ptr, rust_vec_len, data_len)
+        }
+        96 => {
+            wire__crate__api__app_policy__reset_app_rpc_rejections_impl(ptr, rust_vec_len, data_len)
+        }
+        101 => wire__crate__api__app_policy__set_app_rpc_policy_impl(ptr, rust_vec_len, data_len),
+        104 => {
+            wire__crate__api__local_channel__set_local_event_port_impl(ptr, rust_vec_len, data_len)
+        }
+        107 => {
+            wire__crate__api__redact__set_unredacted_debug_logging_impl(ptr, rust_vec_len, data_len)
+        }
+        122 => {
+            wire__crate__api__local_channel__verify_local_event_impl(ptr, rust_vec_len, data_len)
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<EventBusSubscriber> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<EventBusSubscriber>
+{
+}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<EventBusSubscriber>> for EventBusSubscriber {
+    fn into_into_dart(self) -> FrbWrapper<EventBusSubscriber> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<JoinHandle<()>> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<JoinHandle<()>> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<JoinHandle<()>>> for JoinHandle<()> {
+    fn into_into_dart(self) -> FrbWrapper<JoinHandle<()>> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<JoinHandle<Result<String, String>>> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<JoinHandle<Result<String, String>>>
+{
+}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<JoinHandle<Result<String, String>>>>
+    for JoinHandle<Result<String, String>>
+{
+    fn into_into_dart(self) -> FrbWrapper<JoinHandle<Result<String, String>>> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<PeerRoutePair> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<PeerRoutePair> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<PeerRoutePair>> for PeerRoutePair {
+    fn into_into_dart(self) -> FrbWrapper<PeerRoutePair> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_call_control::AppCallControlC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.from_peer_id.into_into_dart().into_dart(),
+            self.request_id.into_into_dart().into_dart(),
+            self.token.into_into_dart().into_dart(),
+            self.deadline_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_call_control::AppCallControlC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_call_control::AppCallControlC>
+    for crate::api::app_call_control::AppCallControlC
+{
+    fn into_into_dart(self) -> crate::api::app_call_control::AppCallControlC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_call_control::AppCallControlKindC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Deadline => 0.into_dart(),
+            Self::Cancelled => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_call_control::AppCallControlKindC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_call_control::AppCallControlKindC>
+    for crate::api::app_call_control::AppCallControlKindC
+{
+    fn into_into_dart(self) -> crate::api::app_call_control::AppCallControlKindC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_codec::AppCallDecodedC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.status.into_into_dart().into_dart(),
+            self.error_msg.into_into_dart().into_dart(),
+            self.codec.into_into_dart().into_dart(),
+            self.payload.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_codec::AppCallDecodedC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_codec::AppCallDecodedC>
+    for crate::api::app_codec::AppCallDecodedC
+{
+    fn into_into_dart(self) -> crate::api::app_codec::AppCallDecodedC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::AppCallResultC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.status.into_into_dart().into_dart(),
+            self.error_msg.into_into_dart().into_dart(),
+            self.payload.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::p2p::AppCallResultC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::AppCallResultC>
+    for crate::api::p2p::AppCallResultC
+{
+    fn into_into_dart(self) -> crate::api::p2p::AppCallResultC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::AppInboundEventC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.from_peer_id.into_into_dart().into_dart(),
+            self.channel.into_into_dart().into_dart(),
+            self.request_id.into_into_dart().into_dart(),
+            self.token.into_into_dart().into_dart(),
+            self.payload.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::p2p::AppInboundEventC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::AppInboundEventC>
+    for crate::api::p2p::AppInboundEventC
+{
+    fn into_into_dart(self) -> crate::api::p2p::AppInboundEventC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::AppInboundKindC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Call => 0.into_dart(),
+            Self::Notify => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::p2p::AppInboundKindC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::AppInboundKindC>
+    for crate::api::p2p::AppInboundKindC
+{
+    fn into_into_dart(self) -> crate::api::p2p::AppInboundKindC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_metrics::AppRpcCountersC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.calls_sent.into_into_dart().into_dart(),
+            self.calls_received.into_into_dart().into_dart(),
+            self.notifies_sent.into_into_dart().into_dart(),
+            self.notifies_received.into_into_dart().into_dart(),
+            self.replies_sent.into_into_dart().into_dart(),
+            self.replies_received.into_into_dart().into_dart(),
+            self.timeouts.into_into_dart().into_dart(),
+            self.no_subscriber.into_into_dart().into_dart(),
+            self.service_dropped.into_into_dart().into_dart(),
+            self.cancelled.into_into_dart().into_dart(),
+            self.send_errors.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_metrics::AppRpcCountersC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_metrics::AppRpcCountersC>
+    for crate::api::app_metrics::AppRpcCountersC
+{
+    fn into_into_dart(self) -> crate::api::app_metrics::AppRpcCountersC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_metrics::AppRpcMetricsC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.lagged_events.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+            self.peers.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_metrics::AppRpcMetricsC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_metrics::AppRpcMetricsC>
+    for crate::api::app_metrics::AppRpcMetricsC
+{
+    fn into_into_dart(self) -> crate::api::app_metrics::AppRpcMetricsC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_policy::AppRpcPolicyC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_payload_bytes.into_into_dart().into_dart(),
+            self.peer_rate.into_into_dart().into_dart(),
+            self.channel_rate.into_into_dart().into_dart(),
+            self.channel_rules.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_policy::AppRpcPolicyC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_policy::AppRpcPolicyC>
+    for crate::api::app_policy::AppRpcPolicyC
+{
+    fn into_into_dart(self) -> crate::api::app_policy::AppRpcPolicyC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_policy::AppRpcRejectionsC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.total.into_into_dart().into_dart(),
+            self.by_reason.into_into_dart().into_dart(),
+            self.by_peer.into_into_dart().into_dart(),
+            self.by_channel.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_policy::AppRpcRejectionsC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_policy::AppRpcRejectionsC>
+    for crate::api::app_policy::AppRpcRejectionsC
+{
+    fn into_into_dart(self) -> crate::api::app_policy::AppRpcRejectionsC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_stream::AppStreamProgressC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.state.into_into_dart().into_dart(),
+            self.bytes.into_into_dart().into_dart(),
+            self.total_len.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_stream::AppStreamProgressC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_stream::AppStreamProgressC>
+    for crate::api::app_stream::AppStreamProgressC
+{
+    fn into_into_dart(self) -> crate::api::app_stream::AppStreamProgressC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_stream::AppStreamStateC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Open => 0.into_dart(),
+            Self::Finished => 1.into_dart(),
+            Self::Cancelled => 2.into_dart(),
+            Self::Failed => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_stream::AppStreamStateC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_stream::AppStreamStateC>
+    for crate::api::app_stream::AppStreamStateC
+{
+    fn into_into_dart(self) -> crate::api::app_stream::AppStreamStateC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_broadcast::BroadcastDeliveryC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.peer_id.into_into_dart().into_dart(),
+            self.delivered.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_broadcast::BroadcastDeliveryC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_broadcast::BroadcastDeliveryC>
+    for crate::api::app_broadcast::BroadcastDeliveryC
+{
+    fn into_into_dart(self) -> crate::api::app_broadcast::BroadcastDeliveryC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_broadcast::BroadcastScopeC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.peer_ids.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_broadcast::BroadcastScopeC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_broadcast::BroadcastScopeC>
+    for crate::api::app_broadcast::BroadcastScopeC
+{
+    fn into_into_dart(self) -> crate::api::app_broadcast::BroadcastScopeC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_broadcast::BroadcastScopeKindC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::All => 0.into_dart(),
+            Self::DirectOnly => 1.into_dart(),
+            Self::Peers => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_broadcast::BroadcastScopeKindC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_broadcast::BroadcastScopeKindC>
+    for crate::api::app_broadcast::BroadcastScopeKindC
+{
+    fn into_into_dart(self) -> crate::api::app_broadcast::BroadcastScopeKindC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_metrics::ChannelMetricsC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.channel.into_into_dart().into_dart(),
+            self.counters.into_into_dart().into_dart(),
+            self.call_latency.into_into_dart().into_dart(),
+            self.handler_latency.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_metrics::ChannelMetricsC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_metrics::ChannelMetricsC>
+    for crate::api::app_metrics::ChannelMetricsC
+{
+    fn into_into_dart(self) -> crate::api::app_metrics::ChannelMetricsC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_policy::ChannelRejectionsC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.channel.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_policy::ChannelRejectionsC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_policy::ChannelRejectionsC>
+    for crate::api::app_policy::ChannelRejectionsC
+{
+    fn into_into_dart(self) -> crate::api::app_policy::ChannelRejectionsC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::messaging::ChatMessageC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.from_peer_id.into_into_dart().into_dart(),
+            self.to_peer_id.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.sent_at_ms.into_into_dart().into_dart(),
+            self.outgoing.into_into_dart().into_dart(),
+            self.delivered_to.into_into_dart().into_dart(),
+            self.read_by.into_into_dart().into_dart(),
+            self.queued_for.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::messaging::ChatMessageC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::messaging::ChatMessageC>
+    for crate::api::messaging::ChatMessageC
+{
+    fn into_into_dart(self) -> crate::api::messaging::ChatMessageC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::messaging::ChatMessageKindC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Direct => 0.into_dart(),
+            Self::Room => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::messaging::ChatMessageKindC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::messaging::ChatMessageKindC>
+    for crate::api::messaging::ChatMessageKindC
+{
+    fn into_into_dart(self) -> crate::api::messaging::ChatMessageKindC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::diagnostics::ConnectErrorC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.dst.into_into_dart().into_dart(),
+            self.ip_version.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+            self.timestamp_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::diagnostics::ConnectErrorC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::diagnostics::ConnectErrorC>
+    for crate::api::diagnostics::ConnectErrorC
+{
+    fn into_into_dart(self) -> crate::api::diagnostics::ConnectErrorC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::credential::CredentialEventC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.server_url.into_into_dart().into_dart(),
+            self.public_key.into_into_dart().into_dart(),
+            self.fingerprint.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::credential::CredentialEventC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::credential::CredentialEventC>
+    for crate::api::credential::CredentialEventC
+{
+    fn into_into_dart(self) -> crate::api::credential::CredentialEventC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::credential::CredentialEventKindC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::LocalKeyChanged => 0.into_dart(),
+            Self::PinnedKeyChanged => 1.into_dart(),
+            Self::InstanceCredentialChanged => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::credential::CredentialEventKindC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::credential::CredentialEventKindC>
+    for crate::api::credential::CredentialEventKindC
+{
+    fn into_into_dart(self) -> crate::api::credential::CredentialEventKindC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_codec::DecodedPayloadC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.codec.into_into_dart().into_dart(),
+            self.payload.into_into_dart().into_dart(),
+            self.tagged.into_into_dart().into_dart(),
+            self.peer_accepts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_codec::DecodedPayloadC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_codec::DecodedPayloadC>
+    for crate::api::app_codec::DecodedPayloadC
+{
+    fn into_into_dart(self) -> crate::api::app_codec::DecodedPayloadC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::diagnostics::DiagnosticCheckC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.target.into_into_dart().into_dart(),
+            self.detail.into_into_dart().into_dart(),
+            self.suggestion.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::diagnostics::DiagnosticCheckC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::diagnostics::DiagnosticCheckC>
+    for crate::api::diagnostics::DiagnosticCheckC
+{
+    fn into_into_dart(self) -> crate::api::diagnostics::DiagnosticCheckC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::diagnostics::DiagnosticCheckKindC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::ServerResolve => 0.into_dart(),
+            Self::ServerReachability => 1.into_dart(),
+            Self::Listener => 2.into_dart(),
+            Self::TunDevice => 3.into_dart(),
+            Self::NatType => 4.into_dart(),
+            Self::HolePunching => 5.into_dart(),
+            Self::Firewall => 6.into_dart(),
+            Self::Mtu => 7.into_dart(),
+            Self::ConnectErrors => 8.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::diagnostics::DiagnosticCheckKindC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::diagnostics::DiagnosticCheckKindC>
+    for crate::api::diagnostics::DiagnosticCheckKindC
+{
+    fn into_into_dart(self) -> crate::api::diagnostics::DiagnosticCheckKindC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::diagnostics::DiagnosticReportC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.overall.into_into_dart().into_dart(),
+            self.checks.into_into_dart().into_dart(),
+            self.recent_connect_errors.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::diagnostics::DiagnosticReportC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::diagnostics::DiagnosticReportC>
+    for crate::api::diagnostics::DiagnosticReportC
+{
+    fn into_into_dart(self) -> crate::api::diagnostics::DiagnosticReportC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::diagnostics::DiagnosticStatusC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Pass => 0.into_dart(),
+            Self::Warn => 1.into_dart(),
+            Self::Fail => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::diagnostics::DiagnosticStatusC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::diagnostics::DiagnosticStatusC>
+    for crate::api::diagnostics::DiagnosticStatusC
+{
+    fn into_into_dart(self) -> crate::api::diagnostics::DiagnosticStatusC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::endpoints::EndpointOutcomeC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Added => 0.into_dart(),
+            Self::AlreadyPresent => 1.into_dart(),
+            Self::Removed => 2.into_dart(),
+            Self::NotFound => 3.into_dart(),
+            Self::Failed => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::endpoints::EndpointOutcomeC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::endpoints::EndpointOutcomeC>
+    for crate::api::endpoints::EndpointOutcomeC
+{
+    fn into_into_dart(self) -> crate::api::endpoints::EndpointOutcomeC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::endpoints::EndpointResultC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.outcome.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::endpoints::EndpointResultC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::endpoints::EndpointResultC>
+    for crate::api::endpoints::EndpointResultC
+{
+    fn into_into_dart(self) -> crate::api::endpoints::EndpointResultC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::file_transfer::FileOfferC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.from_peer_id.into_into_dart().into_dart(),
+            self.transfer_id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.sha256.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::file_transfer::FileOfferC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::file_transfer::FileOfferC>
+    for crate::api::file_transfer::FileOfferC
+{
+    fn into_into_dart(self) -> crate::api::file_transfer::FileOfferC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::file_transfer::FileTransferDirectionC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Sending => 0.into_dart(),
+            Self::Receiving => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::file_transfer::FileTransferDirectionC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::file_transfer::FileTransferDirectionC>
+    for crate::api::file_transfer::FileTransferDirectionC
+{
+    fn into_into_dart(self) -> crate::api::file_transfer::FileTransferDirectionC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::file_transfer::FileTransferProgressC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.transfer_id.into_into_dart().into_dart(),
+            self.peer_id.into_into_dart().into_dart(),
+            self.direction.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.bytes.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::file_transfer::FileTransferProgressC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::file_transfer::FileTransferProgressC>
+    for crate::api::file_transfer::FileTransferProgressC
+{
+    fn into_into_dart(self) -> crate::api::file_transfer::FileTransferProgressC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::file_transfer::FileTransferStateC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Offered => 0.into_dart(),
+            Self::Transferring => 1.into_dart(),
+            Self::Completed => 2.into_dart(),
+            Self::Rejected => 3.into_dart(),
+            Self::Cancelled => 4.into_dart(),
+            Self::Failed => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::file_transfer::FileTransferStateC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::file_transfer::FileTransferStateC>
+    for crate::api::file_transfer::FileTransferStateC
+{
+    fn into_into_dart(self) -> crate::api::file_transfer::FileTransferStateC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::firewall::FirewallBackendC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::None => 0.into_dart(),
+            Self::Firewalld => 1.into_dart(),
+            Self::Ufw => 2.into_dart(),
+            Self::Nftables => 3.into_dart(),
+            Self::Iptables => 4.into_dart(),
+            Self::WindowsFirewall => 5.into_dart(),
+            Self::Unknown => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::firewall::FirewallBackendC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::firewall::FirewallBackendC>
+    for crate::api::firewall::FirewallBackendC
+{
+    fn into_into_dart(self) -> crate::api::firewall::FirewallBackendC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::firewall::FirewallDiagnosisC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.backend.into_into_dart().into_dart(),
+            self.active.into_into_dart().into_dart(),
+            self.findings.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::firewall::FirewallDiagnosisC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::firewall::FirewallDiagnosisC>
+    for crate::api::firewall::FirewallDiagnosisC
+{
+    fn into_into_dart(self) -> crate::api::firewall::FirewallDiagnosisC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::firewall::FirewallError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::firewall::FirewallError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::firewall::FirewallError>
+    for crate::api::firewall::FirewallError
+{
+    fn into_into_dart(self) -> crate::api::firewall::FirewallError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::firewall::FirewallErrorKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::InvalidProfile => 0.into_dart(),
+            Self::Unsupported => 1.into_dart(),
+            Self::Instance => 2.into_dart(),
+            Self::Backend => 3.into_dart(),
+            Self::NeedsPrivileges => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::firewall::FirewallErrorKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::firewall::FirewallErrorKind>
+    for crate::api::firewall::FirewallErrorKind
+{
+    fn into_into_dart(self) -> crate::api::firewall::FirewallErrorKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::firewall::FirewallFindingC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rule.into_into_dart().into_dart(),
+            self.blocked.into_into_dart().into_dart(),
+            self.blocking_rule.into_into_dart().into_dart(),
+            self.suggestion.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::firewall::FirewallFindingC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::firewall::FirewallFindingC>
+    for crate::api::firewall::FirewallFindingC
+{
+    fn into_into_dart(self) -> crate::api::firewall::FirewallFindingC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::firewall::FirewallPortC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.proto.into_into_dart().into_dart(),
+            self.port.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::firewall::FirewallPortC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::firewall::FirewallPortC>
+    for crate::api::firewall::FirewallPortC
+{
+    fn into_into_dart(self) -> crate::api::firewall::FirewallPortC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::firewall::FirewallPortStatusC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.proto.into_into_dart().into_dart(),
+            self.port.into_into_dart().into_dart(),
+            self.allowed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::firewall::FirewallPortStatusC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::firewall::FirewallPortStatusC>
+    for crate::api::firewall::FirewallPortStatusC
+{
+    fn into_into_dart(self) -> crate::api::firewall::FirewallPortStatusC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::firewall::FirewallProfile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Domain => 0.into_dart(),
+            Self::Private => 1.into_dart(),
+            Self::Public => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::firewall::FirewallProfile
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::firewall::FirewallProfile>
+    for crate::api::firewall::FirewallProfile
+{
+    fn into_into_dart(self) -> crate::api::firewall::FirewallProfile {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::firewall::FirewallProfileStatusC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.profile.into_into_dart().into_dart(),
+            self.enabled.into_into_dart().into_dart(),
+            self.current.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::firewall::FirewallProfileStatusC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::firewall::FirewallProfileStatusC>
+    for crate::api::firewall::FirewallProfileStatusC
+{
+    fn into_into_dart(self) -> crate::api::firewall::FirewallProfileStatusC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::firewall::FirewallRuleC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.proto.into_into_dart().into_dart(),
+            self.port.into_into_dart().into_dart(),
+            self.detail.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::firewall::FirewallRuleC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::firewall::FirewallRuleC>
+    for crate::api::firewall::FirewallRuleC
+{
+    fn into_into_dart(self) -> crate::api::firewall::FirewallRuleC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::firewall::FirewallRuleKindC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Overlay => 0.into_dart(),
+            Self::ListenerPort => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::firewall::FirewallRuleKindC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::firewall::FirewallRuleKindC>
+    for crate::api::firewall::FirewallRuleKindC
+{
+    fn into_into_dart(self) -> crate::api::firewall::FirewallRuleKindC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::firewall::FirewallSnapshotC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.backend.into_into_dart().into_dart(),
+            self.profiles.into_into_dart().into_dart(),
+            self.current_profiles.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::firewall::FirewallSnapshotC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::firewall::FirewallSnapshotC>
+    for crate::api::firewall::FirewallSnapshotC
+{
+    fn into_into_dart(self) -> crate::api::firewall::FirewallSnapshotC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::FlagsC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.default_protocol.into_into_dart().into_dart(),
+            self.dev_name.into_into_dart().into_dart(),
+            self.enable_encryption.into_into_dart().into_dart(),
+            self.enable_ipv6.into_into_dart().into_dart(),
+            self.mtu.into_into_dart().into_dart(),
+            self.latency_first.into_into_dart().into_dart(),
+            self.enable_exit_node.into_into_dart().into_dart(),
+            self.no_tun.into_into_dart().into_dart(),
+            self.use_smoltcp.into_into_dart().into_dart(),
+            self.relay_network_whitelist.into_into_dart().into_dart(),
+            self.disable_p2p.into_into_dart().into_dart(),
+            self.relay_all_peer_rpc.into_into_dart().into_dart(),
+            self.disable_udp_hole_punching.into_into_dart().into_dart(),
+            self.disable_tcp_hole_punching.into_into_dart().into_dart(),
+            self.multi_thread.into_into_dart().into_dart(),
+            self.data_compress_algo.into_into_dart().into_dart(),
+            self.bind_device.into_into_dart().into_dart(),
+            self.enable_kcp_proxy.into_into_dart().into_dart(),
+            self.disable_kcp_input.into_into_dart().into_dart(),
+            self.disable_relay_kcp.into_into_dart().into_dart(),
+            self.proxy_forward_by_system.into_into_dart().into_dart(),
+            self.accept_dns.into_into_dart().into_dart(),
+            self.private_mode.into_into_dart().into_dart(),
+            self.enable_quic_proxy.into_into_dart().into_dart(),
+            self.disable_quic_input.into_into_dart().into_dart(),
+            self.disable_sym_hole_punching.into_into_dart().into_dart(),
+            self.tcp_whitelist.into_into_dart().into_dart(),
+            self.udp_whitelist.into_into_dart().into_dart(),
+            self.secure_mode.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::p2p::FlagsC {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::FlagsC> for crate::api::p2p::FlagsC {
+    fn into_into_dart(self) -> crate::api::p2p::FlagsC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::Forward {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.bind_addr.into_into_dart().into_dart(),
+            self.dst_addr.into_into_dart().into_dart(),
+            self.proto.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::p2p::Forward {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::Forward> for crate::api::p2p::Forward {
+    fn into_into_dart(self) -> crate::api::p2p::Forward {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_stream::InboundAppStreamC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.from_peer_id.into_into_dart().into_dart(),
+            self.stream_id.into_into_dart().into_dart(),
+            self.channel.into_into_dart().into_dart(),
+            self.total_len.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_stream::InboundAppStreamC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_stream::InboundAppStreamC>
+    for crate::api::app_stream::InboundAppStreamC
+{
+    fn into_into_dart(self) -> crate::api::app_stream::InboundAppStreamC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::KVNetworkStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.total_nodes.into_into_dart().into_dart(),
+            self.nodes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::p2p::KVNetworkStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::KVNetworkStatus>
+    for crate::api::p2p::KVNetworkStatus
+{
+    fn into_into_dart(self) -> crate::api::p2p::KVNetworkStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::KVNodeConnectionStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.conn_type.into_into_dart().into_dart(),
+            self.rx_bytes.into_into_dart().into_dart(),
+            self.tx_bytes.into_into_dart().into_dart(),
+            self.rx_packets.into_into_dart().into_dart(),
+            self.tx_packets.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::p2p::KVNodeConnectionStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::KVNodeConnectionStats>
+    for crate::api::p2p::KVNodeConnectionStats
+{
+    fn into_into_dart(self) -> crate::api::p2p::KVNodeConnectionStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::KVNodeInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.peer_id.into_into_dart().into_dart(),
+            self.hostname.into_into_dart().into_dart(),
+            self.ipv4.into_into_dart().into_dart(),
+            self.ipv6.into_into_dart().into_dart(),
+            self.latency_ms.into_into_dart().into_dart(),
+            self.nat.into_into_dart().into_dart(),
+            self.hops.into_into_dart().into_dart(),
+            self.loss_rate.into_into_dart().into_dart(),
+            self.connections.into_into_dart().into_dart(),
+            self.tunnel_proto.into_into_dart().into_dart(),
+            self.conn_type.into_into_dart().into_dart(),
+            self.rx_bytes.into_into_dart().into_dart(),
+            self.tx_bytes.into_into_dart().into_dart(),
+            self.version.into_into_dart().into_dart(),
+            self.cost.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::p2p::KVNodeInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::KVNodeInfo>
+    for crate::api::p2p::KVNodeInfo
+{
+    fn into_into_dart(self) -> crate::api::p2p::KVNodeInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_metrics::LatencyHistogramC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.bounds_ms.into_into_dart().into_dart(),
+            self.counts.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+            self.sum_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_metrics::LatencyHistogramC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_metrics::LatencyHistogramC>
+    for crate::api::app_metrics::LatencyHistogramC
+{
+    fn into_into_dart(self) -> crate::api::app_metrics::LatencyHistogramC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::port_mapping::ListenerMappingC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.local_listener.into_into_dart().into_dart(),
+            self.mapped_listener.into_into_dart().into_dart(),
+            self.backend.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.managed_by_easytier.into_into_dart().into_dart(),
+            self.lease_remaining_secs.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::port_mapping::ListenerMappingC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::port_mapping::ListenerMappingC>
+    for crate::api::port_mapping::ListenerMappingC
+{
+    fn into_into_dart(self) -> crate::api::port_mapping::ListenerMappingC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::port_mapping::LocalEndpointsC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.listeners.into_into_dart().into_dart(),
+            self.mapped_listeners.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::port_mapping::LocalEndpointsC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::port_mapping::LocalEndpointsC>
+    for crate::api::port_mapping::LocalEndpointsC
+{
+    fn into_into_dart(self) -> crate::api::port_mapping::LocalEndpointsC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::local_channel::LocalEventC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.instance_id.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.seq.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::local_channel::LocalEventC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::local_channel::LocalEventC>
+    for crate::api::local_channel::LocalEventC
+{
+    fn into_into_dart(self) -> crate::api::local_channel::LocalEventC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::messaging::MessageEventC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.peer_id.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::messaging::MessageEventC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::messaging::MessageEventC>
+    for crate::api::messaging::MessageEventC
+{
+    fn into_into_dart(self) -> crate::api::messaging::MessageEventC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::messaging::MessageEventKindC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Received => 0.into_dart(),
+            Self::Sent => 1.into_dart(),
+            Self::Delivered => 2.into_dart(),
+            Self::Read => 3.into_dart(),
+            Self::Queued => 4.into_dart(),
+            Self::HistoryWriteFailed => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::messaging::MessageEventKindC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::messaging::MessageEventKindC>
+    for crate::api::messaging::MessageEventKindC
+{
+    fn into_into_dart(self) -> crate::api::messaging::MessageEventKindC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nat::NatCompatibilityC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.peer_nat_type.into_into_dart().into_dart(),
+            self.likelihood.into_into_dart().into_dart(),
+            self.explanation.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::nat::NatCompatibilityC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nat::NatCompatibilityC>
+    for crate::api::nat::NatCompatibilityC
+{
+    fn into_into_dart(self) -> crate::api::nat::NatCompatibilityC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nat::NatDetectionC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.udp_nat_type.into_into_dart().into_dart(),
+            self.tcp_nat_type.into_into_dart().into_dart(),
+            self.udp_port_mapping.into_into_dart().into_dart(),
+            self.public_ips.into_into_dart().into_dart(),
+            self.mapped_udp_addr.into_into_dart().into_dart(),
+            self.min_port.into_into_dart().into_dart(),
+            self.max_port.into_into_dart().into_dart(),
+            self.compatibility.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::nat::NatDetectionC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nat::NatDetectionC>
+    for crate::api::nat::NatDetectionC
+{
+    fn into_into_dart(self) -> crate::api::nat::NatDetectionC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nat::NatTypeC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Unknown => 0.into_dart(),
+            Self::OpenInternet => 1.into_dart(),
+            Self::NoPat => 2.into_dart(),
+            Self::FullCone => 3.into_dart(),
+            Self::Restricted => 4.into_dart(),
+            Self::PortRestricted => 5.into_dart(),
+            Self::Symmetric => 6.into_dart(),
+            Self::SymUdpFirewall => 7.into_dart(),
+            Self::SymmetricEasyInc => 8.into_dart(),
+            Self::SymmetricEasyDec => 9.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::nat::NatTypeC {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nat::NatTypeC> for crate::api::nat::NatTypeC {
+    fn into_into_dart(self) -> crate::api::nat::NatTypeC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::p2p::NodeHopStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.peer_id.into_into_dart().into_dart(),
+            self.target_ip.into_into_dart().into_dart(),
+            self.latency_ms.into_into_dart().into_dart(),
+            self.packet_loss.into_into_dart().into_dart(),
+            self.node_name.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::p2p::NodeHopStats {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::NodeHopStats>
+    for crate::api::p2p::NodeHopStats
+{
+    fn into_into_dart(self) -> crate::api::p2p::NodeHopStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::credential::NodeKeyInfoC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.public_key.into_into_dart().into_dart(),
+            self.fingerprint.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::credential::NodeKeyInfoC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::credential::NodeKeyInfoC>
+    for crate::api::credential::NodeKeyInfoC
+{
+    fn into_into_dart(self) -> crate::api::credential::NodeKeyInfoC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nat::P2pLikelihoodC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Likely => 0.into_dart(),
+            Self::Possible => 1.into_dart(),
+            Self::Unlikely => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::nat::P2pLikelihoodC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nat::P2pLikelihoodC>
+    for crate::api::nat::P2pLikelihoodC
+{
+    fn into_into_dart(self) -> crate::api::nat::P2pLikelihoodC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_codec::PayloadCodecC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Raw => 0.into_dart(),
+            Self::Json => 1.into_dart(),
+            Self::MessagePack => 2.into_dart(),
+            Self::Cbor => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_codec::PayloadCodecC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_codec::PayloadCodecC>
+    for crate::api::app_codec::PayloadCodecC
+{
+    fn into_into_dart(self) -> crate::api::app_codec::PayloadCodecC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_policy::PeerChannelRuleC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.peer_id.into_into_dart().into_dart(),
+            self.allow.into_into_dart().into_dart(),
+            self.deny.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_policy::PeerChannelRuleC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_policy::PeerChannelRuleC>
+    for crate::api::app_policy::PeerChannelRuleC
+{
+    fn into_into_dart(self) -> crate::api::app_policy::PeerChannelRuleC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::peer_control::PeerConnectionC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.conn_id.into_into_dart().into_dart(),
+            self.tunnel_type.into_into_dart().into_dart(),
+            self.remote_addr.into_into_dart().into_dart(),
+            self.is_client.into_into_dart().into_dart(),
+            self.connector_url.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::peer_control::PeerConnectionC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::peer_control::PeerConnectionC>
+    for crate::api::peer_control::PeerConnectionC
+{
+    fn into_into_dart(self) -> crate::api::peer_control::PeerConnectionC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_metrics::PeerMetricsC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.peer_id.into_into_dart().into_dart(),
+            self.counters.into_into_dart().into_dart(),
+            self.call_latency.into_into_dart().into_dart(),
+            self.handler_latency.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_metrics::PeerMetricsC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_metrics::PeerMetricsC>
+    for crate::api::app_metrics::PeerMetricsC
+{
+    fn into_into_dart(self) -> crate::api::app_metrics::PeerMetricsC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::presence::PeerPresenceC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.node.into_into_dart().into_dart(),
+            self.profile.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::presence::PeerPresenceC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::presence::PeerPresenceC>
+    for crate::api::presence::PeerPresenceC
+{
+    fn into_into_dart(self) -> crate::api::presence::PeerPresenceC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::proxy_cidr::PeerProxyCidrsC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.peer_id.into_into_dart().into_dart(),
+            self.hostname.into_into_dart().into_dart(),
+            self.cidrs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::proxy_cidr::PeerProxyCidrsC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::proxy_cidr::PeerProxyCidrsC>
+    for crate::api::proxy_cidr::PeerProxyCidrsC
+{
+    fn into_into_dart(self) -> crate::api::proxy_cidr::PeerProxyCidrsC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_policy::PeerRejectionsC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.peer_id.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_policy::PeerRejectionsC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_policy::PeerRejectionsC>
+    for crate::api::app_policy::PeerRejectionsC
+{
+    fn into_into_dart(self) -> crate::api::app_policy::PeerRejectionsC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::credential::PinnedServerKeyC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.server_url.into_into_dart().into_dart(),
+            self.public_key.into_into_dart().into_dart(),
+            self.fingerprint.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::credential::PinnedServerKeyC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::credential::PinnedServerKeyC>
+    for crate::api::credential::PinnedServerKeyC
+{
+    fn into_into_dart(self) -> crate::api::credential::PinnedServerKeyC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::port_forward::PortForwardC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.proto.into_into_dart().into_dart(),
+            self.bind_addr.into_into_dart().into_dart(),
+            self.dst_addr.into_into_dart().into_dart(),
+            self.managed.into_into_dart().into_dart(),
+            self.active_connections.into_into_dart().into_dart(),
+            self.total_connections.into_into_dart().into_dart(),
+            self.tx_bytes.into_into_dart().into_dart(),
+            self.rx_bytes.into_into_dart().into_dart(),
+            self.errors.into_into_dart().into_dart(),
+            self.last_error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::port_forward::PortForwardC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::port_forward::PortForwardC>
+    for crate::api::port_forward::PortForwardC
+{
+    fn into_into_dart(self) -> crate::api::port_forward::PortForwardC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nat::PortMappingC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Unknown => 0.into_dart(),
+            Self::NoTranslation => 1.into_dart(),
+            Self::EndpointIndependent => 2.into_dart(),
+            Self::EndpointDependentPredictable => 3.into_dart(),
+            Self::EndpointDependent => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::nat::PortMappingC {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nat::PortMappingC>
+    for crate::api::nat::PortMappingC
+{
+    fn into_into_dart(self) -> crate::api::nat::PortMappingC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::port_mapping::PortMappingRequestC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.listener.into_into_dart().into_dart(),
+            self.external_port.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::port_mapping::PortMappingRequestC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::port_mapping::PortMappingRequestC>
+    for crate::api::port_mapping::PortMappingRequestC
+{
+    fn into_into_dart(self) -> crate::api::port_mapping::PortMappingRequestC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::port_mapping::PortMappingStateC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Active => 0.into_dart(),
+            Self::Failed => 1.into_dart(),
+            Self::Expired => 2.into_dart(),
+            Self::Removed => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::port_mapping::PortMappingStateC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::port_mapping::PortMappingStateC>
+    for crate::api::port_mapping::PortMappingStateC
+{
+    fn into_into_dart(self) -> crate::api::port_mapping::PortMappingStateC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::presence::PresenceEventC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.peer_id.into_into_dart().into_dart(),
+            self.profile.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::presence::PresenceEventC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::presence::PresenceEventC>
+    for crate::api::presence::PresenceEventC
+{
+    fn into_into_dart(self) -> crate::api::presence::PresenceEventC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::presence::PresenceEventKindC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Updated => 0.into_dart(),
+            Self::Left => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::presence::PresenceEventKindC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::presence::PresenceEventKindC>
+    for crate::api::presence::PresenceEventKindC
+{
+    fn into_into_dart(self) -> crate::api::presence::PresenceEventKindC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::presence::PresenceProfileC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.display_name.into_into_dart().into_dart(),
+            self.avatar_hash.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.app_name.into_into_dart().into_dart(),
+            self.app_version.into_into_dart().into_dart(),
+            self.current_game.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::presence::PresenceProfileC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::presence::PresenceProfileC>
+    for crate::api::presence::PresenceProfileC
+{
+    fn into_into_dart(self) -> crate::api::presence::PresenceProfileC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::presence::PresenceStatusC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Online => 0.into_dart(),
+            Self::Away => 1.into_dart(),
+            Self::Busy => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::presence::PresenceStatusC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::presence::PresenceStatusC>
+    for crate::api::presence::PresenceStatusC
+{
+    fn into_into_dart(self) -> crate::api::presence::PresenceStatusC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::proxy_cidr::ProxyCidrC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.cidr.into_into_dart().into_dart(),
+            self.mapped_cidr.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::proxy_cidr::ProxyCidrC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::proxy_cidr::ProxyCidrC>
+    for crate::api::proxy_cidr::ProxyCidrC
+{
+    fn into_into_dart(self) -> crate::api::proxy_cidr::ProxyCidrC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::proxy_cidr::ProxyCidrConflictC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.cidr.into_into_dart().into_dart(),
+            self.other_cidr.into_into_dart().into_dart(),
+            self.peer_id.into_into_dart().into_dart(),
+            self.hostname.into_into_dart().into_dart(),
+            self.interface.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::proxy_cidr::ProxyCidrConflictC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::proxy_cidr::ProxyCidrConflictC>
+    for crate::api::proxy_cidr::ProxyCidrConflictC
+{
+    fn into_into_dart(self) -> crate::api::proxy_cidr::ProxyCidrConflictC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::proxy_cidr::ProxyCidrConflictKindC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::VirtualNetwork => 0.into_dart(),
+            Self::PeerExport => 1.into_dart(),
+            Self::LocalInterface => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::proxy_cidr::ProxyCidrConflictKindC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::proxy_cidr::ProxyCidrConflictKindC>
+    for crate::api::proxy_cidr::ProxyCidrConflictKindC
+{
+    fn into_into_dart(self) -> crate::api::proxy_cidr::ProxyCidrConflictKindC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_policy::RateLimitC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.per_second.into_into_dart().into_dart(),
+            self.burst.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_policy::RateLimitC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_policy::RateLimitC>
+    for crate::api::app_policy::RateLimitC
+{
+    fn into_into_dart(self) -> crate::api::app_policy::RateLimitC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_policy::ReasonRejectionsC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.reason.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_policy::ReasonRejectionsC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_policy::ReasonRejectionsC>
+    for crate::api::app_policy::ReasonRejectionsC
+{
+    fn into_into_dart(self) -> crate::api::app_policy::ReasonRejectionsC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_policy::RejectReasonC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::PayloadTooLarge => 0.into_dart(),
+            Self::PeerRateLimited => 1.into_dart(),
+            Self::ChannelRateLimited => 2.into_dart(),
+            Self::ChannelDenied => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_policy::RejectReasonC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_policy::RejectReasonC>
+    for crate::api::app_policy::RejectReasonC
+{
+    fn into_into_dart(self) -> crate::api::app_policy::RejectReasonC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::server_selection::SelectedServerC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.role.into_into_dart().into_dart(),
+            self.latency_ms.into_into_dart().into_dart(),
+            self.connected.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::server_selection::SelectedServerC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::server_selection::SelectedServerC>
+    for crate::api::server_selection::SelectedServerC
+{
+    fn into_into_dart(self) -> crate::api::server_selection::SelectedServerC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::server_probe::ServerProbeResultC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.scheme.into_into_dart().into_dart(),
+            self.protocol_supported.into_into_dart().into_dart(),
+            self.resolved_addr.into_into_dart().into_dart(),
+            self.dns_ms.into_into_dart().into_dart(),
+            self.reachable.into_into_dart().into_dart(),
+            self.latency_ms.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::server_probe::ServerProbeResultC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::server_probe::ServerProbeResultC>
+    for crate::api::server_probe::ServerProbeResultC
+{
+    fn into_into_dart(self) -> crate::api::server_probe::ServerProbeResultC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::server_selection::ServerRoleC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Active => 0.into_dart(),
+            Self::Standby => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::server_selection::ServerRoleC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::server_selection::ServerRoleC>
+    for crate::api::server_selection::ServerRoleC
+{
+    fn into_into_dart(self) -> crate::api::server_selection::ServerRoleC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::server_selection::ServerSelectionStatusC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.enabled.into_into_dart().into_dart(),
+            self.active_count.into_into_dart().into_dart(),
+            self.servers.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::server_selection::ServerSelectionStatusC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::server_selection::ServerSelectionStatusC>
+    for crate::api::server_selection::ServerSelectionStatusC
+{
+    fn into_into_dart(self) -> crate::api::server_selection::ServerSelectionStatusC {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::firewall::TunFirewallStatusC {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.backend.into_into_dart().into_dart(),
+            self.active.into_into_dart().into_dart(),
+            self.tun_allowed.into_into_dart().into_dart(),
+            self.ports.into_into_dart().into_dart(),
+            self.detail.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::firewall::TunFirewallStatusC
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::firewall::TunFirewallStatusC>
+    for crate::api::firewall::TunFirewallStatusC
+{
+    fn into_into_dart(self) -> crate::api::firewall::TunFirewallStatusC {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode for EventBusSubscriber {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode for JoinHandle<()> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner< JoinHandle < () >>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode for JoinHandle<Result<String, String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
+                JoinHandle<Result<String, String>>,
+            >,
+        >>::sse_encode(
+            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for PeerRoutePair {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<()>>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<String, String>>>,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::app_call_control::AppCallControlC,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::api::p2p::AppInboundEventC, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::app_stream::AppStreamProgressC,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::credential::CredentialEventC,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::file_transfer::FileOfferC,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::file_transfer::FileTransferProgressC,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::app_stream::InboundAppStreamC,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for StreamSink<Vec<u8>, flutter_rust_bridge::for_generated::SseCodec> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::port_mapping::ListenerMappingC,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::messaging::MessageEventC,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::presence::PresenceEventC,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::app_call_control::AppCallControlC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::app_call_control::AppCallControlKindC>::sse_encode(self.kind, serializer);
+        <u32>::sse_encode(self.from_peer_id, serializer);
+        <u64>::sse_encode(self.request_id, serializer);
+        <u64>::sse_encode(self.token, serializer);
+        <u64>::sse_encode(self.deadline_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::app_call_control::AppCallControlKindC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::app_call_control::AppCallControlKindC::Deadline => 0,
+                crate::api::app_call_control::AppCallControlKindC::Cancelled => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::app_codec::AppCallDecodedC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.status, serializer);
+        <String>::sse_encode(self.error_msg, serializer);
+        <crate::api::app_codec::PayloadCodecC>::sse_encode(self.codec, serializer);
+        <Vec<u8>>::sse_encode(self.payload, serializer);
+    }
+}
+
+impl SseEncode for crate::api::p2p::AppCallResultC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.status, serializer);
+        <String>::sse_encode(self.error_msg, serializer);
+        <Vec<u8>>::sse_encode(self.payload, serializer);
+    }
+}
+
+impl SseEncode for crate::api::p2p::AppInboundEventC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::p2p::AppInboundKindC>::sse_encode(self.kind, serializer);
+        <u32>::sse_encode(self.from_peer_id, serializer);
+        <String>::sse_encode(self.channel, serializer);
+        <u64>::sse_encode(self.request_id, serializer);
+        <u64>::sse_encode(self.token, serializer);
+        <Vec<u8>>::sse_encode(self.payload, serializer);
+    }
+}
+
+impl SseEncode for crate::api::p2p::AppInboundKindC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::p2p::AppInboundKindC::Call => 0,
+                crate::api::p2p::AppInboundKindC::Notify => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::app_metrics::AppRpcCountersC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.calls_sent, serializer);
+        <u64>::sse_encode(self.calls_received, serializer);
+        <u64>::sse_encode(self.notifies_sent, serializer);
+        <u64>::sse_encode(self.notifies_received, serializer);
+        <u64>::sse_encode(self.replies_sent, serializer);
+        <u64>::sse_encode(self.replies_received, serializer);
+        <u64>::sse_encode(self.timeouts, serializer);
+        <u64>::sse_encode(self.no_subscriber, serializer);
+        <u64>::sse_encode(self.service_dropped, serializer);
+        <u64>::sse_encode(self.cancelled, serializer);
+        <u64>::sse_encode(self.send_errors, serializer);
+    }
+}
+
+impl SseEncode for crate::api::app_metrics::AppRpcMetricsC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.lagged_events, serializer);
+        <Vec<crate::api::app_metrics::ChannelMetricsC>>::sse_encode(self.channels, serializer);
+        <Vec<crate::api::app_metrics::PeerMetricsC>>::sse_encode(self.peers, serializer);
+    }
+}
+
+impl SseEncode for crate::api::app_policy::AppRpcPolicyC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.max_payload_bytes, serializer);
+        <crate::api::app_policy::RateLimitC>::sse_encode(self.peer_rate, serializer);
+        <crate::api::app_policy::RateLimitC>::sse_encode(self.channel_rate, serializer);
+        <Vec<crate::api::app_policy::PeerChannelRuleC>>::sse_encode(self.channel_rules, serializer);
+    }
+}
+
+impl SseEncode for crate::api::app_policy::AppRpcRejectionsC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.total, serializer);
+        <Vec<crate::api::app_policy::ReasonRejectionsC>>::sse_encode(self.by_reason, serializer);
+        <Vec<crate::api::app_policy::PeerRejectionsC>>::sse_encode(self.by_peer, serializer);
+        <Vec<crate::api::app_policy::ChannelRejectionsC>>::sse_encode(self.by_channel, serializer);
+    }
+}
+
+impl SseEncode for crate::api::app_stream::AppStreamProgressC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::app_stream::AppStreamStateC>::sse_encode(self.state, serializer);
+        <u64>::sse_encode(self.bytes, serializer);
+        <u64>::sse_encode(self.total_len, serializer);
+        <String>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::app_stream::AppStreamStateC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::app_stream::AppStreamStateC::Open => 0,
+                crate::api::app_stream::AppStreamStateC::Finished => 1,
+                crate::api::app_stream::AppStreamStateC::Cancelled => 2,
+                crate::api::app_stream::AppStreamStateC::Failed => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::app_broadcast::BroadcastDeliveryC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.peer_id, serializer);
+        <bool>::sse_encode(self.delivered, serializer);
+        <String>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::app_broadcast::BroadcastScopeC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::app_broadcast::BroadcastScopeKindC>::sse_encode(self.kind, serializer);
+        <Vec<u32>>::sse_encode(self.peer_ids, serializer);
+    }
+}
+
+impl SseEncode for crate::api::app_broadcast::BroadcastScopeKindC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::app_broadcast::BroadcastScopeKindC::All => 0,
+                crate::api::app_broadcast::BroadcastScopeKindC::DirectOnly => 1,
+                crate::api::app_broadcast::BroadcastScopeKindC::Peers => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::app_metrics::ChannelMetricsC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.channel, serializer);
+        <crate::api::app_metrics::AppRpcCountersC>::sse_encode(self.counters, serializer);
+        <crate::api::app_metrics::LatencyHistogramC>::sse_encode(self.call_latency, serializer);
+        <crate::api::app_metrics::LatencyHistogramC>::sse_encode(self.handler_latency, serializer);
+    }
+}
+
+impl SseEncode for crate::api::app_policy::ChannelRejectionsC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.channel, serializer);
+        <u64>::sse_encode(self.count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::messaging::ChatMessageC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <crate::api::messaging::ChatMessageKindC>::sse_encode(self.kind, serializer);
+        <u32>::sse_encode(self.from_peer_id, serializer);
+        <u32>::sse_encode(self.to_peer_id, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <u64>::sse_encode(self.sent_at_ms, serializer);
+        <bool>::sse_encode(self.outgoing, serializer);
+        <Vec<u32>>::sse_encode(self.delivered_to, serializer);
+        <Vec<u32>>::sse_encode(self.read_by, serializer);
+        <Vec<u32>>::sse_encode(self.queued_for, serializer);
+    }
+}
+
+impl SseEncode for crate::api::messaging::ChatMessageKindC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::messaging::ChatMessageKindC::Direct => 0,
+                crate::api::messaging::ChatMessageKindC::Room => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::diagnostics::ConnectErrorC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.dst, serializer);
+        <String>::sse_encode(self.ip_version, serializer);
+        <String>::sse_encode(self.error, serializer);
+        <u64>::sse_encode(self.timestamp_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::credential::CredentialEventC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::credential::CredentialEventKindC>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.server_url, serializer);
+        <String>::sse_encode(self.public_key, serializer);
+        <String>::sse_encode(self.fingerprint, serializer);
+    }
+}
+
+impl SseEncode for crate::api::credential::CredentialEventKindC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::credential::CredentialEventKindC::LocalKeyChanged => 0,
+                crate::api::credential::CredentialEventKindC::PinnedKeyChanged => 1,
+                crate::api::credential::CredentialEventKindC::InstanceCredentialChanged => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::app_codec::DecodedPayloadC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::app_codec::PayloadCodecC>::sse_encode(self.codec, serializer);
+        <Vec<u8>>::sse_encode(self.payload, serializer);
+        <bool>::sse_encode(self.tagged, serializer);
+        <Vec<crate::api::app_codec::PayloadCodecC>>::sse_encode(self.peer_accepts, serializer);
+    }
+}
+
+impl SseEncode for crate::api::diagnostics::DiagnosticCheckC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::diagnostics::DiagnosticCheckKindC>::sse_encode(self.kind, serializer);
+        <crate::api::diagnostics::DiagnosticStatusC>::sse_encode(self.status, serializer);
+        <String>::sse_encode(self.target, serializer);
+        <String>::sse_encode(self.detail, serializer);
+        <String>::sse_encode(self.suggestion, serializer);
+    }
+}
+
+impl SseEncode for crate::api::diagnostics::DiagnosticCheckKindC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::diagnostics::DiagnosticCheckKindC::ServerResolve => 0,
+                crate::api::diagnostics::DiagnosticCheckKindC::ServerReachability => 1,
+                crate::api::diagnostics::DiagnosticCheckKindC::Listener => 2,
+                crate::api::diagnostics::DiagnosticCheckKindC::TunDevice => 3,
+                crate::api::diagnostics::DiagnosticCheckKindC::NatType => 4,
+                crate::api::diagnostics::DiagnosticCheckKindC::HolePunching => 5,
+                crate::api::diagnostics::DiagnosticCheckKindC::Firewall => 6,
+                crate::api::diagnostics::DiagnosticCheckKindC::Mtu => 7,
+                crate::api::diagnostics::DiagnosticCheckKindC::ConnectErrors => 8,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::diagnostics::DiagnosticReportC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::diagnostics::DiagnosticStatusC>::sse_encode(self.overall, serializer);
+        <Vec<crate::api::diagnostics::DiagnosticCheckC>>::sse_encode(self.checks, serializer);
+        <Vec<crate::api::diagnostics::ConnectErrorC>>::sse_encode(
+            self.recent_connect_errors,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::diagnostics::DiagnosticStatusC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::diagnostics::DiagnosticStatusC::Pass => 0,
+                crate::api::diagnostics::DiagnosticStatusC::Warn => 1,
+                crate::api::diagnostics::DiagnosticStatusC::Fail => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::endpoints::EndpointOutcomeC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::endpoints::EndpointOutcomeC::Added => 0,
+                crate::api::endpoints::EndpointOutcomeC::AlreadyPresent => 1,
+                crate::api::endpoints::EndpointOutcomeC::Removed => 2,
+                crate::api::endpoints::EndpointOutcomeC::NotFound => 3,
+                crate::api::endpoints::EndpointOutcomeC::Failed => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
 }
 
-impl SseDecode for EventBusSubscriber {
+impl SseEncode for crate::api::endpoints::EndpointResultC {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <crate::api::endpoints::EndpointOutcomeC>::sse_encode(self.outcome, serializer);
+        <String>::sse_encode(self.error, serializer);
     }
 }
 
-impl SseDecode for JoinHandle<()> {
+impl SseEncode for f32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<()>>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for JoinHandle<Result<String, String>> {
+impl SseEncode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-                JoinHandle<Result<String, String>>,
-            >,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for PeerRoutePair {
+impl SseEncode for crate::api::file_transfer::FileOfferC {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.from_peer_id, serializer);
+        <String>::sse_encode(self.transfer_id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <u64>::sse_encode(self.size, serializer);
+        <String>::sse_encode(self.sha256, serializer);
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>>
-{
+impl SseEncode for crate::api::file_transfer::FileTransferDirectionC {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::file_transfer::FileTransferDirectionC::Sending => 0,
+                crate::api::file_transfer::FileTransferDirectionC::Receiving => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<()>>>
-{
+impl SseEncode for crate::api::file_transfer::FileTransferProgressC {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.transfer_id, serializer);
+        <u32>::sse_encode(self.peer_id, serializer);
+        <crate::api::file_transfer::FileTransferDirectionC>::sse_encode(self.direction, serializer);
+        <crate::api::file_transfer::FileTransferStateC>::sse_encode(self.state, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <u64>::sse_encode(self.bytes, serializer);
+        <u64>::sse_encode(self.size, serializer);
+        <String>::sse_encode(self.error, serializer);
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<
-        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<String, String>>>,
-    >
-{
+impl SseEncode for crate::api::file_transfer::FileTransferStateC {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::file_transfer::FileTransferStateC::Offered => 0,
+                crate::api::file_transfer::FileTransferStateC::Transferring => 1,
+                crate::api::file_transfer::FileTransferStateC::Completed => 2,
+                crate::api::file_transfer::FileTransferStateC::Rejected => 3,
+                crate::api::file_transfer::FileTransferStateC::Cancelled => 4,
+                crate::api::file_transfer::FileTransferStateC::Failed => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>>
-{
+impl SseEncode for crate::api::firewall::FirewallBackendC {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::firewall::FirewallBackendC::None => 0,
+                crate::api::firewall::FirewallBackendC::Firewalld => 1,
+                crate::api::firewall::FirewallBackendC::Ufw => 2,
+                crate::api::firewall::FirewallBackendC::Nftables => 3,
+                crate::api::firewall::FirewallBackendC::Iptables => 4,
+                crate::api::firewall::FirewallBackendC::WindowsFirewall => 5,
+                crate::api::firewall::FirewallBackendC::Unknown => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode
-    for StreamSink<crate::api::p2p::AppInboundEventC, flutter_rust_bridge::for_generated::SseCodec>
-{
+impl SseEncode for crate::api::firewall::FirewallDiagnosisC {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::firewall::FirewallBackendC>::sse_encode(self.backend, serializer);
+        <bool>::sse_encode(self.active, serializer);
+        <Vec<crate::api::firewall::FirewallFindingC>>::sse_encode(self.findings, serializer);
     }
 }
 
-impl SseDecode for String {
+impl SseEncode for crate::api::firewall::FirewallError {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::firewall::FirewallErrorKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.message, serializer);
     }
 }
 
-impl SseDecode for crate::api::p2p::AppCallResultC {
+impl SseEncode for crate::api::firewall::FirewallErrorKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_status = <i32>::sse_decode(deserializer);
-        let mut var_errorMsg = <String>::sse_decode(deserializer);
-        let mut var_payload = <Vec<u8>>::sse_decode(deserializer);
-        return crate::api::p2p::AppCallResultC {
-            status: var_status,
-            error_msg: var_errorMsg,
-            payload: var_payload,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::firewall::FirewallErrorKind::InvalidProfile => 0,
+                crate::api::firewall::FirewallErrorKind::Unsupported => 1,
+                crate::api::firewall::FirewallErrorKind::Instance => 2,
+                crate::api::firewall::FirewallErrorKind::Backend => 3,
+                crate::api::firewall::FirewallErrorKind::NeedsPrivileges => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for crate::api::p2p::AppInboundEventC {
+impl SseEncode for crate::api::firewall::FirewallFindingC {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_kind = <crate::api::p2p::AppInboundKindC>::sse_decode(deserializer);
-        let mut var_fromPeerId = <u32>::sse_decode(deserializer);
-        let mut var_channel = <String>::sse_decode(deserializer);
-        let mut var_requestId = <u64>::sse_decode(deserializer);
-        let mut var_token = <u64>::sse_decode(deserializer);
-        let mut var_payload = <Vec<u8>>::sse_decode(deserializer);
-        return crate::api::p2p::AppInboundEventC {
-            kind: var_kind,
-            from_peer_id: var_fromPeerId,
-            channel: var_channel,
-            request_id: var_requestId,
-            token: var_token,
-            payload: var_payload,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::firewall::FirewallRuleC>::sse_encode(self.rule, serializer);
+        <bool>::sse_encode(self.blocked, serializer);
+        <String>::sse_encode(self.blocking_rule, serializer);
+        <String>::sse_encode(self.suggestion, serializer);
     }
 }
 
-impl SseDecode for crate::api::p2p::AppInboundKindC {
+impl SseEncode for crate::api::firewall::FirewallPortC {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::p2p::AppInboundKindC::Call,
-            1 => crate::api::p2p::AppInboundKindC::Notify,
-            _ => unreachable!("Invalid variant for AppInboundKindC: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.proto, serializer);
+        <u16>::sse_encode(self.port, serializer);
+    }
+}
+
+impl SseEncode for crate::api::firewall::FirewallPortStatusC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.proto, serializer);
+        <u16>::sse_encode(self.port, serializer);
+        <bool>::sse_encode(self.allowed, serializer);
+    }
+}
+
+impl SseEncode for crate::api::firewall::FirewallProfile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::firewall::FirewallProfile::Domain => 0,
+                crate::api::firewall::FirewallProfile::Private => 1,
+                crate::api::firewall::FirewallProfile::Public => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::firewall::FirewallProfileStatusC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::firewall::FirewallProfile>::sse_encode(self.profile, serializer);
+        <bool>::sse_encode(self.enabled, serializer);
+        <bool>::sse_encode(self.current, serializer);
+    }
+}
+
+impl SseEncode for crate::api::firewall::FirewallRuleC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::firewall::FirewallRuleKindC>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.proto, serializer);
+        <u16>::sse_encode(self.port, serializer);
+        <String>::sse_encode(self.detail, serializer);
+    }
+}
+
+impl SseEncode for crate::api::firewall::FirewallRuleKindC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::firewall::FirewallRuleKindC::Overlay => 0,
+                crate::api::firewall::FirewallRuleKindC::ListenerPort => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::firewall::FirewallSnapshotC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::firewall::FirewallBackendC>::sse_encode(self.backend, serializer);
+        <Vec<crate::api::firewall::FirewallProfileStatusC>>::sse_encode(self.profiles, serializer);
+        <Vec<crate::api::firewall::FirewallProfile>>::sse_encode(self.current_profiles, serializer);
+    }
+}
+
+impl SseEncode for crate::api::p2p::FlagsC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.default_protocol, serializer);
+        <String>::sse_encode(self.dev_name, serializer);
+        <bool>::sse_encode(self.enable_encryption, serializer);
+        <bool>::sse_encode(self.enable_ipv6, serializer);
+        <u32>::sse_encode(self.mtu, serializer);
+        <bool>::sse_encode(self.latency_first, serializer);
+        <bool>::sse_encode(self.enable_exit_node, serializer);
+        <bool>::sse_encode(self.no_tun, serializer);
+        <bool>::sse_encode(self.use_smoltcp, serializer);
+        <String>::sse_encode(self.relay_network_whitelist, serializer);
+        <bool>::sse_encode(self.disable_p2p, serializer);
+        <bool>::sse_encode(self.relay_all_peer_rpc, serializer);
+        <bool>::sse_encode(self.disable_udp_hole_punching, serializer);
+        <bool>::sse_encode(self.disable_tcp_hole_punching, serializer);
+        <bool>::sse_encode(self.multi_thread, serializer);
+        <i32>::sse_encode(self.data_compress_algo, serializer);
+        <bool>::sse_encode(self.bind_device, serializer);
+        <bool>::sse_encode(self.enable_kcp_proxy, serializer);
+        <bool>::sse_encode(self.disable_kcp_input, serializer);
+        <bool>::sse_encode(self.disable_relay_kcp, serializer);
+        <bool>::sse_encode(self.proxy_forward_by_system, serializer);
+        <bool>::sse_encode(self.accept_dns, serializer);
+        <bool>::sse_encode(self.private_mode, serializer);
+        <bool>::sse_encode(self.enable_quic_proxy, serializer);
+        <bool>::sse_encode(self.disable_quic_input, serializer);
+        <bool>::sse_encode(self.disable_sym_hole_punching, serializer);
+        <String>::sse_encode(self.tcp_whitelist, serializer);
+        <String>::sse_encode(self.udp_whitelist, serializer);
+        <bool>::sse_encode(self.secure_mode, serializer);
+    }
+}
+
+impl SseEncode for crate::api::p2p::Forward {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.bind_addr, serializer);
+        <String>::sse_encode(self.dst_addr, serializer);
+        <String>::sse_encode(self.proto, serializer);
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for i64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for f32 {
+impl SseEncode for crate::api::app_stream::InboundAppStreamC {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.from_peer_id, serializer);
+        <u64>::sse_encode(self.stream_id, serializer);
+        <String>::sse_encode(self.channel, serializer);
+        <u64>::sse_encode(self.total_len, serializer);
+        <Vec<u8>>::sse_encode(self.metadata, serializer);
     }
 }
 
-impl SseDecode for f64 {
+impl SseEncode for crate::api::p2p::KVNetworkStatus {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.total_nodes, serializer);
+        <Vec<crate::api::p2p::KVNodeInfo>>::sse_encode(self.nodes, serializer);
     }
 }
 
-impl SseDecode for crate::api::p2p::FlagsC {
+impl SseEncode for crate::api::p2p::KVNodeConnectionStats {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_defaultProtocol = <String>::sse_decode(deserializer);
-        let mut var_devName = <String>::sse_decode(deserializer);
-        let mut var_enableEncryption = <bool>::sse_decode(deserializer);
-        let mut var_enableIpv6 = <bool>::sse_decode(deserializer);
-        let mut var_mtu = <u32>::sse_decode(deserializer);
-        let mut var_latencyFirst = <bool>::sse_decode(deserializer);
-        let mut var_enableExitNode = <bool>::sse_decode(deserializer);
-        let mut var_noTun = <bool>::sse_decode(deserializer);
-        let mut var_useSmoltcp = <bool>::sse_decode(deserializer);
-        let mut var_relayNetworkWhitelist = <String>::sse_decode(deserializer);
-        let mut var_disableP2P = <bool>::sse_decode(deserializer);
-        let mut var_relayAllPeerRpc = <bool>::sse_decode(deserializer);
-        let mut var_disableUdpHolePunching = <bool>::sse_decode(deserializer);
-        let mut var_disableTcpHolePunching = <bool>::sse_decode(deserializer);
-        let mut var_multiThread = <bool>::sse_decode(deserializer);
-        let mut var_dataCompressAlgo = <i32>::sse_decode(deserializer);
-        let mut var_bindDevice = <bool>::sse_decode(deserializer);
-        let mut var_enableKcpProxy = <bool>::sse_decode(deserializer);
-        let mut var_disableKcpInput = <bool>::sse_decode(deserializer);
-        let mut var_disableRelayKcp = <bool>::sse_decode(deserializer);
-        let mut var_proxyForwardBySystem = <bool>::sse_decode(deserializer);
-        let mut var_acceptDns = <bool>::sse_decode(deserializer);
-        let mut var_privateMode = <bool>::sse_decode(deserializer);
-        let mut var_enableQuicProxy = <bool>::sse_decode(deserializer);
-        let mut var_disableQuicInput = <bool>::sse_decode(deserializer);
-        let mut var_disableSymHolePunching = <bool>::sse_decode(deserializer);
-        let mut var_tcpWhitelist = <String>::sse_decode(deserializer);
-        let mut var_udpWhitelist = <String>::sse_decode(deserializer);
-        return crate::api::p2p::FlagsC {
-            default_protocol: var_defaultProtocol,
-            dev_name: var_devName,
-            enable_encryption: var_enableEncryption,
-            enable_ipv6: var_enableIpv6,
-            mtu: var_mtu,
-            latency_first: var_latencyFirst,
-            enable_exit_node: var_enableExitNode,
-            no_tun: var_noTun,
-            use_smoltcp: var_useSmoltcp,
-            relay_network_whitelist: var_relayNetworkWhitelist,
-            disable_p2p: var_disableP2P,
-            relay_all_peer_rpc: var_relayAllPeerRpc,
-            disable_udp_hole_punching: var_disableUdpHolePunching,
-            disable_tcp_hole_punching: var_disableTcpHolePunching,
-            multi_thread: var_multiThread,
-            data_compress_algo: var_dataCompressAlgo,
-            bind_device: var_bindDevice,
-            enable_kcp_proxy: var_enableKcpProxy,
-            disable_kcp_input: var_disableKcpInput,
-            disable_relay_kcp: var_disableRelayKcp,
-            proxy_forward_by_system: var_proxyForwardBySystem,
-            accept_dns: var_acceptDns,
-            private_mode: var_privateMode,
-            enable_quic_proxy: var_enableQuicProxy,
-            disable_quic_input: var_disableQuicInput,
-            disable_sym_hole_punching: var_disableSymHolePunching,
-            tcp_whitelist: var_tcpWhitelist,
-            udp_whitelist: var_udpWhitelist,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.conn_type, serializer);
+        <u64>::sse_encode(self.rx_bytes, serializer);
+        <u64>::sse_encode(self.tx_bytes, serializer);
+        <u64>::sse_encode(self.rx_packets, serializer);
+        <u64>::sse_encode(self.tx_packets, serializer);
     }
 }
 
-impl SseDecode for crate::api::p2p::Forward {
+impl SseEncode for crate::api::p2p::KVNodeInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_bindAddr = <String>::sse_decode(deserializer);
-        let mut var_dstAddr = <String>::sse_decode(deserializer);
-        let mut var_proto = <String>::sse_decode(deserializer);
-        return crate::api::p2p::Forward {
-            bind_addr: var_bindAddr,
-            dst_addr: var_dstAddr,
-            proto: var_proto,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.peer_id, serializer);
+        <String>::sse_encode(self.hostname, serializer);
+        <String>::sse_encode(self.ipv4, serializer);
+        <String>::sse_encode(self.ipv6, serializer);
+        <f64>::sse_encode(self.latency_ms, serializer);
+        <String>::sse_encode(self.nat, serializer);
+        <Vec<crate::api::p2p::NodeHopStats>>::sse_encode(self.hops, serializer);
+        <f32>::sse_encode(self.loss_rate, serializer);
+        <Vec<crate::api::p2p::KVNodeConnectionStats>>::sse_encode(self.connections, serializer);
+        <String>::sse_encode(self.tunnel_proto, serializer);
+        <String>::sse_encode(self.conn_type, serializer);
+        <u64>::sse_encode(self.rx_bytes, serializer);
+        <u64>::sse_encode(self.tx_bytes, serializer);
+        <String>::sse_encode(self.version, serializer);
+        <i32>::sse_encode(self.cost, serializer);
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for crate::api::app_metrics::LatencyHistogramC {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<f64>>::sse_encode(self.bounds_ms, serializer);
+        <Vec<u64>>::sse_encode(self.counts, serializer);
+        <u64>::sse_encode(self.count, serializer);
+        <f64>::sse_encode(self.sum_ms, serializer);
     }
 }
 
-impl SseDecode for i64 {
+impl SseEncode for Vec<PeerRoutePair> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <PeerRoutePair>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::p2p::KVNetworkStatus {
+impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_totalNodes = <usize>::sse_decode(deserializer);
-        let mut var_nodes = <Vec<crate::api::p2p::KVNodeInfo>>::sse_decode(deserializer);
-        return crate::api::p2p::KVNetworkStatus {
-            total_nodes: var_totalNodes,
-            nodes: var_nodes,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::p2p::KVNodeConnectionStats {
+impl SseEncode for Vec<crate::api::app_broadcast::BroadcastDeliveryC> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_connType = <String>::sse_decode(deserializer);
-        let mut var_rxBytes = <u64>::sse_decode(deserializer);
-        let mut var_txBytes = <u64>::sse_decode(deserializer);
-        let mut var_rxPackets = <u64>::sse_decode(deserializer);
-        let mut var_txPackets = <u64>::sse_decode(deserializer);
-        return crate::api::p2p::KVNodeConnectionStats {
-            conn_type: var_connType,
-            rx_bytes: var_rxBytes,
-            tx_bytes: var_txBytes,
-            rx_packets: var_rxPackets,
-            tx_packets: var_txPackets,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::app_broadcast::BroadcastDeliveryC>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::p2p::KVNodeInfo {
+impl SseEncode for Vec<crate::api::app_metrics::ChannelMetricsC> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_peerId = <u32>::sse_decode(deserializer);
-        let mut var_hostname = <String>::sse_decode(deserializer);
-        let mut var_ipv4 = <String>::sse_decode(deserializer);
-        let mut var_ipv6 = <String>::sse_decode(deserializer);
-        let mut var_latencyMs = <f64>::sse_decode(deserializer);
-        let mut var_nat = <String>::sse_decode(deserializer);
-        let mut var_hops = <Vec<crate::api::p2p::NodeHopStats>>::sse_decode(deserializer);
-        let mut var_lossRate = <f32>::sse_decode(deserializer);
-        let mut var_connections =
-            <Vec<crate::api::p2p::KVNodeConnectionStats>>::sse_decode(deserializer);
-        let mut var_tunnelProto = <String>::sse_decode(deserializer);
-        let mut var_connType = <String>::sse_decode(deserializer);
-        let mut var_rxBytes = <u64>::sse_decode(deserializer);
-        let mut var_txBytes = <u64>::sse_decode(deserializer);
-        let mut var_version = <String>::sse_decode(deserializer);
-        let mut var_cost = <i32>::sse_decode(deserializer);
-        return crate::api::p2p::KVNodeInfo {
-            peer_id: var_peerId,
-            hostname: var_hostname,
-            ipv4: var_ipv4,
-            ipv6: var_ipv6,
-            latency_ms: var_latencyMs,
-            nat: var_nat,
-            hops: var_hops,
-            loss_rate: var_lossRate,
-            connections: var_connections,
-            tunnel_proto: var_tunnelProto,
-            conn_type: var_connType,
-            rx_bytes: var_rxBytes,
-            tx_bytes: var_txBytes,
-            version: var_version,
-            cost: var_cost,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::app_metrics::ChannelMetricsC>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for Vec<PeerRoutePair> {
+impl SseEncode for Vec<crate::api::app_policy::ChannelRejectionsC> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<PeerRoutePair>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::app_policy::ChannelRejectionsC>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseEncode for Vec<crate::api::messaging::ChatMessageC> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::messaging::ChatMessageC>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::p2p::Forward> {
+impl SseEncode for Vec<crate::api::diagnostics::ConnectErrorC> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::p2p::Forward>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::diagnostics::ConnectErrorC>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::p2p::KVNodeConnectionStats> {
+impl SseEncode for Vec<crate::api::diagnostics::DiagnosticCheckC> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::p2p::KVNodeConnectionStats>::sse_decode(
-                deserializer,
-            ));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::diagnostics::DiagnosticCheckC>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::p2p::KVNodeInfo> {
+impl SseEncode for Vec<crate::api::file_transfer::FileOfferC> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::p2p::KVNodeInfo>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::file_transfer::FileOfferC>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::firewall::FirewallFindingC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::firewall::FirewallFindingC>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::p2p::NodeHopStats> {
+impl SseEncode for Vec<crate::api::firewall::FirewallPortC> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::p2p::NodeHopStats>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::firewall::FirewallPortC>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for Vec<crate::api::firewall::FirewallPortStatusC> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::firewall::FirewallPortStatusC>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for crate::api::p2p::NodeHopStats {
+impl SseEncode for Vec<crate::api::firewall::FirewallProfile> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_peerId = <u32>::sse_decode(deserializer);
-        let mut var_targetIp = <String>::sse_decode(deserializer);
-        let mut var_latencyMs = <f64>::sse_decode(deserializer);
-        let mut var_packetLoss = <f32>::sse_decode(deserializer);
-        let mut var_nodeName = <String>::sse_decode(deserializer);
-        return crate::api::p2p::NodeHopStats {
-            peer_id: var_peerId,
-            target_ip: var_targetIp,
-            latency_ms: var_latencyMs,
-            packet_loss: var_packetLoss,
-            node_name: var_nodeName,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::firewall::FirewallProfile>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode for Vec<crate::api::firewall::FirewallProfileStatusC> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::firewall::FirewallProfileStatusC>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode for Vec<crate::api::firewall::FirewallRuleC> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::firewall::FirewallRuleC>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for Vec<crate::api::p2p::Forward> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::p2p::Forward>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for Vec<crate::api::p2p::KVNodeConnectionStats> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::p2p::KVNodeConnectionStats>::sse_encode(item, serializer);
+        }
+    }
 }
 
-impl SseDecode for usize {
+impl SseEncode for Vec<crate::api::p2p::KVNodeInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::p2p::KVNodeInfo>::sse_encode(item, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__p2p__app_call_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__p2p__app_call_reply_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__p2p__app_notify_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__p2p__close_server_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__p2p__create_server_impl(port, ptr, rust_vec_len, data_len),
-        6 => {
-            wire__crate__api__p2p__create_server_with_flags_impl(port, ptr, rust_vec_len, data_len)
-        }
-        7 => wire__crate__api__p2p__easytier_version_impl(port, ptr, rust_vec_len, data_len),
-        8 => {
-            wire__crate__api__firewall__get_firewall_status_impl(port, ptr, rust_vec_len, data_len)
-        }
-        9 => wire__crate__api__p2p__get_ips_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__p2p__get_network_status_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__p2p__get_peer_route_pairs_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__p2p__get_running_info_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__p2p__handle_event_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__p2p__init_app_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__p2p__is_easytier_running_impl(port, ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__p2p__join_handle_result_impl(port, ptr, rust_vec_len, data_len),
-        19 => wire__crate__api__p2p__my_peer_id_impl(port, ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__p2p__peer_ping_impl(port, ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__p2p__pending_app_call_count_impl(port, ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__p2p__send_udp_to_localhost_impl(port, ptr, rust_vec_len, data_len),
-        23 => {
-            wire__crate__api__firewall__set_firewall_status_impl(port, ptr, rust_vec_len, data_len)
+impl SseEncode for Vec<crate::api::port_mapping::ListenerMappingC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::port_mapping::ListenerMappingC>::sse_encode(item, serializer);
         }
-        24 => wire__crate__api__p2p__set_tun_fd_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__p2p__subscribe_app_inbound_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        13 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for Vec<crate::api::nat::NatCompatibilityC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::nat::NatCompatibilityC>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<EventBusSubscriber> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for Vec<crate::api::p2p::NodeHopStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::p2p::NodeHopStats>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<EventBusSubscriber>
-{
-}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<EventBusSubscriber>> for EventBusSubscriber {
-    fn into_into_dart(self) -> FrbWrapper<EventBusSubscriber> {
-        self.into()
+impl SseEncode for Vec<crate::api::app_codec::PayloadCodecC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::app_codec::PayloadCodecC>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<JoinHandle<()>> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for Vec<crate::api::app_policy::PeerChannelRuleC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::app_policy::PeerChannelRuleC>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<JoinHandle<()>> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<JoinHandle<()>>> for JoinHandle<()> {
-    fn into_into_dart(self) -> FrbWrapper<JoinHandle<()>> {
-        self.into()
+impl SseEncode for Vec<crate::api::peer_control::PeerConnectionC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::peer_control::PeerConnectionC>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<JoinHandle<Result<String, String>>> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for Vec<crate::api::app_metrics::PeerMetricsC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::app_metrics::PeerMetricsC>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<JoinHandle<Result<String, String>>>
-{
-}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<JoinHandle<Result<String, String>>>>
-    for JoinHandle<Result<String, String>>
-{
-    fn into_into_dart(self) -> FrbWrapper<JoinHandle<Result<String, String>>> {
-        self.into()
+impl SseEncode for Vec<crate::api::presence::PeerPresenceC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::presence::PeerPresenceC>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<PeerRoutePair> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for Vec<crate::api::proxy_cidr::PeerProxyCidrsC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::proxy_cidr::PeerProxyCidrsC>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<PeerRoutePair> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<PeerRoutePair>> for PeerRoutePair {
-    fn into_into_dart(self) -> FrbWrapper<PeerRoutePair> {
-        self.into()
+impl SseEncode for Vec<crate::api::app_policy::PeerRejectionsC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::app_policy::PeerRejectionsC>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::p2p::AppCallResultC {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.status.into_into_dart().into_dart(),
-            self.error_msg.into_into_dart().into_dart(),
-            self.payload.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for Vec<crate::api::credential::PinnedServerKeyC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::credential::PinnedServerKeyC>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::p2p::AppCallResultC
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::AppCallResultC>
-    for crate::api::p2p::AppCallResultC
-{
-    fn into_into_dart(self) -> crate::api::p2p::AppCallResultC {
-        self
+
+impl SseEncode for Vec<crate::api::port_forward::PortForwardC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::port_forward::PortForwardC>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::p2p::AppInboundEventC {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.kind.into_into_dart().into_dart(),
-            self.from_peer_id.into_into_dart().into_dart(),
-            self.channel.into_into_dart().into_dart(),
-            self.request_id.into_into_dart().into_dart(),
-            self.token.into_into_dart().into_dart(),
-            self.payload.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::port_mapping::PortMappingRequestC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::port_mapping::PortMappingRequestC>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::p2p::AppInboundEventC
-{
+
+impl SseEncode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f64>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::AppInboundEventC>
-    for crate::api::p2p::AppInboundEventC
-{
-    fn into_into_dart(self) -> crate::api::p2p::AppInboundEventC {
-        self
+
+impl SseEncode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u32>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::p2p::AppInboundKindC {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Call => 0.into_dart(),
-            Self::Notify => 1.into_dart(),
-            _ => unreachable!(),
+
+impl SseEncode for Vec<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u64>::sse_encode(item, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::p2p::AppInboundKindC
-{
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::AppInboundKindC>
-    for crate::api::p2p::AppInboundKindC
-{
-    fn into_into_dart(self) -> crate::api::p2p::AppInboundKindC {
-        self
+
+impl SseEncode for Vec<crate::api::proxy_cidr::ProxyCidrC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::proxy_cidr::ProxyCidrC>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::p2p::FlagsC {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.default_protocol.into_into_dart().into_dart(),
-            self.dev_name.into_into_dart().into_dart(),
-            self.enable_encryption.into_into_dart().into_dart(),
-            self.enable_ipv6.into_into_dart().into_dart(),
-            self.mtu.into_into_dart().into_dart(),
-            self.latency_first.into_into_dart().into_dart(),
-            self.enable_exit_node.into_into_dart().into_dart(),
-            self.no_tun.into_into_dart().into_dart(),
-            self.use_smoltcp.into_into_dart().into_dart(),
-            self.relay_network_whitelist.into_into_dart().into_dart(),
-            self.disable_p2p.into_into_dart().into_dart(),
-            self.relay_all_peer_rpc.into_into_dart().into_dart(),
-            self.disable_udp_hole_punching.into_into_dart().into_dart(),
-            self.disable_tcp_hole_punching.into_into_dart().into_dart(),
-            self.multi_thread.into_into_dart().into_dart(),
-            self.data_compress_algo.into_into_dart().into_dart(),
-            self.bind_device.into_into_dart().into_dart(),
-            self.enable_kcp_proxy.into_into_dart().into_dart(),
-            self.disable_kcp_input.into_into_dart().into_dart(),
-            self.disable_relay_kcp.into_into_dart().into_dart(),
-            self.proxy_forward_by_system.into_into_dart().into_dart(),
-            self.accept_dns.into_into_dart().into_dart(),
-            self.private_mode.into_into_dart().into_dart(),
-            self.enable_quic_proxy.into_into_dart().into_dart(),
-            self.disable_quic_input.into_into_dart().into_dart(),
-            self.disable_sym_hole_punching.into_into_dart().into_dart(),
-            self.tcp_whitelist.into_into_dart().into_dart(),
-            self.udp_whitelist.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::proxy_cidr::ProxyCidrConflictC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::proxy_cidr::ProxyCidrConflictC>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::p2p::FlagsC {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::FlagsC> for crate::api::p2p::FlagsC {
-    fn into_into_dart(self) -> crate::api::p2p::FlagsC {
-        self
+
+impl SseEncode for Vec<crate::api::app_policy::ReasonRejectionsC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::app_policy::ReasonRejectionsC>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::p2p::Forward {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.bind_addr.into_into_dart().into_dart(),
-            self.dst_addr.into_into_dart().into_dart(),
-            self.proto.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::server_selection::SelectedServerC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::server_selection::SelectedServerC>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::p2p::Forward {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::Forward> for crate::api::p2p::Forward {
-    fn into_into_dart(self) -> crate::api::p2p::Forward {
-        self
+
+impl SseEncode for Vec<crate::api::server_probe::ServerProbeResultC> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::server_probe::ServerProbeResultC>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::p2p::KVNetworkStatus {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.total_nodes.into_into_dart().into_dart(),
-            self.nodes.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::port_mapping::ListenerMappingC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.local_listener, serializer);
+        <String>::sse_encode(self.mapped_listener, serializer);
+        <String>::sse_encode(self.backend, serializer);
+        <crate::api::port_mapping::PortMappingStateC>::sse_encode(self.state, serializer);
+        <bool>::sse_encode(self.managed_by_easytier, serializer);
+        <u32>::sse_encode(self.lease_remaining_secs, serializer);
+        <String>::sse_encode(self.error, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::p2p::KVNetworkStatus
-{
+
+impl SseEncode for crate::api::port_mapping::LocalEndpointsC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.listeners, serializer);
+        <Vec<String>>::sse_encode(self.mapped_listeners, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::KVNetworkStatus>
-    for crate::api::p2p::KVNetworkStatus
-{
-    fn into_into_dart(self) -> crate::api::p2p::KVNetworkStatus {
-        self
+
+impl SseEncode for crate::api::local_channel::LocalEventC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.instance_id, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <u64>::sse_encode(self.seq, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::p2p::KVNodeConnectionStats {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.conn_type.into_into_dart().into_dart(),
-            self.rx_bytes.into_into_dart().into_dart(),
-            self.tx_bytes.into_into_dart().into_dart(),
-            self.rx_packets.into_into_dart().into_dart(),
-            self.tx_packets.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::messaging::MessageEventC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::messaging::MessageEventKindC>::sse_encode(self.kind, serializer);
+        <u32>::sse_encode(self.peer_id, serializer);
+        <crate::api::messaging::ChatMessageC>::sse_encode(self.message, serializer);
+        <String>::sse_encode(self.error, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::p2p::KVNodeConnectionStats
-{
+
+impl SseEncode for crate::api::messaging::MessageEventKindC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::messaging::MessageEventKindC::Received => 0,
+                crate::api::messaging::MessageEventKindC::Sent => 1,
+                crate::api::messaging::MessageEventKindC::Delivered => 2,
+                crate::api::messaging::MessageEventKindC::Read => 3,
+                crate::api::messaging::MessageEventKindC::Queued => 4,
+                crate::api::messaging::MessageEventKindC::HistoryWriteFailed => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::KVNodeConnectionStats>
-    for crate::api::p2p::KVNodeConnectionStats
-{
-    fn into_into_dart(self) -> crate::api::p2p::KVNodeConnectionStats {
-        self
+
+impl SseEncode for crate::api::nat::NatCompatibilityC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::nat::NatTypeC>::sse_encode(self.peer_nat_type, serializer);
+        <crate::api::nat::P2pLikelihoodC>::sse_encode(self.likelihood, serializer);
+        <String>::sse_encode(self.explanation, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::p2p::KVNodeInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.peer_id.into_into_dart().into_dart(),
-            self.hostname.into_into_dart().into_dart(),
-            self.ipv4.into_into_dart().into_dart(),
-            self.ipv6.into_into_dart().into_dart(),
-            self.latency_ms.into_into_dart().into_dart(),
-            self.nat.into_into_dart().into_dart(),
-            self.hops.into_into_dart().into_dart(),
-            self.loss_rate.into_into_dart().into_dart(),
-            self.connections.into_into_dart().into_dart(),
-            self.tunnel_proto.into_into_dart().into_dart(),
-            self.conn_type.into_into_dart().into_dart(),
-            self.rx_bytes.into_into_dart().into_dart(),
-            self.tx_bytes.into_into_dart().into_dart(),
-            self.version.into_into_dart().into_dart(),
-            self.cost.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::nat::NatDetectionC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::nat::NatTypeC>::sse_encode(self.udp_nat_type, serializer);
+        <crate::api::nat::NatTypeC>::sse_encode(self.tcp_nat_type, serializer);
+        <crate::api::nat::PortMappingC>::sse_encode(self.udp_port_mapping, serializer);
+        <Vec<String>>::sse_encode(self.public_ips, serializer);
+        <String>::sse_encode(self.mapped_udp_addr, serializer);
+        <u32>::sse_encode(self.min_port, serializer);
+        <u32>::sse_encode(self.max_port, serializer);
+        <Vec<crate::api::nat::NatCompatibilityC>>::sse_encode(self.compatibility, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::p2p::KVNodeInfo {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::KVNodeInfo>
-    for crate::api::p2p::KVNodeInfo
-{
-    fn into_into_dart(self) -> crate::api::p2p::KVNodeInfo {
-        self
+
+impl SseEncode for crate::api::nat::NatTypeC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::nat::NatTypeC::Unknown => 0,
+                crate::api::nat::NatTypeC::OpenInternet => 1,
+                crate::api::nat::NatTypeC::NoPat => 2,
+                crate::api::nat::NatTypeC::FullCone => 3,
+                crate::api::nat::NatTypeC::Restricted => 4,
+                crate::api::nat::NatTypeC::PortRestricted => 5,
+                crate::api::nat::NatTypeC::Symmetric => 6,
+                crate::api::nat::NatTypeC::SymUdpFirewall => 7,
+                crate::api::nat::NatTypeC::SymmetricEasyInc => 8,
+                crate::api::nat::NatTypeC::SymmetricEasyDec => 9,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::p2p::NodeHopStats {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.peer_id.into_into_dart().into_dart(),
-            self.target_ip.into_into_dart().into_dart(),
-            self.latency_ms.into_into_dart().into_dart(),
-            self.packet_loss.into_into_dart().into_dart(),
-            self.node_name.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::p2p::NodeHopStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.peer_id, serializer);
+        <String>::sse_encode(self.target_ip, serializer);
+        <f64>::sse_encode(self.latency_ms, serializer);
+        <f32>::sse_encode(self.packet_loss, serializer);
+        <String>::sse_encode(self.node_name, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::p2p::NodeHopStats {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::p2p::NodeHopStats>
-    for crate::api::p2p::NodeHopStats
-{
-    fn into_into_dart(self) -> crate::api::p2p::NodeHopStats {
-        self
+
+impl SseEncode for crate::api::credential::NodeKeyInfoC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.public_key, serializer);
+        <String>::sse_encode(self.fingerprint, serializer);
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for Option<crate::api::app_policy::AppRpcPolicyC> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::app_policy::AppRpcPolicyC>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for EventBusSubscriber {
+impl SseEncode for Option<crate::api::local_channel::LocalEventC> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::local_channel::LocalEventC>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for JoinHandle<()> {
+impl SseEncode for Option<crate::api::credential::NodeKeyInfoC> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner< JoinHandle < () >>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::credential::NodeKeyInfoC>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for JoinHandle<Result<String, String>> {
+impl SseEncode for Option<crate::api::presence::PresenceProfileC> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
-                JoinHandle<Result<String, String>>,
-            >,
-        >>::sse_encode(
-            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::presence::PresenceProfileC>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::nat::P2pLikelihoodC {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::nat::P2pLikelihoodC::Likely => 0,
+                crate::api::nat::P2pLikelihoodC::Possible => 1,
+                crate::api::nat::P2pLikelihoodC::Unlikely => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
             serializer,
         );
     }
 }
 
-impl SseEncode for PeerRoutePair {
+impl SseEncode for crate::api::app_codec::PayloadCodecC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::app_codec::PayloadCodecC::Raw => 0,
+                crate::api::app_codec::PayloadCodecC::Json => 1,
+                crate::api::app_codec::PayloadCodecC::MessagePack => 2,
+                crate::api::app_codec::PayloadCodecC::Cbor => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EventBusSubscriber>>
-{
+impl SseEncode for crate::api::app_policy::PeerChannelRuleC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <u32>::sse_encode(self.peer_id, serializer);
+        <Vec<String>>::sse_encode(self.allow, serializer);
+        <Vec<String>>::sse_encode(self.deny, serializer);
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<()>>>
-{
+impl SseEncode for crate::api::peer_control::PeerConnectionC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <String>::sse_encode(self.conn_id, serializer);
+        <String>::sse_encode(self.tunnel_type, serializer);
+        <String>::sse_encode(self.remote_addr, serializer);
+        <bool>::sse_encode(self.is_client, serializer);
+        <String>::sse_encode(self.connector_url, serializer);
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<
-        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<JoinHandle<Result<String, String>>>,
-    >
-{
+impl SseEncode for crate::api::app_metrics::PeerMetricsC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <u32>::sse_encode(self.peer_id, serializer);
+        <crate::api::app_metrics::AppRpcCountersC>::sse_encode(self.counters, serializer);
+        <crate::api::app_metrics::LatencyHistogramC>::sse_encode(self.call_latency, serializer);
+        <crate::api::app_metrics::LatencyHistogramC>::sse_encode(self.handler_latency, serializer);
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PeerRoutePair>>
-{
+impl SseEncode for crate::api::presence::PeerPresenceC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <crate::api::p2p::KVNodeInfo>::sse_encode(self.node, serializer);
+        <Option<crate::api::presence::PresenceProfileC>>::sse_encode(self.profile, serializer);
     }
 }
 
-impl SseEncode
-    for StreamSink<crate::api::p2p::AppInboundEventC, flutter_rust_bridge::for_generated::SseCodec>
-{
+impl SseEncode for crate::api::proxy_cidr::PeerProxyCidrsC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <u32>::sse_encode(self.peer_id, serializer);
+        <String>::sse_encode(self.hostname, serializer);
+        <Vec<String>>::sse_encode(self.cidrs, serializer);
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::api::app_policy::PeerRejectionsC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <u32>::sse_encode(self.peer_id, serializer);
+        <u64>::sse_encode(self.count, serializer);
     }
 }
 
-impl SseEncode for crate::api::p2p::AppCallResultC {
+impl SseEncode for crate::api::credential::PinnedServerKeyC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.status, serializer);
-        <String>::sse_encode(self.error_msg, serializer);
-        <Vec<u8>>::sse_encode(self.payload, serializer);
+        <String>::sse_encode(self.server_url, serializer);
+        <String>::sse_encode(self.public_key, serializer);
+        <String>::sse_encode(self.fingerprint, serializer);
     }
 }
 
-impl SseEncode for crate::api::p2p::AppInboundEventC {
+impl SseEncode for crate::api::port_forward::PortForwardC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <crate::api::p2p::AppInboundKindC>::sse_encode(self.kind, serializer);
-        <u32>::sse_encode(self.from_peer_id, serializer);
-        <String>::sse_encode(self.channel, serializer);
-        <u64>::sse_encode(self.request_id, serializer);
-        <u64>::sse_encode(self.token, serializer);
-        <Vec<u8>>::sse_encode(self.payload, serializer);
+        <String>::sse_encode(self.proto, serializer);
+        <String>::sse_encode(self.bind_addr, serializer);
+        <String>::sse_encode(self.dst_addr, serializer);
+        <bool>::sse_encode(self.managed, serializer);
+        <u32>::sse_encode(self.active_connections, serializer);
+        <u64>::sse_encode(self.total_connections, serializer);
+        <u64>::sse_encode(self.tx_bytes, serializer);
+        <u64>::sse_encode(self.rx_bytes, serializer);
+        <u64>::sse_encode(self.errors, serializer);
+        <String>::sse_encode(self.last_error, serializer);
     }
 }
 
-impl SseEncode for crate::api::p2p::AppInboundKindC {
+impl SseEncode for crate::api::nat::PortMappingC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(
             match self {
-                crate::api::p2p::AppInboundKindC::Call => 0,
-                crate::api::p2p::AppInboundKindC::Notify => 1,
+                crate::api::nat::PortMappingC::Unknown => 0,
+                crate::api::nat::PortMappingC::NoTranslation => 1,
+                crate::api::nat::PortMappingC::EndpointIndependent => 2,
+                crate::api::nat::PortMappingC::EndpointDependentPredictable => 3,
+                crate::api::nat::PortMappingC::EndpointDependent => 4,
                 _ => {
                     unimplemented!("");
                 }
@@ -1992,202 +11350,222 @@ impl SseEncode for crate::api::p2p::AppInboundKindC {
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::port_mapping::PortMappingRequestC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <String>::sse_encode(self.listener, serializer);
+        <u16>::sse_encode(self.external_port, serializer);
     }
 }
 
-impl SseEncode for f32 {
+impl SseEncode for crate::api::port_mapping::PortMappingStateC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(
+            match self {
+                crate::api::port_mapping::PortMappingStateC::Active => 0,
+                crate::api::port_mapping::PortMappingStateC::Failed => 1,
+                crate::api::port_mapping::PortMappingStateC::Expired => 2,
+                crate::api::port_mapping::PortMappingStateC::Removed => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for f64 {
+impl SseEncode for crate::api::presence::PresenceEventC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+        <crate::api::presence::PresenceEventKindC>::sse_encode(self.kind, serializer);
+        <u32>::sse_encode(self.peer_id, serializer);
+        <Option<crate::api::presence::PresenceProfileC>>::sse_encode(self.profile, serializer);
     }
 }
 
-impl SseEncode for crate::api::p2p::FlagsC {
+impl SseEncode for crate::api::presence::PresenceEventKindC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.default_protocol, serializer);
-        <String>::sse_encode(self.dev_name, serializer);
-        <bool>::sse_encode(self.enable_encryption, serializer);
-        <bool>::sse_encode(self.enable_ipv6, serializer);
-        <u32>::sse_encode(self.mtu, serializer);
-        <bool>::sse_encode(self.latency_first, serializer);
-        <bool>::sse_encode(self.enable_exit_node, serializer);
-        <bool>::sse_encode(self.no_tun, serializer);
-        <bool>::sse_encode(self.use_smoltcp, serializer);
-        <String>::sse_encode(self.relay_network_whitelist, serializer);
-        <bool>::sse_encode(self.disable_p2p, serializer);
-        <bool>::sse_encode(self.relay_all_peer_rpc, serializer);
-        <bool>::sse_encode(self.disable_udp_hole_punching, serializer);
-        <bool>::sse_encode(self.disable_tcp_hole_punching, serializer);
-        <bool>::sse_encode(self.multi_thread, serializer);
-        <i32>::sse_encode(self.data_compress_algo, serializer);
-        <bool>::sse_encode(self.bind_device, serializer);
-        <bool>::sse_encode(self.enable_kcp_proxy, serializer);
-        <bool>::sse_encode(self.disable_kcp_input, serializer);
-        <bool>::sse_encode(self.disable_relay_kcp, serializer);
-        <bool>::sse_encode(self.proxy_forward_by_system, serializer);
-        <bool>::sse_encode(self.accept_dns, serializer);
-        <bool>::sse_encode(self.private_mode, serializer);
-        <bool>::sse_encode(self.enable_quic_proxy, serializer);
-        <bool>::sse_encode(self.disable_quic_input, serializer);
-        <bool>::sse_encode(self.disable_sym_hole_punching, serializer);
-        <String>::sse_encode(self.tcp_whitelist, serializer);
-        <String>::sse_encode(self.udp_whitelist, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::presence::PresenceEventKindC::Updated => 0,
+                crate::api::presence::PresenceEventKindC::Left => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for crate::api::p2p::Forward {
+impl SseEncode for crate::api::presence::PresenceProfileC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.bind_addr, serializer);
-        <String>::sse_encode(self.dst_addr, serializer);
-        <String>::sse_encode(self.proto, serializer);
+        <String>::sse_encode(self.display_name, serializer);
+        <String>::sse_encode(self.avatar_hash, serializer);
+        <crate::api::presence::PresenceStatusC>::sse_encode(self.status, serializer);
+        <String>::sse_encode(self.app_name, serializer);
+        <String>::sse_encode(self.app_version, serializer);
+        <String>::sse_encode(self.current_game, serializer);
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::api::presence::PresenceStatusC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(
+            match self {
+                crate::api::presence::PresenceStatusC::Online => 0,
+                crate::api::presence::PresenceStatusC::Away => 1,
+                crate::api::presence::PresenceStatusC::Busy => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for i64 {
+impl SseEncode for crate::api::proxy_cidr::ProxyCidrC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+        <String>::sse_encode(self.cidr, serializer);
+        <String>::sse_encode(self.mapped_cidr, serializer);
     }
 }
 
-impl SseEncode for crate::api::p2p::KVNetworkStatus {
+impl SseEncode for crate::api::proxy_cidr::ProxyCidrConflictC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <usize>::sse_encode(self.total_nodes, serializer);
-        <Vec<crate::api::p2p::KVNodeInfo>>::sse_encode(self.nodes, serializer);
+        <crate::api::proxy_cidr::ProxyCidrConflictKindC>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.cidr, serializer);
+        <String>::sse_encode(self.other_cidr, serializer);
+        <u32>::sse_encode(self.peer_id, serializer);
+        <String>::sse_encode(self.hostname, serializer);
+        <String>::sse_encode(self.interface, serializer);
     }
 }
 
-impl SseEncode for crate::api::p2p::KVNodeConnectionStats {
+impl SseEncode for crate::api::proxy_cidr::ProxyCidrConflictKindC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.conn_type, serializer);
-        <u64>::sse_encode(self.rx_bytes, serializer);
-        <u64>::sse_encode(self.tx_bytes, serializer);
-        <u64>::sse_encode(self.rx_packets, serializer);
-        <u64>::sse_encode(self.tx_packets, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::proxy_cidr::ProxyCidrConflictKindC::VirtualNetwork => 0,
+                crate::api::proxy_cidr::ProxyCidrConflictKindC::PeerExport => 1,
+                crate::api::proxy_cidr::ProxyCidrConflictKindC::LocalInterface => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for crate::api::p2p::KVNodeInfo {
+impl SseEncode for crate::api::app_policy::RateLimitC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u32>::sse_encode(self.peer_id, serializer);
-        <String>::sse_encode(self.hostname, serializer);
-        <String>::sse_encode(self.ipv4, serializer);
-        <String>::sse_encode(self.ipv6, serializer);
-        <f64>::sse_encode(self.latency_ms, serializer);
-        <String>::sse_encode(self.nat, serializer);
-        <Vec<crate::api::p2p::NodeHopStats>>::sse_encode(self.hops, serializer);
-        <f32>::sse_encode(self.loss_rate, serializer);
-        <Vec<crate::api::p2p::KVNodeConnectionStats>>::sse_encode(self.connections, serializer);
-        <String>::sse_encode(self.tunnel_proto, serializer);
-        <String>::sse_encode(self.conn_type, serializer);
-        <u64>::sse_encode(self.rx_bytes, serializer);
-        <u64>::sse_encode(self.tx_bytes, serializer);
-        <String>::sse_encode(self.version, serializer);
-        <i32>::sse_encode(self.cost, serializer);
+        <u32>::sse_encode(self.per_second, serializer);
+        <u32>::sse_encode(self.burst, serializer);
     }
 }
 
-impl SseEncode for Vec<PeerRoutePair> {
+impl SseEncode for crate::api::app_policy::ReasonRejectionsC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <PeerRoutePair>::sse_encode(item, serializer);
-        }
+        <crate::api::app_policy::RejectReasonC>::sse_encode(self.reason, serializer);
+        <u64>::sse_encode(self.count, serializer);
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for crate::api::app_policy::RejectReasonC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::app_policy::RejectReasonC::PayloadTooLarge => 0,
+                crate::api::app_policy::RejectReasonC::PeerRateLimited => 1,
+                crate::api::app_policy::RejectReasonC::ChannelRateLimited => 2,
+                crate::api::app_policy::RejectReasonC::ChannelDenied => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<crate::api::p2p::Forward> {
+impl SseEncode for crate::api::server_selection::SelectedServerC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::p2p::Forward>::sse_encode(item, serializer);
-        }
+        <String>::sse_encode(self.url, serializer);
+        <crate::api::server_selection::ServerRoleC>::sse_encode(self.role, serializer);
+        <u32>::sse_encode(self.latency_ms, serializer);
+        <bool>::sse_encode(self.connected, serializer);
+        <String>::sse_encode(self.reason, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::p2p::KVNodeConnectionStats> {
+impl SseEncode for crate::api::server_probe::ServerProbeResultC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::p2p::KVNodeConnectionStats>::sse_encode(item, serializer);
-        }
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.scheme, serializer);
+        <bool>::sse_encode(self.protocol_supported, serializer);
+        <String>::sse_encode(self.resolved_addr, serializer);
+        <u32>::sse_encode(self.dns_ms, serializer);
+        <bool>::sse_encode(self.reachable, serializer);
+        <u32>::sse_encode(self.latency_ms, serializer);
+        <String>::sse_encode(self.error, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::p2p::KVNodeInfo> {
+impl SseEncode for crate::api::server_selection::ServerRoleC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::p2p::KVNodeInfo>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::server_selection::ServerRoleC::Active => 0,
+                crate::api::server_selection::ServerRoleC::Standby => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<crate::api::p2p::NodeHopStats> {
+impl SseEncode for crate::api::server_selection::ServerSelectionStatusC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::p2p::NodeHopStats>::sse_encode(item, serializer);
-        }
+        <bool>::sse_encode(self.enabled, serializer);
+        <u32>::sse_encode(self.active_count, serializer);
+        <Vec<crate::api::server_selection::SelectedServerC>>::sse_encode(self.servers, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::api::firewall::TunFirewallStatusC {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <crate::api::firewall::FirewallBackendC>::sse_encode(self.backend, serializer);
+        <bool>::sse_encode(self.active, serializer);
+        <bool>::sse_encode(self.tun_allowed, serializer);
+        <Vec<crate::api::firewall::FirewallPortStatusC>>::sse_encode(self.ports, serializer);
+        <String>::sse_encode(self.detail, serializer);
     }
 }
 
-impl SseEncode for crate::api::p2p::NodeHopStats {
+impl SseEncode for u16 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u32>::sse_encode(self.peer_id, serializer);
-        <String>::sse_encode(self.target_ip, serializer);
-        <f64>::sse_encode(self.latency_ms, serializer);
-        <f32>::sse_encode(self.packet_loss, serializer);
-        <String>::sse_encode(self.node_name, serializer);
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
     }
 }
 